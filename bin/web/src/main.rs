@@ -1,24 +1,46 @@
 use std::sync::Arc;
 
 use actix_web::{HttpServer, dev::ServerHandle, rt::signal};
+use domain::traits::email_sender::EmailSender;
 use infrastructure::{
-    config::{database::DatabaseConfig, web::WebConfig},
+    config::{database::DatabaseConfig, email::EmailConfig, web::WebConfig},
     database::{migrator::migrator, pool::create_db_pool},
+    email::{file_email_sender::FileEmailSender, smtp_email_sender::SmtpEmailSender},
     repositories::{
+        auto_reply_rule_repository_impl::AutoReplyRuleRepositoryImpl,
+        business_hours_repository_impl::BusinessHoursRepositoryImpl,
+        campaign_repository_impl::CampaignRepositoryImpl,
+        contact_import_repository_impl::ContactImportRepositoryImpl,
+        contact_repository_impl::ContactRepositoryImpl,
         conversation_repository_impl::ConversationRepositoryImpl,
         message_repository_impl::MessageRepositoryImpl,
+        message_status_event_repository_impl::MessageStatusEventRepositoryImpl,
+        message_template_repository_impl::MessageTemplateRepositoryImpl,
+        opt_out_repository_impl::OptOutRepositoryImpl,
+        organization_invitation_repository_impl::OrganizationInvitationRepositoryImpl,
+        organization_repository_impl::OrganizationRepositoryImpl,
+        outbound_message_queue_repository_impl::OutboundMessageQueueRepositoryImpl,
         phone_number_repository_impl::PhoneNumberRepositoryImpl,
         processed_webhook_event_repository_impl::ProcessedWebhookEventRepositoryImpl,
-        user_repository_impl::UserRepositoryImpl,
+        reset_password_repository_impl::ResetPasswordRepositoryImpl,
+        usage_limit_repository_impl::UsageLimitRepositoryImpl,
+        usage_repository_impl::UsageRepositoryImpl, user_repository_impl::UserRepositoryImpl,
     },
     security::argon2_hasher::Argon2Hasher,
+    security::paseto_media_url_signer::PasetoMediaUrlSigner,
     security::paseto_tokenizer::PasetoAuthenticationTokenService,
+    storage::local_media_storage::LocalMediaStorage,
 };
 use telnyx::TelnyxClient;
 use tracing::{info, subscriber::set_global_default};
 use tracing_log::LogTracer;
 use tracing_subscriber::{EnvFilter, Registry, layer::SubscriberExt};
-use web::{realtime::MessageEventBroadcaster, server::create_web_service};
+use web::{
+    campaign_worker::CampaignWorker, contact_import_worker::ContactImportWorker,
+    outbound_dispatcher::OutboundMessageDispatcher, realtime::MessageEventBroadcaster,
+    server::create_web_service, snooze_worker::SnoozeWorker, usage_alert_worker::UsageAlertWorker,
+    webhook_inbox::WebhookInboxWorker,
+};
 
 #[actix_web::main]
 async fn main() -> eyre::Result<()> {
@@ -28,6 +50,7 @@ async fn main() -> eyre::Result<()> {
 
     let config = WebConfig::from_env()?;
     let db_config = DatabaseConfig::from_env()?;
+    let email_config = EmailConfig::from_env()?;
 
     let pool = create_db_pool(&db_config.url).await?;
     let _ = migrator(&db_config.url).await;
@@ -39,6 +62,11 @@ async fn main() -> eyre::Result<()> {
             .build(),
     );
     let message_repository = Arc::new(MessageRepositoryImpl::builder().pool(pool.clone()).build());
+    let message_status_event_repository = Arc::new(
+        MessageStatusEventRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
     let phone_number_repository = Arc::new(
         PhoneNumberRepositoryImpl::builder()
             .pool(pool.clone())
@@ -49,6 +77,56 @@ async fn main() -> eyre::Result<()> {
             .pool(pool.clone())
             .build(),
     );
+    let reset_password_repository = Arc::new(
+        ResetPasswordRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let outbound_message_queue_repository = Arc::new(
+        OutboundMessageQueueRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let opt_out_repository = Arc::new(OptOutRepositoryImpl::builder().pool(pool.clone()).build());
+    let contact_repository = Arc::new(ContactRepositoryImpl::builder().pool(pool.clone()).build());
+    let contact_import_repository = Arc::new(
+        ContactImportRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let campaign_repository =
+        Arc::new(CampaignRepositoryImpl::builder().pool(pool.clone()).build());
+    let message_template_repository = Arc::new(
+        MessageTemplateRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let auto_reply_rule_repository = Arc::new(
+        AutoReplyRuleRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let business_hours_repository = Arc::new(
+        BusinessHoursRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let usage_repository = Arc::new(UsageRepositoryImpl::builder().pool(pool.clone()).build());
+    let usage_limit_repository = Arc::new(
+        UsageLimitRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let organization_repository = Arc::new(
+        OrganizationRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
+    let organization_invitation_repository = Arc::new(
+        OrganizationInvitationRepositoryImpl::builder()
+            .pool(pool.clone())
+            .build(),
+    );
     let password_hasher = Arc::new(Argon2Hasher::new());
     let token_service = Arc::new(PasetoAuthenticationTokenService::new(
         &config.paseto_symmetric_key,
@@ -60,9 +138,115 @@ async fn main() -> eyre::Result<()> {
             .messaging_profile_id(config.telnyx_messaging_profile_id.clone())
             .build(),
     );
+    let media_storage = Arc::new(LocalMediaStorage::new(config.media_storage_dir.clone()));
+    let media_url_signer = Arc::new(PasetoMediaUrlSigner::new(
+        token_service.clone(),
+        config.public_base_url.clone(),
+    ));
+    let email_sender: Arc<dyn EmailSender> = match email_config.smtp {
+        Some(smtp) => Arc::new(
+            SmtpEmailSender::builder()
+                .host(smtp.host)
+                .port(smtp.port)
+                .tls(smtp.tls)
+                .maybe_username(smtp.username)
+                .maybe_password(smtp.password)
+                .from(smtp.from)
+                .build(),
+        ),
+        None => {
+            info!(
+                "SMTP_HOST is not set, writing outgoing emails to {}",
+                email_config.outbox_dir
+            );
+            Arc::new(FileEmailSender::new(email_config.outbox_dir))
+        }
+    };
     let message_event_broadcaster = Arc::new(MessageEventBroadcaster::default());
 
+    let outbound_message_dispatcher = OutboundMessageDispatcher::builder()
+        .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+        .message_repository(message_repository.clone())
+        .message_status_event_repository(message_status_event_repository.clone())
+        .conversation_repository(conversation_repository.clone())
+        .contact_repository(contact_repository.clone())
+        .opt_out_repository(opt_out_repository.clone())
+        .outbound_message_service(outbound_message_service.clone())
+        .media_url_signer(media_url_signer.clone())
+        .usage_repository(usage_repository.clone())
+        .usage_limit_repository(usage_limit_repository.clone())
+        .message_event_broadcaster(message_event_broadcaster.clone())
+        .maybe_segment_price_micros(config.sms_segment_price_micros)
+        .build();
+    actix::spawn(outbound_message_dispatcher.run());
+
+    let webhook_inbox_worker = WebhookInboxWorker::builder()
+        .processed_webhook_event_repository(processed_webhook_event_repository.clone())
+        .conversation_repository(conversation_repository.clone())
+        .contact_repository(contact_repository.clone())
+        .message_repository(message_repository.clone())
+        .message_status_event_repository(message_status_event_repository.clone())
+        .phone_number_repository(phone_number_repository.clone())
+        .opt_out_repository(opt_out_repository.clone())
+        .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+        .auto_reply_rule_repository(auto_reply_rule_repository.clone())
+        .business_hours_repository(business_hours_repository.clone())
+        .organization_repository(organization_repository.clone())
+        .media_storage(media_storage.clone())
+        .media_downloader(outbound_message_service.clone())
+        .media_url_signer(media_url_signer.clone())
+        .usage_repository(usage_repository.clone())
+        .usage_limit_repository(usage_limit_repository.clone())
+        .message_event_broadcaster(message_event_broadcaster.clone())
+        .maybe_help_reply(config.sms_help_reply.clone())
+        .build();
+    actix::spawn(webhook_inbox_worker.run());
+
+    let contact_import_worker = ContactImportWorker::builder()
+        .contact_import_repository(contact_import_repository.clone())
+        .contact_repository(contact_repository.clone())
+        .build();
+    actix::spawn(contact_import_worker.run());
+
+    let campaign_worker = CampaignWorker::builder()
+        .campaign_repository(campaign_repository.clone())
+        .conversation_repository(conversation_repository.clone())
+        .message_repository(message_repository.clone())
+        .phone_number_repository(phone_number_repository.clone())
+        .user_repository(user_repository.clone())
+        .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+        .opt_out_repository(opt_out_repository.clone())
+        .organization_repository(organization_repository.clone())
+        .message_template_repository(message_template_repository.clone())
+        .contact_repository(contact_repository.clone())
+        .media_storage(media_storage.clone())
+        .usage_repository(usage_repository.clone())
+        .usage_limit_repository(usage_limit_repository.clone())
+        .block_unverified_senders(config.block_unverified_senders)
+        .build();
+    actix::spawn(campaign_worker.run());
+
+    let usage_alert_worker = UsageAlertWorker::builder()
+        .usage_repository(usage_repository.clone())
+        .usage_limit_repository(usage_limit_repository.clone())
+        .user_repository(user_repository.clone())
+        .email_sender(email_sender.clone())
+        .message_event_broadcaster(message_event_broadcaster.clone())
+        .build();
+    actix::spawn(usage_alert_worker.run());
+
+    let snooze_worker = SnoozeWorker::builder()
+        .conversation_repository(conversation_repository.clone())
+        .contact_repository(contact_repository.clone())
+        .message_event_broadcaster(message_event_broadcaster.clone())
+        .build();
+    actix::spawn(snooze_worker.run());
+
     let session_secret = config.session_secret.clone();
+    let public_base_url = config.public_base_url.clone();
+    let block_unverified_senders = config.block_unverified_senders;
+    let ops_api_token = config.ops_api_token.clone();
+    let sms_segment_price_micros = config.sms_segment_price_micros;
     let telnyx_public_key = config.telnyx_public_key.clone();
     let telnyx_webhook_forward_urls = config.telnyx_webhook_forward_urls.clone();
     let server = HttpServer::new(move || {
@@ -72,11 +256,32 @@ async fn main() -> eyre::Result<()> {
             user_repository.clone(),
             conversation_repository.clone(),
             message_repository.clone(),
+            message_status_event_repository.clone(),
             phone_number_repository.clone(),
             processed_webhook_event_repository.clone(),
+            reset_password_repository.clone(),
+            outbound_message_queue_repository.clone(),
+            opt_out_repository.clone(),
+            contact_repository.clone(),
+            contact_import_repository.clone(),
+            campaign_repository.clone(),
+            message_template_repository.clone(),
+            auto_reply_rule_repository.clone(),
+            business_hours_repository.clone(),
+            usage_repository.clone(),
+            usage_limit_repository.clone(),
+            organization_repository.clone(),
+            organization_invitation_repository.clone(),
             password_hasher.clone(),
             token_service.clone(),
+            media_storage.clone(),
             outbound_message_service.clone(),
+            media_url_signer.clone(),
+            email_sender.clone(),
+            public_base_url.clone(),
+            block_unverified_senders,
+            ops_api_token.clone(),
+            sms_segment_price_micros,
             telnyx_public_key.clone(),
             telnyx_webhook_forward_urls.clone(),
             message_event_broadcaster.clone(),
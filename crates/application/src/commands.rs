@@ -1,5 +1,14 @@
+use std::collections::BTreeMap;
+
+use domain::models::auto_reply_rule::AutoReplyMatchType;
+use domain::models::business_hours::OpeningHours;
+use domain::models::campaign::CampaignStatus;
+use domain::models::contact::normalize_phone_number;
+use domain::models::contact_import::{ContactImportColumnMapping, ContactImportFormat};
+use domain::models::conversation::ConversationStateChange;
+use domain::models::organization::OrganizationRole;
 use garde::Validate;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
 #[derive(Debug, Validate, Clone)]
@@ -64,22 +73,316 @@ impl ResetPasswordCommand {
 #[derive(Debug, Clone)]
 pub struct CreateConversationCommand {
     pub user_id: Uuid,
+    pub organization_id: Uuid,
     pub phone_number_id: Uuid,
     pub recipient_phone_number: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct AssignConversationCommand {
+    /// The member making the change.
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub conversation_id: Uuid,
+    /// `None` unassigns the conversation.
+    pub assigned_user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChangeConversationStateCommand {
+    /// The member making the change.
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub conversation_id: Uuid,
+    pub change: ConversationStateChange,
+}
+
 #[derive(Debug, Clone)]
 pub struct CreatePhoneNumberCommand {
     pub user_id: Uuid,
+    pub organization_id: Uuid,
     pub name: String,
     pub phone: String,
 }
 
+#[derive(Debug, Validate, Clone)]
+pub struct ContactDetails {
+    #[garde(length(min = 1, max = 120))]
+    pub name: String,
+
+    #[garde(length(min = 1))]
+    pub phone_numbers: Vec<String>,
+
+    #[garde(email)]
+    pub email: Option<String>,
+
+    #[garde(skip)]
+    pub notes: Option<String>,
+
+    #[garde(skip)]
+    pub custom_fields: BTreeMap<String, String>,
+
+    #[garde(inner(length(max = 50)))]
+    pub tags: Vec<String>,
+}
+
+impl ContactDetails {
+    /// Strips common separators, checks every number is E.164 and drops duplicates while
+    /// keeping the order the user entered them in.
+    pub fn normalized_phone_numbers(&self) -> Result<Vec<String>, garde::Error> {
+        let mut phone_numbers = Vec::with_capacity(self.phone_numbers.len());
+
+        for phone_number in &self.phone_numbers {
+            let Some(normalized) = normalize_phone_number(phone_number, None) else {
+                return Err(garde::Error::new(
+                    "Phone numbers must be in E.164 format, e.g. +15551234567",
+                ));
+            };
+
+            if !phone_numbers.contains(&normalized) {
+                phone_numbers.push(normalized);
+            }
+        }
+
+        Ok(phone_numbers)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateContactCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub details: ContactDetails,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateContactCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub contact_id: Uuid,
+    pub details: ContactDetails,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportContactsCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub file_name: String,
+    pub content: String,
+    /// Detected from the file name and content when not given.
+    pub format: Option<ContactImportFormat>,
+    pub column_mapping: ContactImportColumnMapping,
+    pub default_country_code: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateCampaignCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub name: String,
+    /// Message text with `{{first_name}}`-style placeholders.
+    pub body: String,
+    /// Adds every contact with this tag as a recipient.
+    pub tag: Option<String>,
+    pub contact_ids: Vec<Uuid>,
+    pub phone_numbers: Vec<String>,
+    pub messages_per_second: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChangeCampaignStatusCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub campaign_id: Uuid,
+    pub status: CampaignStatus,
+}
+
+#[derive(Debug, Validate, Clone)]
+pub struct MessageTemplateDetails {
+    #[garde(length(min = 1, max = 120))]
+    pub name: String,
+
+    #[garde(length(min = 1, max = 1600))]
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateMessageTemplateCommand {
+    pub user_id: Uuid,
+    pub details: MessageTemplateDetails,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateMessageTemplateCommand {
+    pub user_id: Uuid,
+    pub template_id: Uuid,
+    pub details: MessageTemplateDetails,
+}
+
+#[derive(Debug, Validate, Clone)]
+pub struct AutoReplyRuleDetails {
+    #[garde(skip)]
+    pub match_type: AutoReplyMatchType,
+
+    #[garde(length(min = 1, max = 160))]
+    pub pattern: String,
+
+    #[garde(length(min = 1, max = 1600))]
+    pub reply_template: String,
+
+    #[garde(length(min = 1, max = 64))]
+    pub tag: Option<String>,
+
+    /// Up to 30 days.
+    #[garde(range(min = 0, max = 2_592_000))]
+    pub cooldown_seconds: i64,
+
+    #[garde(skip)]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateAutoReplyRuleCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub details: AutoReplyRuleDetails,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateAutoReplyRuleCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub rule_id: Uuid,
+    pub details: AutoReplyRuleDetails,
+}
+
+#[derive(Debug, Validate, Clone)]
+pub struct BusinessHoursDetails {
+    #[garde(length(min = 1, max = 64))]
+    pub timezone: String,
+
+    #[garde(length(max = 70))]
+    pub opening_hours: Vec<OpeningHours>,
+
+    #[garde(length(max = 366))]
+    pub holidays: Vec<Date>,
+
+    #[garde(length(min = 1, max = 1600))]
+    pub away_message: String,
+
+    #[garde(skip)]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveBusinessHoursCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub details: BusinessHoursDetails,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreviewMessageTemplateCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub template_id: Uuid,
+    /// Fills in the recipient's contact and sender details when given.
+    pub conversation_id: Option<Uuid>,
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateMessageCommand {
     pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub conversation_id: Uuid,
+    pub content: String,
+    pub attachments: Vec<MessageAttachmentUpload>,
+    /// Sends the message at this time instead of right away.
+    pub scheduled_at: Option<OffsetDateTime>,
+    /// Sends this template, rendered for the conversation, instead of `content`.
+    pub template_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreviewMessageCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
     pub conversation_id: Uuid,
     pub content: String,
+    /// Previews this template, rendered for the conversation, instead of `content`.
+    pub template_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveUsageLimitCommand {
+    pub user_id: Uuid,
+    pub period: domain::models::usage::UsagePeriod,
+    /// Amounts in micros; `None` removes that limit.
+    pub soft_limit_micros: Option<i64>,
+    pub hard_limit_micros: Option<i64>,
+}
+
+#[derive(Debug, Validate, Clone)]
+pub struct CreateOrganizationCommand {
+    #[garde(skip)]
+    pub user_id: Uuid,
+
+    #[garde(length(min = 1, max = 120))]
+    pub name: String,
+}
+
+#[derive(Debug, Validate, Clone)]
+pub struct InviteOrganizationMemberCommand {
+    /// The member sending the invitation.
+    #[garde(skip)]
+    pub user_id: Uuid,
+
+    #[garde(skip)]
+    pub organization_id: Uuid,
+
+    #[garde(email)]
+    pub email: String,
+
+    #[garde(skip)]
+    pub role: OrganizationRole,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChangeOrganizationMemberRoleCommand {
+    /// The member making the change.
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub member_user_id: Uuid,
+    pub role: OrganizationRole,
+}
+
+#[derive(Debug, Clone)]
+pub struct RescheduleMessageCommand {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub conversation_id: Uuid,
+    pub message_id: Uuid,
+    pub scheduled_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub struct MessageAttachmentUpload {
+    pub file_name: String,
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IngestTelnyxWebhookCommand {
+    pub event_id: String,
+    pub event_type: String,
+    pub occurred_at: OffsetDateTime,
+    pub provider_message_id: Option<String>,
+    pub raw_payload: serde_json::Value,
 }
 
 #[derive(Debug, Clone)]
@@ -88,25 +391,42 @@ pub struct ProcessTelnyxWebhookCommand {
     pub event_type: String,
     pub occurred_at: OffsetDateTime,
     pub payload: TelnyxWebhookMessagePayload,
-    pub raw_payload: serde_json::Value,
 }
 
 #[derive(Debug, Clone)]
 pub struct TelnyxWebhookMessagePayload {
     pub provider_message_id: String,
     pub from_phone_number: Option<String>,
+    pub from_carrier: Option<String>,
+    pub from_line_type: Option<String>,
     pub to: Vec<TelnyxWebhookMessageParticipant>,
     pub text: Option<String>,
     pub received_at: Option<OffsetDateTime>,
     pub sent_at: Option<OffsetDateTime>,
     pub completed_at: Option<OffsetDateTime>,
     pub errors: Vec<TelnyxWebhookMessageError>,
+    pub media: Vec<TelnyxWebhookMessageMedia>,
+    /// Number of SMS parts Telnyx split the message into.
+    pub parts: Option<i32>,
+    /// Encoding named by Telnyx, e.g. `GSM-7` or `UCS-2`.
+    pub encoding: Option<String>,
+    pub cost: Option<TelnyxWebhookMessageCost>,
+    pub messaging_profile_id: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct TelnyxWebhookMessageParticipant {
     pub phone_number: Option<String>,
     pub status: Option<String>,
+    pub carrier: Option<String>,
+    pub line_type: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TelnyxWebhookMessageCost {
+    /// Decimal amount as sent by Telnyx, e.g. `0.0040`.
+    pub amount: Option<String>,
+    pub currency: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -115,3 +435,11 @@ pub struct TelnyxWebhookMessageError {
     pub detail: Option<String>,
     pub title: Option<String>,
 }
+
+#[derive(Debug, Clone)]
+pub struct TelnyxWebhookMessageMedia {
+    pub url: String,
+    pub content_type: Option<String>,
+    pub size: Option<i64>,
+    pub sha256: Option<String>,
+}
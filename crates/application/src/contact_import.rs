@@ -0,0 +1,515 @@
+//! Readers for uploaded address book files. They only split a file into entries; phone
+//! numbers are normalized and de-duplicated by the import usecase.
+
+use std::collections::BTreeMap;
+
+use domain::models::contact_import::ContactImportColumnMapping;
+
+/// One contact as written in the file, before any validation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedContact {
+    /// 1-based line the entry starts on.
+    pub line: usize,
+    pub name: Option<String>,
+    pub phone_numbers: Vec<String>,
+    pub email: Option<String>,
+    pub notes: Option<String>,
+    pub custom_fields: BTreeMap<String, String>,
+}
+
+const NAME_HEADERS: [&str; 5] = ["name", "full name", "full_name", "display name", "contact"];
+const FIRST_NAME_HEADERS: [&str; 3] = ["first name", "first_name", "given name"];
+const LAST_NAME_HEADERS: [&str; 4] = ["last name", "last_name", "family name", "surname"];
+const NOTES_HEADERS: [&str; 2] = ["notes", "note"];
+
+/// Reads a CSV file whose first row is a header. Fields may be quoted, contain the
+/// delimiter or line breaks, and the delimiter may be a comma, semicolon or tab.
+pub fn parse_csv(
+    content: &str,
+    mapping: &ContactImportColumnMapping,
+) -> Result<Vec<ParsedContact>, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let delimiter = detect_delimiter(content.lines().next().unwrap_or_default());
+    let mut records = read_csv_records(content, delimiter)?.into_iter();
+
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+    let columns = CsvColumns::resolve(&header, mapping)?;
+
+    Ok(records
+        .filter(|(_, fields)| fields.iter().any(|field| !field.trim().is_empty()))
+        .map(|(line, fields)| columns.read(line, &header, &fields))
+        .collect())
+}
+
+/// Reads vCard 3.0 and 4.0 entries, including folded lines and grouped properties such
+/// as `item1.TEL`.
+pub fn parse_vcard(content: &str) -> Result<Vec<ParsedContact>, String> {
+    let mut contacts = Vec::new();
+    let mut current: Option<ParsedContact> = None;
+    let mut structured_name = None;
+
+    for (line, text) in unfold_vcard_lines(content.trim_start_matches('\u{feff}')) {
+        let Some((property, value)) = split_vcard_property(&text) else {
+            continue;
+        };
+
+        match (property.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VCARD") => {
+                current = Some(ParsedContact {
+                    line,
+                    ..Default::default()
+                });
+                structured_name = None;
+            }
+            ("BEGIN", Some(contact)) => {
+                return Err(format!(
+                    "vCard starting on line {} is missing END:VCARD",
+                    contact.line
+                ));
+            }
+            ("END", Some(_)) => {
+                let mut contact = current.take().unwrap_or_default();
+                if contact.name.is_none() {
+                    contact.name = structured_name.take();
+                }
+                contacts.push(contact);
+            }
+            ("FN", Some(contact)) => contact.name = non_empty(unescape_vcard(&value)),
+            ("N", Some(_)) => structured_name = name_from_components(&value),
+            ("TEL", Some(contact)) => {
+                let value = unescape_vcard(&value);
+                if !value.trim().is_empty() {
+                    contact.phone_numbers.push(value);
+                }
+            }
+            ("EMAIL", Some(contact)) if contact.email.is_none() => {
+                contact.email = non_empty(unescape_vcard(&value));
+            }
+            ("NOTE", Some(contact)) => contact.notes = non_empty(unescape_vcard(&value)),
+            ("ORG", Some(contact)) => {
+                if let Some(organization) = non_empty(
+                    split_vcard_components(&value)
+                        .into_iter()
+                        .filter(|part| !part.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ) {
+                    contact
+                        .custom_fields
+                        .insert("organization".to_owned(), organization);
+                }
+            }
+            ("TITLE", Some(contact)) => {
+                if let Some(title) = non_empty(unescape_vcard(&value)) {
+                    contact.custom_fields.insert("title".to_owned(), title);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(contact) = current {
+        return Err(format!(
+            "vCard starting on line {} is missing END:VCARD",
+            contact.line
+        ));
+    }
+
+    if contacts.is_empty() && !content.trim().is_empty() {
+        return Err("No vCard entries found in the file".to_owned());
+    }
+
+    Ok(contacts)
+}
+
+struct CsvColumns {
+    name: Vec<usize>,
+    phone_numbers: Vec<usize>,
+    email: Option<usize>,
+    notes: Option<usize>,
+}
+
+impl CsvColumns {
+    fn resolve(header: &[String], mapping: &ContactImportColumnMapping) -> Result<Self, String> {
+        let find = |wanted: &str| {
+            header
+                .iter()
+                .position(|column| column.trim().eq_ignore_ascii_case(wanted.trim()))
+        };
+        let find_any = |candidates: &[&str]| candidates.iter().find_map(|wanted| find(wanted));
+        let require = |wanted: &str| {
+            find(wanted).ok_or_else(|| format!("Column \"{}\" is not in the header row", wanted))
+        };
+
+        let name = match mapping.name.as_deref() {
+            Some(column) => vec![require(column)?],
+            None => match find_any(&NAME_HEADERS) {
+                Some(index) => vec![index],
+                None => [find_any(&FIRST_NAME_HEADERS), find_any(&LAST_NAME_HEADERS)]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            },
+        };
+
+        let phone_numbers = if mapping.phone_numbers.is_empty() {
+            header
+                .iter()
+                .enumerate()
+                .filter(|(_, column)| is_phone_header(column))
+                .map(|(index, _)| index)
+                .collect()
+        } else {
+            mapping
+                .phone_numbers
+                .iter()
+                .map(|column| require(column))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        if phone_numbers.is_empty() {
+            return Err("No phone number column found; name one in the column mapping".to_owned());
+        }
+
+        let email = match mapping.email.as_deref() {
+            Some(column) => Some(require(column)?),
+            None => header
+                .iter()
+                .position(|column| column.to_ascii_lowercase().contains("email")),
+        };
+        let notes = match mapping.notes.as_deref() {
+            Some(column) => Some(require(column)?),
+            None => find_any(&NOTES_HEADERS),
+        };
+
+        Ok(Self {
+            name,
+            phone_numbers,
+            email,
+            notes,
+        })
+    }
+
+    fn read(&self, line: usize, header: &[String], fields: &[String]) -> ParsedContact {
+        let field = |index: usize| fields.get(index).map(|value| value.trim()).unwrap_or("");
+
+        let name = self
+            .name
+            .iter()
+            .map(|index| field(*index))
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let phone_numbers = self
+            .phone_numbers
+            .iter()
+            .flat_map(|index| field(*index).split([';', '|']))
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned)
+            .collect();
+        let custom_fields = header
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.uses(*index))
+            .filter_map(|(index, column)| {
+                let value = field(index);
+                (!column.trim().is_empty() && !value.is_empty())
+                    .then(|| (column.trim().to_owned(), value.to_owned()))
+            })
+            .collect();
+
+        ParsedContact {
+            line,
+            name: non_empty(name),
+            phone_numbers,
+            email: self
+                .email
+                .and_then(|index| non_empty(field(index).to_owned())),
+            notes: self
+                .notes
+                .and_then(|index| non_empty(field(index).to_owned())),
+            custom_fields,
+        }
+    }
+
+    fn uses(&self, index: usize) -> bool {
+        self.name.contains(&index)
+            || self.phone_numbers.contains(&index)
+            || self.email == Some(index)
+            || self.notes == Some(index)
+    }
+}
+
+fn is_phone_header(column: &str) -> bool {
+    let column = column.trim().to_ascii_lowercase();
+
+    ["phone", "mobile", "cell"]
+        .iter()
+        .any(|keyword| column.contains(keyword))
+        || matches!(column.as_str(), "number" | "tel" | "telephone")
+}
+
+fn detect_delimiter(header: &str) -> char {
+    [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|delimiter| header.matches(*delimiter).count())
+        .filter(|delimiter| header.contains(*delimiter))
+        .unwrap_or(',')
+}
+
+/// Splits CSV content into records, keeping the line each record starts on. Records end on
+/// `\n` or `\r\n` outside quotes; quoted fields keep their line breaks, including a lone `\r`.
+fn read_csv_records(content: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            '\n' if in_quotes => {
+                line += 1;
+                field.push('\n');
+            }
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut record)));
+                line += 1;
+                record_line = line;
+            }
+            c if c == delimiter && !in_quotes => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(format!(
+            "Quoted field starting on line {} is never closed",
+            record_line
+        ));
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((record_line, record));
+    }
+
+    Ok(records)
+}
+
+/// Joins folded vCard lines (continuations start with a space or tab).
+fn unfold_vcard_lines(content: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+
+    for (index, text) in content.lines().enumerate() {
+        let text = text.trim_end_matches('\r');
+        match (text.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, previous))) => previous.push_str(continuation),
+            _ => lines.push((index + 1, text.to_owned())),
+        }
+    }
+
+    lines
+}
+
+/// Splits `group.NAME;PARAM=x:value` into the upper-cased property name and the raw value.
+/// Parameters are not needed for import and are dropped.
+fn split_vcard_property(text: &str) -> Option<(String, String)> {
+    let mut in_quotes = false;
+    let colon = text.char_indices().find_map(|(index, c)| {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => return Some(index),
+            _ => {}
+        }
+        None
+    })?;
+
+    let (key, value) = (&text[..colon], &text[colon + 1..]);
+    let name = key.split(';').next().unwrap_or(key);
+    let name = name.rsplit('.').next().unwrap_or(name);
+
+    Some((name.trim().to_ascii_uppercase(), value.to_owned()))
+}
+
+/// Builds a display name from `N:Family;Given;Additional;Prefix;Suffix`.
+fn name_from_components(value: &str) -> Option<String> {
+    let parts = split_vcard_components(value);
+    let part = |index: usize| parts.get(index).map(String::as_str).unwrap_or("");
+
+    non_empty(
+        [part(3), part(1), part(2), part(0), part(4)]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+fn split_vcard_components(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut escaped = false;
+
+    for c in value.chars() {
+        match c {
+            _ if escaped => {
+                current.push('\\');
+                current.push(c);
+                escaped = false;
+            }
+            '\\' => escaped = true,
+            ';' => parts.push(unescape_vcard(&std::mem::take(&mut current))),
+            c => current.push(c),
+        }
+    }
+    parts.push(unescape_vcard(&current));
+
+    parts
+        .into_iter()
+        .map(|part| part.trim().to_owned())
+        .collect()
+}
+
+fn unescape_vcard(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n' | 'N') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use domain::models::contact_import::ContactImportColumnMapping;
+
+    use super::{parse_csv, parse_vcard};
+
+    #[test]
+    fn reads_csv_with_quotes_and_column_mapping() {
+        let content = "\u{feff}Customer;Cell;Work phone;Company;Comment\r\n\
+                       \"Lovelace, Ada\";+44 20 7946 0958;\"+1 555 123 4567|+1 555 765 4321\";Analytical;\"multi\nline\"\r\n\
+                       ;;;;\r\n\
+                       Grace Hopper;+1 555 000 1111;;Navy;\r\n";
+        let mapping = ContactImportColumnMapping {
+            name: Some("customer".to_owned()),
+            notes: Some("Comment".to_owned()),
+            ..Default::default()
+        };
+
+        let contacts = parse_csv(content, &mapping).expect("csv should parse");
+
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(contacts[0].line, 2);
+        assert_eq!(contacts[0].name.as_deref(), Some("Lovelace, Ada"));
+        assert_eq!(
+            contacts[0].phone_numbers,
+            vec!["+44 20 7946 0958", "+1 555 123 4567", "+1 555 765 4321"]
+        );
+        assert_eq!(contacts[0].notes.as_deref(), Some("multi\nline"));
+        assert_eq!(
+            contacts[0].custom_fields,
+            BTreeMap::from([("Company".to_owned(), "Analytical".to_owned())])
+        );
+        assert_eq!(contacts[1].line, 5);
+        assert_eq!(contacts[1].name.as_deref(), Some("Grace Hopper"));
+
+        let missing = ContactImportColumnMapping {
+            email: Some("Email".to_owned()),
+            ..Default::default()
+        };
+        assert!(parse_csv(content, &missing).is_err());
+    }
+
+    #[test]
+    fn keeps_carriage_returns_inside_quoted_fields() {
+        let content = "Name,Phone,Notes\r\n\
+                       Ada,+44 20 7946 0958,\"first\rsecond\r\nthird\"\r\n\
+                       Grace,+1 555 000 1111,\r\n";
+        let mapping = ContactImportColumnMapping {
+            notes: Some("Notes".to_owned()),
+            ..Default::default()
+        };
+
+        let contacts = parse_csv(content, &mapping).expect("csv should parse");
+
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(contacts[0].notes.as_deref(), Some("first\rsecond\r\nthird"));
+        assert_eq!(contacts[1].line, 4);
+        assert_eq!(contacts[1].name.as_deref(), Some("Grace"));
+    }
+
+    #[test]
+    fn reads_vcard_3_and_4_entries() {
+        let content = "BEGIN:VCARD\r\n\
+                       VERSION:3.0\r\n\
+                       N:Lovelace;Ada;;Countess;\r\n\
+                       item1.TEL;TYPE=CELL:+44 20 7946 0958\r\n\
+                       NOTE:Met at the\\, uh\\, \r\n engine demo\r\n\
+                       ORG:Analytical Engines;Research\r\n\
+                       END:VCARD\r\n\
+                       BEGIN:VCARD\r\n\
+                       VERSION:4.0\r\n\
+                       FN:Grace Hopper\r\n\
+                       TEL;VALUE=uri;TYPE=\"voice,cell\":tel:+1-555-000-1111\r\n\
+                       EMAIL:grace@example.com\r\n\
+                       END:VCARD\r\n";
+
+        let contacts = parse_vcard(content).expect("vcard should parse");
+
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(contacts[0].line, 1);
+        assert_eq!(contacts[0].name.as_deref(), Some("Countess Ada Lovelace"));
+        assert_eq!(contacts[0].phone_numbers, vec!["+44 20 7946 0958"]);
+        assert_eq!(
+            contacts[0].notes.as_deref(),
+            Some("Met at the, uh, engine demo")
+        );
+        assert_eq!(
+            contacts[0]
+                .custom_fields
+                .get("organization")
+                .map(String::as_str),
+            Some("Analytical Engines, Research")
+        );
+        assert_eq!(contacts[1].line, 9);
+        assert_eq!(contacts[1].phone_numbers, vec!["tel:+1-555-000-1111"]);
+        assert_eq!(contacts[1].email.as_deref(), Some("grace@example.com"));
+
+        assert!(parse_vcard("BEGIN:VCARD\nFN:Nobody\n").is_err());
+    }
+}
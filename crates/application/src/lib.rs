@@ -1,4 +1,5 @@
 pub mod commands;
+pub mod contact_import;
 pub mod responses;
 pub mod traits;
 pub mod usecases;
@@ -23,6 +23,77 @@ pub struct CreateConversationResult {
     pub id: Uuid,
 }
 
+#[derive(Debug)]
+pub struct CampaignResult {
+    pub campaign: domain::models::campaign::Campaign,
+    pub progress: domain::models::campaign::CampaignProgress,
+}
+
+#[derive(Debug)]
+pub struct CreateContactResult {
+    pub contact: domain::models::contact::Contact,
+}
+
+#[derive(Debug)]
+pub struct CreateMessageTemplateResult {
+    pub template: domain::models::message_template::MessageTemplate,
+}
+
+#[derive(Debug)]
+pub struct CreateAutoReplyRuleResult {
+    pub rule: domain::models::auto_reply_rule::AutoReplyRule,
+}
+
+#[derive(Debug)]
+pub struct MessageTemplatePreview {
+    pub template: domain::models::message_template::MessageTemplate,
+    /// Rendered text, with placeholders that have no value left as written.
+    pub content: String,
+    pub unresolved_variables: Vec<String>,
+    pub segments: domain::models::sms_encoding::SegmentCount,
+}
+
+#[derive(Debug)]
+pub struct MessagePreview {
+    /// Rendered text, with placeholders that have no value left as written.
+    pub content: String,
+    pub unresolved_variables: Vec<String>,
+    pub analysis: domain::models::sms_encoding::SmsAnalysis,
+    /// Segments times the configured segment price, in micros.
+    pub estimated_cost_micros: Option<i64>,
+}
+
+#[derive(Debug)]
+pub struct UsagePeriodTotals {
+    pub period_start: OffsetDateTime,
+    pub totals: domain::models::usage::UsageTotals,
+}
+
+#[derive(Debug)]
+pub struct UsageLimitStatus {
+    pub limit: domain::models::usage::UsageLimit,
+    /// Spend so far in the current period, in micros.
+    pub spent_micros: i64,
+    pub resets_at: OffsetDateTime,
+}
+
+#[derive(Debug)]
+pub struct UsageReport {
+    pub period: domain::models::usage::UsagePeriod,
+    /// Usage per period and phone number, oldest period first.
+    pub rollups: Vec<domain::models::usage::UsageRollup>,
+    /// `rollups` summed over every phone number.
+    pub totals: Vec<UsagePeriodTotals>,
+    pub limits: Vec<UsageLimitStatus>,
+}
+
+#[derive(Debug)]
+pub struct OrganizationMembers {
+    pub members: Vec<domain::models::organization::OrganizationMember>,
+    /// Invitations that are neither accepted nor expired, newest first.
+    pub invitations: Vec<domain::models::organization::OrganizationInvitation>,
+}
+
 #[derive(Debug)]
 pub struct CreateMessageResult {
     pub message: domain::models::message::Message,
@@ -38,6 +109,9 @@ pub struct DashboardAnalyticsResult {
     pub total_conversations: u64,
     pub total_messages: u64,
     pub total_phone_numbers: u64,
+    /// Conversations with inbound messages the member has not read.
+    pub unread_conversations: u64,
+    pub unread_messages: u64,
 }
 
 #[derive(Debug)]
@@ -45,3 +119,9 @@ pub struct DashboardHomeResult {
     pub phone_numbers: Vec<domain::models::phone_number::PhoneNumber>,
     pub analytics: DashboardAnalyticsResult,
 }
+
+#[derive(Debug)]
+pub struct MessageAttachmentContentResult {
+    pub attachment: domain::models::message_attachment::MessageAttachment,
+    pub bytes: Vec<u8>,
+}
@@ -0,0 +1,412 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::organization::{Organization, OrganizationMember},
+    repositories::{
+        RepositoryError, organization_invitation_repository::OrganizationInvitationRepository,
+        organization_repository::OrganizationRepository, user_repository::UserRepository,
+    },
+    traits::token_service::{PasetoClaimPurpose, TokenService},
+};
+
+/// Adds the signed-in user to the organization named by an invitation link. The user's
+/// email must be the invited one, and each invitation can be used once.
+#[derive(bon::Builder)]
+pub struct AcceptOrganizationInvitationUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+    organization_invitation_repository: Arc<dyn OrganizationInvitationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    token_service: Arc<dyn TokenService>,
+}
+
+impl AcceptOrganizationInvitationUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        token: String,
+    ) -> Result<Organization, UsecaseError> {
+        let claims = self
+            .token_service
+            .validate_token(token, PasetoClaimPurpose::OrganizationInvitation)
+            .map_err(|_| UsecaseError::InvalidInvitation)?;
+
+        let invitation = match self
+            .organization_invitation_repository
+            .find_by_id(&claims.id)
+            .await
+        {
+            Ok(invitation) => invitation,
+            Err(RepositoryError::NotFound) => return Err(UsecaseError::InvalidInvitation),
+            Err(err) => return Err(err.into()),
+        };
+        let user = self.user_repository.find_by_id(&user_id).await?;
+        let now = OffsetDateTime::now_utc();
+        if !invitation.is_pending(now)
+            || !invitation.email.eq_ignore_ascii_case(&claims.email)
+            || !invitation.email.eq_ignore_ascii_case(&user.email)
+        {
+            return Err(UsecaseError::InvalidInvitation);
+        }
+
+        let member = OrganizationMember::builder()
+            .organization_id(invitation.organization_id)
+            .user_id(user.id)
+            .role(invitation.role)
+            .created_at(now)
+            .build();
+        match self
+            .organization_invitation_repository
+            .accept_invitation(&invitation.id, &member, now)
+            .await
+        {
+            Ok(()) => {}
+            Err(RepositoryError::NotFound) => return Err(UsecaseError::InvalidInvitation),
+            Err(err) => return Err(err.into()),
+        }
+
+        let organization = self
+            .organization_repository
+            .find_by_id(&invitation.organization_id)
+            .await?;
+
+        Ok(organization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            conversation::ConversationRouting,
+            organization::{
+                Organization, OrganizationInvitation, OrganizationMember, OrganizationRole,
+            },
+            user::User,
+        },
+        repositories::{
+            RepositoryError, organization_invitation_repository::OrganizationInvitationRepository,
+            organization_repository::OrganizationRepository, user_repository::UserRepository,
+        },
+        traits::token_service::{
+            PasetoClaimPurpose, PasetoClaims, TokenService, TokenServiceError,
+        },
+    };
+    use time::{OffsetDateTime, UtcDateTime};
+
+    use crate::usecases::UsecaseError;
+
+    use super::AcceptOrganizationInvitationUsecase;
+
+    const TOKEN: &str = "invitation-token";
+
+    struct FakeTokenService {
+        claims: PasetoClaims,
+    }
+
+    impl TokenService for FakeTokenService {
+        fn generate_token(
+            &self,
+            _claims: PasetoClaims,
+            _expiration: Duration,
+        ) -> Result<(String, UtcDateTime), TokenServiceError> {
+            Err(TokenServiceError::TokenGenerationFailed)
+        }
+
+        fn validate_token(
+            &self,
+            token: String,
+            purpose: PasetoClaimPurpose,
+        ) -> Result<PasetoClaims, TokenServiceError> {
+            if token == TOKEN && purpose == self.claims.purpose {
+                Ok(self.claims.clone())
+            } else {
+                Err(TokenServiceError::TokenInvalid)
+            }
+        }
+    }
+
+    struct FakeOrganizationRepository {
+        organization: Organization,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            if *id == self.organization.id {
+                Ok(self.organization.clone())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeOrganizationInvitationRepository {
+        invitation: Mutex<OrganizationInvitation>,
+        members: Mutex<Vec<OrganizationMember>>,
+    }
+
+    #[async_trait]
+    impl OrganizationInvitationRepository for FakeOrganizationInvitationRepository {
+        async fn create_invitation(
+            &self,
+            _invitation: &OrganizationInvitation,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<OrganizationInvitation, RepositoryError> {
+            let invitation = self.invitation.lock().expect("lock");
+            if invitation.id == *id {
+                Ok(invitation.clone())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        }
+
+        async fn list_pending(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<OrganizationInvitation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn accept_invitation(
+            &self,
+            id: &uuid::Uuid,
+            member: &OrganizationMember,
+            now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            let mut invitation = self.invitation.lock().expect("lock");
+            if invitation.id != *id || invitation.accepted_at.is_some() {
+                return Err(RepositoryError::NotFound);
+            }
+            invitation.accepted_at = Some(now);
+            self.members.lock().expect("lock").push(member.clone());
+            Ok(())
+        }
+
+        async fn delete_invitation(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeUserRepository {
+        users: Vec<(uuid::Uuid, &'static str)>,
+    }
+
+    #[async_trait]
+    impl UserRepository for FakeUserRepository {
+        async fn create_user(&self, _user: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn create_user_with_organization(
+            &self,
+            _user: &User,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<User, RepositoryError> {
+            self.users
+                .iter()
+                .find(|(user_id, _)| user_id == id)
+                .map(|(user_id, email)| user(*user_id, email))
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn find_by_email(&self, _email: &str) -> Result<User, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list(&self, _page: u64, _limit: u64) -> Result<Vec<User>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_user(&self, _new_data: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete_user(&self, _id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        invited: uuid::Uuid,
+        stranger: uuid::Uuid,
+        invitations: Arc<FakeOrganizationInvitationRepository>,
+        usecase: AcceptOrganizationInvitationUsecase,
+    }
+
+    fn user(id: uuid::Uuid, email: &str) -> User {
+        let now = OffsetDateTime::now_utc();
+        User::builder()
+            .id(id)
+            .email(email.to_owned())
+            .hash(String::new())
+            .salt(String::new())
+            .email_verified(true)
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    fn fixture() -> Fixture {
+        let now = OffsetDateTime::now_utc();
+        let organization = Organization::builder()
+            .id(uuid::Uuid::now_v7())
+            .name("Acme".to_owned())
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let invitation = OrganizationInvitation::builder()
+            .id(uuid::Uuid::now_v7())
+            .organization_id(organization.id)
+            .email("ada@example.com".to_owned())
+            .role(OrganizationRole::Agent)
+            .invited_by(uuid::Uuid::now_v7())
+            .expires_at(now + time::Duration::days(7))
+            .created_at(now)
+            .build();
+        let invited = uuid::Uuid::now_v7();
+        let stranger = uuid::Uuid::now_v7();
+        let claims = PasetoClaims::new(
+            invitation.id,
+            invitation.email.clone(),
+            Duration::from_secs(60),
+            PasetoClaimPurpose::OrganizationInvitation,
+        );
+        let invitations = Arc::new(FakeOrganizationInvitationRepository {
+            invitation: Mutex::new(invitation),
+            members: Mutex::new(Vec::new()),
+        });
+
+        Fixture {
+            invited,
+            stranger,
+            invitations: invitations.clone(),
+            usecase: AcceptOrganizationInvitationUsecase::builder()
+                .organization_repository(Arc::new(FakeOrganizationRepository { organization }))
+                .organization_invitation_repository(invitations)
+                .user_repository(Arc::new(FakeUserRepository {
+                    users: vec![
+                        (invited, "Ada@Example.com"),
+                        (stranger, "mallory@example.com"),
+                    ],
+                }))
+                .token_service(Arc::new(FakeTokenService { claims }))
+                .build(),
+        }
+    }
+
+    #[tokio::test]
+    async fn invited_user_joins_once() {
+        let fixture = fixture();
+
+        let organization = fixture
+            .usecase
+            .execute(fixture.invited, TOKEN.to_owned())
+            .await
+            .expect("invitation should be accepted");
+        assert_eq!(organization.name, "Acme");
+        let members = fixture.invitations.members.lock().expect("lock").clone();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].user_id, fixture.invited);
+        assert_eq!(members[0].role, OrganizationRole::Agent);
+
+        let again = fixture
+            .usecase
+            .execute(fixture.invited, TOKEN.to_owned())
+            .await;
+        assert!(matches!(again, Err(UsecaseError::InvalidInvitation)));
+    }
+
+    #[tokio::test]
+    async fn rejects_users_with_another_email() {
+        let fixture = fixture();
+
+        let result = fixture
+            .usecase
+            .execute(fixture.stranger, TOKEN.to_owned())
+            .await;
+
+        assert!(matches!(result, Err(UsecaseError::InvalidInvitation)));
+        assert!(fixture.invitations.members.lock().expect("lock").is_empty());
+    }
+}
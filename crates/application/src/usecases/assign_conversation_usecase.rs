@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::AssignConversationCommand,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::{conversation::Conversation, organization::Permission},
+    repositories::{
+        RepositoryError, conversation_repository::ConversationRepository,
+        organization_repository::OrganizationRepository,
+    },
+};
+
+/// Assigns, reassigns or unassigns a conversation. Only members who can send messages take
+/// conversations, so read-only members are never assignees.
+#[derive(bon::Builder)]
+pub struct AssignConversationUsecase {
+    conversation_repository: Arc<dyn ConversationRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl AssignConversationUsecase {
+    pub async fn execute(
+        &self,
+        cmd: AssignConversationCommand,
+    ) -> Result<Conversation, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::AssignConversations,
+        )
+        .await?;
+
+        let conversation = self
+            .conversation_repository
+            .find_by_id(&cmd.organization_id, &cmd.conversation_id)
+            .await?;
+
+        if let Some(assigned_user_id) = cmd.assigned_user_id {
+            let assignee = match self
+                .organization_repository
+                .find_member(&cmd.organization_id, &assigned_user_id)
+                .await
+            {
+                Ok(assignee) => assignee,
+                Err(RepositoryError::NotFound) => {
+                    return Err(garde::Error::new(
+                        "Conversations can only be assigned to members of the organization",
+                    )
+                    .into());
+                }
+                Err(err) => return Err(err.into()),
+            };
+            if !assignee.role.can(Permission::SendMessages) {
+                return Err(garde::Error::new(
+                    "Read-only members cannot be assigned conversations",
+                )
+                .into());
+            }
+        }
+
+        if conversation.assigned_user_id == cmd.assigned_user_id {
+            return Ok(conversation);
+        }
+
+        let conversation = self
+            .conversation_repository
+            .assign(
+                &conversation,
+                cmd.assigned_user_id,
+                OffsetDateTime::now_utc(),
+            )
+            .await?;
+
+        Ok(conversation)
+    }
+}
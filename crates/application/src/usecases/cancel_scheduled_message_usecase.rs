@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::usecases::{
+    UsecaseError, authorize_member, reschedule_message_usecase::find_scheduled_message,
+};
+use domain::{
+    models::organization::Permission,
+    repositories::{
+        message_repository::MessageRepository, organization_repository::OrganizationRepository,
+    },
+    traits::media_storage::MediaStorage,
+};
+
+/// Deletes a scheduled message before it is sent. Its delivery job and attachment rows go
+/// with it; the stored attachment files are removed afterwards.
+#[derive(bon::Builder)]
+pub struct CancelScheduledMessageUsecase {
+    message_repository: Arc<dyn MessageRepository>,
+    media_storage: Arc<dyn MediaStorage>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl CancelScheduledMessageUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
+        conversation_id: uuid::Uuid,
+        message_id: uuid::Uuid,
+    ) -> Result<(), UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::SendMessages,
+        )
+        .await?;
+
+        let message = find_scheduled_message(
+            self.message_repository.as_ref(),
+            organization_id,
+            conversation_id,
+            message_id,
+        )
+        .await?;
+
+        if !self
+            .message_repository
+            .delete_scheduled_message(&organization_id, &message.id)
+            .await?
+        {
+            return Err(UsecaseError::MessageNotScheduled);
+        }
+
+        for attachment in &message.attachments {
+            let _ = self.media_storage.delete(&attachment.storage_key).await;
+        }
+
+        Ok(())
+    }
+}
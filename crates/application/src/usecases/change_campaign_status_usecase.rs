@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::ChangeCampaignStatusCommand,
+    responses::CampaignResult,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::{campaign::CampaignStatus, organization::Permission},
+    repositories::{
+        campaign_repository::CampaignRepository, organization_repository::OrganizationRepository,
+    },
+};
+
+/// Pauses, resumes or cancels a campaign. Messages already created keep going through
+/// the outbound queue; only recipients that were not messaged yet are held back.
+#[derive(bon::Builder)]
+pub struct ChangeCampaignStatusUsecase {
+    campaign_repository: Arc<dyn CampaignRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl ChangeCampaignStatusUsecase {
+    pub async fn execute(
+        &self,
+        cmd: ChangeCampaignStatusCommand,
+    ) -> Result<CampaignResult, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::SendMessages,
+        )
+        .await?;
+
+        let mut campaign = self
+            .campaign_repository
+            .find_by_id(&cmd.organization_id, &cmd.campaign_id)
+            .await?;
+
+        let conflict = UsecaseError::CampaignStatusConflict {
+            from: campaign.status,
+            to: cmd.status,
+        };
+        if cmd.status == CampaignStatus::Completed || !campaign.status.can_transition_to(cmd.status)
+        {
+            return Err(conflict);
+        }
+
+        let now = OffsetDateTime::now_utc();
+        if !self
+            .campaign_repository
+            .change_status(&campaign.id, campaign.status, cmd.status, now)
+            .await?
+        {
+            // The worker completed it, or another request got there first.
+            return Err(conflict);
+        }
+
+        campaign.status = cmd.status;
+        campaign.updated_at = now;
+        if cmd.status.is_terminal() {
+            campaign.completed_at = Some(now);
+        }
+        let progress = self.campaign_repository.progress(&campaign.id).await?;
+
+        Ok(CampaignResult { campaign, progress })
+    }
+}
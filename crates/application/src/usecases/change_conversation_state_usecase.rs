@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::ChangeConversationStateCommand,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::{
+        conversation::{Conversation, ConversationStateChange},
+        organization::Permission,
+    },
+    repositories::{
+        conversation_repository::ConversationRepository,
+        organization_repository::OrganizationRepository,
+    },
+};
+
+/// Archives, pins or snoozes a conversation, or undoes it. Unlike deleting, none of these
+/// touch the messages.
+#[derive(bon::Builder)]
+pub struct ChangeConversationStateUsecase {
+    conversation_repository: Arc<dyn ConversationRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl ChangeConversationStateUsecase {
+    pub async fn execute(
+        &self,
+        cmd: ChangeConversationStateCommand,
+    ) -> Result<Conversation, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::TriageConversations,
+        )
+        .await?;
+
+        let now = OffsetDateTime::now_utc();
+        if let ConversationStateChange::Snooze(until) = cmd.change
+            && until <= now
+        {
+            return Err(garde::Error::new("Snooze time must be in the future").into());
+        }
+
+        let conversation = self
+            .conversation_repository
+            .find_by_id(&cmd.organization_id, &cmd.conversation_id)
+            .await?;
+
+        let conversation = self
+            .conversation_repository
+            .change_state(&conversation, cmd.change, now)
+            .await?;
+
+        Ok(conversation)
+    }
+}
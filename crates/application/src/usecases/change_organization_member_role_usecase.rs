@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use crate::{
+    commands::ChangeOrganizationMemberRoleCommand,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::organization::{OrganizationMember, OrganizationRole, Permission},
+    repositories::organization_repository::OrganizationRepository,
+};
+
+/// Changes a member's role. Admins manage the admin, agent and read-only roles; granting
+/// or taking away the owner role takes an owner, and the last owner keeps it.
+#[derive(bon::Builder)]
+pub struct ChangeOrganizationMemberRoleUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl ChangeOrganizationMemberRoleUsecase {
+    pub async fn execute(
+        &self,
+        cmd: ChangeOrganizationMemberRoleCommand,
+    ) -> Result<OrganizationMember, UsecaseError> {
+        let members = self
+            .organization_repository
+            .list_members(&cmd.organization_id)
+            .await?;
+        let Some(member) = members
+            .iter()
+            .find(|member| member.user_id == cmd.member_user_id)
+        else {
+            return Err(UsecaseError::EntityNotFound);
+        };
+
+        let permission =
+            if member.role == OrganizationRole::Owner || cmd.role == OrganizationRole::Owner {
+                Permission::ManageOwners
+            } else {
+                Permission::ManageMembers
+            };
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            permission,
+        )
+        .await?;
+
+        let owners = members
+            .iter()
+            .filter(|member| member.role == OrganizationRole::Owner)
+            .count();
+        if member.role == OrganizationRole::Owner
+            && cmd.role != OrganizationRole::Owner
+            && owners == 1
+        {
+            return Err(garde::Error::new("The organization needs at least one owner").into());
+        }
+
+        self.organization_repository
+            .update_member_role(&cmd.organization_id, &cmd.member_user_id, cmd.role)
+            .await?;
+
+        Ok(OrganizationMember {
+            role: cmd.role,
+            ..member.clone()
+        })
+    }
+}
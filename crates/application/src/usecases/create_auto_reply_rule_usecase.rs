@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{
+    commands::{AutoReplyRuleDetails, CreateAutoReplyRuleCommand},
+    responses::CreateAutoReplyRuleResult,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::{
+        auto_reply_rule::{AutoReplyMatchType, AutoReplyRule, compile_pattern},
+        message_template::template_variables,
+        organization::Permission,
+    },
+    repositories::{
+        auto_reply_rule_repository::AutoReplyRuleRepository,
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct CreateAutoReplyRuleUsecase {
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl CreateAutoReplyRuleUsecase {
+    pub async fn execute(
+        &self,
+        cmd: CreateAutoReplyRuleCommand,
+    ) -> Result<CreateAutoReplyRuleResult, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::ManagePhoneNumbers,
+        )
+        .await?;
+
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&cmd.organization_id, &cmd.phone_number_id)
+            .await?;
+        let now = OffsetDateTime::now_utc();
+        let rule = build_auto_reply_rule(
+            cmd.details,
+            uuid::Uuid::now_v7(),
+            phone_number.organization_id,
+            cmd.user_id,
+            phone_number.id,
+            now,
+            now,
+        )?;
+
+        self.auto_reply_rule_repository.create_rule(&rule).await?;
+
+        Ok(CreateAutoReplyRuleResult { rule })
+    }
+}
+
+/// Validates the submitted details and turns them into a rule. Regex patterns must compile
+/// and reply placeholders need a name; a blank tag is stored as missing.
+pub(crate) fn build_auto_reply_rule(
+    details: AutoReplyRuleDetails,
+    id: uuid::Uuid,
+    organization_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+    phone_number_id: uuid::Uuid,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+) -> Result<AutoReplyRule, UsecaseError> {
+    let details = AutoReplyRuleDetails {
+        pattern: details.pattern.trim().to_owned(),
+        reply_template: details.reply_template.trim().to_owned(),
+        tag: details
+            .tag
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty()),
+        ..details
+    };
+    details.validate()?;
+
+    if details.match_type == AutoReplyMatchType::Regex
+        && let Err(err) = compile_pattern(&details.pattern)
+    {
+        return Err(garde::Error::new(format!("Pattern is not a valid regex: {}", err)).into());
+    }
+    if template_variables(&details.reply_template)
+        .iter()
+        .any(|name| name.is_empty())
+    {
+        return Err(garde::Error::new("Placeholders need a name, e.g. {{first_name}}").into());
+    }
+
+    Ok(AutoReplyRule::builder()
+        .id(id)
+        .organization_id(organization_id)
+        .user_id(user_id)
+        .phone_number_id(phone_number_id)
+        .match_type(details.match_type)
+        .pattern(details.pattern)
+        .reply_template(details.reply_template)
+        .maybe_tag(details.tag)
+        .cooldown_seconds(details.cooldown_seconds)
+        .enabled(details.enabled)
+        .created_at(created_at)
+        .updated_at(updated_at)
+        .build())
+}
@@ -0,0 +1,656 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::CreateCampaignCommand,
+    responses::CampaignResult,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::{
+        campaign::{
+            Campaign, CampaignProgress, CampaignRecipient, CampaignRecipientStatus, CampaignStatus,
+        },
+        contact::{Contact, normalize_phone_number},
+        message_template::{contact_template_variables, render_template},
+        organization::Permission,
+    },
+    repositories::{
+        campaign_repository::CampaignRepository, contact_repository::ContactRepository,
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+pub const DEFAULT_CAMPAIGN_MESSAGES_PER_SECOND: i32 = 1;
+pub const MAX_CAMPAIGN_MESSAGES_PER_SECOND: i32 = 50;
+pub const MAX_CAMPAIGN_RECIPIENTS: usize = 10_000;
+
+/// Resolves the audience of a new campaign and renders the body for every recipient up
+/// front. Recipients whose placeholders cannot be filled in are stored as skipped; the
+/// rest are messaged by `ProcessCampaignsUsecase`.
+#[derive(bon::Builder)]
+pub struct CreateCampaignUsecase {
+    campaign_repository: Arc<dyn CampaignRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl CreateCampaignUsecase {
+    pub async fn execute(
+        &self,
+        cmd: CreateCampaignCommand,
+    ) -> Result<CampaignResult, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::SendMessages,
+        )
+        .await?;
+
+        let name = cmd.name.trim();
+        if name.is_empty() || name.chars().count() > 120 {
+            return Err(garde::Error::new("Campaign name must be 1-120 characters").into());
+        }
+        let body = cmd.body.trim();
+        if body.is_empty() {
+            return Err(garde::Error::new("Campaign message is required").into());
+        }
+        let messages_per_second = cmd
+            .messages_per_second
+            .unwrap_or(DEFAULT_CAMPAIGN_MESSAGES_PER_SECOND);
+        if !(1..=MAX_CAMPAIGN_MESSAGES_PER_SECOND).contains(&messages_per_second) {
+            return Err(garde::Error::new(format!(
+                "Messages per second must be between 1 and {}",
+                MAX_CAMPAIGN_MESSAGES_PER_SECOND
+            ))
+            .into());
+        }
+        let tag = cmd
+            .tag
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty());
+
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&cmd.organization_id, &cmd.phone_number_id)
+            .await?;
+
+        let audience = self
+            .resolve_audience(
+                phone_number.organization_id,
+                tag.as_deref(),
+                &cmd.contact_ids,
+                &cmd.phone_numbers,
+            )
+            .await?;
+        if audience.is_empty() {
+            return Err(garde::Error::new("The campaign has no recipients").into());
+        }
+        if audience.len() > MAX_CAMPAIGN_RECIPIENTS {
+            return Err(garde::Error::new(format!(
+                "A campaign can have at most {} recipients",
+                MAX_CAMPAIGN_RECIPIENTS
+            ))
+            .into());
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let campaign_id = uuid::Uuid::now_v7();
+        let recipients = audience
+            .into_iter()
+            .map(|(recipient_phone_number, contact)| {
+                let variables =
+                    contact_template_variables(contact.as_ref(), &recipient_phone_number);
+                let (status, content, reason) = match render_template(body, &variables) {
+                    Ok(content) => (CampaignRecipientStatus::Pending, content, None),
+                    Err(missing) => (
+                        CampaignRecipientStatus::Skipped,
+                        String::new(),
+                        Some(format!(
+                            "No value for {}",
+                            missing
+                                .iter()
+                                .map(|name| format!("{{{{{}}}}}", name))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )),
+                    ),
+                };
+
+                CampaignRecipient::builder()
+                    .id(uuid::Uuid::now_v7())
+                    .campaign_id(campaign_id)
+                    .user_id(cmd.user_id)
+                    .recipient_phone_number(recipient_phone_number)
+                    .maybe_contact_id(contact.map(|contact| contact.id))
+                    .status(status)
+                    .content(content)
+                    .maybe_reason(reason)
+                    .created_at(now)
+                    .updated_at(now)
+                    .build()
+            })
+            .collect::<Vec<_>>();
+
+        // Nothing left to send when every recipient was skipped.
+        let all_skipped = recipients
+            .iter()
+            .all(|recipient| recipient.status == CampaignRecipientStatus::Skipped);
+        let campaign = Campaign::builder()
+            .id(campaign_id)
+            .user_id(cmd.user_id)
+            .organization_id(phone_number.organization_id)
+            .phone_number_id(phone_number.id)
+            .name(name.to_owned())
+            .body(body.to_owned())
+            .maybe_tag(tag)
+            .messages_per_second(messages_per_second)
+            .status(if all_skipped {
+                CampaignStatus::Completed
+            } else {
+                CampaignStatus::Running
+            })
+            .next_batch_at(now)
+            .maybe_completed_at(all_skipped.then_some(now))
+            .created_at(now)
+            .updated_at(now)
+            .build();
+
+        self.campaign_repository
+            .create_campaign(&campaign, &recipients)
+            .await?;
+
+        let skipped = recipients
+            .iter()
+            .filter(|recipient| recipient.status == CampaignRecipientStatus::Skipped)
+            .count() as u64;
+        let progress = CampaignProgress {
+            total: recipients.len() as u64,
+            pending: recipients.len() as u64 - skipped,
+            skipped,
+            ..CampaignProgress::default()
+        };
+
+        Ok(CampaignResult { campaign, progress })
+    }
+
+    /// Recipient numbers in the order they were added, each with the contact it belongs to.
+    /// Contacts are addressed on their first number.
+    async fn resolve_audience(
+        &self,
+        organization_id: uuid::Uuid,
+        tag: Option<&str>,
+        contact_ids: &[uuid::Uuid],
+        phone_numbers: &[String],
+    ) -> Result<Vec<(String, Option<Contact>)>, UsecaseError> {
+        let mut audience = Vec::<(String, Option<Contact>)>::new();
+        let mut seen = HashSet::<String>::new();
+        let mut push = |phone_number: String, contact: Option<Contact>| {
+            if seen.insert(phone_number.clone()) {
+                audience.push((phone_number, contact));
+            }
+        };
+
+        if tag.is_some() || !contact_ids.is_empty() {
+            let contacts = self
+                .contact_repository
+                .list_by_organization_id(&organization_id)
+                .await?;
+            for contact_id in contact_ids {
+                if !contacts.iter().any(|contact| contact.id == *contact_id) {
+                    return Err(UsecaseError::EntityNotFound);
+                }
+            }
+
+            for contact in contacts {
+                let selected = contact_ids.contains(&contact.id)
+                    || tag.is_some_and(|tag| contact.tags.iter().any(|t| t == tag));
+                if !selected {
+                    continue;
+                }
+                if let Some(phone_number) = contact.phone_numbers.first().cloned() {
+                    push(phone_number, Some(contact));
+                }
+            }
+        }
+
+        let mut normalized = Vec::with_capacity(phone_numbers.len());
+        for phone_number in phone_numbers {
+            let Some(number) = normalize_phone_number(phone_number, None) else {
+                return Err(garde::Error::new(format!(
+                    "{} is not a phone number in E.164 format, e.g. +15551234567",
+                    phone_number.trim()
+                ))
+                .into());
+            };
+            normalized.push(number);
+        }
+        let mut contacts_by_number = HashMap::<String, Contact>::new();
+        for contact in self
+            .contact_repository
+            .find_by_phone_numbers(&organization_id, &normalized)
+            .await?
+        {
+            for number in &contact.phone_numbers {
+                contacts_by_number.insert(number.to_owned(), contact.clone());
+            }
+        }
+        for number in normalized {
+            let contact = contacts_by_number.get(&number).cloned();
+            push(number, contact);
+        }
+
+        Ok(audience)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            campaign::{
+                Campaign, CampaignProgress, CampaignRecipient, CampaignRecipientStatus,
+                CampaignStatus,
+            },
+            contact::Contact,
+            conversation::ConversationRouting,
+            organization::{Organization, OrganizationMember, OrganizationRole},
+            phone_number::PhoneNumber,
+        },
+        repositories::{
+            RepositoryError, campaign_repository::CampaignRepository,
+            contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+            phone_number_repository::PhoneNumberRepository,
+        },
+    };
+    use time::OffsetDateTime;
+
+    use crate::commands::CreateCampaignCommand;
+
+    use super::CreateCampaignUsecase;
+
+    #[derive(Default)]
+    struct FakeCampaignRepository {
+        created: Mutex<Vec<(Campaign, Vec<CampaignRecipient>)>>,
+    }
+
+    #[async_trait]
+    impl CampaignRepository for FakeCampaignRepository {
+        async fn create_campaign(
+            &self,
+            campaign: &Campaign,
+            recipients: &[CampaignRecipient],
+        ) -> Result<(), RepositoryError> {
+            self.created
+                .lock()
+                .expect("lock")
+                .push((campaign.clone(), recipients.to_vec()));
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Campaign, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Campaign>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn change_status(
+            &self,
+            _id: &uuid::Uuid,
+            _from: CampaignStatus,
+            _to: CampaignStatus,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn pause(
+            &self,
+            _id: &uuid::Uuid,
+            _reason: &str,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn claim_due(
+            &self,
+            _now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            _limit: usize,
+        ) -> Result<Vec<Campaign>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn schedule_next_batch(
+            &self,
+            _id: &uuid::Uuid,
+            _next_batch_at: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_pending_recipients(
+            &self,
+            _campaign_id: &uuid::Uuid,
+            _limit: usize,
+        ) -> Result<Vec<CampaignRecipient>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_recipient(
+            &self,
+            _recipient: &CampaignRecipient,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn progress(
+            &self,
+            _campaign_id: &uuid::Uuid,
+        ) -> Result<CampaignProgress, RepositoryError> {
+            Ok(CampaignProgress::default())
+        }
+    }
+
+    struct FakeContactRepository {
+        contacts: Vec<Contact>,
+    }
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self.contacts.clone())
+        }
+
+        async fn find_by_phone_numbers(
+            &self,
+            _organization_id: &uuid::Uuid,
+            phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self
+                .contacts
+                .iter()
+                .filter(|contact| {
+                    contact
+                        .phone_numbers
+                        .iter()
+                        .any(|number| phone_numbers.contains(number))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakePhoneNumberRepository {
+        phone_number: PhoneNumber,
+    }
+
+    #[async_trait]
+    impl PhoneNumberRepository for FakePhoneNumberRepository {
+        async fn create_phone_number(
+            &self,
+            _phone_number: &PhoneNumber,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<PhoneNumber, RepositoryError> {
+            Ok(self.phone_number.clone())
+        }
+
+        async fn find_by_phone(&self, _phone: &str) -> Result<PhoneNumber, RepositoryError> {
+            Ok(self.phone_number.clone())
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<PhoneNumber>, RepositoryError> {
+            Ok(vec![self.phone_number.clone()])
+        }
+
+        async fn delete_phone_number(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeOrganizationRepository {
+        role: Option<OrganizationRole>,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            let role = self.role.ok_or(RepositoryError::NotFound)?;
+            Ok(OrganizationMember::builder()
+                .organization_id(*organization_id)
+                .user_id(*user_id)
+                .role(role)
+                .created_at(OffsetDateTime::now_utc())
+                .build())
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn contact(user_id: uuid::Uuid, name: &str, phone_number: &str, tags: &[&str]) -> Contact {
+        let now = OffsetDateTime::now_utc();
+        Contact::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(user_id)
+            .name(name.to_owned())
+            .phone_numbers(vec![phone_number.to_owned()])
+            .tags(tags.iter().map(|tag| (*tag).to_owned()).collect())
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn renders_the_body_for_every_recipient_of_the_audience() {
+        let user_id = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        let phone_number = PhoneNumber::builder()
+            .id(uuid::Uuid::now_v7())
+            .organization_id(user_id)
+            .user_id(user_id)
+            .name("Primary".to_owned())
+            .phone("+13125550100".to_owned())
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let campaign_repository = Arc::new(FakeCampaignRepository::default());
+        let usecase = CreateCampaignUsecase::builder()
+            .campaign_repository(campaign_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository {
+                contacts: vec![
+                    contact(user_id, "Ada Lovelace", "+14155550001", &["vip"]),
+                    contact(user_id, "Charles Babbage", "+14155550002", &[]),
+                    contact(user_id, "Grace Hopper", "+14155550003", &["vip", "navy"]),
+                ],
+            }))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: phone_number.clone(),
+            }))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let result = usecase
+            .execute(CreateCampaignCommand {
+                user_id,
+                organization_id: user_id,
+                phone_number_id: phone_number.id,
+                name: " Launch ".to_owned(),
+                body: "Hi {{first_name}}, we launched!".to_owned(),
+                tag: Some(" VIP ".to_owned()),
+                contact_ids: Vec::new(),
+                phone_numbers: vec![
+                    "+1 415 555 0002".to_owned(),
+                    "+14155550003".to_owned(),
+                    "+14155550009".to_owned(),
+                ],
+                messages_per_second: Some(5),
+            })
+            .await
+            .expect("campaign should be created");
+
+        assert_eq!(result.campaign.name, "Launch");
+        assert_eq!(result.campaign.tag.as_deref(), Some("vip"));
+        assert_eq!(result.campaign.status, CampaignStatus::Running);
+        assert_eq!(
+            (
+                result.progress.total,
+                result.progress.pending,
+                result.progress.skipped
+            ),
+            (4, 3, 1)
+        );
+
+        let created = campaign_repository.created.lock().expect("lock");
+        let recipients = &created[0].1;
+        assert_eq!(
+            recipients
+                .iter()
+                .map(|recipient| (
+                    recipient.recipient_phone_number.as_str(),
+                    recipient.status,
+                    recipient.content.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "+14155550001",
+                    CampaignRecipientStatus::Pending,
+                    "Hi Ada, we launched!"
+                ),
+                (
+                    "+14155550003",
+                    CampaignRecipientStatus::Pending,
+                    "Hi Grace, we launched!"
+                ),
+                (
+                    "+14155550002",
+                    CampaignRecipientStatus::Pending,
+                    "Hi Charles, we launched!"
+                ),
+                ("+14155550009", CampaignRecipientStatus::Skipped, ""),
+            ]
+        );
+        assert_eq!(
+            recipients[3].reason.as_deref(),
+            Some("No value for {{first_name}}")
+        );
+    }
+}
@@ -0,0 +1,350 @@
+use std::sync::Arc;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{
+    commands::{ContactDetails, CreateContactCommand},
+    responses::CreateContactResult,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::{contact::Contact, organization::Permission},
+    repositories::{
+        contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct CreateContactUsecase {
+    contact_repository: Arc<dyn ContactRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl CreateContactUsecase {
+    pub async fn execute(
+        &self,
+        cmd: CreateContactCommand,
+    ) -> Result<CreateContactResult, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::ManageContacts,
+        )
+        .await?;
+
+        let now = OffsetDateTime::now_utc();
+        let contact = build_contact(
+            cmd.details,
+            uuid::Uuid::now_v7(),
+            cmd.user_id,
+            cmd.organization_id,
+            now,
+            now,
+        )?;
+
+        self.contact_repository.create_contact(&contact).await?;
+
+        Ok(CreateContactResult { contact })
+    }
+}
+
+/// Validates the submitted details and turns them into a contact, trimming blank optional
+/// fields so they are stored as missing rather than empty.
+pub(crate) fn build_contact(
+    details: ContactDetails,
+    id: uuid::Uuid,
+    user_id: uuid::Uuid,
+    organization_id: uuid::Uuid,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+) -> Result<Contact, UsecaseError> {
+    let details = ContactDetails {
+        name: details.name.trim().to_owned(),
+        email: non_blank(details.email),
+        notes: non_blank(details.notes),
+        ..details
+    };
+    details.validate()?;
+    let phone_numbers = details.normalized_phone_numbers()?;
+    let custom_fields = details
+        .custom_fields
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let key = key.trim().to_owned();
+            (!key.is_empty()).then(|| (key, value.trim().to_owned()))
+        })
+        .collect();
+    let mut tags = Vec::<String>::with_capacity(details.tags.len());
+    for tag in details.tags {
+        let tag = tag.trim().to_lowercase();
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    Ok(Contact::builder()
+        .id(id)
+        .user_id(user_id)
+        .organization_id(organization_id)
+        .name(details.name)
+        .phone_numbers(phone_numbers)
+        .maybe_email(details.email)
+        .maybe_notes(details.notes)
+        .custom_fields(custom_fields)
+        .tags(tags)
+        .created_at(created_at)
+        .updated_at(updated_at)
+        .build())
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            contact::Contact,
+            conversation::ConversationRouting,
+            organization::{Organization, OrganizationMember, OrganizationRole, Permission},
+        },
+        repositories::{
+            RepositoryError, contact_repository::ContactRepository,
+            organization_repository::OrganizationRepository,
+        },
+    };
+    use time::OffsetDateTime;
+
+    use crate::{
+        commands::{ContactDetails, CreateContactCommand},
+        usecases::UsecaseError,
+    };
+
+    use super::CreateContactUsecase;
+
+    #[derive(Default)]
+    struct FakeContactRepository {
+        created: Mutex<Vec<Contact>>,
+    }
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, contact: &Contact) -> Result<(), RepositoryError> {
+            self.created.lock().expect("lock").push(contact.clone());
+            Ok(())
+        }
+
+        async fn update_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_by_phone_numbers(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeOrganizationRepository {
+        role: Option<OrganizationRole>,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            let role = self.role.ok_or(RepositoryError::NotFound)?;
+            Ok(OrganizationMember::builder()
+                .organization_id(*organization_id)
+                .user_id(*user_id)
+                .role(role)
+                .created_at(OffsetDateTime::now_utc())
+                .build())
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn details(phone_numbers: &[&str]) -> ContactDetails {
+        ContactDetails {
+            name: "  Ada Lovelace ".to_owned(),
+            phone_numbers: phone_numbers.iter().map(|n| (*n).to_owned()).collect(),
+            email: Some(" ".to_owned()),
+            notes: None,
+            custom_fields: BTreeMap::from([
+                (" company ".to_owned(), "Analytical Engines".to_owned()),
+                ("".to_owned(), "dropped".to_owned()),
+            ]),
+            tags: vec![" VIP ".to_owned(), "vip".to_owned(), "".to_owned()],
+        }
+    }
+
+    #[tokio::test]
+    async fn normalizes_contact_details_before_saving() {
+        let repository = Arc::new(FakeContactRepository::default());
+        let usecase = CreateContactUsecase::builder()
+            .contact_repository(repository.clone())
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let result = usecase
+            .execute(CreateContactCommand {
+                user_id: uuid::Uuid::now_v7(),
+                organization_id: uuid::Uuid::now_v7(),
+                details: details(&["+1 (555) 123-4567", "+15551234567", "+44 20 7946 0958"]),
+            })
+            .await
+            .expect("contact should be created");
+
+        assert_eq!(result.contact.name, "Ada Lovelace");
+        assert_eq!(
+            result.contact.phone_numbers,
+            vec!["+15551234567".to_owned(), "+442079460958".to_owned()]
+        );
+        assert_eq!(result.contact.email, None);
+        assert_eq!(
+            result.contact.custom_fields,
+            BTreeMap::from([("company".to_owned(), "Analytical Engines".to_owned())])
+        );
+        assert_eq!(result.contact.tags, vec!["vip".to_owned()]);
+        assert_eq!(repository.created.lock().expect("lock").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_numbers_that_are_not_e164() {
+        let repository = Arc::new(FakeContactRepository::default());
+        let usecase = CreateContactUsecase::builder()
+            .contact_repository(repository.clone())
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let result = usecase
+            .execute(CreateContactCommand {
+                user_id: uuid::Uuid::now_v7(),
+                organization_id: uuid::Uuid::now_v7(),
+                details: details(&["555-1234"]),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UsecaseError::Validation(_))));
+        assert!(repository.created.lock().expect("lock").is_empty());
+    }
+
+    #[tokio::test]
+    async fn read_only_members_cannot_add_contacts() {
+        let repository = Arc::new(FakeContactRepository::default());
+        let usecase = CreateContactUsecase::builder()
+            .contact_repository(repository.clone())
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::ReadOnly),
+            }))
+            .build();
+
+        let result = usecase
+            .execute(CreateContactCommand {
+                user_id: uuid::Uuid::now_v7(),
+                organization_id: uuid::Uuid::now_v7(),
+                details: details(&["+15551234567"]),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UsecaseError::Forbidden(Permission::ManageContacts))
+        ));
+        assert!(repository.created.lock().expect("lock").is_empty());
+    }
+}
@@ -3,17 +3,22 @@ use std::sync::Arc;
 use time::OffsetDateTime;
 
 use crate::{
-    commands::CreateConversationCommand, responses::CreateConversationResult,
-    usecases::UsecaseError,
+    commands::CreateConversationCommand,
+    responses::CreateConversationResult,
+    usecases::{UsecaseError, authorize_member},
 };
 use domain::{
-    models::conversation::Conversation,
-    repositories::conversation_repository::ConversationRepository,
+    models::{conversation::Conversation, organization::Permission},
+    repositories::{
+        conversation_repository::ConversationRepository,
+        organization_repository::OrganizationRepository,
+    },
 };
 
 #[derive(bon::Builder)]
 pub struct CreateConversationUsecase {
     conversation_repository: Arc<dyn ConversationRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
 }
 
 impl CreateConversationUsecase {
@@ -21,6 +26,14 @@ impl CreateConversationUsecase {
         &self,
         cmd: CreateConversationCommand,
     ) -> Result<CreateConversationResult, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::SendMessages,
+        )
+        .await?;
+
         let recipient_phone_number = cmd.recipient_phone_number.trim();
         if recipient_phone_number.is_empty() {
             return Err(garde::Error::new("Recipient phone number is required").into());
@@ -31,6 +44,7 @@ impl CreateConversationUsecase {
         let conversation = Conversation::builder()
             .id(conversation_id)
             .phone_number_id(cmd.phone_number_id)
+            .organization_id(cmd.organization_id)
             .user_id(cmd.user_id)
             .maybe_recipient_phone_number(Some(recipient_phone_number.to_owned()))
             .last_message_at(now)
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{
+    commands::{CreateMessageTemplateCommand, MessageTemplateDetails},
+    responses::CreateMessageTemplateResult,
+    usecases::UsecaseError,
+};
+use domain::{
+    models::message_template::{MessageTemplate, template_variables},
+    repositories::message_template_repository::MessageTemplateRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct CreateMessageTemplateUsecase {
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+}
+
+impl CreateMessageTemplateUsecase {
+    pub async fn execute(
+        &self,
+        cmd: CreateMessageTemplateCommand,
+    ) -> Result<CreateMessageTemplateResult, UsecaseError> {
+        let now = OffsetDateTime::now_utc();
+        let template =
+            build_message_template(cmd.details, uuid::Uuid::now_v7(), cmd.user_id, now, now)?;
+
+        self.message_template_repository
+            .create_template(&template)
+            .await?;
+
+        Ok(CreateMessageTemplateResult { template })
+    }
+}
+
+/// Validates the submitted details and turns them into a template. Every placeholder must
+/// have a name; whether it has a value is only known once a recipient is picked.
+pub(crate) fn build_message_template(
+    details: MessageTemplateDetails,
+    id: uuid::Uuid,
+    user_id: uuid::Uuid,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+) -> Result<MessageTemplate, UsecaseError> {
+    let details = MessageTemplateDetails {
+        name: details.name.trim().to_owned(),
+        body: details.body.trim().to_owned(),
+    };
+    details.validate()?;
+
+    if template_variables(&details.body)
+        .iter()
+        .any(|name| name.is_empty())
+    {
+        return Err(garde::Error::new("Placeholders need a name, e.g. {{first_name}}").into());
+    }
+
+    Ok(MessageTemplate::builder()
+        .id(id)
+        .user_id(user_id)
+        .name(details.name)
+        .body(details.body)
+        .created_at(created_at)
+        .updated_at(updated_at)
+        .build())
+}
@@ -1,25 +1,73 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use time::OffsetDateTime;
+use time::{OffsetDateTime, UtcOffset};
 
 use crate::{
-    commands::CreateMessageCommand, responses::CreateMessageResult, usecases::UsecaseError,
+    commands::{CreateMessageCommand, MessageAttachmentUpload},
+    responses::CreateMessageResult,
+    usecases::{
+        UsecaseError, authorize_member,
+        preview_message_template_usecase::{conversation_variables, unresolved_variables_error},
+    },
 };
 use domain::{
-    models::message::{Message, MessageStatus, MessageType},
+    models::{
+        message::{Message, MessageStatus, MessageType},
+        message_attachment::MessageAttachment,
+        message_template::render_template,
+        organization::Permission,
+        outbound_message_job::OutboundMessageJob,
+        sms_encoding::count_segments,
+    },
     repositories::{
-        conversation_repository::ConversationRepository, message_repository::MessageRepository,
+        contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+        message_repository::MessageRepository,
+        message_template_repository::MessageTemplateRepository,
+        opt_out_repository::OptOutRepository, organization_repository::OrganizationRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
         phone_number_repository::PhoneNumberRepository,
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+        user_repository::UserRepository,
     },
-    traits::outbound_message_service::{OutboundMessageService, SendMessageRequest},
+    traits::media_storage::MediaStorage,
 };
 
+pub const MAX_ATTACHMENTS_PER_MESSAGE: usize = 10;
+pub const MAX_ATTACHMENT_BYTES_PER_MESSAGE: usize = 5 * 1024 * 1024;
+const ALLOWED_ATTACHMENT_TYPE_PREFIXES: [&str; 3] = ["image/", "video/", "audio/"];
+const ALLOWED_ATTACHMENT_TYPES: [&str; 3] = ["application/pdf", "text/vcard", "text/x-vcard"];
+/// How far ahead a message may be scheduled.
+pub const MAX_SCHEDULE_AHEAD: Duration = Duration::from_secs(60 * 60 * 24 * 365);
+
+/// Whether accounts that have not verified their email address may send messages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnverifiedSenderPolicy {
+    #[default]
+    Allow,
+    Block,
+}
+
+/// Persists an outbound message as `Pending` and queues it for delivery. The provider is only
+/// contacted by `DispatchOutboundMessagesUsecase`, so a message is never sent without a record.
+/// Messages with a `scheduled_at` are stored as `Scheduled` and their job only becomes due then.
+/// A `template_id` is rendered with the recipient's contact details and must resolve fully.
+/// Users whose spend has reached a hard limit cannot send until the period resets.
 #[derive(bon::Builder)]
 pub struct CreateMessageUsecase {
     conversation_repository: Arc<dyn ConversationRepository>,
     message_repository: Arc<dyn MessageRepository>,
     phone_number_repository: Arc<dyn PhoneNumberRepository>,
-    outbound_message_service: Arc<dyn OutboundMessageService>,
+    user_repository: Arc<dyn UserRepository>,
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    media_storage: Arc<dyn MediaStorage>,
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    #[builder(default)]
+    unverified_sender_policy: UnverifiedSenderPolicy,
 }
 
 impl CreateMessageUsecase {
@@ -27,14 +75,46 @@ impl CreateMessageUsecase {
         &self,
         cmd: CreateMessageCommand,
     ) -> Result<CreateMessageResult, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::SendMessages,
+        )
+        .await?;
+
         let content = cmd.content.trim();
-        if content.is_empty() {
+        if cmd.template_id.is_some() && !content.is_empty() {
+            return Err(
+                garde::Error::new("Send either message content or a template, not both").into(),
+            );
+        }
+        if content.is_empty() && cmd.attachments.is_empty() && cmd.template_id.is_none() {
             return Err(garde::Error::new("Message content is required").into());
         }
+        validate_attachments(&cmd.attachments)?;
+        let scheduled_at = cmd
+            .scheduled_at
+            .map(|at| validate_scheduled_at(at, OffsetDateTime::now_utc()))
+            .transpose()?;
+
+        if self.unverified_sender_policy == UnverifiedSenderPolicy::Block {
+            let user = self.user_repository.find_by_id(&cmd.user_id).await?;
+            if !user.email_verified {
+                return Err(UsecaseError::EmailNotVerified);
+            }
+        }
+        ensure_within_hard_limits(
+            &self.usage_limit_repository,
+            &self.usage_repository,
+            &cmd.user_id,
+            OffsetDateTime::now_utc(),
+        )
+        .await?;
 
-        let mut conversation = self
+        let conversation = self
             .conversation_repository
-            .find_by_id(&cmd.user_id, &cmd.conversation_id)
+            .find_by_id(&cmd.organization_id, &cmd.conversation_id)
             .await?;
 
         let recipient_phone_number = conversation
@@ -47,73 +127,284 @@ impl CreateMessageUsecase {
 
         let phone_number = self
             .phone_number_repository
-            .find_by_id(&cmd.user_id, &conversation.phone_number_id)
+            .find_by_id(&cmd.organization_id, &conversation.phone_number_id)
             .await?;
 
-        let provider_response = self
-            .outbound_message_service
-            .send_text_message(SendMessageRequest {
-                from: phone_number.phone.clone(),
-                to: recipient_phone_number,
-                text: content.to_owned(),
-            })
-            .await?;
+        if self
+            .opt_out_repository
+            .is_opted_out(&phone_number.id, &recipient_phone_number)
+            .await?
+        {
+            return Err(UsecaseError::RecipientOptedOut);
+        }
 
+        let content = match cmd.template_id {
+            Some(template_id) => {
+                let template = self
+                    .message_template_repository
+                    .find_by_id(&cmd.user_id, &template_id)
+                    .await?;
+                let variables = conversation_variables(
+                    self.contact_repository.as_ref(),
+                    &conversation,
+                    &phone_number,
+                )
+                .await?;
+
+                render_template(&template.body, &variables)
+                    .map_err(|names| unresolved_variables_error(&names))?
+            }
+            None => content.to_owned(),
+        };
+
+        let segment_count = count_segments(&content);
+        let message_id = uuid::Uuid::now_v7();
         let now = OffsetDateTime::now_utc();
+        let attachments = self
+            .store_attachments(cmd.user_id, message_id, now, cmd.attachments)
+            .await?;
+
         let message = Message::builder()
-            .id(uuid::Uuid::now_v7())
+            .id(message_id)
             .conversation_id(conversation.id)
+            .organization_id(conversation.organization_id)
             .user_id(cmd.user_id)
             .message_type(MessageType::Outbound)
-            .status(MessageStatus::Queued)
-            .maybe_provider_message_id(Some(provider_response.provider_message_id))
-            .maybe_provider_status(Some("queued".to_owned()))
+            .status(if scheduled_at.is_some() {
+                MessageStatus::Scheduled
+            } else {
+                MessageStatus::Pending
+            })
+            .maybe_provider_message_id(None)
+            .maybe_provider_status(None)
             .maybe_provider_status_updated_at(None)
             .maybe_provider_error_code(None)
             .maybe_provider_error_detail(None)
             .from_number(phone_number.phone)
-            .content(content.to_owned())
+            .content(content)
+            .maybe_scheduled_at(scheduled_at)
+            .segments(segment_count.segments as i32)
+            .encoding(segment_count.encoding)
             .created_at(now)
             .updated_at(now)
+            .attachments(attachments)
             .build();
 
-        let message = self.message_repository.create_message(&message).await?;
+        let message = match self.message_repository.create_message(&message).await {
+            Ok(message) => message,
+            Err(err) => {
+                self.discard_attachments(&message.attachments).await;
+                return Err(err.into());
+            }
+        };
+
+        let job = OutboundMessageJob::builder()
+            .message_id(message.id)
+            .user_id(message.user_id)
+            .to_number(recipient_phone_number)
+            .next_attempt_at(scheduled_at.unwrap_or(now))
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        if let Err(err) = self.outbound_message_queue_repository.enqueue(&job).await {
+            // Nothing was sent; leave a failed record rather than a message that never moves.
+            let mut failed = message;
+            failed.transition_to(MessageStatus::Failed)?;
+            failed.provider_error_detail =
+                Some("Message could not be queued for delivery".to_owned());
+            let _ = self.message_repository.update_message(&failed).await;
+            return Err(err.into());
+        }
 
-        conversation.last_message_at = message.created_at;
-        conversation.updated_at = message.updated_at;
         self.conversation_repository
-            .update_conversation(&conversation)
+            .record_activity(&conversation, message.created_at, message.updated_at)
             .await?;
 
         Ok(CreateMessageResult { message })
     }
+
+    async fn store_attachments(
+        &self,
+        user_id: uuid::Uuid,
+        message_id: uuid::Uuid,
+        now: OffsetDateTime,
+        uploads: Vec<MessageAttachmentUpload>,
+    ) -> Result<Vec<MessageAttachment>, UsecaseError> {
+        let mut attachments = Vec::with_capacity(uploads.len());
+
+        for upload in uploads {
+            let attachment_id = uuid::Uuid::now_v7();
+            let storage_key = format!("{}/{}/{}", user_id, message_id, attachment_id);
+
+            let stored = match self.media_storage.put(&storage_key, &upload.bytes).await {
+                Ok(stored) => stored,
+                Err(err) => {
+                    self.discard_attachments(&attachments).await;
+                    return Err(err.into());
+                }
+            };
+
+            attachments.push(
+                MessageAttachment::builder()
+                    .id(attachment_id)
+                    .message_id(message_id)
+                    .user_id(user_id)
+                    .file_name(upload.file_name)
+                    .content_type(upload.content_type)
+                    .size_bytes(stored.size_bytes)
+                    .storage_key(storage_key)
+                    .checksum_sha256(stored.checksum_sha256)
+                    .created_at(now)
+                    .build(),
+            );
+        }
+
+        Ok(attachments)
+    }
+
+    async fn discard_attachments(&self, attachments: &[MessageAttachment]) {
+        for attachment in attachments {
+            let _ = self.media_storage.delete(&attachment.storage_key).await;
+        }
+    }
+}
+
+/// Fails with [`UsecaseError::UsageLimitReached`] when `user_id` has spent their hard limit
+/// for any period. Every message the app sends on a user's behalf goes through this check.
+pub(crate) async fn ensure_within_hard_limits(
+    usage_limit_repository: &Arc<dyn UsageLimitRepository>,
+    usage_repository: &Arc<dyn UsageRepository>,
+    user_id: &uuid::Uuid,
+    now: OffsetDateTime,
+) -> Result<(), UsecaseError> {
+    let limits = usage_limit_repository.list_by_user_id(user_id).await?;
+    for limit in limits
+        .iter()
+        .filter(|limit| limit.hard_limit_micros.is_some())
+    {
+        let totals = usage_repository
+            .totals_since(user_id, limit.period.start(now))
+            .await?;
+        if limit.hard_limit_reached(totals.cost_micros) {
+            return Err(UsecaseError::UsageLimitReached {
+                period: limit.period,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a send-later time lies in the future and within `MAX_SCHEDULE_AHEAD`, and
+/// returns it in UTC.
+pub(crate) fn validate_scheduled_at(
+    scheduled_at: OffsetDateTime,
+    now: OffsetDateTime,
+) -> Result<OffsetDateTime, UsecaseError> {
+    if scheduled_at <= now {
+        return Err(garde::Error::new("Scheduled time must be in the future").into());
+    }
+    if scheduled_at > now + MAX_SCHEDULE_AHEAD {
+        return Err(garde::Error::new("Messages can be scheduled at most one year ahead").into());
+    }
+
+    Ok(scheduled_at.to_offset(UtcOffset::UTC))
+}
+
+fn validate_attachments(attachments: &[MessageAttachmentUpload]) -> Result<(), UsecaseError> {
+    if attachments.len() > MAX_ATTACHMENTS_PER_MESSAGE {
+        return Err(garde::Error::new(format!(
+            "A message can include at most {} attachments",
+            MAX_ATTACHMENTS_PER_MESSAGE
+        ))
+        .into());
+    }
+
+    let total_bytes = attachments
+        .iter()
+        .map(|attachment| attachment.bytes.len())
+        .sum::<usize>();
+    if total_bytes > MAX_ATTACHMENT_BYTES_PER_MESSAGE {
+        return Err(garde::Error::new("Attachments exceed the 5 MB limit per message").into());
+    }
+
+    for attachment in attachments {
+        if attachment.bytes.is_empty() {
+            return Err(
+                garde::Error::new(format!("Attachment {} is empty", attachment.file_name)).into(),
+            );
+        }
+
+        let content_type = attachment.content_type.to_ascii_lowercase();
+        let allowed = ALLOWED_ATTACHMENT_TYPE_PREFIXES
+            .iter()
+            .any(|prefix| content_type.starts_with(prefix))
+            || ALLOWED_ATTACHMENT_TYPES.contains(&content_type.as_str());
+        if !allowed {
+            return Err(garde::Error::new(format!(
+                "Attachment type {} is not supported",
+                attachment.content_type
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::{Arc, Mutex};
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
 
     use async_trait::async_trait;
     use domain::{
         models::{
-            conversation::Conversation,
+            contact::Contact,
+            conversation::{
+                AssigneeLoad, Conversation, ConversationFilter, ConversationRouting,
+                ConversationStateChange, ConversationView, UnreadCount,
+            },
             message::{Message, MessageStatus, MessageType},
+            message_attachment::MessageAttachment,
+            message_template::MessageTemplate,
+            opt_out::OptOut,
+            organization::{Organization, OrganizationMember, OrganizationRole, Permission},
+            outbound_message_job::OutboundMessageJob,
             phone_number::PhoneNumber,
+            sms_encoding::SmsEncoding,
+            usage::{UsageLimit, UsagePeriod, UsageRecord, UsageRollup, UsageTotals},
+            user::User,
         },
         repositories::{
-            RepositoryError, conversation_repository::ConversationRepository,
+            RepositoryError,
+            contact_repository::ContactRepository,
+            conversation_repository::ConversationRepository,
             message_repository::{MessagePage, MessageRepository},
+            message_template_repository::MessageTemplateRepository,
+            opt_out_repository::OptOutRepository,
+            organization_repository::OrganizationRepository,
+            outbound_message_queue_repository::OutboundMessageQueueRepository,
             phone_number_repository::PhoneNumberRepository,
+            usage_limit_repository::UsageLimitRepository,
+            usage_repository::UsageRepository,
+            user_repository::UserRepository,
         },
-        traits::outbound_message_service::{
-            OutboundMessageError, OutboundMessageService, SendMessageRequest, SendMessageResponse,
-        },
+        traits::media_storage::{MediaStorage, MediaStorageError, StoredMedia},
     };
     use time::OffsetDateTime;
 
-    use crate::{commands::CreateMessageCommand, usecases::UsecaseError};
+    use crate::{
+        commands::{CreateMessageCommand, MessageAttachmentUpload},
+        usecases::UsecaseError,
+    };
+
+    use super::{CreateMessageUsecase, UnverifiedSenderPolicy};
 
-    use super::CreateMessageUsecase;
+    const ORGANIZATION_ID: uuid::Uuid = uuid::Uuid::from_u128(1);
 
     struct FakeConversationRepository {
         conversation: Conversation,
@@ -129,17 +420,6 @@ mod tests {
             Ok(())
         }
 
-        async fn update_conversation(
-            &self,
-            conversation: &Conversation,
-        ) -> Result<(), RepositoryError> {
-            self.updated_conversation
-                .lock()
-                .expect("lock")
-                .replace(conversation.clone());
-            Ok(())
-        }
-
         async fn find_by_id(
             &self,
             _user_id: &uuid::Uuid,
@@ -157,13 +437,101 @@ mod tests {
             Ok(self.conversation.clone())
         }
 
-        async fn list_by_user_id(
+        async fn list_by_organization_id(
             &self,
-            _user_id: &uuid::Uuid,
+            _organization_id: &uuid::Uuid,
         ) -> Result<Vec<Conversation>, RepositoryError> {
             Ok(vec![self.conversation.clone()])
         }
 
+        async fn list_filtered(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _view: ConversationView,
+            _filter: ConversationFilter,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn change_state(
+            &self,
+            conversation: &Conversation,
+            _change: ConversationStateChange,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn record_activity(
+            &self,
+            conversation: &Conversation,
+            last_message_at: OffsetDateTime,
+            updated_at: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            let mut updated = conversation.clone();
+            updated.last_message_at = last_message_at;
+            updated.updated_at = updated_at;
+            self.updated_conversation
+                .lock()
+                .expect("lock")
+                .replace(updated.clone());
+            Ok(updated)
+        }
+
+        async fn reopen(&self, _conversation: &Conversation) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn assign(
+            &self,
+            conversation: &Conversation,
+            _assigned_user_id: Option<uuid::Uuid>,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn claim_assignment(
+            &self,
+            _conversation: &Conversation,
+            _assigned_user_id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+
+        async fn list_assignee_loads(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn mark_read(
+            &self,
+            _conversation: &Conversation,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_unread_counts(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UnreadCount>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn wake_snoozed(
+            &self,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
         async fn delete_conversation(
             &self,
             _user_id: &uuid::Uuid,
@@ -171,6 +539,15 @@ mod tests {
         ) -> Result<(), RepositoryError> {
             Ok(())
         }
+
+        async fn claim_away_reply(
+            &self,
+            _conversation: &Conversation,
+            _closed_since: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
     }
 
     struct FakeMessageRepository {
@@ -187,7 +564,14 @@ mod tests {
             Ok(message.clone())
         }
 
-        async fn count_by_user_id(&self, _user_id: &uuid::Uuid) -> Result<u64, RepositoryError> {
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn count_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<u64, RepositoryError> {
             Ok(0)
         }
 
@@ -198,6 +582,13 @@ mod tests {
             Err(RepositoryError::NotFound)
         }
 
+        async fn find_reply_to(
+            &self,
+            _message_id: &uuid::Uuid,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
         async fn list_by_conversation_id(
             &self,
             _user_id: &uuid::Uuid,
@@ -222,6 +613,47 @@ mod tests {
         async fn update_message(&self, message: &Message) -> Result<Message, RepositoryError> {
             Ok(message.clone())
         }
+
+        async fn list_scheduled_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+            _scheduled_at: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn delete_scheduled_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn release_scheduled_message(
+            &self,
+            _id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn find_attachment_by_id(
+            &self,
+            _id: &uuid::Uuid,
+        ) -> Result<MessageAttachment, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
     }
 
     struct FakePhoneNumberRepository {
@@ -249,9 +681,9 @@ mod tests {
             Ok(self.phone_number.clone())
         }
 
-        async fn list_by_user_id(
+        async fn list_by_organization_id(
             &self,
-            _user_id: &uuid::Uuid,
+            _organization_id: &uuid::Uuid,
         ) -> Result<Vec<PhoneNumber>, RepositoryError> {
             Ok(vec![self.phone_number.clone()])
         }
@@ -265,113 +697,547 @@ mod tests {
         }
     }
 
-    struct FakeOutboundMessageService {
-        response: Result<SendMessageResponse, OutboundMessageError>,
-        requests: Mutex<Vec<SendMessageRequest>>,
+    #[derive(Default)]
+    struct FakeOutboundMessageQueueRepository {
+        jobs: Mutex<Vec<OutboundMessageJob>>,
     }
 
     #[async_trait]
-    impl OutboundMessageService for FakeOutboundMessageService {
-        async fn send_text_message(
+    impl OutboundMessageQueueRepository for FakeOutboundMessageQueueRepository {
+        async fn enqueue(&self, job: &OutboundMessageJob) -> Result<(), RepositoryError> {
+            self.jobs.lock().expect("lock").push(job.clone());
+            Ok(())
+        }
+
+        async fn claim_due(
             &self,
-            request: SendMessageRequest,
-        ) -> Result<SendMessageResponse, OutboundMessageError> {
-            self.requests.lock().expect("lock").push(request);
-            self.response.clone()
+            _now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            _limit: usize,
+        ) -> Result<Vec<OutboundMessageJob>, RepositoryError> {
+            Ok(Vec::new())
         }
-    }
 
-    fn build_conversation(
-        user_id: uuid::Uuid,
-        conversation_id: uuid::Uuid,
-        phone_number_id: uuid::Uuid,
-        recipient_phone_number: Option<String>,
-    ) -> Conversation {
-        let now = OffsetDateTime::now_utc();
-        Conversation::builder()
-            .id(conversation_id)
-            .phone_number_id(phone_number_id)
-            .user_id(user_id)
-            .maybe_recipient_phone_number(recipient_phone_number)
-            .last_message_at(now)
-            .created_at(now)
-            .updated_at(now)
-            .build()
+        async fn reschedule(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+            _last_error: &str,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn postpone(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn complete(&self, _message_id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
     }
 
-    fn build_phone_number(user_id: uuid::Uuid, phone_number_id: uuid::Uuid) -> PhoneNumber {
-        let now = OffsetDateTime::now_utc();
-        PhoneNumber::builder()
-            .id(phone_number_id)
-            .user_id(user_id)
-            .name("Primary".to_owned())
-            .phone("+13125550100".to_owned())
-            .created_at(now)
-            .updated_at(now)
-            .build()
+    #[derive(Default)]
+    struct FakeOptOutRepository {
+        opted_out: Mutex<Vec<(uuid::Uuid, String)>>,
     }
 
-    #[tokio::test]
-    async fn creates_queued_outbound_message_and_updates_conversation() {
-        let user_id = uuid::Uuid::now_v7();
-        let conversation_id = uuid::Uuid::now_v7();
-        let phone_number_id = uuid::Uuid::now_v7();
-        let conversation_repository = Arc::new(FakeConversationRepository {
-            conversation: build_conversation(
-                user_id,
-                conversation_id,
-                phone_number_id,
-                Some("+14155551234".to_owned()),
-            ),
-            updated_conversation: Mutex::new(None),
-        });
-        let message_repository = Arc::new(FakeMessageRepository {
-            created_messages: Mutex::new(Vec::new()),
-        });
-        let phone_number_repository = Arc::new(FakePhoneNumberRepository {
-            phone_number: build_phone_number(user_id, phone_number_id),
-        });
-        let outbound_message_service = Arc::new(FakeOutboundMessageService {
-            response: Ok(SendMessageResponse {
-                provider_message_id: "provider-message-id".to_owned(),
-            }),
-            requests: Mutex::new(Vec::new()),
-        });
+    #[async_trait]
+    impl OptOutRepository for FakeOptOutRepository {
+        async fn opt_out(&self, opt_out: &OptOut) -> Result<(), RepositoryError> {
+            self.opted_out.lock().expect("lock").push((
+                opt_out.phone_number_id,
+                opt_out.recipient_phone_number.to_owned(),
+            ));
+            Ok(())
+        }
 
-        let usecase = CreateMessageUsecase::builder()
-            .conversation_repository(conversation_repository.clone())
-            .message_repository(message_repository.clone())
-            .phone_number_repository(phone_number_repository)
-            .outbound_message_service(outbound_message_service.clone())
-            .build();
+        async fn opt_in(
+            &self,
+            phone_number_id: &uuid::Uuid,
+            recipient_phone_number: &str,
+        ) -> Result<(), RepositoryError> {
+            self.opted_out
+                .lock()
+                .expect("lock")
+                .retain(|(id, recipient)| {
+                    id != phone_number_id || recipient != recipient_phone_number
+                });
+            Ok(())
+        }
 
-        let result = usecase
-            .execute(CreateMessageCommand {
-                user_id,
-                conversation_id,
-                content: " Hello ".to_owned(),
-            })
-            .await
-            .expect("message should be created");
+        async fn is_opted_out(
+            &self,
+            phone_number_id: &uuid::Uuid,
+            recipient_phone_number: &str,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self
+                .opted_out
+                .lock()
+                .expect("lock")
+                .iter()
+                .any(|(id, recipient)| {
+                    id == phone_number_id && recipient == recipient_phone_number
+                }))
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeMediaStorage {
+        objects: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl MediaStorage for FakeMediaStorage {
+        async fn put(&self, key: &str, bytes: &[u8]) -> Result<StoredMedia, MediaStorageError> {
+            self.objects
+                .lock()
+                .expect("lock")
+                .insert(key.to_owned(), bytes.to_vec());
+            Ok(StoredMedia {
+                size_bytes: bytes.len() as i64,
+                checksum_sha256: format!("sha256-{}", bytes.len()),
+            })
+        }
+
+        async fn get(&self, key: &str) -> Result<Vec<u8>, MediaStorageError> {
+            self.objects
+                .lock()
+                .expect("lock")
+                .get(key)
+                .cloned()
+                .ok_or(MediaStorageError::NotFound)
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), MediaStorageError> {
+            self.objects.lock().expect("lock").remove(key);
+            Ok(())
+        }
+    }
+
+    struct FakeUserRepository {
+        email_verified: bool,
+    }
+
+    #[async_trait]
+    impl UserRepository for FakeUserRepository {
+        async fn create_user(&self, _user: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn create_user_with_organization(
+            &self,
+            _user: &User,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<User, RepositoryError> {
+            let now = OffsetDateTime::now_utc();
+            Ok(User::builder()
+                .id(*id)
+                .email("owner@example.com".to_owned())
+                .hash("hash".to_owned())
+                .salt("salt".to_owned())
+                .email_verified(self.email_verified)
+                .maybe_email_verified_at(self.email_verified.then_some(now))
+                .created_at(now)
+                .updated_at(now)
+                .build())
+        }
+
+        async fn find_by_email(&self, _email: &str) -> Result<User, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list(&self, _page: u64, _limit: u64) -> Result<Vec<User>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_user(&self, _new_data: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete_user(&self, _id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeMessageTemplateRepository {
+        templates: Vec<MessageTemplate>,
+    }
+
+    #[async_trait]
+    impl MessageTemplateRepository for FakeMessageTemplateRepository {
+        async fn create_template(
+            &self,
+            _template: &MessageTemplate,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_template(
+            &self,
+            _template: &MessageTemplate,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            user_id: &uuid::Uuid,
+            id: &uuid::Uuid,
+        ) -> Result<MessageTemplate, RepositoryError> {
+            self.templates
+                .iter()
+                .find(|template| template.user_id == *user_id && template.id == *id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<MessageTemplate>, RepositoryError> {
+            Ok(self.templates.clone())
+        }
+
+        async fn delete_template(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeContactRepository {
+        contacts: Vec<Contact>,
+    }
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self.contacts.clone())
+        }
+
+        async fn find_by_phone_numbers(
+            &self,
+            organization_id: &uuid::Uuid,
+            phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self
+                .contacts
+                .iter()
+                .filter(|contact| {
+                    contact.organization_id == *organization_id
+                        && contact
+                            .phone_numbers
+                            .iter()
+                            .any(|number| phone_numbers.contains(number))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeUsageRepository {
+        spent_micros: i64,
+    }
+
+    #[async_trait]
+    impl UsageRepository for FakeUsageRepository {
+        async fn record_usage(&self, _record: &UsageRecord) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn record_cost(
+            &self,
+            _message_id: &uuid::Uuid,
+            _cost_micros: i64,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn totals_since(
+            &self,
+            _user_id: &uuid::Uuid,
+            _since: OffsetDateTime,
+        ) -> Result<UsageTotals, RepositoryError> {
+            Ok(UsageTotals {
+                cost_micros: self.spent_micros,
+                ..UsageTotals::default()
+            })
+        }
+
+        async fn rollups(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _since: OffsetDateTime,
+        ) -> Result<Vec<UsageRollup>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeUsageLimitRepository {
+        limits: Vec<UsageLimit>,
+    }
+
+    #[async_trait]
+    impl UsageLimitRepository for FakeUsageLimitRepository {
+        async fn save_limit(&self, _limit: &UsageLimit) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            user_id: &uuid::Uuid,
+        ) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(self
+                .limits
+                .iter()
+                .filter(|limit| limit.user_id == *user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_limit(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_soft_limit_notification(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _period_start: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct FakeOrganizationRepository {
+        role: Option<OrganizationRole>,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            let role = self.role.ok_or(RepositoryError::NotFound)?;
+            Ok(OrganizationMember::builder()
+                .organization_id(*organization_id)
+                .user_id(*user_id)
+                .role(role)
+                .created_at(OffsetDateTime::now_utc())
+                .build())
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn build_conversation(
+        user_id: uuid::Uuid,
+        conversation_id: uuid::Uuid,
+        phone_number_id: uuid::Uuid,
+        recipient_phone_number: Option<String>,
+    ) -> Conversation {
+        let now = OffsetDateTime::now_utc();
+        Conversation::builder()
+            .id(conversation_id)
+            .phone_number_id(phone_number_id)
+            .organization_id(ORGANIZATION_ID)
+            .user_id(user_id)
+            .maybe_recipient_phone_number(recipient_phone_number)
+            .last_message_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    fn build_phone_number(user_id: uuid::Uuid, phone_number_id: uuid::Uuid) -> PhoneNumber {
+        let now = OffsetDateTime::now_utc();
+        PhoneNumber::builder()
+            .id(phone_number_id)
+            .organization_id(ORGANIZATION_ID)
+            .user_id(user_id)
+            .name("Primary".to_owned())
+            .phone("+13125550100".to_owned())
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn creates_pending_outbound_message_and_queues_it_for_delivery() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversation: build_conversation(
+                user_id,
+                conversation_id,
+                phone_number_id,
+                Some("+14155551234".to_owned()),
+            ),
+            updated_conversation: Mutex::new(None),
+        });
+        let message_repository = Arc::new(FakeMessageRepository {
+            created_messages: Mutex::new(Vec::new()),
+        });
+        let phone_number_repository = Arc::new(FakePhoneNumberRepository {
+            phone_number: build_phone_number(user_id, phone_number_id),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+
+        let usecase = CreateMessageUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(message_repository.clone())
+            .phone_number_repository(phone_number_repository)
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: true,
+            }))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository::default()))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let result = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: " Hello ".to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: None,
+            })
+            .await
+            .expect("message should be created");
 
         assert_eq!(result.message.conversation_id, conversation_id);
         assert_eq!(result.message.user_id, user_id);
         assert_eq!(result.message.message_type, MessageType::Outbound);
-        assert_eq!(result.message.status, MessageStatus::Queued);
-        assert_eq!(
-            result.message.provider_message_id.as_deref(),
-            Some("provider-message-id")
-        );
-        assert_eq!(result.message.provider_status.as_deref(), Some("queued"));
+        assert_eq!(result.message.status, MessageStatus::Pending);
+        assert!(result.message.provider_message_id.is_none());
+        assert!(result.message.provider_status.is_none());
         assert!(result.message.provider_status_updated_at.is_none());
         assert_eq!(result.message.from_number, "+13125550100");
         assert_eq!(result.message.content, "Hello");
+        assert_eq!(result.message.segments, Some(1));
+        assert_eq!(result.message.encoding, Some(SmsEncoding::Gsm7));
 
-        let requests = outbound_message_service.requests.lock().expect("lock");
-        assert_eq!(requests.len(), 1);
-        assert_eq!(requests[0].from, "+13125550100");
-        assert_eq!(requests[0].to, "+14155551234");
-        assert_eq!(requests[0].text, "Hello");
+        let jobs = outbound_message_queue_repository.jobs.lock().expect("lock");
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].message_id, result.message.id);
+        assert_eq!(jobs[0].to_number, "+14155551234");
+        assert_eq!(jobs[0].attempts, 0);
 
         let updated_conversation = conversation_repository
             .updated_conversation
@@ -383,6 +1249,81 @@ mod tests {
         assert!(updated_conversation.updated_at >= updated_conversation.created_at);
     }
 
+    #[tokio::test]
+    async fn stores_attachments_before_queueing_media_message() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let media_storage = Arc::new(FakeMediaStorage::default());
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = CreateMessageUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversation: build_conversation(
+                    user_id,
+                    conversation_id,
+                    phone_number_id,
+                    Some("+14155551234".to_owned()),
+                ),
+                updated_conversation: Mutex::new(None),
+            }))
+            .message_repository(Arc::new(FakeMessageRepository {
+                created_messages: Mutex::new(Vec::new()),
+            }))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: build_phone_number(user_id, phone_number_id),
+            }))
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: true,
+            }))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository::default()))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(media_storage.clone())
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let result = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: String::new(),
+                attachments: vec![MessageAttachmentUpload {
+                    file_name: "photo.jpg".to_owned(),
+                    content_type: "image/jpeg".to_owned(),
+                    bytes: vec![0xFF, 0xD8, 0xFF],
+                }],
+                scheduled_at: None,
+                template_id: None,
+            })
+            .await
+            .expect("media message should be created");
+
+        assert_eq!(result.message.attachments.len(), 1);
+        let attachment = &result.message.attachments[0];
+        assert_eq!(attachment.message_id, result.message.id);
+        assert_eq!(attachment.file_name, "photo.jpg");
+        assert_eq!(attachment.size_bytes, 3);
+        assert_eq!(attachment.checksum_sha256.as_deref(), Some("sha256-3"));
+        assert!(
+            media_storage
+                .objects
+                .lock()
+                .expect("lock")
+                .contains_key(&attachment.storage_key)
+        );
+
+        let jobs = outbound_message_queue_repository.jobs.lock().expect("lock");
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].message_id, result.message.id);
+    }
+
     #[tokio::test]
     async fn returns_validation_error_when_recipient_is_missing() {
         let user_id = uuid::Uuid::now_v7();
@@ -399,23 +1340,517 @@ mod tests {
             .phone_number_repository(Arc::new(FakePhoneNumberRepository {
                 phone_number: build_phone_number(user_id, phone_number_id),
             }))
-            .outbound_message_service(Arc::new(FakeOutboundMessageService {
-                response: Ok(SendMessageResponse {
-                    provider_message_id: "provider-message-id".to_owned(),
-                }),
-                requests: Mutex::new(Vec::new()),
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: true,
+            }))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository::default()))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
             }))
             .build();
 
         let err = usecase
             .execute(CreateMessageCommand {
                 user_id,
+                organization_id: ORGANIZATION_ID,
                 conversation_id,
                 content: "Hello".to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: None,
             })
             .await
             .expect_err("missing recipient should fail");
 
         assert!(matches!(err, UsecaseError::Validation(_)));
     }
+
+    #[tokio::test]
+    async fn refuses_non_members_and_read_only_members() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+
+        for role in [None, Some(OrganizationRole::ReadOnly)] {
+            let message_repository = Arc::new(FakeMessageRepository {
+                created_messages: Mutex::new(Vec::new()),
+            });
+            let usecase = CreateMessageUsecase::builder()
+                .conversation_repository(Arc::new(FakeConversationRepository {
+                    conversation: build_conversation(
+                        user_id,
+                        conversation_id,
+                        phone_number_id,
+                        Some("+14155551234".to_owned()),
+                    ),
+                    updated_conversation: Mutex::new(None),
+                }))
+                .message_repository(message_repository.clone())
+                .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                    phone_number: build_phone_number(user_id, phone_number_id),
+                }))
+                .user_repository(Arc::new(FakeUserRepository {
+                    email_verified: true,
+                }))
+                .outbound_message_queue_repository(Arc::new(
+                    FakeOutboundMessageQueueRepository::default(),
+                ))
+                .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+                .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+                .contact_repository(Arc::new(FakeContactRepository::default()))
+                .usage_repository(Arc::new(FakeUsageRepository::default()))
+                .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+                .media_storage(Arc::new(FakeMediaStorage::default()))
+                .organization_repository(Arc::new(FakeOrganizationRepository { role }))
+                .build();
+
+            let err = usecase
+                .execute(CreateMessageCommand {
+                    user_id,
+                    organization_id: ORGANIZATION_ID,
+                    conversation_id,
+                    content: "Hello".to_owned(),
+                    attachments: Vec::new(),
+                    scheduled_at: None,
+                    template_id: None,
+                })
+                .await
+                .expect_err("sending should be refused");
+
+            assert!(
+                matches!(err, UsecaseError::Forbidden(Permission::SendMessages)),
+                "{:?}",
+                role
+            );
+            assert!(
+                message_repository
+                    .created_messages
+                    .lock()
+                    .expect("lock")
+                    .is_empty()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_messages_once_the_hard_limit_is_reached() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = CreateMessageUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversation: build_conversation(
+                    user_id,
+                    conversation_id,
+                    phone_number_id,
+                    Some("+14155551234".to_owned()),
+                ),
+                updated_conversation: Mutex::new(None),
+            }))
+            .message_repository(Arc::new(FakeMessageRepository {
+                created_messages: Mutex::new(Vec::new()),
+            }))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: build_phone_number(user_id, phone_number_id),
+            }))
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: true,
+            }))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository {
+                spent_micros: 5_000_000,
+            }))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository {
+                limits: vec![
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Daily)
+                        .soft_limit_micros(1_000_000)
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Monthly)
+                        .hard_limit_micros(5_000_000)
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                ],
+            }))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let err = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: "Hello".to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: None,
+            })
+            .await
+            .expect_err("sending past the hard limit should be rejected");
+
+        assert!(matches!(
+            err,
+            UsecaseError::UsageLimitReached {
+                period: UsagePeriod::Monthly
+            }
+        ));
+        assert!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn blocks_unverified_sender_when_policy_requires_verification() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = CreateMessageUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversation: build_conversation(
+                    user_id,
+                    conversation_id,
+                    phone_number_id,
+                    Some("+14155551234".to_owned()),
+                ),
+                updated_conversation: Mutex::new(None),
+            }))
+            .message_repository(Arc::new(FakeMessageRepository {
+                created_messages: Mutex::new(Vec::new()),
+            }))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: build_phone_number(user_id, phone_number_id),
+            }))
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: false,
+            }))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository::default()))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .unverified_sender_policy(UnverifiedSenderPolicy::Block)
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let err = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: "Hello".to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: None,
+            })
+            .await
+            .expect_err("unverified sender should be blocked");
+
+        assert!(matches!(err, UsecaseError::EmailNotVerified));
+        assert!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn refuses_to_message_opted_out_recipient() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let message_repository = Arc::new(FakeMessageRepository {
+            created_messages: Mutex::new(Vec::new()),
+        });
+        let opt_out_repository = Arc::new(FakeOptOutRepository::default());
+        opt_out_repository
+            .opt_out(
+                &OptOut::builder()
+                    .id(uuid::Uuid::now_v7())
+                    .user_id(user_id)
+                    .phone_number_id(phone_number_id)
+                    .recipient_phone_number("+14155551234".to_owned())
+                    .keyword("STOP".to_owned())
+                    .created_at(OffsetDateTime::now_utc())
+                    .build(),
+            )
+            .await
+            .expect("opt out");
+        let usecase = CreateMessageUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversation: build_conversation(
+                    user_id,
+                    conversation_id,
+                    phone_number_id,
+                    Some("+14155551234".to_owned()),
+                ),
+                updated_conversation: Mutex::new(None),
+            }))
+            .message_repository(message_repository.clone())
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: build_phone_number(user_id, phone_number_id),
+            }))
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: true,
+            }))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .opt_out_repository(opt_out_repository)
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository::default()))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let err = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: "Hello".to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: None,
+            })
+            .await
+            .expect_err("opted out recipient should be refused");
+
+        assert!(matches!(err, UsecaseError::RecipientOptedOut));
+        assert!(
+            message_repository
+                .created_messages
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn schedules_message_and_delays_its_delivery_job() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = CreateMessageUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversation: build_conversation(
+                    user_id,
+                    conversation_id,
+                    phone_number_id,
+                    Some("+14155551234".to_owned()),
+                ),
+                updated_conversation: Mutex::new(None),
+            }))
+            .message_repository(Arc::new(FakeMessageRepository {
+                created_messages: Mutex::new(Vec::new()),
+            }))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: build_phone_number(user_id, phone_number_id),
+            }))
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: true,
+            }))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository::default()))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository::default()))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+        let scheduled_at = (OffsetDateTime::now_utc() + Duration::from_secs(60 * 60))
+            .to_offset(time::UtcOffset::from_hms(-5, 0, 0).expect("offset"));
+
+        let result = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: "Happy birthday!".to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: Some(scheduled_at),
+                template_id: None,
+            })
+            .await
+            .expect("message should be scheduled");
+
+        assert_eq!(result.message.status, MessageStatus::Scheduled);
+        assert_eq!(result.message.scheduled_at, Some(scheduled_at));
+        assert!(
+            result
+                .message
+                .scheduled_at
+                .is_some_and(|at| at.offset().is_utc())
+        );
+        {
+            let jobs = outbound_message_queue_repository.jobs.lock().expect("lock");
+            assert_eq!(jobs.len(), 1);
+            assert_eq!(jobs[0].next_attempt_at, scheduled_at);
+        }
+
+        let err = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: "Too late".to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: Some(OffsetDateTime::now_utc() - Duration::from_secs(60)),
+                template_id: None,
+            })
+            .await
+            .expect_err("past schedule should be rejected");
+        assert!(matches!(err, UsecaseError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn renders_template_with_recipient_contact_details() {
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        let greeting = MessageTemplate::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .name("Greeting".to_owned())
+            .body("Hi {{first_name}}, this is {{ sender_name }}.".to_owned())
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let follow_up = MessageTemplate::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .name("Follow up".to_owned())
+            .body("Your order {{order_id}} has shipped.".to_owned())
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let contact = Contact::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(ORGANIZATION_ID)
+            .name("Ada Lovelace".to_owned())
+            .phone_numbers(vec!["+14155551234".to_owned()])
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let message_repository = Arc::new(FakeMessageRepository {
+            created_messages: Mutex::new(Vec::new()),
+        });
+        let usecase = CreateMessageUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversation: build_conversation(
+                    user_id,
+                    conversation_id,
+                    phone_number_id,
+                    Some("+14155551234".to_owned()),
+                ),
+                updated_conversation: Mutex::new(None),
+            }))
+            .message_repository(message_repository.clone())
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: build_phone_number(user_id, phone_number_id),
+            }))
+            .user_repository(Arc::new(FakeUserRepository {
+                email_verified: true,
+            }))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository {
+                templates: vec![greeting.clone(), follow_up.clone()],
+            }))
+            .contact_repository(Arc::new(FakeContactRepository {
+                contacts: vec![contact],
+            }))
+            .usage_repository(Arc::new(FakeUsageRepository::default()))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let result = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: String::new(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: Some(greeting.id),
+            })
+            .await
+            .expect("template message should be created");
+
+        assert_eq!(result.message.content, "Hi Ada, this is Primary.");
+
+        let err = usecase
+            .execute(CreateMessageCommand {
+                user_id,
+                organization_id: ORGANIZATION_ID,
+                conversation_id,
+                content: String::new(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: Some(follow_up.id),
+            })
+            .await
+            .expect_err("unresolved placeholders should be rejected");
+
+        assert!(matches!(err, UsecaseError::Validation(_)));
+        assert_eq!(
+            message_repository
+                .created_messages
+                .lock()
+                .expect("lock")
+                .len(),
+            1
+        );
+    }
 }
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{commands::CreateOrganizationCommand, usecases::UsecaseError};
+use domain::{
+    models::organization::{Organization, OrganizationMember, OrganizationRole},
+    repositories::organization_repository::OrganizationRepository,
+};
+
+/// Creates an organization with the requesting user as its first member.
+#[derive(bon::Builder)]
+pub struct CreateOrganizationUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl CreateOrganizationUsecase {
+    pub async fn execute(
+        &self,
+        cmd: CreateOrganizationCommand,
+    ) -> Result<Organization, UsecaseError> {
+        let cmd = CreateOrganizationCommand {
+            name: cmd.name.trim().to_owned(),
+            ..cmd
+        };
+        cmd.validate()?;
+
+        let now = OffsetDateTime::now_utc();
+        let organization = Organization::builder()
+            .id(uuid::Uuid::now_v7())
+            .name(cmd.name)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let owner = OrganizationMember::builder()
+            .organization_id(organization.id)
+            .user_id(cmd.user_id)
+            .role(OrganizationRole::Owner)
+            .created_at(now)
+            .build();
+        self.organization_repository
+            .create_organization(&organization, &owner)
+            .await?;
+
+        Ok(organization)
+    }
+}
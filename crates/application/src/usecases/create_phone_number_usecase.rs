@@ -3,15 +3,22 @@ use std::sync::Arc;
 use time::OffsetDateTime;
 
 use crate::{
-    commands::CreatePhoneNumberCommand, responses::CreatePhoneNumberResult, usecases::UsecaseError,
+    commands::CreatePhoneNumberCommand,
+    responses::CreatePhoneNumberResult,
+    usecases::{UsecaseError, authorize_member},
 };
 use domain::{
-    models::phone_number::PhoneNumber, repositories::phone_number_repository::PhoneNumberRepository,
+    models::{organization::Permission, phone_number::PhoneNumber},
+    repositories::{
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
 };
 
 #[derive(bon::Builder)]
 pub struct CreatePhoneNumberUsecase {
     phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
 }
 
 impl CreatePhoneNumberUsecase {
@@ -19,10 +26,19 @@ impl CreatePhoneNumberUsecase {
         &self,
         cmd: CreatePhoneNumberCommand,
     ) -> Result<CreatePhoneNumberResult, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::ManagePhoneNumbers,
+        )
+        .await?;
+
         let now = OffsetDateTime::now_utc();
         let phone_number_id = uuid::Uuid::now_v7();
         let phone_number = PhoneNumber::builder()
             .id(phone_number_id)
+            .organization_id(cmd.organization_id)
             .user_id(cmd.user_id)
             .name(cmd.name)
             .phone(cmd.phone)
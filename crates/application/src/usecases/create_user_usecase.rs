@@ -5,7 +5,9 @@ use uuid::Uuid;
 
 use crate::{commands::SignupCommand, responses::SignupResult, usecases::UsecaseError};
 use domain::{
-    repositories::user_repository::UserRepository, traits::password_hasher::PasswordHasher,
+    models::organization::{Organization, OrganizationMember, OrganizationRole},
+    repositories::user_repository::UserRepository,
+    traits::password_hasher::PasswordHasher,
 };
 
 #[derive(bon::Builder)]
@@ -35,7 +37,23 @@ impl CreateUserUsecase {
             .updated_at(now)
             .build();
 
-        self.user_repository.create_user(&user).await?;
+        // Numbers and conversations belong to organizations, so every account starts with a
+        // personal one.
+        let organization = Organization::builder()
+            .id(Uuid::now_v7())
+            .name(user.email.clone())
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let owner = OrganizationMember::builder()
+            .organization_id(organization.id)
+            .user_id(user.id)
+            .role(OrganizationRole::Owner)
+            .created_at(now)
+            .build();
+        self.user_repository
+            .create_user_with_organization(&user, &organization, &owner)
+            .await?;
 
         Ok(SignupResult {
             id: user_id,
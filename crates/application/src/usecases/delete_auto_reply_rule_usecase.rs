@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::organization::Permission,
+    repositories::{
+        auto_reply_rule_repository::AutoReplyRuleRepository,
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct DeleteAutoReplyRuleUsecase {
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl DeleteAutoReplyRuleUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
+        phone_number_id: uuid::Uuid,
+        rule_id: uuid::Uuid,
+    ) -> Result<(), UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::ManagePhoneNumbers,
+        )
+        .await?;
+
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&organization_id, &phone_number_id)
+            .await?;
+        let rule = self
+            .auto_reply_rule_repository
+            .find_by_id(&phone_number.organization_id, &rule_id)
+            .await?;
+        if rule.phone_number_id != phone_number.id {
+            return Err(UsecaseError::EntityNotFound);
+        }
+
+        self.auto_reply_rule_repository
+            .delete_rule(&phone_number.organization_id, &rule_id)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::organization::Permission,
+    repositories::{
+        business_hours_repository::BusinessHoursRepository,
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct DeleteBusinessHoursUsecase {
+    business_hours_repository: Arc<dyn BusinessHoursRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl DeleteBusinessHoursUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
+        phone_number_id: uuid::Uuid,
+    ) -> Result<(), UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::ManagePhoneNumbers,
+        )
+        .await?;
+
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&organization_id, &phone_number_id)
+            .await?;
+        self.business_hours_repository
+            .delete_business_hours(&phone_number.organization_id, &phone_number.id)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::organization::Permission,
+    repositories::{
+        contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct DeleteContactUsecase {
+    contact_repository: Arc<dyn ContactRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl DeleteContactUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
+        contact_id: uuid::Uuid,
+    ) -> Result<(), UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::ManageContacts,
+        )
+        .await?;
+
+        self.contact_repository
+            .delete_contact(&organization_id, &contact_id)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,21 +1,37 @@
 use std::sync::Arc;
 
-use crate::usecases::UsecaseError;
-use domain::repositories::conversation_repository::ConversationRepository;
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::organization::Permission,
+    repositories::{
+        conversation_repository::ConversationRepository,
+        organization_repository::OrganizationRepository,
+    },
+};
 
 #[derive(bon::Builder)]
 pub struct DeleteConversationUsecase {
     conversation_repository: Arc<dyn ConversationRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
 }
 
 impl DeleteConversationUsecase {
     pub async fn execute(
         &self,
         user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
         conversation_id: uuid::Uuid,
     ) -> Result<(), UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::DeleteConversations,
+        )
+        .await?;
+
         self.conversation_repository
-            .delete_conversation(&user_id, &conversation_id)
+            .delete_conversation(&organization_id, &conversation_id)
             .await?;
 
         Ok(())
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::repositories::message_template_repository::MessageTemplateRepository;
+
+#[derive(bon::Builder)]
+pub struct DeleteMessageTemplateUsecase {
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+}
+
+impl DeleteMessageTemplateUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        template_id: uuid::Uuid,
+    ) -> Result<(), UsecaseError> {
+        self.message_template_repository
+            .delete_template(&user_id, &template_id)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,21 +1,37 @@
 use std::sync::Arc;
 
-use crate::usecases::UsecaseError;
-use domain::repositories::phone_number_repository::PhoneNumberRepository;
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::organization::Permission,
+    repositories::{
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
 
 #[derive(bon::Builder)]
 pub struct DeletePhoneNumberUsecase {
     phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
 }
 
 impl DeletePhoneNumberUsecase {
     pub async fn execute(
         &self,
         user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
         phone_number_id: uuid::Uuid,
     ) -> Result<(), UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::ManagePhoneNumbers,
+        )
+        .await?;
+
         self.phone_number_repository
-            .delete_phone_number(&user_id, &phone_number_id)
+            .delete_phone_number(&organization_id, &phone_number_id)
             .await?;
 
         Ok(())
@@ -0,0 +1,1450 @@
+use std::{sync::Arc, time::Duration};
+
+use time::OffsetDateTime;
+
+use crate::usecases::{
+    UsecaseError, create_message_usecase::ensure_within_hard_limits, exponential_backoff,
+};
+use domain::{
+    models::{
+        conversation::Conversation,
+        message::{Message, MessageStatus},
+        message_status_event::MessageStatusEvent,
+        outbound_message_job::OutboundMessageJob,
+        sms_encoding::count_segments,
+        usage::UsageRecord,
+    },
+    repositories::{
+        RepositoryError, conversation_repository::ConversationRepository,
+        message_repository::MessageRepository,
+        message_status_event_repository::MessageStatusEventRepository,
+        opt_out_repository::OptOutRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+    },
+    traits::{
+        media_url_signer::MediaUrlSigner,
+        outbound_message_service::{
+            OutboundMessageService, SendMessageRequest, SendMessageResponse,
+        },
+    },
+};
+
+pub const OUTBOUND_DISPATCH_BATCH_SIZE: usize = 20;
+pub const MAX_OUTBOUND_DISPATCH_ATTEMPTS: i32 = 8;
+/// How long a claimed job stays invisible to other workers. Must comfortably exceed the
+/// provider request timeout, otherwise a slow send could be picked up and sent twice.
+const OUTBOUND_DISPATCH_LEASE: Duration = Duration::from_secs(2 * 60);
+const MEDIA_URL_TTL: Duration = Duration::from_secs(60 * 60 * 24);
+const BASE_RETRY_DELAY: Duration = Duration::from_secs(10);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone)]
+pub struct OutboundMessageNotification {
+    pub organization_id: uuid::Uuid,
+    pub message: Message,
+    pub conversation: Conversation,
+}
+
+#[derive(Debug, Default)]
+pub struct DispatchOutboundMessagesResult {
+    /// Messages whose status changed during this run.
+    pub notifications: Vec<OutboundMessageNotification>,
+    /// Jobs that could not be processed. They are retried once their lease expires.
+    pub failures: Vec<(uuid::Uuid, UsecaseError)>,
+    /// Sent messages that could not be added to the usage ledger.
+    pub usage_failures: Vec<(uuid::Uuid, UsecaseError)>,
+}
+
+/// Hands queued outbound messages to the provider. Transient provider outages are retried
+/// with exponential backoff, rejections and exhausted retries mark the message `Failed`.
+/// Scheduled messages are released to `Pending` once due, unless the recipient opted out
+/// in the meantime. Messages whose sender reached a hard spending limit after they were
+/// queued or scheduled are marked `Failed` instead of sent. Sent messages are added to the
+/// usage ledger, costed at `segment_price_micros` per segment until Telnyx reports the
+/// actual cost.
+#[derive(bon::Builder)]
+pub struct DispatchOutboundMessagesUsecase {
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    message_repository: Arc<dyn MessageRepository>,
+    message_status_event_repository: Arc<dyn MessageStatusEventRepository>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    outbound_message_service: Arc<dyn OutboundMessageService>,
+    media_url_signer: Arc<dyn MediaUrlSigner>,
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    segment_price_micros: Option<i64>,
+    #[builder(default = OUTBOUND_DISPATCH_BATCH_SIZE)]
+    batch_size: usize,
+    #[builder(default = MAX_OUTBOUND_DISPATCH_ATTEMPTS)]
+    max_attempts: i32,
+}
+
+impl DispatchOutboundMessagesUsecase {
+    pub async fn execute(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<DispatchOutboundMessagesResult, UsecaseError> {
+        let jobs = self
+            .outbound_message_queue_repository
+            .claim_due(now, now + OUTBOUND_DISPATCH_LEASE, self.batch_size)
+            .await?;
+
+        let mut result = DispatchOutboundMessagesResult::default();
+        for job in jobs {
+            match self.dispatch(&job, now).await {
+                Ok(Some(message)) => {
+                    let conversation = match self
+                        .conversation_repository
+                        .find_by_id(&message.organization_id, &message.conversation_id)
+                        .await
+                    {
+                        Ok(conversation) => conversation,
+                        Err(err) => {
+                            if message.status == MessageStatus::Queued {
+                                result.usage_failures.push((message.id, err.into()));
+                            }
+                            continue;
+                        }
+                    };
+                    // The message is already with the provider, so a ledger error must not
+                    // fail the job and have it sent again.
+                    if message.status == MessageStatus::Queued
+                        && let Err(err) = self.record_usage(&message, &conversation, now).await
+                    {
+                        result.usage_failures.push((message.id, err));
+                    }
+
+                    result.notifications.push(OutboundMessageNotification {
+                        organization_id: message.organization_id,
+                        message,
+                        conversation,
+                    });
+                }
+                Ok(None) => {}
+                Err(err) => result.failures.push((job.message_id, err)),
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn dispatch(
+        &self,
+        job: &OutboundMessageJob,
+        now: OffsetDateTime,
+    ) -> Result<Option<Message>, UsecaseError> {
+        let mut message = match self.message_repository.find_by_id(&job.message_id).await {
+            Ok(message) => message,
+            Err(RepositoryError::NotFound) => {
+                self.outbound_message_queue_repository
+                    .complete(&job.message_id)
+                    .await?;
+                return Ok(None);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        if message.status == MessageStatus::Scheduled {
+            if let Some(scheduled_at) = message.scheduled_at.filter(|at| *at > now) {
+                // Rescheduled to a later time after this job was claimed.
+                self.outbound_message_queue_repository
+                    .postpone(&job.message_id, scheduled_at)
+                    .await?;
+                return Ok(None);
+            }
+
+            if !self
+                .message_repository
+                .release_scheduled_message(&message.id, now)
+                .await?
+            {
+                // Cancelled or rescheduled meanwhile, which removed or postponed this job.
+                return Ok(None);
+            }
+            message.transition_to(MessageStatus::Pending)?;
+            message.updated_at = now;
+
+            if self.is_opted_out(&message, job).await? {
+                return self
+                    .fail(
+                        message,
+                        "Recipient opted out before the scheduled time".to_owned(),
+                        now,
+                    )
+                    .await
+                    .map(Some);
+            }
+        }
+
+        // A previous worker sent it but stopped before completing the job.
+        if message.status != MessageStatus::Pending {
+            self.outbound_message_queue_repository
+                .complete(&job.message_id)
+                .await?;
+            return Ok(None);
+        }
+
+        // Checked again here because the limit may have been reached since the message was
+        // created, e.g. while it waited for its scheduled time or behind other jobs.
+        match ensure_within_hard_limits(
+            &self.usage_limit_repository,
+            &self.usage_repository,
+            &message.user_id,
+            now,
+        )
+        .await
+        {
+            Ok(()) => {}
+            Err(UsecaseError::UsageLimitReached { period }) => {
+                let reason = format!(
+                    "Not sent because the {} spending limit was reached",
+                    period.name()
+                );
+                return self.fail(message, reason, now).await.map(Some);
+            }
+            Err(err) => return Err(err),
+        }
+
+        let response = match self.send(&message, job).await {
+            Ok(response) => response,
+            Err(UsecaseError::MessageRejected(reason)) => {
+                return self.fail(message, reason, now).await.map(Some);
+            }
+            Err(err) if job.attempts >= self.max_attempts => {
+                return self.fail(message, err.to_string(), now).await.map(Some);
+            }
+            Err(err) => {
+                self.outbound_message_queue_repository
+                    .reschedule(
+                        &job.message_id,
+                        now + retry_delay(job.attempts),
+                        &err.to_string(),
+                    )
+                    .await?;
+                return Ok(None);
+            }
+        };
+
+        message.transition_to(MessageStatus::Queued)?;
+        message.provider_message_id = Some(response.provider_message_id);
+        message.provider_status = Some("queued".to_owned());
+        message.updated_at = now;
+        let message = self.message_repository.update_message(&message).await?;
+        self.record_status_event(&message, now).await?;
+
+        self.outbound_message_queue_repository
+            .complete(&job.message_id)
+            .await?;
+
+        Ok(Some(message))
+    }
+
+    async fn record_usage(
+        &self,
+        message: &Message,
+        conversation: &Conversation,
+        now: OffsetDateTime,
+    ) -> Result<(), UsecaseError> {
+        let segments = message
+            .segments
+            .unwrap_or_else(|| count_segments(&message.content).segments as i32);
+        let cost_micros = message.cost_micros.or_else(|| {
+            self.segment_price_micros
+                .map(|price| price * i64::from(segments))
+        });
+        let record = UsageRecord::builder()
+            .message_id(message.id)
+            .user_id(message.user_id)
+            .phone_number_id(conversation.phone_number_id)
+            .segments(segments)
+            .cost_micros(cost_micros.unwrap_or_default())
+            .cost_estimated(message.cost_micros.is_none())
+            .recorded_at(now)
+            .build();
+
+        self.usage_repository.record_usage(&record).await?;
+        Ok(())
+    }
+
+    async fn is_opted_out(
+        &self,
+        message: &Message,
+        job: &OutboundMessageJob,
+    ) -> Result<bool, UsecaseError> {
+        let conversation = self
+            .conversation_repository
+            .find_by_id(&message.organization_id, &message.conversation_id)
+            .await?;
+
+        Ok(self
+            .opt_out_repository
+            .is_opted_out(&conversation.phone_number_id, &job.to_number)
+            .await?)
+    }
+
+    async fn send(
+        &self,
+        message: &Message,
+        job: &OutboundMessageJob,
+    ) -> Result<SendMessageResponse, UsecaseError> {
+        let media_urls = message
+            .attachments
+            .iter()
+            .map(|attachment| {
+                self.media_url_signer
+                    .signed_url(&attachment.id, MEDIA_URL_TTL)
+                    .map_err(|_| UsecaseError::TokenGenerationFailed)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let response = self
+            .outbound_message_service
+            .send_text_message(SendMessageRequest {
+                from: message.from_number.to_owned(),
+                to: job.to_number.to_owned(),
+                text: message.content.to_owned(),
+                media_urls,
+            })
+            .await?;
+
+        Ok(response)
+    }
+
+    async fn fail(
+        &self,
+        mut message: Message,
+        reason: String,
+        now: OffsetDateTime,
+    ) -> Result<Message, UsecaseError> {
+        message.transition_to(MessageStatus::Failed)?;
+        message.provider_error_detail = Some(reason);
+        message.updated_at = now;
+        let message = self.message_repository.update_message(&message).await?;
+        self.record_status_event(&message, now).await?;
+
+        self.outbound_message_queue_repository
+            .complete(&message.id)
+            .await?;
+
+        Ok(message)
+    }
+
+    async fn record_status_event(
+        &self,
+        message: &Message,
+        now: OffsetDateTime,
+    ) -> Result<(), UsecaseError> {
+        let event = MessageStatusEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .message_id(message.id)
+            .user_id(message.user_id)
+            .status(message.status)
+            .maybe_provider_status(message.provider_status.clone())
+            .maybe_error_detail(message.provider_error_detail.clone())
+            .occurred_at(now)
+            .created_at(now)
+            .build();
+
+        self.message_status_event_repository.record(&event).await?;
+
+        Ok(())
+    }
+}
+
+fn retry_delay(attempts: i32) -> Duration {
+    exponential_backoff(attempts, BASE_RETRY_DELAY, MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            conversation::{
+                AssigneeLoad, Conversation, ConversationFilter, ConversationStateChange,
+                ConversationView, UnreadCount,
+            },
+            message::{Message, MessageStatus, MessageType},
+            message_attachment::MessageAttachment,
+            message_status_event::MessageStatusEvent,
+            opt_out::OptOut,
+            outbound_message_job::OutboundMessageJob,
+            usage::{UsageLimit, UsagePeriod, UsageRecord, UsageRollup, UsageTotals},
+        },
+        repositories::{
+            RepositoryError,
+            conversation_repository::ConversationRepository,
+            message_repository::{MessagePage, MessageRepository},
+            message_status_event_repository::MessageStatusEventRepository,
+            opt_out_repository::OptOutRepository,
+            outbound_message_queue_repository::OutboundMessageQueueRepository,
+            usage_limit_repository::UsageLimitRepository,
+            usage_repository::UsageRepository,
+        },
+        traits::{
+            media_url_signer::MediaUrlSigner,
+            outbound_message_service::{
+                OutboundMessageError, OutboundMessageService, SendMessageRequest,
+                SendMessageResponse,
+            },
+            token_service::TokenServiceError,
+        },
+    };
+    use time::OffsetDateTime;
+
+    use super::{DispatchOutboundMessagesUsecase, retry_delay};
+
+    #[derive(Default)]
+    struct FakeOutboundMessageQueueRepository {
+        jobs: Mutex<Vec<OutboundMessageJob>>,
+        rescheduled: Mutex<Vec<(uuid::Uuid, OffsetDateTime, String)>>,
+        postponed: Mutex<Vec<(uuid::Uuid, OffsetDateTime)>>,
+        completed: Mutex<Vec<uuid::Uuid>>,
+    }
+
+    #[async_trait]
+    impl OutboundMessageQueueRepository for FakeOutboundMessageQueueRepository {
+        async fn enqueue(&self, job: &OutboundMessageJob) -> Result<(), RepositoryError> {
+            self.jobs.lock().expect("lock").push(job.clone());
+            Ok(())
+        }
+
+        async fn claim_due(
+            &self,
+            now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            limit: usize,
+        ) -> Result<Vec<OutboundMessageJob>, RepositoryError> {
+            let mut jobs = self.jobs.lock().expect("lock");
+            let (due, rest): (Vec<_>, Vec<_>) =
+                jobs.drain(..).partition(|job| job.next_attempt_at <= now);
+            *jobs = rest;
+
+            Ok(due
+                .into_iter()
+                .take(limit)
+                .map(|mut job| {
+                    job.attempts += 1;
+                    job
+                })
+                .collect())
+        }
+
+        async fn reschedule(
+            &self,
+            message_id: &uuid::Uuid,
+            next_attempt_at: OffsetDateTime,
+            last_error: &str,
+        ) -> Result<(), RepositoryError> {
+            self.rescheduled.lock().expect("lock").push((
+                *message_id,
+                next_attempt_at,
+                last_error.to_owned(),
+            ));
+            Ok(())
+        }
+
+        async fn postpone(
+            &self,
+            message_id: &uuid::Uuid,
+            next_attempt_at: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            self.postponed
+                .lock()
+                .expect("lock")
+                .push((*message_id, next_attempt_at));
+            Ok(())
+        }
+
+        async fn complete(&self, message_id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            self.completed.lock().expect("lock").push(*message_id);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeMessageRepository {
+        messages: Mutex<HashMap<uuid::Uuid, Message>>,
+    }
+
+    #[async_trait]
+    impl MessageRepository for FakeMessageRepository {
+        async fn create_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            self.messages
+                .lock()
+                .expect("lock")
+                .insert(message.id, message.clone());
+            Ok(message.clone())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+            self.messages
+                .lock()
+                .expect("lock")
+                .get(id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn count_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn find_by_provider_message_id(
+            &self,
+            _provider_message_id: &str,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_reply_to(
+            &self,
+            _message_id: &uuid::Uuid,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_page_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+            _cursor: Option<&uuid::Uuid>,
+            _limit: usize,
+        ) -> Result<MessagePage, RepositoryError> {
+            Ok(MessagePage {
+                messages: Vec::new(),
+                next_cursor: None,
+            })
+        }
+
+        async fn update_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            self.messages
+                .lock()
+                .expect("lock")
+                .insert(message.id, message.clone());
+            Ok(message.clone())
+        }
+
+        async fn list_scheduled_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+            _scheduled_at: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn delete_scheduled_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn release_scheduled_message(
+            &self,
+            id: &uuid::Uuid,
+            now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            let mut messages = self.messages.lock().expect("lock");
+            match messages.get_mut(id) {
+                Some(message)
+                    if message.status == MessageStatus::Scheduled
+                        && message.scheduled_at.is_some_and(|at| at <= now) =>
+                {
+                    message.status = MessageStatus::Pending;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn find_attachment_by_id(
+            &self,
+            _id: &uuid::Uuid,
+        ) -> Result<MessageAttachment, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeMessageStatusEventRepository {
+        events: Mutex<Vec<MessageStatusEvent>>,
+    }
+
+    #[async_trait]
+    impl MessageStatusEventRepository for FakeMessageStatusEventRepository {
+        async fn record(&self, event: &MessageStatusEvent) -> Result<(), RepositoryError> {
+            self.events.lock().expect("lock").push(event.clone());
+            Ok(())
+        }
+
+        async fn list_by_message_ids(
+            &self,
+            _user_id: &uuid::Uuid,
+            message_ids: &[uuid::Uuid],
+        ) -> Result<Vec<MessageStatusEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|event| message_ids.contains(&event.message_id))
+                .cloned()
+                .collect())
+        }
+    }
+
+    struct FakeConversationRepository {
+        conversation: Conversation,
+    }
+
+    #[async_trait]
+    impl ConversationRepository for FakeConversationRepository {
+        async fn create_conversation(
+            &self,
+            _conversation: &Conversation,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(self.conversation.clone())
+        }
+
+        async fn find_by_phone_number_and_recipient(
+            &self,
+            _user_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(self.conversation.clone())
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(vec![self.conversation.clone()])
+        }
+
+        async fn list_filtered(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _view: ConversationView,
+            _filter: ConversationFilter,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn change_state(
+            &self,
+            conversation: &Conversation,
+            _change: ConversationStateChange,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn record_activity(
+            &self,
+            conversation: &Conversation,
+            _last_message_at: OffsetDateTime,
+            _updated_at: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn reopen(&self, _conversation: &Conversation) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn assign(
+            &self,
+            conversation: &Conversation,
+            _assigned_user_id: Option<uuid::Uuid>,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn claim_assignment(
+            &self,
+            _conversation: &Conversation,
+            _assigned_user_id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+
+        async fn list_assignee_loads(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn mark_read(
+            &self,
+            _conversation: &Conversation,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_unread_counts(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UnreadCount>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn wake_snoozed(
+            &self,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_conversation(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_away_reply(
+            &self,
+            _conversation: &Conversation,
+            _closed_since: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeOptOutRepository {
+        opted_out: Mutex<Vec<(uuid::Uuid, String)>>,
+    }
+
+    #[async_trait]
+    impl OptOutRepository for FakeOptOutRepository {
+        async fn opt_out(&self, opt_out: &OptOut) -> Result<(), RepositoryError> {
+            self.opted_out.lock().expect("lock").push((
+                opt_out.phone_number_id,
+                opt_out.recipient_phone_number.to_owned(),
+            ));
+            Ok(())
+        }
+
+        async fn opt_in(
+            &self,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn is_opted_out(
+            &self,
+            phone_number_id: &uuid::Uuid,
+            recipient_phone_number: &str,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self
+                .opted_out
+                .lock()
+                .expect("lock")
+                .iter()
+                .any(|(id, recipient)| {
+                    id == phone_number_id && recipient == recipient_phone_number
+                }))
+        }
+    }
+
+    struct FakeOutboundMessageService {
+        response: Result<SendMessageResponse, OutboundMessageError>,
+        requests: Mutex<Vec<SendMessageRequest>>,
+    }
+
+    #[async_trait]
+    impl OutboundMessageService for FakeOutboundMessageService {
+        async fn send_text_message(
+            &self,
+            request: SendMessageRequest,
+        ) -> Result<SendMessageResponse, OutboundMessageError> {
+            self.requests.lock().expect("lock").push(request);
+            self.response.clone()
+        }
+    }
+
+    struct FakeMediaUrlSigner;
+
+    impl MediaUrlSigner for FakeMediaUrlSigner {
+        fn signed_path(
+            &self,
+            attachment_id: &uuid::Uuid,
+            _ttl: Duration,
+        ) -> Result<String, TokenServiceError> {
+            Ok(format!("/media/{}", attachment_id))
+        }
+
+        fn signed_url(
+            &self,
+            attachment_id: &uuid::Uuid,
+            _ttl: Duration,
+        ) -> Result<String, TokenServiceError> {
+            Ok(format!("https://example.com/media/{}", attachment_id))
+        }
+
+        fn verify(&self, _token: &str) -> Result<uuid::Uuid, TokenServiceError> {
+            Err(TokenServiceError::TokenInvalid)
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeUsageRepository {
+        records: Mutex<Vec<UsageRecord>>,
+    }
+
+    #[async_trait]
+    impl UsageRepository for FakeUsageRepository {
+        async fn record_usage(&self, record: &UsageRecord) -> Result<(), RepositoryError> {
+            self.records.lock().expect("lock").push(record.clone());
+            Ok(())
+        }
+
+        async fn record_cost(
+            &self,
+            _message_id: &uuid::Uuid,
+            _cost_micros: i64,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn totals_since(
+            &self,
+            user_id: &uuid::Uuid,
+            since: OffsetDateTime,
+        ) -> Result<UsageTotals, RepositoryError> {
+            let mut totals = UsageTotals::default();
+            for record in self.records.lock().expect("lock").iter() {
+                if record.user_id == *user_id && record.recorded_at >= since {
+                    totals.add(&UsageTotals {
+                        messages: 1,
+                        segments: i64::from(record.segments),
+                        cost_micros: record.cost_micros,
+                    });
+                }
+            }
+            Ok(totals)
+        }
+
+        async fn rollups(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _since: OffsetDateTime,
+        ) -> Result<Vec<UsageRollup>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeUsageLimitRepository {
+        limits: Mutex<Vec<UsageLimit>>,
+    }
+
+    #[async_trait]
+    impl UsageLimitRepository for FakeUsageLimitRepository {
+        async fn save_limit(&self, limit: &UsageLimit) -> Result<(), RepositoryError> {
+            self.limits.lock().expect("lock").push(limit.clone());
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            user_id: &uuid::Uuid,
+        ) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(self
+                .limits
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|limit| limit.user_id == *user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_limit(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_soft_limit_notification(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _period_start: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct Fixture {
+        queue: Arc<FakeOutboundMessageQueueRepository>,
+        messages: Arc<FakeMessageRepository>,
+        status_events: Arc<FakeMessageStatusEventRepository>,
+        opt_outs: Arc<FakeOptOutRepository>,
+        outbound_message_service: Arc<FakeOutboundMessageService>,
+        usage: Arc<FakeUsageRepository>,
+        usage_limits: Arc<FakeUsageLimitRepository>,
+        usecase: DispatchOutboundMessagesUsecase,
+        message: Message,
+        phone_number_id: uuid::Uuid,
+    }
+
+    async fn fixture(
+        response: Result<SendMessageResponse, OutboundMessageError>,
+        attempts: i32,
+    ) -> Fixture {
+        let now = OffsetDateTime::now_utc();
+        let user_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let message_id = uuid::Uuid::now_v7();
+        let attachment_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+
+        let message = Message::builder()
+            .id(message_id)
+            .conversation_id(conversation_id)
+            .organization_id(user_id)
+            .user_id(user_id)
+            .message_type(MessageType::Outbound)
+            .status(MessageStatus::Pending)
+            .from_number("+13125550100".to_owned())
+            .content("Hello".to_owned())
+            .created_at(now)
+            .updated_at(now)
+            .attachments(vec![
+                MessageAttachment::builder()
+                    .id(attachment_id)
+                    .message_id(message_id)
+                    .user_id(user_id)
+                    .file_name("photo.jpg".to_owned())
+                    .content_type("image/jpeg".to_owned())
+                    .size_bytes(3)
+                    .storage_key(format!("{}/{}/{}", user_id, message_id, attachment_id))
+                    .created_at(now)
+                    .build(),
+            ])
+            .build();
+
+        let queue = Arc::new(FakeOutboundMessageQueueRepository::default());
+        queue
+            .enqueue(
+                &OutboundMessageJob::builder()
+                    .message_id(message_id)
+                    .user_id(user_id)
+                    .to_number("+14155551234".to_owned())
+                    .attempts(attempts)
+                    .next_attempt_at(now)
+                    .created_at(now)
+                    .updated_at(now)
+                    .build(),
+            )
+            .await
+            .expect("enqueue");
+
+        let messages = Arc::new(FakeMessageRepository::default());
+        messages.create_message(&message).await.expect("create");
+
+        let status_events = Arc::new(FakeMessageStatusEventRepository::default());
+        let opt_outs = Arc::new(FakeOptOutRepository::default());
+        let outbound_message_service = Arc::new(FakeOutboundMessageService {
+            response,
+            requests: Mutex::new(Vec::new()),
+        });
+        let usage = Arc::new(FakeUsageRepository::default());
+        let usage_limits = Arc::new(FakeUsageLimitRepository::default());
+
+        let usecase = DispatchOutboundMessagesUsecase::builder()
+            .outbound_message_queue_repository(queue.clone())
+            .message_repository(messages.clone())
+            .message_status_event_repository(status_events.clone())
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversation: Conversation::builder()
+                    .id(conversation_id)
+                    .phone_number_id(phone_number_id)
+                    .organization_id(user_id)
+                    .user_id(user_id)
+                    .recipient_phone_number("+14155551234".to_owned())
+                    .last_message_at(now)
+                    .created_at(now)
+                    .updated_at(now)
+                    .build(),
+            }))
+            .opt_out_repository(opt_outs.clone())
+            .outbound_message_service(outbound_message_service.clone())
+            .media_url_signer(Arc::new(FakeMediaUrlSigner))
+            .usage_repository(usage.clone())
+            .usage_limit_repository(usage_limits.clone())
+            .segment_price_micros(7_500)
+            .max_attempts(3)
+            .build();
+
+        Fixture {
+            queue,
+            messages,
+            status_events,
+            opt_outs,
+            outbound_message_service,
+            usage,
+            usage_limits,
+            usecase,
+            message,
+            phone_number_id,
+        }
+    }
+
+    async fn schedule(fixture: &Fixture, scheduled_at: OffsetDateTime) {
+        let mut message = fixture.message.clone();
+        message.status = MessageStatus::Scheduled;
+        message.scheduled_at = Some(scheduled_at);
+        fixture
+            .messages
+            .update_message(&message)
+            .await
+            .expect("update");
+    }
+
+    /// Spends the sender's whole daily hard limit on an earlier message.
+    async fn reach_hard_limit(fixture: &Fixture, now: OffsetDateTime) {
+        let user_id = fixture.message.user_id;
+        fixture
+            .usage_limits
+            .save_limit(
+                &UsageLimit::builder()
+                    .user_id(user_id)
+                    .period(UsagePeriod::Daily)
+                    .hard_limit_micros(10_000)
+                    .created_at(now)
+                    .updated_at(now)
+                    .build(),
+            )
+            .await
+            .expect("save limit");
+        fixture
+            .usage
+            .record_usage(
+                &UsageRecord::builder()
+                    .message_id(uuid::Uuid::now_v7())
+                    .user_id(user_id)
+                    .phone_number_id(fixture.phone_number_id)
+                    .segments(2)
+                    .cost_micros(10_000)
+                    .cost_estimated(false)
+                    .recorded_at(now)
+                    .build(),
+            )
+            .await
+            .expect("record usage");
+    }
+
+    async fn assert_held_back_by_hard_limit(fixture: &Fixture, now: OffsetDateTime) {
+        let result = fixture
+            .usecase
+            .execute(now)
+            .await
+            .expect("dispatch should succeed");
+
+        assert_eq!(result.notifications.len(), 1);
+        let message = &result.notifications[0].message;
+        assert_eq!(message.status, MessageStatus::Failed);
+        assert_eq!(
+            message.provider_error_detail.as_deref(),
+            Some("Not sent because the daily spending limit was reached")
+        );
+        assert!(
+            fixture
+                .outbound_message_service
+                .requests
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert_eq!(
+            *fixture.queue.completed.lock().expect("lock"),
+            vec![fixture.message.id]
+        );
+        let history = fixture.status_events.events.lock().expect("lock");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, MessageStatus::Failed);
+        // Only the earlier message that used up the limit is in the ledger.
+        assert_eq!(fixture.usage.records.lock().expect("lock").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn sends_pending_message_and_marks_it_queued() {
+        let fixture = fixture(
+            Ok(SendMessageResponse {
+                provider_message_id: "provider-message-id".to_owned(),
+            }),
+            0,
+        )
+        .await;
+
+        let result = fixture
+            .usecase
+            .execute(OffsetDateTime::now_utc())
+            .await
+            .expect("dispatch should succeed");
+
+        assert!(result.failures.is_empty());
+        assert_eq!(result.notifications.len(), 1);
+        let message = &result.notifications[0].message;
+        assert_eq!(message.status, MessageStatus::Queued);
+        assert_eq!(
+            message.provider_message_id.as_deref(),
+            Some("provider-message-id")
+        );
+        assert_eq!(message.provider_status.as_deref(), Some("queued"));
+
+        let history = fixture.status_events.events.lock().expect("lock");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, MessageStatus::Queued);
+
+        let requests = fixture
+            .outbound_message_service
+            .requests
+            .lock()
+            .expect("lock");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].from, "+13125550100");
+        assert_eq!(requests[0].to, "+14155551234");
+        assert_eq!(requests[0].text, "Hello");
+        assert_eq!(
+            requests[0].media_urls,
+            vec![format!(
+                "https://example.com/media/{}",
+                fixture.message.attachments[0].id
+            )]
+        );
+        assert_eq!(
+            *fixture.queue.completed.lock().expect("lock"),
+            vec![fixture.message.id]
+        );
+
+        let records = fixture.usage.records.lock().expect("lock");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message_id, fixture.message.id);
+        assert_eq!(records[0].phone_number_id, fixture.phone_number_id);
+        assert_eq!(records[0].segments, 1);
+        assert_eq!(records[0].cost_micros, 7_500);
+        assert!(records[0].cost_estimated);
+    }
+
+    #[tokio::test]
+    async fn reschedules_with_backoff_when_provider_is_unavailable() {
+        let fixture = fixture(
+            Err(OutboundMessageError::Unavailable("timeout".to_owned())),
+            1,
+        )
+        .await;
+        let now = OffsetDateTime::now_utc();
+
+        let result = fixture
+            .usecase
+            .execute(now)
+            .await
+            .expect("dispatch should succeed");
+
+        assert!(result.notifications.is_empty());
+        let stored = fixture
+            .messages
+            .find_by_id(&fixture.message.id)
+            .await
+            .expect("message");
+        assert_eq!(stored.status, MessageStatus::Pending);
+
+        let rescheduled = fixture.queue.rescheduled.lock().expect("lock");
+        assert_eq!(rescheduled.len(), 1);
+        assert_eq!(rescheduled[0].0, fixture.message.id);
+        assert_eq!(rescheduled[0].1, now + Duration::from_secs(20));
+        assert!(rescheduled[0].2.contains("timeout"));
+        assert!(fixture.queue.completed.lock().expect("lock").is_empty());
+        assert!(
+            fixture
+                .status_events
+                .events
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn marks_message_failed_when_provider_rejects_it() {
+        let fixture = fixture(
+            Err(OutboundMessageError::Rejected("invalid number".to_owned())),
+            0,
+        )
+        .await;
+
+        let result = fixture
+            .usecase
+            .execute(OffsetDateTime::now_utc())
+            .await
+            .expect("dispatch should succeed");
+
+        assert_eq!(result.notifications.len(), 1);
+        let message = &result.notifications[0].message;
+        assert_eq!(message.status, MessageStatus::Failed);
+        assert_eq!(
+            message.provider_error_detail.as_deref(),
+            Some("invalid number")
+        );
+        assert!(fixture.queue.rescheduled.lock().expect("lock").is_empty());
+        assert_eq!(
+            *fixture.queue.completed.lock().expect("lock"),
+            vec![fixture.message.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn marks_message_failed_after_last_attempt() {
+        let fixture = fixture(
+            Err(OutboundMessageError::Unavailable("timeout".to_owned())),
+            2,
+        )
+        .await;
+
+        let result = fixture
+            .usecase
+            .execute(OffsetDateTime::now_utc())
+            .await
+            .expect("dispatch should succeed");
+
+        assert_eq!(result.notifications.len(), 1);
+        assert_eq!(
+            result.notifications[0].message.status,
+            MessageStatus::Failed
+        );
+        assert!(fixture.queue.rescheduled.lock().expect("lock").is_empty());
+    }
+
+    #[tokio::test]
+    async fn releases_due_scheduled_message_and_sends_it() {
+        let fixture = fixture(
+            Ok(SendMessageResponse {
+                provider_message_id: "provider-message-id".to_owned(),
+            }),
+            0,
+        )
+        .await;
+        let now = OffsetDateTime::now_utc();
+        schedule(&fixture, now - Duration::from_secs(1)).await;
+
+        let result = fixture
+            .usecase
+            .execute(now)
+            .await
+            .expect("dispatch should succeed");
+
+        assert!(result.failures.is_empty());
+        assert_eq!(result.notifications.len(), 1);
+        assert_eq!(
+            result.notifications[0].message.status,
+            MessageStatus::Queued
+        );
+        assert_eq!(
+            fixture
+                .outbound_message_service
+                .requests
+                .lock()
+                .expect("lock")
+                .len(),
+            1
+        );
+        assert_eq!(
+            *fixture.queue.completed.lock().expect("lock"),
+            vec![fixture.message.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn postpones_scheduled_message_moved_to_a_later_time() {
+        let fixture = fixture(
+            Ok(SendMessageResponse {
+                provider_message_id: "provider-message-id".to_owned(),
+            }),
+            0,
+        )
+        .await;
+        let now = OffsetDateTime::now_utc();
+        let later = now + Duration::from_secs(60 * 60);
+        schedule(&fixture, later).await;
+
+        let result = fixture
+            .usecase
+            .execute(now)
+            .await
+            .expect("dispatch should succeed");
+
+        assert!(result.notifications.is_empty());
+        assert!(
+            fixture
+                .outbound_message_service
+                .requests
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert_eq!(
+            *fixture.queue.postponed.lock().expect("lock"),
+            vec![(fixture.message.id, later)]
+        );
+        assert!(fixture.queue.completed.lock().expect("lock").is_empty());
+        let stored = fixture
+            .messages
+            .find_by_id(&fixture.message.id)
+            .await
+            .expect("message");
+        assert_eq!(stored.status, MessageStatus::Scheduled);
+    }
+
+    #[tokio::test]
+    async fn fails_scheduled_message_when_recipient_opted_out_meanwhile() {
+        let fixture = fixture(
+            Ok(SendMessageResponse {
+                provider_message_id: "provider-message-id".to_owned(),
+            }),
+            0,
+        )
+        .await;
+        let now = OffsetDateTime::now_utc();
+        schedule(&fixture, now - Duration::from_secs(1)).await;
+        fixture
+            .opt_outs
+            .opt_out(
+                &OptOut::builder()
+                    .id(uuid::Uuid::now_v7())
+                    .user_id(fixture.message.user_id)
+                    .phone_number_id(fixture.phone_number_id)
+                    .recipient_phone_number("+14155551234".to_owned())
+                    .keyword("STOP".to_owned())
+                    .created_at(now)
+                    .build(),
+            )
+            .await
+            .expect("opt out");
+
+        let result = fixture
+            .usecase
+            .execute(now)
+            .await
+            .expect("dispatch should succeed");
+
+        assert_eq!(result.notifications.len(), 1);
+        assert_eq!(
+            result.notifications[0].message.status,
+            MessageStatus::Failed
+        );
+        assert!(
+            fixture
+                .outbound_message_service
+                .requests
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert_eq!(
+            *fixture.queue.completed.lock().expect("lock"),
+            vec![fixture.message.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_queued_message_once_hard_limit_is_reached() {
+        let fixture = fixture(
+            Ok(SendMessageResponse {
+                provider_message_id: "provider-message-id".to_owned(),
+            }),
+            0,
+        )
+        .await;
+        let now = OffsetDateTime::now_utc();
+        reach_hard_limit(&fixture, now).await;
+
+        assert_held_back_by_hard_limit(&fixture, now).await;
+    }
+
+    #[tokio::test]
+    async fn fails_scheduled_message_when_hard_limit_was_reached_meanwhile() {
+        let fixture = fixture(
+            Ok(SendMessageResponse {
+                provider_message_id: "provider-message-id".to_owned(),
+            }),
+            0,
+        )
+        .await;
+        let now = OffsetDateTime::now_utc();
+        schedule(&fixture, now - Duration::from_secs(1)).await;
+        reach_hard_limit(&fixture, now).await;
+
+        assert_held_back_by_hard_limit(&fixture, now).await;
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        assert_eq!(retry_delay(1), Duration::from_secs(10));
+        assert_eq!(retry_delay(2), Duration::from_secs(20));
+        assert_eq!(retry_delay(4), Duration::from_secs(80));
+        assert_eq!(retry_delay(30), Duration::from_secs(30 * 60));
+    }
+}
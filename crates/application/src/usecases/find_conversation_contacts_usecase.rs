@@ -0,0 +1,189 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::{contact::Contact, conversation::Conversation},
+    repositories::contact_repository::ContactRepository,
+};
+
+/// Resolves conversation recipients to entries of the address book of the organization
+/// each conversation belongs to, so they can be shown by name.
+#[derive(bon::Builder)]
+pub struct FindConversationContactsUsecase {
+    contact_repository: Arc<dyn ContactRepository>,
+}
+
+impl FindConversationContactsUsecase {
+    /// Returns the matching contact keyed by the id of each conversation that has one.
+    pub async fn execute(
+        &self,
+        conversations: &[Conversation],
+    ) -> Result<HashMap<uuid::Uuid, Contact>, UsecaseError> {
+        let mut phone_numbers_by_organization = HashMap::<uuid::Uuid, Vec<String>>::new();
+        for conversation in conversations {
+            if let Some(phone_number) = &conversation.recipient_phone_number {
+                phone_numbers_by_organization
+                    .entry(conversation.organization_id)
+                    .or_default()
+                    .push(phone_number.to_owned());
+            }
+        }
+
+        let mut contacts_by_number = HashMap::<(uuid::Uuid, String), Contact>::new();
+        for (organization_id, mut phone_numbers) in phone_numbers_by_organization {
+            phone_numbers.sort();
+            phone_numbers.dedup();
+
+            let contacts = self
+                .contact_repository
+                .find_by_phone_numbers(&organization_id, &phone_numbers)
+                .await?;
+            for contact in contacts {
+                for phone_number in &contact.phone_numbers {
+                    if phone_numbers.binary_search(phone_number).is_ok() {
+                        contacts_by_number
+                            .insert((organization_id, phone_number.to_owned()), contact.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(conversations
+            .iter()
+            .filter_map(|conversation| {
+                let phone_number = conversation.recipient_phone_number.clone()?;
+                contacts_by_number
+                    .get(&(conversation.organization_id, phone_number))
+                    .map(|contact| (conversation.id, contact.clone()))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{contact::Contact, conversation::Conversation},
+        repositories::{RepositoryError, contact_repository::ContactRepository},
+    };
+    use time::OffsetDateTime;
+
+    use super::FindConversationContactsUsecase;
+
+    struct FakeContactRepository {
+        contacts: Vec<Contact>,
+    }
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_by_phone_numbers(
+            &self,
+            organization_id: &uuid::Uuid,
+            phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self
+                .contacts
+                .iter()
+                .filter(|contact| {
+                    contact.organization_id == *organization_id
+                        && contact
+                            .phone_numbers
+                            .iter()
+                            .any(|number| phone_numbers.contains(number))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn contact(organization_id: uuid::Uuid, name: &str, phone_number: &str) -> Contact {
+        let now = OffsetDateTime::now_utc();
+        Contact::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(uuid::Uuid::now_v7())
+            .organization_id(organization_id)
+            .name(name.to_owned())
+            .phone_numbers(vec![phone_number.to_owned()])
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    fn conversation(organization_id: uuid::Uuid, recipient_phone_number: &str) -> Conversation {
+        let now = OffsetDateTime::now_utc();
+        Conversation::builder()
+            .id(uuid::Uuid::now_v7())
+            .phone_number_id(uuid::Uuid::now_v7())
+            .organization_id(organization_id)
+            .user_id(uuid::Uuid::now_v7())
+            .recipient_phone_number(recipient_phone_number.to_owned())
+            .last_message_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn names_recipients_from_the_address_book_of_their_organization() {
+        let support = uuid::Uuid::now_v7();
+        let sales = uuid::Uuid::now_v7();
+        let usecase = FindConversationContactsUsecase::builder()
+            .contact_repository(Arc::new(FakeContactRepository {
+                contacts: vec![
+                    contact(support, "Ada Lovelace", "+15550001111"),
+                    contact(sales, "Ada (Sales)", "+15550001111"),
+                ],
+            }))
+            .build();
+        let support_conversation = conversation(support, "+15550001111");
+        let sales_conversation = conversation(sales, "+15550001111");
+        let unknown_conversation = conversation(support, "+15550002222");
+
+        let contacts = usecase
+            .execute(&[
+                support_conversation.clone(),
+                sales_conversation.clone(),
+                unknown_conversation.clone(),
+            ])
+            .await
+            .expect("contacts should resolve");
+
+        assert_eq!(contacts[&support_conversation.id].name, "Ada Lovelace");
+        assert_eq!(contacts[&sales_conversation.id].name, "Ada (Sales)");
+        assert!(!contacts.contains_key(&unknown_conversation.id));
+    }
+}
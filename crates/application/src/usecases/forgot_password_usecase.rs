@@ -0,0 +1,345 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{commands::ForgotPasswordCommand, usecases::UsecaseError};
+use domain::{
+    models::reset_password::ResetPassword,
+    repositories::{
+        RepositoryError, reset_password_repository::ResetPasswordRepository,
+        user_repository::UserRepository,
+    },
+    traits::{
+        email_sender::{EmailMessage, EmailSender},
+        token_service::{PasetoClaimPurpose, PasetoClaims, TokenService},
+    },
+};
+
+/// How long a password reset link stays valid after it is issued.
+pub const PASSWORD_RESET_TOKEN_TTL: Duration = Duration::from_secs(30 * 60);
+
+#[derive(bon::Builder)]
+pub struct ForgotPasswordUsecase {
+    user_repository: Arc<dyn UserRepository>,
+    reset_password_repository: Arc<dyn ResetPasswordRepository>,
+    token_service: Arc<dyn TokenService>,
+    email_sender: Arc<dyn EmailSender>,
+    /// Absolute base URL used to build the link in the email, e.g. `https://app.example.com`.
+    public_base_url: String,
+}
+
+impl ForgotPasswordUsecase {
+    /// Issues a reset link for the account. Unknown emails succeed silently so the
+    /// endpoint cannot be used to discover which addresses have accounts.
+    pub async fn execute(&self, cmd: ForgotPasswordCommand) -> Result<(), UsecaseError> {
+        cmd.validate()?;
+
+        let user = match self.user_repository.find_by_email(cmd.email.trim()).await {
+            Ok(user) => user,
+            Err(RepositoryError::NotFound) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let claims = PasetoClaims::new(
+            user.id,
+            user.email.clone(),
+            PASSWORD_RESET_TOKEN_TTL,
+            PasetoClaimPurpose::PasswordReset,
+        );
+        let (token, _) = self
+            .token_service
+            .generate_token(claims, PASSWORD_RESET_TOKEN_TTL)
+            .map_err(|_| UsecaseError::TokenGenerationFailed)?;
+
+        let now = OffsetDateTime::now_utc();
+        let reset_password = ResetPassword::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user.id)
+            .token(token.clone())
+            .consumed(false)
+            .maybe_consumed_at(None)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        self.reset_password_repository
+            .create_reset_password(&reset_password)
+            .await?;
+
+        let reset_url = format!(
+            "{}/auth/reset-password?token={}",
+            self.public_base_url.trim_end_matches('/'),
+            token
+        );
+        let email = EmailMessage {
+            to: user.email,
+            subject: "Reset your password".to_owned(),
+            text_body: format!(
+                "We received a request to reset the password for your account.\n\n\
+                 Open the link below to choose a new password. It expires in {} minutes \
+                 and can only be used once.\n\n{}\n\n\
+                 If you did not request a reset, you can ignore this email.\n",
+                PASSWORD_RESET_TOKEN_TTL.as_secs() / 60,
+                reset_url
+            ),
+        };
+
+        if let Err(err) = self.email_sender.send(&email).await {
+            let _ = self
+                .reset_password_repository
+                .consume(&reset_password.id)
+                .await;
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            organization::{Organization, OrganizationMember},
+            reset_password::ResetPassword,
+            user::User,
+        },
+        repositories::{
+            RepositoryError, reset_password_repository::ResetPasswordRepository,
+            user_repository::UserRepository,
+        },
+        traits::{
+            email_sender::{EmailMessage, EmailSender, EmailSenderError},
+            token_service::{PasetoClaimPurpose, PasetoClaims, TokenService, TokenServiceError},
+        },
+    };
+    use time::{OffsetDateTime, UtcDateTime};
+
+    use crate::commands::ForgotPasswordCommand;
+
+    use super::ForgotPasswordUsecase;
+
+    const TOKEN: &str = "reset-token-reset-token-reset-token";
+
+    struct FakeUserRepository {
+        user_id: uuid::Uuid,
+        email: &'static str,
+    }
+
+    impl FakeUserRepository {
+        fn user(&self) -> User {
+            let now = OffsetDateTime::now_utc();
+            User::builder()
+                .id(self.user_id)
+                .email(self.email.to_owned())
+                .hash(String::new())
+                .salt(String::new())
+                .email_verified(true)
+                .created_at(now)
+                .updated_at(now)
+                .build()
+        }
+    }
+
+    #[async_trait]
+    impl UserRepository for FakeUserRepository {
+        async fn create_user(&self, _user: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn create_user_with_organization(
+            &self,
+            _user: &User,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<User, RepositoryError> {
+            if *id == self.user_id {
+                Ok(self.user())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        }
+
+        async fn find_by_email(&self, email: &str) -> Result<User, RepositoryError> {
+            if email == self.email {
+                Ok(self.user())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        }
+
+        async fn list(&self, _page: u64, _limit: u64) -> Result<Vec<User>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_user(&self, _new_data: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete_user(&self, _id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeResetPasswordRepository {
+        reset_passwords: Mutex<Vec<ResetPassword>>,
+    }
+
+    #[async_trait]
+    impl ResetPasswordRepository for FakeResetPasswordRepository {
+        async fn create_reset_password(
+            &self,
+            reset_password: &ResetPassword,
+        ) -> Result<(), RepositoryError> {
+            self.reset_passwords
+                .lock()
+                .expect("lock")
+                .push(reset_password.clone());
+            Ok(())
+        }
+
+        async fn find_by_token(&self, _token: &str) -> Result<ResetPassword, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn consume(&self, _id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn consume_all_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeTokenService;
+
+    impl TokenService for FakeTokenService {
+        fn generate_token(
+            &self,
+            claims: PasetoClaims,
+            expiration: Duration,
+        ) -> Result<(String, UtcDateTime), TokenServiceError> {
+            assert_eq!(claims.purpose, PasetoClaimPurpose::PasswordReset);
+            Ok((TOKEN.to_owned(), UtcDateTime::now() + expiration))
+        }
+
+        fn validate_token(
+            &self,
+            _token: String,
+            _purpose: PasetoClaimPurpose,
+        ) -> Result<PasetoClaims, TokenServiceError> {
+            Err(TokenServiceError::TokenInvalid)
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeEmailSender {
+        emails: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl EmailSender for FakeEmailSender {
+        async fn send(&self, email: &EmailMessage) -> Result<(), EmailSenderError> {
+            self.emails.lock().expect("lock").push(email.clone());
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        user_id: uuid::Uuid,
+        reset_passwords: Arc<FakeResetPasswordRepository>,
+        email_sender: Arc<FakeEmailSender>,
+        usecase: ForgotPasswordUsecase,
+    }
+
+    fn fixture() -> Fixture {
+        let user_id = uuid::Uuid::now_v7();
+        let reset_passwords = Arc::new(FakeResetPasswordRepository::default());
+        let email_sender = Arc::new(FakeEmailSender::default());
+
+        Fixture {
+            user_id,
+            reset_passwords: reset_passwords.clone(),
+            email_sender: email_sender.clone(),
+            usecase: ForgotPasswordUsecase::builder()
+                .user_repository(Arc::new(FakeUserRepository {
+                    user_id,
+                    email: "ada@example.com",
+                }))
+                .reset_password_repository(reset_passwords)
+                .token_service(Arc::new(FakeTokenService))
+                .email_sender(email_sender)
+                .public_base_url("https://app.example.com/".to_owned())
+                .build(),
+        }
+    }
+
+    #[tokio::test]
+    async fn emails_a_single_use_link_to_known_accounts() {
+        let fixture = fixture();
+
+        fixture
+            .usecase
+            .execute(ForgotPasswordCommand {
+                email: "ada@example.com".to_owned(),
+            })
+            .await
+            .expect("reset should be issued");
+
+        let reset_passwords = fixture
+            .reset_passwords
+            .reset_passwords
+            .lock()
+            .expect("lock");
+        assert_eq!(reset_passwords.len(), 1);
+        assert_eq!(reset_passwords[0].user_id, fixture.user_id);
+        assert_eq!(reset_passwords[0].token, TOKEN);
+        assert!(!reset_passwords[0].consumed);
+
+        let emails = fixture.email_sender.emails.lock().expect("lock");
+        assert_eq!(emails.len(), 1);
+        assert_eq!(emails[0].to, "ada@example.com");
+        assert!(emails[0].text_body.contains(&format!(
+            "https://app.example.com/auth/reset-password?token={}",
+            TOKEN
+        )));
+    }
+
+    #[tokio::test]
+    async fn succeeds_silently_for_unknown_emails() {
+        let fixture = fixture();
+
+        fixture
+            .usecase
+            .execute(ForgotPasswordCommand {
+                email: "mallory@example.com".to_owned(),
+            })
+            .await
+            .expect("unknown emails should not be reported");
+
+        assert!(
+            fixture
+                .reset_passwords
+                .reset_passwords
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert!(fixture.email_sender.emails.lock().expect("lock").is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::auto_reply_rule::AutoReplyRule,
+    repositories::{
+        auto_reply_rule_repository::AutoReplyRuleRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct GetAutoReplyRuleUsecase {
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+}
+
+impl GetAutoReplyRuleUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        phone_number_id: uuid::Uuid,
+        rule_id: uuid::Uuid,
+    ) -> Result<AutoReplyRule, UsecaseError> {
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&organization_id, &phone_number_id)
+            .await?;
+        let rule = self
+            .auto_reply_rule_repository
+            .find_by_id(&phone_number.organization_id, &rule_id)
+            .await?;
+        if rule.phone_number_id != phone_number.id {
+            return Err(UsecaseError::EntityNotFound);
+        }
+
+        Ok(rule)
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::business_hours::BusinessHours,
+    repositories::{
+        business_hours_repository::BusinessHoursRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct GetBusinessHoursUsecase {
+    business_hours_repository: Arc<dyn BusinessHoursRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+}
+
+impl GetBusinessHoursUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        phone_number_id: uuid::Uuid,
+    ) -> Result<BusinessHours, UsecaseError> {
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&organization_id, &phone_number_id)
+            .await?;
+        let business_hours = self
+            .business_hours_repository
+            .find_by_phone_number_id(&phone_number.organization_id, &phone_number.id)
+            .await?;
+
+        Ok(business_hours)
+    }
+}
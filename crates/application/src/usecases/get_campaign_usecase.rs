@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::{responses::CampaignResult, usecases::UsecaseError};
+use domain::repositories::campaign_repository::CampaignRepository;
+
+#[derive(bon::Builder)]
+pub struct GetCampaignUsecase {
+    campaign_repository: Arc<dyn CampaignRepository>,
+}
+
+impl GetCampaignUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        campaign_id: uuid::Uuid,
+    ) -> Result<CampaignResult, UsecaseError> {
+        let campaign = self
+            .campaign_repository
+            .find_by_id(&organization_id, &campaign_id)
+            .await?;
+        let progress = self.campaign_repository.progress(&campaign.id).await?;
+
+        Ok(CampaignResult { campaign, progress })
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::contact_import::ContactImport,
+    repositories::contact_import_repository::ContactImportRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct GetContactImportUsecase {
+    contact_import_repository: Arc<dyn ContactImportRepository>,
+}
+
+impl GetContactImportUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        import_id: uuid::Uuid,
+    ) -> Result<ContactImport, UsecaseError> {
+        let import = self
+            .contact_import_repository
+            .find_by_id(&organization_id, &import_id)
+            .await?;
+
+        Ok(import)
+    }
+}
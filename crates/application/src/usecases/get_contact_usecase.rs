@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{models::contact::Contact, repositories::contact_repository::ContactRepository};
+
+#[derive(bon::Builder)]
+pub struct GetContactUsecase {
+    contact_repository: Arc<dyn ContactRepository>,
+}
+
+impl GetContactUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        contact_id: uuid::Uuid,
+    ) -> Result<Contact, UsecaseError> {
+        let contact = self
+            .contact_repository
+            .find_by_id(&organization_id, &contact_id)
+            .await?;
+
+        Ok(contact)
+    }
+}
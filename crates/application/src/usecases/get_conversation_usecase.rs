@@ -14,12 +14,12 @@ pub struct GetConversationUsecase {
 impl GetConversationUsecase {
     pub async fn execute(
         &self,
-        user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
         conversation_id: uuid::Uuid,
     ) -> Result<Conversation, UsecaseError> {
         let conversation = self
             .conversation_repository
-            .find_by_id(&user_id, &conversation_id)
+            .find_by_id(&organization_id, &conversation_id)
             .await?;
 
         Ok(conversation)
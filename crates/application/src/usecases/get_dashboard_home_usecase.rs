@@ -17,15 +17,26 @@ pub struct GetDashboardHomeUsecase {
 }
 
 impl GetDashboardHomeUsecase {
-    pub async fn execute(&self, user_id: uuid::Uuid) -> Result<DashboardHomeResult, UsecaseError> {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> Result<DashboardHomeResult, UsecaseError> {
         let conversations = self
             .conversation_repository
-            .list_by_user_id(&user_id)
+            .list_by_organization_id(&organization_id)
+            .await?;
+        let unread_counts = self
+            .conversation_repository
+            .list_unread_counts(&organization_id, &user_id)
+            .await?;
+        let total_messages = self
+            .message_repository
+            .count_by_organization_id(&organization_id)
             .await?;
-        let total_messages = self.message_repository.count_by_user_id(&user_id).await?;
         let phone_numbers = self
             .phone_number_repository
-            .list_by_user_id(&user_id)
+            .list_by_organization_id(&organization_id)
             .await?;
 
         Ok(DashboardHomeResult {
@@ -33,6 +44,8 @@ impl GetDashboardHomeUsecase {
                 total_conversations: conversations.len() as u64,
                 total_messages,
                 total_phone_numbers: phone_numbers.len() as u64,
+                unread_conversations: unread_counts.len() as u64,
+                unread_messages: unread_counts.iter().map(|count| count.unread as u64).sum(),
             },
             phone_numbers,
         })
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::{responses::MessageAttachmentContentResult, usecases::UsecaseError};
+use domain::{
+    repositories::message_repository::MessageRepository, traits::media_storage::MediaStorage,
+};
+
+#[derive(bon::Builder)]
+pub struct GetMessageAttachmentUsecase {
+    message_repository: Arc<dyn MessageRepository>,
+    media_storage: Arc<dyn MediaStorage>,
+}
+
+impl GetMessageAttachmentUsecase {
+    /// Loads an attachment and its bytes. Callers are expected to have
+    /// verified a signed `FileAccess` link for `attachment_id` beforehand.
+    pub async fn execute(
+        &self,
+        attachment_id: uuid::Uuid,
+    ) -> Result<MessageAttachmentContentResult, UsecaseError> {
+        let attachment = self
+            .message_repository
+            .find_attachment_by_id(&attachment_id)
+            .await?;
+
+        let bytes = self.media_storage.get(&attachment.storage_key).await?;
+
+        Ok(MessageAttachmentContentResult { attachment, bytes })
+    }
+}
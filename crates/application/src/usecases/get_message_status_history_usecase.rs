@@ -0,0 +1,316 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::message_status_event::MessageStatusEvent,
+    repositories::{
+        message_repository::MessageRepository,
+        message_status_event_repository::MessageStatusEventRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct GetMessageStatusHistoryUsecase {
+    message_repository: Arc<dyn MessageRepository>,
+    message_status_event_repository: Arc<dyn MessageStatusEventRepository>,
+}
+
+impl GetMessageStatusHistoryUsecase {
+    /// Delivery timeline of a single message, oldest first. Messages outside the
+    /// organization's conversations are reported as not found.
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        conversation_id: uuid::Uuid,
+        message_id: uuid::Uuid,
+    ) -> Result<Vec<MessageStatusEvent>, UsecaseError> {
+        let message = self.message_repository.find_by_id(&message_id).await?;
+        if message.organization_id != organization_id || message.conversation_id != conversation_id
+        {
+            return Err(UsecaseError::EntityNotFound);
+        }
+
+        self.message_status_event_repository
+            .list_by_message_ids(&organization_id, &[message_id])
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use async_trait::async_trait;
+    use time::{Duration, OffsetDateTime};
+
+    use super::GetMessageStatusHistoryUsecase;
+    use crate::usecases::UsecaseError;
+    use domain::{
+        models::{
+            message::{Message, MessageStatus, MessageType},
+            message_attachment::MessageAttachment,
+            message_status_event::{MessageStatusEvent, sort_timeline},
+        },
+        repositories::{
+            RepositoryError,
+            message_repository::{MessagePage, MessageRepository},
+            message_status_event_repository::MessageStatusEventRepository,
+        },
+    };
+
+    struct FakeMessageRepository {
+        messages: HashMap<uuid::Uuid, Message>,
+    }
+
+    #[async_trait]
+    impl MessageRepository for FakeMessageRepository {
+        async fn create_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            Ok(message.clone())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+            self.messages
+                .get(id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn count_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn find_by_provider_message_id(
+            &self,
+            _provider_message_id: &str,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_reply_to(
+            &self,
+            _message_id: &uuid::Uuid,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_conversation_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_page_by_conversation_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+            _cursor: Option<&uuid::Uuid>,
+            _limit: usize,
+        ) -> Result<MessagePage, RepositoryError> {
+            Ok(MessagePage {
+                messages: Vec::new(),
+                next_cursor: None,
+            })
+        }
+
+        async fn update_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            Ok(message.clone())
+        }
+
+        async fn list_scheduled_by_conversation_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule_message(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+            _scheduled_at: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn delete_scheduled_message(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn release_scheduled_message(
+            &self,
+            _id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn find_attachment_by_id(
+            &self,
+            _id: &uuid::Uuid,
+        ) -> Result<MessageAttachment, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+    }
+
+    /// Keeps events in the order they were recorded and sorts them on the way out, like
+    /// the database does.
+    struct FakeMessageStatusEventRepository {
+        events: Vec<MessageStatusEvent>,
+    }
+
+    #[async_trait]
+    impl MessageStatusEventRepository for FakeMessageStatusEventRepository {
+        async fn record(&self, _event: &MessageStatusEvent) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_message_ids(
+            &self,
+            _organization_id: &uuid::Uuid,
+            message_ids: &[uuid::Uuid],
+        ) -> Result<Vec<MessageStatusEvent>, RepositoryError> {
+            let mut events = self
+                .events
+                .iter()
+                .filter(|event| message_ids.contains(&event.message_id))
+                .cloned()
+                .collect::<Vec<_>>();
+            sort_timeline(&mut events);
+            Ok(events)
+        }
+    }
+
+    fn build_message(organization_id: uuid::Uuid, conversation_id: uuid::Uuid) -> Message {
+        let now = OffsetDateTime::now_utc();
+        Message::builder()
+            .id(uuid::Uuid::now_v7())
+            .conversation_id(conversation_id)
+            .organization_id(organization_id)
+            .user_id(organization_id)
+            .message_type(MessageType::Outbound)
+            .status(MessageStatus::Delivered)
+            .from_number("+13125550100".to_owned())
+            .content("Hello".to_owned())
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    fn build_event(
+        message: &Message,
+        status: MessageStatus,
+        occurred_at: OffsetDateTime,
+    ) -> MessageStatusEvent {
+        MessageStatusEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .message_id(message.id)
+            .user_id(message.user_id)
+            .status(status)
+            .occurred_at(occurred_at)
+            .created_at(OffsetDateTime::now_utc())
+            .build()
+    }
+
+    struct Fixture {
+        usecase: GetMessageStatusHistoryUsecase,
+        message: Message,
+    }
+
+    /// A delivered message whose events were recorded out of order, next to another
+    /// message of the same conversation.
+    fn fixture() -> Fixture {
+        let organization_id = uuid::Uuid::now_v7();
+        let conversation_id = uuid::Uuid::now_v7();
+        let message = build_message(organization_id, conversation_id);
+        let other = build_message(organization_id, conversation_id);
+        let now = OffsetDateTime::now_utc();
+        let events = vec![
+            build_event(&message, MessageStatus::Delivered, now),
+            build_event(&other, MessageStatus::Queued, now - Duration::seconds(5)),
+            build_event(&message, MessageStatus::Queued, now - Duration::seconds(3)),
+            build_event(&message, MessageStatus::Sent, now - Duration::seconds(2)),
+        ];
+        let usecase = GetMessageStatusHistoryUsecase::builder()
+            .message_repository(Arc::new(FakeMessageRepository {
+                messages: HashMap::from([(message.id, message.clone()), (other.id, other)]),
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository { events }))
+            .build();
+
+        Fixture { usecase, message }
+    }
+
+    #[tokio::test]
+    async fn returns_the_timeline_of_the_message_oldest_first() {
+        let fixture = fixture();
+
+        let history = fixture
+            .usecase
+            .execute(
+                fixture.message.organization_id,
+                fixture.message.conversation_id,
+                fixture.message.id,
+            )
+            .await
+            .expect("history should load");
+
+        assert!(
+            history
+                .iter()
+                .all(|event| event.message_id == fixture.message.id)
+        );
+        let statuses = history.iter().map(|event| event.status).collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                MessageStatus::Queued,
+                MessageStatus::Sent,
+                MessageStatus::Delivered,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn message_of_another_organization_is_not_found() {
+        let fixture = fixture();
+
+        let result = fixture
+            .usecase
+            .execute(
+                uuid::Uuid::now_v7(),
+                fixture.message.conversation_id,
+                fixture.message.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(UsecaseError::EntityNotFound)));
+    }
+
+    #[tokio::test]
+    async fn message_of_another_conversation_is_not_found() {
+        let fixture = fixture();
+
+        let result = fixture
+            .usecase
+            .execute(
+                fixture.message.organization_id,
+                uuid::Uuid::now_v7(),
+                fixture.message.id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(UsecaseError::EntityNotFound)));
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::message_template::MessageTemplate,
+    repositories::message_template_repository::MessageTemplateRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct GetMessageTemplateUsecase {
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+}
+
+impl GetMessageTemplateUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        template_id: uuid::Uuid,
+    ) -> Result<MessageTemplate, UsecaseError> {
+        let template = self
+            .message_template_repository
+            .find_by_id(&user_id, &template_id)
+            .await?;
+
+        Ok(template)
+    }
+}
@@ -13,12 +13,12 @@ pub struct GetPhoneNumberUsecase {
 impl GetPhoneNumberUsecase {
     pub async fn execute(
         &self,
-        user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
         phone_number_id: uuid::Uuid,
     ) -> Result<PhoneNumber, UsecaseError> {
         let phone_number = self
             .phone_number_repository
-            .find_by_id(&user_id, &phone_number_id)
+            .find_by_id(&organization_id, &phone_number_id)
             .await?;
 
         Ok(phone_number)
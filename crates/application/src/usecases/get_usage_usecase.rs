@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    responses::{UsageLimitStatus, UsagePeriodTotals, UsageReport},
+    usecases::UsecaseError,
+};
+use domain::{
+    models::usage::{UsagePeriod, UsageRollup},
+    repositories::{
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+    },
+};
+
+/// Days covered by the daily report.
+pub const DAILY_USAGE_HISTORY: usize = 31;
+/// Months covered by the monthly report.
+pub const MONTHLY_USAGE_HISTORY: usize = 12;
+
+/// Reports a user's usage per day or month, along with their limits and how much of each is
+/// spent in the current period.
+#[derive(bon::Builder)]
+pub struct GetUsageUsecase {
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+}
+
+impl GetUsageUsecase {
+    pub async fn execute(
+        &self,
+        user_id: uuid::Uuid,
+        period: UsagePeriod,
+    ) -> Result<UsageReport, UsecaseError> {
+        let now = OffsetDateTime::now_utc();
+        let rollups = self
+            .usage_repository
+            .rollups(&user_id, period, history_start(period, now))
+            .await?;
+
+        let mut limits = Vec::new();
+        for limit in self
+            .usage_limit_repository
+            .list_by_user_id(&user_id)
+            .await?
+        {
+            let spent = self
+                .usage_repository
+                .totals_since(&user_id, limit.period.start(now))
+                .await?;
+            limits.push(UsageLimitStatus {
+                spent_micros: spent.cost_micros,
+                resets_at: limit.period.next_start(now),
+                limit,
+            });
+        }
+
+        Ok(UsageReport {
+            period,
+            totals: totals_per_period(&rollups),
+            rollups,
+            limits,
+        })
+    }
+}
+
+fn history_start(period: UsagePeriod, now: OffsetDateTime) -> OffsetDateTime {
+    let mut start = period.start(now);
+    let earlier = match period {
+        UsagePeriod::Daily => DAILY_USAGE_HISTORY,
+        UsagePeriod::Monthly => MONTHLY_USAGE_HISTORY,
+    } - 1;
+    for _ in 0..earlier {
+        start = period.start(start - Duration::days(1));
+    }
+
+    start
+}
+
+/// Sums `rollups`, which are ordered by period, over every phone number.
+fn totals_per_period(rollups: &[UsageRollup]) -> Vec<UsagePeriodTotals> {
+    let mut totals: Vec<UsagePeriodTotals> = Vec::new();
+    for rollup in rollups {
+        match totals.last_mut() {
+            Some(last) if last.period_start == rollup.period_start => {
+                last.totals.add(&rollup.totals)
+            }
+            _ => totals.push(UsagePeriodTotals {
+                period_start: rollup.period_start,
+                totals: rollup.totals,
+            }),
+        }
+    }
+
+    totals
+}
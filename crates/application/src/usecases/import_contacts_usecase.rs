@@ -0,0 +1,595 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::{ContactDetails, ImportContactsCommand},
+    contact_import::{ParsedContact, parse_csv, parse_vcard},
+    usecases::{UsecaseError, authorize_member, create_contact_usecase::build_contact},
+};
+use domain::{
+    models::{
+        contact::normalize_phone_number,
+        contact_import::{
+            ContactImport, ContactImportFormat, ContactImportOutcome, ContactImportRow,
+            ContactImportStatus,
+        },
+        organization::Permission,
+    },
+    repositories::{
+        RepositoryError, contact_import_repository::ContactImportRepository,
+        contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+    },
+};
+
+pub const MAX_CONTACT_IMPORT_BYTES: usize = 5 * 1024 * 1024;
+/// Files with more entries than this are handed to the background worker.
+pub const INLINE_CONTACT_IMPORT_ENTRIES: usize = 100;
+
+/// Stores an uploaded CSV or vCard file as an import. Small files are applied right away
+/// so the report comes back with the response; larger ones stay pending for the worker.
+#[derive(bon::Builder)]
+pub struct ImportContactsUsecase {
+    contact_import_repository: Arc<dyn ContactImportRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    #[builder(default = INLINE_CONTACT_IMPORT_ENTRIES)]
+    inline_entries: usize,
+}
+
+impl ImportContactsUsecase {
+    pub async fn execute(&self, cmd: ImportContactsCommand) -> Result<ContactImport, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::ManageContacts,
+        )
+        .await?;
+
+        if cmd.content.trim().is_empty() {
+            return Err(garde::Error::new("The uploaded file is empty").into());
+        }
+        if cmd.content.len() > MAX_CONTACT_IMPORT_BYTES {
+            return Err(garde::Error::new("Contact files are limited to 5 MB").into());
+        }
+
+        let default_country_code = match cmd.default_country_code.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(code) => {
+                let code = code.trim_start_matches('+');
+                if code.is_empty() || code.len() > 3 || !code.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(garde::Error::new(
+                        "Default country code must be 1-3 digits, e.g. 1 or 44",
+                    )
+                    .into());
+                }
+                Some(code.to_owned())
+            }
+        };
+
+        let now = OffsetDateTime::now_utc();
+        let mut import = ContactImport::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(cmd.user_id)
+            .organization_id(cmd.organization_id)
+            .format(
+                cmd.format
+                    .unwrap_or_else(|| ContactImportFormat::detect(&cmd.file_name, &cmd.content)),
+            )
+            .file_name(cmd.file_name)
+            .status(ContactImportStatus::Pending)
+            .column_mapping(cmd.column_mapping)
+            .maybe_default_country_code(default_country_code)
+            .content(cmd.content)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+
+        let entries = parse_entries(&import).map_err(garde::Error::new)?;
+        if entries.is_empty() {
+            return Err(garde::Error::new("The uploaded file has no contacts").into());
+        }
+
+        self.contact_import_repository
+            .create_import(&import)
+            .await?;
+
+        if entries.len() <= self.inline_entries {
+            apply_entries(self.contact_repository.as_ref(), &mut import, entries).await?;
+            self.contact_import_repository
+                .update_import(&import)
+                .await?;
+        }
+
+        Ok(import)
+    }
+}
+
+pub(crate) fn parse_entries(import: &ContactImport) -> Result<Vec<ParsedContact>, String> {
+    match import.format {
+        ContactImportFormat::Csv => parse_csv(&import.content, &import.column_mapping),
+        ContactImportFormat::VCard => parse_vcard(&import.content),
+    }
+}
+
+/// Creates a contact for every entry whose numbers are not saved yet and records the
+/// outcome of each entry on the import, which ends up `Completed`.
+pub(crate) async fn apply_entries(
+    contact_repository: &dyn ContactRepository,
+    import: &mut ContactImport,
+    entries: Vec<ParsedContact>,
+) -> Result<(), UsecaseError> {
+    let country_code = import.default_country_code.as_deref();
+    let normalized = entries
+        .iter()
+        .map(|entry| {
+            entry
+                .phone_numbers
+                .iter()
+                .map(|raw| (raw.clone(), normalize_phone_number(raw, country_code)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut lookup = normalized
+        .iter()
+        .flatten()
+        .filter_map(|(_, number)| number.clone())
+        .collect::<Vec<_>>();
+    lookup.sort();
+    lookup.dedup();
+    let saved = contact_repository
+        .find_by_phone_numbers(&import.organization_id, &lookup)
+        .await?
+        .into_iter()
+        .flat_map(|contact| contact.phone_numbers)
+        .collect::<HashSet<_>>();
+
+    let mut seen_on_line = HashMap::<String, usize>::new();
+    let mut rows = Vec::with_capacity(entries.len());
+
+    for (entry, numbers) in entries.into_iter().zip(normalized) {
+        let invalid = numbers
+            .iter()
+            .filter(|(_, number)| number.is_none())
+            .map(|(raw, _)| raw.as_str())
+            .collect::<Vec<_>>();
+        let mut valid = Vec::<String>::new();
+        for number in numbers.iter().filter_map(|(_, number)| number.clone()) {
+            if !valid.contains(&number) {
+                valid.push(number);
+            }
+        }
+
+        let row = |outcome, phone_numbers: Vec<String>, reason: Option<String>| ContactImportRow {
+            line: entry.line,
+            outcome,
+            name: entry.name.clone(),
+            phone_numbers,
+            contact_id: None,
+            reason,
+        };
+
+        if valid.is_empty() {
+            let reason = if invalid.is_empty() {
+                "No phone number".to_owned()
+            } else if country_code.is_none() {
+                format!(
+                    "Not a valid phone number: {}. Add a country code or set a default one",
+                    invalid.join(", ")
+                )
+            } else {
+                format!("Not a valid phone number: {}", invalid.join(", "))
+            };
+            rows.push(row(ContactImportOutcome::Invalid, Vec::new(), Some(reason)));
+            continue;
+        }
+
+        let fresh = valid
+            .iter()
+            .filter(|number| !saved.contains(*number) && !seen_on_line.contains_key(*number))
+            .cloned()
+            .collect::<Vec<_>>();
+        if fresh.is_empty() {
+            let reason = match valid.iter().find_map(|number| seen_on_line.get(number)) {
+                Some(line) => format!("Duplicate of line {}", line),
+                None => "Already saved as a contact".to_owned(),
+            };
+            rows.push(row(ContactImportOutcome::Skipped, valid, Some(reason)));
+            continue;
+        }
+
+        let details = ContactDetails {
+            name: entry.name.clone().unwrap_or_else(|| fresh[0].clone()),
+            phone_numbers: fresh.clone(),
+            email: entry.email.clone(),
+            notes: entry.notes.clone(),
+            custom_fields: entry.custom_fields.clone(),
+            tags: Vec::new(),
+        };
+        let now = OffsetDateTime::now_utc();
+        let contact = match build_contact(
+            details,
+            uuid::Uuid::now_v7(),
+            import.user_id,
+            import.organization_id,
+            now,
+            now,
+        ) {
+            Ok(contact) => contact,
+            Err(err @ UsecaseError::Validation(_)) => {
+                rows.push(row(
+                    ContactImportOutcome::Invalid,
+                    valid,
+                    Some(err.to_http_message()),
+                ));
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        match contact_repository.create_contact(&contact).await {
+            Ok(()) => {}
+            Err(RepositoryError::ConstraintViolation(_)) => {
+                rows.push(row(
+                    ContactImportOutcome::Skipped,
+                    valid,
+                    Some("Already saved as a contact".to_owned()),
+                ));
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut notes = Vec::new();
+        if fresh.len() < valid.len() {
+            notes.push("numbers that are already saved were left out".to_owned());
+        }
+        if !invalid.is_empty() {
+            notes.push(format!("invalid numbers ignored: {}", invalid.join(", ")));
+        }
+        for number in &fresh {
+            seen_on_line.insert(number.clone(), entry.line);
+        }
+
+        rows.push(ContactImportRow {
+            contact_id: Some(contact.id),
+            name: Some(contact.name),
+            ..row(
+                ContactImportOutcome::Imported,
+                fresh,
+                (!notes.is_empty()).then(|| notes.join("; ")),
+            )
+        });
+    }
+
+    let now = OffsetDateTime::now_utc();
+    import.rows = rows;
+    import.status = ContactImportStatus::Completed;
+    import.content = String::new();
+    import.last_error = None;
+    import.completed_at = Some(now);
+    import.updated_at = now;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            contact::Contact,
+            contact_import::{
+                ContactImport, ContactImportColumnMapping, ContactImportOutcome,
+                ContactImportStatus,
+            },
+            conversation::ConversationRouting,
+            organization::{Organization, OrganizationMember, OrganizationRole},
+        },
+        repositories::{
+            RepositoryError, contact_import_repository::ContactImportRepository,
+            contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+        },
+    };
+    use time::OffsetDateTime;
+
+    use crate::commands::ImportContactsCommand;
+
+    use super::ImportContactsUsecase;
+
+    #[derive(Default)]
+    struct FakeContactImportRepository {
+        imports: Mutex<Vec<ContactImport>>,
+    }
+
+    #[async_trait]
+    impl ContactImportRepository for FakeContactImportRepository {
+        async fn create_import(&self, import: &ContactImport) -> Result<(), RepositoryError> {
+            self.imports.lock().expect("lock").push(import.clone());
+            Ok(())
+        }
+
+        async fn update_import(&self, import: &ContactImport) -> Result<(), RepositoryError> {
+            let mut imports = self.imports.lock().expect("lock");
+            let stored = imports
+                .iter_mut()
+                .find(|stored| stored.id == import.id)
+                .ok_or(RepositoryError::NotFound)?;
+            *stored = import.clone();
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            id: &uuid::Uuid,
+        ) -> Result<ContactImport, RepositoryError> {
+            self.imports
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|import| import.id == *id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn claim_pending(
+            &self,
+            _now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+        ) -> Result<Option<ContactImport>, RepositoryError> {
+            Ok(None)
+        }
+    }
+
+    struct FakeContactRepository {
+        contacts: Mutex<Vec<Contact>>,
+    }
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, contact: &Contact) -> Result<(), RepositoryError> {
+            self.contacts.lock().expect("lock").push(contact.clone());
+            Ok(())
+        }
+
+        async fn update_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self.contacts.lock().expect("lock").clone())
+        }
+
+        async fn find_by_phone_numbers(
+            &self,
+            _organization_id: &uuid::Uuid,
+            phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self
+                .contacts
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|contact| {
+                    contact
+                        .phone_numbers
+                        .iter()
+                        .any(|number| phone_numbers.contains(number))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeOrganizationRepository {
+        role: Option<OrganizationRole>,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            let role = self.role.ok_or(RepositoryError::NotFound)?;
+            Ok(OrganizationMember::builder()
+                .organization_id(*organization_id)
+                .user_id(*user_id)
+                .role(role)
+                .created_at(OffsetDateTime::now_utc())
+                .build())
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn existing_contact(user_id: uuid::Uuid) -> Contact {
+        let now = OffsetDateTime::now_utc();
+        Contact::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(user_id)
+            .name("Existing".to_owned())
+            .phone_numbers(vec!["+15550001111".to_owned()])
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    fn command(user_id: uuid::Uuid, content: &str) -> ImportContactsCommand {
+        ImportContactsCommand {
+            user_id,
+            organization_id: user_id,
+            file_name: "contacts.csv".to_owned(),
+            content: content.to_owned(),
+            format: None,
+            column_mapping: ContactImportColumnMapping::default(),
+            default_country_code: Some("+1".to_owned()),
+        }
+    }
+
+    #[tokio::test]
+    async fn small_files_are_imported_with_a_per_row_report() {
+        let user_id = uuid::Uuid::now_v7();
+        let imports = Arc::new(FakeContactImportRepository::default());
+        let contacts = Arc::new(FakeContactRepository {
+            contacts: Mutex::new(vec![existing_contact(user_id)]),
+        });
+        let usecase = ImportContactsUsecase::builder()
+            .contact_import_repository(imports.clone())
+            .contact_repository(contacts.clone())
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+
+        let import = usecase
+            .execute(command(
+                user_id,
+                "name,phone,email\n\
+                 Ada,(555) 123-4567,ada@example.com\n\
+                 Existing again,555-000-1111,\n\
+                 Ada twin,+1 555 123 4567,\n\
+                 Nobody,12,\n\
+                 Bad email,555 222 3333,not-an-email\n",
+            ))
+            .await
+            .expect("import should succeed");
+
+        assert_eq!(import.status, ContactImportStatus::Completed);
+        assert!(import.content.is_empty());
+        let outcomes = import
+            .rows
+            .iter()
+            .map(|row| (row.line, row.outcome))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                (2, ContactImportOutcome::Imported),
+                (3, ContactImportOutcome::Skipped),
+                (4, ContactImportOutcome::Skipped),
+                (5, ContactImportOutcome::Invalid),
+                (6, ContactImportOutcome::Invalid),
+            ]
+        );
+        assert_eq!(import.rows[0].phone_numbers, vec!["+15551234567"]);
+        assert_eq!(
+            import.rows[2].reason.as_deref(),
+            Some("Duplicate of line 2")
+        );
+        assert_eq!(contacts.contacts.lock().expect("lock").len(), 2);
+        assert_eq!(
+            imports.imports.lock().expect("lock")[0].status,
+            ContactImportStatus::Completed
+        );
+    }
+
+    #[tokio::test]
+    async fn large_files_are_left_for_the_worker() {
+        let user_id = uuid::Uuid::now_v7();
+        let imports = Arc::new(FakeContactImportRepository::default());
+        let contacts = Arc::new(FakeContactRepository {
+            contacts: Mutex::new(Vec::new()),
+        });
+        let usecase = ImportContactsUsecase::builder()
+            .contact_import_repository(imports.clone())
+            .contact_repository(contacts.clone())
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .inline_entries(1)
+            .build();
+
+        let import = usecase
+            .execute(command(
+                user_id,
+                "name,phone\nAda,555 123 4567\nGrace,555 765 4321\n",
+            ))
+            .await
+            .expect("import should be queued");
+
+        assert_eq!(import.status, ContactImportStatus::Pending);
+        assert!(import.rows.is_empty());
+        assert!(contacts.contacts.lock().expect("lock").is_empty());
+        assert_eq!(imports.imports.lock().expect("lock").len(), 1);
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{commands::IngestTelnyxWebhookCommand, usecases::UsecaseError};
+use domain::{
+    models::processed_webhook_event::{ProcessedWebhookEvent, WebhookEventStatus},
+    repositories::{
+        RepositoryError, processed_webhook_event_repository::ProcessedWebhookEventRepository,
+    },
+};
+
+/// Stores a verified webhook in the inbox so it can be acknowledged right away and
+/// processed by `ProcessWebhookInboxUsecase`. Redeliveries of a known event are ignored.
+#[derive(bon::Builder)]
+pub struct IngestTelnyxWebhookUsecase {
+    processed_webhook_event_repository: Arc<dyn ProcessedWebhookEventRepository>,
+}
+
+impl IngestTelnyxWebhookUsecase {
+    pub async fn execute(&self, cmd: IngestTelnyxWebhookCommand) -> Result<(), UsecaseError> {
+        if cmd.event_id.trim().is_empty() {
+            return Err(garde::Error::new("webhook event id is required").into());
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let event = ProcessedWebhookEvent::builder()
+            .event_id(cmd.event_id)
+            .event_type(cmd.event_type)
+            .maybe_provider_message_id(cmd.provider_message_id)
+            .occurred_at(cmd.occurred_at)
+            .payload_json(cmd.raw_payload)
+            .status(WebhookEventStatus::Pending)
+            .next_attempt_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+
+        match self
+            .processed_webhook_event_repository
+            .create_processed_webhook_event(&event)
+            .await
+        {
+            Ok(()) | Err(RepositoryError::ConstraintViolation(_)) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
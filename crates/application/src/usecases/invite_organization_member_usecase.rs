@@ -0,0 +1,141 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{
+    commands::InviteOrganizationMemberCommand,
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::organization::{OrganizationInvitation, OrganizationRole, Permission},
+    repositories::{
+        RepositoryError, organization_invitation_repository::OrganizationInvitationRepository,
+        organization_repository::OrganizationRepository, user_repository::UserRepository,
+    },
+    traits::{
+        email_sender::{EmailMessage, EmailSender},
+        token_service::{PasetoClaimPurpose, PasetoClaims, TokenService},
+    },
+};
+
+/// How long an invitation link stays valid after it is sent.
+pub const ORGANIZATION_INVITATION_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Emails an invitation to join the organization. The person signs in, or signs up, with
+/// the invited address and opens the link to become a member with the invited role.
+/// Admins may invite anyone but owners, whom only owners may invite.
+#[derive(bon::Builder)]
+pub struct InviteOrganizationMemberUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+    organization_invitation_repository: Arc<dyn OrganizationInvitationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    token_service: Arc<dyn TokenService>,
+    email_sender: Arc<dyn EmailSender>,
+    /// Absolute base URL used to build the link in the email, e.g. `https://app.example.com`.
+    public_base_url: String,
+}
+
+impl InviteOrganizationMemberUsecase {
+    pub async fn execute(
+        &self,
+        cmd: InviteOrganizationMemberCommand,
+    ) -> Result<OrganizationInvitation, UsecaseError> {
+        let cmd = InviteOrganizationMemberCommand {
+            email: cmd.email.trim().to_lowercase(),
+            ..cmd
+        };
+        cmd.validate()?;
+
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            if cmd.role == OrganizationRole::Owner {
+                Permission::ManageOwners
+            } else {
+                Permission::ManageMembers
+            },
+        )
+        .await?;
+
+        let organization = self
+            .organization_repository
+            .find_by_id(&cmd.organization_id)
+            .await?;
+        let inviter = self.user_repository.find_by_id(&cmd.user_id).await?;
+        match self.user_repository.find_by_email(&cmd.email).await {
+            Ok(user) => match self
+                .organization_repository
+                .find_member(&organization.id, &user.id)
+                .await
+            {
+                Ok(_) => {
+                    return Err(garde::Error::new(
+                        "This person is already a member of the organization",
+                    )
+                    .into());
+                }
+                Err(RepositoryError::NotFound) => {}
+                Err(err) => return Err(err.into()),
+            },
+            Err(RepositoryError::NotFound) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let invitation = OrganizationInvitation::builder()
+            .id(uuid::Uuid::now_v7())
+            .organization_id(organization.id)
+            .email(cmd.email)
+            .role(cmd.role)
+            .invited_by(inviter.id)
+            .expires_at(now + ORGANIZATION_INVITATION_TTL)
+            .created_at(now)
+            .build();
+
+        let claims = PasetoClaims::new(
+            invitation.id,
+            invitation.email.clone(),
+            ORGANIZATION_INVITATION_TTL,
+            PasetoClaimPurpose::OrganizationInvitation,
+        );
+        let (token, _) = self
+            .token_service
+            .generate_token(claims, ORGANIZATION_INVITATION_TTL)
+            .map_err(|_| UsecaseError::TokenGenerationFailed)?;
+
+        self.organization_invitation_repository
+            .create_invitation(&invitation)
+            .await?;
+
+        let accept_url = format!(
+            "{}/organizations/invitations/accept?token={}",
+            self.public_base_url.trim_end_matches('/'),
+            token
+        );
+        let email = EmailMessage {
+            to: invitation.email.clone(),
+            subject: format!("Join {} on Telnyx Web Client", organization.name),
+            text_body: format!(
+                "{} invited you to join {} and share its phone numbers and conversations.\n\n\
+                 Sign in or create an account with this email address, then open the link \
+                 below. It expires in {} days.\n\n{}\n",
+                inviter.email,
+                organization.name,
+                ORGANIZATION_INVITATION_TTL.as_secs() / (24 * 60 * 60),
+                accept_url
+            ),
+        };
+        if let Err(err) = self.email_sender.send(&email).await {
+            let _ = self
+                .organization_invitation_repository
+                .delete_invitation(&invitation.organization_id, &invitation.id)
+                .await;
+            return Err(err.into());
+        }
+
+        Ok(invitation)
+    }
+}
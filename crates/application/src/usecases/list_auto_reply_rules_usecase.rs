@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::auto_reply_rule::AutoReplyRule,
+    repositories::{
+        auto_reply_rule_repository::AutoReplyRuleRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct ListAutoReplyRulesUsecase {
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+}
+
+impl ListAutoReplyRulesUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        phone_number_id: uuid::Uuid,
+    ) -> Result<Vec<AutoReplyRule>, UsecaseError> {
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&organization_id, &phone_number_id)
+            .await?;
+        let rules = self
+            .auto_reply_rule_repository
+            .list_by_phone_number_id(&phone_number.organization_id, &phone_number.id)
+            .await?;
+
+        Ok(rules)
+    }
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::{responses::CampaignResult, usecases::UsecaseError};
+use domain::repositories::campaign_repository::CampaignRepository;
+
+#[derive(bon::Builder)]
+pub struct ListCampaignsUsecase {
+    campaign_repository: Arc<dyn CampaignRepository>,
+}
+
+impl ListCampaignsUsecase {
+    /// Newest first, each with its current progress.
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Vec<CampaignResult>, UsecaseError> {
+        let campaigns = self
+            .campaign_repository
+            .list_by_organization_id(&organization_id)
+            .await?;
+
+        let mut results = Vec::with_capacity(campaigns.len());
+        for campaign in campaigns {
+            let progress = self.campaign_repository.progress(&campaign.id).await?;
+            results.push(CampaignResult { campaign, progress });
+        }
+
+        Ok(results)
+    }
+}
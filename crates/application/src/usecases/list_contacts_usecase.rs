@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{models::contact::Contact, repositories::contact_repository::ContactRepository};
+
+#[derive(bon::Builder)]
+pub struct ListContactsUsecase {
+    contact_repository: Arc<dyn ContactRepository>,
+}
+
+impl ListContactsUsecase {
+    pub async fn execute(&self, organization_id: uuid::Uuid) -> Result<Vec<Contact>, UsecaseError> {
+        let contacts = self
+            .contact_repository
+            .list_by_organization_id(&organization_id)
+            .await?;
+
+        Ok(contacts)
+    }
+}
@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
+use time::OffsetDateTime;
+
 use crate::usecases::UsecaseError;
 use domain::{
-    models::conversation::Conversation,
+    models::conversation::{Conversation, ConversationFilter, ConversationView},
     repositories::conversation_repository::ConversationRepository,
 };
 
@@ -12,10 +14,24 @@ pub struct ListConversationsUsecase {
 }
 
 impl ListConversationsUsecase {
-    pub async fn execute(&self, user_id: uuid::Uuid) -> Result<Vec<Conversation>, UsecaseError> {
+    /// `user_id` is the member looking at the list, which `ConversationView::Mine` refers to.
+    /// Pinned conversations come first, then the most recently active.
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        view: ConversationView,
+        filter: ConversationFilter,
+    ) -> Result<Vec<Conversation>, UsecaseError> {
         let conversations = self
             .conversation_repository
-            .list_by_user_id(&user_id)
+            .list_filtered(
+                &organization_id,
+                &user_id,
+                view,
+                filter,
+                OffsetDateTime::now_utc(),
+            )
             .await?;
 
         Ok(conversations)
@@ -0,0 +1,131 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::message_status_event::MessageStatusEvent,
+    repositories::message_status_event_repository::MessageStatusEventRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct ListMessageStatusHistoryUsecase {
+    message_status_event_repository: Arc<dyn MessageStatusEventRepository>,
+}
+
+impl ListMessageStatusHistoryUsecase {
+    /// Delivery timelines for a page of messages, keyed by message id.
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        message_ids: &[uuid::Uuid],
+    ) -> Result<HashMap<uuid::Uuid, Vec<MessageStatusEvent>>, UsecaseError> {
+        let events = self
+            .message_status_event_repository
+            .list_by_message_ids(&organization_id, message_ids)
+            .await?;
+
+        let mut history: HashMap<uuid::Uuid, Vec<MessageStatusEvent>> = HashMap::new();
+        for event in events {
+            history.entry(event.message_id).or_default().push(event);
+        }
+
+        Ok(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use time::{Duration, OffsetDateTime};
+
+    use super::ListMessageStatusHistoryUsecase;
+    use domain::{
+        models::{message::MessageStatus, message_status_event::MessageStatusEvent},
+        repositories::{
+            RepositoryError, message_status_event_repository::MessageStatusEventRepository,
+        },
+    };
+
+    struct FakeMessageStatusEventRepository {
+        events: Vec<MessageStatusEvent>,
+    }
+
+    #[async_trait]
+    impl MessageStatusEventRepository for FakeMessageStatusEventRepository {
+        async fn record(&self, _event: &MessageStatusEvent) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_message_ids(
+            &self,
+            _organization_id: &uuid::Uuid,
+            message_ids: &[uuid::Uuid],
+        ) -> Result<Vec<MessageStatusEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .iter()
+                .filter(|event| message_ids.contains(&event.message_id))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn build_event(
+        message_id: uuid::Uuid,
+        status: MessageStatus,
+        occurred_at: OffsetDateTime,
+    ) -> MessageStatusEvent {
+        MessageStatusEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .message_id(message_id)
+            .user_id(uuid::Uuid::nil())
+            .status(status)
+            .occurred_at(occurred_at)
+            .created_at(occurred_at)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn groups_timelines_by_message_and_keeps_them_oldest_first() {
+        let first = uuid::Uuid::now_v7();
+        let second = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        // Interleaved and oldest first, as the repository returns them.
+        let events = vec![
+            build_event(first, MessageStatus::Queued, now - Duration::seconds(4)),
+            build_event(second, MessageStatus::Queued, now - Duration::seconds(3)),
+            build_event(first, MessageStatus::Sent, now - Duration::seconds(2)),
+            build_event(second, MessageStatus::Failed, now - Duration::seconds(1)),
+            build_event(first, MessageStatus::Delivered, now),
+        ];
+        let usecase = ListMessageStatusHistoryUsecase::builder()
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository { events }))
+            .build();
+
+        let history = usecase
+            .execute(uuid::Uuid::now_v7(), &[first, second])
+            .await
+            .expect("history should load");
+
+        let statuses = |message_id| {
+            history[&message_id]
+                .iter()
+                .map(|event| event.status)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            statuses(first),
+            vec![
+                MessageStatus::Queued,
+                MessageStatus::Sent,
+                MessageStatus::Delivered,
+            ]
+        );
+        assert_eq!(
+            statuses(second),
+            vec![MessageStatus::Queued, MessageStatus::Failed]
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::message_template::MessageTemplate,
+    repositories::message_template_repository::MessageTemplateRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct ListMessageTemplatesUsecase {
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+}
+
+impl ListMessageTemplatesUsecase {
+    pub async fn execute(&self, user_id: uuid::Uuid) -> Result<Vec<MessageTemplate>, UsecaseError> {
+        let templates = self
+            .message_template_repository
+            .list_by_user_id(&user_id)
+            .await?;
+
+        Ok(templates)
+    }
+}
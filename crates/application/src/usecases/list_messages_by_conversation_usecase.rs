@@ -11,13 +11,18 @@ pub struct ListMessagesByConversationUsecase {
 impl ListMessagesByConversationUsecase {
     pub async fn execute(
         &self,
-        user_id: uuid::Uuid,
+        organization_id: uuid::Uuid,
         conversation_id: uuid::Uuid,
         cursor: Option<uuid::Uuid>,
         limit: usize,
     ) -> Result<MessagePage, UsecaseError> {
         self.message_repository
-            .list_page_by_conversation_id(&user_id, &conversation_id, cursor.as_ref(), limit)
+            .list_page_by_conversation_id(
+                &organization_id,
+                &conversation_id,
+                cursor.as_ref(),
+                limit,
+            )
             .await
             .map_err(Into::into)
     }
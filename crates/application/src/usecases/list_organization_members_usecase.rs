@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{responses::OrganizationMembers, usecases::UsecaseError};
+use domain::repositories::{
+    organization_invitation_repository::OrganizationInvitationRepository,
+    organization_repository::OrganizationRepository,
+};
+
+/// Members of an organization along with the invitations still waiting for an answer.
+#[derive(bon::Builder)]
+pub struct ListOrganizationMembersUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+    organization_invitation_repository: Arc<dyn OrganizationInvitationRepository>,
+}
+
+impl ListOrganizationMembersUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<OrganizationMembers, UsecaseError> {
+        let members = self
+            .organization_repository
+            .list_members(&organization_id)
+            .await?;
+        let invitations = self
+            .organization_invitation_repository
+            .list_pending(&organization_id, OffsetDateTime::now_utc())
+            .await?;
+
+        Ok(OrganizationMembers {
+            members,
+            invitations,
+        })
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::organization::Organization,
+    repositories::organization_repository::OrganizationRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct ListOrganizationsUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl ListOrganizationsUsecase {
+    pub async fn execute(&self, user_id: uuid::Uuid) -> Result<Vec<Organization>, UsecaseError> {
+        let organizations = self
+            .organization_repository
+            .list_by_user_id(&user_id)
+            .await?;
+
+        Ok(organizations)
+    }
+}
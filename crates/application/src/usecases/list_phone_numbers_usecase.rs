@@ -11,10 +11,13 @@ pub struct ListPhoneNumbersUsecase {
 }
 
 impl ListPhoneNumbersUsecase {
-    pub async fn execute(&self, user_id: uuid::Uuid) -> Result<Vec<PhoneNumber>, UsecaseError> {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Vec<PhoneNumber>, UsecaseError> {
         let phone_numbers = self
             .phone_number_repository
-            .list_by_user_id(&user_id)
+            .list_by_organization_id(&organization_id)
             .await?;
 
         Ok(phone_numbers)
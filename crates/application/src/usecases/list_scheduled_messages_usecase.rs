@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::message::Message,
+    repositories::{
+        conversation_repository::ConversationRepository, message_repository::MessageRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct ListScheduledMessagesUsecase {
+    conversation_repository: Arc<dyn ConversationRepository>,
+    message_repository: Arc<dyn MessageRepository>,
+}
+
+impl ListScheduledMessagesUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        conversation_id: uuid::Uuid,
+    ) -> Result<Vec<Message>, UsecaseError> {
+        self.conversation_repository
+            .find_by_id(&organization_id, &conversation_id)
+            .await?;
+
+        let messages = self
+            .message_repository
+            .list_scheduled_by_conversation_id(&organization_id, &conversation_id)
+            .await?;
+
+        Ok(messages)
+    }
+}
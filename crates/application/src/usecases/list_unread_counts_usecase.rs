@@ -0,0 +1,29 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::usecases::UsecaseError;
+use domain::repositories::conversation_repository::ConversationRepository;
+
+/// Unread inbound messages per conversation for one member. Conversations without unread
+/// messages are left out.
+#[derive(bon::Builder)]
+pub struct ListUnreadCountsUsecase {
+    conversation_repository: Arc<dyn ConversationRepository>,
+}
+
+impl ListUnreadCountsUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> Result<HashMap<uuid::Uuid, i64>, UsecaseError> {
+        let counts = self
+            .conversation_repository
+            .list_unread_counts(&organization_id, &user_id)
+            .await?
+            .into_iter()
+            .map(|count| (count.conversation_id, count.unread))
+            .collect();
+
+        Ok(counts)
+    }
+}
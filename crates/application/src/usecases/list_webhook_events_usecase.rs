@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::processed_webhook_event::{ProcessedWebhookEvent, WebhookEventStatus},
+    repositories::processed_webhook_event_repository::ProcessedWebhookEventRepository,
+};
+
+pub const DEFAULT_WEBHOOK_EVENT_LIMIT: usize = 50;
+pub const MAX_WEBHOOK_EVENT_LIMIT: usize = 500;
+
+#[derive(bon::Builder)]
+pub struct ListWebhookEventsUsecase {
+    processed_webhook_event_repository: Arc<dyn ProcessedWebhookEventRepository>,
+}
+
+impl ListWebhookEventsUsecase {
+    pub async fn execute(
+        &self,
+        status: WebhookEventStatus,
+        limit: Option<usize>,
+    ) -> Result<Vec<ProcessedWebhookEvent>, UsecaseError> {
+        let limit = limit
+            .unwrap_or(DEFAULT_WEBHOOK_EVENT_LIMIT)
+            .clamp(1, MAX_WEBHOOK_EVENT_LIMIT);
+
+        let events = self
+            .processed_webhook_event_repository
+            .list_by_status(status, limit)
+            .await?;
+
+        Ok(events)
+    }
+}
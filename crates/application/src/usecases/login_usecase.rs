@@ -39,7 +39,6 @@ impl LoginUsecase {
         let access_token_claims = PasetoClaims::new(
             user.id,
             user.email.clone(),
-            "user".to_string(),
             access_token_exp,
             PasetoClaimPurpose::AccessToken,
         );
@@ -53,7 +52,6 @@ impl LoginUsecase {
         let refresh_token_claims = PasetoClaims::new(
             user.id,
             user.email.clone(),
-            "user".to_string(),
             refresh_token_exp,
             PasetoClaimPurpose::RefreshToken,
         );
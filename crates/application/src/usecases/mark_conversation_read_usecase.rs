@@ -0,0 +1,415 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::repositories::conversation_repository::ConversationRepository;
+
+/// Marks everything in a conversation as read for one member. Read cursors are per member, so
+/// this never changes what teammates see as unread.
+#[derive(bon::Builder)]
+pub struct MarkConversationReadUsecase {
+    conversation_repository: Arc<dyn ConversationRepository>,
+}
+
+impl MarkConversationReadUsecase {
+    /// Returns how many unread messages the member has left across the organization.
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        conversation_id: uuid::Uuid,
+    ) -> Result<i64, UsecaseError> {
+        let conversation = self
+            .conversation_repository
+            .find_by_id(&organization_id, &conversation_id)
+            .await?;
+
+        self.conversation_repository
+            .mark_read(&conversation, &user_id)
+            .await?;
+
+        let total_unread = self
+            .conversation_repository
+            .list_unread_counts(&organization_id, &user_id)
+            .await?
+            .iter()
+            .map(|count| count.unread)
+            .sum();
+
+        Ok(total_unread)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc, sync::Mutex};
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            conversation::{
+                AssigneeLoad, Conversation, ConversationFilter, ConversationStateChange,
+                ConversationView, UnreadCount,
+            },
+            message::MessageType,
+        },
+        repositories::{RepositoryError, conversation_repository::ConversationRepository},
+    };
+    use time::OffsetDateTime;
+
+    use super::MarkConversationReadUsecase;
+    use crate::usecases::{UsecaseError, list_unread_counts_usecase::ListUnreadCountsUsecase};
+
+    /// Keeps read cursors and message ids in memory, following the same rules as the
+    /// database: cursors point at the newest stored message, only move forward, and only
+    /// inbound messages count as unread.
+    #[derive(Default)]
+    struct FakeConversationRepository {
+        conversations: Mutex<Vec<Conversation>>,
+        messages: Mutex<Vec<(uuid::Uuid, uuid::Uuid, MessageType)>>,
+        read_cursors: Mutex<HashMap<(uuid::Uuid, uuid::Uuid), uuid::Uuid>>,
+    }
+
+    impl FakeConversationRepository {
+        fn receive(&self, conversation: &Conversation, message_type: MessageType) -> uuid::Uuid {
+            let id = uuid::Uuid::now_v7();
+            self.messages
+                .lock()
+                .expect("lock")
+                .push((id, conversation.id, message_type));
+            id
+        }
+
+        fn read_through(&self, conversation: &Conversation, user_id: &uuid::Uuid, id: uuid::Uuid) {
+            self.read_cursors
+                .lock()
+                .expect("lock")
+                .insert((conversation.id, *user_id), id);
+        }
+
+        fn read_cursor(
+            &self,
+            conversation: &Conversation,
+            user_id: &uuid::Uuid,
+        ) -> Option<uuid::Uuid> {
+            self.read_cursors
+                .lock()
+                .expect("lock")
+                .get(&(conversation.id, *user_id))
+                .copied()
+        }
+    }
+
+    #[async_trait]
+    impl ConversationRepository for FakeConversationRepository {
+        async fn create_conversation(
+            &self,
+            conversation: &Conversation,
+        ) -> Result<(), RepositoryError> {
+            self.conversations
+                .lock()
+                .expect("lock")
+                .push(conversation.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            organization_id: &uuid::Uuid,
+            id: &uuid::Uuid,
+        ) -> Result<Conversation, RepositoryError> {
+            self.conversations
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|conversation| {
+                    conversation.id == *id && conversation.organization_id == *organization_id
+                })
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn find_by_phone_number_and_recipient(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<Conversation, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_filtered(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _view: ConversationView,
+            _filter: ConversationFilter,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn change_state(
+            &self,
+            conversation: &Conversation,
+            _change: ConversationStateChange,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn record_activity(
+            &self,
+            conversation: &Conversation,
+            _last_message_at: OffsetDateTime,
+            _updated_at: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn reopen(&self, _conversation: &Conversation) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn assign(
+            &self,
+            conversation: &Conversation,
+            _assigned_user_id: Option<uuid::Uuid>,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn claim_assignment(
+            &self,
+            _conversation: &Conversation,
+            _assigned_user_id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+
+        async fn list_assignee_loads(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn mark_read(
+            &self,
+            conversation: &Conversation,
+            user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            let newest = self
+                .messages
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|(_, conversation_id, _)| *conversation_id == conversation.id)
+                .map(|(id, _, _)| *id)
+                .max();
+            if let Some(newest) = newest {
+                self.read_cursors
+                    .lock()
+                    .expect("lock")
+                    .entry((conversation.id, *user_id))
+                    .and_modify(|last_read| *last_read = (*last_read).max(newest))
+                    .or_insert(newest);
+            }
+            Ok(())
+        }
+
+        async fn list_unread_counts(
+            &self,
+            organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<Vec<UnreadCount>, RepositoryError> {
+            let conversations = self.conversations.lock().expect("lock");
+            let messages = self.messages.lock().expect("lock");
+            let read_cursors = self.read_cursors.lock().expect("lock");
+
+            let counts = conversations
+                .iter()
+                .filter(|conversation| conversation.organization_id == *organization_id)
+                .filter_map(|conversation| {
+                    let last_read = read_cursors.get(&(conversation.id, *user_id));
+                    let unread = messages
+                        .iter()
+                        .filter(|(id, conversation_id, message_type)| {
+                            *conversation_id == conversation.id
+                                && *message_type == MessageType::Inbound
+                                && last_read.is_none_or(|last_read| id > last_read)
+                        })
+                        .count() as i64;
+                    (unread > 0).then_some(UnreadCount {
+                        conversation_id: conversation.id,
+                        unread,
+                    })
+                })
+                .collect();
+
+            Ok(counts)
+        }
+
+        async fn wake_snoozed(
+            &self,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_conversation(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_away_reply(
+            &self,
+            _conversation: &Conversation,
+            _closed_since: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+    }
+
+    async fn conversation(
+        repository: &FakeConversationRepository,
+        organization_id: uuid::Uuid,
+    ) -> Conversation {
+        let now = OffsetDateTime::now_utc();
+        let conversation = Conversation::builder()
+            .id(uuid::Uuid::now_v7())
+            .phone_number_id(uuid::Uuid::now_v7())
+            .organization_id(organization_id)
+            .user_id(uuid::Uuid::now_v7())
+            .recipient_phone_number("+15555550100".to_owned())
+            .last_message_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        repository
+            .create_conversation(&conversation)
+            .await
+            .expect("create conversation");
+        conversation
+    }
+
+    fn usecases(
+        repository: &Arc<FakeConversationRepository>,
+    ) -> (MarkConversationReadUsecase, ListUnreadCountsUsecase) {
+        let mark_read = MarkConversationReadUsecase::builder()
+            .conversation_repository(repository.clone())
+            .build();
+        let list_unread_counts = ListUnreadCountsUsecase::builder()
+            .conversation_repository(repository.clone())
+            .build();
+        (mark_read, list_unread_counts)
+    }
+
+    #[tokio::test]
+    async fn counts_inbound_messages_until_the_member_reads_them() {
+        let repository = Arc::new(FakeConversationRepository::default());
+        let (mark_read, list_unread_counts) = usecases(&repository);
+        let organization_id = uuid::Uuid::now_v7();
+        let user_id = uuid::Uuid::now_v7();
+        let first = conversation(&repository, organization_id).await;
+        let second = conversation(&repository, organization_id).await;
+
+        repository.receive(&first, MessageType::Inbound);
+        repository.receive(&first, MessageType::Inbound);
+        repository.receive(&first, MessageType::Outbound);
+        repository.receive(&second, MessageType::Inbound);
+
+        let counts = list_unread_counts
+            .execute(organization_id, user_id)
+            .await
+            .expect("list unread counts");
+        assert_eq!(counts, HashMap::from([(first.id, 2), (second.id, 1)]));
+
+        let total_unread = mark_read
+            .execute(organization_id, user_id, first.id)
+            .await
+            .expect("mark read");
+        assert_eq!(total_unread, 1);
+
+        repository.receive(&first, MessageType::Inbound);
+        let counts = list_unread_counts
+            .execute(organization_id, user_id)
+            .await
+            .expect("list unread counts");
+        assert_eq!(counts, HashMap::from([(first.id, 1), (second.id, 1)]));
+    }
+
+    #[tokio::test]
+    async fn keeps_a_separate_read_cursor_for_each_member() {
+        let repository = Arc::new(FakeConversationRepository::default());
+        let (mark_read, list_unread_counts) = usecases(&repository);
+        let organization_id = uuid::Uuid::now_v7();
+        let reader = uuid::Uuid::now_v7();
+        let teammate = uuid::Uuid::now_v7();
+        let conversation = conversation(&repository, organization_id).await;
+        repository.receive(&conversation, MessageType::Inbound);
+
+        let total_unread = mark_read
+            .execute(organization_id, reader, conversation.id)
+            .await
+            .expect("mark read");
+        assert_eq!(total_unread, 0);
+
+        let counts = list_unread_counts
+            .execute(organization_id, teammate)
+            .await
+            .expect("list unread counts");
+        assert_eq!(counts, HashMap::from([(conversation.id, 1)]));
+        assert_eq!(repository.read_cursor(&conversation, &teammate), None);
+    }
+
+    #[tokio::test]
+    async fn never_moves_the_read_cursor_back() {
+        let repository = Arc::new(FakeConversationRepository::default());
+        let (mark_read, _) = usecases(&repository);
+        let organization_id = uuid::Uuid::now_v7();
+        let user_id = uuid::Uuid::now_v7();
+        let conversation = conversation(&repository, organization_id).await;
+        repository.receive(&conversation, MessageType::Inbound);
+        // A concurrent read already saw a message this request's snapshot does not include.
+        let read_later = uuid::Uuid::now_v7();
+        repository.read_through(&conversation, &user_id, read_later);
+
+        mark_read
+            .execute(organization_id, user_id, conversation.id)
+            .await
+            .expect("mark read");
+
+        assert_eq!(
+            repository.read_cursor(&conversation, &user_id),
+            Some(read_later)
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_mark_conversations_of_other_organizations_read() {
+        let repository = Arc::new(FakeConversationRepository::default());
+        let (mark_read, _) = usecases(&repository);
+        let user_id = uuid::Uuid::now_v7();
+        let conversation = conversation(&repository, uuid::Uuid::now_v7()).await;
+
+        let result = mark_read
+            .execute(uuid::Uuid::now_v7(), user_id, conversation.id)
+            .await;
+
+        assert!(matches!(result, Err(UsecaseError::EntityNotFound)));
+        assert_eq!(repository.read_cursor(&conversation, &user_id), None);
+    }
+}
@@ -1,19 +1,95 @@
+//! Organization permissions are enforced here rather than in the web handlers: every
+//! usecase that changes an organization's data starts with `authorize_member`, so the
+//! HTTP handlers and the background workers go through the same check. Reads are scoped
+//! by organization id and open to every member.
+
+pub mod accept_organization_invitation_usecase;
+pub mod assign_conversation_usecase;
+pub mod cancel_scheduled_message_usecase;
+pub mod change_campaign_status_usecase;
+pub mod change_conversation_state_usecase;
+pub mod change_organization_member_role_usecase;
+pub mod create_auto_reply_rule_usecase;
+pub mod create_campaign_usecase;
+pub mod create_contact_usecase;
 pub mod create_conversation_usecase;
+pub mod create_message_template_usecase;
 pub mod create_message_usecase;
+pub mod create_organization_usecase;
 pub mod create_phone_number_usecase;
 pub mod create_user_usecase;
+pub mod delete_auto_reply_rule_usecase;
+pub mod delete_business_hours_usecase;
+pub mod delete_contact_usecase;
 pub mod delete_conversation_usecase;
+pub mod delete_message_template_usecase;
 pub mod delete_phone_number_usecase;
+pub mod dispatch_outbound_messages_usecase;
+pub mod find_conversation_contacts_usecase;
+pub mod forgot_password_usecase;
+pub mod get_auto_reply_rule_usecase;
+pub mod get_business_hours_usecase;
+pub mod get_campaign_usecase;
+pub mod get_contact_import_usecase;
+pub mod get_contact_usecase;
 pub mod get_conversation_usecase;
 pub mod get_dashboard_home_usecase;
+pub mod get_message_attachment_usecase;
+pub mod get_message_status_history_usecase;
+pub mod get_message_template_usecase;
 pub mod get_phone_number_usecase;
+pub mod get_usage_usecase;
+pub mod import_contacts_usecase;
+pub mod ingest_telnyx_webhook_usecase;
+pub mod invite_organization_member_usecase;
+pub mod list_auto_reply_rules_usecase;
+pub mod list_campaigns_usecase;
+pub mod list_contacts_usecase;
 pub mod list_conversations_usecase;
+pub mod list_message_status_history_usecase;
+pub mod list_message_templates_usecase;
 pub mod list_messages_by_conversation_usecase;
+pub mod list_organization_members_usecase;
+pub mod list_organizations_usecase;
 pub mod list_phone_numbers_usecase;
+pub mod list_scheduled_messages_usecase;
+pub mod list_unread_counts_usecase;
+pub mod list_webhook_events_usecase;
 pub mod login_usecase;
+pub mod mark_conversation_read_usecase;
+pub mod notify_usage_limits_usecase;
+pub mod preview_message_template_usecase;
+pub mod preview_message_usecase;
+pub mod process_campaigns_usecase;
+pub mod process_contact_imports_usecase;
 pub mod process_telnyx_messaging_webhook_usecase;
+pub mod process_webhook_inbox_usecase;
+pub mod remove_organization_member_usecase;
+pub mod replay_webhook_event_usecase;
+pub mod reschedule_message_usecase;
+pub mod reset_password_usecase;
+pub mod revoke_organization_invitation_usecase;
+pub mod save_business_hours_usecase;
+pub mod save_usage_limit_usecase;
+pub mod send_email_verification_usecase;
+pub mod update_auto_reply_rule_usecase;
+pub mod update_contact_usecase;
+pub mod update_conversation_routing_usecase;
+pub mod update_message_template_usecase;
+pub mod verify_email_usecase;
+pub mod wake_snoozed_conversations_usecase;
+
+use std::{sync::Arc, time::Duration};
 
+use domain::models::campaign::CampaignStatus;
+use domain::models::message::InvalidStatusTransition;
+use domain::models::organization::{OrganizationRole, Permission};
+use domain::models::usage::UsagePeriod;
 use domain::repositories::RepositoryError;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::traits::email_sender::EmailSenderError;
+use domain::traits::media_downloader::MediaDownloadError;
+use domain::traits::media_storage::MediaStorageError;
 use domain::traits::outbound_message_service::OutboundMessageError;
 use garde::Report;
 
@@ -37,6 +113,39 @@ pub enum UsecaseError {
     #[error("Token generation failed")]
     TokenGenerationFailed,
 
+    #[error("Invalid or expired password reset token")]
+    InvalidResetToken,
+
+    #[error("Invalid or expired email verification token")]
+    InvalidVerificationToken,
+
+    #[error("Invalid or expired organization invitation")]
+    InvalidInvitation,
+
+    #[error("Organization role does not allow {0:?}")]
+    Forbidden(Permission),
+
+    #[error("Email address is not verified")]
+    EmailNotVerified,
+
+    #[error("Recipient has opted out of messages from this number")]
+    RecipientOptedOut,
+
+    #[error("Phone number already belongs to another contact")]
+    ContactPhoneNumberTaken,
+
+    #[error("Campaign cannot change from {from:?} to {to:?}")]
+    CampaignStatusConflict {
+        from: CampaignStatus,
+        to: CampaignStatus,
+    },
+
+    #[error("Message is no longer scheduled")]
+    MessageNotScheduled,
+
+    #[error("The {} usage limit has been reached", period.name())]
+    UsageLimitReached { period: UsagePeriod },
+
     #[error("Database error: {0}")]
     Database(String),
 
@@ -45,6 +154,12 @@ pub enum UsecaseError {
 
     #[error("External service error: {0}")]
     ExternalService(String),
+
+    #[error("Media storage error: {0}")]
+    MediaStorage(String),
+
+    #[error(transparent)]
+    InvalidStatusTransition(#[from] InvalidStatusTransition),
 }
 
 impl From<Report> for UsecaseError {
@@ -69,7 +184,11 @@ impl From<RepositoryError> for UsecaseError {
                     return UsecaseError::EmailAlreadyTaken;
                 }
 
-                return UsecaseError::Database(e);
+                if e.contains("contact_phone_numbers") {
+                    return UsecaseError::ContactPhoneNumberTaken;
+                }
+
+                UsecaseError::Database(e)
             }
             RepositoryError::NotFound => UsecaseError::EntityNotFound,
             RepositoryError::UnexpectedError(e) => UsecaseError::Database(e),
@@ -106,11 +225,55 @@ impl UsecaseError {
             UsecaseError::TokenGenerationFailed => {
                 "An error occurred while generating authentication token".to_string()
             }
+            UsecaseError::InvalidResetToken => {
+                "This password reset link is invalid or has expired".to_string()
+            }
+            UsecaseError::InvalidVerificationToken => {
+                "This verification link is invalid or has expired".to_string()
+            }
+            UsecaseError::InvalidInvitation => {
+                "This invitation is invalid, has expired or was sent to another email address"
+                    .to_string()
+            }
+            UsecaseError::Forbidden(_) => {
+                "Your role in this organization does not allow this action".to_string()
+            }
+            UsecaseError::EmailNotVerified => {
+                "Verify your email address before sending messages".to_string()
+            }
+            UsecaseError::RecipientOptedOut => {
+                "This recipient replied STOP and has opted out of messages from this number. \
+                 They can text START to opt back in."
+                    .to_string()
+            }
+            UsecaseError::ContactPhoneNumberTaken => {
+                "One of these phone numbers already belongs to another contact".to_string()
+            }
+            UsecaseError::CampaignStatusConflict { from, .. } => match from {
+                CampaignStatus::Running => "This campaign is already running".to_string(),
+                CampaignStatus::Paused => "This campaign is already paused".to_string(),
+                CampaignStatus::Completed => "This campaign has already completed".to_string(),
+                CampaignStatus::Cancelled => "This campaign has been cancelled".to_string(),
+            },
+            UsecaseError::MessageNotScheduled => {
+                "This message has already been sent and can no longer be changed".to_string()
+            }
+            UsecaseError::UsageLimitReached { period } => format!(
+                "You have reached your {} spending limit. Sending resumes when the period \
+                 resets or the limit is raised.",
+                period.name()
+            ),
             UsecaseError::Database(_) => "An error occurred while saving your account".to_string(),
             UsecaseError::MessageRejected(message) => message.to_owned(),
             UsecaseError::ExternalService(_) => {
                 "An external service is unavailable right now".to_string()
             }
+            UsecaseError::MediaStorage(_) => {
+                "An error occurred while storing your attachments".to_string()
+            }
+            UsecaseError::InvalidStatusTransition(_) => {
+                "This message can no longer change status".to_string()
+            }
         }
     }
 }
@@ -123,3 +286,209 @@ impl From<OutboundMessageError> for UsecaseError {
         }
     }
 }
+
+impl From<MediaStorageError> for UsecaseError {
+    fn from(value: MediaStorageError) -> Self {
+        match value {
+            MediaStorageError::NotFound => Self::EntityNotFound,
+            other => Self::MediaStorage(other.to_string()),
+        }
+    }
+}
+
+impl From<MediaDownloadError> for UsecaseError {
+    fn from(value: MediaDownloadError) -> Self {
+        Self::ExternalService(value.to_string())
+    }
+}
+
+impl From<EmailSenderError> for UsecaseError {
+    fn from(value: EmailSenderError) -> Self {
+        Self::ExternalService(value.to_string())
+    }
+}
+
+/// Fails with [`UsecaseError::Forbidden`] unless `role` grants `permission`.
+pub fn require_permission(
+    role: OrganizationRole,
+    permission: Permission,
+) -> Result<(), UsecaseError> {
+    if role.can(permission) {
+        Ok(())
+    } else {
+        Err(UsecaseError::Forbidden(permission))
+    }
+}
+
+/// Looks up the role of `user_id` and checks it grants `permission`. Non-members are
+/// refused the same way as members whose role is too low.
+pub(crate) async fn authorize_member(
+    organization_repository: &Arc<dyn OrganizationRepository>,
+    organization_id: &uuid::Uuid,
+    user_id: &uuid::Uuid,
+    permission: Permission,
+) -> Result<OrganizationRole, UsecaseError> {
+    let member = match organization_repository
+        .find_member(organization_id, user_id)
+        .await
+    {
+        Ok(member) => member,
+        Err(RepositoryError::NotFound) => return Err(UsecaseError::Forbidden(permission)),
+        Err(err) => return Err(err.into()),
+    };
+    require_permission(member.role, permission)?;
+
+    Ok(member.role)
+}
+
+/// Delay before the next attempt, doubling from `base` up to `max`.
+/// `attempts` counts the attempt that just failed.
+pub(crate) fn exponential_backoff(attempts: i32, base: Duration, max: Duration) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    base.saturating_mul(1 << exponent).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            conversation::ConversationRouting,
+            organization::{Organization, OrganizationMember, OrganizationRole, Permission},
+        },
+        repositories::{RepositoryError, organization_repository::OrganizationRepository},
+    };
+    use time::OffsetDateTime;
+
+    use super::{UsecaseError, authorize_member, require_permission};
+
+    struct FakeOrganizationRepository {
+        role: Option<OrganizationRole>,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            let role = self.role.ok_or(RepositoryError::NotFound)?;
+            Ok(OrganizationMember::builder()
+                .organization_id(*organization_id)
+                .user_id(*user_id)
+                .role(role)
+                .created_at(OffsetDateTime::now_utc())
+                .build())
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn require_permission_refuses_roles_without_it() {
+        assert!(require_permission(OrganizationRole::Agent, Permission::SendMessages).is_ok());
+        assert!(matches!(
+            require_permission(OrganizationRole::ReadOnly, Permission::SendMessages),
+            Err(UsecaseError::Forbidden(Permission::SendMessages))
+        ));
+        assert!(matches!(
+            require_permission(OrganizationRole::Agent, Permission::ManagePhoneNumbers),
+            Err(UsecaseError::Forbidden(Permission::ManagePhoneNumbers))
+        ));
+    }
+
+    #[tokio::test]
+    async fn authorize_member_refuses_non_members_and_read_only_members() {
+        let organization_id = uuid::Uuid::now_v7();
+        let user_id = uuid::Uuid::now_v7();
+
+        for role in [None, Some(OrganizationRole::ReadOnly)] {
+            let organization_repository: Arc<dyn OrganizationRepository> =
+                Arc::new(FakeOrganizationRepository { role });
+
+            let result = authorize_member(
+                &organization_repository,
+                &organization_id,
+                &user_id,
+                Permission::SendMessages,
+            )
+            .await;
+
+            assert!(
+                matches!(
+                    result,
+                    Err(UsecaseError::Forbidden(Permission::SendMessages))
+                ),
+                "{:?}",
+                role
+            );
+        }
+
+        let organization_repository: Arc<dyn OrganizationRepository> =
+            Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            });
+        let role = authorize_member(
+            &organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::SendMessages,
+        )
+        .await
+        .expect("agents may send messages");
+        assert_eq!(role, OrganizationRole::Agent);
+    }
+}
@@ -0,0 +1,365 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::{
+        money::format_micros,
+        usage::{UsageLimit, UsagePeriod},
+    },
+    repositories::{
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+        user_repository::UserRepository,
+    },
+    traits::email_sender::{EmailMessage, EmailSender},
+};
+
+#[derive(Debug, Clone)]
+pub struct UsageAlert {
+    pub user_id: uuid::Uuid,
+    pub period: UsagePeriod,
+    pub period_start: OffsetDateTime,
+    pub resets_at: OffsetDateTime,
+    pub soft_limit_micros: i64,
+    pub spent_micros: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct NotifyUsageLimitsResult {
+    /// Soft limits crossed since the last run. Each is reported once per period.
+    pub alerts: Vec<UsageAlert>,
+    /// Users whose spend could not be checked or whose email could not be sent.
+    pub failures: Vec<(uuid::Uuid, UsecaseError)>,
+}
+
+/// Emails users whose spend crossed a soft limit, once per period. The alerts are returned so
+/// the caller can also push them to open sessions.
+#[derive(bon::Builder)]
+pub struct NotifyUsageLimitsUsecase {
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    email_sender: Arc<dyn EmailSender>,
+}
+
+impl NotifyUsageLimitsUsecase {
+    pub async fn execute(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<NotifyUsageLimitsResult, UsecaseError> {
+        let limits = self.usage_limit_repository.list_with_soft_limit().await?;
+
+        let mut result = NotifyUsageLimitsResult::default();
+        for limit in limits {
+            match self.check(&limit, now).await {
+                Ok(Some(alert)) => {
+                    if let Err(err) = self.send_email(&alert).await {
+                        result.failures.push((limit.user_id, err));
+                    }
+                    result.alerts.push(alert);
+                }
+                Ok(None) => {}
+                Err(err) => result.failures.push((limit.user_id, err)),
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn check(
+        &self,
+        limit: &UsageLimit,
+        now: OffsetDateTime,
+    ) -> Result<Option<UsageAlert>, UsecaseError> {
+        let Some(soft_limit_micros) = limit.soft_limit_micros else {
+            return Ok(None);
+        };
+        let period_start = limit.period.start(now);
+        if limit
+            .soft_limit_notified_at
+            .is_some_and(|notified_at| notified_at >= period_start)
+        {
+            return Ok(None);
+        }
+
+        let spent = self
+            .usage_repository
+            .totals_since(&limit.user_id, period_start)
+            .await?;
+        if !limit.soft_limit_reached(spent.cost_micros) {
+            return Ok(None);
+        }
+        // Another worker may have got there first.
+        if !self
+            .usage_limit_repository
+            .claim_soft_limit_notification(&limit.user_id, limit.period, period_start, now)
+            .await?
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(UsageAlert {
+            user_id: limit.user_id,
+            period: limit.period,
+            period_start,
+            resets_at: limit.period.next_start(now),
+            soft_limit_micros,
+            spent_micros: spent.cost_micros,
+        }))
+    }
+
+    async fn send_email(&self, alert: &UsageAlert) -> Result<(), UsecaseError> {
+        let user = self.user_repository.find_by_id(&alert.user_id).await?;
+        let email = EmailMessage {
+            to: user.email,
+            subject: format!("You have reached your {} usage alert", alert.period.name()),
+            text_body: format!(
+                "Your messaging spend this {} period is {}, which is past your alert \
+                 threshold of {}.\n\n\
+                 Sending continues until any hard limit you set is reached. \
+                 The period resets on {} UTC.\n",
+                alert.period.name(),
+                format_micros(alert.spent_micros),
+                format_micros(alert.soft_limit_micros),
+                alert.resets_at.date()
+            ),
+        };
+        self.email_sender.send(&email).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            organization::{Organization, OrganizationMember},
+            usage::{UsageLimit, UsagePeriod, UsageRecord, UsageRollup, UsageTotals},
+            user::User,
+        },
+        repositories::{
+            RepositoryError, usage_limit_repository::UsageLimitRepository,
+            usage_repository::UsageRepository, user_repository::UserRepository,
+        },
+        traits::email_sender::{EmailMessage, EmailSender, EmailSenderError},
+    };
+    use time::{Duration, OffsetDateTime};
+
+    use super::NotifyUsageLimitsUsecase;
+
+    struct FakeUsageRepository {
+        spent_micros: i64,
+    }
+
+    #[async_trait]
+    impl UsageRepository for FakeUsageRepository {
+        async fn record_usage(&self, _record: &UsageRecord) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn record_cost(
+            &self,
+            _message_id: &uuid::Uuid,
+            _cost_micros: i64,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn totals_since(
+            &self,
+            _user_id: &uuid::Uuid,
+            _since: OffsetDateTime,
+        ) -> Result<UsageTotals, RepositoryError> {
+            Ok(UsageTotals {
+                cost_micros: self.spent_micros,
+                ..UsageTotals::default()
+            })
+        }
+
+        async fn rollups(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _since: OffsetDateTime,
+        ) -> Result<Vec<UsageRollup>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    struct FakeUsageLimitRepository {
+        limits: Mutex<Vec<UsageLimit>>,
+    }
+
+    #[async_trait]
+    impl UsageLimitRepository for FakeUsageLimitRepository {
+        async fn save_limit(&self, _limit: &UsageLimit) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(self.limits.lock().expect("lock").clone())
+        }
+
+        async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(self.limits.lock().expect("lock").clone())
+        }
+
+        async fn delete_limit(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_soft_limit_notification(
+            &self,
+            user_id: &uuid::Uuid,
+            period: UsagePeriod,
+            period_start: OffsetDateTime,
+            now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            let mut limits = self.limits.lock().expect("lock");
+            let Some(limit) = limits
+                .iter_mut()
+                .find(|limit| limit.user_id == *user_id && limit.period == period)
+            else {
+                return Ok(false);
+            };
+            if limit
+                .soft_limit_notified_at
+                .is_some_and(|notified_at| notified_at >= period_start)
+            {
+                return Ok(false);
+            }
+
+            limit.soft_limit_notified_at = Some(now);
+            Ok(true)
+        }
+    }
+
+    struct FakeUserRepository {
+        user_id: uuid::Uuid,
+    }
+
+    impl FakeUserRepository {
+        fn user(&self) -> User {
+            let now = OffsetDateTime::now_utc();
+            User::builder()
+                .id(self.user_id)
+                .email("owner@example.com".to_owned())
+                .hash(String::new())
+                .salt(String::new())
+                .email_verified(true)
+                .created_at(now)
+                .updated_at(now)
+                .build()
+        }
+    }
+
+    #[async_trait]
+    impl UserRepository for FakeUserRepository {
+        async fn create_user(&self, _user: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn create_user_with_organization(
+            &self,
+            _user: &User,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<User, RepositoryError> {
+            Ok(self.user())
+        }
+
+        async fn find_by_email(&self, _email: &str) -> Result<User, RepositoryError> {
+            Ok(self.user())
+        }
+
+        async fn list(&self, _page: u64, _limit: u64) -> Result<Vec<User>, RepositoryError> {
+            Ok(vec![self.user()])
+        }
+
+        async fn update_user(&self, _new_data: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete_user(&self, _id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeEmailSender {
+        emails: Mutex<Vec<EmailMessage>>,
+    }
+
+    #[async_trait]
+    impl EmailSender for FakeEmailSender {
+        async fn send(&self, email: &EmailMessage) -> Result<(), EmailSenderError> {
+            self.emails.lock().expect("lock").push(email.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn notifies_once_per_period_when_the_soft_limit_is_crossed() {
+        let now = OffsetDateTime::now_utc();
+        let user_id = uuid::Uuid::now_v7();
+        let email_sender = Arc::new(FakeEmailSender::default());
+        let usecase = NotifyUsageLimitsUsecase::builder()
+            .usage_repository(Arc::new(FakeUsageRepository {
+                spent_micros: 12_500_000,
+            }))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository {
+                limits: Mutex::new(vec![
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Monthly)
+                        .soft_limit_micros(10_000_000)
+                        .hard_limit_micros(20_000_000)
+                        // Notified last month, which does not count for this one.
+                        .soft_limit_notified_at(UsagePeriod::Monthly.start(now) - Duration::days(3))
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Daily)
+                        .soft_limit_micros(15_000_000)
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                ]),
+            }))
+            .user_repository(Arc::new(FakeUserRepository { user_id }))
+            .email_sender(email_sender.clone())
+            .build();
+
+        let first = usecase.execute(now).await.expect("first run");
+        let second = usecase.execute(now).await.expect("second run");
+
+        assert!(first.failures.is_empty());
+        assert_eq!(first.alerts.len(), 1);
+        assert_eq!(first.alerts[0].period, UsagePeriod::Monthly);
+        assert_eq!(first.alerts[0].spent_micros, 12_500_000);
+        assert!(second.alerts.is_empty());
+
+        let emails = email_sender.emails.lock().expect("lock");
+        assert_eq!(emails.len(), 1);
+        assert_eq!(emails[0].to, "owner@example.com");
+        assert!(emails[0].text_body.contains("12.50"));
+    }
+}
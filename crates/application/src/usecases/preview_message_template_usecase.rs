@@ -0,0 +1,114 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    commands::PreviewMessageTemplateCommand, responses::MessageTemplatePreview,
+    usecases::UsecaseError,
+};
+use domain::{
+    models::{
+        conversation::Conversation,
+        message_template::{conversation_template_variables, preview_template},
+        phone_number::PhoneNumber,
+        sms_encoding::count_segments,
+    },
+    repositories::{
+        contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+        message_template_repository::MessageTemplateRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+/// Renders a template the way it would be sent, without sending it. Placeholders without a
+/// value are reported instead of failing, so a draft can be previewed before a recipient
+/// is picked.
+#[derive(bon::Builder)]
+pub struct PreviewMessageTemplateUsecase {
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+}
+
+impl PreviewMessageTemplateUsecase {
+    pub async fn execute(
+        &self,
+        cmd: PreviewMessageTemplateCommand,
+    ) -> Result<MessageTemplatePreview, UsecaseError> {
+        let template = self
+            .message_template_repository
+            .find_by_id(&cmd.user_id, &cmd.template_id)
+            .await?;
+
+        let variables = match cmd.conversation_id {
+            Some(conversation_id) => {
+                let conversation = self
+                    .conversation_repository
+                    .find_by_id(&cmd.organization_id, &conversation_id)
+                    .await?;
+                let phone_number = self
+                    .phone_number_repository
+                    .find_by_id(&cmd.organization_id, &conversation.phone_number_id)
+                    .await?;
+
+                conversation_variables(
+                    self.contact_repository.as_ref(),
+                    &conversation,
+                    &phone_number,
+                )
+                .await?
+            }
+            None => BTreeMap::new(),
+        };
+
+        let (content, unresolved_variables) = preview_template(&template.body, &variables);
+        let segments = count_segments(&content);
+
+        Ok(MessageTemplatePreview {
+            template,
+            content,
+            unresolved_variables,
+            segments,
+        })
+    }
+}
+
+/// Template variables for a message in `conversation`, with the recipient looked up in the
+/// address book.
+pub(crate) async fn conversation_variables(
+    contact_repository: &dyn ContactRepository,
+    conversation: &Conversation,
+    sender: &PhoneNumber,
+) -> Result<BTreeMap<String, String>, UsecaseError> {
+    let recipient_phone_number = conversation
+        .recipient_phone_number
+        .as_deref()
+        .unwrap_or_default();
+    let contact = if recipient_phone_number.is_empty() {
+        None
+    } else {
+        contact_repository
+            .find_by_phone_numbers(
+                &conversation.organization_id,
+                &[recipient_phone_number.to_owned()],
+            )
+            .await?
+            .into_iter()
+            .next()
+    };
+
+    Ok(conversation_template_variables(
+        contact.as_ref(),
+        recipient_phone_number,
+        sender,
+    ))
+}
+
+pub(crate) fn unresolved_variables_error(names: &[String]) -> UsecaseError {
+    let names = names
+        .iter()
+        .map(|name| format!("{{{{{}}}}}", name))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    garde::Error::new(format!("No value for {} in this conversation", names)).into()
+}
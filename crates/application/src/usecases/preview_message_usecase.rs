@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::{
+    commands::PreviewMessageCommand,
+    responses::MessagePreview,
+    usecases::{UsecaseError, preview_message_template_usecase::conversation_variables},
+};
+use domain::{
+    models::{message_template::preview_template, sms_encoding::analyze},
+    repositories::{
+        contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+        message_template_repository::MessageTemplateRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+/// Shows how a message would go out in a conversation without sending it: the rendered
+/// text, its SMS segments and encoding, GSM-7 stand-ins for characters that force UCS-2
+/// and, when a segment price is configured, the estimated cost.
+#[derive(bon::Builder)]
+pub struct PreviewMessageUsecase {
+    conversation_repository: Arc<dyn ConversationRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    /// Price of one segment in micros, see `domain::models::money`.
+    segment_price_micros: Option<i64>,
+}
+
+impl PreviewMessageUsecase {
+    pub async fn execute(
+        &self,
+        cmd: PreviewMessageCommand,
+    ) -> Result<MessagePreview, UsecaseError> {
+        let content = cmd.content.trim();
+        if cmd.template_id.is_some() && !content.is_empty() {
+            return Err(
+                garde::Error::new("Send either message content or a template, not both").into(),
+            );
+        }
+
+        let conversation = self
+            .conversation_repository
+            .find_by_id(&cmd.organization_id, &cmd.conversation_id)
+            .await?;
+        let (content, unresolved_variables) = match cmd.template_id {
+            Some(template_id) => {
+                let template = self
+                    .message_template_repository
+                    .find_by_id(&cmd.user_id, &template_id)
+                    .await?;
+                let phone_number = self
+                    .phone_number_repository
+                    .find_by_id(&cmd.organization_id, &conversation.phone_number_id)
+                    .await?;
+                let variables = conversation_variables(
+                    self.contact_repository.as_ref(),
+                    &conversation,
+                    &phone_number,
+                )
+                .await?;
+
+                preview_template(&template.body, &variables)
+            }
+            None => (content.to_owned(), Vec::new()),
+        };
+
+        let analysis = analyze(&content);
+        let estimated_cost_micros = self
+            .segment_price_micros
+            .map(|price| price.saturating_mul(analysis.count.segments as i64));
+
+        Ok(MessagePreview {
+            content,
+            unresolved_variables,
+            analysis,
+            estimated_cost_micros,
+        })
+    }
+}
@@ -0,0 +1,1447 @@
+use std::{sync::Arc, time::Duration};
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::CreateMessageCommand,
+    usecases::{UsecaseError, authorize_member, create_message_usecase::CreateMessageUsecase},
+};
+use domain::{
+    models::{
+        campaign::{Campaign, CampaignRecipient, CampaignRecipientStatus, CampaignStatus},
+        conversation::Conversation,
+        organization::Permission,
+    },
+    repositories::{
+        RepositoryError, campaign_repository::CampaignRepository,
+        conversation_repository::ConversationRepository, opt_out_repository::OptOutRepository,
+        organization_repository::OrganizationRepository,
+    },
+};
+
+pub const CAMPAIGN_BATCH_INTERVAL: Duration = Duration::from_secs(1);
+const CAMPAIGNS_PER_RUN: usize = 10;
+const CAMPAIGN_LEASE: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+pub struct ProcessCampaignsResult {
+    /// Recipients messaged during this run.
+    pub messaged: usize,
+    /// Recipients not messaged because they opted out or their message was refused.
+    pub skipped: usize,
+    pub completed: Vec<uuid::Uuid>,
+    /// Campaigns whose batch stopped early. The remaining recipients are retried with the
+    /// next batch.
+    pub failures: Vec<(uuid::Uuid, UsecaseError)>,
+}
+
+/// Fans running campaigns out into per-recipient conversations and messages, at most
+/// `messages_per_second` recipients per campaign per batch. Messages go through
+/// `CreateMessageUsecase`, so opt-outs and the sender policy apply as for manual sends.
+/// Campaigns whose creator left the organization or lost the right to send are paused.
+#[derive(bon::Builder)]
+pub struct ProcessCampaignsUsecase {
+    campaign_repository: Arc<dyn CampaignRepository>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    create_message_usecase: CreateMessageUsecase,
+    #[builder(default = CAMPAIGNS_PER_RUN)]
+    campaigns_per_run: usize,
+}
+
+impl ProcessCampaignsUsecase {
+    pub async fn execute(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<ProcessCampaignsResult, UsecaseError> {
+        let campaigns = self
+            .campaign_repository
+            .claim_due(now, now + CAMPAIGN_LEASE, self.campaigns_per_run)
+            .await?;
+
+        let mut result = ProcessCampaignsResult::default();
+        for campaign in campaigns {
+            if let Err(err) = self.send_batch(&campaign, now, &mut result).await {
+                result.failures.push((campaign.id, err));
+            }
+
+            self.campaign_repository
+                .schedule_next_batch(&campaign.id, now + CAMPAIGN_BATCH_INTERVAL)
+                .await?;
+        }
+
+        Ok(result)
+    }
+
+    async fn send_batch(
+        &self,
+        campaign: &Campaign,
+        now: OffsetDateTime,
+        result: &mut ProcessCampaignsResult,
+    ) -> Result<(), UsecaseError> {
+        let recipients = self
+            .campaign_repository
+            .list_pending_recipients(&campaign.id, campaign.messages_per_second.max(1) as usize)
+            .await?;
+
+        if recipients.is_empty() {
+            if self
+                .campaign_repository
+                .change_status(
+                    &campaign.id,
+                    CampaignStatus::Running,
+                    CampaignStatus::Completed,
+                    now,
+                )
+                .await?
+            {
+                result.completed.push(campaign.id);
+            }
+            return Ok(());
+        }
+
+        if let Err(err) = authorize_member(
+            &self.organization_repository,
+            &campaign.organization_id,
+            &campaign.user_id,
+            Permission::SendMessages,
+        )
+        .await
+        {
+            if matches!(err, UsecaseError::Forbidden(_)) {
+                self.campaign_repository
+                    .pause(&campaign.id, &pause_reason(&err), now)
+                    .await?;
+            }
+            return Err(err);
+        }
+
+        for mut recipient in recipients {
+            match self.send_to(campaign, &recipient).await {
+                Ok(message_id) => {
+                    recipient.status = CampaignRecipientStatus::Messaged;
+                    recipient.message_id = Some(message_id);
+                    result.messaged += 1;
+                }
+                Err(
+                    err @ (UsecaseError::EmailNotVerified | UsecaseError::UsageLimitReached { .. }),
+                ) => {
+                    // Every other recipient would be refused as well.
+                    self.campaign_repository
+                        .pause(&campaign.id, &pause_reason(&err), now)
+                        .await?;
+                    return Err(err);
+                }
+                Err(err @ (UsecaseError::RecipientOptedOut | UsecaseError::Validation(_))) => {
+                    recipient.status = CampaignRecipientStatus::Skipped;
+                    recipient.reason = Some(match err {
+                        UsecaseError::RecipientOptedOut => "Recipient opted out".to_owned(),
+                        err => err.to_http_message(),
+                    });
+                    result.skipped += 1;
+                }
+                Err(err) => return Err(err),
+            }
+
+            recipient.updated_at = now;
+            self.campaign_repository
+                .update_recipient(&recipient)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn send_to(
+        &self,
+        campaign: &Campaign,
+        recipient: &CampaignRecipient,
+    ) -> Result<uuid::Uuid, UsecaseError> {
+        // Checked before the conversation is created so opted-out recipients do not end up
+        // with an empty conversation.
+        if self
+            .opt_out_repository
+            .is_opted_out(&campaign.phone_number_id, &recipient.recipient_phone_number)
+            .await?
+        {
+            return Err(UsecaseError::RecipientOptedOut);
+        }
+
+        let conversation = self
+            .find_or_create_conversation(campaign, recipient)
+            .await?;
+
+        let result = self
+            .create_message_usecase
+            .execute(CreateMessageCommand {
+                user_id: campaign.user_id,
+                organization_id: campaign.organization_id,
+                conversation_id: conversation.id,
+                content: recipient.content.to_owned(),
+                attachments: Vec::new(),
+                scheduled_at: None,
+                template_id: None,
+            })
+            .await?;
+
+        Ok(result.message.id)
+    }
+
+    async fn find_or_create_conversation(
+        &self,
+        campaign: &Campaign,
+        recipient: &CampaignRecipient,
+    ) -> Result<Conversation, UsecaseError> {
+        match self
+            .conversation_repository
+            .find_by_phone_number_and_recipient(
+                &campaign.organization_id,
+                &campaign.phone_number_id,
+                &recipient.recipient_phone_number,
+            )
+            .await
+        {
+            Ok(conversation) => return Ok(conversation),
+            Err(RepositoryError::NotFound) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let conversation = Conversation::builder()
+            .id(uuid::Uuid::now_v7())
+            .phone_number_id(campaign.phone_number_id)
+            .organization_id(campaign.organization_id)
+            .user_id(campaign.user_id)
+            .maybe_recipient_phone_number(Some(recipient.recipient_phone_number.to_owned()))
+            .last_message_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        self.conversation_repository
+            .create_conversation(&conversation)
+            .await?;
+
+        Ok(conversation)
+    }
+}
+
+/// Shown on a campaign the worker paused because of `err`.
+fn pause_reason(err: &UsecaseError) -> String {
+    match err {
+        UsecaseError::UsageLimitReached { period } => format!(
+            "Paused because the {} spending limit was reached. Resume the campaign once the \
+             period resets or the limit is raised.",
+            period.name()
+        ),
+        UsecaseError::Forbidden(_) => "Paused because its creator can no longer send messages \
+                                       in this organization."
+            .to_owned(),
+        err => format!("Paused: {}", err.to_http_message()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            campaign::{
+                Campaign, CampaignProgress, CampaignRecipient, CampaignRecipientStatus,
+                CampaignStatus,
+            },
+            contact::Contact,
+            conversation::{
+                AssigneeLoad, Conversation, ConversationFilter, ConversationRouting,
+                ConversationStateChange, ConversationView, UnreadCount,
+            },
+            message::Message,
+            message_attachment::MessageAttachment,
+            message_template::MessageTemplate,
+            opt_out::OptOut,
+            organization::{Organization, OrganizationMember, OrganizationRole, Permission},
+            outbound_message_job::OutboundMessageJob,
+            phone_number::PhoneNumber,
+            usage::{UsageLimit, UsagePeriod, UsageRecord, UsageRollup, UsageTotals},
+            user::User,
+        },
+        repositories::{
+            RepositoryError,
+            campaign_repository::CampaignRepository,
+            contact_repository::ContactRepository,
+            conversation_repository::ConversationRepository,
+            message_repository::{MessagePage, MessageRepository},
+            message_template_repository::MessageTemplateRepository,
+            opt_out_repository::OptOutRepository,
+            organization_repository::OrganizationRepository,
+            outbound_message_queue_repository::OutboundMessageQueueRepository,
+            phone_number_repository::PhoneNumberRepository,
+            usage_limit_repository::UsageLimitRepository,
+            usage_repository::UsageRepository,
+            user_repository::UserRepository,
+        },
+        traits::media_storage::{MediaStorage, MediaStorageError, StoredMedia},
+    };
+    use time::OffsetDateTime;
+
+    use crate::usecases::{UsecaseError, create_message_usecase::CreateMessageUsecase};
+
+    use super::ProcessCampaignsUsecase;
+
+    struct FakeCampaignRepository {
+        campaign: Mutex<Campaign>,
+        recipients: Mutex<Vec<CampaignRecipient>>,
+    }
+
+    #[async_trait]
+    impl CampaignRepository for FakeCampaignRepository {
+        async fn create_campaign(
+            &self,
+            _campaign: &Campaign,
+            _recipients: &[CampaignRecipient],
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Campaign, RepositoryError> {
+            Ok(self.campaign.lock().expect("lock").clone())
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Campaign>, RepositoryError> {
+            Ok(vec![self.campaign.lock().expect("lock").clone()])
+        }
+
+        async fn change_status(
+            &self,
+            _id: &uuid::Uuid,
+            from: CampaignStatus,
+            to: CampaignStatus,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            let mut campaign = self.campaign.lock().expect("lock");
+            if campaign.status != from {
+                return Ok(false);
+            }
+            campaign.status = to;
+            campaign.pause_reason = None;
+            Ok(true)
+        }
+
+        async fn pause(
+            &self,
+            _id: &uuid::Uuid,
+            reason: &str,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            let mut campaign = self.campaign.lock().expect("lock");
+            if campaign.status != CampaignStatus::Running {
+                return Ok(false);
+            }
+            campaign.status = CampaignStatus::Paused;
+            campaign.pause_reason = Some(reason.to_owned());
+            Ok(true)
+        }
+
+        async fn claim_due(
+            &self,
+            now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            _limit: usize,
+        ) -> Result<Vec<Campaign>, RepositoryError> {
+            let campaign = self.campaign.lock().expect("lock").clone();
+            let due = campaign.status == CampaignStatus::Running && campaign.next_batch_at <= now;
+            Ok(due.then_some(campaign).into_iter().collect())
+        }
+
+        async fn schedule_next_batch(
+            &self,
+            _id: &uuid::Uuid,
+            next_batch_at: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            self.campaign.lock().expect("lock").next_batch_at = next_batch_at;
+            Ok(())
+        }
+
+        async fn list_pending_recipients(
+            &self,
+            _campaign_id: &uuid::Uuid,
+            limit: usize,
+        ) -> Result<Vec<CampaignRecipient>, RepositoryError> {
+            Ok(self
+                .recipients
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|recipient| recipient.status == CampaignRecipientStatus::Pending)
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn update_recipient(
+            &self,
+            recipient: &CampaignRecipient,
+        ) -> Result<(), RepositoryError> {
+            let mut recipients = self.recipients.lock().expect("lock");
+            let stored = recipients
+                .iter_mut()
+                .find(|stored| stored.id == recipient.id)
+                .ok_or(RepositoryError::NotFound)?;
+            *stored = recipient.clone();
+            Ok(())
+        }
+
+        async fn progress(
+            &self,
+            _campaign_id: &uuid::Uuid,
+        ) -> Result<CampaignProgress, RepositoryError> {
+            Ok(CampaignProgress::default())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeConversationRepository {
+        conversations: Mutex<Vec<Conversation>>,
+    }
+
+    #[async_trait]
+    impl ConversationRepository for FakeConversationRepository {
+        async fn create_conversation(
+            &self,
+            conversation: &Conversation,
+        ) -> Result<(), RepositoryError> {
+            self.conversations
+                .lock()
+                .expect("lock")
+                .push(conversation.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            id: &uuid::Uuid,
+        ) -> Result<Conversation, RepositoryError> {
+            self.conversations
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|conversation| conversation.id == *id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn find_by_phone_number_and_recipient(
+            &self,
+            _user_id: &uuid::Uuid,
+            phone_number_id: &uuid::Uuid,
+            recipient_phone_number: &str,
+        ) -> Result<Conversation, RepositoryError> {
+            self.conversations
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|conversation| {
+                    conversation.phone_number_id == *phone_number_id
+                        && conversation.recipient_phone_number.as_deref()
+                            == Some(recipient_phone_number)
+                })
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(self.conversations.lock().expect("lock").clone())
+        }
+
+        async fn list_filtered(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _view: ConversationView,
+            _filter: ConversationFilter,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn change_state(
+            &self,
+            conversation: &Conversation,
+            _change: ConversationStateChange,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn record_activity(
+            &self,
+            conversation: &Conversation,
+            _last_message_at: OffsetDateTime,
+            _updated_at: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn reopen(&self, _conversation: &Conversation) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn assign(
+            &self,
+            conversation: &Conversation,
+            _assigned_user_id: Option<uuid::Uuid>,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn claim_assignment(
+            &self,
+            _conversation: &Conversation,
+            _assigned_user_id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+
+        async fn list_assignee_loads(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn mark_read(
+            &self,
+            _conversation: &Conversation,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_unread_counts(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UnreadCount>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn wake_snoozed(
+            &self,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_conversation(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_away_reply(
+            &self,
+            _conversation: &Conversation,
+            _closed_since: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeMessageRepository {
+        created_messages: Mutex<Vec<Message>>,
+    }
+
+    #[async_trait]
+    impl MessageRepository for FakeMessageRepository {
+        async fn create_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            self.created_messages
+                .lock()
+                .expect("lock")
+                .push(message.clone());
+            Ok(message.clone())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn count_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn find_by_provider_message_id(
+            &self,
+            _provider_message_id: &str,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_reply_to(
+            &self,
+            _message_id: &uuid::Uuid,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_page_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+            _cursor: Option<&uuid::Uuid>,
+            _limit: usize,
+        ) -> Result<MessagePage, RepositoryError> {
+            Ok(MessagePage {
+                messages: Vec::new(),
+                next_cursor: None,
+            })
+        }
+
+        async fn update_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            Ok(message.clone())
+        }
+
+        async fn list_scheduled_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+            _scheduled_at: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn delete_scheduled_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn release_scheduled_message(
+            &self,
+            _id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn find_attachment_by_id(
+            &self,
+            _id: &uuid::Uuid,
+        ) -> Result<MessageAttachment, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+    }
+
+    struct FakePhoneNumberRepository {
+        phone_number: PhoneNumber,
+    }
+
+    #[async_trait]
+    impl PhoneNumberRepository for FakePhoneNumberRepository {
+        async fn create_phone_number(
+            &self,
+            _phone_number: &PhoneNumber,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<PhoneNumber, RepositoryError> {
+            Ok(self.phone_number.clone())
+        }
+
+        async fn find_by_phone(&self, _phone: &str) -> Result<PhoneNumber, RepositoryError> {
+            Ok(self.phone_number.clone())
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<PhoneNumber>, RepositoryError> {
+            Ok(vec![self.phone_number.clone()])
+        }
+
+        async fn delete_phone_number(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeOutboundMessageQueueRepository {
+        jobs: Mutex<Vec<OutboundMessageJob>>,
+    }
+
+    #[async_trait]
+    impl OutboundMessageQueueRepository for FakeOutboundMessageQueueRepository {
+        async fn enqueue(&self, job: &OutboundMessageJob) -> Result<(), RepositoryError> {
+            self.jobs.lock().expect("lock").push(job.clone());
+            Ok(())
+        }
+
+        async fn claim_due(
+            &self,
+            _now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            _limit: usize,
+        ) -> Result<Vec<OutboundMessageJob>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+            _last_error: &str,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn postpone(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn complete(&self, _message_id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeOptOutRepository {
+        opted_out: Vec<String>,
+    }
+
+    #[async_trait]
+    impl OptOutRepository for FakeOptOutRepository {
+        async fn opt_out(&self, _opt_out: &OptOut) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn opt_in(
+            &self,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn is_opted_out(
+            &self,
+            _phone_number_id: &uuid::Uuid,
+            recipient_phone_number: &str,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self
+                .opted_out
+                .iter()
+                .any(|recipient| recipient == recipient_phone_number))
+        }
+    }
+
+    struct FakeMediaStorage;
+
+    #[async_trait]
+    impl MediaStorage for FakeMediaStorage {
+        async fn put(&self, _key: &str, bytes: &[u8]) -> Result<StoredMedia, MediaStorageError> {
+            Ok(StoredMedia {
+                size_bytes: bytes.len() as i64,
+                checksum_sha256: String::new(),
+            })
+        }
+
+        async fn get(&self, _key: &str) -> Result<Vec<u8>, MediaStorageError> {
+            Err(MediaStorageError::NotFound)
+        }
+
+        async fn delete(&self, _key: &str) -> Result<(), MediaStorageError> {
+            Ok(())
+        }
+    }
+
+    struct FakeUsageRepository;
+
+    #[async_trait]
+    impl UsageRepository for FakeUsageRepository {
+        async fn record_usage(&self, _record: &UsageRecord) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn record_cost(
+            &self,
+            _message_id: &uuid::Uuid,
+            _cost_micros: i64,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn totals_since(
+            &self,
+            _user_id: &uuid::Uuid,
+            _since: OffsetDateTime,
+        ) -> Result<UsageTotals, RepositoryError> {
+            Ok(UsageTotals::default())
+        }
+
+        async fn rollups(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _since: OffsetDateTime,
+        ) -> Result<Vec<UsageRollup>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeUsageLimitRepository {
+        limits: Vec<UsageLimit>,
+    }
+
+    #[async_trait]
+    impl UsageLimitRepository for FakeUsageLimitRepository {
+        async fn save_limit(&self, _limit: &UsageLimit) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            user_id: &uuid::Uuid,
+        ) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(self
+                .limits
+                .iter()
+                .filter(|limit| limit.user_id == *user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_limit(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_soft_limit_notification(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _period_start: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct FakeUserRepository;
+
+    #[async_trait]
+    impl UserRepository for FakeUserRepository {
+        async fn create_user(&self, _user: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn create_user_with_organization(
+            &self,
+            _user: &User,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<User, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_by_email(&self, _email: &str) -> Result<User, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list(&self, _page: u64, _limit: u64) -> Result<Vec<User>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_user(&self, _new_data: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete_user(&self, _id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeMessageTemplateRepository;
+
+    #[async_trait]
+    impl MessageTemplateRepository for FakeMessageTemplateRepository {
+        async fn create_template(
+            &self,
+            _template: &MessageTemplate,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_template(
+            &self,
+            _template: &MessageTemplate,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<MessageTemplate, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<MessageTemplate>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_template(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeContactRepository;
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_by_phone_numbers(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeOrganizationRepository {
+        role: Option<OrganizationRole>,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            let role = self.role.ok_or(RepositoryError::NotFound)?;
+            Ok(OrganizationMember::builder()
+                .organization_id(*organization_id)
+                .user_id(*user_id)
+                .role(role)
+                .created_at(OffsetDateTime::now_utc())
+                .build())
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn build_recipient(campaign: &Campaign, phone_number: &str) -> CampaignRecipient {
+        CampaignRecipient::builder()
+            .id(uuid::Uuid::now_v7())
+            .campaign_id(campaign.id)
+            .user_id(campaign.user_id)
+            .recipient_phone_number(phone_number.to_owned())
+            .status(CampaignRecipientStatus::Pending)
+            .content(format!("Hello {}", phone_number))
+            .created_at(campaign.created_at)
+            .updated_at(campaign.created_at)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn sends_throttled_batches_skipping_opted_out_recipients() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        let campaign = Campaign::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(user_id)
+            .phone_number_id(phone_number_id)
+            .name("Spring sale".to_owned())
+            .body("Hello {{phone_number}}".to_owned())
+            .messages_per_second(2)
+            .status(CampaignStatus::Running)
+            .next_batch_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let campaign_repository = Arc::new(FakeCampaignRepository {
+            recipients: Mutex::new(vec![
+                build_recipient(&campaign, "+14155550001"),
+                build_recipient(&campaign, "+14155550002"),
+                build_recipient(&campaign, "+14155550003"),
+            ]),
+            campaign: Mutex::new(campaign),
+        });
+        let conversation_repository = Arc::new(FakeConversationRepository::default());
+        let message_repository = Arc::new(FakeMessageRepository::default());
+        let queue_repository = Arc::new(FakeOutboundMessageQueueRepository::default());
+        let opt_out_repository = Arc::new(FakeOptOutRepository {
+            opted_out: vec!["+14155550002".to_owned()],
+        });
+        let create_message_usecase = CreateMessageUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(message_repository.clone())
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: PhoneNumber::builder()
+                    .id(phone_number_id)
+                    .organization_id(user_id)
+                    .user_id(user_id)
+                    .name("Primary".to_owned())
+                    .phone("+13125550100".to_owned())
+                    .created_at(now)
+                    .updated_at(now)
+                    .build(),
+            }))
+            .user_repository(Arc::new(FakeUserRepository))
+            .outbound_message_queue_repository(queue_repository.clone())
+            .opt_out_repository(opt_out_repository.clone())
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository))
+            .contact_repository(Arc::new(FakeContactRepository))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+        let usecase = ProcessCampaignsUsecase::builder()
+            .campaign_repository(campaign_repository.clone())
+            .conversation_repository(conversation_repository.clone())
+            .opt_out_repository(opt_out_repository)
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .create_message_usecase(create_message_usecase)
+            .build();
+
+        let first = usecase.execute(now).await.expect("first batch");
+        assert_eq!((first.messaged, first.skipped), (1, 1));
+        assert!(first.failures.is_empty());
+
+        // The next batch is not due within the same second.
+        let early = usecase.execute(now).await.expect("early run");
+        assert_eq!((early.messaged, early.skipped), (0, 0));
+
+        let later = now + time::Duration::seconds(1);
+        let second = usecase.execute(later).await.expect("second batch");
+        assert_eq!((second.messaged, second.skipped), (1, 0));
+        assert!(second.completed.is_empty());
+
+        let last = usecase
+            .execute(later + time::Duration::seconds(1))
+            .await
+            .expect("last run");
+        assert_eq!(last.completed.len(), 1);
+
+        let recipients = campaign_repository.recipients.lock().expect("lock").clone();
+        assert_eq!(
+            recipients
+                .iter()
+                .map(|recipient| recipient.status)
+                .collect::<Vec<_>>(),
+            vec![
+                CampaignRecipientStatus::Messaged,
+                CampaignRecipientStatus::Skipped,
+                CampaignRecipientStatus::Messaged,
+            ]
+        );
+        assert_eq!(recipients[1].reason.as_deref(), Some("Recipient opted out"));
+        assert_eq!(
+            campaign_repository.campaign.lock().expect("lock").status,
+            CampaignStatus::Completed
+        );
+
+        let messages = message_repository.created_messages.lock().expect("lock");
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].content, "Hello +14155550001");
+        assert_eq!(recipients[0].message_id, Some(messages[0].id));
+        assert_eq!(queue_repository.jobs.lock().expect("lock").len(), 2);
+        assert_eq!(
+            conversation_repository
+                .conversations
+                .lock()
+                .expect("lock")
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn pauses_the_campaign_when_the_hard_limit_is_reached() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        let campaign = Campaign::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(user_id)
+            .phone_number_id(phone_number_id)
+            .name("Spring sale".to_owned())
+            .body("Hello {{phone_number}}".to_owned())
+            .messages_per_second(2)
+            .status(CampaignStatus::Running)
+            .next_batch_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let campaign_repository = Arc::new(FakeCampaignRepository {
+            recipients: Mutex::new(vec![
+                build_recipient(&campaign, "+14155550001"),
+                build_recipient(&campaign, "+14155550002"),
+            ]),
+            campaign: Mutex::new(campaign),
+        });
+        let conversation_repository = Arc::new(FakeConversationRepository::default());
+        let message_repository = Arc::new(FakeMessageRepository::default());
+        let queue_repository = Arc::new(FakeOutboundMessageQueueRepository::default());
+        let opt_out_repository = Arc::new(FakeOptOutRepository {
+            opted_out: Vec::new(),
+        });
+        let create_message_usecase = CreateMessageUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(message_repository.clone())
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: PhoneNumber::builder()
+                    .id(phone_number_id)
+                    .organization_id(user_id)
+                    .user_id(user_id)
+                    .name("Primary".to_owned())
+                    .phone("+13125550100".to_owned())
+                    .created_at(now)
+                    .updated_at(now)
+                    .build(),
+            }))
+            .user_repository(Arc::new(FakeUserRepository))
+            .outbound_message_queue_repository(queue_repository.clone())
+            .opt_out_repository(opt_out_repository.clone())
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository))
+            .contact_repository(Arc::new(FakeContactRepository))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository {
+                limits: vec![
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Daily)
+                        .hard_limit_micros(0)
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                ],
+            }))
+            .media_storage(Arc::new(FakeMediaStorage))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+        let usecase = ProcessCampaignsUsecase::builder()
+            .campaign_repository(campaign_repository.clone())
+            .conversation_repository(conversation_repository)
+            .opt_out_repository(opt_out_repository)
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .create_message_usecase(create_message_usecase)
+            .build();
+
+        let result = usecase.execute(now).await.expect("batch should run");
+
+        assert_eq!((result.messaged, result.skipped), (0, 0));
+        assert!(matches!(
+            result.failures.as_slice(),
+            [(
+                _,
+                UsecaseError::UsageLimitReached {
+                    period: UsagePeriod::Daily
+                }
+            )]
+        ));
+        let campaign = campaign_repository.campaign.lock().expect("lock").clone();
+        assert_eq!(campaign.status, CampaignStatus::Paused);
+        assert!(
+            campaign
+                .pause_reason
+                .as_deref()
+                .is_some_and(|reason| reason.contains("daily spending limit"))
+        );
+        assert!(
+            campaign_repository
+                .recipients
+                .lock()
+                .expect("lock")
+                .iter()
+                .all(|recipient| recipient.status == CampaignRecipientStatus::Pending)
+        );
+        assert!(
+            message_repository
+                .created_messages
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert!(queue_repository.jobs.lock().expect("lock").is_empty());
+
+        // Nothing is sent until someone resumes the campaign.
+        let later = usecase
+            .execute(now + time::Duration::seconds(1))
+            .await
+            .expect("later run");
+        assert_eq!((later.messaged, later.failures.len()), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn pauses_the_campaign_when_its_creator_can_no_longer_send() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number_id = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        let campaign = Campaign::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(user_id)
+            .phone_number_id(phone_number_id)
+            .name("Spring sale".to_owned())
+            .body("Hello {{phone_number}}".to_owned())
+            .messages_per_second(2)
+            .status(CampaignStatus::Running)
+            .next_batch_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let campaign_repository = Arc::new(FakeCampaignRepository {
+            recipients: Mutex::new(vec![
+                build_recipient(&campaign, "+14155550001"),
+                build_recipient(&campaign, "+14155550002"),
+            ]),
+            campaign: Mutex::new(campaign),
+        });
+        let conversation_repository = Arc::new(FakeConversationRepository::default());
+        let message_repository = Arc::new(FakeMessageRepository::default());
+        let queue_repository = Arc::new(FakeOutboundMessageQueueRepository::default());
+        let opt_out_repository = Arc::new(FakeOptOutRepository {
+            opted_out: Vec::new(),
+        });
+        let create_message_usecase = CreateMessageUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(message_repository.clone())
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_number: PhoneNumber::builder()
+                    .id(phone_number_id)
+                    .organization_id(user_id)
+                    .user_id(user_id)
+                    .name("Primary".to_owned())
+                    .phone("+13125550100".to_owned())
+                    .created_at(now)
+                    .updated_at(now)
+                    .build(),
+            }))
+            .user_repository(Arc::new(FakeUserRepository))
+            .outbound_message_queue_repository(queue_repository.clone())
+            .opt_out_repository(opt_out_repository.clone())
+            .message_template_repository(Arc::new(FakeMessageTemplateRepository))
+            .contact_repository(Arc::new(FakeContactRepository))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::Agent),
+            }))
+            .build();
+        let usecase = ProcessCampaignsUsecase::builder()
+            .campaign_repository(campaign_repository.clone())
+            .conversation_repository(conversation_repository)
+            .opt_out_repository(opt_out_repository)
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                role: Some(OrganizationRole::ReadOnly),
+            }))
+            .create_message_usecase(create_message_usecase)
+            .build();
+
+        let result = usecase.execute(now).await.expect("batch should run");
+
+        assert_eq!((result.messaged, result.skipped), (0, 0));
+        assert!(matches!(
+            result.failures.as_slice(),
+            [(_, UsecaseError::Forbidden(Permission::SendMessages))]
+        ));
+        let campaign = campaign_repository.campaign.lock().expect("lock").clone();
+        assert_eq!(campaign.status, CampaignStatus::Paused);
+        assert!(
+            campaign
+                .pause_reason
+                .as_deref()
+                .is_some_and(|reason| reason.contains("can no longer send messages"))
+        );
+        assert!(
+            campaign_repository
+                .recipients
+                .lock()
+                .expect("lock")
+                .iter()
+                .all(|recipient| recipient.status == CampaignRecipientStatus::Pending)
+        );
+        assert!(
+            message_repository
+                .created_messages
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert!(queue_repository.jobs.lock().expect("lock").is_empty());
+
+        // Nothing is sent until someone resumes the campaign.
+        let later = usecase
+            .execute(now + time::Duration::seconds(1))
+            .await
+            .expect("later run");
+        assert_eq!((later.messaged, later.failures.len()), (0, 0));
+    }
+}
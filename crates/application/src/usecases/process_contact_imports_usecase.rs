@@ -0,0 +1,77 @@
+use std::{sync::Arc, time::Duration};
+
+use time::OffsetDateTime;
+
+use crate::usecases::{
+    UsecaseError,
+    import_contacts_usecase::{apply_entries, parse_entries},
+};
+use domain::{
+    models::contact_import::{ContactImport, ContactImportStatus},
+    repositories::{
+        contact_import_repository::ContactImportRepository, contact_repository::ContactRepository,
+    },
+};
+
+pub const MAX_CONTACT_IMPORT_ATTEMPTS: i32 = 3;
+const CONTACT_IMPORT_LEASE: Duration = Duration::from_secs(5 * 60);
+
+/// Applies the oldest pending contact import. An import that keeps failing is marked
+/// `Failed` after a few attempts instead of blocking the queue.
+#[derive(bon::Builder)]
+pub struct ProcessContactImportsUsecase {
+    contact_import_repository: Arc<dyn ContactImportRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    #[builder(default = MAX_CONTACT_IMPORT_ATTEMPTS)]
+    max_attempts: i32,
+}
+
+impl ProcessContactImportsUsecase {
+    /// Returns the import it worked on, or `None` when nothing was pending.
+    pub async fn execute(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Option<ContactImport>, UsecaseError> {
+        let Some(mut import) = self
+            .contact_import_repository
+            .claim_pending(now, now + CONTACT_IMPORT_LEASE)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if import.attempts > self.max_attempts {
+            self.fail(&mut import, "Gave up after repeated errors".to_owned())
+                .await?;
+            return Ok(Some(import));
+        }
+
+        let entries = match parse_entries(&import) {
+            Ok(entries) => entries,
+            Err(reason) => {
+                self.fail(&mut import, reason).await?;
+                return Ok(Some(import));
+            }
+        };
+
+        apply_entries(self.contact_repository.as_ref(), &mut import, entries).await?;
+        self.contact_import_repository
+            .update_import(&import)
+            .await?;
+
+        Ok(Some(import))
+    }
+
+    async fn fail(&self, import: &mut ContactImport, reason: String) -> Result<(), UsecaseError> {
+        let now = OffsetDateTime::now_utc();
+        import.status = ContactImportStatus::Failed;
+        import.content = String::new();
+        import.last_error = Some(reason);
+        import.completed_at = Some(now);
+        import.updated_at = now;
+
+        self.contact_import_repository.update_import(import).await?;
+
+        Ok(())
+    }
+}
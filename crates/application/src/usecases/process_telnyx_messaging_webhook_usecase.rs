@@ -4,23 +4,43 @@ use time::OffsetDateTime;
 
 use crate::{
     commands::{
-        ProcessTelnyxWebhookCommand, TelnyxWebhookMessageError, TelnyxWebhookMessageParticipant,
+        ProcessTelnyxWebhookCommand, TelnyxWebhookMessageError, TelnyxWebhookMessageMedia,
+        TelnyxWebhookMessageParticipant, TelnyxWebhookMessagePayload,
     },
-    usecases::UsecaseError,
+    usecases::{UsecaseError, create_message_usecase::ensure_within_hard_limits},
 };
 use domain::{
     models::{
-        conversation::Conversation,
+        contact::Contact,
+        conversation::{Conversation, ConversationRouting},
         message::{Message, MessageStatus, MessageType},
-        processed_webhook_event::ProcessedWebhookEvent,
+        message_attachment::MessageAttachment,
+        message_status_event::MessageStatusEvent,
+        message_template::{conversation_template_variables, render_template},
+        money::parse_micros,
+        opt_out::{ComplianceKeyword, OptOut},
+        organization::{OrganizationRole, Permission},
+        outbound_message_job::OutboundMessageJob,
+        phone_number::PhoneNumber,
+        sms_encoding::{SmsEncoding, count_segments},
     },
     repositories::{
-        RepositoryError, conversation_repository::ConversationRepository,
-        message_repository::MessageRepository, phone_number_repository::PhoneNumberRepository,
-        processed_webhook_event_repository::ProcessedWebhookEventRepository,
+        RepositoryError, auto_reply_rule_repository::AutoReplyRuleRepository,
+        business_hours_repository::BusinessHoursRepository, contact_repository::ContactRepository,
+        conversation_repository::ConversationRepository, message_repository::MessageRepository,
+        message_status_event_repository::MessageStatusEventRepository,
+        opt_out_repository::OptOutRepository, organization_repository::OrganizationRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+        phone_number_repository::PhoneNumberRepository,
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
     },
+    traits::{media_downloader::MediaDownloader, media_storage::MediaStorage},
 };
 
+/// Upper bound for a single inbound media file. Telnyx caps MMS payloads well below this.
+pub const MAX_INBOUND_MEDIA_BYTES: usize = 10 * 1024 * 1024;
+const DEFAULT_MEDIA_CONTENT_TYPE: &str = "application/octet-stream";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessagingWebhookNotificationKind {
     MessageCreated,
@@ -29,23 +49,43 @@ pub enum MessagingWebhookNotificationKind {
 
 #[derive(Debug, Clone)]
 pub struct MessagingWebhookNotification {
-    pub user_id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
     pub kind: MessagingWebhookNotificationKind,
     pub message: Message,
     pub conversation: Conversation,
+    /// The conversation was routed to a member while applying the event.
+    pub assigned: bool,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct ProcessTelnyxMessagingWebhookResult {
     pub notification: Option<MessagingWebhookNotification>,
+    /// A delivery status arrived for a provider message id we have not stored yet, usually
+    /// because the provider answered faster than the send was recorded. The caller should
+    /// keep the event and apply it again once the message exists.
+    pub orphaned: bool,
 }
 
+/// Applies a single Telnyx messaging event. Deduplication and retries are handled by the
+/// webhook inbox, see `ProcessWebhookInboxUsecase`.
 #[derive(bon::Builder)]
 pub struct ProcessTelnyxMessagingWebhookUsecase {
     conversation_repository: Arc<dyn ConversationRepository>,
     message_repository: Arc<dyn MessageRepository>,
+    message_status_event_repository: Arc<dyn MessageStatusEventRepository>,
     phone_number_repository: Arc<dyn PhoneNumberRepository>,
-    processed_webhook_event_repository: Arc<dyn ProcessedWebhookEventRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    business_hours_repository: Arc<dyn BusinessHoursRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    media_storage: Arc<dyn MediaStorage>,
+    media_downloader: Arc<dyn MediaDownloader>,
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    /// Sent back when a recipient texts HELP. No reply is sent when unset.
+    help_reply: Option<String>,
 }
 
 impl ProcessTelnyxMessagingWebhookUsecase {
@@ -53,67 +93,62 @@ impl ProcessTelnyxMessagingWebhookUsecase {
         &self,
         cmd: ProcessTelnyxWebhookCommand,
     ) -> Result<ProcessTelnyxMessagingWebhookResult, UsecaseError> {
-        match self
-            .processed_webhook_event_repository
-            .find_by_event_id(&cmd.event_id)
-            .await
-        {
-            Ok(_) => return Ok(ProcessTelnyxMessagingWebhookResult::default()),
-            Err(RepositoryError::NotFound) => {}
-            Err(err) => return Err(err.into()),
-        }
-
         let notification = match cmd.event_type.as_str() {
-            "message.sent" => self.handle_message_sent(&cmd).await?,
-            "message.finalized" => self.handle_message_finalized(&cmd).await?,
+            "message.sent" | "message.finalized" => {
+                let Some(message) = self
+                    .find_message_by_provider_id(&cmd.payload.provider_message_id)
+                    .await?
+                else {
+                    return Ok(ProcessTelnyxMessagingWebhookResult {
+                        notification: None,
+                        orphaned: true,
+                    });
+                };
+
+                if cmd.event_type == "message.sent" {
+                    self.handle_message_sent(&cmd, message).await?
+                } else {
+                    self.handle_message_finalized(&cmd, message).await?
+                }
+            }
             "message.received" => self.handle_message_received(&cmd).await?,
             _ => None,
         };
 
-        let processed_event = ProcessedWebhookEvent::builder()
-            .event_id(cmd.event_id)
-            .event_type(cmd.event_type)
-            .maybe_provider_message_id(Some(cmd.payload.provider_message_id))
-            .occurred_at(cmd.occurred_at)
-            .payload_json(cmd.raw_payload)
-            .created_at(OffsetDateTime::now_utc())
-            .build();
-
-        match self
-            .processed_webhook_event_repository
-            .create_processed_webhook_event(&processed_event)
-            .await
-        {
-            Ok(()) => {}
-            Err(RepositoryError::ConstraintViolation(_)) => {
-                return Ok(ProcessTelnyxMessagingWebhookResult::default());
-            }
-            Err(err) => return Err(err.into()),
-        }
-
-        Ok(ProcessTelnyxMessagingWebhookResult { notification })
+        Ok(ProcessTelnyxMessagingWebhookResult {
+            notification,
+            orphaned: false,
+        })
     }
 
     async fn handle_message_sent(
         &self,
         cmd: &ProcessTelnyxWebhookCommand,
+        mut message: Message,
     ) -> Result<Option<MessagingWebhookNotification>, UsecaseError> {
-        let Some(mut message) = self
-            .find_message_by_provider_id(&cmd.payload.provider_message_id)
-            .await?
-        else {
-            return Ok(None);
-        };
+        self.record_status_event(&message, cmd, MessageStatus::Sent, "sent", None, None)
+            .await?;
+        let details_changed = apply_provider_details(&mut message, &cmd.payload);
+        if details_changed {
+            self.record_reported_cost(&message).await?;
+        }
 
-        if is_stale_update(&message, cmd.occurred_at) {
+        // Late events that would move the message backwards, e.g. `sent` after
+        // `delivered` with a skewed timestamp, are left in the timeline only, but the
+        // cost and carrier details they carry are still kept.
+        if is_stale_update(&message, cmd.occurred_at)
+            || message.transition_to(MessageStatus::Sent).is_err()
+        {
+            if details_changed {
+                self.message_repository.update_message(&message).await?;
+            }
             return Ok(None);
         }
 
-        let Some(mut conversation) = self.find_conversation_for_message(&message).await? else {
+        let Some(conversation) = self.find_conversation_for_message(&message).await? else {
             return Ok(None);
         };
 
-        message.status = MessageStatus::Sent;
         message.provider_status = Some("sent".to_owned());
         message.provider_status_updated_at = Some(cmd.occurred_at);
         message.provider_error_code = None;
@@ -122,42 +157,59 @@ impl ProcessTelnyxMessagingWebhookUsecase {
 
         let message = self.message_repository.update_message(&message).await?;
 
-        conversation.updated_at = max(conversation.updated_at, message.updated_at);
-        self.conversation_repository
-            .update_conversation(&conversation)
+        let conversation = self
+            .conversation_repository
+            .record_activity(
+                &conversation,
+                conversation.last_message_at,
+                message.updated_at,
+            )
             .await?;
 
         Ok(Some(MessagingWebhookNotification {
-            user_id: message.user_id,
+            organization_id: message.organization_id,
             kind: MessagingWebhookNotificationKind::MessageUpdated,
             message,
             conversation,
+            assigned: false,
         }))
     }
 
     async fn handle_message_finalized(
         &self,
         cmd: &ProcessTelnyxWebhookCommand,
+        mut message: Message,
     ) -> Result<Option<MessagingWebhookNotification>, UsecaseError> {
-        let Some(mut message) = self
-            .find_message_by_provider_id(&cmd.payload.provider_message_id)
-            .await?
-        else {
-            return Ok(None);
-        };
+        let provider_status = first_status(&cmd.payload.to).unwrap_or("failed").to_owned();
+        let (provider_error_code, provider_error_detail) = first_error_details(&cmd.payload.errors);
+        let status = map_finalized_status(&provider_status);
+
+        // The timeline keeps late events too; only the message itself ignores them.
+        self.record_status_event(
+            &message,
+            cmd,
+            status,
+            &provider_status,
+            provider_error_code.clone(),
+            provider_error_detail.clone(),
+        )
+        .await?;
+        let details_changed = apply_provider_details(&mut message, &cmd.payload);
+        if details_changed {
+            self.record_reported_cost(&message).await?;
+        }
 
-        if is_stale_update(&message, cmd.occurred_at) {
+        if is_stale_update(&message, cmd.occurred_at) || message.transition_to(status).is_err() {
+            if details_changed {
+                self.message_repository.update_message(&message).await?;
+            }
             return Ok(None);
         }
 
-        let Some(mut conversation) = self.find_conversation_for_message(&message).await? else {
+        let Some(conversation) = self.find_conversation_for_message(&message).await? else {
             return Ok(None);
         };
 
-        let provider_status = first_status(&cmd.payload.to).unwrap_or("failed").to_owned();
-        let (provider_error_code, provider_error_detail) = first_error_details(&cmd.payload.errors);
-
-        message.status = map_finalized_status(&provider_status);
         message.provider_status = Some(provider_status);
         message.provider_status_updated_at = Some(cmd.occurred_at);
         message.provider_error_code = provider_error_code;
@@ -166,16 +218,21 @@ impl ProcessTelnyxMessagingWebhookUsecase {
 
         let message = self.message_repository.update_message(&message).await?;
 
-        conversation.updated_at = max(conversation.updated_at, message.updated_at);
-        self.conversation_repository
-            .update_conversation(&conversation)
+        let conversation = self
+            .conversation_repository
+            .record_activity(
+                &conversation,
+                conversation.last_message_at,
+                message.updated_at,
+            )
             .await?;
 
         Ok(Some(MessagingWebhookNotification {
-            user_id: message.user_id,
+            organization_id: message.organization_id,
             kind: MessagingWebhookNotificationKind::MessageUpdated,
             message,
             conversation,
+            assigned: false,
         }))
     }
 
@@ -183,15 +240,18 @@ impl ProcessTelnyxMessagingWebhookUsecase {
         &self,
         cmd: &ProcessTelnyxWebhookCommand,
     ) -> Result<Option<MessagingWebhookNotification>, UsecaseError> {
-        match self
+        // A retry after a failure further down finds the message stored already. Every
+        // step after the insert is safe to repeat, so they run again instead of being
+        // skipped.
+        let stored_message = match self
             .message_repository
             .find_by_provider_message_id(&cmd.payload.provider_message_id)
             .await
         {
-            Ok(_) => return Ok(None),
-            Err(RepositoryError::NotFound) => {}
+            Ok(message) => Some(message),
+            Err(RepositoryError::NotFound) => None,
             Err(err) => return Err(err.into()),
-        }
+        };
 
         let recipient_phone_number = first_phone_number(&cmd.payload.to).ok_or_else(|| {
             garde::Error::new("Webhook payload is missing the destination phone number")
@@ -215,11 +275,20 @@ impl ProcessTelnyxMessagingWebhookUsecase {
             Err(err) => return Err(err.into()),
         };
 
+        // Applied before the message is stored so a retry after a failure still records it.
+        let keyword = cmd
+            .payload
+            .text
+            .as_deref()
+            .and_then(ComplianceKeyword::detect);
+        self.apply_opt_out_keyword(keyword, &phone_number, &sender_phone_number, cmd)
+            .await?;
+
         let message_created_at = cmd.payload.received_at.unwrap_or(cmd.occurred_at);
-        let mut conversation = match self
+        let conversation = match self
             .conversation_repository
             .find_by_phone_number_and_recipient(
-                &phone_number.user_id,
+                &phone_number.organization_id,
                 &phone_number.id,
                 &sender_phone_number,
             )
@@ -230,6 +299,7 @@ impl ProcessTelnyxMessagingWebhookUsecase {
                 let conversation = Conversation::builder()
                     .id(uuid::Uuid::now_v7())
                     .phone_number_id(phone_number.id)
+                    .organization_id(phone_number.organization_id)
                     .user_id(phone_number.user_id)
                     .maybe_recipient_phone_number(Some(sender_phone_number.clone()))
                     .last_message_at(message_created_at)
@@ -245,13 +315,99 @@ impl ProcessTelnyxMessagingWebhookUsecase {
             Err(err) => return Err(err.into()),
         };
 
+        let message = match stored_message {
+            Some(message) => message,
+            None => match self
+                .store_inbound_message(
+                    cmd,
+                    &phone_number,
+                    &conversation,
+                    &sender_phone_number,
+                    message_created_at,
+                )
+                .await?
+            {
+                Some(message) => message,
+                None => return Ok(None),
+            },
+        };
+
+        self.conversation_repository.reopen(&conversation).await?;
+        let assigned = self.route_conversation(&conversation).await?;
+
+        // Compliance keywords are answered by the HELP reply alone, never by user rules. A
+        // matching rule answers instead of the away message.
+        let reply = match self
+            .find_queued_reply(&message, &sender_phone_number)
+            .await?
+        {
+            Some(reply) => Some(reply),
+            None => match keyword {
+                Some(ComplianceKeyword::Help) => {
+                    self.queue_help_reply(&phone_number, &conversation, &message)
+                        .await?
+                }
+                Some(_) => None,
+                None => match self
+                    .apply_auto_reply_rules(&phone_number, &conversation, &message)
+                    .await?
+                {
+                    Some(reply) => Some(reply),
+                    None => {
+                        self.queue_away_reply(&phone_number, &conversation, &message)
+                            .await?
+                    }
+                },
+            },
+        };
+        let (last_message_at, updated_at) = match &reply {
+            Some(reply) => (
+                max(message.created_at, reply.created_at),
+                max(message.updated_at, reply.updated_at),
+            ),
+            None => (message.created_at, message.updated_at),
+        };
+        let conversation = self
+            .conversation_repository
+            .record_activity(&conversation, last_message_at, updated_at)
+            .await?;
+
+        Ok(Some(MessagingWebhookNotification {
+            organization_id: message.organization_id,
+            kind: MessagingWebhookNotificationKind::MessageCreated,
+            message,
+            conversation,
+            assigned,
+        }))
+    }
+
+    /// Archives the media of an inbound message and stores it. Returns `None` when another
+    /// delivery of the same webhook stored it first.
+    async fn store_inbound_message(
+        &self,
+        cmd: &ProcessTelnyxWebhookCommand,
+        phone_number: &PhoneNumber,
+        conversation: &Conversation,
+        sender_phone_number: &str,
+        message_created_at: OffsetDateTime,
+    ) -> Result<Option<Message>, UsecaseError> {
         let provider_status = first_status(&cmd.payload.to)
             .unwrap_or("received")
             .to_owned();
         let (provider_error_code, provider_error_detail) = first_error_details(&cmd.payload.errors);
-        let message = Message::builder()
-            .id(uuid::Uuid::now_v7())
+        let message_id = uuid::Uuid::now_v7();
+        let attachments = self
+            .archive_inbound_media(
+                phone_number.user_id,
+                message_id,
+                message_created_at,
+                &cmd.payload.media,
+            )
+            .await?;
+        let mut message = Message::builder()
+            .id(message_id)
             .conversation_id(conversation.id)
+            .organization_id(conversation.organization_id)
             .user_id(phone_number.user_id)
             .message_type(MessageType::Inbound)
             .status(MessageStatus::Delivered)
@@ -260,142 +416,769 @@ impl ProcessTelnyxMessagingWebhookUsecase {
             .maybe_provider_status_updated_at(Some(cmd.occurred_at))
             .maybe_provider_error_code(provider_error_code)
             .maybe_provider_error_detail(provider_error_detail)
-            .from_number(sender_phone_number)
+            .from_number(sender_phone_number.to_owned())
             .content(cmd.payload.text.clone().unwrap_or_default())
             .created_at(message_created_at)
             .updated_at(max(message_created_at, cmd.occurred_at))
+            .attachments(attachments)
             .build();
+        apply_provider_details(&mut message, &cmd.payload);
+
+        match self.message_repository.create_message(&message).await {
+            Ok(message) => Ok(Some(message)),
+            Err(RepositoryError::ConstraintViolation(_)) => {
+                self.discard_attachments(&message.attachments).await;
+                Ok(None)
+            }
+            Err(err) => {
+                self.discard_attachments(&message.attachments).await;
+                Err(err.into())
+            }
+        }
+    }
 
-        let message = match self.message_repository.create_message(&message).await {
-            Ok(message) => message,
-            Err(RepositoryError::ConstraintViolation(_)) => return Ok(None),
+    /// The automated reply an earlier attempt queued for `message`. A reply whose job was
+    /// lost with that attempt is queued again.
+    async fn find_queued_reply(
+        &self,
+        message: &Message,
+        sender_phone_number: &str,
+    ) -> Result<Option<Message>, UsecaseError> {
+        let reply = match self.message_repository.find_reply_to(&message.id).await {
+            Ok(reply) => reply,
+            Err(RepositoryError::NotFound) => return Ok(None),
             Err(err) => return Err(err.into()),
         };
 
-        conversation.last_message_at = message.created_at;
-        conversation.updated_at = max(conversation.updated_at, message.updated_at);
-        self.conversation_repository
-            .update_conversation(&conversation)
+        if reply.status == MessageStatus::Pending {
+            let now = OffsetDateTime::now_utc();
+            let job = OutboundMessageJob::builder()
+                .message_id(reply.id)
+                .user_id(reply.user_id)
+                .to_number(sender_phone_number.to_owned())
+                .next_attempt_at(now)
+                .created_at(now)
+                .updated_at(now)
+                .build();
+            match self.outbound_message_queue_repository.enqueue(&job).await {
+                Ok(()) | Err(RepositoryError::ConstraintViolation(_)) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(Some(reply))
+    }
+
+    /// Hands an unassigned conversation to a member according to the organization's
+    /// routing. Returns whether it got an assignee; a conversation assigned meanwhile, by a
+    /// member or a concurrent inbound message, is left alone.
+    async fn route_conversation(&self, conversation: &Conversation) -> Result<bool, UsecaseError> {
+        if conversation.assigned_user_id.is_some() {
+            return Ok(false);
+        }
+
+        let organization = self
+            .organization_repository
+            .find_by_id(&conversation.organization_id)
             .await?;
+        if organization.conversation_routing == ConversationRouting::Manual {
+            return Ok(false);
+        }
 
-        Ok(Some(MessagingWebhookNotification {
-            user_id: message.user_id,
-            kind: MessagingWebhookNotificationKind::MessageCreated,
-            message,
+        let candidates = self
+            .organization_repository
+            .list_members(&conversation.organization_id)
+            .await?
+            .into_iter()
+            .filter(|member| member.role.can(Permission::SendMessages))
+            .map(|member| member.user_id)
+            .collect::<Vec<_>>();
+        let loads = self
+            .conversation_repository
+            .list_assignee_loads(&conversation.organization_id)
+            .await?;
+        let Some(assigned_user_id) = organization
+            .conversation_routing
+            .pick_assignee(&candidates, &loads)
+        else {
+            return Ok(false);
+        };
+
+        let assigned = self
+            .conversation_repository
+            .claim_assignment(conversation, &assigned_user_id, OffsetDateTime::now_utc())
+            .await?;
+
+        Ok(assigned)
+    }
+
+    async fn apply_opt_out_keyword(
+        &self,
+        keyword: Option<ComplianceKeyword>,
+        phone_number: &PhoneNumber,
+        sender_phone_number: &str,
+        cmd: &ProcessTelnyxWebhookCommand,
+    ) -> Result<(), UsecaseError> {
+        match keyword {
+            Some(ComplianceKeyword::OptOut) => {
+                let opt_out = OptOut::builder()
+                    .id(uuid::Uuid::now_v7())
+                    .user_id(phone_number.user_id)
+                    .phone_number_id(phone_number.id)
+                    .recipient_phone_number(sender_phone_number.to_owned())
+                    .keyword(
+                        cmd.payload
+                            .text
+                            .as_deref()
+                            .unwrap_or_default()
+                            .trim()
+                            .trim_end_matches(['.', '!', '?'])
+                            .to_ascii_uppercase(),
+                    )
+                    .created_at(cmd.occurred_at)
+                    .build();
+                self.opt_out_repository.opt_out(&opt_out).await?;
+            }
+            Some(ComplianceKeyword::OptIn) => {
+                self.opt_out_repository
+                    .opt_in(&phone_number.id, sender_phone_number)
+                    .await?;
+            }
+            Some(ComplianceKeyword::Help) | None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Queues the configured HELP reply, if there is one.
+    async fn queue_help_reply(
+        &self,
+        phone_number: &PhoneNumber,
+        conversation: &Conversation,
+        message: &Message,
+    ) -> Result<Option<Message>, UsecaseError> {
+        let Some(content) = self
+            .help_reply
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let Some(billed_user_id) = self.reply_billed_user_id(phone_number).await? else {
+            return Ok(None);
+        };
+
+        self.queue_automated_reply(
+            phone_number,
             conversation,
-        }))
+            message,
+            billed_user_id,
+            content.to_owned(),
+        )
+        .await
     }
 
-    async fn find_conversation_for_message(
+    /// Runs the first enabled rule of the number that matches the inbound text: tags the
+    /// sender's contact and queues the rendered reply, unless the sender opted out, the reply
+    /// has placeholders without a value, a hard spending limit was reached or the rule is
+    /// cooling down for this sender. The cooldown only starts with a queued reply.
+    async fn apply_auto_reply_rules(
         &self,
+        phone_number: &PhoneNumber,
+        conversation: &Conversation,
         message: &Message,
-    ) -> Result<Option<Conversation>, UsecaseError> {
-        match self
-            .conversation_repository
-            .find_by_id(&message.user_id, &message.conversation_id)
-            .await
+    ) -> Result<Option<Message>, UsecaseError> {
+        let sender_phone_number = message.from_number.as_str();
+        let rules = self
+            .auto_reply_rule_repository
+            .list_by_phone_number_id(&phone_number.organization_id, &phone_number.id)
+            .await?;
+        let Some(rule) = rules
+            .into_iter()
+            .find(|rule| rule.enabled && rule.matches(&message.content))
+        else {
+            return Ok(None);
+        };
+
+        let contact = self
+            .find_sender_contact(phone_number, sender_phone_number)
+            .await?;
+        let variables =
+            conversation_template_variables(contact.as_ref(), sender_phone_number, phone_number);
+        if let Some(tag) = &rule.tag {
+            self.tag_sender(phone_number, sender_phone_number, contact, tag)
+                .await?;
+        }
+
+        let Ok(content) = render_template(&rule.reply_template, &variables) else {
+            return Ok(None);
+        };
+        if self
+            .opt_out_repository
+            .is_opted_out(&phone_number.id, sender_phone_number)
+            .await?
         {
-            Ok(conversation) => Ok(Some(conversation)),
-            Err(RepositoryError::NotFound) => Ok(None),
-            Err(err) => Err(err.into()),
+            return Ok(None);
+        }
+        let Some(billed_user_id) = self.reply_billed_user_id(phone_number).await? else {
+            return Ok(None);
+        };
+        if !self
+            .auto_reply_rule_repository
+            .claim_reply(&rule, sender_phone_number, OffsetDateTime::now_utc())
+            .await?
+        {
+            return Ok(None);
         }
+
+        self.queue_automated_reply(phone_number, conversation, message, billed_user_id, content)
+            .await
     }
 
-    async fn find_message_by_provider_id(
+    /// Queues the number's away message when `received_at` falls outside its business hours,
+    /// at most once per conversation and closed period. A message held back by a hard
+    /// spending limit does not count as that one reply.
+    async fn queue_away_reply(
         &self,
-        provider_message_id: &str,
+        phone_number: &PhoneNumber,
+        conversation: &Conversation,
+        message: &Message,
     ) -> Result<Option<Message>, UsecaseError> {
-        match self
-            .message_repository
-            .find_by_provider_message_id(provider_message_id)
+        let sender_phone_number = message.from_number.as_str();
+        let business_hours = match self
+            .business_hours_repository
+            .find_by_phone_number_id(&phone_number.organization_id, &phone_number.id)
             .await
         {
-            Ok(message) => Ok(Some(message)),
-            Err(RepositoryError::NotFound) => Ok(None),
-            Err(err) => Err(err.into()),
+            Ok(business_hours) if business_hours.enabled => business_hours,
+            Ok(_) | Err(RepositoryError::NotFound) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let Some(closed_since) = business_hours.closed_since(message.created_at) else {
+            return Ok(None);
+        };
+        if conversation
+            .last_away_reply_at
+            .is_some_and(|replied_at| replied_at >= closed_since)
+        {
+            return Ok(None);
         }
-    }
-}
 
-fn first_phone_number(participants: &[TelnyxWebhookMessageParticipant]) -> Option<&str> {
-    participants
-        .iter()
-        .find_map(|participant| participant.phone_number.as_deref())
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-}
+        let contact = self
+            .find_sender_contact(phone_number, sender_phone_number)
+            .await?;
+        let variables =
+            conversation_template_variables(contact.as_ref(), sender_phone_number, phone_number);
+        let Ok(content) = render_template(&business_hours.away_message, &variables) else {
+            return Ok(None);
+        };
+        if self
+            .opt_out_repository
+            .is_opted_out(&phone_number.id, sender_phone_number)
+            .await?
+        {
+            return Ok(None);
+        }
+        let Some(billed_user_id) = self.reply_billed_user_id(phone_number).await? else {
+            return Ok(None);
+        };
+        let now = OffsetDateTime::now_utc();
+        if !self
+            .conversation_repository
+            .claim_away_reply(conversation, closed_since, now)
+            .await?
+        {
+            return Ok(None);
+        }
 
-fn first_status(participants: &[TelnyxWebhookMessageParticipant]) -> Option<&str> {
-    participants
-        .iter()
-        .find_map(|participant| participant.status.as_deref())
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-}
+        self.queue_automated_reply(phone_number, conversation, message, billed_user_id, content)
+            .await
+    }
 
-fn first_error_details(errors: &[TelnyxWebhookMessageError]) -> (Option<String>, Option<String>) {
-    let Some(error) = errors.first() else {
-        return (None, None);
-    };
+    async fn find_sender_contact(
+        &self,
+        phone_number: &PhoneNumber,
+        sender_phone_number: &str,
+    ) -> Result<Option<Contact>, UsecaseError> {
+        let contacts = self
+            .contact_repository
+            .find_by_phone_numbers(
+                &phone_number.organization_id,
+                &[sender_phone_number.to_owned()],
+            )
+            .await?;
 
-    (
-        error.code.clone(),
-        error.detail.clone().or_else(|| error.title.clone()),
-    )
-}
+        Ok(contacts.into_iter().next())
+    }
 
-fn is_stale_update(message: &Message, occurred_at: OffsetDateTime) -> bool {
-    message
-        .provider_status_updated_at
-        .map(|updated_at| occurred_at < updated_at)
-        .unwrap_or(false)
-}
+    /// Adds `tag` to the sender's contact. Senders missing from the address book get a
+    /// contact named after their number, so the tag can address them later, e.g. in a
+    /// campaign.
+    async fn tag_sender(
+        &self,
+        phone_number: &PhoneNumber,
+        sender_phone_number: &str,
+        contact: Option<Contact>,
+        tag: &str,
+    ) -> Result<(), UsecaseError> {
+        let now = OffsetDateTime::now_utc();
 
-fn map_finalized_status(status: &str) -> MessageStatus {
-    match status {
-        "delivered" => MessageStatus::Delivered,
-        "delivery_failed" | "sending_failed" | "failed" | "gw_timeout" | "dlr_timeout" => {
-            MessageStatus::Failed
+        match contact {
+            Some(contact) if contact.tags.iter().any(|existing| existing == tag) => {}
+            Some(mut contact) => {
+                contact.tags.push(tag.to_owned());
+                contact.updated_at = now;
+                self.contact_repository.update_contact(&contact).await?;
+            }
+            None => {
+                let contact = Contact::builder()
+                    .id(uuid::Uuid::now_v7())
+                    .user_id(phone_number.user_id)
+                    .organization_id(phone_number.organization_id)
+                    .name(sender_phone_number.to_owned())
+                    .phone_numbers(vec![sender_phone_number.to_owned()])
+                    .tags(vec![tag.to_owned()])
+                    .created_at(now)
+                    .updated_at(now)
+                    .build();
+                match self.contact_repository.create_contact(&contact).await {
+                    Ok(()) | Err(RepositoryError::ConstraintViolation(_)) => {}
+                    Err(err) => return Err(err.into()),
+                }
+            }
         }
-        "queued" => MessageStatus::Queued,
-        "sending" | "sent" | "delivery_unconfirmed" => MessageStatus::Sent,
-        _ => MessageStatus::Sent,
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::{
-        collections::HashMap,
-        sync::{Arc, Mutex},
+    /// Queues a reply sent by the app itself like any other outbound message, billed to
+    /// `billed_user_id`, see [`Self::reply_billed_user_id`].
+    async fn queue_automated_reply(
+        &self,
+        phone_number: &PhoneNumber,
+        conversation: &Conversation,
+        message: &Message,
+        billed_user_id: uuid::Uuid,
+        content: String,
+    ) -> Result<Option<Message>, UsecaseError> {
+        let now = OffsetDateTime::now_utc();
+        let segment_count = count_segments(&content);
+        let reply = Message::builder()
+            .id(uuid::Uuid::now_v7())
+            .conversation_id(conversation.id)
+            .organization_id(conversation.organization_id)
+            .user_id(billed_user_id)
+            .message_type(MessageType::Outbound)
+            .status(MessageStatus::Pending)
+            .from_number(phone_number.phone.clone())
+            .content(content)
+            .automated(true)
+            .reply_to_message_id(message.id)
+            .segments(segment_count.segments as i32)
+            .encoding(segment_count.encoding)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let reply = self.message_repository.create_message(&reply).await?;
+
+        let job = OutboundMessageJob::builder()
+            .message_id(reply.id)
+            .user_id(reply.user_id)
+            .to_number(message.from_number.clone())
+            .next_attempt_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        self.outbound_message_queue_repository.enqueue(&job).await?;
+
+        Ok(Some(reply))
+    }
+
+    /// The member automated replies in the organization of `phone_number` are billed to: its
+    /// oldest owner, or whoever added the number if it has none. `None` once they have
+    /// reached a hard spending limit, in which case nothing is sent.
+    async fn reply_billed_user_id(
+        &self,
+        phone_number: &PhoneNumber,
+    ) -> Result<Option<uuid::Uuid>, UsecaseError> {
+        let members = self
+            .organization_repository
+            .list_members(&phone_number.organization_id)
+            .await?;
+        let billed_user_id = members
+            .into_iter()
+            .find(|member| member.role == OrganizationRole::Owner)
+            .map_or(phone_number.user_id, |member| member.user_id);
+
+        match ensure_within_hard_limits(
+            &self.usage_limit_repository,
+            &self.usage_repository,
+            &billed_user_id,
+            OffsetDateTime::now_utc(),
+        )
+        .await
+        {
+            Ok(()) => Ok(Some(billed_user_id)),
+            Err(UsecaseError::UsageLimitReached { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Downloads every media item of an inbound MMS into local storage so previews keep
+    /// working after the provider URLs expire. Any failure aborts the whole message so the
+    /// webhook is retried instead of persisting a partial set of attachments.
+    async fn archive_inbound_media(
+        &self,
+        user_id: uuid::Uuid,
+        message_id: uuid::Uuid,
+        now: OffsetDateTime,
+        media: &[TelnyxWebhookMessageMedia],
+    ) -> Result<Vec<MessageAttachment>, UsecaseError> {
+        let mut attachments = Vec::with_capacity(media.len());
+
+        for (index, item) in media.iter().enumerate() {
+            let downloaded = match self
+                .media_downloader
+                .download(&item.url, MAX_INBOUND_MEDIA_BYTES)
+                .await
+            {
+                Ok(downloaded) => downloaded,
+                Err(err) => {
+                    self.discard_attachments(&attachments).await;
+                    return Err(err.into());
+                }
+            };
+
+            let attachment_id = uuid::Uuid::now_v7();
+            let storage_key = format!("{}/{}/{}", user_id, message_id, attachment_id);
+            let stored = match self
+                .media_storage
+                .put(&storage_key, &downloaded.bytes)
+                .await
+            {
+                Ok(stored) => stored,
+                Err(err) => {
+                    self.discard_attachments(&attachments).await;
+                    return Err(err.into());
+                }
+            };
+
+            let content_type = non_empty(item.content_type.as_deref())
+                .or_else(|| non_empty(downloaded.content_type.as_deref()))
+                .unwrap_or(DEFAULT_MEDIA_CONTENT_TYPE)
+                .to_owned();
+            let attachment = MessageAttachment::builder()
+                .id(attachment_id)
+                .message_id(message_id)
+                .user_id(user_id)
+                .file_name(media_file_name(&item.url, index))
+                .content_type(content_type)
+                .size_bytes(stored.size_bytes)
+                .storage_key(storage_key)
+                .checksum_sha256(stored.checksum_sha256)
+                .created_at(now)
+                .build();
+            attachments.push(attachment);
+
+            if let Some(expected) = non_empty(item.sha256.as_deref())
+                && attachments
+                    .last()
+                    .and_then(|attachment| attachment.checksum_sha256.as_deref())
+                    .is_some_and(|actual| !actual.eq_ignore_ascii_case(expected))
+            {
+                self.discard_attachments(&attachments).await;
+                return Err(UsecaseError::ExternalService(format!(
+                    "Checksum mismatch for inbound media {}",
+                    item.url
+                )));
+            }
+        }
+
+        Ok(attachments)
+    }
+
+    async fn discard_attachments(&self, attachments: &[MessageAttachment]) {
+        for attachment in attachments {
+            let _ = self.media_storage.delete(&attachment.storage_key).await;
+        }
+    }
+
+    async fn find_conversation_for_message(
+        &self,
+        message: &Message,
+    ) -> Result<Option<Conversation>, UsecaseError> {
+        match self
+            .conversation_repository
+            .find_by_id(&message.organization_id, &message.conversation_id)
+            .await
+        {
+            Ok(conversation) => Ok(Some(conversation)),
+            Err(RepositoryError::NotFound) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn record_status_event(
+        &self,
+        message: &Message,
+        cmd: &ProcessTelnyxWebhookCommand,
+        status: MessageStatus,
+        provider_status: &str,
+        error_code: Option<String>,
+        error_detail: Option<String>,
+    ) -> Result<(), UsecaseError> {
+        let event = MessageStatusEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .message_id(message.id)
+            .user_id(message.user_id)
+            .status(status)
+            .provider_status(provider_status.to_owned())
+            .maybe_carrier(first_carrier(&cmd.payload.to).map(ToOwned::to_owned))
+            .maybe_error_code(error_code)
+            .maybe_error_detail(error_detail)
+            .occurred_at(cmd.occurred_at)
+            .created_at(OffsetDateTime::now_utc())
+            .build();
+
+        self.message_status_event_repository.record(&event).await?;
+
+        Ok(())
+    }
+
+    /// Replaces the estimated cost in the usage ledger once Telnyx reports the actual one.
+    async fn record_reported_cost(&self, message: &Message) -> Result<(), UsecaseError> {
+        if message.message_type != MessageType::Outbound {
+            return Ok(());
+        }
+        if let Some(cost_micros) = message.cost_micros {
+            self.usage_repository
+                .record_cost(&message.id, cost_micros)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_message_by_provider_id(
+        &self,
+        provider_message_id: &str,
+    ) -> Result<Option<Message>, UsecaseError> {
+        match self
+            .message_repository
+            .find_by_provider_message_id(provider_message_id)
+            .await
+        {
+            Ok(message) => Ok(Some(message)),
+            Err(RepositoryError::NotFound) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn media_file_name(url: &str, index: usize) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let path = path.split_once("://").map_or(path, |(_, rest)| rest);
+    path.split_once('/')
+        .and_then(|(_, path)| path.rsplit('/').next())
+        .filter(|segment| !segment.is_empty() && segment.len() <= 255)
+        .map(str::to_owned)
+        .unwrap_or_else(|| format!("attachment-{}", index + 1))
+}
+
+fn first_phone_number(participants: &[TelnyxWebhookMessageParticipant]) -> Option<&str> {
+    participants
+        .iter()
+        .find_map(|participant| participant.phone_number.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn first_status(participants: &[TelnyxWebhookMessageParticipant]) -> Option<&str> {
+    participants
+        .iter()
+        .find_map(|participant| participant.status.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn first_carrier(participants: &[TelnyxWebhookMessageParticipant]) -> Option<&str> {
+    participants
+        .iter()
+        .find_map(|participant| participant.carrier.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn first_line_type(participants: &[TelnyxWebhookMessageParticipant]) -> Option<&str> {
+    participants
+        .iter()
+        .find_map(|participant| participant.line_type.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Copies the parts, cost and carrier details Telnyx reports onto the message. Details an
+/// event leaves out keep their stored value. Returns whether anything changed.
+fn apply_provider_details(message: &mut Message, payload: &TelnyxWebhookMessagePayload) -> bool {
+    let (carrier, line_type) = match message.message_type {
+        MessageType::Outbound => (first_carrier(&payload.to), first_line_type(&payload.to)),
+        MessageType::Inbound => (
+            non_empty(payload.from_carrier.as_deref()),
+            non_empty(payload.from_line_type.as_deref()),
+        ),
+    };
+    let cost = payload.cost.as_ref().and_then(|cost| {
+        let amount = parse_micros(cost.amount.as_deref()?)?;
+        Some((amount, non_empty(cost.currency.as_deref())))
+    });
+
+    let mut changed = false;
+    changed |= replace_if_reported(
+        &mut message.segments,
+        payload.parts.filter(|parts| *parts > 0),
+    );
+    changed |= replace_if_reported(
+        &mut message.encoding,
+        payload.encoding.as_deref().and_then(provider_encoding),
+    );
+    if let Some((amount, currency)) = cost {
+        changed |= replace_if_reported(&mut message.cost_micros, Some(amount));
+        changed |= replace_if_reported(
+            &mut message.cost_currency,
+            currency.map(str::to_ascii_uppercase),
+        );
+    }
+    changed |= replace_if_reported(&mut message.carrier, carrier.map(ToOwned::to_owned));
+    changed |= replace_if_reported(&mut message.line_type, line_type.map(ToOwned::to_owned));
+    changed |= replace_if_reported(
+        &mut message.messaging_profile_id,
+        non_empty(payload.messaging_profile_id.as_deref()).map(ToOwned::to_owned),
+    );
+
+    changed
+}
+
+fn replace_if_reported<T: PartialEq>(field: &mut Option<T>, reported: Option<T>) -> bool {
+    match reported {
+        Some(value) if field.as_ref() != Some(&value) => {
+            *field = Some(value);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Maps Telnyx encoding names such as `GSM-7` and `UCS-2`.
+fn provider_encoding(value: &str) -> Option<SmsEncoding> {
+    match value
+        .trim()
+        .to_ascii_lowercase()
+        .replace(['-', '_'], "")
+        .as_str()
+    {
+        "gsm7" => Some(SmsEncoding::Gsm7),
+        "ucs2" => Some(SmsEncoding::Ucs2),
+        _ => None,
+    }
+}
+
+fn first_error_details(errors: &[TelnyxWebhookMessageError]) -> (Option<String>, Option<String>) {
+    let Some(error) = errors.first() else {
+        return (None, None);
+    };
+
+    (
+        error.code.clone(),
+        error.detail.clone().or_else(|| error.title.clone()),
+    )
+}
+
+fn is_stale_update(message: &Message, occurred_at: OffsetDateTime) -> bool {
+    message
+        .provider_status_updated_at
+        .map(|updated_at| occurred_at < updated_at)
+        .unwrap_or(false)
+}
+
+fn map_finalized_status(status: &str) -> MessageStatus {
+    match status {
+        "delivered" => MessageStatus::Delivered,
+        "delivery_failed" | "sending_failed" | "failed" | "gw_timeout" | "dlr_timeout" => {
+            MessageStatus::Failed
+        }
+        "queued" => MessageStatus::Queued,
+        "sending" | "sent" | "delivery_unconfirmed" => MessageStatus::Sent,
+        _ => MessageStatus::Sent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
     };
 
     use async_trait::async_trait;
     use domain::{
         models::{
-            conversation::Conversation,
+            auto_reply_rule::{AutoReplyMatchType, AutoReplyRule},
+            business_hours::BusinessHours,
+            contact::Contact,
+            conversation::{
+                AssigneeLoad, Conversation, ConversationFilter, ConversationRouting,
+                ConversationStateChange, ConversationView, UnreadCount,
+            },
             message::{Message, MessageStatus, MessageType},
+            message_attachment::MessageAttachment,
+            message_status_event::MessageStatusEvent,
+            opt_out::OptOut,
+            organization::{Organization, OrganizationMember, OrganizationRole},
+            outbound_message_job::OutboundMessageJob,
             phone_number::PhoneNumber,
-            processed_webhook_event::ProcessedWebhookEvent,
+            sms_encoding::SmsEncoding,
+            usage::{UsageLimit, UsagePeriod, UsageRecord, UsageRollup, UsageTotals},
         },
         repositories::{
-            RepositoryError, conversation_repository::ConversationRepository,
+            RepositoryError,
+            auto_reply_rule_repository::AutoReplyRuleRepository,
+            business_hours_repository::BusinessHoursRepository,
+            contact_repository::ContactRepository,
+            conversation_repository::ConversationRepository,
             message_repository::{MessagePage, MessageRepository},
+            message_status_event_repository::MessageStatusEventRepository,
+            opt_out_repository::OptOutRepository,
+            organization_repository::OrganizationRepository,
+            outbound_message_queue_repository::OutboundMessageQueueRepository,
             phone_number_repository::PhoneNumberRepository,
-            processed_webhook_event_repository::ProcessedWebhookEventRepository,
+            usage_limit_repository::UsageLimitRepository,
+            usage_repository::UsageRepository,
+        },
+        traits::{
+            media_downloader::{DownloadedMedia, MediaDownloadError, MediaDownloader},
+            media_storage::{MediaStorage, MediaStorageError, StoredMedia},
         },
     };
-    use serde_json::json;
     use time::OffsetDateTime;
 
-    use crate::commands::{
-        ProcessTelnyxWebhookCommand, TelnyxWebhookMessageError, TelnyxWebhookMessageParticipant,
-        TelnyxWebhookMessagePayload,
+    use crate::{
+        commands::{
+            ProcessTelnyxWebhookCommand, TelnyxWebhookMessageCost, TelnyxWebhookMessageError,
+            TelnyxWebhookMessageMedia, TelnyxWebhookMessageParticipant,
+            TelnyxWebhookMessagePayload,
+        },
+        usecases::UsecaseError,
     };
 
     use super::{
         MessagingWebhookNotificationKind, ProcessTelnyxMessagingWebhookUsecase,
-        map_finalized_status,
+        map_finalized_status, media_file_name,
     };
 
     struct FakeConversationRepository {
@@ -415,17 +1198,6 @@ mod tests {
             Ok(())
         }
 
-        async fn update_conversation(
-            &self,
-            conversation: &Conversation,
-        ) -> Result<(), RepositoryError> {
-            self.conversations
-                .lock()
-                .expect("lock")
-                .insert(conversation.id, conversation.clone());
-            Ok(())
-        }
-
         async fn find_by_id(
             &self,
             _user_id: &uuid::Uuid,
@@ -458,20 +1230,156 @@ mod tests {
                 .ok_or(RepositoryError::NotFound)
         }
 
-        async fn list_by_user_id(
+        async fn list_by_organization_id(
+            &self,
+            organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(self
+                .conversations
+                .lock()
+                .expect("lock")
+                .values()
+                .filter(|conversation| conversation.organization_id == *organization_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_filtered(
             &self,
+            organization_id: &uuid::Uuid,
             user_id: &uuid::Uuid,
+            view: ConversationView,
+            filter: ConversationFilter,
+            now: OffsetDateTime,
         ) -> Result<Vec<Conversation>, RepositoryError> {
             Ok(self
                 .conversations
                 .lock()
                 .expect("lock")
                 .values()
-                .filter(|conversation| conversation.user_id == *user_id)
+                .filter(|conversation| conversation.organization_id == *organization_id)
+                .filter(|conversation| view.includes(conversation, *user_id))
+                .filter(|conversation| filter.includes(conversation, now))
                 .cloned()
                 .collect())
         }
 
+        async fn change_state(
+            &self,
+            conversation: &Conversation,
+            change: ConversationStateChange,
+            now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            let mut conversations = self.conversations.lock().expect("lock");
+            let stored = conversations
+                .get_mut(&conversation.id)
+                .ok_or(RepositoryError::NotFound)?;
+            stored.apply(change, now);
+            Ok(stored.clone())
+        }
+
+        async fn record_activity(
+            &self,
+            conversation: &Conversation,
+            last_message_at: OffsetDateTime,
+            updated_at: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            let mut conversations = self.conversations.lock().expect("lock");
+            let stored = conversations
+                .get_mut(&conversation.id)
+                .ok_or(RepositoryError::NotFound)?;
+            stored.last_message_at = stored.last_message_at.max(last_message_at);
+            stored.updated_at = stored.updated_at.max(updated_at);
+            Ok(stored.clone())
+        }
+
+        async fn reopen(&self, conversation: &Conversation) -> Result<(), RepositoryError> {
+            if let Some(stored) = self
+                .conversations
+                .lock()
+                .expect("lock")
+                .get_mut(&conversation.id)
+            {
+                stored.reopen();
+            }
+            Ok(())
+        }
+
+        async fn assign(
+            &self,
+            conversation: &Conversation,
+            assigned_user_id: Option<uuid::Uuid>,
+            now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            let mut conversations = self.conversations.lock().expect("lock");
+            let stored = conversations
+                .get_mut(&conversation.id)
+                .ok_or(RepositoryError::NotFound)?;
+            stored.assign(assigned_user_id, now);
+            Ok(stored.clone())
+        }
+
+        async fn claim_assignment(
+            &self,
+            conversation: &Conversation,
+            assigned_user_id: &uuid::Uuid,
+            now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            let mut conversations = self.conversations.lock().expect("lock");
+            let stored = conversations
+                .get_mut(&conversation.id)
+                .ok_or(RepositoryError::NotFound)?;
+            if stored.assigned_user_id.is_some() {
+                return Ok(false);
+            }
+            stored.assign(Some(*assigned_user_id), now);
+            Ok(true)
+        }
+
+        async fn list_assignee_loads(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+            let mut loads = HashMap::<uuid::Uuid, AssigneeLoad>::new();
+            for conversation in self.conversations.lock().expect("lock").values() {
+                let Some(user_id) = conversation.assigned_user_id else {
+                    continue;
+                };
+                let load = loads.entry(user_id).or_insert_with(|| {
+                    AssigneeLoad::builder()
+                        .user_id(user_id)
+                        .assigned_count(0)
+                        .build()
+                });
+                load.assigned_count += 1;
+                load.last_assigned_at = load.last_assigned_at.max(conversation.assigned_at);
+            }
+            Ok(loads.into_values().collect())
+        }
+
+        async fn mark_read(
+            &self,
+            _conversation: &Conversation,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_unread_counts(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UnreadCount>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn wake_snoozed(
+            &self,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
         async fn delete_conversation(
             &self,
             _user_id: &uuid::Uuid,
@@ -480,6 +1388,27 @@ mod tests {
             self.conversations.lock().expect("lock").remove(id);
             Ok(())
         }
+
+        async fn claim_away_reply(
+            &self,
+            conversation: &Conversation,
+            closed_since: OffsetDateTime,
+            now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            let mut conversations = self.conversations.lock().expect("lock");
+            let stored = conversations
+                .get_mut(&conversation.id)
+                .ok_or(RepositoryError::NotFound)?;
+            if stored
+                .last_away_reply_at
+                .is_some_and(|replied_at| replied_at >= closed_since)
+            {
+                return Ok(false);
+            }
+
+            stored.last_away_reply_at = Some(now);
+            Ok(true)
+        }
     }
 
     struct FakeMessageRepository {
@@ -490,27 +1419,39 @@ mod tests {
     impl MessageRepository for FakeMessageRepository {
         async fn create_message(&self, message: &Message) -> Result<Message, RepositoryError> {
             let mut messages = self.messages.lock().expect("lock");
-            if let Some(provider_message_id) = message.provider_message_id.as_deref() {
-                if messages.values().any(|existing| {
+            if let Some(provider_message_id) = message.provider_message_id.as_deref()
+                && messages.values().any(|existing| {
                     existing.provider_message_id.as_deref() == Some(provider_message_id)
-                }) {
-                    return Err(RepositoryError::ConstraintViolation(
-                        "duplicate provider_message_id".to_owned(),
-                    ));
-                }
+                })
+            {
+                return Err(RepositoryError::ConstraintViolation(
+                    "duplicate provider_message_id".to_owned(),
+                ));
             }
 
             messages.insert(message.id, message.clone());
             Ok(message.clone())
         }
 
-        async fn count_by_user_id(&self, user_id: &uuid::Uuid) -> Result<u64, RepositoryError> {
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+            self.messages
+                .lock()
+                .expect("lock")
+                .get(id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn count_by_organization_id(
+            &self,
+            organization_id: &uuid::Uuid,
+        ) -> Result<u64, RepositoryError> {
             Ok(self
                 .messages
                 .lock()
                 .expect("lock")
                 .values()
-                .filter(|message| message.user_id == *user_id)
+                .filter(|message| message.organization_id == *organization_id)
                 .count() as u64)
         }
 
@@ -527,10 +1468,20 @@ mod tests {
                 .ok_or(RepositoryError::NotFound)
         }
 
-        async fn list_by_conversation_id(
-            &self,
-            _user_id: &uuid::Uuid,
-            conversation_id: &uuid::Uuid,
+        async fn find_reply_to(&self, message_id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+            self.messages
+                .lock()
+                .expect("lock")
+                .values()
+                .find(|message| message.reply_to_message_id == Some(*message_id))
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn list_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            conversation_id: &uuid::Uuid,
         ) -> Result<Vec<Message>, RepositoryError> {
             Ok(self
                 .messages
@@ -572,7 +1523,11 @@ mod tests {
             } else {
                 0
             };
-            let page = messages.into_iter().skip(start).take(limit + 1).collect::<Vec<_>>();
+            let page = messages
+                .into_iter()
+                .skip(start)
+                .take(limit + 1)
+                .collect::<Vec<_>>();
             let has_more = page.len() > limit;
             let page = if has_more {
                 page.into_iter().take(limit).collect::<Vec<_>>()
@@ -604,6 +1559,47 @@ mod tests {
                 .insert(message.id, message.clone());
             Ok(message.clone())
         }
+
+        async fn list_scheduled_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+            _scheduled_at: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn delete_scheduled_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn release_scheduled_message(
+            &self,
+            _id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn find_attachment_by_id(
+            &self,
+            _id: &uuid::Uuid,
+        ) -> Result<MessageAttachment, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
     }
 
     struct FakePhoneNumberRepository {
@@ -646,16 +1642,16 @@ mod tests {
                 .ok_or(RepositoryError::NotFound)
         }
 
-        async fn list_by_user_id(
+        async fn list_by_organization_id(
             &self,
-            user_id: &uuid::Uuid,
+            organization_id: &uuid::Uuid,
         ) -> Result<Vec<PhoneNumber>, RepositoryError> {
             Ok(self
                 .phone_numbers
                 .lock()
                 .expect("lock")
                 .values()
-                .filter(|phone_number| phone_number.user_id == *user_id)
+                .filter(|phone_number| phone_number.organization_id == *organization_id)
                 .cloned()
                 .collect())
         }
@@ -670,38 +1666,387 @@ mod tests {
         }
     }
 
-    struct FakeProcessedWebhookEventRepository {
-        events: Mutex<HashMap<String, ProcessedWebhookEvent>>,
+    #[derive(Default)]
+    struct FakeMessageStatusEventRepository {
+        events: Mutex<Vec<MessageStatusEvent>>,
+    }
+
+    #[async_trait]
+    impl MessageStatusEventRepository for FakeMessageStatusEventRepository {
+        async fn record(&self, event: &MessageStatusEvent) -> Result<(), RepositoryError> {
+            self.events.lock().expect("lock").push(event.clone());
+            Ok(())
+        }
+
+        async fn list_by_message_ids(
+            &self,
+            _organization_id: &uuid::Uuid,
+            message_ids: &[uuid::Uuid],
+        ) -> Result<Vec<MessageStatusEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|event| message_ids.contains(&event.message_id))
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeOptOutRepository {
+        opted_out: Mutex<Vec<(uuid::Uuid, String)>>,
     }
 
     #[async_trait]
-    impl ProcessedWebhookEventRepository for FakeProcessedWebhookEventRepository {
-        async fn create_processed_webhook_event(
+    impl OptOutRepository for FakeOptOutRepository {
+        async fn opt_out(&self, opt_out: &OptOut) -> Result<(), RepositoryError> {
+            self.opted_out.lock().expect("lock").push((
+                opt_out.phone_number_id,
+                opt_out.recipient_phone_number.to_owned(),
+            ));
+            Ok(())
+        }
+
+        async fn opt_in(
             &self,
-            event: &ProcessedWebhookEvent,
+            phone_number_id: &uuid::Uuid,
+            recipient_phone_number: &str,
         ) -> Result<(), RepositoryError> {
-            let mut events = self.events.lock().expect("lock");
-            if events.contains_key(&event.event_id) {
+            self.opted_out
+                .lock()
+                .expect("lock")
+                .retain(|(id, recipient)| {
+                    id != phone_number_id || recipient != recipient_phone_number
+                });
+            Ok(())
+        }
+
+        async fn is_opted_out(
+            &self,
+            phone_number_id: &uuid::Uuid,
+            recipient_phone_number: &str,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self
+                .opted_out
+                .lock()
+                .expect("lock")
+                .iter()
+                .any(|(id, recipient)| {
+                    id == phone_number_id && recipient == recipient_phone_number
+                }))
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeOutboundMessageQueueRepository {
+        jobs: Mutex<Vec<OutboundMessageJob>>,
+    }
+
+    #[async_trait]
+    impl OutboundMessageQueueRepository for FakeOutboundMessageQueueRepository {
+        async fn enqueue(&self, job: &OutboundMessageJob) -> Result<(), RepositoryError> {
+            let mut jobs = self.jobs.lock().expect("lock");
+            if jobs
+                .iter()
+                .any(|existing| existing.message_id == job.message_id)
+            {
                 return Err(RepositoryError::ConstraintViolation(
-                    "duplicate webhook event".to_owned(),
+                    "duplicate message_id".to_owned(),
                 ));
             }
 
-            events.insert(event.event_id.clone(), event.clone());
+            jobs.push(job.clone());
+            Ok(())
+        }
+
+        async fn claim_due(
+            &self,
+            _now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            _limit: usize,
+        ) -> Result<Vec<OutboundMessageJob>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+            _last_error: &str,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn postpone(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn complete(&self, _message_id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeMediaStorage {
+        objects: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl MediaStorage for FakeMediaStorage {
+        async fn put(&self, key: &str, bytes: &[u8]) -> Result<StoredMedia, MediaStorageError> {
+            self.objects
+                .lock()
+                .expect("lock")
+                .insert(key.to_owned(), bytes.to_vec());
+            Ok(StoredMedia {
+                size_bytes: bytes.len() as i64,
+                checksum_sha256: format!("sha256-{}", bytes.len()),
+            })
+        }
+
+        async fn get(&self, key: &str) -> Result<Vec<u8>, MediaStorageError> {
+            self.objects
+                .lock()
+                .expect("lock")
+                .get(key)
+                .cloned()
+                .ok_or(MediaStorageError::NotFound)
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), MediaStorageError> {
+            self.objects.lock().expect("lock").remove(key);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeMediaDownloader {
+        files: HashMap<String, DownloadedMedia>,
+    }
+
+    #[async_trait]
+    impl MediaDownloader for FakeMediaDownloader {
+        async fn download(
+            &self,
+            url: &str,
+            _max_bytes: usize,
+        ) -> Result<DownloadedMedia, MediaDownloadError> {
+            self.files
+                .get(url)
+                .cloned()
+                .ok_or_else(|| MediaDownloadError::Failed(format!("{} returned 404", url)))
+        }
+    }
+
+    /// Changes the stored conversations while inbound media downloads, like a teammate
+    /// working on the conversation while the webhook is in flight.
+    struct InterleavingMediaDownloader {
+        conversation_repository: Arc<FakeConversationRepository>,
+        during_download: fn(&mut Conversation),
+    }
+
+    #[async_trait]
+    impl MediaDownloader for InterleavingMediaDownloader {
+        async fn download(
+            &self,
+            _url: &str,
+            _max_bytes: usize,
+        ) -> Result<DownloadedMedia, MediaDownloadError> {
+            self.conversation_repository
+                .conversations
+                .lock()
+                .expect("lock")
+                .values_mut()
+                .for_each(self.during_download);
+            Ok(DownloadedMedia {
+                bytes: vec![0xff, 0xd8, 0xff, 0xe0],
+                content_type: Some("image/jpeg".to_owned()),
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeContactRepository {
+        contacts: Mutex<Vec<Contact>>,
+    }
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, contact: &Contact) -> Result<(), RepositoryError> {
+            self.contacts.lock().expect("lock").push(contact.clone());
+            Ok(())
+        }
+
+        async fn update_contact(&self, contact: &Contact) -> Result<(), RepositoryError> {
+            let mut contacts = self.contacts.lock().expect("lock");
+            let existing = contacts
+                .iter_mut()
+                .find(|existing| existing.id == contact.id)
+                .ok_or(RepositoryError::NotFound)?;
+            *existing = contact.clone();
             Ok(())
         }
 
-        async fn find_by_event_id(
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self.contacts.lock().expect("lock").clone())
+        }
+
+        async fn find_by_phone_numbers(
             &self,
-            event_id: &str,
-        ) -> Result<ProcessedWebhookEvent, RepositoryError> {
-            self.events
+            organization_id: &uuid::Uuid,
+            phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(self
+                .contacts
                 .lock()
                 .expect("lock")
-                .get(event_id)
+                .iter()
+                .filter(|contact| {
+                    contact.organization_id == *organization_id
+                        && contact
+                            .phone_numbers
+                            .iter()
+                            .any(|number| phone_numbers.contains(number))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeAutoReplyRuleRepository {
+        rules: Vec<AutoReplyRule>,
+        replied_at: Mutex<HashMap<(uuid::Uuid, String), OffsetDateTime>>,
+    }
+
+    #[async_trait]
+    impl AutoReplyRuleRepository for FakeAutoReplyRuleRepository {
+        async fn create_rule(&self, _rule: &AutoReplyRule) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_rule(&self, _rule: &AutoReplyRule) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<AutoReplyRule, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_phone_number_id(
+            &self,
+            organization_id: &uuid::Uuid,
+            phone_number_id: &uuid::Uuid,
+        ) -> Result<Vec<AutoReplyRule>, RepositoryError> {
+            Ok(self
+                .rules
+                .iter()
+                .filter(|rule| {
+                    rule.organization_id == *organization_id
+                        && rule.phone_number_id == *phone_number_id
+                })
                 .cloned()
+                .collect())
+        }
+
+        async fn delete_rule(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_reply(
+            &self,
+            rule: &AutoReplyRule,
+            recipient_phone_number: &str,
+            now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            let mut replied_at = self.replied_at.lock().expect("lock");
+            let key = (rule.id, recipient_phone_number.to_owned());
+            if replied_at
+                .get(&key)
+                .is_some_and(|last| *last > now - time::Duration::seconds(rule.cooldown_seconds))
+            {
+                return Ok(false);
+            }
+            replied_at.insert(key, now);
+            Ok(true)
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeBusinessHoursRepository {
+        business_hours: Option<BusinessHours>,
+    }
+
+    #[async_trait]
+    impl BusinessHoursRepository for FakeBusinessHoursRepository {
+        async fn create_business_hours(
+            &self,
+            _business_hours: &BusinessHours,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_business_hours(
+            &self,
+            _business_hours: &BusinessHours,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_phone_number_id(
+            &self,
+            organization_id: &uuid::Uuid,
+            phone_number_id: &uuid::Uuid,
+        ) -> Result<BusinessHours, RepositoryError> {
+            self.business_hours
+                .clone()
+                .filter(|business_hours| {
+                    business_hours.organization_id == *organization_id
+                        && business_hours.phone_number_id == *phone_number_id
+                })
                 .ok_or(RepositoryError::NotFound)
         }
+
+        async fn delete_business_hours(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
     }
 
     fn build_conversation(
@@ -713,6 +2058,7 @@ mod tests {
         Conversation::builder()
             .id(uuid::Uuid::now_v7())
             .phone_number_id(phone_number_id)
+            .organization_id(user_id)
             .user_id(user_id)
             .maybe_recipient_phone_number(Some(recipient_phone_number.to_owned()))
             .last_message_at(now)
@@ -725,6 +2071,7 @@ mod tests {
         let now = OffsetDateTime::now_utc();
         PhoneNumber::builder()
             .id(uuid::Uuid::now_v7())
+            .organization_id(user_id)
             .user_id(user_id)
             .name("Primary".to_owned())
             .phone(phone.to_owned())
@@ -742,6 +2089,7 @@ mod tests {
         Message::builder()
             .id(uuid::Uuid::now_v7())
             .conversation_id(conversation_id)
+            .organization_id(user_id)
             .user_id(user_id)
             .message_type(MessageType::Outbound)
             .status(MessageStatus::Queued)
@@ -757,6 +2105,86 @@ mod tests {
             .build()
     }
 
+    #[derive(Default)]
+    struct FakeUsageLimitRepository {
+        limits: Vec<UsageLimit>,
+    }
+
+    #[async_trait]
+    impl UsageLimitRepository for FakeUsageLimitRepository {
+        async fn save_limit(&self, _limit: &UsageLimit) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            user_id: &uuid::Uuid,
+        ) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(self
+                .limits
+                .iter()
+                .filter(|limit| limit.user_id == *user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_limit(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_soft_limit_notification(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _period_start: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct FakeUsageRepository;
+
+    #[async_trait]
+    impl UsageRepository for FakeUsageRepository {
+        async fn record_usage(&self, _record: &UsageRecord) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn record_cost(
+            &self,
+            _message_id: &uuid::Uuid,
+            _cost_micros: i64,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn totals_since(
+            &self,
+            _user_id: &uuid::Uuid,
+            _since: OffsetDateTime,
+        ) -> Result<UsageTotals, RepositoryError> {
+            Ok(UsageTotals::default())
+        }
+
+        async fn rollups(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _since: OffsetDateTime,
+        ) -> Result<Vec<UsageRollup>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
     fn build_payload(
         provider_message_id: &str,
         to_phone: &str,
@@ -765,34 +2193,177 @@ mod tests {
         TelnyxWebhookMessagePayload {
             provider_message_id: provider_message_id.to_owned(),
             from_phone_number: Some("+14155551234".to_owned()),
+            from_carrier: None,
+            from_line_type: None,
             to: vec![TelnyxWebhookMessageParticipant {
                 phone_number: Some(to_phone.to_owned()),
                 status: Some(status.to_owned()),
+                carrier: None,
+                line_type: None,
             }],
             text: Some("Inbound hello".to_owned()),
             received_at: Some(OffsetDateTime::now_utc()),
             sent_at: None,
             completed_at: None,
             errors: Vec::new(),
+            media: Vec::new(),
+            parts: None,
+            encoding: None,
+            cost: None,
+            messaging_profile_id: None,
         }
     }
 
-    fn build_usecase(
-        conversation_repository: Arc<FakeConversationRepository>,
-        message_repository: Arc<FakeMessageRepository>,
-        phone_number_repository: Arc<FakePhoneNumberRepository>,
-        processed_webhook_event_repository: Arc<FakeProcessedWebhookEventRepository>,
-    ) -> ProcessTelnyxMessagingWebhookUsecase {
+    #[derive(Default)]
+    struct FakeOrganizationRepository {
+        conversation_routing: ConversationRouting,
+        members: Vec<OrganizationMember>,
+        /// Fails the next lookup, which happens after the inbound message is stored.
+        fail_once: Mutex<bool>,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            if std::mem::take(&mut *self.fail_once.lock().expect("lock")) {
+                return Err(RepositoryError::DatabaseError(
+                    "connection reset".to_owned(),
+                ));
+            }
+
+            let now = OffsetDateTime::now_utc();
+            Ok(Organization::builder()
+                .id(*id)
+                .name("Support".to_owned())
+                .conversation_routing(self.conversation_routing)
+                .created_at(now)
+                .updated_at(now)
+                .build())
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            self.members
+                .iter()
+                .find(|member| member.user_id == *user_id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(self.members.clone())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn build_usecase(
+        conversation_repository: Arc<FakeConversationRepository>,
+        message_repository: Arc<FakeMessageRepository>,
+        phone_number_repository: Arc<FakePhoneNumberRepository>,
+        message_status_event_repository: Arc<FakeMessageStatusEventRepository>,
+    ) -> ProcessTelnyxMessagingWebhookUsecase {
         ProcessTelnyxMessagingWebhookUsecase::builder()
             .conversation_repository(conversation_repository)
             .message_repository(message_repository)
+            .message_status_event_repository(message_status_event_repository)
             .phone_number_repository(phone_number_repository)
-            .processed_webhook_event_repository(processed_webhook_event_repository)
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
             .build()
     }
 
+    fn build_inbound_command(
+        event_id: &str,
+        to_phone: &str,
+        text: &str,
+    ) -> ProcessTelnyxWebhookCommand {
+        ProcessTelnyxWebhookCommand {
+            event_id: event_id.to_owned(),
+            event_type: "message.received".to_owned(),
+            occurred_at: OffsetDateTime::now_utc(),
+            payload: TelnyxWebhookMessagePayload {
+                provider_message_id: format!("provider-{}", event_id),
+                from_phone_number: Some("+13125550001".to_owned()),
+                from_carrier: None,
+                from_line_type: None,
+                to: vec![TelnyxWebhookMessageParticipant {
+                    phone_number: Some(to_phone.to_owned()),
+                    status: Some("webhook_delivered".to_owned()),
+                    carrier: None,
+                    line_type: None,
+                }],
+                text: Some(text.to_owned()),
+                received_at: Some(OffsetDateTime::now_utc()),
+                sent_at: None,
+                completed_at: None,
+                errors: Vec::new(),
+                media: Vec::new(),
+                parts: None,
+                encoding: None,
+                cost: None,
+                messaging_profile_id: None,
+            },
+        }
+    }
+
     #[tokio::test]
-    async fn message_sent_updates_outbound_message_and_records_event() {
+    async fn message_sent_updates_outbound_message() {
         let user_id = uuid::Uuid::now_v7();
         let phone_number = build_phone_number(user_id, "+13125550100");
         let conversation = build_conversation(user_id, phone_number.id, "+14155551234");
@@ -808,14 +2379,11 @@ mod tests {
         let phone_number_repository = Arc::new(FakePhoneNumberRepository {
             phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number)])),
         });
-        let processed_event_repository = Arc::new(FakeProcessedWebhookEventRepository {
-            events: Mutex::new(HashMap::new()),
-        });
         let usecase = build_usecase(
             conversation_repository,
             message_repository.clone(),
             phone_number_repository,
-            processed_event_repository.clone(),
+            Arc::new(FakeMessageStatusEventRepository::default()),
         );
 
         let result = usecase
@@ -824,7 +2392,6 @@ mod tests {
                 event_type: "message.sent".to_owned(),
                 occurred_at: OffsetDateTime::now_utc(),
                 payload: build_payload("provider-message-id", "+14155551234", "sent"),
-                raw_payload: json!({ "data": {} }),
             })
             .await
             .expect("webhook should be processed");
@@ -845,11 +2412,68 @@ mod tests {
             .await
             .expect("message should still exist");
         assert_eq!(stored_message.status, MessageStatus::Sent);
+    }
 
-        processed_event_repository
-            .find_by_event_id("event-1")
+    #[tokio::test]
+    async fn status_updates_are_recorded_as_a_timeline() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+13125550100");
+        let conversation = build_conversation(user_id, phone_number.id, "+14155551234");
+        let mut message = build_message(user_id, conversation.id, "provider-message-id");
+        message.provider_status_updated_at = None;
+        let message_id = message.id;
+
+        let message_status_event_repository = Arc::new(FakeMessageStatusEventRepository::default());
+        let usecase = build_usecase(
+            Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::from([(conversation.id, conversation)])),
+            }),
+            Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::from([(message.id, message)])),
+            }),
+            Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number)])),
+            }),
+            message_status_event_repository.clone(),
+        );
+
+        let sent_at = OffsetDateTime::now_utc();
+        for (event_id, event_type, status, occurred_at) in [
+            ("event-sent", "message.sent", "sent", sent_at),
+            (
+                "event-finalized",
+                "message.finalized",
+                "delivered",
+                sent_at + time::Duration::seconds(2),
+            ),
+        ] {
+            usecase
+                .execute(ProcessTelnyxWebhookCommand {
+                    event_id: event_id.to_owned(),
+                    event_type: event_type.to_owned(),
+                    occurred_at,
+                    payload: build_payload("provider-message-id", "+14155551234", status),
+                })
+                .await
+                .expect("webhook should be processed");
+        }
+
+        let timeline = message_status_event_repository
+            .list_by_message_ids(&user_id, &[message_id])
             .await
-            .expect("processed event should be stored");
+            .expect("timeline should load");
+        let statuses = timeline
+            .iter()
+            .map(|event| (event.status, event.provider_status.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                (MessageStatus::Sent, Some("sent".to_owned())),
+                (MessageStatus::Delivered, Some("delivered".to_owned())),
+            ]
+        );
+        assert_eq!(timeline[0].occurred_at, sent_at);
     }
 
     #[tokio::test]
@@ -869,14 +2493,12 @@ mod tests {
         let phone_number_repository = Arc::new(FakePhoneNumberRepository {
             phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number)])),
         });
-        let processed_event_repository = Arc::new(FakeProcessedWebhookEventRepository {
-            events: Mutex::new(HashMap::new()),
-        });
+        let message_status_event_repository = Arc::new(FakeMessageStatusEventRepository::default());
         let usecase = build_usecase(
             conversation_repository,
             message_repository,
             phone_number_repository,
-            processed_event_repository,
+            message_status_event_repository.clone(),
         );
 
         let result = usecase
@@ -887,9 +2509,13 @@ mod tests {
                 payload: TelnyxWebhookMessagePayload {
                     provider_message_id: "provider-message-id".to_owned(),
                     from_phone_number: Some("+13125550100".to_owned()),
+                    from_carrier: None,
+                    from_line_type: None,
                     to: vec![TelnyxWebhookMessageParticipant {
                         phone_number: Some("+14155551234".to_owned()),
                         status: Some("delivery_failed".to_owned()),
+                        carrier: Some("T-Mobile USA".to_owned()),
+                        line_type: None,
                     }],
                     text: Some("Hello".to_owned()),
                     received_at: None,
@@ -900,8 +2526,12 @@ mod tests {
                         detail: Some("Destination unreachable".to_owned()),
                         title: None,
                     }],
+                    media: Vec::new(),
+                    parts: None,
+                    encoding: None,
+                    cost: None,
+                    messaging_profile_id: None,
                 },
-                raw_payload: json!({ "data": {} }),
             })
             .await
             .expect("webhook should be processed");
@@ -916,6 +2546,16 @@ mod tests {
             notification.message.provider_error_detail.as_deref(),
             Some("Destination unreachable")
         );
+
+        let history = message_status_event_repository.events.lock().expect("lock");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, MessageStatus::Failed);
+        assert_eq!(
+            history[0].provider_status.as_deref(),
+            Some("delivery_failed")
+        );
+        assert_eq!(history[0].carrier.as_deref(), Some("T-Mobile USA"));
+        assert_eq!(history[0].error_code.as_deref(), Some("40300"));
     }
 
     #[tokio::test]
@@ -936,14 +2576,11 @@ mod tests {
         let phone_number_repository = Arc::new(FakePhoneNumberRepository {
             phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number)])),
         });
-        let processed_event_repository = Arc::new(FakeProcessedWebhookEventRepository {
-            events: Mutex::new(HashMap::new()),
-        });
         let usecase = build_usecase(
             conversation_repository,
             message_repository.clone(),
             phone_number_repository,
-            processed_event_repository.clone(),
+            Arc::new(FakeMessageStatusEventRepository::default()),
         );
 
         let result = usecase
@@ -952,7 +2589,6 @@ mod tests {
                 event_type: "message.sent".to_owned(),
                 occurred_at: now - time::Duration::seconds(5),
                 payload: build_payload("provider-message-id", "+14155551234", "sent"),
-                raw_payload: json!({ "data": {} }),
             })
             .await
             .expect("webhook should be processed");
@@ -963,11 +2599,155 @@ mod tests {
             .await
             .expect("message should still exist");
         assert_eq!(stored_message.status, message.status);
+    }
+
+    #[tokio::test]
+    async fn late_sent_event_does_not_regress_delivered_message() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+13125550100");
+        let conversation = build_conversation(user_id, phone_number.id, "+14155551234");
+        let mut message = build_message(user_id, conversation.id, "provider-message-id");
+        let now = OffsetDateTime::now_utc();
+        message.status = MessageStatus::Delivered;
+        message.provider_status = Some("delivered".to_owned());
+        message.provider_status_updated_at = Some(now);
 
-        processed_event_repository
-            .find_by_event_id("event-3")
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversations: Mutex::new(HashMap::from([(conversation.id, conversation)])),
+        });
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::from([(message.id, message)])),
+        });
+        let phone_number_repository = Arc::new(FakePhoneNumberRepository {
+            phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number)])),
+        });
+        let message_status_event_repository = Arc::new(FakeMessageStatusEventRepository::default());
+        let usecase = build_usecase(
+            conversation_repository,
+            message_repository.clone(),
+            phone_number_repository,
+            message_status_event_repository.clone(),
+        );
+
+        let result = usecase
+            .execute(ProcessTelnyxWebhookCommand {
+                event_id: "event-late-sent".to_owned(),
+                event_type: "message.sent".to_owned(),
+                occurred_at: now,
+                payload: build_payload("provider-message-id", "+14155551234", "sent"),
+            })
+            .await
+            .expect("webhook should be processed");
+
+        assert!(result.notification.is_none());
+        let stored_message = message_repository
+            .find_by_provider_message_id("provider-message-id")
             .await
-            .expect("stale event should still be recorded");
+            .expect("message should still exist");
+        assert_eq!(stored_message.status, MessageStatus::Delivered);
+        assert_eq!(stored_message.provider_status.as_deref(), Some("delivered"));
+        assert_eq!(
+            message_status_event_repository
+                .events
+                .lock()
+                .expect("lock")
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn late_event_still_records_cost_and_carrier_details() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+13125550100");
+        let conversation = build_conversation(user_id, phone_number.id, "+14155551234");
+        let mut message = build_message(user_id, conversation.id, "provider-message-id");
+        let now = OffsetDateTime::now_utc();
+        message.status = MessageStatus::Delivered;
+        message.provider_status = Some("delivered".to_owned());
+        message.provider_status_updated_at = Some(now);
+        message.segments = Some(1);
+        message.encoding = Some(SmsEncoding::Gsm7);
+
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::from([(message.id, message)])),
+        });
+        let usecase = build_usecase(
+            Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::from([(conversation.id, conversation)])),
+            }),
+            message_repository.clone(),
+            Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number)])),
+            }),
+            Arc::new(FakeMessageStatusEventRepository::default()),
+        );
+        let mut payload = build_payload("provider-message-id", "+14155551234", "sent");
+        payload.to[0].carrier = Some("T-Mobile USA".to_owned());
+        payload.to[0].line_type = Some("Wireless".to_owned());
+        payload.parts = Some(2);
+        payload.encoding = Some("UCS-2".to_owned());
+        payload.cost = Some(TelnyxWebhookMessageCost {
+            amount: Some("0.0080".to_owned()),
+            currency: Some("usd".to_owned()),
+        });
+        payload.messaging_profile_id = Some("profile-1".to_owned());
+
+        let result = usecase
+            .execute(ProcessTelnyxWebhookCommand {
+                event_id: "event-late-sent".to_owned(),
+                event_type: "message.sent".to_owned(),
+                occurred_at: now - time::Duration::seconds(5),
+                payload,
+            })
+            .await
+            .expect("webhook should be processed");
+
+        assert!(result.notification.is_none());
+        let stored_message = message_repository
+            .find_by_provider_message_id("provider-message-id")
+            .await
+            .expect("message should still exist");
+        assert_eq!(stored_message.status, MessageStatus::Delivered);
+        assert_eq!(stored_message.segments, Some(2));
+        assert_eq!(stored_message.encoding, Some(SmsEncoding::Ucs2));
+        assert_eq!(stored_message.cost_micros, Some(8_000));
+        assert_eq!(stored_message.cost_currency.as_deref(), Some("USD"));
+        assert_eq!(stored_message.carrier.as_deref(), Some("T-Mobile USA"));
+        assert_eq!(stored_message.line_type.as_deref(), Some("Wireless"));
+        assert_eq!(
+            stored_message.messaging_profile_id.as_deref(),
+            Some("profile-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn status_update_for_unknown_message_is_reported_as_orphaned() {
+        let usecase = build_usecase(
+            Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }),
+            Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }),
+            Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::new()),
+            }),
+            Arc::new(FakeMessageStatusEventRepository::default()),
+        );
+
+        let result = usecase
+            .execute(ProcessTelnyxWebhookCommand {
+                event_id: "event-4".to_owned(),
+                event_type: "message.finalized".to_owned(),
+                occurred_at: OffsetDateTime::now_utc(),
+                payload: build_payload("not-yet-stored", "+14155551234", "delivered"),
+            })
+            .await
+            .expect("webhook should be processed");
+
+        assert!(result.orphaned);
+        assert!(result.notification.is_none());
     }
 
     #[tokio::test]
@@ -984,14 +2764,11 @@ mod tests {
         let phone_number_repository = Arc::new(FakePhoneNumberRepository {
             phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
         });
-        let processed_event_repository = Arc::new(FakeProcessedWebhookEventRepository {
-            events: Mutex::new(HashMap::new()),
-        });
         let usecase = build_usecase(
             conversation_repository.clone(),
             message_repository.clone(),
             phone_number_repository,
-            processed_event_repository,
+            Arc::new(FakeMessageStatusEventRepository::default()),
         );
 
         let result = usecase
@@ -1002,17 +2779,25 @@ mod tests {
                 payload: TelnyxWebhookMessagePayload {
                     provider_message_id: "inbound-provider-message-id".to_owned(),
                     from_phone_number: Some("+13125550001".to_owned()),
+                    from_carrier: None,
+                    from_line_type: None,
                     to: vec![TelnyxWebhookMessageParticipant {
                         phone_number: Some(phone_number.phone.clone()),
                         status: Some("webhook_delivered".to_owned()),
+                        carrier: None,
+                        line_type: None,
                     }],
                     text: Some("Hello from Telnyx!".to_owned()),
                     received_at: Some(OffsetDateTime::now_utc()),
                     sent_at: None,
                     completed_at: None,
                     errors: Vec::new(),
+                    media: Vec::new(),
+                    parts: None,
+                    encoding: None,
+                    cost: None,
+                    messaging_profile_id: None,
                 },
-                raw_payload: json!({ "data": {} }),
             })
             .await
             .expect("webhook should be processed");
@@ -1034,7 +2819,7 @@ mod tests {
         );
 
         let conversations = conversation_repository
-            .list_by_user_id(&user_id)
+            .list_by_organization_id(&user_id)
             .await
             .expect("conversations should load");
         assert_eq!(conversations.len(), 1);
@@ -1046,6 +2831,1043 @@ mod tests {
         assert_eq!(messages.len(), 1);
     }
 
+    #[tokio::test]
+    async fn stop_and_start_keywords_toggle_the_opt_out() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let opt_out_repository = Arc::new(FakeOptOutRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(opt_out_repository.clone())
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .build();
+
+        let result = usecase
+            .execute(build_inbound_command("stop", &phone_number.phone, " Stop "))
+            .await
+            .expect("webhook should be processed");
+
+        assert!(result.notification.is_some());
+        assert!(
+            opt_out_repository
+                .is_opted_out(&phone_number.id, "+13125550001")
+                .await
+                .expect("lookup")
+        );
+
+        usecase
+            .execute(build_inbound_command("start", &phone_number.phone, "START"))
+            .await
+            .expect("webhook should be processed");
+
+        assert!(
+            !opt_out_repository
+                .is_opted_out(&phone_number.id, "+13125550001")
+                .await
+                .expect("lookup")
+        );
+    }
+
+    #[tokio::test]
+    async fn round_robin_routes_new_conversations_to_members_in_turn() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        let [viewer, first_agent, second_agent] = [1, 2, 3].map(uuid::Uuid::from_u128);
+        let members = [
+            (viewer, OrganizationRole::ReadOnly),
+            (first_agent, OrganizationRole::Agent),
+            (second_agent, OrganizationRole::Admin),
+        ]
+        .map(|(member_id, role)| {
+            OrganizationMember::builder()
+                .organization_id(user_id)
+                .user_id(member_id)
+                .role(role)
+                .created_at(now)
+                .build()
+        });
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                conversation_routing: ConversationRouting::RoundRobin,
+                members: members.to_vec(),
+                ..Default::default()
+            }))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .build();
+
+        let first = usecase
+            .execute(build_inbound_command("first", &phone_number.phone, "Hi"))
+            .await
+            .expect("webhook should be processed")
+            .notification
+            .expect("notification");
+        let mut other_sender = build_inbound_command("second", &phone_number.phone, "Hello");
+        other_sender.payload.from_phone_number = Some("+13125550002".to_owned());
+        let second = usecase
+            .execute(other_sender)
+            .await
+            .expect("webhook should be processed")
+            .notification
+            .expect("notification");
+        let follow_up = usecase
+            .execute(build_inbound_command(
+                "third",
+                &phone_number.phone,
+                "Anyone?",
+            ))
+            .await
+            .expect("webhook should be processed")
+            .notification
+            .expect("notification");
+
+        assert!(first.assigned);
+        assert_eq!(first.conversation.assigned_user_id, Some(first_agent));
+        assert!(second.assigned);
+        assert_eq!(second.conversation.assigned_user_id, Some(second_agent));
+        assert!(!follow_up.assigned);
+        assert_eq!(follow_up.conversation.assigned_user_id, Some(first_agent));
+    }
+
+    #[tokio::test]
+    async fn inbound_message_keeps_an_assignment_made_while_it_was_processed() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        let conversation = build_conversation(user_id, phone_number.id, "+13125550001");
+        let agent = OrganizationMember::builder()
+            .organization_id(user_id)
+            .user_id(uuid::Uuid::now_v7())
+            .role(OrganizationRole::Agent)
+            .created_at(now)
+            .build();
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversations: Mutex::new(HashMap::from([(conversation.id, conversation.clone())])),
+        });
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                conversation_routing: ConversationRouting::RoundRobin,
+                members: vec![agent],
+                ..Default::default()
+            }))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(InterleavingMediaDownloader {
+                conversation_repository: conversation_repository.clone(),
+                during_download: |conversation| {
+                    conversation.assign(Some(uuid::Uuid::from_u128(7)), OffsetDateTime::now_utc())
+                },
+            }))
+            .build();
+
+        let mut command = build_inbound_command("assigned", &phone_number.phone, "Photo");
+        command.payload.media = vec![TelnyxWebhookMessageMedia {
+            url: "https://media.example.com/photo.jpg".to_owned(),
+            content_type: None,
+            size: Some(4),
+            sha256: None,
+        }];
+        let notification = usecase
+            .execute(command)
+            .await
+            .expect("webhook should be processed")
+            .notification
+            .expect("notification");
+
+        assert!(!notification.assigned);
+        assert_eq!(
+            notification.conversation.assigned_user_id,
+            Some(uuid::Uuid::from_u128(7))
+        );
+        let stored =
+            conversation_repository.conversations.lock().expect("lock")[&conversation.id].clone();
+        assert_eq!(stored.assigned_user_id, Some(uuid::Uuid::from_u128(7)));
+    }
+
+    #[tokio::test]
+    async fn inbound_message_keeps_a_pin_made_while_it_was_processed() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let conversation = build_conversation(user_id, phone_number.id, "+13125550001");
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversations: Mutex::new(HashMap::from([(conversation.id, conversation.clone())])),
+        });
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(InterleavingMediaDownloader {
+                conversation_repository: conversation_repository.clone(),
+                during_download: |conversation| {
+                    conversation.apply(ConversationStateChange::Pin, OffsetDateTime::now_utc())
+                },
+            }))
+            .build();
+
+        let mut command = build_inbound_command("pinned", &phone_number.phone, "Photo");
+        command.payload.media = vec![TelnyxWebhookMessageMedia {
+            url: "https://media.example.com/photo.jpg".to_owned(),
+            content_type: None,
+            size: Some(4),
+            sha256: None,
+        }];
+        let notification = usecase
+            .execute(command)
+            .await
+            .expect("webhook should be processed")
+            .notification
+            .expect("notification");
+
+        assert!(notification.conversation.pinned_at.is_some());
+        let stored =
+            conversation_repository.conversations.lock().expect("lock")[&conversation.id].clone();
+        assert!(stored.pinned_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn inbound_message_brings_archived_and_snoozed_conversations_back() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        let mut conversation = build_conversation(user_id, phone_number.id, "+13125550001");
+        conversation.apply(ConversationStateChange::Pin, now);
+        conversation.apply(ConversationStateChange::Archive, now);
+        conversation.apply(
+            ConversationStateChange::Snooze(now + time::Duration::days(1)),
+            now,
+        );
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversations: Mutex::new(HashMap::from([(conversation.id, conversation.clone())])),
+        });
+        let usecase = build_usecase(
+            conversation_repository.clone(),
+            Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }),
+            Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }),
+            Arc::new(FakeMessageStatusEventRepository::default()),
+        );
+
+        usecase
+            .execute(build_inbound_command(
+                "reopen",
+                &phone_number.phone,
+                "Back again",
+            ))
+            .await
+            .expect("webhook should be processed");
+
+        let stored =
+            conversation_repository.conversations.lock().expect("lock")[&conversation.id].clone();
+        assert_eq!(stored.archived_at, None);
+        assert_eq!(stored.snoozed_until, None);
+        assert!(stored.pinned_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn help_keyword_queues_the_configured_reply() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(message_repository.clone())
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .help_reply("Acme support: reply STOP to opt out.".to_owned())
+            .build();
+
+        let result = usecase
+            .execute(build_inbound_command("help", &phone_number.phone, "help"))
+            .await
+            .expect("webhook should be processed");
+
+        let notification = result.notification.expect("notification should exist");
+        let jobs = outbound_message_queue_repository
+            .jobs
+            .lock()
+            .expect("lock")
+            .clone();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].to_number, "+13125550001");
+
+        let reply = message_repository
+            .find_by_id(&jobs[0].message_id)
+            .await
+            .expect("reply should be stored");
+        assert_eq!(reply.message_type, MessageType::Outbound);
+        assert_eq!(reply.status, MessageStatus::Pending);
+        assert_eq!(reply.conversation_id, notification.conversation.id);
+        assert_eq!(reply.from_number, phone_number.phone);
+        assert_eq!(reply.content, "Acme support: reply STOP to opt out.");
+    }
+
+    #[tokio::test]
+    async fn automated_replies_stop_at_the_hard_limit() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(message_repository.clone())
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository {
+                limits: vec![
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Monthly)
+                        .hard_limit_micros(0)
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                ],
+            }))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .help_reply("Acme support: reply STOP to opt out.".to_owned())
+            .build();
+
+        let result = usecase
+            .execute(build_inbound_command("help", &phone_number.phone, "help"))
+            .await
+            .expect("webhook should be processed");
+
+        let notification = result.notification.expect("notification should exist");
+        assert_eq!(notification.message.message_type, MessageType::Inbound);
+        assert!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert_eq!(message_repository.messages.lock().expect("lock").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn auto_reply_held_back_by_the_hard_limit_does_not_start_the_cooldown() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        let rule = AutoReplyRule::builder()
+            .id(uuid::Uuid::now_v7())
+            .organization_id(phone_number.organization_id)
+            .user_id(user_id)
+            .phone_number_id(phone_number.id)
+            .match_type(AutoReplyMatchType::Exact)
+            .pattern("hours".to_owned())
+            .reply_template("We're open 9 to 5.".to_owned())
+            .cooldown_seconds(3600)
+            .enabled(true)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let auto_reply_rule_repository = Arc::new(FakeAutoReplyRuleRepository {
+            rules: vec![rule],
+            replied_at: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(auto_reply_rule_repository.clone())
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository {
+                limits: vec![
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Daily)
+                        .hard_limit_micros(0)
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                ],
+            }))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .build();
+
+        usecase
+            .execute(build_inbound_command("hours", &phone_number.phone, "hours"))
+            .await
+            .expect("webhook should be processed");
+
+        assert!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        assert!(
+            auto_reply_rule_repository
+                .replied_at
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn auto_replies_of_a_shared_organization_are_billed_to_its_owner() {
+        let organization_id = uuid::Uuid::now_v7();
+        let owner_id = uuid::Uuid::now_v7();
+        let admin_id = uuid::Uuid::now_v7();
+        let phone_number = PhoneNumber {
+            organization_id,
+            ..build_phone_number(admin_id, "+17735550002")
+        };
+        let now = OffsetDateTime::now_utc();
+        // Set up by another admin than the one who added the number.
+        let rule = AutoReplyRule::builder()
+            .id(uuid::Uuid::now_v7())
+            .organization_id(organization_id)
+            .user_id(uuid::Uuid::now_v7())
+            .phone_number_id(phone_number.id)
+            .match_type(AutoReplyMatchType::Exact)
+            .pattern("hours".to_owned())
+            .reply_template("We're open 9 to 5.".to_owned())
+            .cooldown_seconds(0)
+            .enabled(true)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let members = [
+            (admin_id, OrganizationRole::Admin),
+            (owner_id, OrganizationRole::Owner),
+        ]
+        .map(|(user_id, role)| {
+            OrganizationMember::builder()
+                .organization_id(organization_id)
+                .user_id(user_id)
+                .role(role)
+                .created_at(now)
+                .build()
+        });
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(message_repository.clone())
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository {
+                rules: vec![rule],
+                replied_at: Mutex::new(HashMap::new()),
+            }))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                members: members.to_vec(),
+                ..Default::default()
+            }))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .build();
+
+        usecase
+            .execute(build_inbound_command("hours", &phone_number.phone, "hours"))
+            .await
+            .expect("webhook should be processed");
+
+        let jobs = outbound_message_queue_repository
+            .jobs
+            .lock()
+            .expect("lock")
+            .clone();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].user_id, owner_id);
+        let reply = message_repository
+            .find_by_id(&jobs[0].message_id)
+            .await
+            .expect("reply should be stored");
+        assert_eq!(reply.user_id, owner_id);
+        assert_eq!(reply.content, "We're open 9 to 5.");
+    }
+
+    #[tokio::test]
+    async fn retry_after_a_failure_past_the_insert_finishes_the_message_once() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversations: Mutex::new(HashMap::new()),
+        });
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(message_repository.clone())
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository {
+                fail_once: Mutex::new(true),
+                ..Default::default()
+            }))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .help_reply("Acme support: reply STOP to opt out.".to_owned())
+            .build();
+        let cmd = build_inbound_command("help-retry", &phone_number.phone, "help");
+
+        let failed = usecase.execute(cmd.clone()).await;
+        assert!(matches!(failed, Err(UsecaseError::Database(_))));
+        assert_eq!(message_repository.messages.lock().expect("lock").len(), 1);
+        assert!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+
+        let result = usecase
+            .execute(cmd.clone())
+            .await
+            .expect("retry should be processed");
+        let notification = result.notification.expect("retry should notify");
+        assert_eq!(
+            notification.kind,
+            MessagingWebhookNotificationKind::MessageCreated
+        );
+        let stored = conversation_repository
+            .conversations
+            .lock()
+            .expect("lock")
+            .get(&notification.conversation.id)
+            .cloned()
+            .expect("conversation should exist");
+        assert!(stored.last_message_at >= notification.message.created_at);
+        assert_eq!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .len(),
+            1
+        );
+
+        usecase
+            .execute(cmd)
+            .await
+            .expect("another retry should be processed");
+        assert_eq!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .len(),
+            1
+        );
+        assert_eq!(message_repository.messages.lock().expect("lock").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn matching_auto_reply_rule_tags_sender_and_replies_once_per_cooldown() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        let contact = Contact::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(user_id)
+            .name("Ada Lovelace".to_owned())
+            .phone_numbers(vec!["+13125550001".to_owned()])
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let rule = AutoReplyRule::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .organization_id(phone_number.organization_id)
+            .phone_number_id(phone_number.id)
+            .match_type(AutoReplyMatchType::Prefix)
+            .pattern("join".to_owned())
+            .reply_template("Welcome to the list, {{first_name}}!".to_owned())
+            .tag("vip".to_owned())
+            .cooldown_seconds(3600)
+            .enabled(true)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let contact_repository = Arc::new(FakeContactRepository {
+            contacts: Mutex::new(vec![contact]),
+        });
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(message_repository.clone())
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(contact_repository.clone())
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository {
+                rules: vec![rule],
+                replied_at: Mutex::new(HashMap::new()),
+            }))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .build();
+
+        usecase
+            .execute(build_inbound_command(
+                "join-1",
+                &phone_number.phone,
+                "JOIN please",
+            ))
+            .await
+            .expect("webhook should be processed");
+        usecase
+            .execute(build_inbound_command("join-2", &phone_number.phone, "join"))
+            .await
+            .expect("webhook should be processed");
+        usecase
+            .execute(build_inbound_command("other", &phone_number.phone, "hello"))
+            .await
+            .expect("webhook should be processed");
+
+        let jobs = outbound_message_queue_repository
+            .jobs
+            .lock()
+            .expect("lock")
+            .clone();
+        assert_eq!(jobs.len(), 1);
+        let reply = message_repository
+            .find_by_id(&jobs[0].message_id)
+            .await
+            .expect("reply should be stored");
+        assert!(reply.automated);
+        assert_eq!(reply.message_type, MessageType::Outbound);
+        assert_eq!(reply.content, "Welcome to the list, Ada!");
+
+        let contacts = contact_repository.contacts.lock().expect("lock").clone();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].tags, vec!["vip".to_owned()]);
+    }
+
+    #[tokio::test]
+    async fn away_message_is_sent_once_per_closed_period() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        // No openings at all, so the number is always closed.
+        let business_hours = BusinessHours::builder()
+            .phone_number_id(phone_number.id)
+            .organization_id(phone_number.organization_id)
+            .user_id(user_id)
+            .timezone("Europe/Berlin".to_owned())
+            .opening_hours(Vec::new())
+            .holidays(Vec::new())
+            .away_message("We're closed, {{sender_name}} will get back to you.".to_owned())
+            .enabled(true)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversations: Mutex::new(HashMap::new()),
+        });
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(message_repository.clone())
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository {
+                business_hours: Some(business_hours),
+            }))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .build();
+
+        for event_id in ["after-hours-1", "after-hours-2"] {
+            usecase
+                .execute(build_inbound_command(event_id, &phone_number.phone, "hi"))
+                .await
+                .expect("webhook should be processed");
+        }
+
+        let jobs = outbound_message_queue_repository
+            .jobs
+            .lock()
+            .expect("lock")
+            .clone();
+        assert_eq!(jobs.len(), 1);
+        let reply = message_repository
+            .find_by_id(&jobs[0].message_id)
+            .await
+            .expect("reply should be stored");
+        assert!(reply.automated);
+        assert_eq!(reply.content, "We're closed, Primary will get back to you.");
+
+        let conversation = conversation_repository
+            .conversations
+            .lock()
+            .expect("lock")
+            .values()
+            .next()
+            .cloned()
+            .expect("conversation should exist");
+        assert!(conversation.last_away_reply_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn away_message_held_back_by_the_hard_limit_is_not_claimed() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let now = OffsetDateTime::now_utc();
+        let business_hours = BusinessHours::builder()
+            .phone_number_id(phone_number.id)
+            .organization_id(phone_number.organization_id)
+            .user_id(user_id)
+            .timezone("Europe/Berlin".to_owned())
+            .opening_hours(Vec::new())
+            .holidays(Vec::new())
+            .away_message("We're closed.".to_owned())
+            .enabled(true)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let conversation_repository = Arc::new(FakeConversationRepository {
+            conversations: Mutex::new(HashMap::new()),
+        });
+        let outbound_message_queue_repository =
+            Arc::new(FakeOutboundMessageQueueRepository::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(conversation_repository.clone())
+            .message_repository(Arc::new(FakeMessageRepository {
+                messages: Mutex::new(HashMap::new()),
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(outbound_message_queue_repository.clone())
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository {
+                business_hours: Some(business_hours),
+            }))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository {
+                limits: vec![
+                    UsageLimit::builder()
+                        .user_id(user_id)
+                        .period(UsagePeriod::Daily)
+                        .hard_limit_micros(0)
+                        .created_at(now)
+                        .updated_at(now)
+                        .build(),
+                ],
+            }))
+            .media_storage(Arc::new(FakeMediaStorage::default()))
+            .media_downloader(Arc::new(FakeMediaDownloader::default()))
+            .build();
+
+        usecase
+            .execute(build_inbound_command(
+                "after-hours",
+                &phone_number.phone,
+                "hi",
+            ))
+            .await
+            .expect("webhook should be processed");
+
+        assert!(
+            outbound_message_queue_repository
+                .jobs
+                .lock()
+                .expect("lock")
+                .is_empty()
+        );
+        let conversation = conversation_repository
+            .conversations
+            .lock()
+            .expect("lock")
+            .values()
+            .next()
+            .cloned()
+            .expect("conversation should exist");
+        assert!(conversation.last_away_reply_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn message_received_archives_inbound_media() {
+        let user_id = uuid::Uuid::now_v7();
+        let phone_number = build_phone_number(user_id, "+17735550002");
+        let media_url = "https://media.telnyx.com/inbound/photo.jpg?X-Amz-Signature=abc";
+
+        let message_repository = Arc::new(FakeMessageRepository {
+            messages: Mutex::new(HashMap::new()),
+        });
+        let media_storage = Arc::new(FakeMediaStorage::default());
+        let usecase = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository {
+                conversations: Mutex::new(HashMap::new()),
+            }))
+            .message_repository(message_repository.clone())
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository::default()))
+            .opt_out_repository(Arc::new(FakeOptOutRepository::default()))
+            .outbound_message_queue_repository(Arc::new(
+                FakeOutboundMessageQueueRepository::default(),
+            ))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository {
+                phone_numbers: Mutex::new(HashMap::from([(phone_number.id, phone_number.clone())])),
+            }))
+            .contact_repository(Arc::new(FakeContactRepository::default()))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository::default()))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository::default()))
+            .organization_repository(Arc::new(FakeOrganizationRepository::default()))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository::default()))
+            .media_storage(media_storage.clone())
+            .media_downloader(Arc::new(FakeMediaDownloader {
+                files: HashMap::from([(
+                    media_url.to_owned(),
+                    DownloadedMedia {
+                        bytes: vec![0xff, 0xd8, 0xff, 0xe0],
+                        content_type: Some("image/jpeg".to_owned()),
+                    },
+                )]),
+            }))
+            .build();
+
+        let mut payload = build_payload("inbound-mms-id", &phone_number.phone, "webhook_delivered");
+        payload.media = vec![TelnyxWebhookMessageMedia {
+            url: media_url.to_owned(),
+            content_type: None,
+            size: Some(4),
+            sha256: Some("SHA256-4".to_owned()),
+        }];
+        let result = usecase
+            .execute(ProcessTelnyxWebhookCommand {
+                event_id: "event-5".to_owned(),
+                event_type: "message.received".to_owned(),
+                occurred_at: OffsetDateTime::now_utc(),
+                payload: payload.clone(),
+            })
+            .await
+            .expect("webhook should be processed");
+
+        let notification = result.notification.expect("notification should exist");
+        let attachment = notification
+            .message
+            .attachments
+            .first()
+            .expect("attachment should be archived");
+        assert_eq!(attachment.file_name, "photo.jpg");
+        assert_eq!(attachment.content_type, "image/jpeg");
+        assert_eq!(attachment.size_bytes, 4);
+        assert_eq!(attachment.checksum_sha256.as_deref(), Some("sha256-4"));
+        assert!(
+            media_storage
+                .get(&attachment.storage_key)
+                .await
+                .is_ok_and(|bytes| bytes.len() == 4)
+        );
+
+        payload.provider_message_id = "inbound-mms-mismatch".to_owned();
+        payload.media[0].sha256 = Some("deadbeef".to_owned());
+        let result = usecase
+            .execute(ProcessTelnyxWebhookCommand {
+                event_id: "event-6".to_owned(),
+                event_type: "message.received".to_owned(),
+                occurred_at: OffsetDateTime::now_utc(),
+                payload,
+            })
+            .await;
+
+        assert!(matches!(result, Err(UsecaseError::ExternalService(_))));
+        assert_eq!(media_storage.objects.lock().expect("lock").len(), 1);
+        assert!(
+            message_repository
+                .find_by_provider_message_id("inbound-mms-mismatch")
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn media_file_name_falls_back_when_url_has_no_file() {
+        assert_eq!(
+            media_file_name("https://example.com/a/b/clip.mp4?sig=1", 0),
+            "clip.mp4"
+        );
+        assert_eq!(media_file_name("https://example.com/", 1), "attachment-2");
+        assert_eq!(media_file_name("https://example.com", 2), "attachment-3");
+    }
+
     #[test]
     fn finalized_status_mapping_matches_expected_outcomes() {
         assert_eq!(map_finalized_status("delivered"), MessageStatus::Delivered);
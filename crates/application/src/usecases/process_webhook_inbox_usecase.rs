@@ -0,0 +1,1226 @@
+use std::{sync::Arc, time::Duration};
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::ProcessTelnyxWebhookCommand,
+    usecases::{
+        UsecaseError, exponential_backoff,
+        process_telnyx_messaging_webhook_usecase::{
+            MessagingWebhookNotification, ProcessTelnyxMessagingWebhookUsecase,
+        },
+    },
+};
+use domain::{
+    models::processed_webhook_event::{ProcessedWebhookEvent, WebhookEventStatus},
+    repositories::processed_webhook_event_repository::ProcessedWebhookEventRepository,
+};
+
+pub const WEBHOOK_INBOX_BATCH_SIZE: usize = 50;
+pub const MAX_WEBHOOK_INBOX_ATTEMPTS: i32 = 10;
+/// How long a claimed event stays invisible to other workers. Inbound MMS processing
+/// downloads media, so this has to cover a few slow downloads.
+const WEBHOOK_INBOX_LEASE: Duration = Duration::from_secs(5 * 60);
+const BASE_RETRY_DELAY: Duration = Duration::from_secs(5);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30 * 60);
+/// How long a delivery status may wait for its outbound message. Sends are retried for well
+/// under an hour, so anything still unmatched after a day is not ours.
+const ORPHANED_EVENT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Default)]
+pub struct ProcessWebhookInboxResult {
+    /// Realtime updates produced by the events processed during this run.
+    pub notifications: Vec<MessagingWebhookNotification>,
+    /// Delivery status events parked until their message exists.
+    pub parked: Vec<String>,
+    /// Events that gave up during this run, with the reason.
+    pub dead_lettered: Vec<(String, String)>,
+    /// Events that failed and were scheduled for another attempt.
+    pub failures: Vec<(String, UsecaseError)>,
+}
+
+/// Drains the webhook inbox filled by `IngestTelnyxWebhookUsecase`. Payloads that cannot be
+/// decoded or refer to unknown data are dead-lettered straight away, anything else is
+/// retried with exponential backoff until `max_attempts` is reached. Delivery statuses that
+/// beat their outbound message are parked and re-applied, in `occurred_at` order, once the
+/// message has its provider id.
+#[derive(bon::Builder)]
+pub struct ProcessWebhookInboxUsecase {
+    processed_webhook_event_repository: Arc<dyn ProcessedWebhookEventRepository>,
+    processor: ProcessTelnyxMessagingWebhookUsecase,
+    #[builder(default = WEBHOOK_INBOX_BATCH_SIZE)]
+    batch_size: usize,
+    #[builder(default = MAX_WEBHOOK_INBOX_ATTEMPTS)]
+    max_attempts: i32,
+}
+
+impl ProcessWebhookInboxUsecase {
+    /// `decode` turns a stored payload back into a command; it lives with the caller because
+    /// the payload format belongs to the Telnyx client.
+    pub async fn execute<F>(
+        &self,
+        now: OffsetDateTime,
+        decode: F,
+    ) -> Result<ProcessWebhookInboxResult, UsecaseError>
+    where
+        F: Fn(&ProcessedWebhookEvent) -> Result<ProcessTelnyxWebhookCommand, String>,
+    {
+        let events = self
+            .processed_webhook_event_repository
+            .claim_pending(now, now + WEBHOOK_INBOX_LEASE, self.batch_size)
+            .await?;
+
+        let mut result = ProcessWebhookInboxResult::default();
+        for event in events {
+            let cmd = match decode(&event) {
+                Ok(cmd) => cmd,
+                Err(reason) => {
+                    self.dead_letter(&event, reason, &mut result).await?;
+                    continue;
+                }
+            };
+
+            match self.processor.execute(cmd).await {
+                Ok(processed) if processed.orphaned => {
+                    // Keep the original deadline when a parked event comes back unmatched.
+                    let deadline = if event.status == WebhookEventStatus::Parked {
+                        event.next_attempt_at
+                    } else {
+                        now + ORPHANED_EVENT_TTL
+                    };
+
+                    if deadline <= now {
+                        let reason = format!(
+                            "no message matches provider message id {}",
+                            event.provider_message_id.as_deref().unwrap_or_default()
+                        );
+                        self.dead_letter(&event, reason, &mut result).await?;
+                    } else {
+                        self.processed_webhook_event_repository
+                            .park(&event.event_id, deadline)
+                            .await?;
+                        result.parked.push(event.event_id);
+                    }
+                }
+                Ok(processed) => {
+                    self.processed_webhook_event_repository
+                        .mark_processed(&event.event_id)
+                        .await?;
+                    result.notifications.extend(processed.notification);
+                }
+                Err(err @ (UsecaseError::Validation(_) | UsecaseError::EntityNotFound)) => {
+                    self.dead_letter(&event, err.to_string(), &mut result)
+                        .await?;
+                }
+                Err(err) if event.attempts >= self.max_attempts => {
+                    self.dead_letter(&event, err.to_string(), &mut result)
+                        .await?;
+                }
+                Err(err) => {
+                    self.processed_webhook_event_repository
+                        .reschedule(
+                            &event.event_id,
+                            now + exponential_backoff(
+                                event.attempts,
+                                BASE_RETRY_DELAY,
+                                MAX_RETRY_DELAY,
+                            ),
+                            &err.to_string(),
+                        )
+                        .await?;
+                    result.failures.push((event.event_id, err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn dead_letter(
+        &self,
+        event: &ProcessedWebhookEvent,
+        reason: String,
+        result: &mut ProcessWebhookInboxResult,
+    ) -> Result<(), UsecaseError> {
+        self.processed_webhook_event_repository
+            .mark_dead_lettered(&event.event_id, &reason)
+            .await?;
+        result
+            .dead_lettered
+            .push((event.event_id.to_owned(), reason));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            auto_reply_rule::AutoReplyRule,
+            business_hours::BusinessHours,
+            contact::Contact,
+            conversation::{
+                AssigneeLoad, Conversation, ConversationFilter, ConversationRouting,
+                ConversationStateChange, ConversationView, UnreadCount,
+            },
+            message::Message,
+            message_attachment::MessageAttachment,
+            message_status_event::MessageStatusEvent,
+            opt_out::OptOut,
+            organization::{Organization, OrganizationMember, OrganizationRole},
+            outbound_message_job::OutboundMessageJob,
+            phone_number::PhoneNumber,
+            processed_webhook_event::{ProcessedWebhookEvent, WebhookEventStatus},
+            usage::{UsageLimit, UsagePeriod, UsageRecord, UsageRollup, UsageTotals},
+        },
+        repositories::{
+            RepositoryError,
+            auto_reply_rule_repository::AutoReplyRuleRepository,
+            business_hours_repository::BusinessHoursRepository,
+            contact_repository::ContactRepository,
+            conversation_repository::ConversationRepository,
+            message_repository::{MessagePage, MessageRepository},
+            message_status_event_repository::MessageStatusEventRepository,
+            opt_out_repository::OptOutRepository,
+            organization_repository::OrganizationRepository,
+            outbound_message_queue_repository::OutboundMessageQueueRepository,
+            phone_number_repository::PhoneNumberRepository,
+            processed_webhook_event_repository::ProcessedWebhookEventRepository,
+            usage_limit_repository::UsageLimitRepository,
+            usage_repository::UsageRepository,
+        },
+        traits::{
+            media_downloader::{DownloadedMedia, MediaDownloadError, MediaDownloader},
+            media_storage::{MediaStorage, MediaStorageError, StoredMedia},
+        },
+    };
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::ProcessWebhookInboxUsecase;
+    use crate::{
+        commands::{ProcessTelnyxWebhookCommand, TelnyxWebhookMessagePayload},
+        usecases::process_telnyx_messaging_webhook_usecase::ProcessTelnyxMessagingWebhookUsecase,
+    };
+
+    #[derive(Default)]
+    struct FakeProcessedWebhookEventRepository {
+        events: Mutex<HashMap<String, ProcessedWebhookEvent>>,
+    }
+
+    impl FakeProcessedWebhookEventRepository {
+        fn get(&self, event_id: &str) -> ProcessedWebhookEvent {
+            self.events
+                .lock()
+                .expect("lock")
+                .get(event_id)
+                .cloned()
+                .expect("event should exist")
+        }
+
+        fn update(
+            &self,
+            event_id: &str,
+            apply: impl FnOnce(&mut ProcessedWebhookEvent),
+        ) -> Result<(), RepositoryError> {
+            let mut events = self.events.lock().expect("lock");
+            let event = events.get_mut(event_id).ok_or(RepositoryError::NotFound)?;
+            apply(event);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ProcessedWebhookEventRepository for FakeProcessedWebhookEventRepository {
+        async fn create_processed_webhook_event(
+            &self,
+            event: &ProcessedWebhookEvent,
+        ) -> Result<(), RepositoryError> {
+            let mut events = self.events.lock().expect("lock");
+            if events.contains_key(&event.event_id) {
+                return Err(RepositoryError::ConstraintViolation(
+                    "processed_webhook_events_pkey".to_owned(),
+                ));
+            }
+            events.insert(event.event_id.clone(), event.clone());
+            Ok(())
+        }
+
+        async fn find_by_event_id(
+            &self,
+            event_id: &str,
+        ) -> Result<ProcessedWebhookEvent, RepositoryError> {
+            self.events
+                .lock()
+                .expect("lock")
+                .get(event_id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn claim_pending(
+            &self,
+            now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            limit: usize,
+        ) -> Result<Vec<ProcessedWebhookEvent>, RepositoryError> {
+            let mut events = self.events.lock().expect("lock");
+            Ok(events
+                .values_mut()
+                .filter(|event| {
+                    matches!(
+                        event.status,
+                        WebhookEventStatus::Pending | WebhookEventStatus::Parked
+                    ) && event.next_attempt_at <= now
+                })
+                .take(limit)
+                .map(|event| {
+                    event.attempts += 1;
+                    let claimed = event.clone();
+                    // Keep it out of the next claim, like the lease would.
+                    event.next_attempt_at = now + time::Duration::minutes(5);
+                    claimed
+                })
+                .collect())
+        }
+
+        async fn mark_processed(&self, event_id: &str) -> Result<(), RepositoryError> {
+            self.update(event_id, |event| {
+                event.status = WebhookEventStatus::Processed;
+                event.processed_at = Some(OffsetDateTime::now_utc());
+            })
+        }
+
+        async fn reschedule(
+            &self,
+            event_id: &str,
+            next_attempt_at: OffsetDateTime,
+            last_error: &str,
+        ) -> Result<(), RepositoryError> {
+            self.update(event_id, |event| {
+                event.next_attempt_at = next_attempt_at;
+                event.last_error = Some(last_error.to_owned());
+            })
+        }
+
+        async fn park(&self, event_id: &str, until: OffsetDateTime) -> Result<(), RepositoryError> {
+            self.update(event_id, |event| {
+                event.status = WebhookEventStatus::Parked;
+                event.next_attempt_at = until;
+            })
+        }
+
+        async fn mark_dead_lettered(
+            &self,
+            event_id: &str,
+            last_error: &str,
+        ) -> Result<(), RepositoryError> {
+            self.update(event_id, |event| {
+                event.status = WebhookEventStatus::DeadLettered;
+                event.last_error = Some(last_error.to_owned());
+            })
+        }
+
+        async fn list_by_status(
+            &self,
+            status: WebhookEventStatus,
+            limit: usize,
+        ) -> Result<Vec<ProcessedWebhookEvent>, RepositoryError> {
+            Ok(self
+                .events
+                .lock()
+                .expect("lock")
+                .values()
+                .filter(|event| event.status == status)
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn replay(&self, event_id: &str) -> Result<(), RepositoryError> {
+            let mut events = self.events.lock().expect("lock");
+            match events.get_mut(event_id) {
+                Some(event) if event.status == WebhookEventStatus::DeadLettered => {
+                    event.status = WebhookEventStatus::Pending;
+                    event.attempts = 0;
+                    event.next_attempt_at = OffsetDateTime::now_utc();
+                    Ok(())
+                }
+                _ => Err(RepositoryError::NotFound),
+            }
+        }
+    }
+
+    /// Only the provider id lookup is exercised: status events never find their message, and a
+    /// database outage surfaces as a transient error.
+    struct FakeMessageRepository {
+        unavailable: bool,
+    }
+
+    #[async_trait]
+    impl MessageRepository for FakeMessageRepository {
+        async fn create_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            Ok(message.clone())
+        }
+
+        async fn find_by_id(&self, _id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn count_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<u64, RepositoryError> {
+            Ok(0)
+        }
+
+        async fn find_by_provider_message_id(
+            &self,
+            _provider_message_id: &str,
+        ) -> Result<Message, RepositoryError> {
+            if self.unavailable {
+                return Err(RepositoryError::DatabaseError(
+                    "connection refused".to_owned(),
+                ));
+            }
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_reply_to(
+            &self,
+            _message_id: &uuid::Uuid,
+        ) -> Result<Message, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_page_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+            _cursor: Option<&uuid::Uuid>,
+            _limit: usize,
+        ) -> Result<MessagePage, RepositoryError> {
+            Ok(MessagePage {
+                messages: Vec::new(),
+                next_cursor: None,
+            })
+        }
+
+        async fn update_message(&self, message: &Message) -> Result<Message, RepositoryError> {
+            Ok(message.clone())
+        }
+
+        async fn list_scheduled_by_conversation_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _conversation_id: &uuid::Uuid,
+        ) -> Result<Vec<Message>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+            _scheduled_at: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn delete_scheduled_message(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn release_scheduled_message(
+            &self,
+            _id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn find_attachment_by_id(
+            &self,
+            _id: &uuid::Uuid,
+        ) -> Result<MessageAttachment, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+    }
+
+    struct FakeConversationRepository;
+
+    #[async_trait]
+    impl ConversationRepository for FakeConversationRepository {
+        async fn create_conversation(
+            &self,
+            _conversation: &Conversation,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Conversation, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_by_phone_number_and_recipient(
+            &self,
+            _user_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<Conversation, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_filtered(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _view: ConversationView,
+            _filter: ConversationFilter,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn change_state(
+            &self,
+            conversation: &Conversation,
+            _change: ConversationStateChange,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn record_activity(
+            &self,
+            conversation: &Conversation,
+            _last_message_at: OffsetDateTime,
+            _updated_at: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn reopen(&self, _conversation: &Conversation) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn assign(
+            &self,
+            conversation: &Conversation,
+            _assigned_user_id: Option<uuid::Uuid>,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn claim_assignment(
+            &self,
+            _conversation: &Conversation,
+            _assigned_user_id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+
+        async fn list_assignee_loads(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn mark_read(
+            &self,
+            _conversation: &Conversation,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_unread_counts(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UnreadCount>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn wake_snoozed(
+            &self,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_conversation(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_away_reply(
+            &self,
+            _conversation: &Conversation,
+            _closed_since: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+    }
+
+    struct FakePhoneNumberRepository;
+
+    #[async_trait]
+    impl PhoneNumberRepository for FakePhoneNumberRepository {
+        async fn create_phone_number(
+            &self,
+            _phone_number: &PhoneNumber,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<PhoneNumber, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn find_by_phone(&self, _phone: &str) -> Result<PhoneNumber, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<PhoneNumber>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_phone_number(
+            &self,
+            _user_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeMessageStatusEventRepository;
+
+    #[async_trait]
+    impl MessageStatusEventRepository for FakeMessageStatusEventRepository {
+        async fn record(&self, _event: &MessageStatusEvent) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_message_ids(
+            &self,
+            _user_id: &uuid::Uuid,
+            _message_ids: &[uuid::Uuid],
+        ) -> Result<Vec<MessageStatusEvent>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    struct FakeOptOutRepository;
+
+    #[async_trait]
+    impl OptOutRepository for FakeOptOutRepository {
+        async fn opt_out(&self, _opt_out: &OptOut) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn opt_in(
+            &self,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn is_opted_out(
+            &self,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct FakeOutboundMessageQueueRepository;
+
+    #[async_trait]
+    impl OutboundMessageQueueRepository for FakeOutboundMessageQueueRepository {
+        async fn enqueue(&self, _job: &OutboundMessageJob) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_due(
+            &self,
+            _now: OffsetDateTime,
+            _locked_until: OffsetDateTime,
+            _limit: usize,
+        ) -> Result<Vec<OutboundMessageJob>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn reschedule(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+            _last_error: &str,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn postpone(
+            &self,
+            _message_id: &uuid::Uuid,
+            _next_attempt_at: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn complete(&self, _message_id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeMediaStorage;
+
+    #[async_trait]
+    impl MediaStorage for FakeMediaStorage {
+        async fn put(&self, _key: &str, bytes: &[u8]) -> Result<StoredMedia, MediaStorageError> {
+            Ok(StoredMedia {
+                size_bytes: bytes.len() as i64,
+                checksum_sha256: format!("sha256-{}", bytes.len()),
+            })
+        }
+
+        async fn get(&self, _key: &str) -> Result<Vec<u8>, MediaStorageError> {
+            Err(MediaStorageError::NotFound)
+        }
+
+        async fn delete(&self, _key: &str) -> Result<(), MediaStorageError> {
+            Ok(())
+        }
+    }
+
+    struct FakeMediaDownloader;
+
+    #[async_trait]
+    impl MediaDownloader for FakeMediaDownloader {
+        async fn download(
+            &self,
+            url: &str,
+            _max_bytes: usize,
+        ) -> Result<DownloadedMedia, MediaDownloadError> {
+            Err(MediaDownloadError::Failed(format!("{} returned 404", url)))
+        }
+    }
+
+    struct FakeContactRepository;
+
+    #[async_trait]
+    impl ContactRepository for FakeContactRepository {
+        async fn create_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_contact(&self, _contact: &Contact) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<Contact, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_by_phone_numbers(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_numbers: &[String],
+        ) -> Result<Vec<Contact>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_contact(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeAutoReplyRuleRepository;
+
+    #[async_trait]
+    impl AutoReplyRuleRepository for FakeAutoReplyRuleRepository {
+        async fn create_rule(&self, _rule: &AutoReplyRule) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_rule(&self, _rule: &AutoReplyRule) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<AutoReplyRule, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_phone_number_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+        ) -> Result<Vec<AutoReplyRule>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_rule(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_reply(
+            &self,
+            _rule: &AutoReplyRule,
+            _recipient_phone_number: &str,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct FakeBusinessHoursRepository;
+
+    #[async_trait]
+    impl BusinessHoursRepository for FakeBusinessHoursRepository {
+        async fn create_business_hours(
+            &self,
+            _business_hours: &BusinessHours,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn update_business_hours(
+            &self,
+            _business_hours: &BusinessHours,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_phone_number_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+        ) -> Result<BusinessHours, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn delete_business_hours(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeUsageLimitRepository;
+
+    #[async_trait]
+    impl UsageLimitRepository for FakeUsageLimitRepository {
+        async fn save_limit(&self, _limit: &UsageLimit) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_limit(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_soft_limit_notification(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _period_start: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+    }
+
+    struct FakeUsageRepository;
+
+    #[async_trait]
+    impl UsageRepository for FakeUsageRepository {
+        async fn record_usage(&self, _record: &UsageRecord) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn record_cost(
+            &self,
+            _message_id: &uuid::Uuid,
+            _cost_micros: i64,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn totals_since(
+            &self,
+            _user_id: &uuid::Uuid,
+            _since: OffsetDateTime,
+        ) -> Result<UsageTotals, RepositoryError> {
+            Ok(UsageTotals::default())
+        }
+
+        async fn rollups(
+            &self,
+            _user_id: &uuid::Uuid,
+            _period: UsagePeriod,
+            _since: OffsetDateTime,
+        ) -> Result<Vec<UsageRollup>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    struct FakeOrganizationRepository;
+
+    #[async_trait]
+    impl OrganizationRepository for FakeOrganizationRepository {
+        async fn create_organization(
+            &self,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+            let now = OffsetDateTime::now_utc();
+            Ok(Organization::builder()
+                .id(*id)
+                .name("Support".to_owned())
+                .created_at(now)
+                .updated_at(now)
+                .build())
+        }
+
+        async fn update_conversation_routing(
+            &self,
+            _id: &uuid::Uuid,
+            _conversation_routing: ConversationRouting,
+            _now: OffsetDateTime,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_by_user_id(
+            &self,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<Organization>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<OrganizationMember, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_members(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_member_role(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _role: OrganizationRole,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn remove_member(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn build_usecase(
+        events: Arc<FakeProcessedWebhookEventRepository>,
+        database_unavailable: bool,
+        max_attempts: i32,
+    ) -> ProcessWebhookInboxUsecase {
+        let processor = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(Arc::new(FakeConversationRepository))
+            .message_repository(Arc::new(FakeMessageRepository {
+                unavailable: database_unavailable,
+            }))
+            .message_status_event_repository(Arc::new(FakeMessageStatusEventRepository))
+            .phone_number_repository(Arc::new(FakePhoneNumberRepository))
+            .opt_out_repository(Arc::new(FakeOptOutRepository))
+            .outbound_message_queue_repository(Arc::new(FakeOutboundMessageQueueRepository))
+            .contact_repository(Arc::new(FakeContactRepository))
+            .auto_reply_rule_repository(Arc::new(FakeAutoReplyRuleRepository))
+            .business_hours_repository(Arc::new(FakeBusinessHoursRepository))
+            .organization_repository(Arc::new(FakeOrganizationRepository))
+            .usage_repository(Arc::new(FakeUsageRepository))
+            .usage_limit_repository(Arc::new(FakeUsageLimitRepository))
+            .media_storage(Arc::new(FakeMediaStorage))
+            .media_downloader(Arc::new(FakeMediaDownloader))
+            .build();
+
+        ProcessWebhookInboxUsecase::builder()
+            .processed_webhook_event_repository(events)
+            .processor(processor)
+            .max_attempts(max_attempts)
+            .build()
+    }
+
+    async fn ingest(
+        events: &FakeProcessedWebhookEventRepository,
+        event_id: &str,
+        event_type: &str,
+    ) {
+        let now = OffsetDateTime::now_utc();
+        events
+            .create_processed_webhook_event(
+                &ProcessedWebhookEvent::builder()
+                    .event_id(event_id.to_owned())
+                    .event_type(event_type.to_owned())
+                    .maybe_provider_message_id(Some("provider-1".to_owned()))
+                    .occurred_at(now)
+                    .payload_json(json!({ "data": { "id": event_id } }))
+                    .status(WebhookEventStatus::Pending)
+                    .next_attempt_at(now)
+                    .created_at(now)
+                    .updated_at(now)
+                    .build(),
+            )
+            .await
+            .expect("event should be stored");
+    }
+
+    fn decode(event: &ProcessedWebhookEvent) -> Result<ProcessTelnyxWebhookCommand, String> {
+        if event.event_id == "undecodable" {
+            return Err("missing field `payload`".to_owned());
+        }
+
+        Ok(ProcessTelnyxWebhookCommand {
+            event_id: event.event_id.clone(),
+            event_type: event.event_type.clone(),
+            occurred_at: event.occurred_at,
+            payload: TelnyxWebhookMessagePayload {
+                provider_message_id: "provider-1".to_owned(),
+                from_phone_number: None,
+                from_carrier: None,
+                from_line_type: None,
+                to: Vec::new(),
+                text: None,
+                received_at: None,
+                sent_at: None,
+                completed_at: None,
+                errors: Vec::new(),
+                media: Vec::new(),
+                parts: None,
+                encoding: None,
+                cost: None,
+                messaging_profile_id: None,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn marks_successfully_processed_events() {
+        let events = Arc::new(FakeProcessedWebhookEventRepository::default());
+        ingest(&events, "event-1", "message.unknown").await;
+        let usecase = build_usecase(events.clone(), false, 10);
+
+        let result = usecase
+            .execute(OffsetDateTime::now_utc(), decode)
+            .await
+            .expect("inbox should be processed");
+
+        assert!(result.failures.is_empty());
+        assert!(result.dead_lettered.is_empty());
+        let event = events.get("event-1");
+        assert_eq!(event.status, WebhookEventStatus::Processed);
+        assert_eq!(event.attempts, 1);
+        assert!(event.processed_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn dead_letters_events_that_cannot_be_decoded() {
+        let events = Arc::new(FakeProcessedWebhookEventRepository::default());
+        ingest(&events, "undecodable", "message.sent").await;
+        let usecase = build_usecase(events.clone(), false, 10);
+
+        let result = usecase
+            .execute(OffsetDateTime::now_utc(), decode)
+            .await
+            .expect("inbox should be processed");
+
+        assert_eq!(result.dead_lettered.len(), 1);
+        let event = events.get("undecodable");
+        assert_eq!(event.status, WebhookEventStatus::DeadLettered);
+        assert_eq!(event.last_error.as_deref(), Some("missing field `payload`"));
+    }
+
+    #[tokio::test]
+    async fn reschedules_transient_failures_with_backoff() {
+        let events = Arc::new(FakeProcessedWebhookEventRepository::default());
+        ingest(&events, "event-1", "message.sent").await;
+        let usecase = build_usecase(events.clone(), true, 10);
+        let now = OffsetDateTime::now_utc();
+
+        let result = usecase
+            .execute(now, decode)
+            .await
+            .expect("inbox should be processed");
+
+        assert_eq!(result.failures.len(), 1);
+        let event = events.get("event-1");
+        assert_eq!(event.status, WebhookEventStatus::Pending);
+        assert_eq!(event.next_attempt_at, now + time::Duration::seconds(5));
+        assert!(event.last_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn dead_letters_after_max_attempts() {
+        let events = Arc::new(FakeProcessedWebhookEventRepository::default());
+        ingest(&events, "event-1", "message.sent").await;
+        let usecase = build_usecase(events.clone(), true, 1);
+
+        let result = usecase
+            .execute(OffsetDateTime::now_utc(), decode)
+            .await
+            .expect("inbox should be processed");
+
+        assert_eq!(result.dead_lettered.len(), 1);
+        assert_eq!(
+            events.get("event-1").status,
+            WebhookEventStatus::DeadLettered
+        );
+    }
+
+    #[tokio::test]
+    async fn parks_status_events_until_their_message_exists() {
+        let events = Arc::new(FakeProcessedWebhookEventRepository::default());
+        ingest(&events, "event-1", "message.finalized").await;
+        let usecase = build_usecase(events.clone(), false, 10);
+        let now = OffsetDateTime::now_utc();
+
+        let result = usecase
+            .execute(now, decode)
+            .await
+            .expect("inbox should be processed");
+
+        assert_eq!(result.parked, vec!["event-1".to_owned()]);
+        assert!(result.dead_lettered.is_empty());
+        let event = events.get("event-1");
+        assert_eq!(event.status, WebhookEventStatus::Parked);
+        assert_eq!(event.next_attempt_at, now + time::Duration::hours(24));
+    }
+
+    #[tokio::test]
+    async fn dead_letters_parked_events_that_never_find_their_message() {
+        let events = Arc::new(FakeProcessedWebhookEventRepository::default());
+        ingest(&events, "event-1", "message.sent").await;
+        let now = OffsetDateTime::now_utc();
+        events
+            .park("event-1", now - time::Duration::seconds(1))
+            .await
+            .expect("event should park");
+        let usecase = build_usecase(events.clone(), false, 10);
+
+        let result = usecase
+            .execute(now, decode)
+            .await
+            .expect("inbox should be processed");
+
+        assert!(result.parked.is_empty());
+        assert_eq!(result.dead_lettered.len(), 1);
+        let event = events.get("event-1");
+        assert_eq!(event.status, WebhookEventStatus::DeadLettered);
+        assert_eq!(
+            event.last_error.as_deref(),
+            Some("no message matches provider message id provider-1")
+        );
+    }
+}
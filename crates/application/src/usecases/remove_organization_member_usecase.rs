@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::organization::{OrganizationRole, Permission},
+    repositories::organization_repository::OrganizationRepository,
+};
+
+/// Removes a member, or lets a member leave. Removing someone else takes an admin, and
+/// removing an owner takes an owner. The last owner cannot leave, since nobody would be
+/// left to manage the organization's numbers and members.
+#[derive(bon::Builder)]
+pub struct RemoveOrganizationMemberUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl RemoveOrganizationMemberUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        member_user_id: uuid::Uuid,
+    ) -> Result<(), UsecaseError> {
+        let members = self
+            .organization_repository
+            .list_members(&organization_id)
+            .await?;
+        let Some(member) = members
+            .iter()
+            .find(|member| member.user_id == member_user_id)
+        else {
+            return Err(UsecaseError::EntityNotFound);
+        };
+
+        if member_user_id != user_id {
+            let permission = if member.role == OrganizationRole::Owner {
+                Permission::ManageOwners
+            } else {
+                Permission::ManageMembers
+            };
+            authorize_member(
+                &self.organization_repository,
+                &organization_id,
+                &user_id,
+                permission,
+            )
+            .await?;
+        }
+
+        let owners = members
+            .iter()
+            .filter(|member| member.role == OrganizationRole::Owner)
+            .count();
+        if member.role == OrganizationRole::Owner && owners == 1 {
+            return Err(garde::Error::new("The last owner cannot leave the organization").into());
+        }
+
+        self.organization_repository
+            .remove_member(&organization_id, &member_user_id)
+            .await?;
+
+        Ok(())
+    }
+}
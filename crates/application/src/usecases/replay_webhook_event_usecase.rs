@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::usecases::UsecaseError;
+use domain::repositories::processed_webhook_event_repository::ProcessedWebhookEventRepository;
+
+/// Puts a dead-lettered webhook back into the inbox. Fails with `EntityNotFound` when the
+/// event does not exist or is not dead-lettered.
+#[derive(bon::Builder)]
+pub struct ReplayWebhookEventUsecase {
+    processed_webhook_event_repository: Arc<dyn ProcessedWebhookEventRepository>,
+}
+
+impl ReplayWebhookEventUsecase {
+    pub async fn execute(&self, event_id: &str) -> Result<(), UsecaseError> {
+        self.processed_webhook_event_repository
+            .replay(event_id)
+            .await?;
+
+        Ok(())
+    }
+}
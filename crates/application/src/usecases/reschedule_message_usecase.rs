@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::RescheduleMessageCommand,
+    usecases::{UsecaseError, authorize_member, create_message_usecase::validate_scheduled_at},
+};
+use domain::{
+    models::{
+        message::{Message, MessageStatus},
+        organization::Permission,
+    },
+    repositories::{
+        message_repository::MessageRepository, organization_repository::OrganizationRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+    },
+};
+
+/// Moves a scheduled message to a new send time, together with its delivery job.
+#[derive(bon::Builder)]
+pub struct RescheduleMessageUsecase {
+    message_repository: Arc<dyn MessageRepository>,
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl RescheduleMessageUsecase {
+    pub async fn execute(&self, cmd: RescheduleMessageCommand) -> Result<Message, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::SendMessages,
+        )
+        .await?;
+
+        let now = OffsetDateTime::now_utc();
+        let scheduled_at = validate_scheduled_at(cmd.scheduled_at, now)?;
+
+        let mut message = find_scheduled_message(
+            self.message_repository.as_ref(),
+            cmd.organization_id,
+            cmd.conversation_id,
+            cmd.message_id,
+        )
+        .await?;
+
+        if !self
+            .message_repository
+            .reschedule_message(&cmd.organization_id, &message.id, scheduled_at, now)
+            .await?
+        {
+            return Err(UsecaseError::MessageNotScheduled);
+        }
+
+        self.outbound_message_queue_repository
+            .postpone(&message.id, scheduled_at)
+            .await?;
+
+        message.scheduled_at = Some(scheduled_at);
+        message.updated_at = now;
+
+        Ok(message)
+    }
+}
+
+/// Loads a message of the given conversation and checks that it has not been released yet.
+pub(crate) async fn find_scheduled_message(
+    message_repository: &dyn MessageRepository,
+    organization_id: uuid::Uuid,
+    conversation_id: uuid::Uuid,
+    message_id: uuid::Uuid,
+) -> Result<Message, UsecaseError> {
+    let message = message_repository.find_by_id(&message_id).await?;
+    if message.organization_id != organization_id || message.conversation_id != conversation_id {
+        return Err(UsecaseError::EntityNotFound);
+    }
+
+    if message.status != MessageStatus::Scheduled {
+        return Err(UsecaseError::MessageNotScheduled);
+    }
+
+    Ok(message)
+}
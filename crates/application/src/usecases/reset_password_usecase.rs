@@ -0,0 +1,407 @@
+use std::sync::Arc;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{
+    commands::ResetPasswordCommand,
+    usecases::{UsecaseError, forgot_password_usecase::PASSWORD_RESET_TOKEN_TTL},
+};
+use domain::{
+    repositories::{
+        RepositoryError, reset_password_repository::ResetPasswordRepository,
+        user_repository::UserRepository,
+    },
+    traits::{
+        password_hasher::PasswordHasher,
+        token_service::{PasetoClaimPurpose, TokenService},
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct ResetPasswordUsecase {
+    user_repository: Arc<dyn UserRepository>,
+    reset_password_repository: Arc<dyn ResetPasswordRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    token_service: Arc<dyn TokenService>,
+}
+
+impl ResetPasswordUsecase {
+    pub async fn execute(&self, cmd: ResetPasswordCommand) -> Result<(), UsecaseError> {
+        cmd.validate()?;
+        cmd.validate_passwords_match()?;
+
+        let claims = self
+            .token_service
+            .validate_token(cmd.token.clone(), PasetoClaimPurpose::PasswordReset)
+            .map_err(|_| UsecaseError::InvalidResetToken)?;
+
+        let reset_password = match self
+            .reset_password_repository
+            .find_by_token(&cmd.token)
+            .await
+        {
+            Ok(reset_password) => reset_password,
+            Err(RepositoryError::NotFound) => return Err(UsecaseError::InvalidResetToken),
+            Err(err) => return Err(err.into()),
+        };
+
+        let expires_at = reset_password.created_at + PASSWORD_RESET_TOKEN_TTL;
+        if reset_password.consumed
+            || reset_password.user_id != claims.id
+            || expires_at <= OffsetDateTime::now_utc()
+        {
+            return Err(UsecaseError::InvalidResetToken);
+        }
+
+        match self
+            .reset_password_repository
+            .consume(&reset_password.id)
+            .await
+        {
+            Ok(()) => {}
+            Err(RepositoryError::NotFound) => return Err(UsecaseError::InvalidResetToken),
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut user = match self
+            .user_repository
+            .find_by_id(&reset_password.user_id)
+            .await
+        {
+            Ok(user) => user,
+            Err(RepositoryError::NotFound) => return Err(UsecaseError::InvalidResetToken),
+            Err(err) => return Err(err.into()),
+        };
+
+        let hashed_password = self.password_hasher.hash(&cmd.password).await?;
+        user.hash = hashed_password.hash;
+        user.salt = hashed_password.salt;
+        user.updated_at = OffsetDateTime::now_utc();
+        self.user_repository.update_user(&user).await?;
+
+        // Any other outstanding links for this account stop working once the password changes.
+        self.reset_password_repository
+            .consume_all_by_user_id(&user.id)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use domain::{
+        models::{
+            organization::{Organization, OrganizationMember},
+            reset_password::ResetPassword,
+            user::User,
+        },
+        repositories::{
+            RepositoryError, reset_password_repository::ResetPasswordRepository,
+            user_repository::UserRepository,
+        },
+        traits::{
+            password_hasher::{HashError, HashedPassword, PasswordHasher},
+            token_service::{PasetoClaimPurpose, PasetoClaims, TokenService, TokenServiceError},
+        },
+    };
+    use time::{OffsetDateTime, UtcDateTime};
+
+    use crate::{
+        commands::ResetPasswordCommand,
+        usecases::{UsecaseError, forgot_password_usecase::PASSWORD_RESET_TOKEN_TTL},
+    };
+
+    use super::ResetPasswordUsecase;
+
+    const TOKEN: &str = "reset-token-reset-token-reset-token";
+    const OTHER_TOKEN: &str = "other-token-other-token-other-token";
+
+    struct FakeUserRepository {
+        user_id: uuid::Uuid,
+        /// Current `(hash, salt)` of the account.
+        password: Mutex<(String, String)>,
+    }
+
+    #[async_trait]
+    impl UserRepository for FakeUserRepository {
+        async fn create_user(&self, _user: &User) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn create_user_with_organization(
+            &self,
+            _user: &User,
+            _organization: &Organization,
+            _owner: &OrganizationMember,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &uuid::Uuid) -> Result<User, RepositoryError> {
+            if *id != self.user_id {
+                return Err(RepositoryError::NotFound);
+            }
+
+            let (hash, salt) = self.password.lock().expect("lock").clone();
+            let now = OffsetDateTime::now_utc();
+            Ok(User::builder()
+                .id(self.user_id)
+                .email("ada@example.com".to_owned())
+                .hash(hash)
+                .salt(salt)
+                .email_verified(true)
+                .created_at(now)
+                .updated_at(now)
+                .build())
+        }
+
+        async fn find_by_email(&self, _email: &str) -> Result<User, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list(&self, _page: u64, _limit: u64) -> Result<Vec<User>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn update_user(&self, new_data: &User) -> Result<(), RepositoryError> {
+            *self.password.lock().expect("lock") = (new_data.hash.clone(), new_data.salt.clone());
+            Ok(())
+        }
+
+        async fn delete_user(&self, _id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    struct FakeResetPasswordRepository {
+        reset_passwords: Mutex<Vec<ResetPassword>>,
+    }
+
+    #[async_trait]
+    impl ResetPasswordRepository for FakeResetPasswordRepository {
+        async fn create_reset_password(
+            &self,
+            _reset_password: &ResetPassword,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_token(&self, token: &str) -> Result<ResetPassword, RepositoryError> {
+            self.reset_passwords
+                .lock()
+                .expect("lock")
+                .iter()
+                .find(|reset_password| reset_password.token == token)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        }
+
+        async fn consume(&self, id: &uuid::Uuid) -> Result<(), RepositoryError> {
+            let mut reset_passwords = self.reset_passwords.lock().expect("lock");
+            let reset_password = reset_passwords
+                .iter_mut()
+                .find(|reset_password| reset_password.id == *id && !reset_password.consumed)
+                .ok_or(RepositoryError::NotFound)?;
+            reset_password.consumed = true;
+            Ok(())
+        }
+
+        async fn consume_all_by_user_id(
+            &self,
+            user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            for reset_password in self.reset_passwords.lock().expect("lock").iter_mut() {
+                if reset_password.user_id == *user_id {
+                    reset_password.consumed = true;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Accepts any token and reports it as issued to `user_id`.
+    struct FakeTokenService {
+        user_id: uuid::Uuid,
+    }
+
+    impl TokenService for FakeTokenService {
+        fn generate_token(
+            &self,
+            _claims: PasetoClaims,
+            _expiration: Duration,
+        ) -> Result<(String, UtcDateTime), TokenServiceError> {
+            Err(TokenServiceError::TokenGenerationFailed)
+        }
+
+        fn validate_token(
+            &self,
+            _token: String,
+            purpose: PasetoClaimPurpose,
+        ) -> Result<PasetoClaims, TokenServiceError> {
+            if purpose != PasetoClaimPurpose::PasswordReset {
+                return Err(TokenServiceError::TokenInvalid);
+            }
+            Ok(PasetoClaims::new(
+                self.user_id,
+                "ada@example.com".to_owned(),
+                PASSWORD_RESET_TOKEN_TTL,
+                purpose,
+            ))
+        }
+    }
+
+    struct FakePasswordHasher;
+
+    #[async_trait]
+    impl PasswordHasher for FakePasswordHasher {
+        async fn hash(&self, plain_password: &str) -> Result<HashedPassword, HashError> {
+            Ok(HashedPassword::new(
+                format!("hashed:{}", plain_password),
+                "salt".to_owned(),
+            ))
+        }
+
+        async fn verify(
+            &self,
+            _plain_password: &str,
+            _hashed_password: &str,
+        ) -> Result<(), HashError> {
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        users: Arc<FakeUserRepository>,
+        reset_passwords: Arc<FakeResetPasswordRepository>,
+        usecase: ResetPasswordUsecase,
+    }
+
+    fn reset_password(
+        user_id: uuid::Uuid,
+        token: &str,
+        created_at: OffsetDateTime,
+    ) -> ResetPassword {
+        ResetPassword::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(user_id)
+            .token(token.to_owned())
+            .consumed(false)
+            .maybe_consumed_at(None)
+            .created_at(created_at)
+            .updated_at(created_at)
+            .build()
+    }
+
+    /// `claims_user_id` is the account the token service reports the token as issued to.
+    fn fixture(reset_passwords: Vec<ResetPassword>, claims_user_id: uuid::Uuid) -> Fixture {
+        let users = Arc::new(FakeUserRepository {
+            user_id: reset_passwords[0].user_id,
+            password: Mutex::new(("old-hash".to_owned(), "old-salt".to_owned())),
+        });
+        let reset_passwords = Arc::new(FakeResetPasswordRepository {
+            reset_passwords: Mutex::new(reset_passwords),
+        });
+
+        Fixture {
+            users: users.clone(),
+            reset_passwords: reset_passwords.clone(),
+            usecase: ResetPasswordUsecase::builder()
+                .user_repository(users)
+                .reset_password_repository(reset_passwords)
+                .password_hasher(Arc::new(FakePasswordHasher))
+                .token_service(Arc::new(FakeTokenService {
+                    user_id: claims_user_id,
+                }))
+                .build(),
+        }
+    }
+
+    fn command(token: &str) -> ResetPasswordCommand {
+        ResetPasswordCommand {
+            token: token.to_owned(),
+            password: "correct horse battery".to_owned(),
+            password_confirmation: "correct horse battery".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn resets_the_password_once_and_revokes_other_links() {
+        let user_id = uuid::Uuid::now_v7();
+        let now = OffsetDateTime::now_utc();
+        let fixture = fixture(
+            vec![
+                reset_password(user_id, TOKEN, now),
+                reset_password(user_id, OTHER_TOKEN, now),
+            ],
+            user_id,
+        );
+
+        fixture
+            .usecase
+            .execute(command(TOKEN))
+            .await
+            .expect("password should be reset");
+
+        let (hash, salt) = fixture.users.password.lock().expect("lock").clone();
+        assert_eq!(hash, "hashed:correct horse battery");
+        assert_eq!(salt, "salt");
+        assert!(
+            fixture
+                .reset_passwords
+                .reset_passwords
+                .lock()
+                .expect("lock")
+                .iter()
+                .all(|reset_password| reset_password.consumed)
+        );
+
+        for token in [TOKEN, OTHER_TOKEN] {
+            let again = fixture.usecase.execute(command(token)).await;
+            assert!(matches!(again, Err(UsecaseError::InvalidResetToken)));
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_expired_tokens() {
+        let user_id = uuid::Uuid::now_v7();
+        let issued_at =
+            OffsetDateTime::now_utc() - PASSWORD_RESET_TOKEN_TTL - time::Duration::minutes(1);
+        let fixture = fixture(vec![reset_password(user_id, TOKEN, issued_at)], user_id);
+
+        let result = fixture.usecase.execute(command(TOKEN)).await;
+
+        assert!(matches!(result, Err(UsecaseError::InvalidResetToken)));
+        assert_eq!(fixture.users.password.lock().expect("lock").0, "old-hash");
+    }
+
+    #[tokio::test]
+    async fn rejects_tokens_issued_to_another_user() {
+        let user_id = uuid::Uuid::now_v7();
+        let fixture = fixture(
+            vec![reset_password(user_id, TOKEN, OffsetDateTime::now_utc())],
+            uuid::Uuid::now_v7(),
+        );
+
+        let result = fixture.usecase.execute(command(TOKEN)).await;
+
+        assert!(matches!(result, Err(UsecaseError::InvalidResetToken)));
+        assert_eq!(fixture.users.password.lock().expect("lock").0, "old-hash");
+        assert!(
+            !fixture
+                .reset_passwords
+                .reset_passwords
+                .lock()
+                .expect("lock")[0]
+                .consumed,
+            "a mismatched token must not burn the real owner's link"
+        );
+    }
+}
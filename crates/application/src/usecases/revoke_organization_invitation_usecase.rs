@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::organization::Permission,
+    repositories::{
+        organization_invitation_repository::OrganizationInvitationRepository,
+        organization_repository::OrganizationRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct RevokeOrganizationInvitationUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+    organization_invitation_repository: Arc<dyn OrganizationInvitationRepository>,
+}
+
+impl RevokeOrganizationInvitationUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        invitation_id: uuid::Uuid,
+    ) -> Result<(), UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::ManageMembers,
+        )
+        .await?;
+
+        self.organization_invitation_repository
+            .delete_invitation(&organization_id, &invitation_id)
+            .await?;
+
+        Ok(())
+    }
+}
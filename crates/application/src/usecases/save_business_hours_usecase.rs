@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use garde::Validate;
+use time::OffsetDateTime;
+
+use crate::{
+    commands::{BusinessHoursDetails, SaveBusinessHoursCommand},
+    usecases::{UsecaseError, authorize_member},
+};
+use domain::{
+    models::{
+        business_hours::{BusinessHours, find_timezone},
+        message_template::template_variables,
+        organization::Permission,
+    },
+    repositories::{
+        RepositoryError, business_hours_repository::BusinessHoursRepository,
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+/// Creates the business hours of a phone number, or replaces the existing ones.
+#[derive(bon::Builder)]
+pub struct SaveBusinessHoursUsecase {
+    business_hours_repository: Arc<dyn BusinessHoursRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl SaveBusinessHoursUsecase {
+    pub async fn execute(
+        &self,
+        cmd: SaveBusinessHoursCommand,
+    ) -> Result<BusinessHours, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::ManagePhoneNumbers,
+        )
+        .await?;
+
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&cmd.organization_id, &cmd.phone_number_id)
+            .await?;
+        let existing = match self
+            .business_hours_repository
+            .find_by_phone_number_id(&phone_number.organization_id, &phone_number.id)
+            .await
+        {
+            Ok(existing) => Some(existing),
+            Err(RepositoryError::NotFound) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        let now = OffsetDateTime::now_utc();
+        let business_hours = build_business_hours(
+            cmd.details,
+            phone_number.organization_id,
+            existing
+                .as_ref()
+                .map_or(cmd.user_id, |existing| existing.user_id),
+            phone_number.id,
+            existing
+                .as_ref()
+                .map_or(now, |existing| existing.created_at),
+            now,
+        )?;
+
+        if existing.is_some() {
+            self.business_hours_repository
+                .update_business_hours(&business_hours)
+                .await?;
+        } else {
+            self.business_hours_repository
+                .create_business_hours(&business_hours)
+                .await?;
+        }
+
+        Ok(business_hours)
+    }
+}
+
+/// Validates the submitted details. The timezone must be known and away message
+/// placeholders need a name; openings and holidays are sorted and deduplicated.
+fn build_business_hours(
+    details: BusinessHoursDetails,
+    organization_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+    phone_number_id: uuid::Uuid,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+) -> Result<BusinessHours, UsecaseError> {
+    let mut details = BusinessHoursDetails {
+        timezone: details.timezone.trim().to_owned(),
+        away_message: details.away_message.trim().to_owned(),
+        ..details
+    };
+    details.validate()?;
+
+    if find_timezone(&details.timezone).is_none() {
+        return Err(garde::Error::new(format!(
+            "Unknown timezone {}, use a name like America/New_York",
+            details.timezone
+        ))
+        .into());
+    }
+    if template_variables(&details.away_message)
+        .iter()
+        .any(|name| name.is_empty())
+    {
+        return Err(garde::Error::new("Placeholders need a name, e.g. {{first_name}}").into());
+    }
+
+    details.opening_hours.sort_by_key(|opening| {
+        (
+            opening.weekday.number_days_from_monday(),
+            opening.opens_at,
+            opening.closes_at,
+        )
+    });
+    details.opening_hours.dedup();
+    details.holidays.sort();
+    details.holidays.dedup();
+
+    Ok(BusinessHours::builder()
+        .phone_number_id(phone_number_id)
+        .organization_id(organization_id)
+        .user_id(user_id)
+        .timezone(details.timezone)
+        .opening_hours(details.opening_hours)
+        .holidays(details.holidays)
+        .away_message(details.away_message)
+        .enabled(details.enabled)
+        .created_at(created_at)
+        .updated_at(updated_at)
+        .build())
+}
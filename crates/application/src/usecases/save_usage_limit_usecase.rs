@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{commands::SaveUsageLimitCommand, usecases::UsecaseError};
+use domain::{
+    models::usage::UsageLimit,
+    repositories::{
+        RepositoryError, usage_limit_repository::UsageLimitRepository,
+        user_repository::UserRepository,
+    },
+};
+
+/// Sets a user's spending limits for a period. Clearing both limits removes the period's
+/// limit altogether. Saving re-arms the soft limit notification.
+#[derive(bon::Builder)]
+pub struct SaveUsageLimitUsecase {
+    user_repository: Arc<dyn UserRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+}
+
+impl SaveUsageLimitUsecase {
+    pub async fn execute(
+        &self,
+        cmd: SaveUsageLimitCommand,
+    ) -> Result<Option<UsageLimit>, UsecaseError> {
+        if [cmd.soft_limit_micros, cmd.hard_limit_micros]
+            .into_iter()
+            .flatten()
+            .any(|amount| amount < 0)
+        {
+            return Err(garde::Error::new("Limits cannot be negative").into());
+        }
+        if let (Some(soft), Some(hard)) = (cmd.soft_limit_micros, cmd.hard_limit_micros)
+            && soft > hard
+        {
+            return Err(garde::Error::new("The soft limit cannot be above the hard limit").into());
+        }
+
+        let user = self.user_repository.find_by_id(&cmd.user_id).await?;
+
+        if cmd.soft_limit_micros.is_none() && cmd.hard_limit_micros.is_none() {
+            match self
+                .usage_limit_repository
+                .delete_limit(&user.id, cmd.period)
+                .await
+            {
+                Ok(()) | Err(RepositoryError::NotFound) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let created_at = self
+            .usage_limit_repository
+            .list_by_user_id(&user.id)
+            .await?
+            .into_iter()
+            .find(|limit| limit.period == cmd.period)
+            .map_or(now, |existing| existing.created_at);
+        let limit = UsageLimit::builder()
+            .user_id(user.id)
+            .period(cmd.period)
+            .maybe_soft_limit_micros(cmd.soft_limit_micros)
+            .maybe_hard_limit_micros(cmd.hard_limit_micros)
+            .created_at(created_at)
+            .updated_at(now)
+            .build();
+        self.usage_limit_repository.save_limit(&limit).await?;
+
+        Ok(Some(limit))
+    }
+}
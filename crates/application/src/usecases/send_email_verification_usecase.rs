@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    repositories::user_repository::UserRepository,
+    traits::{
+        email_sender::{EmailMessage, EmailSender},
+        token_service::{PasetoClaimPurpose, PasetoClaims, TokenService},
+    },
+};
+
+/// How long an email verification link stays valid after it is sent.
+pub const EMAIL_VERIFICATION_TOKEN_TTL: Duration = Duration::from_secs(60 * 60 * 24);
+
+#[derive(bon::Builder)]
+pub struct SendEmailVerificationUsecase {
+    user_repository: Arc<dyn UserRepository>,
+    token_service: Arc<dyn TokenService>,
+    email_sender: Arc<dyn EmailSender>,
+    /// Absolute base URL used to build the link in the email, e.g. `https://app.example.com`.
+    public_base_url: String,
+}
+
+impl SendEmailVerificationUsecase {
+    /// Emails a verification link to the user. Already verified accounts are left alone.
+    pub async fn execute(&self, user_id: uuid::Uuid) -> Result<(), UsecaseError> {
+        let user = self.user_repository.find_by_id(&user_id).await?;
+        if user.email_verified {
+            return Ok(());
+        }
+
+        // The email is part of the claims so a link stops working if the address changes.
+        let claims = PasetoClaims::new(
+            user.id,
+            user.email.clone(),
+            EMAIL_VERIFICATION_TOKEN_TTL,
+            PasetoClaimPurpose::EmailVerification,
+        );
+        let (token, _) = self
+            .token_service
+            .generate_token(claims, EMAIL_VERIFICATION_TOKEN_TTL)
+            .map_err(|_| UsecaseError::TokenGenerationFailed)?;
+
+        let verify_url = format!(
+            "{}/auth/verify-email?token={}",
+            self.public_base_url.trim_end_matches('/'),
+            token
+        );
+        let email = EmailMessage {
+            to: user.email,
+            subject: "Verify your email address".to_owned(),
+            text_body: format!(
+                "Confirm this email address for your account by opening the link below. \
+                 It expires in {} hours.\n\n{}\n\n\
+                 If you did not create an account, you can ignore this email.\n",
+                EMAIL_VERIFICATION_TOKEN_TTL.as_secs() / 3600,
+                verify_url
+            ),
+        };
+        self.email_sender.send(&email).await?;
+
+        Ok(())
+    }
+}
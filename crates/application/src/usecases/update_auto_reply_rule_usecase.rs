@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::UpdateAutoReplyRuleCommand,
+    usecases::{
+        UsecaseError, authorize_member, create_auto_reply_rule_usecase::build_auto_reply_rule,
+    },
+};
+use domain::{
+    models::{auto_reply_rule::AutoReplyRule, organization::Permission},
+    repositories::{
+        auto_reply_rule_repository::AutoReplyRuleRepository,
+        organization_repository::OrganizationRepository,
+        phone_number_repository::PhoneNumberRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct UpdateAutoReplyRuleUsecase {
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl UpdateAutoReplyRuleUsecase {
+    pub async fn execute(
+        &self,
+        cmd: UpdateAutoReplyRuleCommand,
+    ) -> Result<AutoReplyRule, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::ManagePhoneNumbers,
+        )
+        .await?;
+
+        let phone_number = self
+            .phone_number_repository
+            .find_by_id(&cmd.organization_id, &cmd.phone_number_id)
+            .await?;
+        let existing = self
+            .auto_reply_rule_repository
+            .find_by_id(&phone_number.organization_id, &cmd.rule_id)
+            .await?;
+        if existing.phone_number_id != phone_number.id {
+            return Err(UsecaseError::EntityNotFound);
+        }
+
+        let rule = build_auto_reply_rule(
+            cmd.details,
+            existing.id,
+            existing.organization_id,
+            existing.user_id,
+            existing.phone_number_id,
+            existing.created_at,
+            OffsetDateTime::now_utc(),
+        )?;
+
+        self.auto_reply_rule_repository.update_rule(&rule).await?;
+
+        Ok(rule)
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::UpdateContactCommand,
+    usecases::{UsecaseError, authorize_member, create_contact_usecase::build_contact},
+};
+use domain::{
+    models::{contact::Contact, organization::Permission},
+    repositories::{
+        contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+    },
+};
+
+#[derive(bon::Builder)]
+pub struct UpdateContactUsecase {
+    contact_repository: Arc<dyn ContactRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl UpdateContactUsecase {
+    pub async fn execute(&self, cmd: UpdateContactCommand) -> Result<Contact, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &cmd.organization_id,
+            &cmd.user_id,
+            Permission::ManageContacts,
+        )
+        .await?;
+
+        let existing = self
+            .contact_repository
+            .find_by_id(&cmd.organization_id, &cmd.contact_id)
+            .await?;
+        let contact = build_contact(
+            cmd.details,
+            existing.id,
+            existing.user_id,
+            existing.organization_id,
+            existing.created_at,
+            OffsetDateTime::now_utc(),
+        )?;
+
+        self.contact_repository.update_contact(&contact).await?;
+
+        Ok(contact)
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::usecases::{UsecaseError, authorize_member};
+use domain::{
+    models::{
+        conversation::ConversationRouting,
+        organization::{Organization, Permission},
+    },
+    repositories::organization_repository::OrganizationRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct UpdateConversationRoutingUsecase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl UpdateConversationRoutingUsecase {
+    pub async fn execute(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        conversation_routing: ConversationRouting,
+    ) -> Result<Organization, UsecaseError> {
+        authorize_member(
+            &self.organization_repository,
+            &organization_id,
+            &user_id,
+            Permission::ManageRouting,
+        )
+        .await?;
+
+        self.organization_repository
+            .update_conversation_routing(
+                &organization_id,
+                conversation_routing,
+                OffsetDateTime::now_utc(),
+            )
+            .await?;
+        let organization = self
+            .organization_repository
+            .find_by_id(&organization_id)
+            .await?;
+
+        Ok(organization)
+    }
+}
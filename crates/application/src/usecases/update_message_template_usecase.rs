@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::{
+    commands::UpdateMessageTemplateCommand,
+    usecases::{UsecaseError, create_message_template_usecase::build_message_template},
+};
+use domain::{
+    models::message_template::MessageTemplate,
+    repositories::message_template_repository::MessageTemplateRepository,
+};
+
+#[derive(bon::Builder)]
+pub struct UpdateMessageTemplateUsecase {
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+}
+
+impl UpdateMessageTemplateUsecase {
+    pub async fn execute(
+        &self,
+        cmd: UpdateMessageTemplateCommand,
+    ) -> Result<MessageTemplate, UsecaseError> {
+        let existing = self
+            .message_template_repository
+            .find_by_id(&cmd.user_id, &cmd.template_id)
+            .await?;
+        let template = build_message_template(
+            cmd.details,
+            existing.id,
+            existing.user_id,
+            existing.created_at,
+            OffsetDateTime::now_utc(),
+        )?;
+
+        self.message_template_repository
+            .update_template(&template)
+            .await?;
+
+        Ok(template)
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    repositories::{RepositoryError, user_repository::UserRepository},
+    traits::token_service::{PasetoClaimPurpose, TokenService},
+};
+
+#[derive(bon::Builder)]
+pub struct VerifyEmailUsecase {
+    user_repository: Arc<dyn UserRepository>,
+    token_service: Arc<dyn TokenService>,
+}
+
+impl VerifyEmailUsecase {
+    /// Marks the email in the token as verified. Opening the same link twice is harmless.
+    pub async fn execute(&self, token: String) -> Result<(), UsecaseError> {
+        let claims = self
+            .token_service
+            .validate_token(token, PasetoClaimPurpose::EmailVerification)
+            .map_err(|_| UsecaseError::InvalidVerificationToken)?;
+
+        let mut user = match self.user_repository.find_by_id(&claims.id).await {
+            Ok(user) => user,
+            Err(RepositoryError::NotFound) => return Err(UsecaseError::InvalidVerificationToken),
+            Err(err) => return Err(err.into()),
+        };
+
+        if !user.email.eq_ignore_ascii_case(&claims.email) {
+            return Err(UsecaseError::InvalidVerificationToken);
+        }
+
+        if user.email_verified {
+            return Ok(());
+        }
+
+        let now = OffsetDateTime::now_utc();
+        user.email_verified = true;
+        user.email_verified_at = Some(now);
+        user.updated_at = now;
+        self.user_repository.update_user(&user).await?;
+
+        Ok(())
+    }
+}
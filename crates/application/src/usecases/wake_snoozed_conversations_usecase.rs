@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::usecases::UsecaseError;
+use domain::{
+    models::conversation::Conversation,
+    repositories::conversation_repository::ConversationRepository,
+};
+
+/// Returns snoozed conversations to the inbox once their time is up.
+#[derive(bon::Builder)]
+pub struct WakeSnoozedConversationsUsecase {
+    conversation_repository: Arc<dyn ConversationRepository>,
+}
+
+impl WakeSnoozedConversationsUsecase {
+    pub async fn execute(&self, now: OffsetDateTime) -> Result<Vec<Conversation>, UsecaseError> {
+        let conversations = self.conversation_repository.wake_snoozed(now).await?;
+
+        Ok(conversations)
+    }
+}
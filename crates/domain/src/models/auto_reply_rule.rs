@@ -0,0 +1,103 @@
+use regex::{Regex, RegexBuilder};
+use time::OffsetDateTime;
+
+/// How an auto-reply rule compares its pattern with the inbound text. Every kind ignores
+/// case and the whitespace around the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoReplyMatchType {
+    /// The whole message equals the pattern.
+    Exact,
+    /// The message starts with the pattern.
+    Prefix,
+    /// The pattern is a regular expression found anywhere in the message.
+    Regex,
+}
+
+/// Upper bound for the compiled size of a regex pattern, so a rule cannot make inbound
+/// processing expensive.
+const MAX_REGEX_SIZE: usize = 64 * 1024;
+
+/// Replies automatically to inbound messages sent to one of the organization's numbers.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct AutoReplyRule {
+    pub id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub phone_number_id: uuid::Uuid,
+    pub match_type: AutoReplyMatchType,
+    pub pattern: String,
+    /// Reply text with `{{first_name}}`-style placeholders.
+    pub reply_template: String,
+    /// Added to the sender's contact when the rule matches.
+    pub tag: Option<String>,
+    /// Minimum time between two replies of this rule to the same sender; `0` always replies.
+    pub cooldown_seconds: i64,
+    pub enabled: bool,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl AutoReplyRule {
+    pub fn matches(&self, text: &str) -> bool {
+        let text = text.trim();
+        let pattern = self.pattern.trim();
+
+        match self.match_type {
+            AutoReplyMatchType::Exact => text.to_lowercase() == pattern.to_lowercase(),
+            AutoReplyMatchType::Prefix => text.to_lowercase().starts_with(&pattern.to_lowercase()),
+            AutoReplyMatchType::Regex => {
+                compile_pattern(pattern).is_ok_and(|regex| regex.is_match(text))
+            }
+        }
+    }
+}
+
+/// Compiles a regex rule pattern the way it is matched against inbound messages.
+pub fn compile_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(MAX_REGEX_SIZE)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::{AutoReplyMatchType, AutoReplyRule};
+
+    fn rule(match_type: AutoReplyMatchType, pattern: &str) -> AutoReplyRule {
+        let now = OffsetDateTime::now_utc();
+        AutoReplyRule::builder()
+            .id(uuid::Uuid::now_v7())
+            .organization_id(uuid::Uuid::now_v7())
+            .user_id(uuid::Uuid::now_v7())
+            .phone_number_id(uuid::Uuid::now_v7())
+            .match_type(match_type)
+            .pattern(pattern.to_owned())
+            .reply_template("Thanks!".to_owned())
+            .cooldown_seconds(0)
+            .enabled(true)
+            .created_at(now)
+            .updated_at(now)
+            .build()
+    }
+
+    #[test]
+    fn matches_inbound_text_ignoring_case() {
+        let exact = rule(AutoReplyMatchType::Exact, "hours");
+        assert!(exact.matches(" Hours "));
+        assert!(!exact.matches("hours please"));
+
+        let prefix = rule(AutoReplyMatchType::Prefix, "JOIN");
+        assert!(prefix.matches("join vip"));
+        assert!(!prefix.matches("please join"));
+
+        let regex = rule(AutoReplyMatchType::Regex, r"\border\s+#?\d+");
+        assert!(regex.matches("Where is ORDER #1234?"));
+        assert!(!regex.matches("where is my order"));
+
+        let invalid = rule(AutoReplyMatchType::Regex, "(");
+        assert!(!invalid.matches("("));
+    }
+}
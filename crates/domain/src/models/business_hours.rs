@@ -0,0 +1,179 @@
+use time::{
+    Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, Weekday,
+    format_description::well_known::Iso8601,
+};
+use time_tz::{OffsetDateTimeExt, OffsetResult, PrimitiveDateTimeExt, Tz, timezones};
+
+/// How far back `BusinessHours::closed_since` looks for the last opening. A number closed
+/// for longer than that starts a new closed period every year.
+const LOOKBACK_DAYS: i64 = 366;
+
+/// One weekly opening in the schedule's timezone. An opening that closes at or before the
+/// time it opens runs past midnight into the next day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpeningHours {
+    pub weekday: Weekday,
+    pub opens_at: Time,
+    pub closes_at: Time,
+}
+
+/// When a phone number is staffed, and what inbound messages get back outside those hours.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct BusinessHours {
+    pub phone_number_id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    /// IANA timezone name, e.g. `America/New_York`.
+    pub timezone: String,
+    pub opening_hours: Vec<OpeningHours>,
+    /// Local dates on which no opening starts.
+    pub holidays: Vec<Date>,
+    /// Reply text with `{{first_name}}`-style placeholders.
+    pub away_message: String,
+    pub enabled: bool,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl BusinessHours {
+    /// Start of the closed period `at` falls in, i.e. when the last opening ended, or `None`
+    /// while the number is open. An unknown timezone counts as always open.
+    pub fn closed_since(&self, at: OffsetDateTime) -> Option<OffsetDateTime> {
+        let tz = find_timezone(&self.timezone)?;
+        let today = at.to_timezone(tz).date();
+        let mut last_closed_at: Option<OffsetDateTime> = None;
+
+        for days_back in 0..=LOOKBACK_DAYS {
+            let Some(date) = today.checked_sub(Duration::days(days_back)) else {
+                break;
+            };
+            // Openings last a day at most, so none starting earlier can end any later.
+            if let Some(closed_at) = last_closed_at
+                && date.next_day() < Some(closed_at.to_timezone(tz).date())
+            {
+                break;
+            }
+            if self.holidays.contains(&date) {
+                continue;
+            }
+
+            for opening in self
+                .opening_hours
+                .iter()
+                .filter(|opening| opening.weekday == date.weekday())
+            {
+                let close_date = if opening.closes_at <= opening.opens_at {
+                    date.next_day().unwrap_or(date)
+                } else {
+                    date
+                };
+                let opens_at = resolve_local(date.with_time(opening.opens_at), tz);
+                let closes_at = resolve_local(close_date.with_time(opening.closes_at), tz);
+
+                if opens_at <= at && at < closes_at {
+                    return None;
+                }
+                if closes_at <= at {
+                    last_closed_at = last_closed_at.max(Some(closes_at));
+                }
+            }
+        }
+
+        Some(last_closed_at.unwrap_or(at - Duration::days(LOOKBACK_DAYS)))
+    }
+}
+
+pub fn find_timezone(name: &str) -> Option<&'static Tz> {
+    timezones::get_by_name(name)
+}
+
+/// Parses a `HH:MM` time of day.
+pub fn parse_time_of_day(value: &str) -> Option<Time> {
+    let (hour, minute) = value.trim().split_once(':')?;
+    if hour.len() != 2 || minute.len() != 2 {
+        return None;
+    }
+
+    Time::from_hms(hour.parse().ok()?, minute.parse().ok()?, 0).ok()
+}
+
+pub fn format_time_of_day(time: Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+/// Parses a `YYYY-MM-DD` holiday date.
+pub fn parse_date(value: &str) -> Option<Date> {
+    Date::parse(value.trim(), &Iso8601::DATE).ok()
+}
+
+/// Places a wall-clock time in `tz`. A time repeated when the clocks go back resolves to its
+/// first occurrence, and one skipped when they go forward is shifted past the gap.
+fn resolve_local(local: PrimitiveDateTime, tz: &Tz) -> OffsetDateTime {
+    match local.assume_timezone(tz) {
+        OffsetResult::Some(at) | OffsetResult::Ambiguous(at, _) => at,
+        OffsetResult::None => local.assume_timezone_utc(tz),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, OffsetDateTime, Time, Weekday};
+
+    use super::{BusinessHours, OpeningHours, parse_date, parse_time_of_day};
+
+    fn utc(day: u8, hour: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::December, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn opening(weekday: Weekday, opens_at: &str, closes_at: &str) -> OpeningHours {
+        OpeningHours {
+            weekday,
+            opens_at: parse_time_of_day(opens_at).unwrap(),
+            closes_at: parse_time_of_day(closes_at).unwrap(),
+        }
+    }
+
+    #[test]
+    fn closed_since_tracks_the_last_opening_in_local_time() {
+        let now = OffsetDateTime::now_utc();
+        let weekdays = [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ];
+        let mut opening_hours = weekdays
+            .into_iter()
+            .map(|weekday| opening(weekday, "09:00", "17:00"))
+            .collect::<Vec<_>>();
+        opening_hours.push(opening(Weekday::Saturday, "22:00", "02:00"));
+        let business_hours = BusinessHours::builder()
+            .phone_number_id(uuid::Uuid::now_v7())
+            .organization_id(uuid::Uuid::now_v7())
+            .user_id(uuid::Uuid::now_v7())
+            .timezone("America/New_York".to_owned())
+            .opening_hours(opening_hours)
+            .holidays(vec![parse_date("2026-12-25").unwrap()])
+            .away_message("We're closed.".to_owned())
+            .enabled(true)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+
+        // Tuesday 10:00 and 18:00 in New York, five hours behind UTC.
+        assert_eq!(business_hours.closed_since(utc(22, 15)), None);
+        assert_eq!(business_hours.closed_since(utc(22, 23)), Some(utc(22, 22)));
+        // Christmas falls on the Friday, so Thursday's closing still counts on Saturday.
+        assert_eq!(business_hours.closed_since(utc(26, 15)), Some(utc(24, 22)));
+        // Sunday 01:00 is inside the Saturday night opening.
+        assert_eq!(business_hours.closed_since(utc(27, 6)), None);
+        assert_eq!(business_hours.closed_since(utc(27, 8)), Some(utc(27, 7)));
+        assert_eq!(Time::from_hms(9, 0, 0).ok(), parse_time_of_day("09:00"));
+        assert_eq!(parse_time_of_day("9:00"), None);
+    }
+}
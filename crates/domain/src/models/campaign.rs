@@ -0,0 +1,112 @@
+use time::OffsetDateTime;
+
+/// Lifecycle of a campaign. `Running` campaigns are fanned out by the campaign worker and
+/// become `Completed` once every recipient has been messaged or skipped. `Completed` and
+/// `Cancelled` are terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CampaignStatus {
+    Running,
+    Paused,
+    Completed,
+    Cancelled,
+}
+
+impl CampaignStatus {
+    pub fn is_terminal(self) -> bool {
+        matches!(self, CampaignStatus::Completed | CampaignStatus::Cancelled)
+    }
+
+    pub fn can_transition_to(self, next: CampaignStatus) -> bool {
+        use CampaignStatus::*;
+
+        match self {
+            Running => matches!(next, Paused | Completed | Cancelled),
+            Paused => matches!(next, Running | Cancelled),
+            Completed | Cancelled => false,
+        }
+    }
+}
+
+/// One message sent to many recipients from the same number, throttled to
+/// `messages_per_second`.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct Campaign {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    /// Owner of `phone_number_id`, under which recipient conversations are created.
+    pub organization_id: uuid::Uuid,
+    pub phone_number_id: uuid::Uuid,
+    pub name: String,
+    /// Message text with `{{first_name}}`-style placeholders.
+    pub body: String,
+    /// Contacts carrying this tag were added as recipients.
+    pub tag: Option<String>,
+    pub messages_per_second: i32,
+    pub status: CampaignStatus,
+    /// When the worker may send the next batch.
+    pub next_batch_at: OffsetDateTime,
+    /// Why the worker paused the campaign, e.g. a spending limit. Cleared by the next
+    /// status change.
+    pub pause_reason: Option<String>,
+    pub completed_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CampaignRecipientStatus {
+    /// Not messaged yet.
+    Pending,
+    /// A message was created and queued; its status tracks delivery from here on.
+    Messaged,
+    /// Not messaged, e.g. because the recipient opted out; `reason` says why.
+    Skipped,
+}
+
+#[derive(Debug, Clone, bon::Builder)]
+pub struct CampaignRecipient {
+    pub id: uuid::Uuid,
+    pub campaign_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub recipient_phone_number: String,
+    pub contact_id: Option<uuid::Uuid>,
+    pub status: CampaignRecipientStatus,
+    /// The campaign body rendered for this recipient.
+    pub content: String,
+    pub message_id: Option<uuid::Uuid>,
+    pub reason: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+/// Recipient counts of a campaign. Messaged recipients are counted by the current status of
+/// their message, so the numbers follow delivery webhooks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CampaignProgress {
+    pub total: u64,
+    pub pending: u64,
+    pub skipped: u64,
+    /// Messages waiting for or handed to the provider.
+    pub queued: u64,
+    pub sent: u64,
+    pub delivered: u64,
+    pub failed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CampaignStatus;
+
+    #[test]
+    fn only_active_campaigns_can_change_status() {
+        use CampaignStatus::*;
+
+        assert!(Running.can_transition_to(Paused));
+        assert!(Paused.can_transition_to(Running));
+        assert!(Paused.can_transition_to(Cancelled));
+        assert!(!Paused.can_transition_to(Completed));
+        assert!(!Completed.can_transition_to(Running));
+        assert!(!Cancelled.can_transition_to(Running));
+        assert!(Cancelled.is_terminal());
+    }
+}
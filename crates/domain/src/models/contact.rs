@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use time::OffsetDateTime;
+
+/// An entry in an organization's shared address book. Conversations of the organization
+/// whose recipient matches one of the contact's numbers are shown under the contact's name.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct Contact {
+    pub id: uuid::Uuid,
+    /// Member who created the contact.
+    pub user_id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+    pub name: String,
+    /// E.164 numbers, unique per organization across all contacts.
+    #[builder(default)]
+    pub phone_numbers: Vec<String>,
+    pub email: Option<String>,
+    pub notes: Option<String>,
+    /// Free-form key/value pairs, e.g. `company` or `account_id`.
+    #[builder(default)]
+    pub custom_fields: BTreeMap<String, String>,
+    /// Lowercase labels used to group contacts, e.g. to address a campaign.
+    #[builder(default)]
+    pub tags: Vec<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+/// Normalizes a phone number to E.164, ignoring common separators and `tel:` prefixes.
+/// Numbers without a `+` or `00` international prefix are read as national numbers of
+/// `default_country_code`; without one they are rejected.
+pub fn normalize_phone_number(raw: &str, default_country_code: Option<&str>) -> Option<String> {
+    let raw = raw.trim();
+    let raw = raw
+        .strip_prefix("tel:")
+        .or_else(|| raw.strip_prefix("TEL:"))
+        .unwrap_or(raw);
+    let compact = raw
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')' | '/' | '\u{a0}'))
+        .collect::<String>();
+
+    let digits = if let Some(international) = compact.strip_prefix('+') {
+        international.to_owned()
+    } else if let Some(international) = compact.strip_prefix("00") {
+        international.to_owned()
+    } else {
+        let country_code = default_country_code?.trim().trim_start_matches('+');
+        let national = match country_code {
+            // NANP numbers are often written with the leading 1 trunk prefix.
+            "1" if compact.len() == 11 => compact.strip_prefix('1').unwrap_or(&compact),
+            _ => compact.strip_prefix('0').unwrap_or(&compact),
+        };
+        format!("{}{}", country_code, national)
+    };
+
+    if (8..=15).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit()) {
+        Some(format!("+{}", digits))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_phone_number;
+
+    #[test]
+    fn normalizes_international_and_national_numbers() {
+        assert_eq!(
+            normalize_phone_number("+1 (555) 123-4567", None).as_deref(),
+            Some("+15551234567")
+        );
+        assert_eq!(
+            normalize_phone_number("0044 20 7946 0958", None).as_deref(),
+            Some("+442079460958")
+        );
+        assert_eq!(
+            normalize_phone_number("tel:+33-1-23-45-67-89", None).as_deref(),
+            Some("+33123456789")
+        );
+        assert_eq!(
+            normalize_phone_number("020 7946 0958", Some("44")).as_deref(),
+            Some("+442079460958")
+        );
+        assert_eq!(
+            normalize_phone_number("1-555-123-4567", Some("+1")).as_deref(),
+            Some("+15551234567")
+        );
+        assert_eq!(normalize_phone_number("555 123 4567", None), None);
+        assert_eq!(normalize_phone_number("+1 555 CALL NOW", None), None);
+    }
+}
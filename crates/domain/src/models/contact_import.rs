@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactImportFormat {
+    Csv,
+    VCard,
+}
+
+impl ContactImportFormat {
+    /// Picks the format from the file extension, falling back to sniffing the content.
+    pub fn detect(file_name: &str, content: &str) -> Self {
+        let extension = file_name
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("vcf" | "vcard") => Self::VCard,
+            Some("csv" | "txt") => Self::Csv,
+            _ if content
+                .trim_start_matches('\u{feff}')
+                .trim_start()
+                .to_ascii_uppercase()
+                .starts_with("BEGIN:VCARD") =>
+            {
+                Self::VCard
+            }
+            _ => Self::Csv,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactImportStatus {
+    /// Waiting for the background worker.
+    Pending,
+    Completed,
+    /// The file could not be read at all; `last_error` says why.
+    Failed,
+}
+
+/// Which CSV columns hold which contact fields, matched against the header row without
+/// regard to case. Fields left empty fall back to well-known header names, and columns
+/// that are not mapped are kept as custom fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContactImportColumnMapping {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub phone_numbers: Vec<String>,
+    pub email: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContactImportOutcome {
+    Imported,
+    /// Every number already belongs to a contact, or appeared earlier in the file.
+    Skipped,
+    Invalid,
+}
+
+/// What happened to one entry of the file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContactImportRow {
+    /// 1-based line the entry starts on.
+    pub line: usize,
+    pub outcome: ContactImportOutcome,
+    pub name: Option<String>,
+    pub phone_numbers: Vec<String>,
+    pub contact_id: Option<uuid::Uuid>,
+    pub reason: Option<String>,
+}
+
+/// An uploaded address book file and, once processed, its per-entry report. The raw
+/// content is dropped after processing.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct ContactImport {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    /// Address book the entries are imported into.
+    pub organization_id: uuid::Uuid,
+    pub file_name: String,
+    pub format: ContactImportFormat,
+    pub status: ContactImportStatus,
+    #[builder(default)]
+    pub column_mapping: ContactImportColumnMapping,
+    /// Country calling code used for numbers written without an international prefix.
+    pub default_country_code: Option<String>,
+    #[builder(default)]
+    pub content: String,
+    #[builder(default)]
+    pub rows: Vec<ContactImportRow>,
+    #[builder(default)]
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub completed_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl ContactImport {
+    pub fn count(&self, outcome: ContactImportOutcome) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| row.outcome == outcome)
+            .count()
+    }
+}
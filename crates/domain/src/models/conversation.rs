@@ -1,12 +1,273 @@
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 #[derive(Debug, Clone, bon::Builder)]
 pub struct Conversation {
     pub id: uuid::Uuid,
     pub phone_number_id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+    /// Who started the conversation, or the number's owner for inbound ones.
     pub user_id: uuid::Uuid,
     pub recipient_phone_number: Option<String>,
+    /// The member answering this conversation, if any.
+    pub assigned_user_id: Option<uuid::Uuid>,
+    pub assigned_at: Option<OffsetDateTime>,
     pub last_message_at: OffsetDateTime,
+    /// When the last away message went out, see `BusinessHours`.
+    pub last_away_reply_at: Option<OffsetDateTime>,
+    /// Set while the conversation is out of the inbox. The next inbound message clears it.
+    pub archived_at: Option<OffsetDateTime>,
+    pub pinned_at: Option<OffsetDateTime>,
+    /// Hidden from the inbox until then, or until the next inbound message.
+    pub snoozed_until: Option<OffsetDateTime>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
+
+impl Conversation {
+    /// Hands the conversation to `assigned_user_id`, or back to the team when `None`.
+    pub fn assign(&mut self, assigned_user_id: Option<uuid::Uuid>, now: OffsetDateTime) {
+        self.assigned_at = assigned_user_id.map(|_| now);
+        self.assigned_user_id = assigned_user_id;
+        self.updated_at = now;
+    }
+
+    pub fn apply(&mut self, change: ConversationStateChange, now: OffsetDateTime) {
+        match change {
+            ConversationStateChange::Archive => self.archived_at = Some(now),
+            ConversationStateChange::Unarchive => self.archived_at = None,
+            ConversationStateChange::Pin => self.pinned_at = Some(now),
+            ConversationStateChange::Unpin => self.pinned_at = None,
+            ConversationStateChange::Snooze(until) => self.snoozed_until = Some(until),
+            ConversationStateChange::Unsnooze => self.snoozed_until = None,
+        }
+        self.updated_at = now;
+    }
+
+    /// Brings an archived or snoozed conversation back to the inbox when a message comes in.
+    /// Pins are kept.
+    pub fn reopen(&mut self) {
+        self.archived_at = None;
+        self.snoozed_until = None;
+    }
+
+    pub fn is_snoozed(&self, now: OffsetDateTime) -> bool {
+        self.snoozed_until.is_some_and(|until| until > now)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversationStateChange {
+    Archive,
+    Unarchive,
+    Pin,
+    Unpin,
+    Snooze(OffsetDateTime),
+    Unsnooze,
+}
+
+/// Which conversations a list shows by archive, pin and snooze state. Archived conversations
+/// only show under `Archived`, whatever else they are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConversationFilter {
+    /// Neither archived nor snoozed.
+    #[default]
+    Inbox,
+    Pinned,
+    Snoozed,
+    Archived,
+}
+
+impl ConversationFilter {
+    pub fn includes(self, conversation: &Conversation, now: OffsetDateTime) -> bool {
+        let archived = conversation.archived_at.is_some();
+        match self {
+            ConversationFilter::Inbox => !archived && !conversation.is_snoozed(now),
+            ConversationFilter::Pinned => !archived && conversation.pinned_at.is_some(),
+            ConversationFilter::Snoozed => !archived && conversation.is_snoozed(now),
+            ConversationFilter::Archived => archived,
+        }
+    }
+}
+
+/// Inbound messages a member has not read yet in one conversation.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct UnreadCount {
+    pub conversation_id: uuid::Uuid,
+    pub unread: i64,
+}
+
+/// Which conversations a list shows, relative to the member looking at it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConversationView {
+    #[default]
+    All,
+    Mine,
+    Unassigned,
+}
+
+impl ConversationView {
+    pub fn includes(self, conversation: &Conversation, user_id: uuid::Uuid) -> bool {
+        match self {
+            ConversationView::All => true,
+            ConversationView::Mine => conversation.assigned_user_id == Some(user_id),
+            ConversationView::Unassigned => conversation.assigned_user_id.is_none(),
+        }
+    }
+}
+
+/// How an organization assigns conversations that receive a message while unassigned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationRouting {
+    /// Conversations wait in the unassigned view.
+    #[default]
+    Manual,
+    /// Each member in turn, starting with whoever was assigned least recently.
+    RoundRobin,
+    /// The member holding the fewest conversations.
+    LeastLoad,
+}
+
+/// What a member currently holds in an organization's inboxes.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct AssigneeLoad {
+    pub user_id: uuid::Uuid,
+    pub assigned_count: i64,
+    pub last_assigned_at: Option<OffsetDateTime>,
+}
+
+impl ConversationRouting {
+    /// Picks among `candidates`, given in the order they joined, which also breaks ties.
+    /// Candidates missing from `loads` hold nothing yet and are picked first.
+    pub fn pick_assignee(
+        self,
+        candidates: &[uuid::Uuid],
+        loads: &[AssigneeLoad],
+    ) -> Option<uuid::Uuid> {
+        let load_of = |user_id: &uuid::Uuid| loads.iter().find(|load| load.user_id == *user_id);
+        let last_assigned_at = |user_id: &uuid::Uuid| load_of(user_id)?.last_assigned_at;
+        let assigned_count =
+            |user_id: &uuid::Uuid| load_of(user_id).map_or(0, |load| load.assigned_count);
+
+        // `min_by_key` keeps the first of equal candidates, and `None` sorts before any time.
+        match self {
+            ConversationRouting::Manual => None,
+            ConversationRouting::RoundRobin => candidates
+                .iter()
+                .min_by_key(|user_id| last_assigned_at(user_id)),
+            ConversationRouting::LeastLoad => candidates
+                .iter()
+                .min_by_key(|user_id| (assigned_count(user_id), last_assigned_at(user_id))),
+        }
+        .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+
+    use super::{
+        AssigneeLoad, Conversation, ConversationFilter, ConversationRouting,
+        ConversationStateChange,
+    };
+
+    #[test]
+    fn round_robin_and_least_load_pick_different_members() {
+        let now = OffsetDateTime::now_utc();
+        let [first, second, third] = [1, 2, 3].map(uuid::Uuid::from_u128);
+        let loads = [
+            AssigneeLoad::builder()
+                .user_id(first)
+                .assigned_count(1)
+                .last_assigned_at(now)
+                .build(),
+            AssigneeLoad::builder()
+                .user_id(second)
+                .assigned_count(5)
+                .last_assigned_at(now - Duration::hours(1))
+                .build(),
+        ];
+
+        assert_eq!(
+            ConversationRouting::RoundRobin.pick_assignee(&[first, second], &loads),
+            Some(second)
+        );
+        assert_eq!(
+            ConversationRouting::LeastLoad.pick_assignee(&[first, second], &loads),
+            Some(first)
+        );
+        // Someone who never got a conversation goes first either way.
+        assert_eq!(
+            ConversationRouting::RoundRobin.pick_assignee(&[first, second, third], &loads),
+            Some(third)
+        );
+        assert_eq!(
+            ConversationRouting::LeastLoad.pick_assignee(&[first, second, third], &loads),
+            Some(third)
+        );
+        assert_eq!(
+            ConversationRouting::Manual.pick_assignee(&[first, second], &loads),
+            None
+        );
+        assert_eq!(
+            ConversationRouting::RoundRobin.pick_assignee(&[], &loads),
+            None
+        );
+    }
+
+    #[test]
+    fn filters_follow_archive_pin_and_snooze_state() {
+        let now = OffsetDateTime::now_utc();
+        let mut conversation = Conversation::builder()
+            .id(uuid::Uuid::from_u128(1))
+            .phone_number_id(uuid::Uuid::from_u128(2))
+            .organization_id(uuid::Uuid::from_u128(3))
+            .user_id(uuid::Uuid::from_u128(3))
+            .last_message_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let shown_in = |conversation: &Conversation, now| {
+            [
+                ConversationFilter::Inbox,
+                ConversationFilter::Pinned,
+                ConversationFilter::Snoozed,
+                ConversationFilter::Archived,
+            ]
+            .into_iter()
+            .filter(|filter| filter.includes(conversation, now))
+            .collect::<Vec<_>>()
+        };
+
+        conversation.apply(ConversationStateChange::Pin, now);
+        assert_eq!(
+            shown_in(&conversation, now),
+            [ConversationFilter::Inbox, ConversationFilter::Pinned]
+        );
+
+        conversation.apply(
+            ConversationStateChange::Snooze(now + Duration::hours(1)),
+            now,
+        );
+        assert_eq!(
+            shown_in(&conversation, now),
+            [ConversationFilter::Pinned, ConversationFilter::Snoozed]
+        );
+        // A lapsed snooze counts as woken even before the worker clears it.
+        assert_eq!(
+            shown_in(&conversation, now + Duration::hours(2)),
+            [ConversationFilter::Inbox, ConversationFilter::Pinned]
+        );
+
+        conversation.apply(ConversationStateChange::Archive, now);
+        assert_eq!(shown_in(&conversation, now), [ConversationFilter::Archived]);
+
+        conversation.reopen();
+        assert_eq!(
+            shown_in(&conversation, now),
+            [ConversationFilter::Inbox, ConversationFilter::Pinned]
+        );
+    }
+}
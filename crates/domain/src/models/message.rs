@@ -1,13 +1,20 @@
 use time::OffsetDateTime;
 
+use crate::models::{message_attachment::MessageAttachment, sms_encoding::SmsEncoding};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessageType {
     Inbound,
     Outbound,
 }
 
+/// Lifecycle of a message. Outbound messages move `Pending → Queued → Sent → Delivered`,
+/// may skip ahead when provider events arrive out of order, and can fail from any
+/// non-terminal state. Messages sent later start as `Scheduled` and are released to
+/// `Pending` once due. `Delivered` and `Failed` are terminal.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessageStatus {
+    Scheduled,
     Pending,
     Queued,
     Delivered,
@@ -15,10 +22,57 @@ pub enum MessageStatus {
     Sent,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("message status cannot change from {from:?} to {to:?}")]
+pub struct InvalidStatusTransition {
+    pub from: MessageStatus,
+    pub to: MessageStatus,
+}
+
+impl MessageStatus {
+    pub fn is_terminal(self) -> bool {
+        matches!(self, MessageStatus::Delivered | MessageStatus::Failed)
+    }
+
+    /// Whether `next` may follow `self`. Staying in the same status is allowed so
+    /// repeated provider events can refresh details without moving the message.
+    pub fn can_transition_to(self, next: MessageStatus) -> bool {
+        use MessageStatus::*;
+
+        if self == next {
+            return true;
+        }
+
+        match self {
+            Scheduled => matches!(next, Pending | Failed),
+            Pending => matches!(next, Queued | Sent | Delivered | Failed),
+            Queued => matches!(next, Sent | Delivered | Failed),
+            Sent => matches!(next, Delivered | Failed),
+            Delivered | Failed => false,
+        }
+    }
+
+    pub fn transition_to(
+        self,
+        next: MessageStatus,
+    ) -> Result<MessageStatus, InvalidStatusTransition> {
+        if self.can_transition_to(next) {
+            Ok(next)
+        } else {
+            Err(InvalidStatusTransition {
+                from: self,
+                to: next,
+            })
+        }
+    }
+}
+
 #[derive(Debug, Clone, bon::Builder)]
 pub struct Message {
     pub id: uuid::Uuid,
     pub conversation_id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+    /// Who sent the message, or the number's owner for inbound and automated ones.
     pub user_id: uuid::Uuid,
     pub message_type: MessageType,
     pub status: MessageStatus,
@@ -29,6 +83,115 @@ pub struct Message {
     pub provider_error_detail: Option<String>,
     pub from_number: String,
     pub content: String,
+    /// When a `Scheduled` message should be handed to the provider.
+    pub scheduled_at: Option<OffsetDateTime>,
+    /// Sent by the app on its own, e.g. an auto-reply rule or the HELP reply.
+    #[builder(default)]
+    pub automated: bool,
+    /// The inbound message an automated reply answers. At most one reply is queued per
+    /// inbound message, so a retried webhook finds the reply it queued before.
+    pub reply_to_message_id: Option<uuid::Uuid>,
+    /// SMS segments and encoding of `content`, worked out when an outbound message is created
+    /// and replaced by what Telnyx reports once it has handled the message.
+    pub segments: Option<i32>,
+    pub encoding: Option<SmsEncoding>,
+    /// What Telnyx charged for the message, in micros of `cost_currency`.
+    pub cost_micros: Option<i64>,
+    pub cost_currency: Option<String>,
+    /// Carrier and line type (`Wireless`, `Wireline`, ...) of the contact's number.
+    pub carrier: Option<String>,
+    pub line_type: Option<String>,
+    pub messaging_profile_id: Option<String>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
+    #[builder(default)]
+    pub attachments: Vec<MessageAttachment>,
+}
+
+impl Message {
+    /// Moves the message to `next`, leaving it untouched when the transition is not allowed.
+    pub fn transition_to(&mut self, next: MessageStatus) -> Result<(), InvalidStatusTransition> {
+        self.status = self.status.transition_to(next)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidStatusTransition, MessageStatus};
+
+    const ALL: [MessageStatus; 6] = [
+        MessageStatus::Scheduled,
+        MessageStatus::Pending,
+        MessageStatus::Queued,
+        MessageStatus::Sent,
+        MessageStatus::Delivered,
+        MessageStatus::Failed,
+    ];
+
+    fn allowed(from: MessageStatus, to: MessageStatus) -> bool {
+        use MessageStatus::*;
+
+        matches!(
+            (from, to),
+            (Scheduled, Scheduled | Pending | Failed)
+                | (Pending, Pending | Queued | Sent | Delivered | Failed)
+                | (Queued, Queued | Sent | Delivered | Failed)
+                | (Sent, Sent | Delivered | Failed)
+                | (Delivered, Delivered)
+                | (Failed, Failed)
+        )
+    }
+
+    #[test]
+    fn every_transition_matches_the_lifecycle() {
+        for from in ALL {
+            for to in ALL {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed(from, to),
+                    "{:?} -> {:?}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn terminal_statuses_only_allow_themselves() {
+        for from in ALL.into_iter().filter(|status| status.is_terminal()) {
+            for to in ALL.into_iter().filter(|to| *to != from) {
+                assert_eq!(
+                    from.transition_to(to),
+                    Err(InvalidStatusTransition { from, to })
+                );
+            }
+        }
+
+        assert!(MessageStatus::Delivered.is_terminal());
+        assert!(MessageStatus::Failed.is_terminal());
+        assert!(!MessageStatus::Scheduled.is_terminal());
+        assert!(!MessageStatus::Pending.is_terminal());
+        assert!(!MessageStatus::Queued.is_terminal());
+        assert!(!MessageStatus::Sent.is_terminal());
+    }
+
+    #[test]
+    fn late_sent_cannot_regress_delivered() {
+        assert_eq!(
+            MessageStatus::Delivered.transition_to(MessageStatus::Sent),
+            Err(InvalidStatusTransition {
+                from: MessageStatus::Delivered,
+                to: MessageStatus::Sent,
+            })
+        );
+        assert_eq!(
+            MessageStatus::Sent.transition_to(MessageStatus::Queued),
+            Err(InvalidStatusTransition {
+                from: MessageStatus::Sent,
+                to: MessageStatus::Queued,
+            })
+        );
+    }
 }
@@ -0,0 +1,14 @@
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, bon::Builder)]
+pub struct MessageAttachment {
+    pub id: uuid::Uuid,
+    pub message_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub checksum_sha256: Option<String>,
+    pub created_at: OffsetDateTime,
+}
@@ -0,0 +1,75 @@
+use time::OffsetDateTime;
+
+use crate::models::message::MessageStatus;
+
+/// One entry in a message's delivery timeline, e.g. queued → sent → delivered.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct MessageStatusEvent {
+    pub id: uuid::Uuid,
+    pub message_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub status: MessageStatus,
+    /// Status exactly as reported by Telnyx, such as `delivery_failed` or `gw_timeout`.
+    pub provider_status: Option<String>,
+    pub carrier: Option<String>,
+    pub error_code: Option<String>,
+    pub error_detail: Option<String>,
+    pub occurred_at: OffsetDateTime,
+    pub created_at: OffsetDateTime,
+}
+
+/// Orders a timeline oldest first. Events reported for the same instant keep the order in
+/// which they were recorded.
+pub fn sort_timeline(events: &mut [MessageStatusEvent]) {
+    events.sort_by(|a, b| {
+        a.occurred_at
+            .cmp(&b.occurred_at)
+            .then_with(|| a.created_at.cmp(&b.created_at))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+
+    use super::{MessageStatusEvent, sort_timeline};
+    use crate::models::message::MessageStatus;
+
+    fn event(status: MessageStatus, occurred_at: OffsetDateTime) -> MessageStatusEvent {
+        MessageStatusEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .message_id(uuid::Uuid::nil())
+            .user_id(uuid::Uuid::nil())
+            .status(status)
+            .occurred_at(occurred_at)
+            .created_at(occurred_at)
+            .build()
+    }
+
+    #[test]
+    fn timeline_is_ordered_by_occurrence_then_by_recording() {
+        let now = OffsetDateTime::now_utc();
+        // Webhooks arrive out of order, and the failure reported for the same instant as
+        // the delivery was recorded after it.
+        let delivered = event(MessageStatus::Delivered, now);
+        let sent = event(MessageStatus::Sent, now - Duration::seconds(2));
+        let queued = event(MessageStatus::Queued, now - Duration::seconds(3));
+        let mut failed = event(MessageStatus::Failed, now);
+        failed.created_at = now + Duration::seconds(1);
+        let mut events = vec![failed, delivered, sent, queued];
+
+        sort_timeline(&mut events);
+
+        let statuses = events.iter().map(|event| event.status).collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                MessageStatus::Queued,
+                MessageStatus::Sent,
+                MessageStatus::Delivered,
+                MessageStatus::Failed,
+            ]
+        );
+    }
+}
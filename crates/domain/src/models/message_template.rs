@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+
+use time::OffsetDateTime;
+
+use crate::models::{contact::Contact, phone_number::PhoneNumber};
+
+/// A reusable reply saved by a user. `body` may contain `{{first_name}}`-style placeholders.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct MessageTemplate {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub name: String,
+    pub body: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl MessageTemplate {
+    /// Placeholder names used in the body, in the order they first appear.
+    pub fn variables(&self) -> Vec<String> {
+        template_variables(&self.body)
+    }
+}
+
+/// Replaces `{{name}}` placeholders in `body` with values from `variables`, ignoring case
+/// and whitespace inside the braces. Fails with the placeholders that have no value, in
+/// the order they first appear.
+pub fn render_template(
+    body: &str,
+    variables: &BTreeMap<String, String>,
+) -> Result<String, Vec<String>> {
+    let (rendered, unresolved) = preview_template(body, variables);
+
+    if unresolved.is_empty() {
+        Ok(rendered)
+    } else {
+        Err(unresolved)
+    }
+}
+
+/// Like `render_template`, but keeps placeholders without a value in the text and returns
+/// their names next to it, so a draft can be shown before every value is known.
+pub fn preview_template(body: &str, variables: &BTreeMap<String, String>) -> (String, Vec<String>) {
+    let mut rendered = String::with_capacity(body.len());
+    let mut unresolved = Vec::<String>::new();
+
+    for part in parse_template(body) {
+        match part {
+            TemplatePart::Text(text) => rendered.push_str(text),
+            TemplatePart::Placeholder { raw, name } => {
+                match variables.get(&name).filter(|value| !value.is_empty()) {
+                    Some(value) => rendered.push_str(value),
+                    None => {
+                        rendered.push_str(raw);
+                        if !unresolved.contains(&name) {
+                            unresolved.push(name);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (rendered, unresolved)
+}
+
+/// Placeholder names used in `body`, in the order they first appear.
+pub fn template_variables(body: &str) -> Vec<String> {
+    let mut names = Vec::<String>::new();
+    for part in parse_template(body) {
+        if let TemplatePart::Placeholder { name, .. } = part
+            && !names.contains(&name)
+        {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+enum TemplatePart<'a> {
+    Text(&'a str),
+    Placeholder { raw: &'a str, name: String },
+}
+
+/// Splits `body` into literal text and `{{ ... }}` placeholders. An opening `{{` without a
+/// closing `}}` is kept as text.
+fn parse_template(body: &str) -> Vec<TemplatePart<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = body;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        if start > 0 {
+            parts.push(TemplatePart::Text(&rest[..start]));
+        }
+
+        let close = start + 2 + end + 2;
+        parts.push(TemplatePart::Placeholder {
+            raw: &rest[start..close],
+            name: variable_key(&rest[start + 2..start + 2 + end]),
+        });
+        rest = &rest[close..];
+    }
+    if !rest.is_empty() {
+        parts.push(TemplatePart::Text(rest));
+    }
+
+    parts
+}
+
+/// Variables available to a message addressed to `recipient_phone_number`: `phone_number`
+/// always, plus `name`, `first_name`, `last_name`, `email` and every custom field (as a
+/// snake_case key) when the recipient is a saved contact.
+pub fn contact_template_variables(
+    contact: Option<&Contact>,
+    recipient_phone_number: &str,
+) -> BTreeMap<String, String> {
+    let mut variables = BTreeMap::new();
+
+    if let Some(contact) = contact {
+        for (key, value) in &contact.custom_fields {
+            variables.insert(variable_key(key), value.to_owned());
+        }
+
+        let mut names = contact.name.split_whitespace();
+        let first_name = names.next().unwrap_or_default();
+        let last_name = names.collect::<Vec<_>>().join(" ");
+        variables.insert("name".to_owned(), contact.name.to_owned());
+        variables.insert("first_name".to_owned(), first_name.to_owned());
+        variables.insert("last_name".to_owned(), last_name);
+        if let Some(email) = &contact.email {
+            variables.insert("email".to_owned(), email.to_owned());
+        }
+    }
+
+    variables.insert("phone_number".to_owned(), recipient_phone_number.to_owned());
+
+    variables
+}
+
+/// Variables for a reply in a conversation: everything `contact_template_variables` offers
+/// plus `sender_name` and `sender_phone_number` of the number the message is sent from.
+pub fn conversation_template_variables(
+    contact: Option<&Contact>,
+    recipient_phone_number: &str,
+    sender: &PhoneNumber,
+) -> BTreeMap<String, String> {
+    let mut variables = contact_template_variables(contact, recipient_phone_number);
+    variables.insert("sender_name".to_owned(), sender.name.to_owned());
+    variables.insert("sender_phone_number".to_owned(), sender.phone.to_owned());
+
+    variables
+}
+
+fn variable_key(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use time::OffsetDateTime;
+
+    use crate::models::contact::Contact;
+
+    use super::{
+        contact_template_variables, preview_template, render_template, template_variables,
+    };
+
+    #[test]
+    fn renders_contact_variables_and_reports_missing_ones() {
+        let contact = Contact::builder()
+            .id(uuid::Uuid::now_v7())
+            .user_id(uuid::Uuid::now_v7())
+            .organization_id(uuid::Uuid::now_v7())
+            .name("Ada King Lovelace".to_owned())
+            .custom_fields(BTreeMap::from([(
+                "Account ID".to_owned(),
+                "A-1".to_owned(),
+            )]))
+            .created_at(OffsetDateTime::now_utc())
+            .updated_at(OffsetDateTime::now_utc())
+            .build();
+        let variables = contact_template_variables(Some(&contact), "+15551234567");
+
+        assert_eq!(
+            render_template(
+                "Hi {{ First_Name }} {{last_name}}, account {{account_id}} ({{phone_number}})",
+                &variables
+            ),
+            Ok("Hi Ada King Lovelace, account A-1 (+15551234567)".to_owned())
+        );
+        assert_eq!(
+            render_template("{{email}} {{ unknown }} {{email}} {{", &variables),
+            Err(vec!["email".to_owned(), "unknown".to_owned()])
+        );
+        assert_eq!(
+            render_template(
+                "Hello {{name}}",
+                &contact_template_variables(None, "+15551234567")
+            ),
+            Err(vec!["name".to_owned()])
+        );
+    }
+
+    #[test]
+    fn previews_keep_unresolved_placeholders() {
+        let variables = contact_template_variables(None, "+15551234567");
+
+        assert_eq!(
+            preview_template("Hi {{ name }}, this is {{phone_number}} {{", &variables),
+            (
+                "Hi {{ name }}, this is +15551234567 {{".to_owned(),
+                vec!["name".to_owned()]
+            )
+        );
+        assert_eq!(
+            template_variables("{{First Name}} {{phone_number}} {{ first name }}"),
+            vec!["first_name".to_owned(), "phone_number".to_owned()]
+        );
+    }
+}
@@ -1,6 +1,20 @@
+pub mod auto_reply_rule;
+pub mod business_hours;
+pub mod campaign;
+pub mod contact;
+pub mod contact_import;
 pub mod conversation;
 pub mod message;
+pub mod message_attachment;
+pub mod message_status_event;
+pub mod message_template;
+pub mod money;
+pub mod opt_out;
+pub mod organization;
+pub mod outbound_message_job;
 pub mod phone_number;
 pub mod processed_webhook_event;
 pub mod reset_password;
+pub mod sms_encoding;
+pub mod usage;
 pub mod user;
@@ -0,0 +1,69 @@
+/// Amounts are kept in millionths of the currency unit, so per-segment prices such as
+/// `0.0045` add up without rounding.
+pub const MICROS_PER_UNIT: i64 = 1_000_000;
+
+/// Parses a decimal amount like `0.0045` or `-1.5` into micros. Digits past the sixth
+/// decimal place are rejected rather than rounded.
+pub fn parse_micros(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || fraction.len() > 6
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<i64>().ok()?
+    };
+    let fraction = format!("{:0<6}", fraction).parse::<i64>().ok()?;
+    let micros = whole.checked_mul(MICROS_PER_UNIT)?.checked_add(fraction)?;
+
+    Some(if negative { -micros } else { micros })
+}
+
+/// Formats micros as a decimal amount with at least two decimal places, e.g. `0.0045` or
+/// `12.30`.
+pub fn format_micros(micros: i64) -> String {
+    let sign = if micros < 0 { "-" } else { "" };
+    let micros = micros.unsigned_abs();
+    let fraction = format!("{:06}", micros % MICROS_PER_UNIT as u64);
+    let fraction = fraction.trim_end_matches('0');
+
+    format!(
+        "{}{}.{:0<2}",
+        sign,
+        micros / MICROS_PER_UNIT as u64,
+        fraction
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_micros, parse_micros};
+
+    #[test]
+    fn parses_and_formats_decimal_amounts() {
+        assert_eq!(parse_micros("0.0045"), Some(4_500));
+        assert_eq!(parse_micros(" 12.3 "), Some(12_300_000));
+        assert_eq!(parse_micros(".5"), Some(500_000));
+        assert_eq!(parse_micros("-1"), Some(-1_000_000));
+        assert_eq!(parse_micros("0.0000001"), None);
+        assert_eq!(parse_micros("1e3"), None);
+        assert_eq!(parse_micros("."), None);
+
+        assert_eq!(format_micros(4_500), "0.0045");
+        assert_eq!(format_micros(12_300_000), "12.30");
+        assert_eq!(format_micros(-1_000_000), "-1.00");
+    }
+}
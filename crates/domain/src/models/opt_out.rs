@@ -0,0 +1,75 @@
+use time::OffsetDateTime;
+
+/// A recipient who texted a stop keyword to one of the user's numbers. Carriers require that
+/// no further messages are sent from that number until the recipient opts back in.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct OptOut {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub phone_number_id: uuid::Uuid,
+    pub recipient_phone_number: String,
+    /// The keyword that triggered the opt-out, e.g. `STOP`.
+    pub keyword: String,
+    pub created_at: OffsetDateTime,
+}
+
+/// Carrier compliance keywords recognised in inbound messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplianceKeyword {
+    OptOut,
+    OptIn,
+    Help,
+}
+
+impl ComplianceKeyword {
+    /// Matches a message consisting of a single keyword, ignoring case, surrounding
+    /// whitespace and trailing punctuation. Keywords inside longer texts do not count.
+    pub fn detect(text: &str) -> Option<Self> {
+        let word = text
+            .trim()
+            .trim_end_matches(['.', '!', '?'])
+            .to_ascii_uppercase();
+
+        match word.as_str() {
+            "STOP" | "UNSUBSCRIBE" | "CANCEL" => Some(Self::OptOut),
+            "START" | "UNSTOP" => Some(Self::OptIn),
+            "HELP" => Some(Self::Help),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComplianceKeyword;
+
+    #[test]
+    fn detects_standalone_keywords_only() {
+        assert_eq!(
+            ComplianceKeyword::detect(" stop "),
+            Some(ComplianceKeyword::OptOut)
+        );
+        assert_eq!(
+            ComplianceKeyword::detect("Unsubscribe!"),
+            Some(ComplianceKeyword::OptOut)
+        );
+        assert_eq!(
+            ComplianceKeyword::detect("CANCEL"),
+            Some(ComplianceKeyword::OptOut)
+        );
+        assert_eq!(
+            ComplianceKeyword::detect("unstop"),
+            Some(ComplianceKeyword::OptIn)
+        );
+        assert_eq!(
+            ComplianceKeyword::detect("Start."),
+            Some(ComplianceKeyword::OptIn)
+        );
+        assert_eq!(
+            ComplianceKeyword::detect("help?"),
+            Some(ComplianceKeyword::Help)
+        );
+        assert_eq!(ComplianceKeyword::detect("please stop texting me"), None);
+        assert_eq!(ComplianceKeyword::detect(""), None);
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::models::conversation::ConversationRouting;
+
+/// What a member may do in an organization, from most to least privileged. Every
+/// organization keeps at least one owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationRole {
+    Owner,
+    Admin,
+    Agent,
+    ReadOnly,
+}
+
+/// Actions gated by [`OrganizationRole`]. Reading the shared inboxes needs no permission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Start conversations, send, schedule and cancel messages, and run campaigns.
+    SendMessages,
+    /// Assign, reassign and unassign conversations.
+    AssignConversations,
+    /// Archive, pin and snooze conversations.
+    TriageConversations,
+    /// Add, edit, import and delete contacts in the shared address book.
+    ManageContacts,
+    /// Add and delete numbers and edit their auto-replies and business hours.
+    ManagePhoneNumbers,
+    /// Delete conversations along with their messages.
+    DeleteConversations,
+    /// Invite and remove members and change roles other than owner.
+    ManageMembers,
+    /// Grant, take away or remove the owner role.
+    ManageOwners,
+    /// Choose how unassigned conversations are routed.
+    ManageRouting,
+}
+
+impl OrganizationRole {
+    pub fn can(self, permission: Permission) -> bool {
+        use OrganizationRole::*;
+
+        match permission {
+            Permission::SendMessages
+            | Permission::AssignConversations
+            | Permission::TriageConversations
+            | Permission::ManageContacts => {
+                matches!(self, Owner | Admin | Agent)
+            }
+            Permission::ManagePhoneNumbers
+            | Permission::DeleteConversations
+            | Permission::ManageMembers
+            | Permission::ManageRouting => matches!(self, Owner | Admin),
+            Permission::ManageOwners => self == Owner,
+        }
+    }
+}
+
+/// A workspace that owns phone numbers and their conversations. Every user gets a personal
+/// organization at signup and can be invited into others.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct Organization {
+    pub id: uuid::Uuid,
+    pub name: String,
+    #[builder(default)]
+    pub conversation_routing: ConversationRouting,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, bon::Builder)]
+pub struct OrganizationMember {
+    pub organization_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub role: OrganizationRole,
+    /// Filled in when members are listed.
+    pub email: Option<String>,
+    pub created_at: OffsetDateTime,
+}
+
+/// An emailed invitation to join an organization. The link carries a signed token naming
+/// the invitation, so nothing secret is stored here.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct OrganizationInvitation {
+    pub id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+    pub email: String,
+    /// Role the member gets on accepting.
+    pub role: OrganizationRole,
+    pub invited_by: uuid::Uuid,
+    pub expires_at: OffsetDateTime,
+    pub accepted_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl OrganizationInvitation {
+    pub fn is_pending(&self, now: OffsetDateTime) -> bool {
+        self.accepted_at.is_none() && self.expires_at > now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrganizationRole, Permission};
+
+    const ROLES: [OrganizationRole; 4] = [
+        OrganizationRole::Owner,
+        OrganizationRole::Admin,
+        OrganizationRole::Agent,
+        OrganizationRole::ReadOnly,
+    ];
+
+    const PERMISSIONS: [Permission; 9] = [
+        Permission::SendMessages,
+        Permission::AssignConversations,
+        Permission::TriageConversations,
+        Permission::ManageContacts,
+        Permission::ManagePhoneNumbers,
+        Permission::DeleteConversations,
+        Permission::ManageMembers,
+        Permission::ManageOwners,
+        Permission::ManageRouting,
+    ];
+
+    fn granted(role: OrganizationRole, permission: Permission) -> bool {
+        use OrganizationRole::*;
+        use Permission::*;
+
+        matches!(
+            (role, permission),
+            (Owner, _)
+                | (
+                    Admin,
+                    SendMessages
+                        | AssignConversations
+                        | TriageConversations
+                        | ManageContacts
+                        | ManagePhoneNumbers
+                        | DeleteConversations
+                        | ManageMembers
+                        | ManageRouting
+                )
+                | (
+                    Agent,
+                    SendMessages | AssignConversations | TriageConversations | ManageContacts
+                )
+        )
+    }
+
+    #[test]
+    fn every_role_has_exactly_its_permissions() {
+        for role in ROLES {
+            for permission in PERMISSIONS {
+                assert_eq!(
+                    role.can(permission),
+                    granted(role, permission),
+                    "{:?} {:?}",
+                    role,
+                    permission
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use time::OffsetDateTime;
+
+/// A pending outbound message waiting to be handed to the messaging provider.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct OutboundMessageJob {
+    pub message_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub to_number: String,
+    #[builder(default)]
+    pub attempts: i32,
+    pub next_attempt_at: OffsetDateTime,
+    pub last_error: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
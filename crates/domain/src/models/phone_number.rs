@@ -3,6 +3,8 @@ use time::OffsetDateTime;
 #[derive(Debug, Clone, bon::Builder)]
 pub struct PhoneNumber {
     pub id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+    /// Who added the number.
     pub user_id: uuid::Uuid,
     pub name: String,
     pub phone: String,
@@ -1,5 +1,17 @@
 use time::OffsetDateTime;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookEventStatus {
+    /// Stored in the inbox and waiting for the worker.
+    Pending,
+    Processed,
+    /// Delivery status for a provider message id we have not stored yet. Picked up again
+    /// once the message exists, or when `next_attempt_at` passes.
+    Parked,
+    /// Gave up after a permanent error or too many attempts; can be replayed manually.
+    DeadLettered,
+}
+
 #[derive(Debug, Clone, bon::Builder)]
 pub struct ProcessedWebhookEvent {
     pub event_id: String,
@@ -7,5 +19,12 @@ pub struct ProcessedWebhookEvent {
     pub provider_message_id: Option<String>,
     pub occurred_at: OffsetDateTime,
     pub payload_json: serde_json::Value,
+    pub status: WebhookEventStatus,
+    #[builder(default)]
+    pub attempts: i32,
+    pub next_attempt_at: OffsetDateTime,
+    pub last_error: Option<String>,
+    pub processed_at: Option<OffsetDateTime>,
     pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
 }
@@ -0,0 +1,265 @@
+/// Character set an SMS body is sent in. Bodies made only of GSM 03.38 characters use
+/// 7-bit `Gsm7`, anything else forces the whole message into 16-bit `Ucs2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmsEncoding {
+    Gsm7,
+    Ucs2,
+}
+
+/// How a body is split into SMS segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentCount {
+    pub encoding: SmsEncoding,
+    /// Septets for `Gsm7` (extension characters take two), UTF-16 code units for `Ucs2`.
+    pub units: usize,
+    pub segments: usize,
+}
+
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+                          ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+const GSM7_EXTENSION: &str = "\u{0C}^{}\\[~]|€";
+
+const GSM7_SINGLE_SEGMENT: usize = 160;
+const GSM7_MULTI_SEGMENT: usize = 153;
+const UCS2_SINGLE_SEGMENT: usize = 70;
+const UCS2_MULTI_SEGMENT: usize = 67;
+
+/// Septets `c` takes in GSM-7, or `None` when it has no GSM-7 representation.
+pub fn gsm7_septets(c: char) -> Option<usize> {
+    if GSM7_BASIC.contains(c) {
+        Some(1)
+    } else if GSM7_EXTENSION.contains(c) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Counts the segments `body` is sent as. Multi-part messages lose room to the
+/// concatenation header, and an extension character or surrogate pair is never split
+/// across two segments.
+pub fn count_segments(body: &str) -> SegmentCount {
+    let gsm7 = body.chars().map(gsm7_septets).collect::<Option<Vec<_>>>();
+    let (encoding, unit_sizes, single, multi) = match gsm7 {
+        Some(septets) => (
+            SmsEncoding::Gsm7,
+            septets,
+            GSM7_SINGLE_SEGMENT,
+            GSM7_MULTI_SEGMENT,
+        ),
+        None => (
+            SmsEncoding::Ucs2,
+            body.chars().map(char::len_utf16).collect(),
+            UCS2_SINGLE_SEGMENT,
+            UCS2_MULTI_SEGMENT,
+        ),
+    };
+
+    let units = unit_sizes.iter().sum::<usize>();
+    let segments = if units == 0 {
+        0
+    } else if units <= single {
+        1
+    } else {
+        let mut segments = 1;
+        let mut used = 0;
+        for size in unit_sizes {
+            if used + size > multi {
+                segments += 1;
+                used = 0;
+            }
+            used += size;
+        }
+        segments
+    };
+
+    SegmentCount {
+        encoding,
+        units,
+        segments,
+    }
+}
+
+/// GSM-7 stand-ins for characters that commonly force a body into UCS-2: typographic
+/// punctuation pasted from word processors, unusual spaces, invisible joiners and a few
+/// emoji with a well-known emoticon.
+const GSM7_REPLACEMENTS: &[(char, &str)] = &[
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', "'"),
+    ('\u{201B}', "'"),
+    ('\u{2032}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{201F}', "\""),
+    ('\u{2033}', "\""),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('\u{2212}', "-"),
+    ('\u{2026}', "..."),
+    ('\u{2022}', "*"),
+    ('\u{00AB}', "<<"),
+    ('\u{00BB}', ">>"),
+    ('\u{00A0}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2009}', " "),
+    ('\u{202F}', " "),
+    ('\u{00AD}', ""),
+    ('\u{200B}', ""),
+    ('\u{200D}', ""),
+    ('\u{FE0F}', ""),
+    ('\u{2122}', "TM"),
+    ('\u{00A9}', "(c)"),
+    ('\u{00AE}', "(R)"),
+    ('\u{1F642}', ":)"),
+    ('\u{1F60A}', ":)"),
+    ('\u{263A}', ":)"),
+    ('\u{1F600}', ":D"),
+    ('\u{1F603}', ":D"),
+    ('\u{1F604}', ":D"),
+    ('\u{1F601}', ":D"),
+    ('\u{1F609}', ";)"),
+    ('\u{1F641}', ":("),
+    ('\u{2639}', ":("),
+    ('\u{1F61E}', ":("),
+    ('\u{1F622}', ":'("),
+    ('\u{1F61B}', ":P"),
+    ('\u{1F61C}', ";P"),
+    ('\u{1F62E}', ":O"),
+    ('\u{2764}', "<3"),
+    ('\u{1F44D}', "(y)"),
+];
+
+/// A character that keeps a body out of GSM-7, with its stand-in when there is one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gsm7Replacement {
+    pub character: char,
+    pub replacement: Option<&'static str>,
+    pub occurrences: usize,
+}
+
+/// Segment count of a body, plus what it would take to send it in GSM-7 instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmsAnalysis {
+    pub count: SegmentCount,
+    /// Every non-GSM-7 character in order of first appearance. Empty for `Gsm7` bodies.
+    pub replacements: Vec<Gsm7Replacement>,
+    /// The body with every stand-in applied, when that is enough to make it GSM-7.
+    pub suggested_body: Option<String>,
+    pub suggested_count: Option<SegmentCount>,
+}
+
+pub fn gsm7_replacement(c: char) -> Option<&'static str> {
+    GSM7_REPLACEMENTS
+        .iter()
+        .find(|(character, _)| *character == c)
+        .map(|(_, replacement)| *replacement)
+}
+
+pub fn analyze(body: &str) -> SmsAnalysis {
+    let count = count_segments(body);
+    if count.encoding == SmsEncoding::Gsm7 {
+        return SmsAnalysis {
+            count,
+            replacements: Vec::new(),
+            suggested_body: None,
+            suggested_count: None,
+        };
+    }
+
+    let mut replacements: Vec<Gsm7Replacement> = Vec::new();
+    for c in body.chars().filter(|c| gsm7_septets(*c).is_none()) {
+        match replacements.iter_mut().find(|entry| entry.character == c) {
+            Some(entry) => entry.occurrences += 1,
+            None => replacements.push(Gsm7Replacement {
+                character: c,
+                replacement: gsm7_replacement(c),
+                occurrences: 1,
+            }),
+        }
+    }
+
+    let suggested_body = replacements
+        .iter()
+        .all(|entry| entry.replacement.is_some())
+        .then(|| {
+            body.chars()
+                .map(|c| match gsm7_replacement(c) {
+                    Some(replacement) if gsm7_septets(c).is_none() => replacement.to_owned(),
+                    _ => c.to_string(),
+                })
+                .collect::<String>()
+        });
+    let suggested_count = suggested_body.as_deref().map(count_segments);
+
+    SmsAnalysis {
+        count,
+        replacements,
+        suggested_body,
+        suggested_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SegmentCount, SmsEncoding, analyze, count_segments};
+
+    #[test]
+    fn counts_gsm7_and_ucs2_segments() {
+        assert_eq!(
+            count_segments(""),
+            SegmentCount {
+                encoding: SmsEncoding::Gsm7,
+                units: 0,
+                segments: 0,
+            }
+        );
+        assert_eq!(count_segments(&"a".repeat(160)).segments, 1);
+        assert_eq!(count_segments(&"a".repeat(161)).segments, 2);
+        assert_eq!(count_segments(&"a".repeat(306)).segments, 2);
+        assert_eq!(count_segments(&"a".repeat(307)).segments, 3);
+
+        let euro = count_segments(&"€".repeat(80));
+        assert_eq!(euro.encoding, SmsEncoding::Gsm7);
+        assert_eq!(euro.units, 160);
+        assert_eq!(euro.segments, 1);
+        // 306 septets would fit two parts, but the escaped character cannot take the one
+        // slot left after the first 152 and pushes the rest into a third.
+        assert_eq!(
+            count_segments(&format!("{}€{}", "a".repeat(152), "a".repeat(152))).segments,
+            3
+        );
+
+        let emoji = count_segments("Hi 👋");
+        assert_eq!(emoji.encoding, SmsEncoding::Ucs2);
+        assert_eq!(emoji.units, 5);
+        assert_eq!(emoji.segments, 1);
+        assert_eq!(count_segments(&"ж".repeat(70)).segments, 1);
+        assert_eq!(count_segments(&"ж".repeat(71)).segments, 2);
+    }
+
+    #[test]
+    fn suggests_gsm7_stand_ins_for_smart_quotes_and_emoji() {
+        let analysis = analyze("\u{201C}Thanks\u{201D} \u{2014} see you soon \u{1F642}");
+        assert_eq!(analysis.count.encoding, SmsEncoding::Ucs2);
+        assert_eq!(analysis.replacements.len(), 4);
+        assert_eq!(analysis.replacements[0].occurrences, 1);
+        assert_eq!(
+            analysis.suggested_body.as_deref(),
+            Some("\"Thanks\" - see you soon :)")
+        );
+        assert_eq!(
+            analysis.suggested_count.map(|count| count.encoding),
+            Some(SmsEncoding::Gsm7)
+        );
+
+        let unsupported = analyze("\u{201C}Hi\u{201D} \u{0436}");
+        assert_eq!(unsupported.replacements[2].replacement, None);
+        assert_eq!(unsupported.suggested_body, None);
+
+        assert!(analyze("plain text").replacements.is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+use time::{Date, Month, OffsetDateTime};
+
+/// Window that usage is rolled up and capped over. Periods follow UTC calendar days and
+/// months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UsagePeriod {
+    Daily,
+    Monthly,
+}
+
+impl UsagePeriod {
+    pub const ALL: [UsagePeriod; 2] = [UsagePeriod::Daily, UsagePeriod::Monthly];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            UsagePeriod::Daily => "daily",
+            UsagePeriod::Monthly => "monthly",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "daily" | "day" => Some(UsagePeriod::Daily),
+            "monthly" | "month" => Some(UsagePeriod::Monthly),
+            _ => None,
+        }
+    }
+
+    /// Start of the period `at` falls in.
+    pub fn start(self, at: OffsetDateTime) -> OffsetDateTime {
+        let date = at.to_offset(time::UtcOffset::UTC).date();
+        let date = match self {
+            UsagePeriod::Daily => date,
+            UsagePeriod::Monthly => date.replace_day(1).unwrap_or(date),
+        };
+
+        date.midnight().assume_utc()
+    }
+
+    /// Start of the period after the one `at` falls in, i.e. when caps reset.
+    pub fn next_start(self, at: OffsetDateTime) -> OffsetDateTime {
+        let start = self.start(at);
+        match self {
+            UsagePeriod::Daily => start + time::Duration::days(1),
+            UsagePeriod::Monthly => {
+                let (year, month) = match start.month() {
+                    Month::December => (start.year() + 1, Month::January),
+                    month => (start.year(), month.next()),
+                };
+                Date::from_calendar_date(year, month, 1)
+                    .map(|date| date.midnight().assume_utc())
+                    .unwrap_or(start)
+            }
+        }
+    }
+}
+
+/// One outbound message in the usage ledger.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct UsageRecord {
+    pub message_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub phone_number_id: uuid::Uuid,
+    pub segments: i32,
+    /// Cost in micros, see `crate::models::money`.
+    pub cost_micros: i64,
+    /// Whether `cost_micros` is still the estimate made when the message was sent rather
+    /// than the cost Telnyx reported.
+    pub cost_estimated: bool,
+    pub recorded_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsageTotals {
+    pub messages: i64,
+    pub segments: i64,
+    pub cost_micros: i64,
+}
+
+impl UsageTotals {
+    pub fn add(&mut self, other: &UsageTotals) {
+        self.messages += other.messages;
+        self.segments += other.segments;
+        self.cost_micros += other.cost_micros;
+    }
+}
+
+/// Usage of one phone number over one period.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageRollup {
+    pub period_start: OffsetDateTime,
+    pub phone_number_id: uuid::Uuid,
+    pub totals: UsageTotals,
+}
+
+/// Spending caps of a user over a period. Crossing the soft limit sends one notification
+/// per period; reaching the hard limit stops new messages until the period resets.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct UsageLimit {
+    pub user_id: uuid::Uuid,
+    pub period: UsagePeriod,
+    pub soft_limit_micros: Option<i64>,
+    pub hard_limit_micros: Option<i64>,
+    /// When the soft limit notification last went out.
+    pub soft_limit_notified_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl UsageLimit {
+    pub fn soft_limit_reached(&self, spent_micros: i64) -> bool {
+        self.soft_limit_micros
+            .is_some_and(|limit| spent_micros >= limit)
+    }
+
+    pub fn hard_limit_reached(&self, spent_micros: i64) -> bool {
+        self.hard_limit_micros
+            .is_some_and(|limit| spent_micros >= limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, OffsetDateTime, UtcOffset};
+
+    use super::UsagePeriod;
+
+    fn utc(year: i32, month: Month, day: u8, hour: u8) -> OffsetDateTime {
+        Date::from_calendar_date(year, month, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn periods_follow_utc_days_and_months() {
+        // 20:00 on the 31st in New York is already January 1st in UTC.
+        let at = utc(2027, Month::January, 1, 1).to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+
+        assert_eq!(
+            UsagePeriod::Daily.start(at),
+            utc(2027, Month::January, 1, 0)
+        );
+        assert_eq!(
+            UsagePeriod::Daily.next_start(at),
+            utc(2027, Month::January, 2, 0)
+        );
+        assert_eq!(
+            UsagePeriod::Monthly.start(utc(2026, Month::December, 31, 23)),
+            utc(2026, Month::December, 1, 0)
+        );
+        assert_eq!(
+            UsagePeriod::Monthly.next_start(utc(2026, Month::December, 31, 23)),
+            utc(2027, Month::January, 1, 0)
+        );
+        assert_eq!(UsagePeriod::parse("Monthly"), Some(UsagePeriod::Monthly));
+        assert_eq!(UsagePeriod::parse("weekly"), None);
+    }
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{models::auto_reply_rule::AutoReplyRule, repositories::RepositoryError};
+
+#[async_trait]
+pub trait AutoReplyRuleRepository: Send + Sync + 'static {
+    async fn create_rule(&self, rule: &AutoReplyRule) -> Result<(), RepositoryError>;
+    async fn update_rule(&self, rule: &AutoReplyRule) -> Result<(), RepositoryError>;
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<AutoReplyRule, RepositoryError>;
+    /// Rules of a phone number in the order they are tried, oldest first.
+    async fn list_by_phone_number_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_number_id: &uuid::Uuid,
+    ) -> Result<Vec<AutoReplyRule>, RepositoryError>;
+    async fn delete_rule(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError>;
+    /// Records a reply of `rule` to `recipient_phone_number` at `now`. Returns `false`, without
+    /// recording anything, while the previous reply is still within the rule's cooldown.
+    async fn claim_reply(
+        &self,
+        rule: &AutoReplyRule,
+        recipient_phone_number: &str,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
+}
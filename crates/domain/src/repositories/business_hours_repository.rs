@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+
+use crate::{models::business_hours::BusinessHours, repositories::RepositoryError};
+
+#[async_trait]
+pub trait BusinessHoursRepository: Send + Sync + 'static {
+    async fn create_business_hours(
+        &self,
+        business_hours: &BusinessHours,
+    ) -> Result<(), RepositoryError>;
+    async fn update_business_hours(
+        &self,
+        business_hours: &BusinessHours,
+    ) -> Result<(), RepositoryError>;
+    async fn find_by_phone_number_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_number_id: &uuid::Uuid,
+    ) -> Result<BusinessHours, RepositoryError>;
+    async fn delete_business_hours(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_number_id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError>;
+}
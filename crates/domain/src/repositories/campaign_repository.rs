@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{
+    models::campaign::{Campaign, CampaignProgress, CampaignRecipient, CampaignStatus},
+    repositories::RepositoryError,
+};
+
+#[async_trait]
+pub trait CampaignRepository: Send + Sync + 'static {
+    /// Stores the campaign together with its recipients, all or nothing.
+    async fn create_campaign(
+        &self,
+        campaign: &Campaign,
+        recipients: &[CampaignRecipient],
+    ) -> Result<(), RepositoryError>;
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<Campaign, RepositoryError>;
+    async fn list_by_organization_id(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<Campaign>, RepositoryError>;
+    /// Moves the campaign from `from` to `to`. Returns `false` when it is no longer in
+    /// `from`, e.g. because another request or the worker changed it first.
+    async fn change_status(
+        &self,
+        id: &uuid::Uuid,
+        from: CampaignStatus,
+        to: CampaignStatus,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
+    /// Pauses a running campaign and records why. Returns `false` when it is no longer
+    /// running.
+    async fn pause(
+        &self,
+        id: &uuid::Uuid,
+        reason: &str,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
+    /// Leases up to `limit` running campaigns whose next batch is due until `locked_until`.
+    async fn claim_due(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+        limit: usize,
+    ) -> Result<Vec<Campaign>, RepositoryError>;
+    /// Releases the lease and schedules the next batch.
+    async fn schedule_next_batch(
+        &self,
+        id: &uuid::Uuid,
+        next_batch_at: OffsetDateTime,
+    ) -> Result<(), RepositoryError>;
+    async fn list_pending_recipients(
+        &self,
+        campaign_id: &uuid::Uuid,
+        limit: usize,
+    ) -> Result<Vec<CampaignRecipient>, RepositoryError>;
+    async fn update_recipient(&self, recipient: &CampaignRecipient) -> Result<(), RepositoryError>;
+    async fn progress(&self, campaign_id: &uuid::Uuid)
+    -> Result<CampaignProgress, RepositoryError>;
+}
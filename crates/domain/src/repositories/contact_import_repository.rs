@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{models::contact_import::ContactImport, repositories::RepositoryError};
+
+#[async_trait]
+pub trait ContactImportRepository: Send + Sync + 'static {
+    async fn create_import(&self, import: &ContactImport) -> Result<(), RepositoryError>;
+    /// Stores the outcome of processing, clearing any lease.
+    async fn update_import(&self, import: &ContactImport) -> Result<(), RepositoryError>;
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<ContactImport, RepositoryError>;
+    /// Leases the oldest pending import until `locked_until` and bumps its attempt count.
+    /// Imports leased by another worker are skipped until their lease expires.
+    async fn claim_pending(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+    ) -> Result<Option<ContactImport>, RepositoryError>;
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+
+use crate::{models::contact::Contact, repositories::RepositoryError};
+
+#[async_trait]
+pub trait ContactRepository: Send + Sync + 'static {
+    /// Fails with `ConstraintViolation` when one of the numbers already belongs to another
+    /// contact of the same organization.
+    async fn create_contact(&self, contact: &Contact) -> Result<(), RepositoryError>;
+    async fn update_contact(&self, contact: &Contact) -> Result<(), RepositoryError>;
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<Contact, RepositoryError>;
+    async fn list_by_organization_id(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<Contact>, RepositoryError>;
+    /// Returns the contacts owning any of the given numbers.
+    async fn find_by_phone_numbers(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_numbers: &[String],
+    ) -> Result<Vec<Contact>, RepositoryError>;
+    async fn delete_contact(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError>;
+}
@@ -1,31 +1,114 @@
 use async_trait::async_trait;
+use time::OffsetDateTime;
 
-use crate::{models::conversation::Conversation, repositories::RepositoryError};
+use crate::{
+    models::conversation::{
+        AssigneeLoad, Conversation, ConversationFilter, ConversationStateChange, ConversationView,
+        UnreadCount,
+    },
+    repositories::RepositoryError,
+};
 
 #[async_trait]
 pub trait ConversationRepository: Send + Sync + 'static {
     async fn create_conversation(&self, conversation: &Conversation)
     -> Result<(), RepositoryError>;
-    async fn update_conversation(&self, conversation: &Conversation)
-    -> Result<(), RepositoryError>;
     async fn find_by_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<Conversation, RepositoryError>;
     async fn find_by_phone_number_and_recipient(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         phone_number_id: &uuid::Uuid,
         recipient_phone_number: &str,
     ) -> Result<Conversation, RepositoryError>;
-    async fn list_by_user_id(
+    async fn list_by_organization_id(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<Conversation>, RepositoryError>;
+    /// The conversations `user_id` sees in one view and filter, with pinned conversations
+    /// first, then the most recently active.
+    async fn list_filtered(
         &self,
+        organization_id: &uuid::Uuid,
         user_id: &uuid::Uuid,
+        view: ConversationView,
+        filter: ConversationFilter,
+        now: OffsetDateTime,
     ) -> Result<Vec<Conversation>, RepositoryError>;
-    async fn delete_conversation(
+    /// Archives, pins or snoozes the conversation, or undoes it, and returns the stored
+    /// conversation. Only the changed state is written, so the others are kept.
+    async fn change_state(
+        &self,
+        conversation: &Conversation,
+        change: ConversationStateChange,
+        now: OffsetDateTime,
+    ) -> Result<Conversation, RepositoryError>;
+    /// Moves `last_message_at` and `updated_at` forward, never back, and returns the stored
+    /// conversation. Only those columns are written, so assignment and state changes made
+    /// meanwhile are kept.
+    async fn record_activity(
+        &self,
+        conversation: &Conversation,
+        last_message_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Conversation, RepositoryError>;
+    /// Brings an archived or snoozed conversation back to the inbox, see
+    /// [`Conversation::reopen`].
+    async fn reopen(&self, conversation: &Conversation) -> Result<(), RepositoryError>;
+    /// Assigns the conversation to `assigned_user_id`, or unassigns it with `None`, and
+    /// returns the stored conversation.
+    async fn assign(
         &self,
+        conversation: &Conversation,
+        assigned_user_id: Option<uuid::Uuid>,
+        now: OffsetDateTime,
+    ) -> Result<Conversation, RepositoryError>;
+    /// Assigns the conversation only while nobody has it. Returns `false`, without changing
+    /// anything, when it was assigned in the meantime.
+    async fn claim_assignment(
+        &self,
+        conversation: &Conversation,
+        assigned_user_id: &uuid::Uuid,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
+    /// Assigned conversation count and latest assignment per member, for routing.
+    async fn list_assignee_loads(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<AssigneeLoad>, RepositoryError>;
+    /// Moves the member's read cursor forward to the newest message stored in the
+    /// conversation. The cursor never moves back.
+    async fn mark_read(
+        &self,
+        conversation: &Conversation,
+        user_id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError>;
+    /// Inbound messages stored after the member's read cursor, for conversations that have
+    /// any. Messages are ordered by their uuid v7 id, which is minted when they are stored,
+    /// so a webhook processed late still counts. Without a cursor every inbound message
+    /// counts.
+    async fn list_unread_counts(
+        &self,
+        organization_id: &uuid::Uuid,
         user_id: &uuid::Uuid,
+    ) -> Result<Vec<UnreadCount>, RepositoryError>;
+    /// Clears every snooze that ended by `now` and returns the conversations that woke up.
+    async fn wake_snoozed(&self, now: OffsetDateTime)
+    -> Result<Vec<Conversation>, RepositoryError>;
+    async fn delete_conversation(
+        &self,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<(), RepositoryError>;
+    /// Records an away reply sent at `now`. Returns `false`, without recording anything, when
+    /// the conversation already got one at or after `closed_since`.
+    async fn claim_away_reply(
+        &self,
+        conversation: &Conversation,
+        closed_since: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
 }
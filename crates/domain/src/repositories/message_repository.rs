@@ -1,6 +1,8 @@
 use async_trait::async_trait;
+use time::OffsetDateTime;
 
 use crate::models::message::Message;
+use crate::models::message_attachment::MessageAttachment;
 use crate::repositories::RepositoryError;
 
 #[derive(Debug, Clone)]
@@ -12,22 +14,60 @@ pub struct MessagePage {
 #[async_trait]
 pub trait MessageRepository: Send + Sync + 'static {
     async fn create_message(&self, message: &Message) -> Result<Message, RepositoryError>;
-    async fn count_by_user_id(&self, user_id: &uuid::Uuid) -> Result<u64, RepositoryError>;
+    async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Message, RepositoryError>;
+    async fn count_by_organization_id(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<u64, RepositoryError>;
     async fn find_by_provider_message_id(
         &self,
         provider_message_id: &str,
     ) -> Result<Message, RepositoryError>;
+    /// The automated reply queued for the inbound message `message_id`.
+    async fn find_reply_to(&self, message_id: &uuid::Uuid) -> Result<Message, RepositoryError>;
     async fn list_by_conversation_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         conversation_id: &uuid::Uuid,
     ) -> Result<Vec<Message>, RepositoryError>;
     async fn list_page_by_conversation_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         conversation_id: &uuid::Uuid,
         cursor: Option<&uuid::Uuid>,
         limit: usize,
     ) -> Result<MessagePage, RepositoryError>;
     async fn update_message(&self, message: &Message) -> Result<Message, RepositoryError>;
+    /// Scheduled messages of a conversation, soonest first.
+    async fn list_scheduled_by_conversation_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        conversation_id: &uuid::Uuid,
+    ) -> Result<Vec<Message>, RepositoryError>;
+    /// Moves a message that is still `Scheduled` to `scheduled_at`. Returns `false` when
+    /// the message has already been released for sending.
+    async fn reschedule_message(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+        scheduled_at: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
+    /// Deletes a message that is still `Scheduled`, returning `false` when it was not.
+    async fn delete_scheduled_message(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<bool, RepositoryError>;
+    /// Moves a `Scheduled` message that is due at `now` to `Pending`. Returns `false` when
+    /// it was cancelled, rescheduled or released by someone else in the meantime.
+    async fn release_scheduled_message(
+        &self,
+        id: &uuid::Uuid,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
+    async fn find_attachment_by_id(
+        &self,
+        id: &uuid::Uuid,
+    ) -> Result<MessageAttachment, RepositoryError>;
 }
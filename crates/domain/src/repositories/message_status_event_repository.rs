@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::{models::message_status_event::MessageStatusEvent, repositories::RepositoryError};
+
+#[async_trait]
+pub trait MessageStatusEventRepository: Send + Sync + 'static {
+    /// Appends a transition. Recording the same status at the same instant twice is a no-op,
+    /// so retried webhooks do not duplicate timeline entries.
+    async fn record(&self, event: &MessageStatusEvent) -> Result<(), RepositoryError>;
+
+    /// Timeline entries for the given messages, oldest first. Messages of other
+    /// organizations are left out.
+    async fn list_by_message_ids(
+        &self,
+        organization_id: &uuid::Uuid,
+        message_ids: &[uuid::Uuid],
+    ) -> Result<Vec<MessageStatusEvent>, RepositoryError>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::{models::message_template::MessageTemplate, repositories::RepositoryError};
+
+#[async_trait]
+pub trait MessageTemplateRepository: Send + Sync + 'static {
+    async fn create_template(&self, template: &MessageTemplate) -> Result<(), RepositoryError>;
+    async fn update_template(&self, template: &MessageTemplate) -> Result<(), RepositoryError>;
+    async fn find_by_id(
+        &self,
+        user_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<MessageTemplate, RepositoryError>;
+    async fn list_by_user_id(
+        &self,
+        user_id: &uuid::Uuid,
+    ) -> Result<Vec<MessageTemplate>, RepositoryError>;
+    async fn delete_template(
+        &self,
+        user_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError>;
+}
@@ -1,7 +1,21 @@
+pub mod auto_reply_rule_repository;
+pub mod business_hours_repository;
+pub mod campaign_repository;
+pub mod contact_import_repository;
+pub mod contact_repository;
 pub mod conversation_repository;
 pub mod message_repository;
+pub mod message_status_event_repository;
+pub mod message_template_repository;
+pub mod opt_out_repository;
+pub mod organization_invitation_repository;
+pub mod organization_repository;
+pub mod outbound_message_queue_repository;
 pub mod phone_number_repository;
 pub mod processed_webhook_event_repository;
+pub mod reset_password_repository;
+pub mod usage_limit_repository;
+pub mod usage_repository;
 pub mod user_repository;
 
 #[derive(Debug, thiserror::Error)]
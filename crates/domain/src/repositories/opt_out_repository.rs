@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::{models::opt_out::OptOut, repositories::RepositoryError};
+
+#[async_trait]
+pub trait OptOutRepository: Send + Sync + 'static {
+    /// Records the opt-out. Opting out again while already opted out is a no-op.
+    async fn opt_out(&self, opt_out: &OptOut) -> Result<(), RepositoryError>;
+
+    /// Removes the opt-out, if any.
+    async fn opt_in(
+        &self,
+        phone_number_id: &uuid::Uuid,
+        recipient_phone_number: &str,
+    ) -> Result<(), RepositoryError>;
+
+    async fn is_opted_out(
+        &self,
+        phone_number_id: &uuid::Uuid,
+        recipient_phone_number: &str,
+    ) -> Result<bool, RepositoryError>;
+}
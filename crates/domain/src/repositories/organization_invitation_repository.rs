@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{
+    models::organization::{OrganizationInvitation, OrganizationMember},
+    repositories::RepositoryError,
+};
+
+#[async_trait]
+pub trait OrganizationInvitationRepository: Send + Sync + 'static {
+    async fn create_invitation(
+        &self,
+        invitation: &OrganizationInvitation,
+    ) -> Result<(), RepositoryError>;
+    async fn find_by_id(&self, id: &uuid::Uuid) -> Result<OrganizationInvitation, RepositoryError>;
+    /// Invitations that are neither accepted nor expired at `now`, newest first.
+    async fn list_pending(
+        &self,
+        organization_id: &uuid::Uuid,
+        now: OffsetDateTime,
+    ) -> Result<Vec<OrganizationInvitation>, RepositoryError>;
+    /// Marks the invitation accepted and adds `member` in one transaction. Returns `NotFound`
+    /// when the invitation was already accepted or revoked so a link only works once.
+    async fn accept_invitation(
+        &self,
+        id: &uuid::Uuid,
+        member: &OrganizationMember,
+        now: OffsetDateTime,
+    ) -> Result<(), RepositoryError>;
+    async fn delete_invitation(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError>;
+}
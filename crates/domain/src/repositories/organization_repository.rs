@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{
+    models::{
+        conversation::ConversationRouting,
+        organization::{Organization, OrganizationMember, OrganizationRole},
+    },
+    repositories::RepositoryError,
+};
+
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync + 'static {
+    /// Creates the organization with `owner` as its first member.
+    async fn create_organization(
+        &self,
+        organization: &Organization,
+        owner: &OrganizationMember,
+    ) -> Result<(), RepositoryError>;
+    async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Organization, RepositoryError>;
+    async fn update_conversation_routing(
+        &self,
+        id: &uuid::Uuid,
+        conversation_routing: ConversationRouting,
+        now: OffsetDateTime,
+    ) -> Result<(), RepositoryError>;
+    /// Organizations the user belongs to, oldest membership first.
+    async fn list_by_user_id(
+        &self,
+        user_id: &uuid::Uuid,
+    ) -> Result<Vec<Organization>, RepositoryError>;
+    async fn find_member(
+        &self,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+    ) -> Result<OrganizationMember, RepositoryError>;
+    /// Members with their email, oldest first.
+    async fn list_members(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<OrganizationMember>, RepositoryError>;
+    async fn update_member_role(
+        &self,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+        role: OrganizationRole,
+    ) -> Result<(), RepositoryError>;
+    /// Also unassigns the member's conversations.
+    async fn remove_member(
+        &self,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError>;
+}
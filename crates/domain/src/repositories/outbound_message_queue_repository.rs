@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{models::outbound_message_job::OutboundMessageJob, repositories::RepositoryError};
+
+#[async_trait]
+pub trait OutboundMessageQueueRepository: Send + Sync + 'static {
+    async fn enqueue(&self, job: &OutboundMessageJob) -> Result<(), RepositoryError>;
+    /// Leases up to `limit` due jobs until `locked_until` and bumps their attempt count.
+    /// Jobs leased by another worker are skipped, and a lease that expires without the
+    /// job being completed or rescheduled makes it claimable again.
+    async fn claim_due(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+        limit: usize,
+    ) -> Result<Vec<OutboundMessageJob>, RepositoryError>;
+    async fn reschedule(
+        &self,
+        message_id: &uuid::Uuid,
+        next_attempt_at: OffsetDateTime,
+        last_error: &str,
+    ) -> Result<(), RepositoryError>;
+    /// Moves a job to `next_attempt_at` without counting it as a failed attempt.
+    async fn postpone(
+        &self,
+        message_id: &uuid::Uuid,
+        next_attempt_at: OffsetDateTime,
+    ) -> Result<(), RepositoryError>;
+    async fn complete(&self, message_id: &uuid::Uuid) -> Result<(), RepositoryError>;
+}
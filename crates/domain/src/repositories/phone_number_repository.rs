@@ -7,17 +7,17 @@ pub trait PhoneNumberRepository: Send + Sync + 'static {
     async fn create_phone_number(&self, phone_number: &PhoneNumber) -> Result<(), RepositoryError>;
     async fn find_by_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<PhoneNumber, RepositoryError>;
     async fn find_by_phone(&self, phone: &str) -> Result<PhoneNumber, RepositoryError>;
-    async fn list_by_user_id(
+    async fn list_by_organization_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
     ) -> Result<Vec<PhoneNumber>, RepositoryError>;
     async fn delete_phone_number(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<(), RepositoryError>;
 }
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
+use time::OffsetDateTime;
 
 use crate::{
-    models::processed_webhook_event::ProcessedWebhookEvent, repositories::RepositoryError,
+    models::processed_webhook_event::{ProcessedWebhookEvent, WebhookEventStatus},
+    repositories::RepositoryError,
 };
 
 #[async_trait]
 pub trait ProcessedWebhookEventRepository: Send + Sync + 'static {
+    /// Stores a new event. Fails with `ConstraintViolation` if the event id was already seen.
     async fn create_processed_webhook_event(
         &self,
         event: &ProcessedWebhookEvent,
@@ -15,4 +18,44 @@ pub trait ProcessedWebhookEventRepository: Send + Sync + 'static {
         &self,
         event_id: &str,
     ) -> Result<ProcessedWebhookEvent, RepositoryError>;
+
+    /// Leases up to `limit` pending events that are due, plus parked events whose message now
+    /// exists or whose parking has expired, incrementing their attempt count. Events come back
+    /// ordered by `occurred_at`. Leased events are invisible to other workers until `locked_until`.
+    async fn claim_pending(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+        limit: usize,
+    ) -> Result<Vec<ProcessedWebhookEvent>, RepositoryError>;
+
+    async fn mark_processed(&self, event_id: &str) -> Result<(), RepositoryError>;
+
+    async fn reschedule(
+        &self,
+        event_id: &str,
+        next_attempt_at: OffsetDateTime,
+        last_error: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// Sets the event aside until a message with its provider message id exists, giving up
+    /// at `until`.
+    async fn park(&self, event_id: &str, until: OffsetDateTime) -> Result<(), RepositoryError>;
+
+    async fn mark_dead_lettered(
+        &self,
+        event_id: &str,
+        last_error: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// Most recent events first.
+    async fn list_by_status(
+        &self,
+        status: WebhookEventStatus,
+        limit: usize,
+    ) -> Result<Vec<ProcessedWebhookEvent>, RepositoryError>;
+
+    /// Moves a dead-lettered event back to pending with a fresh attempt budget.
+    /// Returns `NotFound` unless the event exists and is dead-lettered.
+    async fn replay(&self, event_id: &str) -> Result<(), RepositoryError>;
 }
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::{models::reset_password::ResetPassword, repositories::RepositoryError};
+
+#[async_trait]
+pub trait ResetPasswordRepository: Send + Sync + 'static {
+    async fn create_reset_password(
+        &self,
+        reset_password: &ResetPassword,
+    ) -> Result<(), RepositoryError>;
+    async fn find_by_token(&self, token: &str) -> Result<ResetPassword, RepositoryError>;
+    /// Marks an unconsumed token as used. Returns `NotFound` when the token was already
+    /// consumed so concurrent submissions cannot both succeed.
+    async fn consume(&self, id: &uuid::Uuid) -> Result<(), RepositoryError>;
+    async fn consume_all_by_user_id(&self, user_id: &uuid::Uuid) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{
+    models::usage::{UsageLimit, UsagePeriod},
+    repositories::RepositoryError,
+};
+
+#[async_trait]
+pub trait UsageLimitRepository: Send + Sync + 'static {
+    /// Creates or replaces the user's limit for `limit.period`.
+    async fn save_limit(&self, limit: &UsageLimit) -> Result<(), RepositoryError>;
+    async fn list_by_user_id(
+        &self,
+        user_id: &uuid::Uuid,
+    ) -> Result<Vec<UsageLimit>, RepositoryError>;
+    async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError>;
+    async fn delete_limit(
+        &self,
+        user_id: &uuid::Uuid,
+        period: UsagePeriod,
+    ) -> Result<(), RepositoryError>;
+    /// Marks the soft limit as notified unless that already happened since `period_start`.
+    /// Returns whether this call claimed it.
+    async fn claim_soft_limit_notification(
+        &self,
+        user_id: &uuid::Uuid,
+        period: UsagePeriod,
+        period_start: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError>;
+}
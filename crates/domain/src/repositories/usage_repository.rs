@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::{
+    models::usage::{UsagePeriod, UsageRecord, UsageRollup, UsageTotals},
+    repositories::RepositoryError,
+};
+
+#[async_trait]
+pub trait UsageRepository: Send + Sync + 'static {
+    /// Adds a message to the ledger. A message already recorded is left as it is.
+    async fn record_usage(&self, record: &UsageRecord) -> Result<(), RepositoryError>;
+    /// Replaces the estimated cost of a recorded message with the one Telnyx reported.
+    async fn record_cost(
+        &self,
+        message_id: &uuid::Uuid,
+        cost_micros: i64,
+    ) -> Result<(), RepositoryError>;
+    async fn totals_since(
+        &self,
+        user_id: &uuid::Uuid,
+        since: OffsetDateTime,
+    ) -> Result<UsageTotals, RepositoryError>;
+    /// Usage per period and phone number, oldest period first.
+    async fn rollups(
+        &self,
+        user_id: &uuid::Uuid,
+        period: UsagePeriod,
+        since: OffsetDateTime,
+    ) -> Result<Vec<UsageRollup>, RepositoryError>;
+}
@@ -1,10 +1,24 @@
 use async_trait::async_trait;
 
-use crate::{models::user::User, repositories::RepositoryError};
+use crate::{
+    models::{
+        organization::{Organization, OrganizationMember},
+        user::User,
+    },
+    repositories::RepositoryError,
+};
 
 #[async_trait]
 pub trait UserRepository: Send + Sync + 'static {
     async fn create_user(&self, user: &User) -> Result<(), RepositoryError>;
+    /// Creates the user together with its personal organization in one transaction, so a
+    /// signup never leaves an account without an organization behind.
+    async fn create_user_with_organization(
+        &self,
+        user: &User,
+        organization: &Organization,
+        owner: &OrganizationMember,
+    ) -> Result<(), RepositoryError>;
     async fn find_by_id(&self, id: &uuid::Uuid) -> Result<User, RepositoryError>;
     async fn find_by_email(&self, email: &str) -> Result<User, RepositoryError>;
     async fn list(&self, page: u64, limit: u64) -> Result<Vec<User>, RepositoryError>;
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum EmailSenderError {
+    #[error("Email delivery failed: {0}")]
+    Failed(String),
+}
+
+#[async_trait]
+pub trait EmailSender: Send + Sync + 'static {
+    async fn send(&self, email: &EmailMessage) -> Result<(), EmailSenderError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadedMedia {
+    pub bytes: Vec<u8>,
+    pub content_type: Option<String>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MediaDownloadError {
+    #[error("Media download failed: {0}")]
+    Failed(String),
+    #[error("Media exceeds the maximum size of {0} bytes")]
+    TooLarge(usize),
+}
+
+#[async_trait]
+pub trait MediaDownloader: Send + Sync + 'static {
+    async fn download(
+        &self,
+        url: &str,
+        max_bytes: usize,
+    ) -> Result<DownloadedMedia, MediaDownloadError>;
+}
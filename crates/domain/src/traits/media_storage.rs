@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MediaStorageError {
+    #[error("Media object not found")]
+    NotFound,
+    #[error("Invalid media storage key: {0}")]
+    InvalidKey(String),
+    #[error("Media storage failure: {0}")]
+    Io(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredMedia {
+    pub size_bytes: i64,
+    pub checksum_sha256: String,
+}
+
+#[async_trait]
+pub trait MediaStorage: Send + Sync + 'static {
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<StoredMedia, MediaStorageError>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>, MediaStorageError>;
+    async fn delete(&self, key: &str) -> Result<(), MediaStorageError>;
+}
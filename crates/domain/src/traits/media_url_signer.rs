@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use crate::traits::token_service::TokenServiceError;
+
+/// Issues time-limited `FileAccess` links for stored media. `signed_url` is
+/// absolute so the messaging provider can fetch outbound attachments;
+/// `signed_path` is relative and used for in-app previews.
+pub trait MediaUrlSigner: Send + Sync + 'static {
+    fn signed_path(
+        &self,
+        attachment_id: &uuid::Uuid,
+        ttl: Duration,
+    ) -> Result<String, TokenServiceError>;
+    fn signed_url(
+        &self,
+        attachment_id: &uuid::Uuid,
+        ttl: Duration,
+    ) -> Result<String, TokenServiceError>;
+    fn verify(&self, token: &str) -> Result<uuid::Uuid, TokenServiceError>;
+}
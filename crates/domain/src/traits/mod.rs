@@ -1,3 +1,7 @@
+pub mod email_sender;
+pub mod media_downloader;
+pub mod media_storage;
+pub mod media_url_signer;
 pub mod outbound_message_service;
 pub mod password_hasher;
 pub mod token_service;
@@ -5,6 +5,7 @@ pub struct SendMessageRequest {
     pub from: String,
     pub to: String,
     pub text: String,
+    pub media_urls: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -13,6 +13,7 @@ pub enum PasetoClaimPurpose {
     FileUpload,
     FileRequest,
     FileAccess,
+    OrganizationInvitation,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -41,6 +42,7 @@ impl TryFrom<&str> for PasetoClaimPurpose {
             "file_upload" => Ok(Self::FileUpload),
             "file_request" => Ok(Self::FileRequest),
             "file_access" => Ok(Self::FileAccess),
+            "organization_invitation" => Ok(Self::OrganizationInvitation),
             _ => Err(TokenServiceError::TokenInvalid),
         }
     }
@@ -50,23 +52,15 @@ impl TryFrom<&str> for PasetoClaimPurpose {
 pub struct PasetoClaims {
     pub id: uuid::Uuid,
     pub email: String,
-    pub role: String,
     pub exp: Duration,
     pub purpose: PasetoClaimPurpose,
 }
 
 impl PasetoClaims {
-    pub fn new(
-        id: uuid::Uuid,
-        email: String,
-        role: String,
-        exp: Duration,
-        purpose: PasetoClaimPurpose,
-    ) -> Self {
+    pub fn new(id: uuid::Uuid, email: String, exp: Duration, purpose: PasetoClaimPurpose) -> Self {
         Self {
             id,
             email,
-            role,
             exp,
             purpose,
         }
@@ -0,0 +1,64 @@
+use crate::{config::ConfigError, email::smtp_email_sender::SmtpTls};
+
+#[derive(Debug, Clone, bon::Builder)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub tls: SmtpTls,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+}
+
+/// Outgoing email settings. Without `SMTP_HOST` emails are written to `outbox_dir` instead.
+#[derive(Debug, bon::Builder)]
+pub struct EmailConfig {
+    pub smtp: Option<SmtpConfig>,
+    pub outbox_dir: String,
+}
+
+impl EmailConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let outbox_dir =
+            non_empty_var("EMAIL_OUTBOX_DIR").unwrap_or_else(|| "storage/outbox".to_string());
+
+        let Some(host) = non_empty_var("SMTP_HOST") else {
+            return Ok(Self::builder().outbox_dir(outbox_dir).build());
+        };
+
+        let tls = match non_empty_var("SMTP_TLS") {
+            Some(value) => SmtpTls::try_from(value.as_str())
+                .map_err(|_| ConfigError::EnvVarNotValid("SMTP_TLS".to_string()))?,
+            None => SmtpTls::StartTls,
+        };
+        let port = match non_empty_var("SMTP_PORT") {
+            Some(value) => value
+                .parse::<u16>()
+                .map_err(|_| ConfigError::EnvVarNotValid("SMTP_PORT".to_string()))?,
+            None => match tls {
+                SmtpTls::Implicit => 465,
+                SmtpTls::StartTls => 587,
+                SmtpTls::None => 25,
+            },
+        };
+        let from = non_empty_var("SMTP_FROM")
+            .ok_or_else(|| ConfigError::EnvVarNotFound("SMTP_FROM".to_string()))?;
+
+        let smtp = SmtpConfig::builder()
+            .host(host)
+            .port(port)
+            .tls(tls)
+            .maybe_username(non_empty_var("SMTP_USERNAME"))
+            .maybe_password(non_empty_var("SMTP_PASSWORD"))
+            .from(from)
+            .build();
+
+        Ok(Self::builder().smtp(smtp).outbox_dir(outbox_dir).build())
+    }
+}
+
+fn non_empty_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
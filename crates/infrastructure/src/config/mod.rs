@@ -1,4 +1,5 @@
 pub mod database;
+pub mod email;
 pub mod web;
 
 #[derive(Debug, thiserror::Error)]
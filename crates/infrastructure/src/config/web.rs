@@ -1,5 +1,7 @@
 use std::net::Ipv4Addr;
 
+use domain::models::money::parse_micros;
+
 use crate::config::ConfigError;
 
 #[derive(Debug, bon::Builder)]
@@ -13,6 +15,13 @@ pub struct WebConfig {
     pub telnyx_api_base_url: String,
     pub telnyx_public_key: String,
     pub telnyx_webhook_forward_urls: Vec<String>,
+    pub media_storage_dir: String,
+    pub public_base_url: String,
+    pub block_unverified_senders: bool,
+    pub ops_api_token: Option<String>,
+    pub sms_help_reply: Option<String>,
+    /// Price of one SMS segment in millionths of the billing currency.
+    pub sms_segment_price_micros: Option<i64>,
 }
 
 impl WebConfig {
@@ -51,6 +60,39 @@ impl WebConfig {
                     .collect::<Vec<_>>()
             })
             .unwrap_or_default();
+        let media_storage_dir = std::env::var("MEDIA_STORAGE_DIR")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| "storage/media".to_string());
+        let public_base_url = std::env::var("PUBLIC_BASE_URL")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| format!("http://{}:{}", host, port));
+        let block_unverified_senders = std::env::var("BLOCK_UNVERIFIED_SENDERS")
+            .map(|value| {
+                matches!(
+                    value.trim().to_ascii_lowercase().as_str(),
+                    "true" | "1" | "yes"
+                )
+            })
+            .unwrap_or(false);
+        let ops_api_token = std::env::var("OPS_API_TOKEN")
+            .ok()
+            .filter(|value| !value.trim().is_empty());
+        let sms_help_reply = std::env::var("SMS_HELP_REPLY")
+            .ok()
+            .filter(|value| !value.trim().is_empty());
+        let sms_segment_price_micros = match std::env::var("SMS_SEGMENT_PRICE")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+        {
+            Some(value) => Some(
+                parse_micros(&value)
+                    .filter(|price| *price >= 0)
+                    .ok_or_else(|| ConfigError::EnvVarNotValid("SMS_SEGMENT_PRICE".to_string()))?,
+            ),
+            None => None,
+        };
 
         Ok(Self::builder()
             .host(host)
@@ -62,6 +104,12 @@ impl WebConfig {
             .telnyx_api_base_url(telnyx_api_base_url)
             .telnyx_public_key(telnyx_public_key)
             .telnyx_webhook_forward_urls(telnyx_webhook_forward_urls)
+            .media_storage_dir(media_storage_dir)
+            .public_base_url(public_base_url)
+            .block_unverified_senders(block_unverified_senders)
+            .maybe_ops_api_token(ops_api_token)
+            .maybe_sms_help_reply(sms_help_reply)
+            .maybe_sms_segment_price_micros(sms_segment_price_micros)
             .build())
     }
 
@@ -0,0 +1,80 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use domain::models::auto_reply_rule::AutoReplyMatchType;
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct AutoReplyRule {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub match_type: String,
+    pub pattern: String,
+    pub reply_template: String,
+    pub tag: Option<String>,
+    pub cooldown_seconds: i64,
+    pub enabled: bool,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(AutoReplyRule {}, "auto_reply_rules");
+
+impl From<&AutoReplyRule> for domain::models::auto_reply_rule::AutoReplyRule {
+    fn from(value: &AutoReplyRule) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .organization_id(value.organization_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .phone_number_id(value.phone_number_id.into_domain())
+            .match_type(match_type_from_db(&value.match_type))
+            .pattern(value.pattern.to_owned())
+            .reply_template(value.reply_template.to_owned())
+            .maybe_tag(value.tag.to_owned())
+            .cooldown_seconds(value.cooldown_seconds)
+            .enabled(value.enabled)
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::auto_reply_rule::AutoReplyRule> for AutoReplyRule {
+    fn from(value: &domain::models::auto_reply_rule::AutoReplyRule) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .organization_id(value.organization_id.into_db())
+            .user_id(value.user_id.into_db())
+            .phone_number_id(value.phone_number_id.into_db())
+            .match_type(match_type_to_db(value.match_type).to_owned())
+            .pattern(value.pattern.to_owned())
+            .reply_template(value.reply_template.to_owned())
+            .maybe_tag(value.tag.to_owned())
+            .cooldown_seconds(value.cooldown_seconds)
+            .enabled(value.enabled)
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
+
+fn match_type_from_db(value: &str) -> AutoReplyMatchType {
+    match value {
+        "prefix" => AutoReplyMatchType::Prefix,
+        "regex" => AutoReplyMatchType::Regex,
+        _ => AutoReplyMatchType::Exact,
+    }
+}
+
+fn match_type_to_db(value: AutoReplyMatchType) -> &'static str {
+    match value {
+        AutoReplyMatchType::Exact => "exact",
+        AutoReplyMatchType::Prefix => "prefix",
+        AutoReplyMatchType::Regex => "regex",
+    }
+}
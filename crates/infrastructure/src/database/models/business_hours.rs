@@ -0,0 +1,101 @@
+use rbatis::rbdc::{DateTime, Uuid, types::Json};
+use serde::{Deserialize, Serialize};
+use time::Weekday;
+
+use domain::models::business_hours::{
+    OpeningHours, format_time_of_day, parse_date, parse_time_of_day,
+};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct BusinessHours {
+    pub phone_number_id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub timezone: String,
+    pub opening_hours: Json,
+    pub holidays: Json,
+    pub away_message: String,
+    pub enabled: bool,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(BusinessHours {}, "business_hours");
+
+/// An opening as stored in the `opening_hours` column, with the weekday counted from Monday.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredOpeningHours {
+    weekday: u8,
+    opens_at: String,
+    closes_at: String,
+}
+
+impl From<&BusinessHours> for domain::models::business_hours::BusinessHours {
+    fn from(value: &BusinessHours) -> Self {
+        let opening_hours = serde_json::from_str::<Vec<StoredOpeningHours>>(&value.opening_hours.0)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|stored| {
+                Some(OpeningHours {
+                    weekday: Weekday::Monday.nth_next(stored.weekday),
+                    opens_at: parse_time_of_day(&stored.opens_at)?,
+                    closes_at: parse_time_of_day(&stored.closes_at)?,
+                })
+            })
+            .collect();
+        let holidays = serde_json::from_str::<Vec<String>>(&value.holidays.0)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|date| parse_date(date))
+            .collect();
+
+        Self::builder()
+            .phone_number_id(value.phone_number_id.into_domain())
+            .organization_id(value.organization_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .timezone(value.timezone.to_owned())
+            .opening_hours(opening_hours)
+            .holidays(holidays)
+            .away_message(value.away_message.to_owned())
+            .enabled(value.enabled)
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::business_hours::BusinessHours> for BusinessHours {
+    fn from(value: &domain::models::business_hours::BusinessHours) -> Self {
+        let opening_hours = value
+            .opening_hours
+            .iter()
+            .map(|opening| StoredOpeningHours {
+                weekday: opening.weekday.number_days_from_monday(),
+                opens_at: format_time_of_day(opening.opens_at),
+                closes_at: format_time_of_day(opening.closes_at),
+            })
+            .collect::<Vec<_>>();
+        let holidays = value
+            .holidays
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        Self::builder()
+            .phone_number_id(value.phone_number_id.into_db())
+            .organization_id(value.organization_id.into_db())
+            .user_id(value.user_id.into_db())
+            .timezone(value.timezone.to_owned())
+            .opening_hours(serde_json::json!(opening_hours).into())
+            .holidays(serde_json::json!(holidays).into())
+            .away_message(value.away_message.to_owned())
+            .enabled(value.enabled)
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
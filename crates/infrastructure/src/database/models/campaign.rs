@@ -0,0 +1,189 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use domain::models::campaign::{CampaignRecipientStatus, CampaignStatus};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct Campaign {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub name: String,
+    pub body: String,
+    pub tag: Option<String>,
+    pub messages_per_second: i32,
+    pub status: String,
+    pub next_batch_at: DateTime,
+    pub locked_until: Option<DateTime>,
+    pub pause_reason: Option<String>,
+    pub completed_at: Option<DateTime>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(Campaign {}, "campaigns");
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct CampaignRecipient {
+    pub id: Uuid,
+    pub campaign_id: Uuid,
+    pub user_id: Uuid,
+    pub recipient_phone_number: String,
+    pub contact_id: Option<Uuid>,
+    pub status: String,
+    pub content: String,
+    pub message_id: Option<Uuid>,
+    pub reason: Option<String>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(CampaignRecipient {}, "campaign_recipients");
+
+#[derive(Debug, Deserialize)]
+pub struct CampaignProgressRow {
+    pub total: i64,
+    pub pending: i64,
+    pub skipped: i64,
+    pub queued: i64,
+    pub sent: i64,
+    pub delivered: i64,
+    pub failed: i64,
+}
+
+impl From<&Campaign> for domain::models::campaign::Campaign {
+    fn from(value: &Campaign) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .organization_id(value.organization_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .phone_number_id(value.phone_number_id.into_domain())
+            .name(value.name.to_owned())
+            .body(value.body.to_owned())
+            .maybe_tag(value.tag.to_owned())
+            .messages_per_second(value.messages_per_second)
+            .status(campaign_status_from_db(&value.status))
+            .next_batch_at(datetime_to_offset_datetime(value.next_batch_at.to_owned()))
+            .maybe_pause_reason(value.pause_reason.to_owned())
+            .maybe_completed_at(
+                value
+                    .completed_at
+                    .to_owned()
+                    .map(datetime_to_offset_datetime),
+            )
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::campaign::Campaign> for Campaign {
+    fn from(value: &domain::models::campaign::Campaign) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .organization_id(value.organization_id.into_db())
+            .user_id(value.user_id.into_db())
+            .phone_number_id(value.phone_number_id.into_db())
+            .name(value.name.to_owned())
+            .body(value.body.to_owned())
+            .maybe_tag(value.tag.to_owned())
+            .messages_per_second(value.messages_per_second)
+            .status(campaign_status_to_db(value.status).to_owned())
+            .next_batch_at(offset_datetime_to_datetime(value.next_batch_at))
+            .maybe_locked_until(None)
+            .maybe_pause_reason(value.pause_reason.to_owned())
+            .maybe_completed_at(value.completed_at.map(offset_datetime_to_datetime))
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
+
+impl From<&CampaignRecipient> for domain::models::campaign::CampaignRecipient {
+    fn from(value: &CampaignRecipient) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .campaign_id(value.campaign_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .recipient_phone_number(value.recipient_phone_number.to_owned())
+            .maybe_contact_id(value.contact_id.as_ref().map(RdbcUuidExt::into_domain))
+            .status(campaign_recipient_status_from_db(&value.status))
+            .content(value.content.to_owned())
+            .maybe_message_id(value.message_id.as_ref().map(RdbcUuidExt::into_domain))
+            .maybe_reason(value.reason.to_owned())
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::campaign::CampaignRecipient> for CampaignRecipient {
+    fn from(value: &domain::models::campaign::CampaignRecipient) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .campaign_id(value.campaign_id.into_db())
+            .user_id(value.user_id.into_db())
+            .recipient_phone_number(value.recipient_phone_number.to_owned())
+            .maybe_contact_id(value.contact_id.as_ref().map(UuidExt::into_db))
+            .status(campaign_recipient_status_to_db(value.status).to_owned())
+            .content(value.content.to_owned())
+            .maybe_message_id(value.message_id.as_ref().map(UuidExt::into_db))
+            .maybe_reason(value.reason.to_owned())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
+
+impl From<&CampaignProgressRow> for domain::models::campaign::CampaignProgress {
+    fn from(value: &CampaignProgressRow) -> Self {
+        Self {
+            total: value.total.max(0) as u64,
+            pending: value.pending.max(0) as u64,
+            skipped: value.skipped.max(0) as u64,
+            queued: value.queued.max(0) as u64,
+            sent: value.sent.max(0) as u64,
+            delivered: value.delivered.max(0) as u64,
+            failed: value.failed.max(0) as u64,
+        }
+    }
+}
+
+fn campaign_status_from_db(value: &str) -> CampaignStatus {
+    match value {
+        "running" => CampaignStatus::Running,
+        "paused" => CampaignStatus::Paused,
+        "cancelled" => CampaignStatus::Cancelled,
+        _ => CampaignStatus::Completed,
+    }
+}
+
+pub(crate) fn campaign_status_to_db(value: CampaignStatus) -> &'static str {
+    match value {
+        CampaignStatus::Running => "running",
+        CampaignStatus::Paused => "paused",
+        CampaignStatus::Completed => "completed",
+        CampaignStatus::Cancelled => "cancelled",
+    }
+}
+
+fn campaign_recipient_status_from_db(value: &str) -> CampaignRecipientStatus {
+    match value {
+        "pending" => CampaignRecipientStatus::Pending,
+        "skipped" => CampaignRecipientStatus::Skipped,
+        _ => CampaignRecipientStatus::Messaged,
+    }
+}
+
+fn campaign_recipient_status_to_db(value: CampaignRecipientStatus) -> &'static str {
+    match value {
+        CampaignRecipientStatus::Pending => "pending",
+        CampaignRecipientStatus::Messaged => "messaged",
+        CampaignRecipientStatus::Skipped => "skipped",
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use rbatis::rbdc::{DateTime, Uuid, types::Json};
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct Contact {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub email: Option<String>,
+    pub notes: Option<String>,
+    pub custom_fields: Json,
+    pub tags: Json,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(Contact {}, "contacts");
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct ContactPhoneNumber {
+    pub id: Uuid,
+    pub contact_id: Uuid,
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub phone_number: String,
+    pub created_at: DateTime,
+}
+
+rbatis::crud!(ContactPhoneNumber {}, "contact_phone_numbers");
+
+/// Phone numbers live in their own table, so the caller fills them in afterwards.
+impl From<&Contact> for domain::models::contact::Contact {
+    fn from(value: &Contact) -> Self {
+        let custom_fields =
+            serde_json::from_str::<BTreeMap<String, String>>(&value.custom_fields.0)
+                .unwrap_or_default();
+
+        Self::builder()
+            .id(value.id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .organization_id(value.organization_id.into_domain())
+            .name(value.name.to_owned())
+            .maybe_email(value.email.to_owned())
+            .maybe_notes(value.notes.to_owned())
+            .custom_fields(custom_fields)
+            .tags(serde_json::from_str(&value.tags.0).unwrap_or_default())
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::contact::Contact> for Contact {
+    fn from(value: &domain::models::contact::Contact) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .user_id(value.user_id.into_db())
+            .organization_id(value.organization_id.into_db())
+            .name(value.name.to_owned())
+            .maybe_email(value.email.to_owned())
+            .maybe_notes(value.notes.to_owned())
+            .custom_fields(serde_json::json!(value.custom_fields).into())
+            .tags(serde_json::json!(value.tags).into())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
+
+impl ContactPhoneNumber {
+    pub fn for_contact(contact: &domain::models::contact::Contact) -> Vec<Self> {
+        contact
+            .phone_numbers
+            .iter()
+            .map(|phone_number| {
+                Self::builder()
+                    .id(uuid::Uuid::now_v7().into_db())
+                    .contact_id(contact.id.into_db())
+                    .user_id(contact.user_id.into_db())
+                    .organization_id(contact.organization_id.into_db())
+                    .phone_number(phone_number.to_owned())
+                    .created_at(offset_datetime_to_datetime(contact.updated_at))
+                    .build()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,110 @@
+use rbatis::rbdc::{DateTime, Uuid, types::Json};
+use serde::{Deserialize, Serialize};
+
+use domain::models::contact_import::{ContactImportFormat, ContactImportStatus};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct ContactImport {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub file_name: String,
+    pub format: String,
+    pub status: String,
+    pub column_mapping: Json,
+    pub default_country_code: Option<String>,
+    pub content: String,
+    pub rows: Json,
+    pub attempts: i32,
+    pub locked_until: Option<DateTime>,
+    pub last_error: Option<String>,
+    pub completed_at: Option<DateTime>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(ContactImport {}, "contact_imports");
+
+impl From<&ContactImport> for domain::models::contact_import::ContactImport {
+    fn from(value: &ContactImport) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .organization_id(value.organization_id.into_domain())
+            .file_name(value.file_name.to_owned())
+            .format(contact_import_format_from_db(&value.format))
+            .status(contact_import_status_from_db(&value.status))
+            .column_mapping(serde_json::from_str(&value.column_mapping.0).unwrap_or_default())
+            .maybe_default_country_code(value.default_country_code.to_owned())
+            .content(value.content.to_owned())
+            .rows(serde_json::from_str(&value.rows.0).unwrap_or_default())
+            .attempts(value.attempts)
+            .maybe_last_error(value.last_error.to_owned())
+            .maybe_completed_at(
+                value
+                    .completed_at
+                    .to_owned()
+                    .map(datetime_to_offset_datetime),
+            )
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::contact_import::ContactImport> for ContactImport {
+    fn from(value: &domain::models::contact_import::ContactImport) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .user_id(value.user_id.into_db())
+            .organization_id(value.organization_id.into_db())
+            .file_name(value.file_name.to_owned())
+            .format(contact_import_format_to_db(value.format).to_owned())
+            .status(contact_import_status_to_db(value.status).to_owned())
+            .column_mapping(serde_json::json!(value.column_mapping).into())
+            .maybe_default_country_code(value.default_country_code.to_owned())
+            .content(value.content.to_owned())
+            .rows(serde_json::json!(value.rows).into())
+            .attempts(value.attempts)
+            .maybe_locked_until(None)
+            .maybe_last_error(value.last_error.to_owned())
+            .maybe_completed_at(value.completed_at.map(offset_datetime_to_datetime))
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
+
+fn contact_import_format_from_db(value: &str) -> ContactImportFormat {
+    match value {
+        "vcard" => ContactImportFormat::VCard,
+        _ => ContactImportFormat::Csv,
+    }
+}
+
+fn contact_import_format_to_db(value: ContactImportFormat) -> &'static str {
+    match value {
+        ContactImportFormat::Csv => "csv",
+        ContactImportFormat::VCard => "vcard",
+    }
+}
+
+fn contact_import_status_from_db(value: &str) -> ContactImportStatus {
+    match value {
+        "pending" => ContactImportStatus::Pending,
+        "failed" => ContactImportStatus::Failed,
+        _ => ContactImportStatus::Completed,
+    }
+}
+
+fn contact_import_status_to_db(value: ContactImportStatus) -> &'static str {
+    match value {
+        ContactImportStatus::Pending => "pending",
+        ContactImportStatus::Completed => "completed",
+        ContactImportStatus::Failed => "failed",
+    }
+}
@@ -1,3 +1,4 @@
+use domain::models::conversation::{AssigneeLoad, UnreadCount};
 use rbatis::rbdc::{DateTime, Uuid};
 use serde::{Deserialize, Serialize};
 
@@ -9,25 +10,81 @@ use crate::database::models::{
 pub struct Conversation {
     pub id: Uuid,
     pub phone_number_id: Uuid,
+    pub organization_id: Uuid,
     pub user_id: Uuid,
     pub recipient_phone_number: Option<String>,
+    pub assigned_user_id: Option<Uuid>,
+    pub assigned_at: Option<DateTime>,
     pub last_message_at: DateTime,
+    pub last_away_reply_at: Option<DateTime>,
+    pub archived_at: Option<DateTime>,
+    pub pinned_at: Option<DateTime>,
+    pub snoozed_until: Option<DateTime>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
 
 rbatis::crud!(Conversation {}, "conversations");
 
+#[derive(Debug, Deserialize)]
+pub struct AssigneeLoadRow {
+    pub assigned_user_id: Uuid,
+    pub assigned_count: i64,
+    pub last_assigned_at: Option<DateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnreadCountRow {
+    pub conversation_id: Uuid,
+    pub unread: i64,
+}
+
+impl From<&UnreadCountRow> for UnreadCount {
+    fn from(value: &UnreadCountRow) -> Self {
+        Self::builder()
+            .conversation_id(value.conversation_id.into_domain())
+            .unread(value.unread)
+            .build()
+    }
+}
+
+impl From<&AssigneeLoadRow> for AssigneeLoad {
+    fn from(value: &AssigneeLoadRow) -> Self {
+        Self::builder()
+            .user_id(value.assigned_user_id.into_domain())
+            .assigned_count(value.assigned_count)
+            .maybe_last_assigned_at(
+                value
+                    .last_assigned_at
+                    .clone()
+                    .map(datetime_to_offset_datetime),
+            )
+            .build()
+    }
+}
+
 impl From<&Conversation> for domain::models::conversation::Conversation {
     fn from(value: &Conversation) -> Self {
         Self::builder()
             .id(value.id.into_domain())
             .phone_number_id(value.phone_number_id.into_domain())
+            .organization_id(value.organization_id.into_domain())
             .user_id(value.user_id.into_domain())
             .maybe_recipient_phone_number(value.recipient_phone_number.to_owned())
+            .maybe_assigned_user_id(value.assigned_user_id.as_ref().map(|id| id.into_domain()))
+            .maybe_assigned_at(value.assigned_at.clone().map(datetime_to_offset_datetime))
             .last_message_at(datetime_to_offset_datetime(
                 value.last_message_at.to_owned(),
             ))
+            .maybe_last_away_reply_at(
+                value
+                    .last_away_reply_at
+                    .clone()
+                    .map(datetime_to_offset_datetime),
+            )
+            .maybe_archived_at(value.archived_at.clone().map(datetime_to_offset_datetime))
+            .maybe_pinned_at(value.pinned_at.clone().map(datetime_to_offset_datetime))
+            .maybe_snoozed_until(value.snoozed_until.clone().map(datetime_to_offset_datetime))
             .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
             .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
             .build()
@@ -39,9 +96,16 @@ impl From<&domain::models::conversation::Conversation> for Conversation {
         Self::builder()
             .id(value.id.into_db())
             .phone_number_id(value.phone_number_id.into_db())
+            .organization_id(value.organization_id.into_db())
             .user_id(value.user_id.into_db())
             .maybe_recipient_phone_number(value.recipient_phone_number.to_owned())
+            .maybe_assigned_user_id(value.assigned_user_id.map(|id| id.into_db()))
+            .maybe_assigned_at(value.assigned_at.map(offset_datetime_to_datetime))
             .last_message_at(offset_datetime_to_datetime(value.last_message_at))
+            .maybe_last_away_reply_at(value.last_away_reply_at.map(offset_datetime_to_datetime))
+            .maybe_archived_at(value.archived_at.map(offset_datetime_to_datetime))
+            .maybe_pinned_at(value.pinned_at.map(offset_datetime_to_datetime))
+            .maybe_snoozed_until(value.snoozed_until.map(offset_datetime_to_datetime))
             .created_at(offset_datetime_to_datetime(value.created_at))
             .updated_at(offset_datetime_to_datetime(value.updated_at))
             .build()
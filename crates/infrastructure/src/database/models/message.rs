@@ -1,6 +1,8 @@
 use rbatis::rbdc::{DateTime, Uuid};
 use serde::{Deserialize, Serialize};
 
+use domain::models::sms_encoding::SmsEncoding;
+
 use crate::database::models::{
     RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
 };
@@ -9,6 +11,7 @@ use crate::database::models::{
 pub struct Message {
     pub id: Uuid,
     pub conversation_id: Uuid,
+    pub organization_id: Uuid,
     pub user_id: Uuid,
     pub message_type: String,
     pub status: String,
@@ -19,6 +22,16 @@ pub struct Message {
     pub provider_error_detail: Option<String>,
     pub from_number: String,
     pub content: String,
+    pub scheduled_at: Option<DateTime>,
+    pub automated: bool,
+    pub reply_to_message_id: Option<Uuid>,
+    pub segments: Option<i32>,
+    pub encoding: Option<String>,
+    pub cost_micros: Option<i64>,
+    pub cost_currency: Option<String>,
+    pub carrier: Option<String>,
+    pub line_type: Option<String>,
+    pub messaging_profile_id: Option<String>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
@@ -30,6 +43,7 @@ impl From<&Message> for domain::models::message::Message {
         Self::builder()
             .id(value.id.into_domain())
             .conversation_id(value.conversation_id.into_domain())
+            .organization_id(value.organization_id.into_domain())
             .user_id(value.user_id.into_domain())
             .message_type(message_type_from_db(&value.message_type))
             .status(message_status_from_db(&value.status))
@@ -45,6 +59,21 @@ impl From<&Message> for domain::models::message::Message {
             .maybe_provider_error_detail(value.provider_error_detail.to_owned())
             .from_number(value.from_number.to_owned())
             .content(value.content.to_owned())
+            .maybe_scheduled_at(value.scheduled_at.clone().map(datetime_to_offset_datetime))
+            .automated(value.automated)
+            .maybe_reply_to_message_id(
+                value
+                    .reply_to_message_id
+                    .as_ref()
+                    .map(RdbcUuidExt::into_domain),
+            )
+            .maybe_segments(value.segments)
+            .maybe_encoding(value.encoding.as_deref().map(encoding_from_db))
+            .maybe_cost_micros(value.cost_micros)
+            .maybe_cost_currency(value.cost_currency.to_owned())
+            .maybe_carrier(value.carrier.to_owned())
+            .maybe_line_type(value.line_type.to_owned())
+            .maybe_messaging_profile_id(value.messaging_profile_id.to_owned())
             .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
             .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
             .build()
@@ -56,6 +85,7 @@ impl From<&domain::models::message::Message> for Message {
         Self::builder()
             .id(value.id.into_db())
             .conversation_id(value.conversation_id.into_db())
+            .organization_id(value.organization_id.into_db())
             .user_id(value.user_id.into_db())
             .message_type(message_type_to_db(value.message_type).to_owned())
             .status(message_status_to_db(value.status).to_owned())
@@ -70,12 +100,40 @@ impl From<&domain::models::message::Message> for Message {
             .maybe_provider_error_detail(value.provider_error_detail.to_owned())
             .from_number(value.from_number.to_owned())
             .content(value.content.to_owned())
+            .maybe_scheduled_at(value.scheduled_at.map(offset_datetime_to_datetime))
+            .automated(value.automated)
+            .maybe_reply_to_message_id(value.reply_to_message_id.as_ref().map(UuidExt::into_db))
+            .maybe_segments(value.segments)
+            .maybe_encoding(
+                value
+                    .encoding
+                    .map(|encoding| encoding_to_db(encoding).to_owned()),
+            )
+            .maybe_cost_micros(value.cost_micros)
+            .maybe_cost_currency(value.cost_currency.to_owned())
+            .maybe_carrier(value.carrier.to_owned())
+            .maybe_line_type(value.line_type.to_owned())
+            .maybe_messaging_profile_id(value.messaging_profile_id.to_owned())
             .created_at(offset_datetime_to_datetime(value.created_at))
             .updated_at(offset_datetime_to_datetime(value.updated_at))
             .build()
     }
 }
 
+fn encoding_from_db(value: &str) -> SmsEncoding {
+    match value {
+        "ucs2" => SmsEncoding::Ucs2,
+        _ => SmsEncoding::Gsm7,
+    }
+}
+
+fn encoding_to_db(value: SmsEncoding) -> &'static str {
+    match value {
+        SmsEncoding::Gsm7 => "gsm7",
+        SmsEncoding::Ucs2 => "ucs2",
+    }
+}
+
 fn message_type_from_db(value: &str) -> domain::models::message::MessageType {
     match value {
         "INBOUND" => domain::models::message::MessageType::Inbound,
@@ -91,8 +149,9 @@ fn message_type_to_db(value: domain::models::message::MessageType) -> &'static s
     }
 }
 
-fn message_status_from_db(value: &str) -> domain::models::message::MessageStatus {
+pub(crate) fn message_status_from_db(value: &str) -> domain::models::message::MessageStatus {
     match value {
+        "scheduled" => domain::models::message::MessageStatus::Scheduled,
         "pending" => domain::models::message::MessageStatus::Pending,
         "queued" => domain::models::message::MessageStatus::Queued,
         "delivered" => domain::models::message::MessageStatus::Delivered,
@@ -102,8 +161,9 @@ fn message_status_from_db(value: &str) -> domain::models::message::MessageStatus
     }
 }
 
-fn message_status_to_db(value: domain::models::message::MessageStatus) -> &'static str {
+pub(crate) fn message_status_to_db(value: domain::models::message::MessageStatus) -> &'static str {
     match value {
+        domain::models::message::MessageStatus::Scheduled => "scheduled",
         domain::models::message::MessageStatus::Pending => "pending",
         domain::models::message::MessageStatus::Queued => "queued",
         domain::models::message::MessageStatus::Delivered => "delivered",
@@ -0,0 +1,53 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub checksum_sha256: Option<String>,
+    pub created_at: DateTime,
+}
+
+rbatis::crud!(MessageAttachment {}, "message_attachments");
+
+impl From<&MessageAttachment> for domain::models::message_attachment::MessageAttachment {
+    fn from(value: &MessageAttachment) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .message_id(value.message_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .file_name(value.file_name.to_owned())
+            .content_type(value.content_type.to_owned())
+            .size_bytes(value.size_bytes)
+            .storage_key(value.storage_key.to_owned())
+            .maybe_checksum_sha256(value.checksum_sha256.to_owned())
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::message_attachment::MessageAttachment> for MessageAttachment {
+    fn from(value: &domain::models::message_attachment::MessageAttachment) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .message_id(value.message_id.into_db())
+            .user_id(value.user_id.into_db())
+            .file_name(value.file_name.to_owned())
+            .content_type(value.content_type.to_owned())
+            .size_bytes(value.size_bytes)
+            .storage_key(value.storage_key.to_owned())
+            .maybe_checksum_sha256(value.checksum_sha256.to_owned())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .build()
+    }
+}
@@ -0,0 +1,58 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime,
+    message::{message_status_from_db, message_status_to_db},
+    offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct MessageStatusEvent {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub status: String,
+    pub provider_status: Option<String>,
+    pub carrier: Option<String>,
+    pub error_code: Option<String>,
+    pub error_detail: Option<String>,
+    pub occurred_at: DateTime,
+    pub created_at: DateTime,
+}
+
+rbatis::crud!(MessageStatusEvent {}, "message_status_events");
+
+impl From<&MessageStatusEvent> for domain::models::message_status_event::MessageStatusEvent {
+    fn from(value: &MessageStatusEvent) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .message_id(value.message_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .status(message_status_from_db(&value.status))
+            .maybe_provider_status(value.provider_status.to_owned())
+            .maybe_carrier(value.carrier.to_owned())
+            .maybe_error_code(value.error_code.to_owned())
+            .maybe_error_detail(value.error_detail.to_owned())
+            .occurred_at(datetime_to_offset_datetime(value.occurred_at.to_owned()))
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::message_status_event::MessageStatusEvent> for MessageStatusEvent {
+    fn from(value: &domain::models::message_status_event::MessageStatusEvent) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .message_id(value.message_id.into_db())
+            .user_id(value.user_id.into_db())
+            .status(message_status_to_db(value.status).to_owned())
+            .maybe_provider_status(value.provider_status.to_owned())
+            .maybe_carrier(value.carrier.to_owned())
+            .maybe_error_code(value.error_code.to_owned())
+            .maybe_error_detail(value.error_detail.to_owned())
+            .occurred_at(offset_datetime_to_datetime(value.occurred_at))
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .build()
+    }
+}
@@ -0,0 +1,44 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct MessageTemplate {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub body: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(MessageTemplate {}, "message_templates");
+
+impl From<&MessageTemplate> for domain::models::message_template::MessageTemplate {
+    fn from(value: &MessageTemplate) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .name(value.name.to_owned())
+            .body(value.body.to_owned())
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::message_template::MessageTemplate> for MessageTemplate {
+    fn from(value: &domain::models::message_template::MessageTemplate) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .user_id(value.user_id.into_db())
+            .name(value.name.to_owned())
+            .body(value.body.to_owned())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
@@ -6,11 +6,23 @@ use rbatis::rbdc::{DateTime, Uuid};
 use serde::Deserialize;
 use time::OffsetDateTime;
 
+pub mod auto_reply_rule;
+pub mod business_hours;
+pub mod campaign;
+pub mod contact;
+pub mod contact_import;
 pub mod conversation;
 pub mod message;
+pub mod message_attachment;
+pub mod message_status_event;
+pub mod message_template;
+pub mod opt_out;
+pub mod organization;
+pub mod outbound_message_job;
 pub mod phone_number;
 pub mod processed_webhook_event;
 pub mod reset_password;
+pub mod usage;
 pub mod user;
 
 pub(crate) fn datetime_to_offset_datetime(dt: DateTime) -> OffsetDateTime {
@@ -24,23 +36,21 @@ pub(crate) fn offset_datetime_to_datetime(dt: OffsetDateTime) -> DateTime {
 
 pub fn uuid_now() -> Uuid {
     let uuid_v7 = uuid::Uuid::now_v7();
-    let id = Uuid(uuid_v7.to_string());
-
-    id
+    Uuid(uuid_v7.to_string())
 }
 
+#[allow(clippy::wrong_self_convention)]
 pub trait RdbcUuidExt {
     fn into_domain(&self) -> uuid::Uuid;
 }
 
 impl RdbcUuidExt for Uuid {
     fn into_domain(&self) -> uuid::Uuid {
-        let id = uuid::Uuid::from_str(&self.0).unwrap(); // :shrug:
-
-        id
+        uuid::Uuid::from_str(&self.0).unwrap() // :shrug:
     }
 }
 
+#[allow(clippy::wrong_self_convention)]
 pub trait UuidExt {
     fn into_db(&self) -> rbatis::rbdc::Uuid;
 }
@@ -48,8 +58,7 @@ pub trait UuidExt {
 impl UuidExt for uuid::Uuid {
     fn into_db(&self) -> rbatis::rbdc::Uuid {
         let uuid_str = self.to_string();
-        let uuid = rbatis::rbdc::Uuid::from_str(&uuid_str).unwrap();
-        uuid
+        rbatis::rbdc::Uuid::from_str(&uuid_str).unwrap()
     }
 }
 
@@ -66,7 +75,7 @@ impl MessageSql {
         "
         SELECT id, created_at
         FROM messages
-        WHERE id = #{cursor_id} AND conversation_id = #{conversation_id} AND user_id = #{user_id}
+        WHERE id = #{cursor_id} AND conversation_id = #{conversation_id} AND organization_id = #{organization_id}
         LIMIT 1
         "
     )]
@@ -74,7 +83,7 @@ impl MessageSql {
         rb: &dyn Executor,
         cursor_id: Uuid,
         conversation_id: Uuid,
-        user_id: Uuid,
+        organization_id: Uuid,
     ) -> Result<Vec<MessageCursorRow>, rbatis::Error> {
     }
 
@@ -82,7 +91,7 @@ impl MessageSql {
         "
         SELECT *
         FROM messages
-        WHERE conversation_id = #{conversation_id} AND user_id = #{user_id}
+        WHERE conversation_id = #{conversation_id} AND organization_id = #{organization_id}
         if cursor_created_at != null:
           AND (created_at, id) < (#{cursor_created_at}, #{cursor_id})
         ORDER BY created_at DESC, id DESC
@@ -92,7 +101,7 @@ impl MessageSql {
     pub async fn select_message_page(
         rb: &dyn Executor,
         conversation_id: Uuid,
-        user_id: Uuid,
+        organization_id: Uuid,
         cursor_created_at: Option<DateTime>,
         cursor_id: Option<Uuid>,
         limit: i64,
@@ -104,6 +113,7 @@ impl MessageSql {
         INSERT INTO messages (
             id,
             conversation_id,
+            organization_id,
             user_id,
             message_type,
             status,
@@ -114,12 +124,23 @@ impl MessageSql {
             provider_error_detail,
             from_number,
             content,
+            scheduled_at,
+            automated,
+            reply_to_message_id,
+            segments,
+            encoding,
+            cost_micros,
+            cost_currency,
+            carrier,
+            line_type,
+            messaging_profile_id,
             created_at,
             updated_at
         )
         VALUES (
             #{record.id},
             #{record.conversation_id},
+            #{record.organization_id},
             #{record.user_id},
             CAST(#{record.message_type} AS message_type),
             CAST(#{record.status} AS message_status),
@@ -130,6 +151,16 @@ impl MessageSql {
             #{record.provider_error_detail},
             #{record.from_number},
             #{record.content},
+            #{record.scheduled_at},
+            #{record.automated},
+            #{record.reply_to_message_id},
+            #{record.segments},
+            #{record.encoding},
+            #{record.cost_micros},
+            #{record.cost_currency},
+            #{record.carrier},
+            #{record.line_type},
+            #{record.messaging_profile_id},
             #{record.created_at},
             #{record.updated_at}
         )
@@ -146,6 +177,7 @@ impl MessageSql {
         UPDATE messages
         SET
             conversation_id = #{record.conversation_id},
+            organization_id = #{record.organization_id},
             user_id = #{record.user_id},
             message_type = CAST(#{record.message_type} AS message_type),
             status = CAST(#{record.status} AS message_status),
@@ -156,9 +188,18 @@ impl MessageSql {
             provider_error_detail = #{record.provider_error_detail},
             from_number = #{record.from_number},
             content = #{record.content},
+            scheduled_at = #{record.scheduled_at},
+            automated = #{record.automated},
+            segments = #{record.segments},
+            encoding = #{record.encoding},
+            cost_micros = #{record.cost_micros},
+            cost_currency = #{record.cost_currency},
+            carrier = #{record.carrier},
+            line_type = #{record.line_type},
+            messaging_profile_id = #{record.messaging_profile_id},
             created_at = #{record.created_at},
             updated_at = #{record.updated_at}
-        WHERE id = #{record.id} AND user_id = #{record.user_id}
+        WHERE id = #{record.id} AND organization_id = #{record.organization_id}
         "
     )]
     pub async fn update_message(
@@ -166,4 +207,938 @@ impl MessageSql {
         record: &message::Message,
     ) -> Result<ExecResult, rbatis::Error> {
     }
+
+    #[rbatis::py_sql(
+        "
+        SELECT *
+        FROM messages
+        WHERE conversation_id = #{conversation_id} AND organization_id = #{organization_id} AND status = 'scheduled'
+        ORDER BY scheduled_at, id
+        "
+    )]
+    pub async fn select_scheduled(
+        rb: &dyn Executor,
+        conversation_id: Uuid,
+        organization_id: Uuid,
+    ) -> Result<Vec<message::Message>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE messages
+        SET scheduled_at = #{scheduled_at}, updated_at = #{now}
+        WHERE id = #{id} AND organization_id = #{organization_id} AND status = 'scheduled'
+        "
+    )]
+    pub async fn reschedule(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        scheduled_at: DateTime,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        DELETE FROM messages
+        WHERE id = #{id} AND organization_id = #{organization_id} AND status = 'scheduled'
+        "
+    )]
+    pub async fn delete_scheduled(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE messages
+        SET status = 'pending', updated_at = #{now}
+        WHERE id = #{id} AND status = 'scheduled' AND scheduled_at <= #{now}
+        "
+    )]
+    pub async fn release_scheduled(
+        rb: &dyn Executor,
+        id: Uuid,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+}
+
+pub(crate) struct ResetPasswordSql;
+
+impl ResetPasswordSql {
+    #[rbatis::py_sql(
+        "
+        UPDATE reset_passwords
+        SET consumed = TRUE, consumed_at = #{now}, updated_at = #{now}
+        WHERE id = #{id} AND consumed = FALSE
+        "
+    )]
+    pub async fn consume(
+        rb: &dyn Executor,
+        id: Uuid,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE reset_passwords
+        SET consumed = TRUE, consumed_at = #{now}, updated_at = #{now}
+        WHERE user_id = #{user_id} AND consumed = FALSE
+        "
+    )]
+    pub async fn consume_all_by_user_id(
+        rb: &dyn Executor,
+        user_id: Uuid,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+}
+
+pub(crate) struct OutboundMessageJobSql;
+
+impl OutboundMessageJobSql {
+    #[rbatis::py_sql(
+        "
+        UPDATE outbound_message_jobs
+        SET attempts = attempts + 1, locked_until = #{locked_until}, updated_at = #{now}
+        WHERE message_id IN (
+            SELECT message_id
+            FROM outbound_message_jobs
+            WHERE next_attempt_at <= #{now} AND (locked_until IS NULL OR locked_until <= #{now})
+            ORDER BY next_attempt_at
+            LIMIT #{limit}
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING *
+        "
+    )]
+    pub async fn claim_due(
+        rb: &dyn Executor,
+        now: DateTime,
+        locked_until: DateTime,
+        limit: i64,
+    ) -> Result<Vec<outbound_message_job::OutboundMessageJob>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE outbound_message_jobs
+        SET next_attempt_at = #{next_attempt_at}, locked_until = NULL, last_error = #{last_error}, updated_at = #{now}
+        WHERE message_id = #{message_id}
+        "
+    )]
+    pub async fn reschedule(
+        rb: &dyn Executor,
+        message_id: Uuid,
+        next_attempt_at: DateTime,
+        last_error: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE outbound_message_jobs
+        SET next_attempt_at = #{next_attempt_at}, locked_until = NULL, updated_at = #{now}
+        WHERE message_id = #{message_id}
+        "
+    )]
+    pub async fn postpone(
+        rb: &dyn Executor,
+        message_id: Uuid,
+        next_attempt_at: DateTime,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+}
+
+pub(crate) struct ProcessedWebhookEventSql;
+
+impl ProcessedWebhookEventSql {
+    #[rbatis::py_sql(
+        "
+        UPDATE processed_webhook_events
+        SET attempts = attempts + 1, locked_until = #{locked_until}, updated_at = #{now}
+        WHERE event_id IN (
+            SELECT event_id
+            FROM processed_webhook_events e
+            WHERE (locked_until IS NULL OR locked_until <= #{now})
+              AND (
+                (status = 'pending' AND next_attempt_at <= #{now})
+                OR (
+                  status = 'parked'
+                  AND (
+                    next_attempt_at <= #{now}
+                    OR EXISTS (
+                      SELECT 1 FROM messages m
+                      WHERE m.provider_message_id = e.provider_message_id
+                    )
+                  )
+                )
+              )
+            ORDER BY occurred_at
+            LIMIT #{limit}
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING *
+        "
+    )]
+    pub async fn claim_pending(
+        rb: &dyn Executor,
+        now: DateTime,
+        locked_until: DateTime,
+        limit: i64,
+    ) -> Result<Vec<processed_webhook_event::ProcessedWebhookEvent>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE processed_webhook_events
+        SET status = 'processed', locked_until = NULL, last_error = NULL, processed_at = #{now}, updated_at = #{now}
+        WHERE event_id = #{event_id}
+        "
+    )]
+    pub async fn mark_processed(
+        rb: &dyn Executor,
+        event_id: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE processed_webhook_events
+        SET next_attempt_at = #{next_attempt_at}, locked_until = NULL, last_error = #{last_error}, updated_at = #{now}
+        WHERE event_id = #{event_id}
+        "
+    )]
+    pub async fn reschedule(
+        rb: &dyn Executor,
+        event_id: &str,
+        next_attempt_at: DateTime,
+        last_error: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE processed_webhook_events
+        SET status = 'parked', next_attempt_at = #{until}, locked_until = NULL, updated_at = #{now}
+        WHERE event_id = #{event_id}
+        "
+    )]
+    pub async fn park(
+        rb: &dyn Executor,
+        event_id: &str,
+        until: DateTime,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE processed_webhook_events
+        SET status = 'dead_lettered', locked_until = NULL, last_error = #{last_error}, updated_at = #{now}
+        WHERE event_id = #{event_id}
+        "
+    )]
+    pub async fn mark_dead_lettered(
+        rb: &dyn Executor,
+        event_id: &str,
+        last_error: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE processed_webhook_events
+        SET status = 'pending', attempts = 0, next_attempt_at = #{now}, locked_until = NULL, updated_at = #{now}
+        WHERE event_id = #{event_id} AND status = 'dead_lettered'
+        "
+    )]
+    pub async fn replay(
+        rb: &dyn Executor,
+        event_id: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT *
+        FROM processed_webhook_events
+        WHERE status = #{status}
+        ORDER BY created_at DESC
+        LIMIT #{limit}
+        "
+    )]
+    pub async fn select_by_status(
+        rb: &dyn Executor,
+        status: &str,
+        limit: i64,
+    ) -> Result<Vec<processed_webhook_event::ProcessedWebhookEvent>, rbatis::Error> {
+    }
+}
+
+pub(crate) struct ContactImportSql;
+
+impl ContactImportSql {
+    #[rbatis::py_sql(
+        "
+        UPDATE contact_imports
+        SET attempts = attempts + 1, locked_until = #{locked_until}, updated_at = #{now}
+        WHERE id IN (
+            SELECT id
+            FROM contact_imports
+            WHERE status = 'pending' AND (locked_until IS NULL OR locked_until <= #{now})
+            ORDER BY created_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING *
+        "
+    )]
+    pub async fn claim_pending(
+        rb: &dyn Executor,
+        now: DateTime,
+        locked_until: DateTime,
+    ) -> Result<Vec<contact_import::ContactImport>, rbatis::Error> {
+    }
+}
+
+pub(crate) struct AutoReplyRuleSql;
+
+impl AutoReplyRuleSql {
+    #[rbatis::py_sql(
+        "
+        INSERT INTO auto_reply_deliveries (rule_id, recipient_phone_number, last_replied_at)
+        VALUES (#{rule_id}, #{recipient_phone_number}, #{now})
+        ON CONFLICT (rule_id, recipient_phone_number) DO UPDATE
+        SET last_replied_at = EXCLUDED.last_replied_at
+        WHERE auto_reply_deliveries.last_replied_at <= #{cooldown_started_before}
+        "
+    )]
+    pub async fn claim_reply(
+        rb: &dyn Executor,
+        rule_id: Uuid,
+        recipient_phone_number: &str,
+        now: DateTime,
+        cooldown_started_before: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+}
+
+pub(crate) struct ConversationSql;
+
+impl ConversationSql {
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET last_away_reply_at = #{now}
+        WHERE id = #{id} AND organization_id = #{organization_id}
+            AND (last_away_reply_at IS NULL OR last_away_reply_at < #{closed_since})
+        "
+    )]
+    pub async fn claim_away_reply(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        closed_since: DateTime,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT *
+        FROM conversations
+        WHERE organization_id = #{organization_id}
+        if view == 'mine':
+          AND assigned_user_id = #{user_id}
+        if view == 'unassigned':
+          AND assigned_user_id IS NULL
+        if filter == 'archived':
+          AND archived_at IS NOT NULL
+        if filter != 'archived':
+          AND archived_at IS NULL
+        if filter == 'inbox':
+          AND (snoozed_until IS NULL OR snoozed_until <= #{now})
+        if filter == 'pinned':
+          AND pinned_at IS NOT NULL
+        if filter == 'snoozed':
+          AND snoozed_until > #{now}
+        ORDER BY pinned_at IS NULL, last_message_at DESC, id DESC
+        "
+    )]
+    pub async fn select_filtered(
+        rb: &dyn Executor,
+        organization_id: Uuid,
+        user_id: Uuid,
+        view: &str,
+        filter: &str,
+        now: DateTime,
+    ) -> Result<Vec<conversation::Conversation>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET archived_at = #{archived_at}, updated_at = GREATEST(updated_at, #{now})
+        WHERE id = #{id} AND organization_id = #{organization_id}
+        RETURNING *
+        "
+    )]
+    pub async fn set_archived_at(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        archived_at: Option<DateTime>,
+        now: DateTime,
+    ) -> Result<Vec<conversation::Conversation>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET pinned_at = #{pinned_at}, updated_at = GREATEST(updated_at, #{now})
+        WHERE id = #{id} AND organization_id = #{organization_id}
+        RETURNING *
+        "
+    )]
+    pub async fn set_pinned_at(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        pinned_at: Option<DateTime>,
+        now: DateTime,
+    ) -> Result<Vec<conversation::Conversation>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET snoozed_until = #{snoozed_until}, updated_at = GREATEST(updated_at, #{now})
+        WHERE id = #{id} AND organization_id = #{organization_id}
+        RETURNING *
+        "
+    )]
+    pub async fn set_snoozed_until(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        snoozed_until: Option<DateTime>,
+        now: DateTime,
+    ) -> Result<Vec<conversation::Conversation>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET last_message_at = GREATEST(last_message_at, #{last_message_at}),
+            updated_at = GREATEST(updated_at, #{updated_at})
+        WHERE id = #{id} AND organization_id = #{organization_id}
+        RETURNING *
+        "
+    )]
+    pub async fn record_activity(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        last_message_at: DateTime,
+        updated_at: DateTime,
+    ) -> Result<Vec<conversation::Conversation>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET archived_at = NULL, snoozed_until = NULL
+        WHERE id = #{id} AND organization_id = #{organization_id}
+        "
+    )]
+    pub async fn reopen(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET assigned_user_id = #{assigned_user_id}, assigned_at = #{assigned_at},
+            updated_at = GREATEST(updated_at, #{now})
+        WHERE id = #{id} AND organization_id = #{organization_id}
+        RETURNING *
+        "
+    )]
+    pub async fn assign(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        assigned_user_id: Option<Uuid>,
+        assigned_at: Option<DateTime>,
+        now: DateTime,
+    ) -> Result<Vec<conversation::Conversation>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET assigned_user_id = #{assigned_user_id}, assigned_at = #{now},
+            updated_at = GREATEST(updated_at, #{now})
+        WHERE id = #{id} AND organization_id = #{organization_id} AND assigned_user_id IS NULL
+        "
+    )]
+    pub async fn claim_assignment(
+        rb: &dyn Executor,
+        id: Uuid,
+        organization_id: Uuid,
+        assigned_user_id: Uuid,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET assigned_user_id = NULL, assigned_at = NULL
+        WHERE organization_id = #{organization_id} AND assigned_user_id = #{user_id}
+        "
+    )]
+    pub async fn unassign_member(
+        rb: &dyn Executor,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT assigned_user_id, COUNT(*) AS assigned_count, MAX(assigned_at) AS last_assigned_at
+        FROM conversations
+        WHERE organization_id = #{organization_id} AND assigned_user_id IS NOT NULL
+        GROUP BY assigned_user_id
+        "
+    )]
+    pub async fn select_assignee_loads(
+        rb: &dyn Executor,
+        organization_id: Uuid,
+    ) -> Result<Vec<conversation::AssigneeLoadRow>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        INSERT INTO conversation_reads (conversation_id, user_id, last_read_message_id)
+        SELECT #{conversation_id}, #{user_id}, id
+        FROM messages
+        WHERE conversation_id = #{conversation_id}
+        ORDER BY id DESC
+        LIMIT 1
+        ON CONFLICT (conversation_id, user_id) DO UPDATE
+        SET last_read_message_id = GREATEST(
+            conversation_reads.last_read_message_id,
+            EXCLUDED.last_read_message_id
+        )
+        "
+    )]
+    pub async fn mark_read(
+        rb: &dyn Executor,
+        conversation_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT m.conversation_id, COUNT(*) AS unread
+        FROM messages m
+        LEFT JOIN conversation_reads r
+            ON r.conversation_id = m.conversation_id AND r.user_id = #{user_id}
+        WHERE m.organization_id = #{organization_id} AND m.message_type = 'INBOUND'
+            AND (r.last_read_message_id IS NULL OR m.id > r.last_read_message_id)
+        GROUP BY m.conversation_id
+        "
+    )]
+    pub async fn select_unread_counts(
+        rb: &dyn Executor,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<conversation::UnreadCountRow>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE conversations
+        SET snoozed_until = NULL, updated_at = #{now}
+        WHERE snoozed_until <= #{now}
+        RETURNING *
+        "
+    )]
+    pub async fn wake_snoozed(
+        rb: &dyn Executor,
+        now: DateTime,
+    ) -> Result<Vec<conversation::Conversation>, rbatis::Error> {
+    }
+}
+
+pub(crate) struct CampaignSql;
+
+impl CampaignSql {
+    #[rbatis::py_sql(
+        "
+        UPDATE campaigns
+        SET status = #{to}, updated_at = #{now}, pause_reason = NULL,
+            completed_at = CASE WHEN #{to}::text IN ('completed', 'cancelled') THEN #{now} ELSE completed_at END
+        WHERE id = #{id} AND status = #{from}
+        "
+    )]
+    pub async fn change_status(
+        rb: &dyn Executor,
+        id: Uuid,
+        from: &str,
+        to: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE campaigns
+        SET status = 'paused', pause_reason = #{reason}, updated_at = #{now}
+        WHERE id = #{id} AND status = 'running'
+        "
+    )]
+    pub async fn pause(
+        rb: &dyn Executor,
+        id: Uuid,
+        reason: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE campaigns
+        SET locked_until = #{locked_until}
+        WHERE id IN (
+            SELECT id
+            FROM campaigns
+            WHERE status = 'running' AND next_batch_at <= #{now}
+                AND (locked_until IS NULL OR locked_until <= #{now})
+            ORDER BY next_batch_at
+            LIMIT #{limit}
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING *
+        "
+    )]
+    pub async fn claim_due(
+        rb: &dyn Executor,
+        now: DateTime,
+        locked_until: DateTime,
+        limit: i64,
+    ) -> Result<Vec<campaign::Campaign>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE campaigns
+        SET next_batch_at = #{next_batch_at}, locked_until = NULL
+        WHERE id = #{id}
+        "
+    )]
+    pub async fn schedule_next_batch(
+        rb: &dyn Executor,
+        id: Uuid,
+        next_batch_at: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT *
+        FROM campaign_recipients
+        WHERE campaign_id = #{campaign_id} AND status = 'pending'
+        ORDER BY created_at, id
+        LIMIT #{limit}
+        "
+    )]
+    pub async fn select_pending_recipients(
+        rb: &dyn Executor,
+        campaign_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<campaign::CampaignRecipient>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT
+            COUNT(*) AS total,
+            COUNT(*) FILTER (WHERE r.status = 'pending') AS pending,
+            COUNT(*) FILTER (WHERE r.status = 'skipped') AS skipped,
+            COUNT(*) FILTER (WHERE m.status IN ('pending', 'queued')) AS queued,
+            COUNT(*) FILTER (WHERE m.status = 'sent') AS sent,
+            COUNT(*) FILTER (WHERE m.status = 'delivered') AS delivered,
+            COUNT(*) FILTER (WHERE r.status = 'messaged' AND (m.status = 'failed' OR m.id IS NULL)) AS failed
+        FROM campaign_recipients r
+        LEFT JOIN messages m ON m.id = r.message_id
+        WHERE r.campaign_id = #{campaign_id}
+        "
+    )]
+    pub async fn select_progress(
+        rb: &dyn Executor,
+        campaign_id: Uuid,
+    ) -> Result<Vec<campaign::CampaignProgressRow>, rbatis::Error> {
+    }
+}
+
+pub(crate) struct UsageSql;
+
+impl UsageSql {
+    #[rbatis::py_sql(
+        "
+        INSERT INTO usage_records (
+            message_id, user_id, phone_number_id, segments, cost_micros, cost_estimated, recorded_at
+        )
+        VALUES (
+            #{record.message_id},
+            #{record.user_id},
+            #{record.phone_number_id},
+            #{record.segments},
+            #{record.cost_micros},
+            #{record.cost_estimated},
+            #{record.recorded_at}
+        )
+        ON CONFLICT (message_id) DO NOTHING
+        "
+    )]
+    pub async fn insert_record(
+        rb: &dyn Executor,
+        record: &usage::UsageRecord,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE usage_records
+        SET cost_micros = #{cost_micros}, cost_estimated = FALSE
+        WHERE message_id = #{message_id}
+        "
+    )]
+    pub async fn update_cost(
+        rb: &dyn Executor,
+        message_id: Uuid,
+        cost_micros: i64,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT
+            COUNT(*) AS messages,
+            COALESCE(SUM(segments), 0)::BIGINT AS segments,
+            COALESCE(SUM(cost_micros), 0)::BIGINT AS cost_micros
+        FROM usage_records
+        WHERE user_id = #{user_id} AND recorded_at >= #{since}
+        "
+    )]
+    pub async fn select_totals(
+        rb: &dyn Executor,
+        user_id: Uuid,
+        since: DateTime,
+    ) -> Result<Vec<usage::UsageTotalsRow>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT
+            date_trunc(#{unit}::text, recorded_at AT TIME ZONE 'UTC') AT TIME ZONE 'UTC' AS period_start,
+            phone_number_id,
+            COUNT(*) AS messages,
+            COALESCE(SUM(segments), 0)::BIGINT AS segments,
+            COALESCE(SUM(cost_micros), 0)::BIGINT AS cost_micros
+        FROM usage_records
+        WHERE user_id = #{user_id} AND recorded_at >= #{since}
+        GROUP BY 1, 2
+        ORDER BY 1, 2
+        "
+    )]
+    pub async fn select_rollups(
+        rb: &dyn Executor,
+        user_id: Uuid,
+        unit: &str,
+        since: DateTime,
+    ) -> Result<Vec<usage::UsageRollupRow>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        INSERT INTO usage_limits (
+            user_id, period, soft_limit_micros, hard_limit_micros, soft_limit_notified_at,
+            created_at, updated_at
+        )
+        VALUES (
+            #{record.user_id},
+            #{record.period},
+            #{record.soft_limit_micros},
+            #{record.hard_limit_micros},
+            #{record.soft_limit_notified_at},
+            #{record.created_at},
+            #{record.updated_at}
+        )
+        ON CONFLICT (user_id, period) DO UPDATE
+        SET soft_limit_micros = EXCLUDED.soft_limit_micros,
+            hard_limit_micros = EXCLUDED.hard_limit_micros,
+            soft_limit_notified_at = EXCLUDED.soft_limit_notified_at,
+            updated_at = EXCLUDED.updated_at
+        "
+    )]
+    pub async fn upsert_limit(
+        rb: &dyn Executor,
+        record: &usage::UsageLimit,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT *
+        FROM usage_limits
+        WHERE soft_limit_micros IS NOT NULL
+        "
+    )]
+    pub async fn select_limits_with_soft_limit(
+        rb: &dyn Executor,
+    ) -> Result<Vec<usage::UsageLimit>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE usage_limits
+        SET soft_limit_notified_at = #{now}
+        WHERE user_id = #{user_id} AND period = #{period}
+            AND (soft_limit_notified_at IS NULL OR soft_limit_notified_at < #{period_start})
+        "
+    )]
+    pub async fn claim_soft_limit_notification(
+        rb: &dyn Executor,
+        user_id: Uuid,
+        period: &str,
+        period_start: DateTime,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+}
+
+pub(crate) struct OrganizationSql;
+
+impl OrganizationSql {
+    #[rbatis::py_sql(
+        "
+        SELECT o.*
+        FROM organizations o
+        JOIN organization_members m ON m.organization_id = o.id
+        WHERE m.user_id = #{user_id}
+        ORDER BY m.created_at, o.id
+        "
+    )]
+    pub async fn select_by_user_id(
+        rb: &dyn Executor,
+        user_id: Uuid,
+    ) -> Result<Vec<organization::Organization>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT m.organization_id, m.user_id, m.role, u.email, m.created_at
+        FROM organization_members m
+        JOIN users u ON u.id = m.user_id
+        WHERE m.organization_id = #{organization_id}
+        ORDER BY m.created_at, m.user_id
+        "
+    )]
+    pub async fn select_members(
+        rb: &dyn Executor,
+        organization_id: Uuid,
+    ) -> Result<Vec<organization::OrganizationMemberRow>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        SELECT *
+        FROM organization_invitations
+        WHERE organization_id = #{organization_id} AND accepted_at IS NULL AND expires_at > #{now}
+        ORDER BY created_at DESC, id DESC
+        "
+    )]
+    pub async fn select_pending_invitations(
+        rb: &dyn Executor,
+        organization_id: Uuid,
+        now: DateTime,
+    ) -> Result<Vec<organization::OrganizationInvitation>, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE organization_invitations
+        SET accepted_at = #{now}
+        WHERE id = #{id} AND accepted_at IS NULL
+        "
+    )]
+    pub async fn accept_invitation(
+        rb: &dyn Executor,
+        id: Uuid,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        INSERT INTO organization_members (organization_id, user_id, role, created_at)
+        VALUES (#{record.organization_id}, #{record.user_id}, #{record.role}, #{record.created_at})
+        ON CONFLICT (organization_id, user_id) DO NOTHING
+        "
+    )]
+    pub async fn insert_member(
+        rb: &dyn Executor,
+        record: &organization::OrganizationMember,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE organization_members
+        SET role = #{role}
+        WHERE organization_id = #{organization_id} AND user_id = #{user_id}
+        "
+    )]
+    pub async fn update_member_role(
+        rb: &dyn Executor,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: &str,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
+
+    #[rbatis::py_sql(
+        "
+        UPDATE organizations
+        SET conversation_routing = #{conversation_routing}, updated_at = #{now}
+        WHERE id = #{id}
+        "
+    )]
+    pub async fn update_conversation_routing(
+        rb: &dyn Executor,
+        id: Uuid,
+        conversation_routing: &str,
+        now: DateTime,
+    ) -> Result<ExecResult, rbatis::Error> {
+    }
 }
@@ -0,0 +1,44 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct OptOut {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub recipient_phone_number: String,
+    pub keyword: String,
+    pub created_at: DateTime,
+}
+
+rbatis::crud!(OptOut {}, "opt_outs");
+
+impl From<&OptOut> for domain::models::opt_out::OptOut {
+    fn from(value: &OptOut) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .phone_number_id(value.phone_number_id.into_domain())
+            .recipient_phone_number(value.recipient_phone_number.to_owned())
+            .keyword(value.keyword.to_owned())
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::opt_out::OptOut> for OptOut {
+    fn from(value: &domain::models::opt_out::OptOut) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .user_id(value.user_id.into_db())
+            .phone_number_id(value.phone_number_id.into_db())
+            .recipient_phone_number(value.recipient_phone_number.to_owned())
+            .keyword(value.keyword.to_owned())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .build()
+    }
+}
@@ -0,0 +1,180 @@
+use domain::models::{conversation::ConversationRouting, organization::OrganizationRole};
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub conversation_routing: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(Organization {}, "organizations");
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct OrganizationMember {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub role: String,
+    pub created_at: DateTime,
+}
+
+rbatis::crud!(OrganizationMember {}, "organization_members");
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct OrganizationInvitation {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub email: String,
+    pub role: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime,
+    pub accepted_at: Option<DateTime>,
+    pub created_at: DateTime,
+}
+
+rbatis::crud!(OrganizationInvitation {}, "organization_invitations");
+
+#[derive(Debug, Deserialize)]
+pub struct OrganizationMemberRow {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub role: String,
+    pub email: String,
+    pub created_at: DateTime,
+}
+
+impl From<&Organization> for domain::models::organization::Organization {
+    fn from(value: &Organization) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .name(value.name.to_owned())
+            .conversation_routing(conversation_routing_from_db(&value.conversation_routing))
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::organization::Organization> for Organization {
+    fn from(value: &domain::models::organization::Organization) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .name(value.name.to_owned())
+            .conversation_routing(conversation_routing_to_db(value.conversation_routing).to_owned())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
+
+impl From<&OrganizationMember> for domain::models::organization::OrganizationMember {
+    fn from(value: &OrganizationMember) -> Self {
+        Self::builder()
+            .organization_id(value.organization_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .role(organization_role_from_db(&value.role))
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&OrganizationMemberRow> for domain::models::organization::OrganizationMember {
+    fn from(value: &OrganizationMemberRow) -> Self {
+        Self::builder()
+            .organization_id(value.organization_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .role(organization_role_from_db(&value.role))
+            .email(value.email.to_owned())
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::organization::OrganizationMember> for OrganizationMember {
+    fn from(value: &domain::models::organization::OrganizationMember) -> Self {
+        Self::builder()
+            .organization_id(value.organization_id.into_db())
+            .user_id(value.user_id.into_db())
+            .role(organization_role_to_db(value.role).to_owned())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .build()
+    }
+}
+
+impl From<&OrganizationInvitation> for domain::models::organization::OrganizationInvitation {
+    fn from(value: &OrganizationInvitation) -> Self {
+        Self::builder()
+            .id(value.id.into_domain())
+            .organization_id(value.organization_id.into_domain())
+            .email(value.email.to_owned())
+            .role(organization_role_from_db(&value.role))
+            .invited_by(value.invited_by.into_domain())
+            .expires_at(datetime_to_offset_datetime(value.expires_at.to_owned()))
+            .maybe_accepted_at(
+                value
+                    .accepted_at
+                    .to_owned()
+                    .map(datetime_to_offset_datetime),
+            )
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::organization::OrganizationInvitation> for OrganizationInvitation {
+    fn from(value: &domain::models::organization::OrganizationInvitation) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .organization_id(value.organization_id.into_db())
+            .email(value.email.to_owned())
+            .role(organization_role_to_db(value.role).to_owned())
+            .invited_by(value.invited_by.into_db())
+            .expires_at(offset_datetime_to_datetime(value.expires_at))
+            .maybe_accepted_at(value.accepted_at.map(offset_datetime_to_datetime))
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .build()
+    }
+}
+
+/// Unknown values fall back to the least privileged role.
+fn organization_role_from_db(value: &str) -> OrganizationRole {
+    match value {
+        "owner" => OrganizationRole::Owner,
+        "admin" => OrganizationRole::Admin,
+        "agent" => OrganizationRole::Agent,
+        _ => OrganizationRole::ReadOnly,
+    }
+}
+
+pub(crate) fn organization_role_to_db(value: OrganizationRole) -> &'static str {
+    match value {
+        OrganizationRole::Owner => "owner",
+        OrganizationRole::Admin => "admin",
+        OrganizationRole::Agent => "agent",
+        OrganizationRole::ReadOnly => "read_only",
+    }
+}
+
+/// Unknown values leave conversations unassigned.
+fn conversation_routing_from_db(value: &str) -> ConversationRouting {
+    match value {
+        "round_robin" => ConversationRouting::RoundRobin,
+        "least_load" => ConversationRouting::LeastLoad,
+        _ => ConversationRouting::Manual,
+    }
+}
+
+pub(crate) fn conversation_routing_to_db(value: ConversationRouting) -> &'static str {
+    match value {
+        ConversationRouting::Manual => "manual",
+        ConversationRouting::RoundRobin => "round_robin",
+        ConversationRouting::LeastLoad => "least_load",
+    }
+}
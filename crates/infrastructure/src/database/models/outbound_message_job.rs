@@ -0,0 +1,54 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct OutboundMessageJob {
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub to_number: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime,
+    pub locked_until: Option<DateTime>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(OutboundMessageJob {}, "outbound_message_jobs");
+
+impl From<&OutboundMessageJob> for domain::models::outbound_message_job::OutboundMessageJob {
+    fn from(value: &OutboundMessageJob) -> Self {
+        Self::builder()
+            .message_id(value.message_id.into_domain())
+            .user_id(value.user_id.into_domain())
+            .to_number(value.to_number.to_owned())
+            .attempts(value.attempts)
+            .next_attempt_at(datetime_to_offset_datetime(
+                value.next_attempt_at.to_owned(),
+            ))
+            .maybe_last_error(value.last_error.to_owned())
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::outbound_message_job::OutboundMessageJob> for OutboundMessageJob {
+    fn from(value: &domain::models::outbound_message_job::OutboundMessageJob) -> Self {
+        Self::builder()
+            .message_id(value.message_id.into_db())
+            .user_id(value.user_id.into_db())
+            .to_number(value.to_number.to_owned())
+            .attempts(value.attempts)
+            .next_attempt_at(offset_datetime_to_datetime(value.next_attempt_at))
+            .maybe_locked_until(None)
+            .maybe_last_error(value.last_error.to_owned())
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
@@ -8,6 +8,7 @@ use crate::database::models::{
 #[derive(Debug, bon::Builder, Serialize, Deserialize)]
 pub struct PhoneNumber {
     pub id: Uuid,
+    pub organization_id: Uuid,
     pub user_id: Uuid,
     pub name: String,
     pub phone: String,
@@ -21,6 +22,7 @@ impl From<&PhoneNumber> for domain::models::phone_number::PhoneNumber {
     fn from(value: &PhoneNumber) -> Self {
         Self::builder()
             .id(value.id.into_domain())
+            .organization_id(value.organization_id.into_domain())
             .user_id(value.user_id.into_domain())
             .name(value.name.to_owned())
             .phone(value.phone.to_owned())
@@ -34,6 +36,7 @@ impl From<&domain::models::phone_number::PhoneNumber> for PhoneNumber {
     fn from(value: &domain::models::phone_number::PhoneNumber) -> Self {
         Self::builder()
             .id(value.id.into_db())
+            .organization_id(value.organization_id.into_db())
             .user_id(value.user_id.into_db())
             .name(value.name.to_owned())
             .phone(value.phone.to_owned())
@@ -10,7 +10,14 @@ pub struct ProcessedWebhookEvent {
     pub provider_message_id: Option<String>,
     pub occurred_at: DateTime,
     pub payload_json: Json,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime,
+    pub locked_until: Option<DateTime>,
+    pub last_error: Option<String>,
+    pub processed_at: Option<DateTime>,
     pub created_at: DateTime,
+    pub updated_at: DateTime,
 }
 
 rbatis::crud!(ProcessedWebhookEvent {}, "processed_webhook_events");
@@ -25,7 +32,20 @@ impl From<&ProcessedWebhookEvent>
             .maybe_provider_message_id(value.provider_message_id.to_owned())
             .occurred_at(datetime_to_offset_datetime(value.occurred_at.to_owned()))
             .payload_json(value.payload_json.clone().into())
+            .status(webhook_event_status_from_db(&value.status))
+            .attempts(value.attempts)
+            .next_attempt_at(datetime_to_offset_datetime(
+                value.next_attempt_at.to_owned(),
+            ))
+            .maybe_last_error(value.last_error.to_owned())
+            .maybe_processed_at(
+                value
+                    .processed_at
+                    .to_owned()
+                    .map(datetime_to_offset_datetime),
+            )
             .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
             .build()
     }
 }
@@ -40,7 +60,40 @@ impl From<&domain::models::processed_webhook_event::ProcessedWebhookEvent>
             .maybe_provider_message_id(value.provider_message_id.to_owned())
             .occurred_at(offset_datetime_to_datetime(value.occurred_at))
             .payload_json(value.payload_json.to_owned().into())
+            .status(webhook_event_status_to_db(value.status).to_owned())
+            .attempts(value.attempts)
+            .next_attempt_at(offset_datetime_to_datetime(value.next_attempt_at))
+            .maybe_locked_until(None)
+            .maybe_last_error(value.last_error.to_owned())
+            .maybe_processed_at(value.processed_at.map(offset_datetime_to_datetime))
             .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
             .build()
     }
 }
+
+fn webhook_event_status_from_db(
+    value: &str,
+) -> domain::models::processed_webhook_event::WebhookEventStatus {
+    match value {
+        "pending" => domain::models::processed_webhook_event::WebhookEventStatus::Pending,
+        "parked" => domain::models::processed_webhook_event::WebhookEventStatus::Parked,
+        "dead_lettered" => {
+            domain::models::processed_webhook_event::WebhookEventStatus::DeadLettered
+        }
+        _ => domain::models::processed_webhook_event::WebhookEventStatus::Processed,
+    }
+}
+
+pub(crate) fn webhook_event_status_to_db(
+    value: domain::models::processed_webhook_event::WebhookEventStatus,
+) -> &'static str {
+    match value {
+        domain::models::processed_webhook_event::WebhookEventStatus::Pending => "pending",
+        domain::models::processed_webhook_event::WebhookEventStatus::Processed => "processed",
+        domain::models::processed_webhook_event::WebhookEventStatus::Parked => "parked",
+        domain::models::processed_webhook_event::WebhookEventStatus::DeadLettered => {
+            "dead_lettered"
+        }
+    }
+}
@@ -29,7 +29,7 @@ impl From<&ResetPassword> for domain::models::reset_password::ResetPassword {
                 value
                     .consumed_at
                     .to_owned()
-                    .map(|dt| datetime_to_offset_datetime(dt)),
+                    .map(datetime_to_offset_datetime),
             )
             .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
             .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
@@ -44,7 +44,7 @@ impl From<&domain::models::reset_password::ResetPassword> for ResetPassword {
             .user_id(value.user_id.into_db())
             .token(value.token.to_owned())
             .consumed(value.consumed)
-            .maybe_consumed_at(value.consumed_at.map(|dt| offset_datetime_to_datetime(dt)))
+            .maybe_consumed_at(value.consumed_at.map(offset_datetime_to_datetime))
             .created_at(offset_datetime_to_datetime(value.created_at))
             .updated_at(offset_datetime_to_datetime(value.updated_at))
             .build()
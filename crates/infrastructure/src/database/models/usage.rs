@@ -0,0 +1,133 @@
+use rbatis::rbdc::{DateTime, Uuid};
+use serde::{Deserialize, Serialize};
+
+use domain::models::usage::{UsagePeriod, UsageTotals};
+
+use crate::database::models::{
+    RdbcUuidExt, UuidExt, datetime_to_offset_datetime, offset_datetime_to_datetime,
+};
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub message_id: Uuid,
+    pub user_id: Uuid,
+    pub phone_number_id: Uuid,
+    pub segments: i32,
+    pub cost_micros: i64,
+    pub cost_estimated: bool,
+    pub recorded_at: DateTime,
+}
+
+rbatis::crud!(UsageRecord {}, "usage_records");
+
+#[derive(Debug, bon::Builder, Serialize, Deserialize)]
+pub struct UsageLimit {
+    pub user_id: Uuid,
+    pub period: String,
+    pub soft_limit_micros: Option<i64>,
+    pub hard_limit_micros: Option<i64>,
+    pub soft_limit_notified_at: Option<DateTime>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+rbatis::crud!(UsageLimit {}, "usage_limits");
+
+#[derive(Debug, Deserialize)]
+pub struct UsageTotalsRow {
+    pub messages: i64,
+    pub segments: i64,
+    pub cost_micros: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsageRollupRow {
+    pub period_start: DateTime,
+    pub phone_number_id: Uuid,
+    pub messages: i64,
+    pub segments: i64,
+    pub cost_micros: i64,
+}
+
+impl From<&domain::models::usage::UsageRecord> for UsageRecord {
+    fn from(value: &domain::models::usage::UsageRecord) -> Self {
+        Self::builder()
+            .message_id(value.message_id.into_db())
+            .user_id(value.user_id.into_db())
+            .phone_number_id(value.phone_number_id.into_db())
+            .segments(value.segments)
+            .cost_micros(value.cost_micros)
+            .cost_estimated(value.cost_estimated)
+            .recorded_at(offset_datetime_to_datetime(value.recorded_at))
+            .build()
+    }
+}
+
+impl From<&UsageLimit> for domain::models::usage::UsageLimit {
+    fn from(value: &UsageLimit) -> Self {
+        Self::builder()
+            .user_id(value.user_id.into_domain())
+            .period(UsagePeriod::parse(&value.period).unwrap_or(UsagePeriod::Monthly))
+            .maybe_soft_limit_micros(value.soft_limit_micros)
+            .maybe_hard_limit_micros(value.hard_limit_micros)
+            .maybe_soft_limit_notified_at(
+                value
+                    .soft_limit_notified_at
+                    .clone()
+                    .map(datetime_to_offset_datetime),
+            )
+            .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
+            .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
+            .build()
+    }
+}
+
+impl From<&domain::models::usage::UsageLimit> for UsageLimit {
+    fn from(value: &domain::models::usage::UsageLimit) -> Self {
+        Self::builder()
+            .user_id(value.user_id.into_db())
+            .period(value.period.name().to_owned())
+            .maybe_soft_limit_micros(value.soft_limit_micros)
+            .maybe_hard_limit_micros(value.hard_limit_micros)
+            .maybe_soft_limit_notified_at(
+                value
+                    .soft_limit_notified_at
+                    .map(offset_datetime_to_datetime),
+            )
+            .created_at(offset_datetime_to_datetime(value.created_at))
+            .updated_at(offset_datetime_to_datetime(value.updated_at))
+            .build()
+    }
+}
+
+impl From<&UsageTotalsRow> for UsageTotals {
+    fn from(value: &UsageTotalsRow) -> Self {
+        Self {
+            messages: value.messages,
+            segments: value.segments,
+            cost_micros: value.cost_micros,
+        }
+    }
+}
+
+impl From<&UsageRollupRow> for domain::models::usage::UsageRollup {
+    fn from(value: &UsageRollupRow) -> Self {
+        Self {
+            period_start: datetime_to_offset_datetime(value.period_start.to_owned()),
+            phone_number_id: value.phone_number_id.into_domain(),
+            totals: UsageTotals {
+                messages: value.messages,
+                segments: value.segments,
+                cost_micros: value.cost_micros,
+            },
+        }
+    }
+}
+
+/// Unit passed to `date_trunc` for a period.
+pub(crate) fn period_unit(period: UsagePeriod) -> &'static str {
+    match period {
+        UsagePeriod::Daily => "day",
+        UsagePeriod::Monthly => "month",
+    }
+}
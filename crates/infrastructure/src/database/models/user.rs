@@ -32,7 +32,7 @@ impl From<&User> for domain::models::user::User {
                 value
                     .email_verified_at
                     .to_owned()
-                    .map(|dt| datetime_to_offset_datetime(dt)),
+                    .map(datetime_to_offset_datetime),
             )
             .created_at(datetime_to_offset_datetime(value.created_at.to_owned()))
             .updated_at(datetime_to_offset_datetime(value.updated_at.to_owned()))
@@ -48,11 +48,7 @@ impl From<&domain::models::user::User> for User {
             .hash(value.hash.to_owned())
             .salt(value.salt.to_owned())
             .email_verified(value.email_verified)
-            .maybe_email_verified_at(
-                value
-                    .email_verified_at
-                    .map(|dt| offset_datetime_to_datetime(dt)),
-            )
+            .maybe_email_verified_at(value.email_verified_at.map(offset_datetime_to_datetime))
             .created_at(offset_datetime_to_datetime(value.created_at))
             .updated_at(offset_datetime_to_datetime(value.updated_at))
             .build()
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use domain::traits::email_sender::{EmailMessage, EmailSender, EmailSenderError};
+use time::{OffsetDateTime, format_description::well_known::Rfc2822};
+use tracing::info;
+
+/// Development adapter that writes every email to an `.eml` file and logs where it went,
+/// so links such as password resets can be followed without an SMTP server.
+#[derive(Debug, Clone)]
+pub struct FileEmailSender {
+    outbox_dir: PathBuf,
+}
+
+impl FileEmailSender {
+    pub fn new(outbox_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            outbox_dir: outbox_dir.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl EmailSender for FileEmailSender {
+    async fn send(&self, email: &EmailMessage) -> Result<(), EmailSenderError> {
+        tokio::fs::create_dir_all(&self.outbox_dir)
+            .await
+            .map_err(|e| EmailSenderError::Failed(e.to_string()))?;
+
+        let now = OffsetDateTime::now_utc();
+        let path = self.outbox_dir.join(format!(
+            "{}-{}.eml",
+            now.unix_timestamp(),
+            uuid::Uuid::now_v7()
+        ));
+        let contents = format!(
+            "Date: {}\r\nTo: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}",
+            now.format(&Rfc2822).unwrap_or_default(),
+            email.to,
+            email.subject,
+            email.text_body
+        );
+
+        tokio::fs::write(&path, contents)
+            .await
+            .map_err(|e| EmailSenderError::Failed(e.to_string()))?;
+
+        info!(
+            "wrote email \"{}\" for {} to {}",
+            email.subject,
+            email.to,
+            path.display()
+        );
+
+        Ok(())
+    }
+}
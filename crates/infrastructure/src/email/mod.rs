@@ -0,0 +1,2 @@
+pub mod file_email_sender;
+pub mod smtp_email_sender;
@@ -0,0 +1,432 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use domain::traits::email_sender::{EmailMessage, EmailSender, EmailSenderError};
+use time::{OffsetDateTime, format_description::well_known::Rfc2822};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+use tokio_rustls::{
+    TlsConnector,
+    rustls::{ClientConfig, RootCertStore, crypto::ring, pki_types::ServerName},
+};
+
+/// How the SMTP connection is secured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// Plain connection, only suitable for local relays such as Mailpit.
+    None,
+    /// Upgrade with `STARTTLS` after the greeting (usually port 587).
+    StartTls,
+    /// TLS from the first byte (usually port 465).
+    Implicit,
+}
+
+impl TryFrom<&str> for SmtpTls {
+    type Error = EmailSenderError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "none" | "plain" => Ok(Self::None),
+            "starttls" => Ok(Self::StartTls),
+            "tls" | "implicit" | "smtps" => Ok(Self::Implicit),
+            other => Err(EmailSenderError::Failed(format!(
+                "unsupported SMTP TLS mode {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Minimal SMTP client supporting `AUTH PLAIN`, STARTTLS and implicit TLS. Each email
+/// uses its own connection, which is plenty for transactional mail.
+///
+/// Written by hand instead of using `lettre` because we only ever send one plain-text
+/// message per connection, and this way it reuses the `tokio-rustls`/`webpki-roots` stack
+/// the crate already builds rather than adding a second TLS and MIME stack. Bodies are
+/// base64 encoded, so no line can start with `.` and dot-stuffing is not needed. If we
+/// ever need attachments, HTML parts or pooled connections, switch to `lettre`.
+#[derive(Debug, Clone, bon::Builder)]
+pub struct SmtpEmailSender {
+    host: String,
+    port: u16,
+    tls: SmtpTls,
+    username: Option<String>,
+    password: Option<String>,
+    from: String,
+    #[builder(default = Duration::from_secs(30))]
+    timeout: Duration,
+}
+
+#[async_trait]
+impl EmailSender for SmtpEmailSender {
+    async fn send(&self, email: &EmailMessage) -> Result<(), EmailSenderError> {
+        for value in [&email.to, &email.subject, &self.from] {
+            if value.contains(['\r', '\n']) {
+                return Err(EmailSenderError::Failed(
+                    "email headers cannot contain line breaks".to_owned(),
+                ));
+            }
+        }
+
+        tokio::time::timeout(self.timeout, self.deliver(email))
+            .await
+            .map_err(|_| EmailSenderError::Failed("SMTP session timed out".to_owned()))?
+    }
+}
+
+impl SmtpEmailSender {
+    async fn deliver(&self, email: &EmailMessage) -> Result<(), EmailSenderError> {
+        let stream = TcpStream::connect((self.host.as_str(), self.port))
+            .await
+            .map_err(smtp_error)?;
+
+        match self.tls {
+            SmtpTls::None => {
+                let mut connection = SmtpConnection::new(stream);
+                connection.expect(220).await?;
+                self.transact(&mut connection, email).await
+            }
+            SmtpTls::Implicit => {
+                let stream = self
+                    .tls_connector()?
+                    .connect(self.server_name()?, stream)
+                    .await;
+                let mut connection = SmtpConnection::new(stream.map_err(smtp_error)?);
+                connection.expect(220).await?;
+                self.transact(&mut connection, email).await
+            }
+            SmtpTls::StartTls => {
+                let mut connection = SmtpConnection::new(stream);
+                connection.expect(220).await?;
+                connection.command("EHLO localhost", 250).await?;
+                connection.command("STARTTLS", 220).await?;
+
+                let stream = self
+                    .tls_connector()?
+                    .connect(self.server_name()?, connection.into_inner())
+                    .await
+                    .map_err(smtp_error)?;
+                let mut connection = SmtpConnection::new(stream);
+                self.transact(&mut connection, email).await
+            }
+        }
+    }
+
+    async fn transact<S>(
+        &self,
+        connection: &mut SmtpConnection<S>,
+        email: &EmailMessage,
+    ) -> Result<(), EmailSenderError>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        connection.command("EHLO localhost", 250).await?;
+
+        if let Some(username) = self.username.as_deref() {
+            let credentials = format!(
+                "\0{}\0{}",
+                username,
+                self.password.as_deref().unwrap_or_default()
+            );
+            connection
+                .command(&format!("AUTH PLAIN {}", STANDARD.encode(credentials)), 235)
+                .await?;
+        }
+
+        connection
+            .command(&format!("MAIL FROM:<{}>", address(&self.from)), 250)
+            .await?;
+        connection
+            .command(&format!("RCPT TO:<{}>", address(&email.to)), 250)
+            .await?;
+        connection.command("DATA", 354).await?;
+        connection.write(&self.render(email)).await?;
+        connection.command(".", 250).await?;
+        let _ = connection.command("QUIT", 221).await;
+
+        Ok(())
+    }
+
+    fn render(&self, email: &EmailMessage) -> String {
+        let domain = address(&self.from)
+            .rsplit_once('@')
+            .map(|(_, domain)| domain.to_owned())
+            .unwrap_or_else(|| "localhost".to_owned());
+        let body = STANDARD.encode(email.text_body.replace("\r\n", "\n").replace('\n', "\r\n"));
+        let body = body
+            .as_bytes()
+            .chunks(76)
+            .map(|line| String::from_utf8_lossy(line).into_owned())
+            .collect::<Vec<_>>()
+            .join("\r\n");
+
+        format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nMessage-ID: <{}@{}>\r\n\
+             MIME-Version: 1.0\r\nContent-Type: text/plain; charset=utf-8\r\n\
+             Content-Transfer-Encoding: base64\r\n\r\n{}\r\n",
+            self.from,
+            email.to,
+            encode_header(&email.subject),
+            OffsetDateTime::now_utc()
+                .format(&Rfc2822)
+                .unwrap_or_default(),
+            uuid::Uuid::now_v7(),
+            domain,
+            body
+        )
+    }
+
+    fn tls_connector(&self) -> Result<TlsConnector, EmailSenderError> {
+        let roots = RootCertStore::from_iter(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        let config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .map_err(smtp_error)?
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+
+        Ok(TlsConnector::from(Arc::new(config)))
+    }
+
+    fn server_name(&self) -> Result<ServerName<'static>, EmailSenderError> {
+        ServerName::try_from(self.host.clone()).map_err(smtp_error)
+    }
+}
+
+struct SmtpConnection<S> {
+    stream: BufReader<S>,
+}
+
+impl<S> SmtpConnection<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    async fn write(&mut self, data: &str) -> Result<(), EmailSenderError> {
+        self.stream
+            .get_mut()
+            .write_all(data.as_bytes())
+            .await
+            .map_err(smtp_error)?;
+        self.stream.get_mut().flush().await.map_err(smtp_error)
+    }
+
+    async fn command(&mut self, line: &str, expected: u16) -> Result<(), EmailSenderError> {
+        self.write(&format!("{}\r\n", line)).await?;
+        self.expect(expected).await
+    }
+
+    /// Reads a possibly multi-line reply (`250-...` continuation lines) and checks its code.
+    async fn expect(&mut self, expected: u16) -> Result<(), EmailSenderError> {
+        loop {
+            let mut line = String::new();
+            let read = self.stream.read_line(&mut line).await.map_err(smtp_error)?;
+            if read == 0 {
+                return Err(EmailSenderError::Failed(
+                    "SMTP server closed the connection".to_owned(),
+                ));
+            }
+
+            let code = line.get(..3).and_then(|code| code.parse::<u16>().ok());
+            if code != Some(expected) {
+                return Err(EmailSenderError::Failed(format!(
+                    "unexpected SMTP reply: {}",
+                    line.trim_end()
+                )));
+            }
+
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Extracts the bare address from `Name <user@example.com>` style values.
+fn address(value: &str) -> &str {
+    value
+        .rsplit_once('<')
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(address, _)| address)
+        .unwrap_or(value)
+        .trim()
+}
+
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_owned()
+    } else {
+        format!("=?utf-8?B?{}?=", STANDARD.encode(value))
+    }
+}
+
+fn smtp_error(err: impl std::fmt::Display) -> EmailSenderError {
+    EmailSenderError::Failed(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use domain::traits::email_sender::{EmailMessage, EmailSender};
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+
+    use super::{SmtpEmailSender, SmtpTls};
+
+    /// Accepts one connection, answers like a relay that rejects recipients with
+    /// `rcpt_reply` when it is not a 2xx reply, and returns every line the client sent.
+    async fn scripted_server(rcpt_reply: &'static str) -> (u16, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let port = listener.local_addr().expect("local addr").port();
+
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let (reader, mut writer) = stream.into_split();
+            let mut reader = BufReader::new(reader);
+            writer
+                .write_all(b"220 mail.test ESMTP\r\n")
+                .await
+                .expect("greeting");
+
+            let mut transcript = Vec::new();
+            let mut in_data = false;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).await.expect("read") == 0 {
+                    break;
+                }
+                let line = line.trim_end_matches("\r\n").to_owned();
+                transcript.push(line.clone());
+
+                let reply = if in_data {
+                    if line != "." {
+                        continue;
+                    }
+                    in_data = false;
+                    "250 queued"
+                } else {
+                    match line.split(' ').next().unwrap_or_default() {
+                        "EHLO" => "250-mail.test\r\n250 AUTH PLAIN",
+                        "AUTH" => "235 authenticated",
+                        "MAIL" => "250 sender ok",
+                        "RCPT" => rcpt_reply,
+                        "DATA" => {
+                            in_data = true;
+                            "354 end with ."
+                        }
+                        "QUIT" => "221 bye",
+                        _ => "500 unrecognised command",
+                    }
+                };
+                writer
+                    .write_all(format!("{}\r\n", reply).as_bytes())
+                    .await
+                    .expect("reply");
+
+                if line == "QUIT" || reply.starts_with('5') {
+                    break;
+                }
+            }
+
+            transcript
+        });
+
+        (port, handle)
+    }
+
+    fn sender(port: u16) -> SmtpEmailSender {
+        SmtpEmailSender::builder()
+            .host("127.0.0.1".to_owned())
+            .port(port)
+            .tls(SmtpTls::None)
+            .username("mailer".to_owned())
+            .password("secret".to_owned())
+            .from("Texts <no-reply@example.com>".to_owned())
+            .build()
+    }
+
+    fn email() -> EmailMessage {
+        EmailMessage {
+            to: "ada@example.com".to_owned(),
+            subject: "Reset your password".to_owned(),
+            text_body: "Hello\nWorld".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn delivers_through_a_smtp_session() {
+        let (port, server) = scripted_server("250 recipient ok").await;
+
+        sender(port)
+            .send(&email())
+            .await
+            .expect("email should send");
+
+        let transcript = server.await.expect("server");
+        assert_eq!(transcript[0], "EHLO localhost");
+        assert_eq!(
+            transcript[1],
+            format!("AUTH PLAIN {}", STANDARD.encode("\0mailer\0secret"))
+        );
+        assert_eq!(transcript[2], "MAIL FROM:<no-reply@example.com>");
+        assert_eq!(transcript[3], "RCPT TO:<ada@example.com>");
+        assert_eq!(transcript[4], "DATA");
+        assert!(transcript.contains(&"From: Texts <no-reply@example.com>".to_owned()));
+        assert!(transcript.contains(&"To: ada@example.com".to_owned()));
+        assert!(transcript.contains(&"Subject: Reset your password".to_owned()));
+        assert!(transcript.contains(&STANDARD.encode("Hello\r\nWorld")));
+        assert_eq!(transcript[transcript.len() - 2..], [".", "QUIT"]);
+    }
+
+    #[tokio::test]
+    async fn reports_rejected_recipients() {
+        let (port, server) = scripted_server("550 no such user").await;
+
+        let err = sender(port)
+            .send(&email())
+            .await
+            .expect_err("rejected recipient should fail");
+
+        assert!(err.to_string().contains("550 no such user"));
+        assert!(!server.await.expect("server").contains(&"DATA".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn rejects_line_breaks_in_headers() {
+        let (port, server) = scripted_server("250 recipient ok").await;
+        let sender = sender(port);
+
+        for email in [
+            EmailMessage {
+                subject: "Hi\r\nBcc: mallory@example.com".to_owned(),
+                ..email()
+            },
+            EmailMessage {
+                to: "ada@example.com\nBcc: mallory@example.com".to_owned(),
+                ..email()
+            },
+        ] {
+            let err = sender
+                .send(&email)
+                .await
+                .expect_err("header injection should fail");
+            assert!(err.to_string().contains("cannot contain line breaks"));
+        }
+
+        server.abort();
+    }
+}
@@ -1,6 +1,8 @@
 pub mod config;
 pub mod database;
+pub mod email;
 pub mod repositories;
 pub mod security;
+pub mod storage;
 
 pub use repositories::phone_number_repository_impl::PhoneNumberRepositoryImpl;
@@ -0,0 +1,17 @@
+CREATE TABLE
+    "outbound_message_jobs" (
+        message_id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        to_number TEXT NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        locked_until TIMESTAMPTZ,
+        last_error TEXT,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "outbound_message_jobs" ADD CONSTRAINT "fk-outbound_message_jobs-message_id" FOREIGN KEY ("message_id") REFERENCES "messages" ("id") ON DELETE CASCADE;
+ALTER TABLE "outbound_message_jobs" ADD CONSTRAINT "fk-outbound_message_jobs-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX outbound_message_jobs_next_attempt_at_idx ON outbound_message_jobs (next_attempt_at);
@@ -0,0 +1,18 @@
+ALTER TABLE "processed_webhook_events" ADD COLUMN status TEXT NOT NULL DEFAULT 'processed';
+ALTER TABLE "processed_webhook_events" ADD COLUMN attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE "processed_webhook_events" ADD COLUMN next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW ();
+ALTER TABLE "processed_webhook_events" ADD COLUMN locked_until TIMESTAMPTZ;
+ALTER TABLE "processed_webhook_events" ADD COLUMN last_error TEXT;
+ALTER TABLE "processed_webhook_events" ADD COLUMN processed_at TIMESTAMPTZ;
+ALTER TABLE "processed_webhook_events" ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ();
+
+ALTER TABLE "processed_webhook_events" ADD CONSTRAINT "chk-processed_webhook_events-status" CHECK (status IN ('pending', 'processed', 'dead_lettered'));
+
+UPDATE "processed_webhook_events" SET processed_at = created_at, updated_at = created_at;
+
+CREATE INDEX processed_webhook_events_pending_idx
+    ON processed_webhook_events (next_attempt_at)
+    WHERE status = 'pending';
+
+CREATE INDEX processed_webhook_events_status_created_at_idx
+    ON processed_webhook_events (status, created_at);
@@ -0,0 +1,6 @@
+ALTER TABLE "processed_webhook_events" DROP CONSTRAINT "chk-processed_webhook_events-status";
+ALTER TABLE "processed_webhook_events" ADD CONSTRAINT "chk-processed_webhook_events-status" CHECK (status IN ('pending', 'processed', 'parked', 'dead_lettered'));
+
+CREATE INDEX processed_webhook_events_parked_idx
+    ON processed_webhook_events (provider_message_id)
+    WHERE status = 'parked';
@@ -0,0 +1,19 @@
+CREATE TABLE
+    "message_status_events" (
+        id UUID NOT NULL PRIMARY KEY,
+        message_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        status TEXT NOT NULL,
+        provider_status TEXT,
+        carrier TEXT,
+        error_code TEXT,
+        error_detail TEXT,
+        occurred_at TIMESTAMPTZ NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "message_status_events" ADD CONSTRAINT "fk-message_status_events-message_id" FOREIGN KEY ("message_id") REFERENCES "messages" ("id") ON DELETE CASCADE;
+ALTER TABLE "message_status_events" ADD CONSTRAINT "fk-message_status_events-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE UNIQUE INDEX message_status_events_transition_unique_idx
+    ON message_status_events (message_id, status, occurred_at);
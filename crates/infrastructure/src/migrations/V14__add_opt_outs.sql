@@ -0,0 +1,15 @@
+CREATE TABLE
+    "opt_outs" (
+        id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        phone_number_id UUID NOT NULL,
+        recipient_phone_number TEXT NOT NULL,
+        keyword TEXT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "opt_outs" ADD CONSTRAINT "fk-opt_outs-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+ALTER TABLE "opt_outs" ADD CONSTRAINT "fk-opt_outs-phone_number_id" FOREIGN KEY ("phone_number_id") REFERENCES "phone_numbers" ("id") ON DELETE CASCADE;
+
+CREATE UNIQUE INDEX opt_outs_phone_number_recipient_unique_idx
+    ON opt_outs (phone_number_id, recipient_phone_number);
@@ -0,0 +1,30 @@
+CREATE TABLE
+    "contacts" (
+        id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        name TEXT NOT NULL,
+        email TEXT,
+        notes TEXT,
+        custom_fields JSONB NOT NULL DEFAULT '{}',
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "contacts" ADD CONSTRAINT "fk-contacts-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX contacts_user_id_idx ON contacts (user_id);
+
+CREATE TABLE
+    "contact_phone_numbers" (
+        id UUID NOT NULL PRIMARY KEY,
+        contact_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        phone_number TEXT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "contact_phone_numbers" ADD CONSTRAINT "fk-contact_phone_numbers-contact_id" FOREIGN KEY ("contact_id") REFERENCES "contacts" ("id") ON DELETE CASCADE;
+ALTER TABLE "contact_phone_numbers" ADD CONSTRAINT "fk-contact_phone_numbers-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE UNIQUE INDEX contact_phone_numbers_user_phone_unique_idx
+    ON contact_phone_numbers (user_id, phone_number);
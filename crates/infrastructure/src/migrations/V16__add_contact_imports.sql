@@ -0,0 +1,22 @@
+CREATE TABLE
+    "contact_imports" (
+        id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        file_name TEXT NOT NULL,
+        format TEXT NOT NULL,
+        status TEXT NOT NULL,
+        column_mapping JSONB NOT NULL DEFAULT '{}',
+        default_country_code TEXT,
+        content TEXT NOT NULL,
+        rows JSONB NOT NULL DEFAULT '[]',
+        attempts INTEGER NOT NULL DEFAULT 0,
+        locked_until TIMESTAMPTZ,
+        last_error TEXT,
+        completed_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "contact_imports" ADD CONSTRAINT "fk-contact_imports-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX contact_imports_pending_idx ON contact_imports (created_at) WHERE status = 'pending';
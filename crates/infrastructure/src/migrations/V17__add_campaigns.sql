@@ -0,0 +1,48 @@
+ALTER TABLE "contacts" ADD COLUMN tags JSONB NOT NULL DEFAULT '[]';
+
+CREATE TABLE
+    "campaigns" (
+        id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        phone_number_id UUID NOT NULL,
+        name TEXT NOT NULL,
+        body TEXT NOT NULL,
+        tag TEXT,
+        messages_per_second INTEGER NOT NULL,
+        status TEXT NOT NULL,
+        next_batch_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        locked_until TIMESTAMPTZ,
+        completed_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "campaigns" ADD CONSTRAINT "fk-campaigns-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+ALTER TABLE "campaigns" ADD CONSTRAINT "fk-campaigns-phone_number_id" FOREIGN KEY ("phone_number_id") REFERENCES "phone_numbers" ("id") ON DELETE CASCADE;
+
+CREATE INDEX campaigns_user_id_idx ON campaigns (user_id, created_at DESC);
+CREATE INDEX campaigns_running_idx ON campaigns (next_batch_at) WHERE status = 'running';
+
+CREATE TABLE
+    "campaign_recipients" (
+        id UUID NOT NULL PRIMARY KEY,
+        campaign_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        recipient_phone_number TEXT NOT NULL,
+        contact_id UUID,
+        status TEXT NOT NULL,
+        content TEXT NOT NULL,
+        message_id UUID,
+        reason TEXT,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "campaign_recipients" ADD CONSTRAINT "fk-campaign_recipients-campaign_id" FOREIGN KEY ("campaign_id") REFERENCES "campaigns" ("id") ON DELETE CASCADE;
+ALTER TABLE "campaign_recipients" ADD CONSTRAINT "fk-campaign_recipients-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+ALTER TABLE "campaign_recipients" ADD CONSTRAINT "fk-campaign_recipients-message_id" FOREIGN KEY ("message_id") REFERENCES "messages" ("id") ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX campaign_recipients_campaign_phone_unique_idx
+    ON campaign_recipients (campaign_id, recipient_phone_number);
+CREATE INDEX campaign_recipients_pending_idx ON campaign_recipients (campaign_id, created_at) WHERE status = 'pending';
+CREATE INDEX campaign_recipients_message_id_idx ON campaign_recipients (message_id);
@@ -0,0 +1,21 @@
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT
+            1
+        FROM
+            pg_enum
+        WHERE
+            enumlabel = 'scheduled'
+            AND enumtypid = 'message_status'::regtype
+    ) THEN
+        ALTER TYPE message_status ADD VALUE 'scheduled';
+    END IF;
+END
+$$;
+
+ALTER TABLE "messages" ADD COLUMN scheduled_at TIMESTAMPTZ;
+
+CREATE INDEX messages_conversation_scheduled_at_idx
+    ON messages (conversation_id, scheduled_at)
+    WHERE scheduled_at IS NOT NULL;
@@ -0,0 +1,13 @@
+CREATE TABLE
+    "message_templates" (
+        id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        name TEXT NOT NULL,
+        body TEXT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "message_templates" ADD CONSTRAINT "fk-message_templates-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX message_templates_user_id_idx ON message_templates (user_id, name);
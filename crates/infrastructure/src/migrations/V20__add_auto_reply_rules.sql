@@ -0,0 +1,31 @@
+ALTER TABLE "messages" ADD COLUMN automated BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE
+    "auto_reply_rules" (
+        id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        phone_number_id UUID NOT NULL,
+        match_type TEXT NOT NULL,
+        pattern TEXT NOT NULL,
+        reply_template TEXT NOT NULL,
+        tag TEXT,
+        cooldown_seconds BIGINT NOT NULL DEFAULT 0,
+        enabled BOOLEAN NOT NULL DEFAULT TRUE,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "auto_reply_rules" ADD CONSTRAINT "fk-auto_reply_rules-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+ALTER TABLE "auto_reply_rules" ADD CONSTRAINT "fk-auto_reply_rules-phone_number_id" FOREIGN KEY ("phone_number_id") REFERENCES "phone_numbers" ("id") ON DELETE CASCADE;
+
+CREATE INDEX auto_reply_rules_phone_number_id_idx ON auto_reply_rules (phone_number_id, created_at);
+
+CREATE TABLE
+    "auto_reply_deliveries" (
+        rule_id UUID NOT NULL,
+        recipient_phone_number TEXT NOT NULL,
+        last_replied_at TIMESTAMPTZ NOT NULL,
+        PRIMARY KEY (rule_id, recipient_phone_number)
+    );
+
+ALTER TABLE "auto_reply_deliveries" ADD CONSTRAINT "fk-auto_reply_deliveries-rule_id" FOREIGN KEY ("rule_id") REFERENCES "auto_reply_rules" ("id") ON DELETE CASCADE;
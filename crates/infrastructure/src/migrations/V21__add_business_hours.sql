@@ -0,0 +1,17 @@
+ALTER TABLE "conversations" ADD COLUMN last_away_reply_at TIMESTAMPTZ;
+
+CREATE TABLE
+    "business_hours" (
+        phone_number_id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        timezone TEXT NOT NULL,
+        opening_hours JSONB NOT NULL DEFAULT '[]',
+        holidays JSONB NOT NULL DEFAULT '[]',
+        away_message TEXT NOT NULL,
+        enabled BOOLEAN NOT NULL DEFAULT TRUE,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "business_hours" ADD CONSTRAINT "fk-business_hours-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+ALTER TABLE "business_hours" ADD CONSTRAINT "fk-business_hours-phone_number_id" FOREIGN KEY ("phone_number_id") REFERENCES "phone_numbers" ("id") ON DELETE CASCADE;
@@ -0,0 +1,2 @@
+ALTER TABLE "messages" ADD COLUMN segments INTEGER;
+ALTER TABLE "messages" ADD COLUMN encoding TEXT;
@@ -0,0 +1,5 @@
+ALTER TABLE "messages" ADD COLUMN cost_micros BIGINT;
+ALTER TABLE "messages" ADD COLUMN cost_currency TEXT;
+ALTER TABLE "messages" ADD COLUMN carrier TEXT;
+ALTER TABLE "messages" ADD COLUMN line_type TEXT;
+ALTER TABLE "messages" ADD COLUMN messaging_profile_id TEXT;
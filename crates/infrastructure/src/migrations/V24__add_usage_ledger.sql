@@ -0,0 +1,29 @@
+-- Ledger rows outlive their message and phone number so past spend stays reconcilable.
+CREATE TABLE
+    "usage_records" (
+        message_id UUID NOT NULL PRIMARY KEY,
+        user_id UUID NOT NULL,
+        phone_number_id UUID NOT NULL,
+        segments INTEGER NOT NULL,
+        cost_micros BIGINT NOT NULL DEFAULT 0,
+        cost_estimated BOOLEAN NOT NULL DEFAULT TRUE,
+        recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "usage_records" ADD CONSTRAINT "fk-usage_records-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX usage_records_user_id_recorded_at_idx ON usage_records (user_id, recorded_at);
+
+CREATE TABLE
+    "usage_limits" (
+        user_id UUID NOT NULL,
+        period TEXT NOT NULL,
+        soft_limit_micros BIGINT,
+        hard_limit_micros BIGINT,
+        soft_limit_notified_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        PRIMARY KEY (user_id, period)
+    );
+
+ALTER TABLE "usage_limits" ADD CONSTRAINT "fk-usage_limits-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
@@ -0,0 +1,67 @@
+CREATE TABLE
+    "organizations" (
+        id UUID NOT NULL PRIMARY KEY,
+        name TEXT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+CREATE TABLE
+    "organization_members" (
+        organization_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        PRIMARY KEY (organization_id, user_id)
+    );
+
+ALTER TABLE "organization_members" ADD CONSTRAINT "fk-organization_members-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+ALTER TABLE "organization_members" ADD CONSTRAINT "fk-organization_members-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX organization_members_user_id_idx ON organization_members (user_id, created_at);
+
+CREATE TABLE
+    "organization_invitations" (
+        id UUID NOT NULL PRIMARY KEY,
+        organization_id UUID NOT NULL,
+        email TEXT NOT NULL,
+        invited_by UUID NOT NULL,
+        expires_at TIMESTAMPTZ NOT NULL,
+        accepted_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+ALTER TABLE "organization_invitations" ADD CONSTRAINT "fk-organization_invitations-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+ALTER TABLE "organization_invitations" ADD CONSTRAINT "fk-organization_invitations-invited_by" FOREIGN KEY ("invited_by") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX organization_invitations_organization_id_idx ON organization_invitations (organization_id, created_at DESC);
+
+-- Every existing user gets a personal organization that takes over their numbers and
+-- conversations. It reuses the user's id so the backfill below is a plain copy.
+INSERT INTO organizations (id, name, created_at, updated_at)
+SELECT id, email, created_at, NOW () FROM users;
+
+INSERT INTO organization_members (organization_id, user_id, created_at)
+SELECT id, id, created_at FROM users;
+
+ALTER TABLE "phone_numbers" ADD COLUMN organization_id UUID;
+UPDATE phone_numbers SET organization_id = user_id;
+ALTER TABLE "phone_numbers" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "phone_numbers" ADD CONSTRAINT "fk-phone_numbers-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+CREATE INDEX phone_numbers_organization_id_idx ON phone_numbers (organization_id);
+
+ALTER TABLE "conversations" ADD COLUMN organization_id UUID;
+UPDATE conversations SET organization_id = user_id;
+ALTER TABLE "conversations" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "conversations" ADD CONSTRAINT "fk-conversations-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+CREATE INDEX conversations_organization_id_idx ON conversations (organization_id, last_message_at DESC);
+
+ALTER TABLE "messages" ADD COLUMN organization_id UUID;
+UPDATE messages SET organization_id = user_id;
+ALTER TABLE "messages" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "messages" ADD CONSTRAINT "fk-messages-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+CREATE INDEX messages_organization_id_conversation_id_idx ON messages (organization_id, conversation_id, created_at DESC);
+
+ALTER TABLE "campaigns" ADD COLUMN organization_id UUID;
+UPDATE campaigns SET organization_id = user_id;
+ALTER TABLE "campaigns" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "campaigns" ADD CONSTRAINT "fk-campaigns-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
@@ -0,0 +1,13 @@
+ALTER TABLE "organization_members" ADD COLUMN role TEXT NOT NULL DEFAULT 'agent';
+
+-- Whoever joined an organization first created it.
+UPDATE organization_members m
+SET role = 'owner'
+WHERE m.created_at = (
+    SELECT MIN(created_at) FROM organization_members WHERE organization_id = m.organization_id
+);
+
+ALTER TABLE "organization_members" ALTER COLUMN role DROP DEFAULT;
+
+ALTER TABLE "organization_invitations" ADD COLUMN role TEXT NOT NULL DEFAULT 'agent';
+ALTER TABLE "organization_invitations" ALTER COLUMN role DROP DEFAULT;
@@ -0,0 +1,7 @@
+ALTER TABLE "conversations" ADD COLUMN assigned_user_id UUID;
+ALTER TABLE "conversations" ADD COLUMN assigned_at TIMESTAMPTZ;
+ALTER TABLE "conversations" ADD CONSTRAINT "fk-conversations-assigned_user_id" FOREIGN KEY ("assigned_user_id") REFERENCES "users" ("id") ON DELETE SET NULL;
+
+CREATE INDEX conversations_assigned_user_id_idx ON conversations (organization_id, assigned_user_id);
+
+ALTER TABLE "organizations" ADD COLUMN conversation_routing TEXT NOT NULL DEFAULT 'manual';
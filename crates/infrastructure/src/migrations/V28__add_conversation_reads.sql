@@ -0,0 +1,12 @@
+CREATE TABLE
+    "conversation_reads" (
+        conversation_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        last_read_at TIMESTAMPTZ NOT NULL,
+        PRIMARY KEY (conversation_id, user_id)
+    );
+
+ALTER TABLE "conversation_reads" ADD CONSTRAINT "fk-conversation_reads-conversation_id" FOREIGN KEY ("conversation_id") REFERENCES "conversations" ("id") ON DELETE CASCADE;
+ALTER TABLE "conversation_reads" ADD CONSTRAINT "fk-conversation_reads-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX conversation_reads_user_id_idx ON conversation_reads (user_id);
@@ -0,0 +1,5 @@
+ALTER TABLE "conversations" ADD COLUMN archived_at TIMESTAMPTZ;
+ALTER TABLE "conversations" ADD COLUMN pinned_at TIMESTAMPTZ;
+ALTER TABLE "conversations" ADD COLUMN snoozed_until TIMESTAMPTZ;
+
+CREATE INDEX conversations_snoozed_until_idx ON conversations (snoozed_until) WHERE snoozed_until IS NOT NULL;
@@ -0,0 +1,3 @@
+ALTER TABLE "messages" ADD COLUMN reply_to_message_id UUID REFERENCES messages(id) ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX messages_reply_to_message_id_idx ON messages (reply_to_message_id) WHERE reply_to_message_id IS NOT NULL;
@@ -0,0 +1,21 @@
+-- Contacts move from the user who created them to the organization's shared address
+-- book. Personal organizations reuse the user's id, so the backfill is a plain copy.
+ALTER TABLE "contacts" ADD COLUMN organization_id UUID;
+UPDATE contacts SET organization_id = user_id;
+ALTER TABLE "contacts" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "contacts" ADD CONSTRAINT "fk-contacts-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+CREATE INDEX contacts_organization_id_idx ON contacts (organization_id);
+
+ALTER TABLE "contact_phone_numbers" ADD COLUMN organization_id UUID;
+UPDATE contact_phone_numbers SET organization_id = user_id;
+ALTER TABLE "contact_phone_numbers" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "contact_phone_numbers" ADD CONSTRAINT "fk-contact_phone_numbers-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+
+DROP INDEX contact_phone_numbers_user_phone_unique_idx;
+CREATE UNIQUE INDEX contact_phone_numbers_organization_phone_unique_idx
+    ON contact_phone_numbers (organization_id, phone_number);
+
+ALTER TABLE "contact_imports" ADD COLUMN organization_id UUID;
+UPDATE contact_imports SET organization_id = user_id;
+ALTER TABLE "contact_imports" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "contact_imports" ADD CONSTRAINT "fk-contact_imports-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
@@ -0,0 +1 @@
+ALTER TABLE "campaigns" ADD COLUMN pause_reason TEXT;
@@ -0,0 +1,15 @@
+-- Read cursors point at the newest message the member has read instead of a timestamp.
+-- Inbound messages keep the provider's receive time in created_at, which can be older than
+-- the read when the webhook is processed late; message ids are uuid v7 minted on storage.
+ALTER TABLE "conversation_reads" ADD COLUMN last_read_message_id UUID;
+UPDATE conversation_reads r
+SET last_read_message_id = (
+    SELECT m.id
+    FROM messages m
+    WHERE m.conversation_id = r.conversation_id AND m.created_at <= r.last_read_at
+    ORDER BY m.id DESC
+    LIMIT 1
+);
+DELETE FROM conversation_reads WHERE last_read_message_id IS NULL;
+ALTER TABLE "conversation_reads" ALTER COLUMN last_read_message_id SET NOT NULL;
+ALTER TABLE "conversation_reads" DROP COLUMN last_read_at;
@@ -0,0 +1,13 @@
+-- Auto-reply rules and business hours belong to the organization that owns the number, not
+-- to the member who set them up.
+ALTER TABLE "auto_reply_rules" ADD COLUMN organization_id UUID;
+UPDATE auto_reply_rules r SET organization_id = p.organization_id
+FROM phone_numbers p WHERE p.id = r.phone_number_id;
+ALTER TABLE "auto_reply_rules" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "auto_reply_rules" ADD CONSTRAINT "fk-auto_reply_rules-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
+
+ALTER TABLE "business_hours" ADD COLUMN organization_id UUID;
+UPDATE business_hours b SET organization_id = p.organization_id
+FROM phone_numbers p WHERE p.id = b.phone_number_id;
+ALTER TABLE "business_hours" ALTER COLUMN organization_id SET NOT NULL;
+ALTER TABLE "business_hours" ADD CONSTRAINT "fk-business_hours-organization_id" FOREIGN KEY ("organization_id") REFERENCES "organizations" ("id") ON DELETE CASCADE;
@@ -0,0 +1,17 @@
+CREATE TABLE
+    "message_attachments" (
+        id UUID NOT NULL PRIMARY KEY,
+        message_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        file_name TEXT NOT NULL,
+        content_type TEXT NOT NULL,
+        size_bytes BIGINT NOT NULL,
+        storage_key TEXT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        CONSTRAINT message_attachments_storage_key_unique UNIQUE (storage_key)
+    );
+
+ALTER TABLE "message_attachments" ADD CONSTRAINT "fk-message_attachments-message_id" FOREIGN KEY ("message_id") REFERENCES "messages" ("id") ON DELETE CASCADE;
+ALTER TABLE "message_attachments" ADD CONSTRAINT "fk-message_attachments-user_id" FOREIGN KEY ("user_id") REFERENCES "users" ("id") ON DELETE CASCADE;
+
+CREATE INDEX message_attachments_message_id_idx ON message_attachments (message_id, created_at);
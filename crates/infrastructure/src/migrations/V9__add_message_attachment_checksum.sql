@@ -0,0 +1 @@
+ALTER TABLE "message_attachments" ADD COLUMN checksum_sha256 TEXT;
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use domain::models::auto_reply_rule::AutoReplyRule;
+use domain::repositories::RepositoryError;
+use domain::repositories::auto_reply_rule_repository::AutoReplyRuleRepository;
+
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+use time::OffsetDateTime;
+
+use crate::database;
+use crate::database::models::{AutoReplyRuleSql, UuidExt, offset_datetime_to_datetime};
+use crate::repositories::RbsErrorExt;
+
+#[derive(Debug, bon::Builder)]
+pub struct AutoReplyRuleRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl AutoReplyRuleRepository for AutoReplyRuleRepositoryImpl {
+    async fn create_rule(&self, rule: &AutoReplyRule) -> Result<(), RepositoryError> {
+        let record = database::models::auto_reply_rule::AutoReplyRule::from(rule);
+
+        database::models::auto_reply_rule::AutoReplyRule::insert(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn update_rule(&self, rule: &AutoReplyRule) -> Result<(), RepositoryError> {
+        let record = database::models::auto_reply_rule::AutoReplyRule::from(rule);
+
+        let result = database::models::auto_reply_rule::AutoReplyRule::update_by_map(
+            self.pool.as_ref(),
+            &record,
+            value! { "id": rule.id.into_db(), "organization_id": rule.organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<AutoReplyRule, RepositoryError> {
+        let record = database::models::auto_reply_rule::AutoReplyRule::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(AutoReplyRule::from(&record))
+    }
+
+    async fn list_by_phone_number_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_number_id: &uuid::Uuid,
+    ) -> Result<Vec<AutoReplyRule>, RepositoryError> {
+        let records = database::models::auto_reply_rule::AutoReplyRule::select_by_map(
+            self.pool.as_ref(),
+            value! {
+                "organization_id": organization_id.into_db(),
+                "phone_number_id": phone_number_id.into_db(),
+            },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut rules = records.iter().map(AutoReplyRule::from).collect::<Vec<_>>();
+        rules.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        Ok(rules)
+    }
+
+    async fn delete_rule(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        let result = database::models::auto_reply_rule::AutoReplyRule::delete_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn claim_reply(
+        &self,
+        rule: &AutoReplyRule,
+        recipient_phone_number: &str,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let cooldown_started_before = now - time::Duration::seconds(rule.cooldown_seconds.max(0));
+        let result = AutoReplyRuleSql::claim_reply(
+            self.pool.as_ref(),
+            rule.id.into_db(),
+            recipient_phone_number,
+            offset_datetime_to_datetime(now),
+            offset_datetime_to_datetime(cooldown_started_before),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use domain::models::business_hours::BusinessHours;
+use domain::repositories::RepositoryError;
+use domain::repositories::business_hours_repository::BusinessHoursRepository;
+
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+
+use crate::database;
+use crate::database::models::UuidExt;
+use crate::repositories::RbsErrorExt;
+
+#[derive(Debug, bon::Builder)]
+pub struct BusinessHoursRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl BusinessHoursRepository for BusinessHoursRepositoryImpl {
+    async fn create_business_hours(
+        &self,
+        business_hours: &BusinessHours,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::business_hours::BusinessHours::from(business_hours);
+
+        database::models::business_hours::BusinessHours::insert(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn update_business_hours(
+        &self,
+        business_hours: &BusinessHours,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::business_hours::BusinessHours::from(business_hours);
+
+        let result = database::models::business_hours::BusinessHours::update_by_map(
+            self.pool.as_ref(),
+            &record,
+            value! {
+                "phone_number_id": business_hours.phone_number_id.into_db(),
+                "organization_id": business_hours.organization_id.into_db(),
+            },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_phone_number_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_number_id: &uuid::Uuid,
+    ) -> Result<BusinessHours, RepositoryError> {
+        let record = database::models::business_hours::BusinessHours::select_by_map(
+            self.pool.as_ref(),
+            value! {
+                "phone_number_id": phone_number_id.into_db(),
+                "organization_id": organization_id.into_db(),
+            },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(BusinessHours::from(&record))
+    }
+
+    async fn delete_business_hours(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_number_id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        let result = database::models::business_hours::BusinessHours::delete_by_map(
+            self.pool.as_ref(),
+            value! {
+                "phone_number_id": phone_number_id.into_db(),
+                "organization_id": organization_id.into_db(),
+            },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
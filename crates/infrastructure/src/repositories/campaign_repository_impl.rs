@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use domain::{
+    models::campaign::{Campaign, CampaignProgress, CampaignRecipient, CampaignStatus},
+    repositories::{RepositoryError, campaign_repository::CampaignRepository},
+};
+use rbatis::executor::RBatisTxExecutor;
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+use time::OffsetDateTime;
+
+use crate::{
+    database::{
+        self,
+        models::{
+            CampaignSql, UuidExt, campaign::campaign_status_to_db, offset_datetime_to_datetime,
+        },
+    },
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct CampaignRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl CampaignRepository for CampaignRepositoryImpl {
+    async fn create_campaign(
+        &self,
+        campaign: &Campaign,
+        recipients: &[CampaignRecipient],
+    ) -> Result<(), RepositoryError> {
+        let tx = self
+            .pool
+            .acquire_begin()
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        match Self::insert_campaign(&tx, campaign, recipients).await {
+            Ok(()) => tx.commit().await.map_err(|e| e.to_repository_error()),
+            Err(err) => {
+                let _ = tx.rollback().await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<Campaign, RepositoryError> {
+        let record = database::models::campaign::Campaign::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(Campaign::from(&record))
+    }
+
+    async fn list_by_organization_id(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<Campaign>, RepositoryError> {
+        let records = database::models::campaign::Campaign::select_by_map(
+            self.pool.as_ref(),
+            value! { "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut campaigns = records.iter().map(Campaign::from).collect::<Vec<_>>();
+        campaigns.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+
+        Ok(campaigns)
+    }
+
+    async fn change_status(
+        &self,
+        id: &uuid::Uuid,
+        from: CampaignStatus,
+        to: CampaignStatus,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let result = CampaignSql::change_status(
+            self.pool.as_ref(),
+            id.into_db(),
+            campaign_status_to_db(from),
+            campaign_status_to_db(to),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn pause(
+        &self,
+        id: &uuid::Uuid,
+        reason: &str,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let result = CampaignSql::pause(
+            self.pool.as_ref(),
+            id.into_db(),
+            reason,
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn claim_due(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+        limit: usize,
+    ) -> Result<Vec<Campaign>, RepositoryError> {
+        let records = CampaignSql::claim_due(
+            self.pool.as_ref(),
+            offset_datetime_to_datetime(now),
+            offset_datetime_to_datetime(locked_until),
+            limit as i64,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(Campaign::from).collect())
+    }
+
+    async fn schedule_next_batch(
+        &self,
+        id: &uuid::Uuid,
+        next_batch_at: OffsetDateTime,
+    ) -> Result<(), RepositoryError> {
+        CampaignSql::schedule_next_batch(
+            self.pool.as_ref(),
+            id.into_db(),
+            offset_datetime_to_datetime(next_batch_at),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn list_pending_recipients(
+        &self,
+        campaign_id: &uuid::Uuid,
+        limit: usize,
+    ) -> Result<Vec<CampaignRecipient>, RepositoryError> {
+        let records = CampaignSql::select_pending_recipients(
+            self.pool.as_ref(),
+            campaign_id.into_db(),
+            limit as i64,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(CampaignRecipient::from).collect())
+    }
+
+    async fn update_recipient(&self, recipient: &CampaignRecipient) -> Result<(), RepositoryError> {
+        let record = database::models::campaign::CampaignRecipient::from(recipient);
+
+        let result = database::models::campaign::CampaignRecipient::update_by_map(
+            self.pool.as_ref(),
+            &record,
+            value! { "id": record.id.clone() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn progress(
+        &self,
+        campaign_id: &uuid::Uuid,
+    ) -> Result<CampaignProgress, RepositoryError> {
+        let rows = CampaignSql::select_progress(self.pool.as_ref(), campaign_id.into_db())
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(rows.first().map(CampaignProgress::from).unwrap_or_default())
+    }
+}
+
+impl CampaignRepositoryImpl {
+    async fn insert_campaign(
+        tx: &RBatisTxExecutor,
+        campaign: &Campaign,
+        recipients: &[CampaignRecipient],
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::campaign::Campaign::from(campaign);
+        database::models::campaign::Campaign::insert(tx, &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        if recipients.is_empty() {
+            return Ok(());
+        }
+
+        let records = recipients
+            .iter()
+            .map(database::models::campaign::CampaignRecipient::from)
+            .collect::<Vec<_>>();
+        database::models::campaign::CampaignRecipient::insert_batch(tx, &records, 500)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use domain::{
+    models::contact_import::ContactImport,
+    repositories::{RepositoryError, contact_import_repository::ContactImportRepository},
+};
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+use time::OffsetDateTime;
+
+use crate::{
+    database::{
+        self,
+        models::{ContactImportSql, UuidExt, offset_datetime_to_datetime},
+    },
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct ContactImportRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl ContactImportRepository for ContactImportRepositoryImpl {
+    async fn create_import(&self, import: &ContactImport) -> Result<(), RepositoryError> {
+        let record = database::models::contact_import::ContactImport::from(import);
+
+        database::models::contact_import::ContactImport::insert(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn update_import(&self, import: &ContactImport) -> Result<(), RepositoryError> {
+        let record = database::models::contact_import::ContactImport::from(import);
+
+        let result = database::models::contact_import::ContactImport::update_by_map(
+            self.pool.as_ref(),
+            &record,
+            value! { "id": record.id.clone() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<ContactImport, RepositoryError> {
+        let record = database::models::contact_import::ContactImport::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(ContactImport::from(&record))
+    }
+
+    async fn claim_pending(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+    ) -> Result<Option<ContactImport>, RepositoryError> {
+        let records = ContactImportSql::claim_pending(
+            self.pool.as_ref(),
+            offset_datetime_to_datetime(now),
+            offset_datetime_to_datetime(locked_until),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.first().map(ContactImport::from))
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use domain::models::contact::Contact;
+use domain::repositories::RepositoryError;
+use domain::repositories::contact_repository::ContactRepository;
+
+use rbatis::executor::RBatisTxExecutor;
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+
+use crate::database;
+use crate::database::models::{RdbcUuidExt, UuidExt};
+use crate::repositories::RbsErrorExt;
+
+#[derive(Debug, bon::Builder)]
+pub struct ContactRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl ContactRepository for ContactRepositoryImpl {
+    async fn create_contact(&self, contact: &Contact) -> Result<(), RepositoryError> {
+        let tx = self
+            .pool
+            .acquire_begin()
+            .await
+            .map_err(|e| e.to_repository_error())?;
+        let result = Self::insert_contact(&tx, contact).await;
+
+        Self::finish(tx, result).await
+    }
+
+    async fn update_contact(&self, contact: &Contact) -> Result<(), RepositoryError> {
+        self.find_by_id(&contact.organization_id, &contact.id)
+            .await?;
+
+        let tx = self
+            .pool
+            .acquire_begin()
+            .await
+            .map_err(|e| e.to_repository_error())?;
+        let result = Self::replace_contact(&tx, contact).await;
+
+        Self::finish(tx, result).await
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<Contact, RepositoryError> {
+        let record = database::models::contact::Contact::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        let mut contacts = self
+            .with_phone_numbers(vec![Contact::from(&record)])
+            .await?;
+        contacts.pop().ok_or(RepositoryError::NotFound)
+    }
+
+    async fn list_by_organization_id(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<Contact>, RepositoryError> {
+        let records = database::models::contact::Contact::select_by_map(
+            self.pool.as_ref(),
+            value! { "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut contacts = records.iter().map(Contact::from).collect::<Vec<_>>();
+        contacts.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        self.with_phone_numbers(contacts).await
+    }
+
+    async fn find_by_phone_numbers(
+        &self,
+        organization_id: &uuid::Uuid,
+        phone_numbers: &[String],
+    ) -> Result<Vec<Contact>, RepositoryError> {
+        if phone_numbers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let number_records = database::models::contact::ContactPhoneNumber::select_by_map(
+            self.pool.as_ref(),
+            value! { "organization_id": organization_id.into_db(), "phone_number": phone_numbers },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if number_records.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let contact_ids = number_records
+            .iter()
+            .map(|record| record.contact_id.clone())
+            .collect::<Vec<_>>();
+        let records = database::models::contact::Contact::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": contact_ids, "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        self.with_phone_numbers(records.iter().map(Contact::from).collect())
+            .await
+    }
+
+    async fn delete_contact(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        self.find_by_id(organization_id, id).await?;
+
+        database::models::contact::Contact::delete_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+}
+
+impl ContactRepositoryImpl {
+    async fn insert_contact(
+        tx: &RBatisTxExecutor,
+        contact: &Contact,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::contact::Contact::from(contact);
+        database::models::contact::Contact::insert(tx, &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Self::insert_phone_numbers(tx, contact).await
+    }
+
+    async fn replace_contact(
+        tx: &RBatisTxExecutor,
+        contact: &Contact,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::contact::Contact::from(contact);
+        database::models::contact::Contact::update_by_map(
+            tx,
+            &record,
+            value! { "id": record.id.clone(), "organization_id": record.organization_id.clone() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        database::models::contact::ContactPhoneNumber::delete_by_map(
+            tx,
+            value! { "contact_id": record.id.clone() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Self::insert_phone_numbers(tx, contact).await
+    }
+
+    async fn insert_phone_numbers(
+        tx: &RBatisTxExecutor,
+        contact: &Contact,
+    ) -> Result<(), RepositoryError> {
+        let records = database::models::contact::ContactPhoneNumber::for_contact(contact);
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        database::models::contact::ContactPhoneNumber::insert_batch(
+            tx,
+            &records,
+            records.len() as u64,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    /// Commits when every write succeeded, otherwise rolls the whole contact back so a
+    /// duplicate number never leaves a half-written contact behind.
+    async fn finish(
+        tx: RBatisTxExecutor,
+        result: Result<(), RepositoryError>,
+    ) -> Result<(), RepositoryError> {
+        match result {
+            Ok(()) => tx.commit().await.map_err(|e| e.to_repository_error()),
+            Err(err) => {
+                let _ = tx.rollback().await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn with_phone_numbers(
+        &self,
+        mut contacts: Vec<Contact>,
+    ) -> Result<Vec<Contact>, RepositoryError> {
+        if contacts.is_empty() {
+            return Ok(contacts);
+        }
+
+        let contact_ids = contacts
+            .iter()
+            .map(|contact| contact.id.into_db())
+            .collect::<Vec<_>>();
+        let mut records = database::models::contact::ContactPhoneNumber::select_by_map(
+            self.pool.as_ref(),
+            value! { "contact_id": contact_ids },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+        records.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+
+        let mut numbers_by_contact = HashMap::<uuid::Uuid, Vec<String>>::new();
+        for record in records {
+            numbers_by_contact
+                .entry(record.contact_id.into_domain())
+                .or_default()
+                .push(record.phone_number);
+        }
+
+        for contact in &mut contacts {
+            contact.phone_numbers = numbers_by_contact.remove(&contact.id).unwrap_or_default();
+        }
+
+        Ok(contacts)
+    }
+}
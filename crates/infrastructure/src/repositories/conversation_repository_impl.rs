@@ -1,13 +1,20 @@
 use std::sync::Arc;
 
 use domain::repositories::conversation_repository::ConversationRepository;
-use domain::{models::conversation::Conversation, repositories::RepositoryError};
+use domain::{
+    models::conversation::{
+        AssigneeLoad, Conversation, ConversationFilter, ConversationStateChange, ConversationView,
+        UnreadCount,
+    },
+    repositories::RepositoryError,
+};
 
 use rbatis::{RBatis, async_trait};
 use rbs::value;
+use time::OffsetDateTime;
 
 use crate::database;
-use crate::database::models::UuidExt;
+use crate::database::models::{ConversationSql, UuidExt, offset_datetime_to_datetime};
 use crate::repositories::RbsErrorExt;
 
 #[derive(Debug, bon::Builder)]
@@ -33,37 +40,16 @@ impl ConversationRepository for ConversationRepositoryImpl {
         Ok(())
     }
 
-    async fn update_conversation(
-        &self,
-        conversation: &Conversation,
-    ) -> Result<(), RepositoryError> {
-        self.find_by_id(&conversation.user_id, &conversation.id)
-            .await?;
-        let updated_conversation = database::models::conversation::Conversation::from(conversation);
-        let conversation_id = updated_conversation.id.clone();
-        let user_id = updated_conversation.user_id.clone();
-
-        database::models::conversation::Conversation::update_by_map(
-            self.pool.as_ref(),
-            &updated_conversation,
-            value! { "id": conversation_id, "user_id": user_id },
-        )
-        .await
-        .map_err(|e| e.to_repository_error())?;
-
-        Ok(())
-    }
-
     async fn find_by_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<Conversation, RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let id_db = id.into_db();
         let conversation = database::models::conversation::Conversation::select_by_map(
             self.pool.as_ref(),
-            value! { "id": id_db, "user_id": user_id_db },
+            value! { "id": id_db, "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?
@@ -76,16 +62,16 @@ impl ConversationRepository for ConversationRepositoryImpl {
 
     async fn find_by_phone_number_and_recipient(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         phone_number_id: &uuid::Uuid,
         recipient_phone_number: &str,
     ) -> Result<Conversation, RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let phone_number_id_db = phone_number_id.into_db();
         let conversation = database::models::conversation::Conversation::select_by_map(
             self.pool.as_ref(),
             value! {
-                "user_id": user_id_db,
+                "organization_id": organization_id_db,
                 "phone_number_id": phone_number_id_db,
                 "recipient_phone_number": recipient_phone_number
             },
@@ -99,14 +85,14 @@ impl ConversationRepository for ConversationRepositoryImpl {
         Ok(Conversation::from(&conversation))
     }
 
-    async fn list_by_user_id(
+    async fn list_by_organization_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
     ) -> Result<Vec<Conversation>, RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let records = database::models::conversation::Conversation::select_by_map(
             self.pool.as_ref(),
-            value! { "user_id": user_id_db },
+            value! { "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?;
@@ -121,22 +107,243 @@ impl ConversationRepository for ConversationRepositoryImpl {
         Ok(conversations)
     }
 
-    async fn delete_conversation(
+    async fn list_filtered(
         &self,
+        organization_id: &uuid::Uuid,
         user_id: &uuid::Uuid,
+        view: ConversationView,
+        filter: ConversationFilter,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Conversation>, RepositoryError> {
+        let view = match view {
+            ConversationView::All => "all",
+            ConversationView::Mine => "mine",
+            ConversationView::Unassigned => "unassigned",
+        };
+        let filter = match filter {
+            ConversationFilter::Inbox => "inbox",
+            ConversationFilter::Pinned => "pinned",
+            ConversationFilter::Snoozed => "snoozed",
+            ConversationFilter::Archived => "archived",
+        };
+        let records = ConversationSql::select_filtered(
+            self.pool.as_ref(),
+            organization_id.into_db(),
+            user_id.into_db(),
+            view,
+            filter,
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(Conversation::from).collect())
+    }
+
+    async fn change_state(
+        &self,
+        conversation: &Conversation,
+        change: ConversationStateChange,
+        now: OffsetDateTime,
+    ) -> Result<Conversation, RepositoryError> {
+        let id = conversation.id.into_db();
+        let organization_id = conversation.organization_id.into_db();
+        let pool = self.pool.as_ref();
+        let at = |set: bool| set.then(|| offset_datetime_to_datetime(now));
+        let now = offset_datetime_to_datetime(now);
+        let records = match change {
+            ConversationStateChange::Archive | ConversationStateChange::Unarchive => {
+                let archived_at = at(change == ConversationStateChange::Archive);
+                ConversationSql::set_archived_at(pool, id, organization_id, archived_at, now).await
+            }
+            ConversationStateChange::Pin | ConversationStateChange::Unpin => {
+                let pinned_at = at(change == ConversationStateChange::Pin);
+                ConversationSql::set_pinned_at(pool, id, organization_id, pinned_at, now).await
+            }
+            ConversationStateChange::Snooze(until) => {
+                let until = Some(offset_datetime_to_datetime(until));
+                ConversationSql::set_snoozed_until(pool, id, organization_id, until, now).await
+            }
+            ConversationStateChange::Unsnooze => {
+                ConversationSql::set_snoozed_until(pool, id, organization_id, None, now).await
+            }
+        }
+        .map_err(|e| e.to_repository_error())?;
+
+        let record = records
+            .into_iter()
+            .next()
+            .ok_or(RepositoryError::NotFound)?;
+        Ok(Conversation::from(&record))
+    }
+
+    async fn record_activity(
+        &self,
+        conversation: &Conversation,
+        last_message_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Conversation, RepositoryError> {
+        let record = ConversationSql::record_activity(
+            self.pool.as_ref(),
+            conversation.id.into_db(),
+            conversation.organization_id.into_db(),
+            offset_datetime_to_datetime(last_message_at),
+            offset_datetime_to_datetime(updated_at),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(Conversation::from(&record))
+    }
+
+    async fn reopen(&self, conversation: &Conversation) -> Result<(), RepositoryError> {
+        ConversationSql::reopen(
+            self.pool.as_ref(),
+            conversation.id.into_db(),
+            conversation.organization_id.into_db(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn assign(
+        &self,
+        conversation: &Conversation,
+        assigned_user_id: Option<uuid::Uuid>,
+        now: OffsetDateTime,
+    ) -> Result<Conversation, RepositoryError> {
+        let record = ConversationSql::assign(
+            self.pool.as_ref(),
+            conversation.id.into_db(),
+            conversation.organization_id.into_db(),
+            assigned_user_id.map(|id| id.into_db()),
+            assigned_user_id.map(|_| offset_datetime_to_datetime(now)),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(Conversation::from(&record))
+    }
+
+    async fn claim_assignment(
+        &self,
+        conversation: &Conversation,
+        assigned_user_id: &uuid::Uuid,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let result = ConversationSql::claim_assignment(
+            self.pool.as_ref(),
+            conversation.id.into_db(),
+            conversation.organization_id.into_db(),
+            assigned_user_id.into_db(),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn list_assignee_loads(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+        let records =
+            ConversationSql::select_assignee_loads(self.pool.as_ref(), organization_id.into_db())
+                .await
+                .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(AssigneeLoad::from).collect())
+    }
+
+    async fn mark_read(
+        &self,
+        conversation: &Conversation,
+        user_id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        ConversationSql::mark_read(
+            self.pool.as_ref(),
+            conversation.id.into_db(),
+            user_id.into_db(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn list_unread_counts(
+        &self,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+    ) -> Result<Vec<UnreadCount>, RepositoryError> {
+        let records = ConversationSql::select_unread_counts(
+            self.pool.as_ref(),
+            organization_id.into_db(),
+            user_id.into_db(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(UnreadCount::from).collect())
+    }
+
+    async fn wake_snoozed(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Conversation>, RepositoryError> {
+        let records =
+            ConversationSql::wake_snoozed(self.pool.as_ref(), offset_datetime_to_datetime(now))
+                .await
+                .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(Conversation::from).collect())
+    }
+
+    async fn delete_conversation(
+        &self,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<(), RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let id_db = id.into_db();
-        self.find_by_id(user_id, id).await?;
+        self.find_by_id(organization_id, id).await?;
 
         database::models::conversation::Conversation::delete_by_map(
             self.pool.as_ref(),
-            value! { "id": id_db, "user_id": user_id_db },
+            value! { "id": id_db, "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?;
 
         Ok(())
     }
+
+    async fn claim_away_reply(
+        &self,
+        conversation: &Conversation,
+        closed_since: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let result = ConversationSql::claim_away_reply(
+            self.pool.as_ref(),
+            conversation.id.into_db(),
+            conversation.organization_id.into_db(),
+            offset_datetime_to_datetime(closed_since),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
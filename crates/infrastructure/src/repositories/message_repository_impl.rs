@@ -1,14 +1,17 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use domain::models::message::Message;
+use domain::models::message_attachment::MessageAttachment;
 use domain::repositories::RepositoryError;
 use domain::repositories::message_repository::{MessagePage, MessageRepository};
 
 use rbatis::{RBatis, async_trait};
 use rbs::value;
+use time::OffsetDateTime;
 
 use crate::database;
-use crate::database::models::{MessageSql, RdbcUuidExt, UuidExt};
+use crate::database::models::{MessageSql, RdbcUuidExt, UuidExt, offset_datetime_to_datetime};
 use crate::repositories::RbsErrorExt;
 
 #[derive(Debug, bon::Builder)]
@@ -26,14 +29,52 @@ impl MessageRepository for MessageRepositoryImpl {
             .await
             .map_err(|e| e.to_repository_error())?;
 
-        Ok(created_message)
+        if !message.attachments.is_empty() {
+            let attachments_db = message
+                .attachments
+                .iter()
+                .map(database::models::message_attachment::MessageAttachment::from)
+                .collect::<Vec<_>>();
+
+            database::models::message_attachment::MessageAttachment::insert_batch(
+                self.pool.as_ref(),
+                &attachments_db,
+                attachments_db.len() as u64,
+            )
+            .await
+            .map_err(|e| e.to_repository_error())?;
+        }
+
+        Ok(Message {
+            attachments: message.attachments.clone(),
+            ..created_message
+        })
     }
 
-    async fn count_by_user_id(&self, user_id: &uuid::Uuid) -> Result<u64, RepositoryError> {
-        let user_id_db = user_id.into_db();
+    async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+        let record = database::models::message::Message::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        let mut messages = self.with_attachments(vec![Message::from(&record)]).await?;
+
+        Ok(messages.remove(0))
+    }
+
+    async fn count_by_organization_id(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<u64, RepositoryError> {
+        let organization_id_db = organization_id.into_db();
         let records = database::models::message::Message::select_by_map(
             self.pool.as_ref(),
-            value! { "user_id": user_id_db },
+            value! { "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?;
@@ -55,19 +96,37 @@ impl MessageRepository for MessageRepositoryImpl {
         .next()
         .ok_or(RepositoryError::NotFound)?;
 
-        Ok(Message::from(&record))
+        let mut messages = self.with_attachments(vec![Message::from(&record)]).await?;
+
+        Ok(messages.remove(0))
+    }
+
+    async fn find_reply_to(&self, message_id: &uuid::Uuid) -> Result<Message, RepositoryError> {
+        let record = database::models::message::Message::select_by_map(
+            self.pool.as_ref(),
+            value! { "reply_to_message_id": message_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        let mut messages = self.with_attachments(vec![Message::from(&record)]).await?;
+
+        Ok(messages.remove(0))
     }
 
     async fn list_by_conversation_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         conversation_id: &uuid::Uuid,
     ) -> Result<Vec<Message>, RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let conversation_id_db = conversation_id.into_db();
         let records = database::models::message::Message::select_by_map(
             self.pool.as_ref(),
-            value! { "conversation_id": conversation_id_db, "user_id": user_id_db },
+            value! { "conversation_id": conversation_id_db, "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?;
@@ -79,19 +138,19 @@ impl MessageRepository for MessageRepositoryImpl {
                 .then_with(|| a.id.cmp(&b.id))
         });
 
-        Ok(messages)
+        self.with_attachments(messages).await
     }
 
     async fn list_page_by_conversation_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         conversation_id: &uuid::Uuid,
         cursor: Option<&uuid::Uuid>,
         limit: usize,
     ) -> Result<MessagePage, RepositoryError> {
         let page_size = limit.max(1);
         let query_limit = (page_size + 1) as i64;
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let conversation_id_db = conversation_id.into_db();
 
         let (cursor_created_at, cursor_id_db) = if let Some(cursor_id) = cursor {
@@ -99,13 +158,13 @@ impl MessageRepository for MessageRepositoryImpl {
                 self.pool.as_ref(),
                 cursor_id.into_db(),
                 conversation_id_db.clone(),
-                user_id_db.clone(),
+                organization_id_db.clone(),
             )
-                .await
-                .map_err(|e| e.to_repository_error())?
-                .into_iter()
-                .next()
-                .ok_or(RepositoryError::NotFound)?;
+            .await
+            .map_err(|e| e.to_repository_error())?
+            .into_iter()
+            .next()
+            .ok_or(RepositoryError::NotFound)?;
 
             (Some(cursor_row.created_at), Some(cursor_row.id))
         } else {
@@ -114,7 +173,7 @@ impl MessageRepository for MessageRepositoryImpl {
         let records = MessageSql::select_message_page(
             self.pool.as_ref(),
             conversation_id_db,
-            user_id_db,
+            organization_id_db,
             cursor_created_at,
             cursor_id_db,
             query_limit,
@@ -140,6 +199,7 @@ impl MessageRepository for MessageRepositoryImpl {
                 .cmp(&b.created_at)
                 .then_with(|| a.id.cmp(&b.id))
         });
+        let messages = self.with_attachments(messages).await?;
 
         Ok(MessagePage {
             messages,
@@ -150,7 +210,7 @@ impl MessageRepository for MessageRepositoryImpl {
     async fn update_message(&self, message: &Message) -> Result<Message, RepositoryError> {
         let existing = database::models::message::Message::select_by_map(
             self.pool.as_ref(),
-            value! { "id": message.id.into_db(), "user_id": message.user_id.into_db() },
+            value! { "id": message.id.into_db(), "organization_id": message.organization_id.into_db() },
         )
         .await
         .map_err(|e| e.to_repository_error())?;
@@ -167,4 +227,134 @@ impl MessageRepository for MessageRepositoryImpl {
 
         Ok(message.clone())
     }
+
+    async fn list_scheduled_by_conversation_id(
+        &self,
+        organization_id: &uuid::Uuid,
+        conversation_id: &uuid::Uuid,
+    ) -> Result<Vec<Message>, RepositoryError> {
+        let records = MessageSql::select_scheduled(
+            self.pool.as_ref(),
+            conversation_id.into_db(),
+            organization_id.into_db(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        self.with_attachments(records.iter().map(Message::from).collect())
+            .await
+    }
+
+    async fn reschedule_message(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+        scheduled_at: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let result = MessageSql::reschedule(
+            self.pool.as_ref(),
+            id.into_db(),
+            organization_id.into_db(),
+            offset_datetime_to_datetime(scheduled_at),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete_scheduled_message(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<bool, RepositoryError> {
+        let result = MessageSql::delete_scheduled(
+            self.pool.as_ref(),
+            id.into_db(),
+            organization_id.into_db(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn release_scheduled_message(
+        &self,
+        id: &uuid::Uuid,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let result = MessageSql::release_scheduled(
+            self.pool.as_ref(),
+            id.into_db(),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn find_attachment_by_id(
+        &self,
+        id: &uuid::Uuid,
+    ) -> Result<MessageAttachment, RepositoryError> {
+        let record = database::models::message_attachment::MessageAttachment::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(MessageAttachment::from(&record))
+    }
+}
+
+impl MessageRepositoryImpl {
+    async fn with_attachments(
+        &self,
+        mut messages: Vec<Message>,
+    ) -> Result<Vec<Message>, RepositoryError> {
+        if messages.is_empty() {
+            return Ok(messages);
+        }
+
+        let message_ids = messages
+            .iter()
+            .map(|message| message.id.into_db())
+            .collect::<Vec<_>>();
+        let records = database::models::message_attachment::MessageAttachment::select_by_map(
+            self.pool.as_ref(),
+            value! { "message_id": message_ids },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut attachments_by_message = HashMap::<uuid::Uuid, Vec<MessageAttachment>>::new();
+        for record in &records {
+            let attachment = MessageAttachment::from(record);
+            attachments_by_message
+                .entry(attachment.message_id)
+                .or_default()
+                .push(attachment);
+        }
+
+        for message in &mut messages {
+            if let Some(mut attachments) = attachments_by_message.remove(&message.id) {
+                attachments.sort_by(|a, b| {
+                    a.created_at
+                        .cmp(&b.created_at)
+                        .then_with(|| a.id.cmp(&b.id))
+                });
+                message.attachments = attachments;
+            }
+        }
+
+        Ok(messages)
+    }
 }
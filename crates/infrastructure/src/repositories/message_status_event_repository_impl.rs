@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use domain::{
+    models::message_status_event::{MessageStatusEvent, sort_timeline},
+    repositories::{
+        RepositoryError, message_status_event_repository::MessageStatusEventRepository,
+    },
+};
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+
+use crate::{
+    database::{self, models::UuidExt},
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct MessageStatusEventRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl MessageStatusEventRepository for MessageStatusEventRepositoryImpl {
+    async fn record(&self, event: &MessageStatusEvent) -> Result<(), RepositoryError> {
+        let record = database::models::message_status_event::MessageStatusEvent::from(event);
+
+        match database::models::message_status_event::MessageStatusEvent::insert(
+            self.pool.as_ref(),
+            &record,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())
+        {
+            Ok(_) | Err(RepositoryError::ConstraintViolation(_)) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn list_by_message_ids(
+        &self,
+        organization_id: &uuid::Uuid,
+        message_ids: &[uuid::Uuid],
+    ) -> Result<Vec<MessageStatusEvent>, RepositoryError> {
+        if message_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let message_ids = message_ids
+            .iter()
+            .map(|message_id| message_id.into_db())
+            .collect::<Vec<_>>();
+        let message_ids = database::models::message::Message::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": message_ids, "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .map(|message| message.id)
+        .collect::<Vec<_>>();
+        if message_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let records = database::models::message_status_event::MessageStatusEvent::select_by_map(
+            self.pool.as_ref(),
+            value! { "message_id": message_ids },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut events = records
+            .iter()
+            .map(MessageStatusEvent::from)
+            .collect::<Vec<_>>();
+        sort_timeline(&mut events);
+
+        Ok(events)
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use domain::models::message_template::MessageTemplate;
+use domain::repositories::RepositoryError;
+use domain::repositories::message_template_repository::MessageTemplateRepository;
+
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+
+use crate::database;
+use crate::database::models::UuidExt;
+use crate::repositories::RbsErrorExt;
+
+#[derive(Debug, bon::Builder)]
+pub struct MessageTemplateRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl MessageTemplateRepository for MessageTemplateRepositoryImpl {
+    async fn create_template(&self, template: &MessageTemplate) -> Result<(), RepositoryError> {
+        let record = database::models::message_template::MessageTemplate::from(template);
+
+        database::models::message_template::MessageTemplate::insert(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn update_template(&self, template: &MessageTemplate) -> Result<(), RepositoryError> {
+        let record = database::models::message_template::MessageTemplate::from(template);
+
+        let result = database::models::message_template::MessageTemplate::update_by_map(
+            self.pool.as_ref(),
+            &record,
+            value! { "id": template.id.into_db(), "user_id": template.user_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        user_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<MessageTemplate, RepositoryError> {
+        let record = database::models::message_template::MessageTemplate::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "user_id": user_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(MessageTemplate::from(&record))
+    }
+
+    async fn list_by_user_id(
+        &self,
+        user_id: &uuid::Uuid,
+    ) -> Result<Vec<MessageTemplate>, RepositoryError> {
+        let records = database::models::message_template::MessageTemplate::select_by_map(
+            self.pool.as_ref(),
+            value! { "user_id": user_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut templates = records
+            .iter()
+            .map(MessageTemplate::from)
+            .collect::<Vec<_>>();
+        templates.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        Ok(templates)
+    }
+
+    async fn delete_template(
+        &self,
+        user_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        let result = database::models::message_template::MessageTemplate::delete_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "user_id": user_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
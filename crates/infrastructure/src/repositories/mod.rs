@@ -1,9 +1,23 @@
 use domain::repositories::RepositoryError;
 
+pub mod auto_reply_rule_repository_impl;
+pub mod business_hours_repository_impl;
+pub mod campaign_repository_impl;
+pub mod contact_import_repository_impl;
+pub mod contact_repository_impl;
 pub mod conversation_repository_impl;
 pub mod message_repository_impl;
+pub mod message_status_event_repository_impl;
+pub mod message_template_repository_impl;
+pub mod opt_out_repository_impl;
+pub mod organization_invitation_repository_impl;
+pub mod organization_repository_impl;
+pub mod outbound_message_queue_repository_impl;
 pub mod phone_number_repository_impl;
 pub mod processed_webhook_event_repository_impl;
+pub mod reset_password_repository_impl;
+pub mod usage_limit_repository_impl;
+pub mod usage_repository_impl;
 pub mod user_repository_impl;
 
 pub trait RbsErrorExt {
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use domain::{
+    models::opt_out::OptOut,
+    repositories::{RepositoryError, opt_out_repository::OptOutRepository},
+};
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+
+use crate::{
+    database::{self, models::UuidExt},
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct OptOutRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl OptOutRepository for OptOutRepositoryImpl {
+    async fn opt_out(&self, opt_out: &OptOut) -> Result<(), RepositoryError> {
+        let record = database::models::opt_out::OptOut::from(opt_out);
+
+        match database::models::opt_out::OptOut::insert(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())
+        {
+            Ok(_) | Err(RepositoryError::ConstraintViolation(_)) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn opt_in(
+        &self,
+        phone_number_id: &uuid::Uuid,
+        recipient_phone_number: &str,
+    ) -> Result<(), RepositoryError> {
+        database::models::opt_out::OptOut::delete_by_map(
+            self.pool.as_ref(),
+            value! {
+                "phone_number_id": phone_number_id.into_db(),
+                "recipient_phone_number": recipient_phone_number,
+            },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn is_opted_out(
+        &self,
+        phone_number_id: &uuid::Uuid,
+        recipient_phone_number: &str,
+    ) -> Result<bool, RepositoryError> {
+        let records = database::models::opt_out::OptOut::select_by_map(
+            self.pool.as_ref(),
+            value! {
+                "phone_number_id": phone_number_id.into_db(),
+                "recipient_phone_number": recipient_phone_number,
+            },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(!records.is_empty())
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use domain::{
+    models::organization::{OrganizationInvitation, OrganizationMember},
+    repositories::{
+        RepositoryError, organization_invitation_repository::OrganizationInvitationRepository,
+    },
+};
+use rbatis::executor::RBatisTxExecutor;
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+use time::OffsetDateTime;
+
+use crate::{
+    database::{
+        self,
+        models::{OrganizationSql, UuidExt, offset_datetime_to_datetime},
+    },
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct OrganizationInvitationRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl OrganizationInvitationRepository for OrganizationInvitationRepositoryImpl {
+    async fn create_invitation(
+        &self,
+        invitation: &OrganizationInvitation,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::organization::OrganizationInvitation::from(invitation);
+
+        database::models::organization::OrganizationInvitation::insert(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &uuid::Uuid) -> Result<OrganizationInvitation, RepositoryError> {
+        let record = database::models::organization::OrganizationInvitation::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(OrganizationInvitation::from(&record))
+    }
+
+    async fn list_pending(
+        &self,
+        organization_id: &uuid::Uuid,
+        now: OffsetDateTime,
+    ) -> Result<Vec<OrganizationInvitation>, RepositoryError> {
+        let records = OrganizationSql::select_pending_invitations(
+            self.pool.as_ref(),
+            organization_id.into_db(),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(OrganizationInvitation::from).collect())
+    }
+
+    async fn accept_invitation(
+        &self,
+        id: &uuid::Uuid,
+        member: &OrganizationMember,
+        now: OffsetDateTime,
+    ) -> Result<(), RepositoryError> {
+        let tx = self
+            .pool
+            .acquire_begin()
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        match Self::accept(&tx, id, member, now).await {
+            Ok(()) => tx.commit().await.map_err(|e| e.to_repository_error()),
+            Err(err) => {
+                let _ = tx.rollback().await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn delete_invitation(
+        &self,
+        organization_id: &uuid::Uuid,
+        id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        let result = database::models::organization::OrganizationInvitation::delete_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db(), "organization_id": organization_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+impl OrganizationInvitationRepositoryImpl {
+    async fn accept(
+        tx: &RBatisTxExecutor,
+        id: &uuid::Uuid,
+        member: &OrganizationMember,
+        now: OffsetDateTime,
+    ) -> Result<(), RepositoryError> {
+        let result =
+            OrganizationSql::accept_invitation(tx, id.into_db(), offset_datetime_to_datetime(now))
+                .await
+                .map_err(|e| e.to_repository_error())?;
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        let record = database::models::organization::OrganizationMember::from(member);
+        OrganizationSql::insert_member(tx, &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+}
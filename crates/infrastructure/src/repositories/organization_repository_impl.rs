@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use domain::{
+    models::{
+        conversation::ConversationRouting,
+        organization::{Organization, OrganizationMember, OrganizationRole},
+    },
+    repositories::{RepositoryError, organization_repository::OrganizationRepository},
+};
+use rbatis::executor::RBatisTxExecutor;
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+use time::OffsetDateTime;
+
+use crate::{
+    database::{
+        self,
+        models::{
+            ConversationSql, OrganizationSql, UuidExt, offset_datetime_to_datetime,
+            organization::{conversation_routing_to_db, organization_role_to_db},
+        },
+    },
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct OrganizationRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl OrganizationRepository for OrganizationRepositoryImpl {
+    async fn create_organization(
+        &self,
+        organization: &Organization,
+        owner: &OrganizationMember,
+    ) -> Result<(), RepositoryError> {
+        let tx = self
+            .pool
+            .acquire_begin()
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        match Self::insert_organization(&tx, organization, owner).await {
+            Ok(()) => tx.commit().await.map_err(|e| e.to_repository_error()),
+            Err(err) => {
+                let _ = tx.rollback().await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Organization, RepositoryError> {
+        let record = database::models::organization::Organization::select_by_map(
+            self.pool.as_ref(),
+            value! { "id": id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(Organization::from(&record))
+    }
+
+    async fn update_conversation_routing(
+        &self,
+        id: &uuid::Uuid,
+        conversation_routing: ConversationRouting,
+        now: OffsetDateTime,
+    ) -> Result<(), RepositoryError> {
+        let result = OrganizationSql::update_conversation_routing(
+            self.pool.as_ref(),
+            id.into_db(),
+            conversation_routing_to_db(conversation_routing),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn list_by_user_id(
+        &self,
+        user_id: &uuid::Uuid,
+    ) -> Result<Vec<Organization>, RepositoryError> {
+        let records = OrganizationSql::select_by_user_id(self.pool.as_ref(), user_id.into_db())
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(Organization::from).collect())
+    }
+
+    async fn find_member(
+        &self,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+    ) -> Result<OrganizationMember, RepositoryError> {
+        let record = database::models::organization::OrganizationMember::select_by_map(
+            self.pool.as_ref(),
+            value! { "organization_id": organization_id.into_db(), "user_id": user_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(OrganizationMember::from(&record))
+    }
+
+    async fn list_members(
+        &self,
+        organization_id: &uuid::Uuid,
+    ) -> Result<Vec<OrganizationMember>, RepositoryError> {
+        let records =
+            OrganizationSql::select_members(self.pool.as_ref(), organization_id.into_db())
+                .await
+                .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(OrganizationMember::from).collect())
+    }
+
+    async fn update_member_role(
+        &self,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+        role: OrganizationRole,
+    ) -> Result<(), RepositoryError> {
+        let result = OrganizationSql::update_member_role(
+            self.pool.as_ref(),
+            organization_id.into_db(),
+            user_id.into_db(),
+            organization_role_to_db(role),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn remove_member(
+        &self,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        self.find_member(organization_id, user_id).await?;
+        let tx = self
+            .pool
+            .acquire_begin()
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        match Self::delete_member(&tx, organization_id, user_id).await {
+            Ok(()) => tx.commit().await.map_err(|e| e.to_repository_error()),
+            Err(err) => {
+                let _ = tx.rollback().await;
+                Err(err)
+            }
+        }
+    }
+}
+
+impl OrganizationRepositoryImpl {
+    async fn insert_organization(
+        tx: &RBatisTxExecutor,
+        organization: &Organization,
+        owner: &OrganizationMember,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::organization::Organization::from(organization);
+        database::models::organization::Organization::insert(tx, &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        let member = database::models::organization::OrganizationMember::from(owner);
+        database::models::organization::OrganizationMember::insert(tx, &member)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    /// Conversations the member was answering go back to the unassigned view.
+    async fn delete_member(
+        tx: &RBatisTxExecutor,
+        organization_id: &uuid::Uuid,
+        user_id: &uuid::Uuid,
+    ) -> Result<(), RepositoryError> {
+        database::models::organization::OrganizationMember::delete_by_map(
+            tx,
+            value! { "organization_id": organization_id.into_db(), "user_id": user_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        ConversationSql::unassign_member(tx, organization_id.into_db(), user_id.into_db())
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+}
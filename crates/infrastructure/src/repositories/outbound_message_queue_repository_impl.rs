@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use domain::{
+    models::outbound_message_job::OutboundMessageJob,
+    repositories::{
+        RepositoryError, outbound_message_queue_repository::OutboundMessageQueueRepository,
+    },
+};
+use rbatis::rbdc::DateTime;
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+use time::OffsetDateTime;
+
+use crate::{
+    database::{
+        self,
+        models::{OutboundMessageJobSql, UuidExt, offset_datetime_to_datetime},
+    },
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct OutboundMessageQueueRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl OutboundMessageQueueRepository for OutboundMessageQueueRepositoryImpl {
+    async fn enqueue(&self, job: &OutboundMessageJob) -> Result<(), RepositoryError> {
+        let record = database::models::outbound_message_job::OutboundMessageJob::from(job);
+
+        database::models::outbound_message_job::OutboundMessageJob::insert(
+            self.pool.as_ref(),
+            &record,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+        limit: usize,
+    ) -> Result<Vec<OutboundMessageJob>, RepositoryError> {
+        let records = OutboundMessageJobSql::claim_due(
+            self.pool.as_ref(),
+            offset_datetime_to_datetime(now),
+            offset_datetime_to_datetime(locked_until),
+            limit as i64,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut jobs = records
+            .iter()
+            .map(OutboundMessageJob::from)
+            .collect::<Vec<_>>();
+        jobs.sort_by_key(|job| job.next_attempt_at);
+
+        Ok(jobs)
+    }
+
+    async fn reschedule(
+        &self,
+        message_id: &uuid::Uuid,
+        next_attempt_at: OffsetDateTime,
+        last_error: &str,
+    ) -> Result<(), RepositoryError> {
+        let result = OutboundMessageJobSql::reschedule(
+            self.pool.as_ref(),
+            message_id.into_db(),
+            offset_datetime_to_datetime(next_attempt_at),
+            last_error,
+            DateTime::now(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn postpone(
+        &self,
+        message_id: &uuid::Uuid,
+        next_attempt_at: OffsetDateTime,
+    ) -> Result<(), RepositoryError> {
+        let result = OutboundMessageJobSql::postpone(
+            self.pool.as_ref(),
+            message_id.into_db(),
+            offset_datetime_to_datetime(next_attempt_at),
+            DateTime::now(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn complete(&self, message_id: &uuid::Uuid) -> Result<(), RepositoryError> {
+        database::models::outbound_message_job::OutboundMessageJob::delete_by_map(
+            self.pool.as_ref(),
+            value! { "message_id": message_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+}
@@ -29,14 +29,14 @@ impl PhoneNumberRepository for PhoneNumberRepositoryImpl {
 
     async fn find_by_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<PhoneNumber, RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let id_db = id.into_db();
         let phone_number = database::models::phone_number::PhoneNumber::select_by_map(
             self.pool.as_ref(),
-            value! { "id": id_db, "user_id": user_id_db },
+            value! { "id": id_db, "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?
@@ -61,14 +61,14 @@ impl PhoneNumberRepository for PhoneNumberRepositoryImpl {
         Ok(PhoneNumber::from(&phone_number))
     }
 
-    async fn list_by_user_id(
+    async fn list_by_organization_id(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
     ) -> Result<Vec<PhoneNumber>, RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let records = database::models::phone_number::PhoneNumber::select_by_map(
             self.pool.as_ref(),
-            value! { "user_id": user_id_db },
+            value! { "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?;
@@ -81,16 +81,16 @@ impl PhoneNumberRepository for PhoneNumberRepositoryImpl {
 
     async fn delete_phone_number(
         &self,
-        user_id: &uuid::Uuid,
+        organization_id: &uuid::Uuid,
         id: &uuid::Uuid,
     ) -> Result<(), RepositoryError> {
-        let user_id_db = user_id.into_db();
+        let organization_id_db = organization_id.into_db();
         let id_db = id.into_db();
-        self.find_by_id(user_id, id).await?;
+        self.find_by_id(organization_id, id).await?;
 
         database::models::phone_number::PhoneNumber::delete_by_map(
             self.pool.as_ref(),
-            value! { "id": id_db, "user_id": user_id_db },
+            value! { "id": id_db, "organization_id": organization_id_db },
         )
         .await
         .map_err(|e| e.to_repository_error())?;
@@ -1,15 +1,27 @@
 use std::sync::Arc;
 
 use domain::{
-    models::processed_webhook_event::ProcessedWebhookEvent,
+    models::processed_webhook_event::{ProcessedWebhookEvent, WebhookEventStatus},
     repositories::{
         RepositoryError, processed_webhook_event_repository::ProcessedWebhookEventRepository,
     },
 };
+use rbatis::rbdc::DateTime;
+use rbatis::rbdc::db::ExecResult;
 use rbatis::{RBatis, async_trait};
 use rbs::value;
+use time::OffsetDateTime;
 
-use crate::{database, repositories::RbsErrorExt};
+use crate::{
+    database::{
+        self,
+        models::{
+            ProcessedWebhookEventSql, offset_datetime_to_datetime,
+            processed_webhook_event::webhook_event_status_to_db,
+        },
+    },
+    repositories::RbsErrorExt,
+};
 
 #[derive(Debug, bon::Builder)]
 pub struct ProcessedWebhookEventRepositoryImpl {
@@ -51,4 +63,120 @@ impl ProcessedWebhookEventRepository for ProcessedWebhookEventRepositoryImpl {
 
         Ok(ProcessedWebhookEvent::from(&record))
     }
+
+    async fn claim_pending(
+        &self,
+        now: OffsetDateTime,
+        locked_until: OffsetDateTime,
+        limit: usize,
+    ) -> Result<Vec<ProcessedWebhookEvent>, RepositoryError> {
+        let records = ProcessedWebhookEventSql::claim_pending(
+            self.pool.as_ref(),
+            offset_datetime_to_datetime(now),
+            offset_datetime_to_datetime(locked_until),
+            limit as i64,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        let mut events = records
+            .iter()
+            .map(ProcessedWebhookEvent::from)
+            .collect::<Vec<_>>();
+        // Telnyx does not guarantee delivery order, so apply events in the order they happened.
+        events.sort_by_key(|event| event.occurred_at);
+
+        Ok(events)
+    }
+
+    async fn mark_processed(&self, event_id: &str) -> Result<(), RepositoryError> {
+        let result =
+            ProcessedWebhookEventSql::mark_processed(self.pool.as_ref(), event_id, DateTime::now())
+                .await
+                .map_err(|e| e.to_repository_error())?;
+
+        ensure_affected(result)
+    }
+
+    async fn reschedule(
+        &self,
+        event_id: &str,
+        next_attempt_at: OffsetDateTime,
+        last_error: &str,
+    ) -> Result<(), RepositoryError> {
+        let result = ProcessedWebhookEventSql::reschedule(
+            self.pool.as_ref(),
+            event_id,
+            offset_datetime_to_datetime(next_attempt_at),
+            last_error,
+            DateTime::now(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        ensure_affected(result)
+    }
+
+    async fn park(&self, event_id: &str, until: OffsetDateTime) -> Result<(), RepositoryError> {
+        let result = ProcessedWebhookEventSql::park(
+            self.pool.as_ref(),
+            event_id,
+            offset_datetime_to_datetime(until),
+            DateTime::now(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        ensure_affected(result)
+    }
+
+    async fn mark_dead_lettered(
+        &self,
+        event_id: &str,
+        last_error: &str,
+    ) -> Result<(), RepositoryError> {
+        let result = ProcessedWebhookEventSql::mark_dead_lettered(
+            self.pool.as_ref(),
+            event_id,
+            last_error,
+            DateTime::now(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        ensure_affected(result)
+    }
+
+    async fn list_by_status(
+        &self,
+        status: WebhookEventStatus,
+        limit: usize,
+    ) -> Result<Vec<ProcessedWebhookEvent>, RepositoryError> {
+        let records = ProcessedWebhookEventSql::select_by_status(
+            self.pool.as_ref(),
+            webhook_event_status_to_db(status),
+            limit as i64,
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(ProcessedWebhookEvent::from).collect())
+    }
+
+    async fn replay(&self, event_id: &str) -> Result<(), RepositoryError> {
+        let result =
+            ProcessedWebhookEventSql::replay(self.pool.as_ref(), event_id, DateTime::now())
+                .await
+                .map_err(|e| e.to_repository_error())?;
+
+        ensure_affected(result)
+    }
+}
+
+fn ensure_affected(result: ExecResult) -> Result<(), RepositoryError> {
+    if result.rows_affected == 0 {
+        return Err(RepositoryError::NotFound);
+    }
+
+    Ok(())
 }
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use domain::{
+    models::reset_password::ResetPassword,
+    repositories::{RepositoryError, reset_password_repository::ResetPasswordRepository},
+};
+use rbatis::rbdc::DateTime;
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+
+use crate::{
+    database::{
+        self,
+        models::{ResetPasswordSql, UuidExt},
+    },
+    repositories::RbsErrorExt,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct ResetPasswordRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl ResetPasswordRepository for ResetPasswordRepositoryImpl {
+    async fn create_reset_password(
+        &self,
+        reset_password: &ResetPassword,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::reset_password::ResetPassword::from(reset_password);
+
+        database::models::reset_password::ResetPassword::insert(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn find_by_token(&self, token: &str) -> Result<ResetPassword, RepositoryError> {
+        let record = database::models::reset_password::ResetPassword::select_by_map(
+            self.pool.as_ref(),
+            value! { "token": token },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(ResetPassword::from(&record))
+    }
+
+    async fn consume(&self, id: &uuid::Uuid) -> Result<(), RepositoryError> {
+        let result = ResetPasswordSql::consume(self.pool.as_ref(), id.into_db(), DateTime::now())
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn consume_all_by_user_id(&self, user_id: &uuid::Uuid) -> Result<(), RepositoryError> {
+        ResetPasswordSql::consume_all_by_user_id(
+            self.pool.as_ref(),
+            user_id.into_db(),
+            DateTime::now(),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use domain::models::usage::{UsageLimit, UsagePeriod};
+use domain::repositories::RepositoryError;
+use domain::repositories::usage_limit_repository::UsageLimitRepository;
+
+use rbatis::{RBatis, async_trait};
+use rbs::value;
+use time::OffsetDateTime;
+
+use crate::database;
+use crate::database::models::{UsageSql, UuidExt, offset_datetime_to_datetime};
+use crate::repositories::RbsErrorExt;
+
+#[derive(Debug, bon::Builder)]
+pub struct UsageLimitRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl UsageLimitRepository for UsageLimitRepositoryImpl {
+    async fn save_limit(&self, limit: &UsageLimit) -> Result<(), RepositoryError> {
+        let record = database::models::usage::UsageLimit::from(limit);
+
+        UsageSql::upsert_limit(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn list_by_user_id(
+        &self,
+        user_id: &uuid::Uuid,
+    ) -> Result<Vec<UsageLimit>, RepositoryError> {
+        let records = database::models::usage::UsageLimit::select_by_map(
+            self.pool.as_ref(),
+            value! { "user_id": user_id.into_db() },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(UsageLimit::from).collect())
+    }
+
+    async fn list_with_soft_limit(&self) -> Result<Vec<UsageLimit>, RepositoryError> {
+        let records = UsageSql::select_limits_with_soft_limit(self.pool.as_ref())
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(records.iter().map(UsageLimit::from).collect())
+    }
+
+    async fn delete_limit(
+        &self,
+        user_id: &uuid::Uuid,
+        period: UsagePeriod,
+    ) -> Result<(), RepositoryError> {
+        let result = database::models::usage::UsageLimit::delete_by_map(
+            self.pool.as_ref(),
+            value! {
+                "user_id": user_id.into_db(),
+                "period": period.name(),
+            },
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn claim_soft_limit_notification(
+        &self,
+        user_id: &uuid::Uuid,
+        period: UsagePeriod,
+        period_start: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<bool, RepositoryError> {
+        let result = UsageSql::claim_soft_limit_notification(
+            self.pool.as_ref(),
+            user_id.into_db(),
+            period.name(),
+            offset_datetime_to_datetime(period_start),
+            offset_datetime_to_datetime(now),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
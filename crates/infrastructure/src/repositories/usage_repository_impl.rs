@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use domain::models::usage::{UsagePeriod, UsageRecord, UsageRollup, UsageTotals};
+use domain::repositories::RepositoryError;
+use domain::repositories::usage_repository::UsageRepository;
+
+use rbatis::{RBatis, async_trait};
+use time::OffsetDateTime;
+
+use crate::database;
+use crate::database::models::usage::period_unit;
+use crate::database::models::{UsageSql, UuidExt, offset_datetime_to_datetime};
+use crate::repositories::RbsErrorExt;
+
+#[derive(Debug, bon::Builder)]
+pub struct UsageRepositoryImpl {
+    pool: Arc<RBatis>,
+}
+
+#[async_trait]
+impl UsageRepository for UsageRepositoryImpl {
+    async fn record_usage(&self, record: &UsageRecord) -> Result<(), RepositoryError> {
+        let record = database::models::usage::UsageRecord::from(record);
+
+        UsageSql::insert_record(self.pool.as_ref(), &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn record_cost(
+        &self,
+        message_id: &uuid::Uuid,
+        cost_micros: i64,
+    ) -> Result<(), RepositoryError> {
+        UsageSql::update_cost(self.pool.as_ref(), message_id.into_db(), cost_micros)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+
+    async fn totals_since(
+        &self,
+        user_id: &uuid::Uuid,
+        since: OffsetDateTime,
+    ) -> Result<UsageTotals, RepositoryError> {
+        let rows = UsageSql::select_totals(
+            self.pool.as_ref(),
+            user_id.into_db(),
+            offset_datetime_to_datetime(since),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(rows.first().map(UsageTotals::from).unwrap_or_default())
+    }
+
+    async fn rollups(
+        &self,
+        user_id: &uuid::Uuid,
+        period: UsagePeriod,
+        since: OffsetDateTime,
+    ) -> Result<Vec<UsageRollup>, RepositoryError> {
+        let rows = UsageSql::select_rollups(
+            self.pool.as_ref(),
+            user_id.into_db(),
+            period_unit(period),
+            offset_datetime_to_datetime(since),
+        )
+        .await
+        .map_err(|e| e.to_repository_error())?;
+
+        Ok(rows.iter().map(UsageRollup::from).collect())
+    }
+}
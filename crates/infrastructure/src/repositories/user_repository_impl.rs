@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
+use domain::models::organization::{Organization, OrganizationMember};
 use domain::repositories::user_repository::UserRepository;
 use domain::{models::user::User, repositories::RepositoryError};
 
+use rbatis::executor::RBatisTxExecutor;
 use rbatis::rbdc::DateTime;
 use rbatis::{PageRequest, RBatis, async_trait};
 use rbs::value;
@@ -27,6 +29,27 @@ impl UserRepository for UserRepositoryImpl {
         Ok(())
     }
 
+    async fn create_user_with_organization(
+        &self,
+        user: &User,
+        organization: &Organization,
+        owner: &OrganizationMember,
+    ) -> Result<(), RepositoryError> {
+        let tx = self
+            .pool
+            .acquire_begin()
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        match Self::insert_user_with_organization(&tx, user, organization, owner).await {
+            Ok(()) => tx.commit().await.map_err(|e| e.to_repository_error()),
+            Err(err) => {
+                let _ = tx.rollback().await;
+                Err(err)
+            }
+        }
+    }
+
     async fn find_by_id(&self, id: &uuid::Uuid) -> Result<User, RepositoryError> {
         let user =
             database::models::user::User::select_by_map(self.pool.as_ref(), value! {"id": id})
@@ -57,11 +80,7 @@ impl UserRepository for UserRepositoryImpl {
         .await
         .map_err(|e| e.to_repository_error())?;
 
-        let users = page
-            .records
-            .iter()
-            .map(|u| User::from(u))
-            .collect::<Vec<_>>();
+        let users = page.records.iter().map(User::from).collect::<Vec<_>>();
 
         Ok(users)
     }
@@ -82,10 +101,19 @@ impl UserRepository for UserRepositoryImpl {
             existing.email = new_data.email.to_owned();
             existing.email_verified = false;
             existing.email_verified_at = None;
+        } else if new_data.email_verified && !existing.email_verified {
+            existing.email_verified = true;
+            existing.email_verified_at = Some(
+                new_data
+                    .email_verified_at
+                    .map(database::models::offset_datetime_to_datetime)
+                    .unwrap_or_else(DateTime::now),
+            );
         }
 
         if existing.hash != new_data.hash {
             existing.hash = new_data.hash.to_owned();
+            existing.salt = new_data.salt.to_owned();
         }
 
         existing.updated_at = DateTime::now();
@@ -109,3 +137,29 @@ impl UserRepository for UserRepositoryImpl {
         Ok(())
     }
 }
+
+impl UserRepositoryImpl {
+    async fn insert_user_with_organization(
+        tx: &RBatisTxExecutor,
+        user: &User,
+        organization: &Organization,
+        owner: &OrganizationMember,
+    ) -> Result<(), RepositoryError> {
+        let record = database::models::user::User::from(user);
+        database::models::user::User::insert(tx, &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        let record = database::models::organization::Organization::from(organization);
+        database::models::organization::Organization::insert(tx, &record)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        let member = database::models::organization::OrganizationMember::from(owner);
+        database::models::organization::OrganizationMember::insert(tx, &member)
+            .await
+            .map_err(|e| e.to_repository_error())?;
+
+        Ok(())
+    }
+}
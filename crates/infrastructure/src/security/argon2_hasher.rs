@@ -5,6 +5,7 @@ use argon2::{
 use async_trait::async_trait;
 use domain::traits::password_hasher::{HashError, HashedPassword, PasswordHasher};
 
+#[derive(Default)]
 pub struct Argon2Hasher;
 
 impl Argon2Hasher {
@@ -39,6 +40,5 @@ impl PasswordHasher for Argon2Hasher {
         argon2
             .verify_password(plain_password.as_bytes(), &hash)
             .map_err(|_| HashError::PasswordMismatch)
-            .and_then(|_| Ok(()))
     }
 }
@@ -1,2 +1,3 @@
 pub mod argon2_hasher;
+pub mod paseto_media_url_signer;
 pub mod paseto_tokenizer;
@@ -0,0 +1,57 @@
+use std::{sync::Arc, time::Duration};
+
+use domain::traits::{
+    media_url_signer::MediaUrlSigner,
+    token_service::{PasetoClaimPurpose, PasetoClaims, TokenService, TokenServiceError},
+};
+
+/// Signs `/media/{id}?token=...` URLs with a `FileAccess` PASETO token.
+pub struct PasetoMediaUrlSigner {
+    token_service: Arc<dyn TokenService>,
+    public_base_url: String,
+}
+
+impl PasetoMediaUrlSigner {
+    pub fn new(token_service: Arc<dyn TokenService>, public_base_url: String) -> Self {
+        Self {
+            token_service,
+            public_base_url: public_base_url.trim_end_matches('/').to_owned(),
+        }
+    }
+}
+
+impl MediaUrlSigner for PasetoMediaUrlSigner {
+    fn signed_path(
+        &self,
+        attachment_id: &uuid::Uuid,
+        ttl: Duration,
+    ) -> Result<String, TokenServiceError> {
+        let claims = PasetoClaims::new(
+            *attachment_id,
+            String::new(),
+            ttl,
+            PasetoClaimPurpose::FileAccess,
+        );
+        let (token, _) = self.token_service.generate_token(claims, ttl)?;
+
+        Ok(format!("/media/{}?token={}", attachment_id, token))
+    }
+
+    fn signed_url(
+        &self,
+        attachment_id: &uuid::Uuid,
+        ttl: Duration,
+    ) -> Result<String, TokenServiceError> {
+        let path = self.signed_path(attachment_id, ttl)?;
+
+        Ok(format!("{}{}", self.public_base_url, path))
+    }
+
+    fn verify(&self, token: &str) -> Result<uuid::Uuid, TokenServiceError> {
+        let claims = self
+            .token_service
+            .validate_token(token.to_owned(), PasetoClaimPurpose::FileAccess)?;
+
+        Ok(claims.id)
+    }
+}
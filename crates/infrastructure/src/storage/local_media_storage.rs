@@ -0,0 +1,72 @@
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+use domain::traits::media_storage::{MediaStorage, MediaStorageError, StoredMedia};
+use sha2::{Digest, Sha256};
+
+/// Stores media objects as plain files below a root directory.
+#[derive(Debug, Clone)]
+pub struct LocalMediaStorage {
+    root: PathBuf,
+}
+
+impl LocalMediaStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> Result<PathBuf, MediaStorageError> {
+        let relative = Path::new(key);
+        let is_safe = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+
+        if !is_safe {
+            return Err(MediaStorageError::InvalidKey(key.to_owned()));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl MediaStorage for LocalMediaStorage {
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<StoredMedia, MediaStorageError> {
+        let path = self.path_for(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| MediaStorageError::Io(e.to_string()))?;
+        }
+
+        tokio::fs::write(&path, bytes)
+            .await
+            .map_err(|e| MediaStorageError::Io(e.to_string()))?;
+
+        Ok(StoredMedia {
+            size_bytes: bytes.len() as i64,
+            checksum_sha256: hex::encode(Sha256::digest(bytes)),
+        })
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, MediaStorageError> {
+        let path = self.path_for(key)?;
+
+        tokio::fs::read(&path).await.map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => MediaStorageError::NotFound,
+            _ => MediaStorageError::Io(e.to_string()),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), MediaStorageError> {
+        let path = self.path_for(key)?;
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(MediaStorageError::Io(e.to_string())),
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose::STANDARD};
-use domain::traits::outbound_message_service::{
-    OutboundMessageError, OutboundMessageService, SendMessageRequest, SendMessageResponse,
+use domain::traits::{
+    media_downloader::{DownloadedMedia, MediaDownloadError, MediaDownloader},
+    outbound_message_service::{
+        OutboundMessageError, OutboundMessageService, SendMessageRequest, SendMessageResponse,
+    },
 };
 use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 use reqwest::{Client, StatusCode};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use time::OffsetDateTime;
 
 #[derive(Debug, Clone, bon::Builder)]
@@ -22,7 +25,10 @@ struct SendTelnyxMessageRequest<'a> {
     messaging_profile_id: &'a str,
     from: &'a str,
     to: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
     text: &'a str,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    media_urls: &'a [String],
 }
 
 #[derive(Debug, Deserialize)]
@@ -66,6 +72,37 @@ pub struct TelnyxMessagingWebhookPayload {
     pub completed_at: Option<OffsetDateTime>,
     #[serde(default)]
     pub errors: Vec<TelnyxErrorItem>,
+    #[serde(default)]
+    pub media: Vec<TelnyxMessagingWebhookMedia>,
+    #[serde(default)]
+    pub parts: Option<i32>,
+    #[serde(default)]
+    pub encoding: Option<String>,
+    #[serde(default)]
+    pub cost: Option<TelnyxMessagingWebhookCost>,
+    #[serde(default)]
+    pub messaging_profile_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelnyxMessagingWebhookCost {
+    /// Decimal amount such as `"0.0040"`. Telnyx sends a string, but plain numbers are
+    /// accepted too.
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub amount: Option<String>,
+    #[serde(default)]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelnyxMessagingWebhookMedia {
+    pub url: String,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    #[serde(default)]
+    pub size: Option<i64>,
+    #[serde(default, alias = "hash_sha256")]
+    pub sha256: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -74,6 +111,10 @@ pub struct TelnyxMessagingWebhookEndpoint {
     pub phone_number: Option<String>,
     #[serde(default)]
     pub status: Option<String>,
+    #[serde(default)]
+    pub carrier: Option<String>,
+    #[serde(default)]
+    pub line_type: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -135,6 +176,7 @@ impl OutboundMessageService for TelnyxClient {
                 from: &request.from,
                 to: &request.to,
                 text: &request.text,
+                media_urls: &request.media_urls,
             })
             .send()
             .await
@@ -169,6 +211,62 @@ impl OutboundMessageService for TelnyxClient {
     }
 }
 
+/// Inbound MMS media links are pre-signed by Telnyx and expire, so they are
+/// fetched without the API key.
+#[async_trait]
+impl MediaDownloader for TelnyxClient {
+    async fn download(
+        &self,
+        url: &str,
+        max_bytes: usize,
+    ) -> Result<DownloadedMedia, MediaDownloadError> {
+        let mut response = self
+            .http_client
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| MediaDownloadError::Failed(err.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(MediaDownloadError::Failed(format!(
+                "unexpected status {}",
+                response.status()
+            )));
+        }
+
+        if response
+            .content_length()
+            .is_some_and(|length| length > max_bytes as u64)
+        {
+            return Err(MediaDownloadError::TooLarge(max_bytes));
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.split(';').next().unwrap_or(value).trim().to_owned())
+            .filter(|value| !value.is_empty());
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| MediaDownloadError::Failed(err.to_string()))?
+        {
+            if bytes.len() + chunk.len() > max_bytes {
+                return Err(MediaDownloadError::TooLarge(max_bytes));
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+
+        Ok(DownloadedMedia {
+            bytes,
+            content_type,
+        })
+    }
+}
+
 pub fn verify_messaging_webhook(
     raw_body: &[u8],
     signature_header: Option<&str>,
@@ -237,7 +335,7 @@ fn parse_public_key(public_key: &str) -> Result<[u8; 32], WebhookVerificationErr
 }
 
 fn is_hex(value: &str) -> bool {
-    value.len() % 2 == 0 && value.bytes().all(|byte| byte.is_ascii_hexdigit())
+    value.len().is_multiple_of(2) && value.bytes().all(|byte| byte.is_ascii_hexdigit())
 }
 
 fn decode_hex(value: &str) -> Result<Vec<u8>, WebhookVerificationError> {
@@ -259,6 +357,19 @@ fn decode_hex(value: &str) -> Result<Vec<u8>, WebhookVerificationError> {
     Ok(bytes)
 }
 
+fn deserialize_amount<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(amount)) => Some(amount),
+            Some(serde_json::Value::Number(amount)) => Some(amount.to_string()),
+            _ => None,
+        },
+    )
+}
+
 fn telnyx_error_message(status: StatusCode, body: &str) -> String {
     if let Ok(parsed) = serde_json::from_str::<TelnyxErrorEnvelope>(body) {
         let details = parsed
@@ -287,8 +398,11 @@ fn telnyx_error_message(status: StatusCode, body: &str) -> String {
 #[cfg(test)]
 mod tests {
     use base64::{Engine as _, engine::general_purpose::STANDARD};
-    use domain::traits::outbound_message_service::{
-        OutboundMessageError, OutboundMessageService, SendMessageRequest,
+    use domain::traits::{
+        media_downloader::{MediaDownloadError, MediaDownloader},
+        outbound_message_service::{
+            OutboundMessageError, OutboundMessageService, SendMessageRequest,
+        },
     };
     use ed25519_dalek::{Signer, SigningKey};
     use time::OffsetDateTime;
@@ -297,7 +411,10 @@ mod tests {
         matchers::{body_json, header, method, path},
     };
 
-    use super::{TelnyxClient, WebhookVerificationError, verify_messaging_webhook};
+    use super::{
+        TelnyxClient, TelnyxMessagingWebhook, TelnyxMessagingWebhookCost, WebhookVerificationError,
+        verify_messaging_webhook,
+    };
 
     #[tokio::test]
     async fn sends_expected_request_and_parses_provider_message_id() {
@@ -331,6 +448,7 @@ mod tests {
                 from: "+13125550100".to_owned(),
                 to: "+14155551234".to_owned(),
                 text: "Hello".to_owned(),
+                media_urls: Vec::new(),
             })
             .await
             .expect("request should succeed");
@@ -338,6 +456,79 @@ mod tests {
         assert_eq!(response.provider_message_id, "provider-message-id");
     }
 
+    #[tokio::test]
+    async fn sends_media_urls_for_mms() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v2/messages"))
+            .and(body_json(serde_json::json!({
+                "messaging_profile_id": "test-messaging-profile-id",
+                "from": "+13125550100",
+                "to": "+14155551234",
+                "media_urls": ["https://example.com/media/photo.jpg"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "provider-mms-id"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = TelnyxClient::builder()
+            .api_key("test-api-key".to_owned())
+            .base_url(server.uri())
+            .messaging_profile_id("test-messaging-profile-id".to_owned())
+            .build();
+
+        let response = client
+            .send_text_message(SendMessageRequest {
+                from: "+13125550100".to_owned(),
+                to: "+14155551234".to_owned(),
+                text: String::new(),
+                media_urls: vec!["https://example.com/media/photo.jpg".to_owned()],
+            })
+            .await
+            .expect("request should succeed");
+
+        assert_eq!(response.provider_message_id, "provider-mms-id");
+    }
+
+    #[tokio::test]
+    async fn downloads_inbound_media_without_api_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/media/photo.jpg"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "image/jpeg; charset=binary")
+                    .set_body_bytes(vec![0xFF, 0xD8, 0xFF]),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = TelnyxClient::builder()
+            .api_key("test-api-key".to_owned())
+            .base_url(server.uri())
+            .messaging_profile_id("test-messaging-profile-id".to_owned())
+            .build();
+
+        let media = client
+            .download(&format!("{}/media/photo.jpg", server.uri()), 1024)
+            .await
+            .expect("download should succeed");
+        assert_eq!(media.bytes, vec![0xFF, 0xD8, 0xFF]);
+        assert_eq!(media.content_type.as_deref(), Some("image/jpeg"));
+
+        let err = client
+            .download(&format!("{}/media/photo.jpg", server.uri()), 2)
+            .await
+            .expect_err("oversized media should fail");
+        assert!(matches!(err, MediaDownloadError::TooLarge(2)));
+    }
+
     #[tokio::test]
     async fn maps_provider_validation_errors_to_rejected() {
         let server = MockServer::start().await;
@@ -362,6 +553,7 @@ mod tests {
                 from: "+13125550100".to_owned(),
                 to: "bad-number".to_owned(),
                 text: "Hello".to_owned(),
+                media_urls: Vec::new(),
             })
             .await
             .expect_err("request should fail");
@@ -396,6 +588,7 @@ mod tests {
                 from: "+13125550100".to_owned(),
                 to: "+14155551234".to_owned(),
                 text: "Hello".to_owned(),
+                media_urls: Vec::new(),
             })
             .await
             .expect_err("request should fail");
@@ -429,6 +622,31 @@ mod tests {
         assert_eq!(event.data.payload.id, "provider-message-id");
     }
 
+    #[test]
+    fn parses_cost_parts_and_carrier_details() {
+        let payload = r#"{"data":{"event_type":"message.finalized","id":"event-1","occurred_at":"2024-01-15T21:32:13.596+00:00","payload":{"id":"provider-message-id","parts":2,"encoding":"UCS-2","cost":{"amount":"0.0080","currency":"USD"},"messaging_profile_id":"profile-1","from":{"phone_number":"+13125550100","carrier":"Telnyx","line_type":"Wireline"},"to":[{"phone_number":"+14155551234","status":"delivered","carrier":"T-Mobile USA","line_type":"Wireless"}]}}}"#;
+
+        let event = serde_json::from_str::<TelnyxMessagingWebhook>(payload).expect("valid payload");
+        let payload = event.data.payload;
+
+        assert_eq!(payload.parts, Some(2));
+        assert_eq!(payload.encoding.as_deref(), Some("UCS-2"));
+        let cost = payload.cost.expect("cost");
+        assert_eq!(cost.amount.as_deref(), Some("0.0080"));
+        assert_eq!(cost.currency.as_deref(), Some("USD"));
+        assert_eq!(payload.messaging_profile_id.as_deref(), Some("profile-1"));
+        assert_eq!(payload.to[0].carrier.as_deref(), Some("T-Mobile USA"));
+        assert_eq!(payload.to[0].line_type.as_deref(), Some("Wireless"));
+        assert_eq!(
+            payload.from.and_then(|from| from.line_type).as_deref(),
+            Some("Wireline")
+        );
+
+        let cost = serde_json::from_str::<TelnyxMessagingWebhookCost>(r#"{"amount":0.004}"#)
+            .expect("numeric amount");
+        assert_eq!(cost.amount.as_deref(), Some("0.004"));
+    }
+
     #[test]
     fn rejects_missing_signature_header() {
         let err = verify_messaging_webhook(
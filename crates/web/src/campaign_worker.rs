@@ -0,0 +1,102 @@
+use std::{sync::Arc, time::Duration};
+
+use application::usecases::{
+    create_message_usecase::{CreateMessageUsecase, UnverifiedSenderPolicy},
+    process_campaigns_usecase::{CAMPAIGN_BATCH_INTERVAL, ProcessCampaignsUsecase},
+};
+use domain::{
+    repositories::{
+        campaign_repository::CampaignRepository, contact_repository::ContactRepository,
+        conversation_repository::ConversationRepository, message_repository::MessageRepository,
+        message_template_repository::MessageTemplateRepository,
+        opt_out_repository::OptOutRepository, organization_repository::OrganizationRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+        phone_number_repository::PhoneNumberRepository,
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+        user_repository::UserRepository,
+    },
+    traits::media_storage::MediaStorage,
+};
+use time::OffsetDateTime;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+
+/// Background worker that fans running campaigns out into queued messages, one throttled
+/// batch per campaign per tick. Safe to run in several processes at once.
+#[derive(bon::Builder)]
+pub struct CampaignWorker {
+    campaign_repository: Arc<dyn CampaignRepository>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    message_repository: Arc<dyn MessageRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    media_storage: Arc<dyn MediaStorage>,
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    #[builder(default)]
+    block_unverified_senders: bool,
+    #[builder(default = CAMPAIGN_BATCH_INTERVAL)]
+    poll_interval: Duration,
+}
+
+impl CampaignWorker {
+    pub async fn run(self) {
+        let create_message_usecase = CreateMessageUsecase::builder()
+            .conversation_repository(self.conversation_repository.clone())
+            .message_repository(self.message_repository.clone())
+            .phone_number_repository(self.phone_number_repository.clone())
+            .user_repository(self.user_repository.clone())
+            .outbound_message_queue_repository(self.outbound_message_queue_repository.clone())
+            .opt_out_repository(self.opt_out_repository.clone())
+            .message_template_repository(self.message_template_repository.clone())
+            .contact_repository(self.contact_repository.clone())
+            .usage_repository(self.usage_repository.clone())
+            .usage_limit_repository(self.usage_limit_repository.clone())
+            .organization_repository(self.organization_repository.clone())
+            .media_storage(self.media_storage.clone())
+            .unverified_sender_policy(if self.block_unverified_senders {
+                UnverifiedSenderPolicy::Block
+            } else {
+                UnverifiedSenderPolicy::Allow
+            })
+            .build();
+        let usecase = ProcessCampaignsUsecase::builder()
+            .campaign_repository(self.campaign_repository.clone())
+            .conversation_repository(self.conversation_repository.clone())
+            .opt_out_repository(self.opt_out_repository.clone())
+            .organization_repository(self.organization_repository.clone())
+            .create_message_usecase(create_message_usecase)
+            .build();
+
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            Self::process_due(&usecase).await;
+        }
+    }
+
+    async fn process_due(usecase: &ProcessCampaignsUsecase) {
+        let result = match usecase.execute(OffsetDateTime::now_utc()).await {
+            Ok(result) => result,
+            Err(err) => {
+                error!("failed to claim campaigns: {}", err);
+                return;
+            }
+        };
+
+        for (campaign_id, err) in result.failures {
+            warn!("campaign {} batch stopped early: {}", campaign_id, err);
+        }
+
+        for campaign_id in result.completed {
+            info!("campaign {} has messaged every recipient", campaign_id);
+        }
+    }
+}
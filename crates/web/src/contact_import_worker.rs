@@ -0,0 +1,68 @@
+use std::{sync::Arc, time::Duration};
+
+use application::usecases::process_contact_imports_usecase::ProcessContactImportsUsecase;
+use domain::{
+    models::contact_import::ContactImportStatus,
+    repositories::{
+        contact_import_repository::ContactImportRepository, contact_repository::ContactRepository,
+    },
+};
+use time::OffsetDateTime;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Background worker that applies contact imports too large to run during the upload
+/// request. Safe to run in several processes at once.
+#[derive(bon::Builder)]
+pub struct ContactImportWorker {
+    contact_import_repository: Arc<dyn ContactImportRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    #[builder(default = POLL_INTERVAL)]
+    poll_interval: Duration,
+}
+
+impl ContactImportWorker {
+    pub async fn run(self) {
+        let usecase = ProcessContactImportsUsecase::builder()
+            .contact_import_repository(self.contact_import_repository.clone())
+            .contact_repository(self.contact_repository.clone())
+            .build();
+
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            // Keep going while there is work so a backlog of imports drains quickly.
+            while self.process_next(&usecase).await {}
+        }
+    }
+
+    async fn process_next(&self, usecase: &ProcessContactImportsUsecase) -> bool {
+        let import = match usecase.execute(OffsetDateTime::now_utc()).await {
+            Ok(Some(import)) => import,
+            Ok(None) => return false,
+            Err(err) => {
+                error!("failed to process contact import: {}", err);
+                return false;
+            }
+        };
+
+        match import.status {
+            ContactImportStatus::Failed => warn!(
+                "contact import {} failed: {}",
+                import.id,
+                import.last_error.as_deref().unwrap_or_default()
+            ),
+            _ => info!(
+                "contact import {} finished with {} rows",
+                import.id,
+                import.rows.len()
+            ),
+        }
+
+        true
+    }
+}
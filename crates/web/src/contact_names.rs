@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use application::usecases::find_conversation_contacts_usecase::FindConversationContactsUsecase;
+use domain::{
+    models::conversation::Conversation, repositories::contact_repository::ContactRepository,
+};
+use tracing::warn;
+
+use crate::dto::ConversationProps;
+
+/// Builds conversation props labelled with the address book name of each recipient.
+/// A failed lookup only costs the names, so it is logged and the raw numbers are shown.
+pub(crate) async fn conversation_props_with_contacts(
+    contact_repository: &Arc<dyn ContactRepository>,
+    conversations: &[Conversation],
+) -> Vec<ConversationProps> {
+    let contacts = FindConversationContactsUsecase::builder()
+        .contact_repository(contact_repository.clone())
+        .build()
+        .execute(conversations)
+        .await
+        .unwrap_or_else(|err| {
+            warn!("failed to resolve conversation contacts: {}", err);
+            Default::default()
+        });
+
+    conversations
+        .iter()
+        .map(|conversation| {
+            ConversationProps::from(conversation).with_contact(contacts.get(&conversation.id))
+        })
+        .collect()
+}
+
+/// Single-conversation form of [`conversation_props_with_contacts`].
+pub(crate) async fn conversation_props_with_contact(
+    contact_repository: &Arc<dyn ContactRepository>,
+    conversation: &Conversation,
+) -> ConversationProps {
+    conversation_props_with_contacts(contact_repository, std::slice::from_ref(conversation))
+        .await
+        .pop()
+        .unwrap_or_else(|| ConversationProps::from(conversation))
+}
@@ -59,6 +59,12 @@ pub struct ForgotPasswordRequest {
     pub email: String,
 }
 
+impl From<ForgotPasswordRequest> for commands::ForgotPasswordCommand {
+    fn from(req: ForgotPasswordRequest) -> Self {
+        Self { email: req.email }
+    }
+}
+
 /// Reset password request DTO
 #[derive(Debug, Deserialize)]
 pub struct ResetPasswordRequest {
@@ -67,6 +73,16 @@ pub struct ResetPasswordRequest {
     pub password_confirmation: String,
 }
 
+impl From<ResetPasswordRequest> for commands::ResetPasswordCommand {
+    fn from(req: ResetPasswordRequest) -> Self {
+        Self {
+            token: req.token,
+            password: req.password,
+            password_confirmation: req.password_confirmation,
+        }
+    }
+}
+
 /// Login page error props
 #[derive(Debug, Clone, Serialize)]
 pub struct LoginErrorProps {
@@ -82,3 +98,17 @@ pub struct SignupErrorProps {
     pub password: Option<String>,
     pub general: Option<String>,
 }
+
+/// Forgot password page error props
+#[derive(Debug, Clone, Serialize)]
+pub struct ForgotPasswordErrorProps {
+    pub email: Option<String>,
+    pub general: Option<String>,
+}
+
+/// Reset password page error props
+#[derive(Debug, Clone, Serialize)]
+pub struct ResetPasswordErrorProps {
+    pub password: Option<String>,
+    pub general: Option<String>,
+}
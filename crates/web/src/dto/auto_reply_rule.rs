@@ -0,0 +1,98 @@
+use application::commands::AutoReplyRuleDetails;
+use domain::models::auto_reply_rule::{AutoReplyMatchType, AutoReplyRule};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoReplyMatchTypeRequest {
+    Exact,
+    Prefix,
+    Regex,
+}
+
+impl From<AutoReplyMatchTypeRequest> for AutoReplyMatchType {
+    fn from(value: AutoReplyMatchTypeRequest) -> Self {
+        match value {
+            AutoReplyMatchTypeRequest::Exact => AutoReplyMatchType::Exact,
+            AutoReplyMatchTypeRequest::Prefix => AutoReplyMatchType::Prefix,
+            AutoReplyMatchTypeRequest::Regex => AutoReplyMatchType::Regex,
+        }
+    }
+}
+
+/// Body of both `POST /phone-numbers/{id}/auto-replies` and
+/// `PUT /phone-numbers/{id}/auto-replies/{rule_id}`; updates replace every field.
+#[derive(Debug, Deserialize)]
+pub struct AutoReplyRuleRequest {
+    #[serde(alias = "matchType")]
+    pub match_type: AutoReplyMatchTypeRequest,
+    pub pattern: String,
+    #[serde(alias = "replyTemplate")]
+    pub reply_template: String,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default, alias = "cooldownSeconds")]
+    pub cooldown_seconds: i64,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+impl From<AutoReplyRuleRequest> for AutoReplyRuleDetails {
+    fn from(value: AutoReplyRuleRequest) -> Self {
+        Self {
+            match_type: value.match_type.into(),
+            pattern: value.pattern,
+            reply_template: value.reply_template,
+            tag: value.tag,
+            cooldown_seconds: value.cooldown_seconds,
+            enabled: value.enabled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoReplyRuleProps {
+    pub id: uuid::Uuid,
+    pub phone_number_id: uuid::Uuid,
+    pub match_type: &'static str,
+    pub pattern: String,
+    pub reply_template: String,
+    pub tag: Option<String>,
+    pub cooldown_seconds: i64,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&AutoReplyRule> for AutoReplyRuleProps {
+    fn from(value: &AutoReplyRule) -> Self {
+        Self {
+            id: value.id,
+            phone_number_id: value.phone_number_id,
+            match_type: match value.match_type {
+                AutoReplyMatchType::Exact => "exact",
+                AutoReplyMatchType::Prefix => "prefix",
+                AutoReplyMatchType::Regex => "regex",
+            },
+            pattern: value.pattern.to_owned(),
+            reply_template: value.reply_template.to_owned(),
+            tag: value.tag.to_owned(),
+            cooldown_seconds: value.cooldown_seconds,
+            enabled: value.enabled,
+            created_at: value
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.created_at.to_string()),
+            updated_at: value
+                .updated_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.updated_at.to_string()),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use application::commands::BusinessHoursDetails;
+use domain::models::business_hours::{
+    BusinessHours, OpeningHours, format_time_of_day, parse_date, parse_time_of_day,
+};
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, Weekday, format_description::well_known::Rfc3339};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekdayRequest {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<WeekdayRequest> for Weekday {
+    fn from(value: WeekdayRequest) -> Self {
+        match value {
+            WeekdayRequest::Monday => Weekday::Monday,
+            WeekdayRequest::Tuesday => Weekday::Tuesday,
+            WeekdayRequest::Wednesday => Weekday::Wednesday,
+            WeekdayRequest::Thursday => Weekday::Thursday,
+            WeekdayRequest::Friday => Weekday::Friday,
+            WeekdayRequest::Saturday => Weekday::Saturday,
+            WeekdayRequest::Sunday => Weekday::Sunday,
+        }
+    }
+}
+
+/// An opening given as `HH:MM` times; closing at or before the opening time runs past
+/// midnight.
+#[derive(Debug, Deserialize)]
+pub struct OpeningHoursRequest {
+    pub weekday: WeekdayRequest,
+    #[serde(alias = "opensAt")]
+    pub opens_at: String,
+    #[serde(alias = "closesAt")]
+    pub closes_at: String,
+}
+
+/// Body of `PUT /phone-numbers/{id}/business-hours`; replaces every field.
+#[derive(Debug, Deserialize)]
+pub struct BusinessHoursRequest {
+    pub timezone: String,
+    #[serde(default, alias = "openingHours")]
+    pub opening_hours: Vec<OpeningHoursRequest>,
+    /// `YYYY-MM-DD` dates in the schedule's timezone.
+    #[serde(default)]
+    pub holidays: Vec<String>,
+    #[serde(alias = "awayMessage")]
+    pub away_message: String,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+impl TryFrom<BusinessHoursRequest> for BusinessHoursDetails {
+    type Error = String;
+
+    fn try_from(value: BusinessHoursRequest) -> Result<Self, Self::Error> {
+        let opening_hours = value
+            .opening_hours
+            .into_iter()
+            .map(|opening| {
+                let parse = |time: &str| {
+                    parse_time_of_day(time)
+                        .ok_or_else(|| format!("Opening times must look like 09:30, got {}", time))
+                };
+                Ok(OpeningHours {
+                    weekday: opening.weekday.into(),
+                    opens_at: parse(&opening.opens_at)?,
+                    closes_at: parse(&opening.closes_at)?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let holidays = value
+            .holidays
+            .iter()
+            .map(|date| {
+                parse_date(date)
+                    .ok_or_else(|| format!("Holidays must look like 2026-12-25, got {}", date))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            timezone: value.timezone,
+            opening_hours,
+            holidays,
+            away_message: value.away_message,
+            enabled: value.enabled,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpeningHoursProps {
+    pub weekday: &'static str,
+    pub opens_at: String,
+    pub closes_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessHoursProps {
+    pub phone_number_id: uuid::Uuid,
+    pub timezone: String,
+    pub opening_hours: Vec<OpeningHoursProps>,
+    pub holidays: Vec<String>,
+    pub away_message: String,
+    pub enabled: bool,
+    /// Whether the number is within its opening hours right now.
+    pub open_now: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&BusinessHours> for BusinessHoursProps {
+    fn from(value: &BusinessHours) -> Self {
+        Self {
+            phone_number_id: value.phone_number_id,
+            timezone: value.timezone.to_owned(),
+            opening_hours: value
+                .opening_hours
+                .iter()
+                .map(|opening| OpeningHoursProps {
+                    weekday: match opening.weekday {
+                        Weekday::Monday => "monday",
+                        Weekday::Tuesday => "tuesday",
+                        Weekday::Wednesday => "wednesday",
+                        Weekday::Thursday => "thursday",
+                        Weekday::Friday => "friday",
+                        Weekday::Saturday => "saturday",
+                        Weekday::Sunday => "sunday",
+                    },
+                    opens_at: format_time_of_day(opening.opens_at),
+                    closes_at: format_time_of_day(opening.closes_at),
+                })
+                .collect(),
+            holidays: value.holidays.iter().map(ToString::to_string).collect(),
+            away_message: value.away_message.to_owned(),
+            enabled: value.enabled,
+            open_now: value.closed_since(OffsetDateTime::now_utc()).is_none(),
+            created_at: value
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.created_at.to_string()),
+            updated_at: value
+                .updated_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.updated_at.to_string()),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use application::responses::CampaignResult;
+use domain::models::campaign::CampaignStatus;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+
+/// Body of `POST /campaigns`. Recipients are the contacts carrying `tag`, the contacts in
+/// `contact_ids` and the numbers in `phone_numbers`, without duplicates.
+#[derive(Debug, Deserialize)]
+pub struct CreateCampaignRequest {
+    pub name: String,
+    #[serde(alias = "phoneNumberId")]
+    pub phone_number_id: uuid::Uuid,
+    pub body: String,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default, alias = "contactIds")]
+    pub contact_ids: Vec<uuid::Uuid>,
+    #[serde(default, alias = "phoneNumbers")]
+    pub phone_numbers: Vec<String>,
+    #[serde(default, alias = "messagesPerSecond")]
+    pub messages_per_second: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignProgressProps {
+    pub total: u64,
+    pub pending: u64,
+    pub skipped: u64,
+    pub queued: u64,
+    pub sent: u64,
+    pub delivered: u64,
+    pub failed: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignProps {
+    pub id: uuid::Uuid,
+    pub phone_number_id: uuid::Uuid,
+    pub name: String,
+    pub body: String,
+    pub tag: Option<String>,
+    pub messages_per_second: i32,
+    /// `running`, `paused`, `completed` or `cancelled`.
+    pub status: String,
+    pub progress: CampaignProgressProps,
+    /// Why the campaign was paused automatically, if it was.
+    pub pause_reason: Option<String>,
+    pub completed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&CampaignResult> for CampaignProps {
+    fn from(value: &CampaignResult) -> Self {
+        let campaign = &value.campaign;
+        let progress = &value.progress;
+
+        Self {
+            id: campaign.id,
+            phone_number_id: campaign.phone_number_id,
+            name: campaign.name.to_owned(),
+            body: campaign.body.to_owned(),
+            tag: campaign.tag.to_owned(),
+            messages_per_second: campaign.messages_per_second,
+            status: match campaign.status {
+                CampaignStatus::Running => "running",
+                CampaignStatus::Paused => "paused",
+                CampaignStatus::Completed => "completed",
+                CampaignStatus::Cancelled => "cancelled",
+            }
+            .to_owned(),
+            progress: CampaignProgressProps {
+                total: progress.total,
+                pending: progress.pending,
+                skipped: progress.skipped,
+                queued: progress.queued,
+                sent: progress.sent,
+                delivered: progress.delivered,
+                failed: progress.failed,
+            },
+            pause_reason: campaign.pause_reason.to_owned(),
+            completed_at: campaign
+                .completed_at
+                .map(|at| at.format(&Rfc3339).unwrap_or_else(|_| at.to_string())),
+            created_at: campaign
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| campaign.created_at.to_string()),
+            updated_at: campaign
+                .updated_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| campaign.updated_at.to_string()),
+        }
+    }
+}
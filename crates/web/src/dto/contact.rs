@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+
+use application::commands::ContactDetails;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+
+/// Body of both `POST /contacts` and `PUT /contacts/{id}`; updates replace every field.
+#[derive(Debug, Deserialize)]
+pub struct ContactRequest {
+    pub name: String,
+    #[serde(default, alias = "phoneNumbers")]
+    pub phone_numbers: Vec<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default, alias = "customFields")]
+    pub custom_fields: BTreeMap<String, String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl From<ContactRequest> for ContactDetails {
+    fn from(value: ContactRequest) -> Self {
+        Self {
+            name: value.name,
+            phone_numbers: value.phone_numbers,
+            email: value.email,
+            notes: value.notes,
+            custom_fields: value.custom_fields,
+            tags: value.tags,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactProps {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub phone_numbers: Vec<String>,
+    pub email: Option<String>,
+    pub notes: Option<String>,
+    pub custom_fields: BTreeMap<String, String>,
+    pub tags: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&domain::models::contact::Contact> for ContactProps {
+    fn from(value: &domain::models::contact::Contact) -> Self {
+        Self {
+            id: value.id,
+            name: value.name.to_owned(),
+            phone_numbers: value.phone_numbers.to_owned(),
+            email: value.email.to_owned(),
+            notes: value.notes.to_owned(),
+            custom_fields: value.custom_fields.to_owned(),
+            tags: value.tags.to_owned(),
+            created_at: value
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.created_at.to_string()),
+            updated_at: value
+                .updated_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.updated_at.to_string()),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use domain::models::contact_import::{
+    ContactImport, ContactImportFormat, ContactImportOutcome, ContactImportRow, ContactImportStatus,
+};
+use serde::Serialize;
+use time::format_description::well_known::Rfc3339;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactImportRowProps {
+    pub line: usize,
+    pub outcome: ContactImportOutcome,
+    pub name: Option<String>,
+    pub phone_numbers: Vec<String>,
+    pub contact_id: Option<uuid::Uuid>,
+    pub reason: Option<String>,
+}
+
+impl From<&ContactImportRow> for ContactImportRowProps {
+    fn from(value: &ContactImportRow) -> Self {
+        Self {
+            line: value.line,
+            outcome: value.outcome,
+            name: value.name.to_owned(),
+            phone_numbers: value.phone_numbers.to_owned(),
+            contact_id: value.contact_id,
+            reason: value.reason.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactImportProps {
+    pub id: uuid::Uuid,
+    pub file_name: String,
+    /// `csv` or `vcard`.
+    pub format: String,
+    /// `pending`, `completed` or `failed`.
+    pub status: String,
+    pub imported: usize,
+    pub skipped: usize,
+    pub invalid: usize,
+    pub rows: Vec<ContactImportRowProps>,
+    pub error: Option<String>,
+    pub completed_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&ContactImport> for ContactImportProps {
+    fn from(value: &ContactImport) -> Self {
+        Self {
+            id: value.id,
+            file_name: value.file_name.to_owned(),
+            format: match value.format {
+                ContactImportFormat::Csv => "csv",
+                ContactImportFormat::VCard => "vcard",
+            }
+            .to_owned(),
+            status: match value.status {
+                ContactImportStatus::Pending => "pending",
+                ContactImportStatus::Completed => "completed",
+                ContactImportStatus::Failed => "failed",
+            }
+            .to_owned(),
+            imported: value.count(ContactImportOutcome::Imported),
+            skipped: value.count(ContactImportOutcome::Skipped),
+            invalid: value.count(ContactImportOutcome::Invalid),
+            rows: value.rows.iter().map(ContactImportRowProps::from).collect(),
+            error: value.last_error.to_owned(),
+            completed_at: value
+                .completed_at
+                .map(|at| at.format(&Rfc3339).unwrap_or_else(|_| at.to_string())),
+            created_at: value
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.created_at.to_string()),
+        }
+    }
+}
@@ -1,6 +1,84 @@
+use std::time::Duration;
+
+use application::responses::MessagePreview;
+use domain::{
+    models::{
+        conversation::{ConversationFilter, ConversationView},
+        money::format_micros,
+        sms_encoding::{Gsm7Replacement, SmsEncoding},
+    },
+    traits::media_url_signer::MediaUrlSigner,
+};
 use serde::{Deserialize, Serialize};
 use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 
+/// How long attachment preview links rendered into pages and events stay valid.
+const MEDIA_PREVIEW_TTL: Duration = Duration::from_secs(60 * 60);
+
+pub const CONVERSATION_ASSIGNED_EVENT: &str = "conversation.assigned";
+pub const CONVERSATION_READ_EVENT: &str = "conversation.read";
+/// Archived, pinned or snoozed, or back from any of those.
+pub const CONVERSATION_UPDATED_EVENT: &str = "conversation.updated";
+
+#[derive(Debug, Deserialize)]
+pub struct ConversationsQuery {
+    /// `all`, `mine` or `unassigned`. Anything else shows all conversations.
+    pub view: Option<String>,
+    /// `inbox`, `pinned`, `snoozed` or `archived`. Anything else shows the inbox.
+    pub state: Option<String>,
+}
+
+pub fn parse_conversation_filter(value: &str) -> Option<ConversationFilter> {
+    match value {
+        "inbox" => Some(ConversationFilter::Inbox),
+        "pinned" => Some(ConversationFilter::Pinned),
+        "snoozed" => Some(ConversationFilter::Snoozed),
+        "archived" => Some(ConversationFilter::Archived),
+        _ => None,
+    }
+}
+
+pub fn conversation_filter_name(filter: ConversationFilter) -> &'static str {
+    match filter {
+        ConversationFilter::Inbox => "inbox",
+        ConversationFilter::Pinned => "pinned",
+        ConversationFilter::Snoozed => "snoozed",
+        ConversationFilter::Archived => "archived",
+    }
+}
+
+pub fn parse_conversation_view(value: &str) -> Option<ConversationView> {
+    match value {
+        "all" => Some(ConversationView::All),
+        "mine" => Some(ConversationView::Mine),
+        "unassigned" => Some(ConversationView::Unassigned),
+        _ => None,
+    }
+}
+
+pub fn conversation_view_name(view: ConversationView) -> &'static str {
+    match view {
+        ConversationView::All => "all",
+        ConversationView::Mine => "mine",
+        ConversationView::Unassigned => "unassigned",
+    }
+}
+
+/// Body of `PUT /conversations/{id}/assignee`. A missing or null id unassigns.
+#[derive(Debug, Deserialize)]
+pub struct AssignConversationRequest {
+    #[serde(default, alias = "assignedUserId")]
+    pub assigned_user_id: Option<uuid::Uuid>,
+}
+
+/// Body of `POST /conversations/{id}/snooze`.
+#[derive(Debug, Deserialize)]
+pub struct SnoozeConversationRequest {
+    /// RFC 3339 time the conversation returns to the inbox, e.g. `2026-03-02T09:00:00-05:00`.
+    #[serde(alias = "snoozedUntil")]
+    pub snoozed_until: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateConversationRequest {
     #[serde(alias = "phoneNumberId")]
@@ -16,7 +94,36 @@ pub struct CreateConversationResponse {
 
 #[derive(Debug, Deserialize)]
 pub struct CreateMessageRequest {
+    #[serde(default)]
     pub content: String,
+    /// Sends the template rendered for this conversation instead of `content`.
+    #[serde(default, alias = "templateId")]
+    pub template_id: Option<uuid::Uuid>,
+    /// RFC 3339 time to send the message at, e.g. `2026-03-01T09:00:00-05:00`. The offset
+    /// picks the timezone, so clients can schedule in their own or the recipient's local time.
+    #[serde(default, alias = "scheduledAt")]
+    pub scheduled_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PreviewMessageRequest {
+    #[serde(default)]
+    pub content: String,
+    /// Previews the template rendered for this conversation instead of `content`.
+    #[serde(default, alias = "templateId")]
+    pub template_id: Option<uuid::Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RescheduleMessageRequest {
+    #[serde(alias = "scheduledAt")]
+    pub scheduled_at: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledMessagesResponse {
+    pub messages: Vec<MessageProps>,
 }
 
 #[derive(Debug, Serialize)]
@@ -40,6 +147,32 @@ pub struct MessageEventProps {
     pub conversation: ConversationProps,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationEventProps {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub conversation: ConversationProps,
+}
+
+/// Sent to the member's other tabs once they read a conversation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationReadEventProps {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub conversation_id: uuid::Uuid,
+    /// Unread messages the member has left across the organization.
+    pub total_unread: i64,
+}
+
+/// Response of `POST /conversations/{id}/read`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkConversationReadResponse {
+    pub total_unread: i64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationProps {
@@ -47,11 +180,39 @@ pub struct ConversationProps {
     pub phone_number_id: uuid::Uuid,
     pub user_id: uuid::Uuid,
     pub recipient_phone_number: Option<String>,
+    /// Address book entry owning the recipient number, if any.
+    pub contact_id: Option<uuid::Uuid>,
+    /// Contact name when the recipient is in the address book, otherwise the raw number.
+    pub display_name: Option<String>,
+    pub assigned_user_id: Option<uuid::Uuid>,
+    pub assigned_at: Option<String>,
+    pub archived_at: Option<String>,
+    pub pinned_at: Option<String>,
+    pub snoozed_until: Option<String>,
     pub last_message_at: String,
+    /// Inbound messages the viewing member has not read. `None` in events broadcast to the
+    /// whole organization, since every member has their own count.
+    pub unread_count: Option<i64>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+impl ConversationProps {
+    pub fn with_unread(mut self, unread_count: i64) -> Self {
+        self.unread_count = Some(unread_count);
+        self
+    }
+
+    pub fn with_contact(mut self, contact: Option<&domain::models::contact::Contact>) -> Self {
+        if let Some(contact) = contact {
+            self.contact_id = Some(contact.id);
+            self.display_name = Some(contact.name.to_owned());
+        }
+
+        self
+    }
+}
+
 impl From<&domain::models::conversation::Conversation> for ConversationProps {
     fn from(value: &domain::models::conversation::Conversation) -> Self {
         Self {
@@ -59,7 +220,15 @@ impl From<&domain::models::conversation::Conversation> for ConversationProps {
             phone_number_id: value.phone_number_id,
             user_id: value.user_id,
             recipient_phone_number: value.recipient_phone_number.to_owned(),
+            contact_id: None,
+            display_name: value.recipient_phone_number.to_owned(),
+            assigned_user_id: value.assigned_user_id,
+            assigned_at: value.assigned_at.map(format_datetime),
+            archived_at: value.archived_at.map(format_datetime),
+            pinned_at: value.pinned_at.map(format_datetime),
+            snoozed_until: value.snoozed_until.map(format_datetime),
             last_message_at: format_datetime(value.last_message_at),
+            unread_count: None,
             created_at: format_datetime(value.created_at),
             updated_at: format_datetime(value.updated_at),
         }
@@ -81,8 +250,40 @@ pub struct MessageProps {
     pub provider_error_detail: Option<String>,
     pub from_number: String,
     pub content: String,
+    pub scheduled_at: Option<String>,
+    pub automated: bool,
+    pub segments: Option<i32>,
+    pub encoding: Option<&'static str>,
+    /// Decimal amount Telnyx charged, in `cost_currency`.
+    pub cost: Option<String>,
+    pub cost_currency: Option<String>,
+    pub carrier: Option<String>,
+    pub line_type: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    pub attachments: Vec<MessageAttachmentProps>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageAttachmentProps {
+    pub id: uuid::Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub url: Option<String>,
+}
+
+impl From<&domain::models::message_attachment::MessageAttachment> for MessageAttachmentProps {
+    fn from(value: &domain::models::message_attachment::MessageAttachment) -> Self {
+        Self {
+            id: value.id,
+            file_name: value.file_name.to_owned(),
+            content_type: value.content_type.to_owned(),
+            size_bytes: value.size_bytes,
+            url: None,
+        }
+    }
 }
 
 impl From<&domain::models::message::Message> for MessageProps {
@@ -95,13 +296,7 @@ impl From<&domain::models::message::Message> for MessageProps {
                 domain::models::message::MessageType::Inbound => "INBOUND",
                 domain::models::message::MessageType::Outbound => "OUTBOUND",
             },
-            status: match value.status {
-                domain::models::message::MessageStatus::Pending => "pending",
-                domain::models::message::MessageStatus::Queued => "queued",
-                domain::models::message::MessageStatus::Delivered => "delivered",
-                domain::models::message::MessageStatus::Failed => "failed",
-                domain::models::message::MessageStatus::Sent => "sent",
-            },
+            status: message_status_name(value.status),
             provider_message_id: value.provider_message_id.to_owned(),
             provider_status: value.provider_status.to_owned(),
             provider_status_updated_at: value.provider_status_updated_at.map(format_datetime),
@@ -109,12 +304,173 @@ impl From<&domain::models::message::Message> for MessageProps {
             provider_error_detail: value.provider_error_detail.to_owned(),
             from_number: value.from_number.to_owned(),
             content: value.content.to_owned(),
+            scheduled_at: value.scheduled_at.map(format_datetime),
+            automated: value.automated,
+            segments: value.segments,
+            encoding: value.encoding.map(sms_encoding_name),
+            cost: value.cost_micros.map(format_micros),
+            cost_currency: value.cost_currency.to_owned(),
+            carrier: value.carrier.to_owned(),
+            line_type: value.line_type.to_owned(),
             created_at: format_datetime(value.created_at),
             updated_at: format_datetime(value.updated_at),
+            attachments: value
+                .attachments
+                .iter()
+                .map(MessageAttachmentProps::from)
+                .collect(),
+        }
+    }
+}
+
+impl MessageProps {
+    /// Builds the props and attaches a signed, expiring preview link to every attachment.
+    pub fn signed(
+        value: &domain::models::message::Message,
+        media_url_signer: &dyn MediaUrlSigner,
+    ) -> Self {
+        let mut props = Self::from(value);
+        for attachment in &mut props.attachments {
+            attachment.url = media_url_signer
+                .signed_path(&attachment.id, MEDIA_PREVIEW_TTL)
+                .inspect_err(|e| {
+                    tracing::error!("failed to sign preview url for {}: {}", attachment.id, e)
+                })
+                .ok();
+        }
+        props
+    }
+}
+
+/// One step of a message's delivery timeline.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageStatusEventProps {
+    pub id: uuid::Uuid,
+    pub message_id: uuid::Uuid,
+    pub status: &'static str,
+    pub provider_status: Option<String>,
+    pub carrier: Option<String>,
+    pub error_code: Option<String>,
+    pub error_detail: Option<String>,
+    pub occurred_at: String,
+}
+
+impl From<&domain::models::message_status_event::MessageStatusEvent> for MessageStatusEventProps {
+    fn from(value: &domain::models::message_status_event::MessageStatusEvent) -> Self {
+        Self {
+            id: value.id,
+            message_id: value.message_id,
+            status: message_status_name(value.status),
+            provider_status: value.provider_status.to_owned(),
+            carrier: value.carrier.to_owned(),
+            error_code: value.error_code.to_owned(),
+            error_detail: value.error_detail.to_owned(),
+            occurred_at: format_datetime(value.occurred_at),
         }
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageStatusHistoryResponse {
+    pub events: Vec<MessageStatusEventProps>,
+}
+
+fn sms_encoding_name(encoding: SmsEncoding) -> &'static str {
+    match encoding {
+        SmsEncoding::Gsm7 => "gsm7",
+        SmsEncoding::Ucs2 => "ucs2",
+    }
+}
+
+fn message_status_name(status: domain::models::message::MessageStatus) -> &'static str {
+    match status {
+        domain::models::message::MessageStatus::Scheduled => "scheduled",
+        domain::models::message::MessageStatus::Pending => "pending",
+        domain::models::message::MessageStatus::Queued => "queued",
+        domain::models::message::MessageStatus::Delivered => "delivered",
+        domain::models::message::MessageStatus::Failed => "failed",
+        domain::models::message::MessageStatus::Sent => "sent",
+    }
+}
+
+/// Parses a send-later time given as RFC 3339 with an explicit UTC offset.
+pub fn parse_scheduled_at(value: &str) -> Result<OffsetDateTime, String> {
+    OffsetDateTime::parse(value.trim(), &Rfc3339).map_err(|_| {
+        "Scheduled time must be an RFC 3339 timestamp with a UTC offset, e.g. \
+         2026-03-01T09:00:00-05:00"
+            .to_owned()
+    })
+}
+
+/// Parses a snooze end given as RFC 3339 with an explicit UTC offset.
+pub fn parse_snoozed_until(value: &str) -> Result<OffsetDateTime, String> {
+    OffsetDateTime::parse(value.trim(), &Rfc3339).map_err(|_| {
+        "Snooze time must be an RFC 3339 timestamp with a UTC offset, e.g. \
+         2026-03-02T09:00:00-05:00"
+            .to_owned()
+    })
+}
+
 fn format_datetime(value: OffsetDateTime) -> String {
     value.format(&Rfc3339).unwrap_or_else(|_| value.to_string())
 }
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gsm7ReplacementProps {
+    pub character: String,
+    /// `None` when the character has no GSM-7 stand-in.
+    pub replacement: Option<&'static str>,
+    pub occurrences: usize,
+}
+
+impl From<&Gsm7Replacement> for Gsm7ReplacementProps {
+    fn from(value: &Gsm7Replacement) -> Self {
+        Self {
+            character: value.character.to_string(),
+            replacement: value.replacement,
+            occurrences: value.occurrences,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessagePreviewProps {
+    pub content: String,
+    pub unresolved_variables: Vec<String>,
+    /// `gsm7` or `ucs2`.
+    pub encoding: &'static str,
+    pub units: usize,
+    pub segments: usize,
+    pub replacements: Vec<Gsm7ReplacementProps>,
+    /// `content` with every stand-in applied, when that is enough to send it as GSM-7.
+    pub suggested_content: Option<String>,
+    pub suggested_segments: Option<usize>,
+    /// Decimal amount in the billing currency, present when a segment price is configured.
+    pub estimated_cost: Option<String>,
+}
+
+impl From<&MessagePreview> for MessagePreviewProps {
+    fn from(value: &MessagePreview) -> Self {
+        let analysis = &value.analysis;
+
+        Self {
+            content: value.content.to_owned(),
+            unresolved_variables: value.unresolved_variables.to_owned(),
+            encoding: sms_encoding_name(analysis.count.encoding),
+            units: analysis.count.units,
+            segments: analysis.count.segments,
+            replacements: analysis
+                .replacements
+                .iter()
+                .map(Gsm7ReplacementProps::from)
+                .collect(),
+            suggested_content: analysis.suggested_body.to_owned(),
+            suggested_segments: analysis.suggested_count.map(|count| count.segments),
+            estimated_cost: value.estimated_cost_micros.map(format_micros),
+        }
+    }
+}
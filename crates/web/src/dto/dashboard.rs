@@ -6,4 +6,6 @@ pub struct DashboardAnalyticsProps {
     pub total_conversations: u64,
     pub total_messages: u64,
     pub total_phone_numbers: u64,
+    pub unread_conversations: u64,
+    pub unread_messages: u64,
 }
@@ -0,0 +1,80 @@
+use application::{commands::MessageTemplateDetails, responses::MessageTemplatePreview};
+use domain::models::{message_template::MessageTemplate, sms_encoding::SmsEncoding};
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+
+/// Body of both `POST /templates` and `PUT /templates/{id}`.
+#[derive(Debug, Deserialize)]
+pub struct MessageTemplateRequest {
+    pub name: String,
+    pub body: String,
+}
+
+impl From<MessageTemplateRequest> for MessageTemplateDetails {
+    fn from(value: MessageTemplateRequest) -> Self {
+        Self {
+            name: value.name,
+            body: value.body,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageTemplateProps {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub body: String,
+    /// Placeholder names used in the body, in the order they first appear.
+    pub variables: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&MessageTemplate> for MessageTemplateProps {
+    fn from(value: &MessageTemplate) -> Self {
+        Self {
+            id: value.id,
+            name: value.name.to_owned(),
+            body: value.body.to_owned(),
+            variables: value.variables(),
+            created_at: value
+                .created_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.created_at.to_string()),
+            updated_at: value
+                .updated_at
+                .format(&Rfc3339)
+                .unwrap_or_else(|_| value.updated_at.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageTemplatePreviewProps {
+    pub template: MessageTemplateProps,
+    pub content: String,
+    pub unresolved_variables: Vec<String>,
+    /// `gsm7` or `ucs2`.
+    pub encoding: String,
+    pub units: usize,
+    pub segments: usize,
+}
+
+impl From<&MessageTemplatePreview> for MessageTemplatePreviewProps {
+    fn from(value: &MessageTemplatePreview) -> Self {
+        Self {
+            template: MessageTemplateProps::from(&value.template),
+            content: value.content.to_owned(),
+            unresolved_variables: value.unresolved_variables.to_owned(),
+            encoding: match value.segments.encoding {
+                SmsEncoding::Gsm7 => "gsm7",
+                SmsEncoding::Ucs2 => "ucs2",
+            }
+            .to_owned(),
+            units: value.segments.units,
+            segments: value.segments.segments,
+        }
+    }
+}
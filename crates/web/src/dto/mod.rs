@@ -3,19 +3,54 @@
 //! Contains request/response structs for API endpoints organized by domain.
 
 pub mod auth;
+pub mod auto_reply_rule;
+pub mod business_hours;
+pub mod campaign;
+pub mod contact;
+pub mod contact_import;
 pub mod conversation;
 pub mod dashboard;
 pub mod flash;
+pub mod message_template;
+pub mod organization;
 pub mod phone_number;
+pub mod usage;
+pub mod webhook_event;
 
 pub use auth::{
-    ForgotPasswordRequest, LoginErrorProps, LoginRequest, LoginResponse, ResetPasswordRequest,
-    SignupErrorProps, SignupRequest,
+    ForgotPasswordErrorProps, ForgotPasswordRequest, LoginErrorProps, LoginRequest, LoginResponse,
+    ResetPasswordErrorProps, ResetPasswordRequest, SignupErrorProps, SignupRequest,
 };
+pub use auto_reply_rule::{AutoReplyRuleProps, AutoReplyRuleRequest};
+pub use business_hours::{BusinessHoursProps, BusinessHoursRequest};
+pub use campaign::{CampaignProgressProps, CampaignProps, CreateCampaignRequest};
+pub use contact::{ContactProps, ContactRequest};
+pub use contact_import::{ContactImportProps, ContactImportRowProps};
 pub use conversation::{
-    ConversationProps, CreateConversationRequest, CreateConversationResponse, CreateMessageRequest,
-    CreateMessageResponse, MessageEventProps, MessageProps, MessagesPageResponse,
+    AssignConversationRequest, CONVERSATION_ASSIGNED_EVENT, CONVERSATION_READ_EVENT,
+    CONVERSATION_UPDATED_EVENT, ConversationEventProps, ConversationProps,
+    ConversationReadEventProps, ConversationsQuery, CreateConversationRequest,
+    CreateConversationResponse, CreateMessageRequest, CreateMessageResponse,
+    MarkConversationReadResponse, MessageAttachmentProps, MessageEventProps, MessagePreviewProps,
+    MessageProps, MessageStatusEventProps, MessageStatusHistoryResponse, MessagesPageResponse,
+    PreviewMessageRequest, RescheduleMessageRequest, ScheduledMessagesResponse,
+    SnoozeConversationRequest, conversation_filter_name, conversation_view_name,
+    parse_conversation_filter, parse_conversation_view, parse_scheduled_at, parse_snoozed_until,
 };
 pub use dashboard::DashboardAnalyticsProps;
 pub use flash::FlashProps;
+pub use message_template::{
+    MessageTemplatePreviewProps, MessageTemplateProps, MessageTemplateRequest,
+};
+pub use organization::{
+    AcceptOrganizationInvitationQuery, ChangeOrganizationMemberRoleRequest,
+    CreateOrganizationRequest, InviteOrganizationMemberRequest, OrganizationInvitationProps,
+    OrganizationMemberProps, OrganizationMembersProps, OrganizationProps,
+    UpdateConversationRoutingRequest,
+};
 pub use phone_number::{CreatePhoneNumberRequest, CreatePhoneNumberResponse, PhoneNumberProps};
+pub use usage::{
+    UsageAlertEventProps, UsageLimitProps, UsageLimitRequest, UsageLimitStatusProps, UsageQuery,
+    UsageReportProps,
+};
+pub use webhook_event::WebhookEventProps;
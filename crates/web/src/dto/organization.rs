@@ -0,0 +1,139 @@
+use application::responses::OrganizationMembers;
+use domain::models::{
+    conversation::ConversationRouting,
+    organization::{Organization, OrganizationInvitation, OrganizationMember, OrganizationRole},
+};
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+/// Body of `POST /organizations`.
+#[derive(Debug, Deserialize)]
+pub struct CreateOrganizationRequest {
+    pub name: String,
+}
+
+/// Body of `POST /organizations/invitations`.
+#[derive(Debug, Deserialize)]
+pub struct InviteOrganizationMemberRequest {
+    pub email: String,
+    /// Defaults to `agent`.
+    #[serde(default = "default_invitation_role")]
+    pub role: OrganizationRole,
+}
+
+fn default_invitation_role() -> OrganizationRole {
+    OrganizationRole::Agent
+}
+
+/// Body of `PUT /organizations/members/{user_id}/role`.
+#[derive(Debug, Deserialize)]
+pub struct ChangeOrganizationMemberRoleRequest {
+    pub role: OrganizationRole,
+}
+
+/// Body of `PUT /organizations/routing`.
+#[derive(Debug, Deserialize)]
+pub struct UpdateConversationRoutingRequest {
+    #[serde(alias = "conversationRouting")]
+    pub conversation_routing: ConversationRouting,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AcceptOrganizationInvitationQuery {
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationProps {
+    pub id: uuid::Uuid,
+    pub name: String,
+    /// Whether this is the organization the session is working in.
+    pub active: bool,
+    pub conversation_routing: ConversationRouting,
+    pub created_at: String,
+}
+
+impl OrganizationProps {
+    pub fn new(organization: &Organization, active_organization_id: uuid::Uuid) -> Self {
+        Self {
+            id: organization.id,
+            name: organization.name.to_owned(),
+            active: organization.id == active_organization_id,
+            conversation_routing: organization.conversation_routing,
+            created_at: format_timestamp(organization.created_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationMemberProps {
+    pub user_id: uuid::Uuid,
+    pub email: Option<String>,
+    pub role: OrganizationRole,
+    pub joined_at: String,
+}
+
+impl From<&OrganizationMember> for OrganizationMemberProps {
+    fn from(value: &OrganizationMember) -> Self {
+        Self {
+            user_id: value.user_id,
+            email: value.email.clone(),
+            role: value.role,
+            joined_at: format_timestamp(value.created_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInvitationProps {
+    pub id: uuid::Uuid,
+    pub email: String,
+    pub role: OrganizationRole,
+    pub invited_by: uuid::Uuid,
+    pub expires_at: String,
+    pub created_at: String,
+}
+
+impl From<&OrganizationInvitation> for OrganizationInvitationProps {
+    fn from(value: &OrganizationInvitation) -> Self {
+        Self {
+            id: value.id,
+            email: value.email.to_owned(),
+            role: value.role,
+            invited_by: value.invited_by,
+            expires_at: format_timestamp(value.expires_at),
+            created_at: format_timestamp(value.created_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationMembersProps {
+    pub members: Vec<OrganizationMemberProps>,
+    pub invitations: Vec<OrganizationInvitationProps>,
+}
+
+impl From<&OrganizationMembers> for OrganizationMembersProps {
+    fn from(value: &OrganizationMembers) -> Self {
+        Self {
+            members: value
+                .members
+                .iter()
+                .map(OrganizationMemberProps::from)
+                .collect(),
+            invitations: value
+                .invitations
+                .iter()
+                .map(OrganizationInvitationProps::from)
+                .collect(),
+        }
+    }
+}
+
+fn format_timestamp(value: OffsetDateTime) -> String {
+    value.format(&Rfc3339).unwrap_or_else(|_| value.to_string())
+}
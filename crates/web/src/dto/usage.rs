@@ -0,0 +1,184 @@
+use application::{
+    commands::SaveUsageLimitCommand,
+    responses::{UsageLimitStatus, UsageReport},
+    usecases::notify_usage_limits_usecase::UsageAlert,
+};
+use domain::models::{
+    money::{format_micros, parse_micros},
+    usage::{UsageLimit, UsagePeriod},
+};
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+pub const USAGE_SOFT_LIMIT_REACHED_EVENT: &str = "usage.soft_limit_reached";
+
+#[derive(Debug, Deserialize)]
+pub struct UsageQuery {
+    pub period: Option<String>,
+}
+
+/// Body of `PUT /usage/limits/{period}`. Amounts are decimal strings such as `25.00`; leaving
+/// both out removes the limit.
+#[derive(Debug, Deserialize)]
+pub struct UsageLimitRequest {
+    #[serde(default, alias = "softLimit")]
+    pub soft_limit: Option<String>,
+    #[serde(default, alias = "hardLimit")]
+    pub hard_limit: Option<String>,
+}
+
+impl UsageLimitRequest {
+    pub fn into_command(
+        self,
+        user_id: uuid::Uuid,
+        period: UsagePeriod,
+    ) -> Result<SaveUsageLimitCommand, String> {
+        Ok(SaveUsageLimitCommand {
+            user_id,
+            period,
+            soft_limit_micros: parse_amount(self.soft_limit.as_deref(), "softLimit")?,
+            hard_limit_micros: parse_amount(self.hard_limit.as_deref(), "hardLimit")?,
+        })
+    }
+}
+
+fn parse_amount(value: Option<&str>, field: &str) -> Result<Option<i64>, String> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        None => Ok(None),
+        Some(value) => parse_micros(value)
+            .map(Some)
+            .ok_or_else(|| format!("{} must be an amount like 25.00, got {}", field, value)),
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageTotalsProps {
+    pub period_start: String,
+    /// Set on per-number rollups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number_id: Option<uuid::Uuid>,
+    pub messages: i64,
+    pub segments: i64,
+    pub cost: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageLimitProps {
+    pub period: &'static str,
+    pub soft_limit: Option<String>,
+    pub hard_limit: Option<String>,
+    pub updated_at: String,
+}
+
+impl From<&UsageLimit> for UsageLimitProps {
+    fn from(value: &UsageLimit) -> Self {
+        Self {
+            period: value.period.name(),
+            soft_limit: value.soft_limit_micros.map(format_micros),
+            hard_limit: value.hard_limit_micros.map(format_micros),
+            updated_at: format_timestamp(value.updated_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageLimitStatusProps {
+    #[serde(flatten)]
+    pub limit: UsageLimitProps,
+    /// Spend so far in the current period.
+    pub spent: String,
+    pub soft_limit_reached: bool,
+    pub hard_limit_reached: bool,
+    pub resets_at: String,
+}
+
+impl From<&UsageLimitStatus> for UsageLimitStatusProps {
+    fn from(value: &UsageLimitStatus) -> Self {
+        Self {
+            limit: UsageLimitProps::from(&value.limit),
+            spent: format_micros(value.spent_micros),
+            soft_limit_reached: value.limit.soft_limit_reached(value.spent_micros),
+            hard_limit_reached: value.limit.hard_limit_reached(value.spent_micros),
+            resets_at: format_timestamp(value.resets_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageReportProps {
+    pub period: &'static str,
+    /// Usage per period summed over every phone number, oldest first.
+    pub totals: Vec<UsageTotalsProps>,
+    /// Usage per period and phone number, oldest first.
+    pub rollups: Vec<UsageTotalsProps>,
+    pub limits: Vec<UsageLimitStatusProps>,
+}
+
+impl From<&UsageReport> for UsageReportProps {
+    fn from(value: &UsageReport) -> Self {
+        Self {
+            period: value.period.name(),
+            totals: value
+                .totals
+                .iter()
+                .map(|totals| UsageTotalsProps {
+                    period_start: format_timestamp(totals.period_start),
+                    phone_number_id: None,
+                    messages: totals.totals.messages,
+                    segments: totals.totals.segments,
+                    cost: format_micros(totals.totals.cost_micros),
+                })
+                .collect(),
+            rollups: value
+                .rollups
+                .iter()
+                .map(|rollup| UsageTotalsProps {
+                    period_start: format_timestamp(rollup.period_start),
+                    phone_number_id: Some(rollup.phone_number_id),
+                    messages: rollup.totals.messages,
+                    segments: rollup.totals.segments,
+                    cost: format_micros(rollup.totals.cost_micros),
+                })
+                .collect(),
+            limits: value
+                .limits
+                .iter()
+                .map(UsageLimitStatusProps::from)
+                .collect(),
+        }
+    }
+}
+
+/// Pushed over `/events/messages` when spend crosses a soft limit.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageAlertEventProps {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub period: &'static str,
+    pub period_start: String,
+    pub resets_at: String,
+    pub soft_limit: String,
+    pub spent: String,
+}
+
+impl From<&UsageAlert> for UsageAlertEventProps {
+    fn from(value: &UsageAlert) -> Self {
+        Self {
+            event_type: USAGE_SOFT_LIMIT_REACHED_EVENT.to_owned(),
+            period: value.period.name(),
+            period_start: format_timestamp(value.period_start),
+            resets_at: format_timestamp(value.resets_at),
+            soft_limit: format_micros(value.soft_limit_micros),
+            spent: format_micros(value.spent_micros),
+        }
+    }
+}
+
+fn format_timestamp(at: OffsetDateTime) -> String {
+    at.format(&Rfc3339).unwrap_or_else(|_| at.to_string())
+}
@@ -0,0 +1,57 @@
+use domain::models::processed_webhook_event::{ProcessedWebhookEvent, WebhookEventStatus};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEventProps {
+    pub event_id: String,
+    pub event_type: String,
+    pub provider_message_id: Option<String>,
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub occurred_at: String,
+    pub next_attempt_at: String,
+    pub processed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub payload: serde_json::Value,
+}
+
+impl From<&ProcessedWebhookEvent> for WebhookEventProps {
+    fn from(value: &ProcessedWebhookEvent) -> Self {
+        Self {
+            event_id: value.event_id.to_owned(),
+            event_type: value.event_type.to_owned(),
+            provider_message_id: value.provider_message_id.to_owned(),
+            status: webhook_event_status_name(value.status).to_owned(),
+            attempts: value.attempts,
+            last_error: value.last_error.to_owned(),
+            occurred_at: value.occurred_at.to_string(),
+            next_attempt_at: value.next_attempt_at.to_string(),
+            processed_at: value.processed_at.map(|value| value.to_string()),
+            created_at: value.created_at.to_string(),
+            updated_at: value.updated_at.to_string(),
+            payload: value.payload_json.to_owned(),
+        }
+    }
+}
+
+pub fn webhook_event_status_name(status: WebhookEventStatus) -> &'static str {
+    match status {
+        WebhookEventStatus::Pending => "pending",
+        WebhookEventStatus::Processed => "processed",
+        WebhookEventStatus::Parked => "parked",
+        WebhookEventStatus::DeadLettered => "dead_lettered",
+    }
+}
+
+pub fn parse_webhook_event_status(value: &str) -> Option<WebhookEventStatus> {
+    match value {
+        "pending" => Some(WebhookEventStatus::Pending),
+        "processed" => Some(WebhookEventStatus::Processed),
+        "parked" => Some(WebhookEventStatus::Parked),
+        "dead_lettered" => Some(WebhookEventStatus::DeadLettered),
+        _ => None,
+    }
+}
@@ -17,10 +17,9 @@ pub fn extract_flash(session: &Session) -> Option<FlashProps> {
         .get::<FlashProps>(FLASH_KEY)
         .ok()
         .flatten()
-        .and_then(|flash| {
+        .inspect(|_| {
             // Clear the flash after reading it
             let _ = session.remove(FLASH_KEY);
-            Some(flash)
         })
 }
 
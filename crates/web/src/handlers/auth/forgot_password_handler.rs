@@ -1,13 +1,108 @@
-use actix_web::{HttpRequest, Responder};
+use std::sync::Arc;
 
-use crate::{Empty, inertia::Page};
+use actix_inertia::inertia_responder::InertiaResponder;
+use actix_session::Session;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use application::usecases::{UsecaseError, forgot_password_usecase::ForgotPasswordUsecase};
+use domain::repositories::reset_password_repository::ResetPasswordRepository;
+use domain::repositories::user_repository::UserRepository;
+use domain::traits::email_sender::EmailSender;
+use domain::traits::token_service::TokenService;
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::auth::{FlashProps, ForgotPasswordErrorProps, ForgotPasswordRequest},
+    flash::{extract_flash, set_flash},
+    inertia::Page,
+    types::PublicBaseUrl,
+};
+
+#[derive(Debug, Serialize)]
+struct ForgotPasswordPageProps {
+    pub errors: Option<ForgotPasswordErrorProps>,
+    pub flash: Option<FlashProps>,
+}
 
 /// Render forgot password page - GET /forgot-password
-pub async fn render_forgot_password(req: HttpRequest) -> impl Responder {
+pub async fn render_forgot_password(req: HttpRequest, session: Session) -> impl Responder {
+    let flash = extract_flash(&session);
+
     Page::builder()
         .req(req)
         .name("ForgotPassword")
-        .props(Empty)
+        .props(ForgotPasswordPageProps {
+            errors: None,
+            flash,
+        })
         .build()
         .to_responder()
 }
+
+/// Process forgot password form - POST /auth/forgot-password
+/// Inertia.js form flow:
+/// - Success: Set flash message and redirect to /auth/login, whether or not the email exists
+/// - Failure: Return ForgotPassword page with props.errors populated
+pub async fn handle_forgot_password(
+    req: HttpRequest,
+    forgot_password_req: web::Json<ForgotPasswordRequest>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    reset_password_repository: web::Data<Arc<dyn ResetPasswordRepository>>,
+    token_service: web::Data<Arc<dyn TokenService>>,
+    email_sender: web::Data<Arc<dyn EmailSender>>,
+    public_base_url: web::Data<PublicBaseUrl>,
+    session: Session,
+) -> impl Responder {
+    let cmd = forgot_password_req.into_inner().into();
+    let forgot_password_usecase = ForgotPasswordUsecase::builder()
+        .user_repository(user_repository.get_ref().clone())
+        .reset_password_repository(reset_password_repository.get_ref().clone())
+        .token_service(token_service.get_ref().clone())
+        .email_sender(email_sender.get_ref().clone())
+        .public_base_url(public_base_url.0.clone())
+        .build();
+
+    match forgot_password_usecase.execute(cmd).await {
+        Ok(()) => {
+            set_flash(
+                &session,
+                FlashProps::success(
+                    "If an account exists for that email, a password reset link is on its way.",
+                ),
+            );
+            HttpResponse::Found()
+                .append_header((actix_web::http::header::LOCATION, "/auth/login"))
+                .finish()
+        }
+        Err(e) => {
+            let errors = match e {
+                UsecaseError::Validation(report) => ForgotPasswordErrorProps {
+                    email: report
+                        .iter()
+                        .next()
+                        .map(|(_, err)| err.message().to_string()),
+                    general: None,
+                },
+                err => {
+                    error!("failed to issue password reset: {}", err);
+                    ForgotPasswordErrorProps {
+                        email: None,
+                        general: Some(
+                            "We could not send the reset email right now. Please try again."
+                                .to_string(),
+                        ),
+                    }
+                }
+            };
+
+            InertiaResponder::new(
+                "ForgotPassword",
+                ForgotPasswordPageProps {
+                    errors: Some(errors),
+                    flash: None,
+                },
+            )
+            .respond_to(&req)
+        }
+    }
+}
@@ -3,16 +3,18 @@ pub mod login_handler;
 pub mod logout_handler;
 pub mod reset_password_handler;
 pub mod signup_handler;
+pub mod verify_email_handler;
 
 use actix_web::{dev::HttpServiceFactory, web};
 
 use crate::{
     handlers::auth::{
-        forgot_password_handler::render_forgot_password,
+        forgot_password_handler::{handle_forgot_password, render_forgot_password},
         login_handler::{handle_login, render_login},
         logout_handler::handle_logout,
-        reset_password_handler::render_reset_password,
+        reset_password_handler::{handle_reset_password, render_reset_password},
         signup_handler::{handle_signup, render_signup},
+        verify_email_handler::{handle_resend_verification_email, handle_verify_email},
     },
     middlewares::auth::{GuestMiddleware, ProtectedMiddleware},
 };
@@ -34,12 +36,20 @@ pub fn build_auth_service() -> impl HttpServiceFactory {
         .service(
             web::resource("/forgot-password")
                 .wrap(GuestMiddleware::new())
-                .route(web::get().to(render_forgot_password)),
+                .route(web::get().to(render_forgot_password))
+                .route(web::post().to(handle_forgot_password)),
         )
         .service(
             web::resource("/reset-password")
                 .wrap(GuestMiddleware::new())
-                .route(web::get().to(render_reset_password)),
+                .route(web::get().to(render_reset_password))
+                .route(web::post().to(handle_reset_password)),
+        )
+        .service(web::resource("/verify-email").route(web::get().to(handle_verify_email)))
+        .service(
+            web::resource("/verify-email/resend")
+                .wrap(ProtectedMiddleware::new())
+                .route(web::post().to(handle_resend_verification_email)),
         )
         .service(
             web::resource("/logout")
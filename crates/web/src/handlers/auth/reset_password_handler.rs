@@ -1,13 +1,128 @@
-use actix_web::{HttpRequest, Responder};
+use std::sync::Arc;
 
-use crate::{Empty, inertia::Page};
+use actix_inertia::inertia_responder::InertiaResponder;
+use actix_session::Session;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use application::usecases::{UsecaseError, reset_password_usecase::ResetPasswordUsecase};
+use domain::repositories::reset_password_repository::ResetPasswordRepository;
+use domain::repositories::user_repository::UserRepository;
+use domain::traits::password_hasher::PasswordHasher;
+use domain::traits::token_service::TokenService;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+use crate::{
+    dto::auth::{FlashProps, ResetPasswordErrorProps, ResetPasswordRequest},
+    flash::{extract_flash, set_flash},
+    inertia::Page,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordQuery {
+    token: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResetPasswordPageProps {
+    pub token: Option<String>,
+    pub errors: Option<ResetPasswordErrorProps>,
+    pub flash: Option<FlashProps>,
+}
+
+/// Render reset password page - GET /reset-password?token=
+pub async fn render_reset_password(
+    req: HttpRequest,
+    query: web::Query<ResetPasswordQuery>,
+    session: Session,
+) -> impl Responder {
+    let flash = extract_flash(&session);
 
-/// Render reset password page - GET /reset-password
-pub async fn render_reset_password(req: HttpRequest) -> impl Responder {
     Page::builder()
         .req(req)
         .name("ResetPassword")
-        .props(Empty)
+        .props(ResetPasswordPageProps {
+            token: query.into_inner().token,
+            errors: None,
+            flash,
+        })
         .build()
         .to_responder()
 }
+
+/// Process reset password form - POST /auth/reset-password
+/// Inertia.js form flow:
+/// - Success: Set flash message and redirect to /auth/login
+/// - Failure: Return ResetPassword page with props.errors populated
+pub async fn handle_reset_password(
+    req: HttpRequest,
+    reset_password_req: web::Json<ResetPasswordRequest>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    reset_password_repository: web::Data<Arc<dyn ResetPasswordRepository>>,
+    password_hasher: web::Data<Arc<dyn PasswordHasher>>,
+    token_service: web::Data<Arc<dyn TokenService>>,
+    session: Session,
+) -> impl Responder {
+    let reset_password_req = reset_password_req.into_inner();
+    let token = reset_password_req.token.clone();
+    let reset_password_usecase = ResetPasswordUsecase::builder()
+        .user_repository(user_repository.get_ref().clone())
+        .reset_password_repository(reset_password_repository.get_ref().clone())
+        .password_hasher(password_hasher.get_ref().clone())
+        .token_service(token_service.get_ref().clone())
+        .build();
+
+    match reset_password_usecase
+        .execute(reset_password_req.into())
+        .await
+    {
+        Ok(()) => {
+            set_flash(
+                &session,
+                FlashProps::success("Your password has been reset. Please log in."),
+            );
+            HttpResponse::Found()
+                .append_header((actix_web::http::header::LOCATION, "/auth/login"))
+                .finish()
+        }
+        Err(e) => {
+            let errors = match e {
+                UsecaseError::Validation(report) => {
+                    let mut error_props = ResetPasswordErrorProps {
+                        password: None,
+                        general: None,
+                    };
+                    for (path, err) in report.iter() {
+                        if path.to_string().contains("token") {
+                            error_props.general =
+                                Some(UsecaseError::InvalidResetToken.to_http_message());
+                        } else {
+                            error_props.password = Some(err.message().to_string());
+                        }
+                    }
+                    error_props
+                }
+                UsecaseError::InvalidResetToken => ResetPasswordErrorProps {
+                    password: None,
+                    general: Some(e.to_http_message()),
+                },
+                err => {
+                    error!("failed to reset password: {}", err);
+                    ResetPasswordErrorProps {
+                        password: None,
+                        general: Some("An error occurred. Please try again.".to_string()),
+                    }
+                }
+            };
+
+            InertiaResponder::new(
+                "ResetPassword",
+                ResetPasswordPageProps {
+                    token: Some(token),
+                    errors: Some(errors),
+                    flash: None,
+                },
+            )
+            .respond_to(&req)
+        }
+    }
+}
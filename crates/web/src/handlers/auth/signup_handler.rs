@@ -9,10 +9,15 @@ use crate::flash::{extract_flash, set_flash};
 use crate::{
     dto::auth::{FlashProps, SignupErrorProps, SignupRequest},
     inertia::Page,
+    types::PublicBaseUrl,
 };
 use application::usecases::create_user_usecase::CreateUserUsecase;
+use application::usecases::send_email_verification_usecase::SendEmailVerificationUsecase;
 use domain::repositories::user_repository::UserRepository;
+use domain::traits::email_sender::EmailSender;
 use domain::traits::password_hasher::PasswordHasher;
+use domain::traits::token_service::TokenService;
+use tracing::error;
 
 #[derive(Debug, Serialize)]
 struct SignupPageProps {
@@ -36,13 +41,16 @@ pub async fn render_signup(req: HttpRequest, session: Session) -> impl Responder
 
 /// Process signup form - POST /auth/signup
 /// Inertia.js form flow:
-/// - Success: Set flash message and redirect to /auth/login
+/// - Success: Send the verification email, set flash message and redirect to /auth/login
 /// - Failure: Return Signup page with props.errors populated
 pub async fn handle_signup(
     req: HttpRequest,
     signup_req: web::Json<SignupRequest>,
     user_repository: web::Data<Arc<dyn UserRepository>>,
     password_hasher: web::Data<Arc<dyn PasswordHasher>>,
+    token_service: web::Data<Arc<dyn TokenService>>,
+    email_sender: web::Data<Arc<dyn EmailSender>>,
+    public_base_url: web::Data<PublicBaseUrl>,
     session: Session,
 ) -> impl Responder {
     let cmd = signup_req.into_inner().into();
@@ -52,11 +60,27 @@ pub async fn handle_signup(
         .build();
 
     match create_user.execute(cmd).await {
-        Ok(_result) => {
+        Ok(result) => {
+            // A failed send is not fatal: the user can request another link after logging in.
+            let send_email_verification = SendEmailVerificationUsecase::builder()
+                .user_repository(user_repository.get_ref().clone())
+                .token_service(token_service.get_ref().clone())
+                .email_sender(email_sender.get_ref().clone())
+                .public_base_url(public_base_url.0.clone())
+                .build();
+            if let Err(err) = send_email_verification.execute(result.id).await {
+                error!(
+                    "failed to send verification email for user {}: {}",
+                    result.id, err
+                );
+            }
+
             // Set flash message and redirect to login
             set_flash(
                 &session,
-                FlashProps::success("Your account has been created successfully. Please log in."),
+                FlashProps::success(
+                    "Your account has been created. Check your email to verify your address, then log in.",
+                ),
             );
             HttpResponse::Found()
                 .append_header((actix_web::http::header::LOCATION, "/auth/login"))
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::{
+    UsecaseError, send_email_verification_usecase::SendEmailVerificationUsecase,
+    verify_email_usecase::VerifyEmailUsecase,
+};
+use domain::repositories::user_repository::UserRepository;
+use domain::traits::email_sender::EmailSender;
+use domain::traits::token_service::TokenService;
+use serde::Deserialize;
+use tracing::error;
+
+use crate::{
+    dto::auth::FlashProps,
+    flash::set_flash,
+    session::{is_authenticated, session_user_id},
+    types::PublicBaseUrl,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyEmailQuery {
+    token: Option<String>,
+}
+
+/// Consume an email verification link - GET /auth/verify-email?token=
+/// Works for guests and logged-in users alike; the outcome is reported through a flash message.
+pub async fn handle_verify_email(
+    query: web::Query<VerifyEmailQuery>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    token_service: web::Data<Arc<dyn TokenService>>,
+    session: Session,
+) -> impl Responder {
+    let verify_email_usecase = VerifyEmailUsecase::builder()
+        .user_repository(user_repository.get_ref().clone())
+        .token_service(token_service.get_ref().clone())
+        .build();
+
+    let flash = match query.into_inner().token {
+        Some(token) => match verify_email_usecase.execute(token).await {
+            Ok(()) => FlashProps::success("Your email address has been verified."),
+            Err(err @ UsecaseError::InvalidVerificationToken) => {
+                FlashProps::error(err.to_http_message())
+            }
+            Err(err) => {
+                error!("failed to verify email address: {}", err);
+                FlashProps::error("We could not verify your email right now. Please try again.")
+            }
+        },
+        None => FlashProps::error(UsecaseError::InvalidVerificationToken.to_http_message()),
+    };
+
+    set_flash(&session, flash);
+
+    let location = if is_authenticated(&session) {
+        "/"
+    } else {
+        "/auth/login"
+    };
+
+    HttpResponse::Found()
+        .append_header((actix_web::http::header::LOCATION, location))
+        .finish()
+}
+
+/// Send a fresh verification email to the logged-in user - POST /auth/verify-email/resend
+pub async fn handle_resend_verification_email(
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    token_service: web::Data<Arc<dyn TokenService>>,
+    email_sender: web::Data<Arc<dyn EmailSender>>,
+    public_base_url: web::Data<PublicBaseUrl>,
+    session: Session,
+) -> impl Responder {
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let send_email_verification_usecase = SendEmailVerificationUsecase::builder()
+        .user_repository(user_repository.get_ref().clone())
+        .token_service(token_service.get_ref().clone())
+        .email_sender(email_sender.get_ref().clone())
+        .public_base_url(public_base_url.0.clone())
+        .build();
+
+    let flash = match send_email_verification_usecase.execute(user_id).await {
+        Ok(()) => FlashProps::success("A new verification link is on its way to your inbox."),
+        Err(err) => {
+            error!(
+                "failed to resend verification email for user {}: {}",
+                user_id, err
+            );
+            FlashProps::error("We could not send the verification email. Please try again.")
+        }
+    };
+
+    set_flash(&session, flash);
+
+    HttpResponse::Found()
+        .append_header((actix_web::http::header::LOCATION, "/"))
+        .finish()
+}
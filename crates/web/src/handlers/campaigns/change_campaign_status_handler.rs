@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::ChangeCampaignStatusCommand;
+use application::usecases::UsecaseError;
+use application::usecases::change_campaign_status_usecase::ChangeCampaignStatusUsecase;
+use domain::{
+    models::campaign::CampaignStatus,
+    repositories::{
+        campaign_repository::CampaignRepository, organization_repository::OrganizationRepository,
+    },
+};
+use tracing::error;
+
+use crate::{dto::CampaignProps, handlers::campaigns::ErrorResponse, membership::CurrentMember};
+
+pub async fn handle_pause_campaign(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    campaign_repository: web::Data<Arc<dyn CampaignRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    change_campaign_status(
+        path.into_inner(),
+        CampaignStatus::Paused,
+        member,
+        campaign_repository,
+        organization_repository,
+    )
+    .await
+}
+
+pub async fn handle_resume_campaign(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    campaign_repository: web::Data<Arc<dyn CampaignRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    change_campaign_status(
+        path.into_inner(),
+        CampaignStatus::Running,
+        member,
+        campaign_repository,
+        organization_repository,
+    )
+    .await
+}
+
+pub async fn handle_cancel_campaign(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    campaign_repository: web::Data<Arc<dyn CampaignRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    change_campaign_status(
+        path.into_inner(),
+        CampaignStatus::Cancelled,
+        member,
+        campaign_repository,
+        organization_repository,
+    )
+    .await
+}
+
+async fn change_campaign_status(
+    campaign_id: uuid::Uuid,
+    status: CampaignStatus,
+    member: CurrentMember,
+    campaign_repository: web::Data<Arc<dyn CampaignRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> HttpResponse {
+    let change_campaign_status_usecase = ChangeCampaignStatusUsecase::builder()
+        .campaign_repository(campaign_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = ChangeCampaignStatusCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        campaign_id,
+        status,
+    };
+
+    match change_campaign_status_usecase.execute(cmd).await {
+        Ok(result) => HttpResponse::Ok().json(CampaignProps::from(&result)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err @ UsecaseError::CampaignStatusConflict { .. }) => {
+            HttpResponse::Conflict().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err) => {
+            error!(
+                "failed to change status of campaign {} for user {}: {}",
+                campaign_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
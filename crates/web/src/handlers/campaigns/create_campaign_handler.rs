@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::CreateCampaignCommand;
+use application::usecases::UsecaseError;
+use application::usecases::create_campaign_usecase::CreateCampaignUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::{
+    campaign_repository::CampaignRepository, contact_repository::ContactRepository,
+    phone_number_repository::PhoneNumberRepository,
+};
+use tracing::error;
+
+use crate::{
+    dto::{CampaignProps, CreateCampaignRequest},
+    handlers::campaigns::ErrorResponse,
+    membership::CurrentMember,
+};
+
+pub async fn handle_create_campaign(
+    create_req: web::Json<CreateCampaignRequest>,
+    member: CurrentMember,
+    campaign_repository: web::Data<Arc<dyn CampaignRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let create_campaign_usecase = CreateCampaignUsecase::builder()
+        .campaign_repository(campaign_repository.get_ref().clone())
+        .contact_repository(contact_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let create_req = create_req.into_inner();
+    let cmd = CreateCampaignCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        phone_number_id: create_req.phone_number_id,
+        name: create_req.name,
+        body: create_req.body,
+        tag: create_req.tag,
+        contact_ids: create_req.contact_ids,
+        phone_numbers: create_req.phone_numbers,
+        messages_per_second: create_req.messages_per_second,
+    };
+
+    match create_campaign_usecase.execute(cmd).await {
+        Ok(result) => HttpResponse::Created().json(CampaignProps::from(&result)),
+        Err(err) => {
+            error!(
+                "failed to create campaign for user {}: {}",
+                member.user_id, err
+            );
+
+            match err {
+                UsecaseError::Validation(_) | UsecaseError::EntityNotFound => {
+                    HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                        error: err.to_http_message(),
+                    })
+                }
+                UsecaseError::Forbidden(_) => HttpResponse::Forbidden().json(ErrorResponse {
+                    error: err.to_http_message(),
+                }),
+                _ => HttpResponse::InternalServerError().json(ErrorResponse {
+                    error: "Unable to create campaign right now.".to_owned(),
+                }),
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::get_campaign_usecase::GetCampaignUsecase;
+use domain::repositories::campaign_repository::CampaignRepository;
+use tracing::error;
+
+use crate::dto::CampaignProps;
+use crate::membership::CurrentMember;
+
+pub async fn handle_get_campaign(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    campaign_repository: web::Data<Arc<dyn CampaignRepository>>,
+) -> impl Responder {
+    let campaign_id = path.into_inner();
+
+    let get_campaign_usecase = GetCampaignUsecase::builder()
+        .campaign_repository(campaign_repository.get_ref().clone())
+        .build();
+
+    match get_campaign_usecase
+        .execute(member.organization_id, campaign_id)
+        .await
+    {
+        Ok(result) => HttpResponse::Ok().json(CampaignProps::from(&result)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to get campaign {} for organization {}: {}",
+                campaign_id, member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
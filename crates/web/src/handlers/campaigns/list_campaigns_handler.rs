@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::list_campaigns_usecase::ListCampaignsUsecase;
+use domain::repositories::campaign_repository::CampaignRepository;
+use tracing::error;
+
+use crate::{dto::CampaignProps, membership::CurrentMember};
+
+pub async fn handle_list_campaigns(
+    member: CurrentMember,
+    campaign_repository: web::Data<Arc<dyn CampaignRepository>>,
+) -> impl Responder {
+    let list_campaigns_usecase = ListCampaignsUsecase::builder()
+        .campaign_repository(campaign_repository.get_ref().clone())
+        .build();
+
+    match list_campaigns_usecase.execute(member.organization_id).await {
+        Ok(campaigns) => HttpResponse::Ok().json(
+            campaigns
+                .iter()
+                .map(CampaignProps::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) => {
+            error!(
+                "failed to list campaigns for organization {}: {}",
+                member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -0,0 +1,33 @@
+pub mod change_campaign_status_handler;
+pub mod create_campaign_handler;
+pub mod get_campaign_handler;
+pub mod list_campaigns_handler;
+
+use actix_web::{dev::HttpServiceFactory, web};
+use serde::Serialize;
+
+use crate::handlers::campaigns::{
+    change_campaign_status_handler::{
+        handle_cancel_campaign, handle_pause_campaign, handle_resume_campaign,
+    },
+    create_campaign_handler::handle_create_campaign,
+    get_campaign_handler::handle_get_campaign,
+    list_campaigns_handler::handle_list_campaigns,
+};
+use crate::middlewares::auth::ProtectedMiddleware;
+
+pub fn build_campaigns_service() -> impl HttpServiceFactory {
+    web::scope("/campaigns")
+        .wrap(ProtectedMiddleware::new())
+        .route("", web::get().to(handle_list_campaigns))
+        .route("", web::post().to(handle_create_campaign))
+        .route("/{id}", web::get().to(handle_get_campaign))
+        .route("/{id}/pause", web::post().to(handle_pause_campaign))
+        .route("/{id}/resume", web::post().to(handle_resume_campaign))
+        .route("/{id}/cancel", web::post().to(handle_cancel_campaign))
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::CreateContactCommand;
+use application::usecases::create_contact_usecase::CreateContactUsecase;
+use domain::repositories::{
+    contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+};
+use tracing::error;
+
+use crate::{
+    dto::{ContactProps, ContactRequest},
+    handlers::contacts::contact_save_error,
+    membership::CurrentMember,
+};
+
+pub async fn handle_create_contact(
+    create_req: web::Json<ContactRequest>,
+    member: CurrentMember,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let create_contact_usecase = CreateContactUsecase::builder()
+        .contact_repository(contact_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = CreateContactCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        details: create_req.into_inner().into(),
+    };
+
+    match create_contact_usecase.execute(cmd).await {
+        Ok(result) => HttpResponse::Created().json(ContactProps::from(&result.contact)),
+        Err(err) => {
+            error!(
+                "failed to create contact for user {}: {}",
+                member.user_id, err
+            );
+            contact_save_error(&err)
+        }
+    }
+}
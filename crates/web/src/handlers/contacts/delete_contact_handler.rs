@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::delete_contact_usecase::DeleteContactUsecase;
+use domain::repositories::{
+    contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::membership::CurrentMember;
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+pub async fn handle_delete_contact(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let contact_id = path.into_inner();
+
+    let delete_contact_usecase = DeleteContactUsecase::builder()
+        .contact_repository(contact_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    match delete_contact_usecase
+        .execute(member.user_id, member.organization_id, contact_id)
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to delete contact {} for user {}: {}",
+                contact_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
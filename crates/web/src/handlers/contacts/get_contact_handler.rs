@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::get_contact_usecase::GetContactUsecase;
+use domain::repositories::contact_repository::ContactRepository;
+use tracing::error;
+
+use crate::dto::ContactProps;
+use crate::membership::CurrentMember;
+
+pub async fn handle_get_contact(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+) -> impl Responder {
+    let contact_id = path.into_inner();
+
+    let get_contact_usecase = GetContactUsecase::builder()
+        .contact_repository(contact_repository.get_ref().clone())
+        .build();
+
+    match get_contact_usecase
+        .execute(member.organization_id, contact_id)
+        .await
+    {
+        Ok(contact) => HttpResponse::Ok().json(ContactProps::from(&contact)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to get contact {} for user {}: {}",
+                contact_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::get_contact_import_usecase::GetContactImportUsecase;
+use domain::repositories::contact_import_repository::ContactImportRepository;
+use tracing::error;
+
+use crate::dto::ContactImportProps;
+use crate::membership::CurrentMember;
+
+pub async fn handle_get_contact_import(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    contact_import_repository: web::Data<Arc<dyn ContactImportRepository>>,
+) -> impl Responder {
+    let import_id = path.into_inner();
+
+    let get_contact_import_usecase = GetContactImportUsecase::builder()
+        .contact_import_repository(contact_import_repository.get_ref().clone())
+        .build();
+
+    match get_contact_import_usecase
+        .execute(member.organization_id, import_id)
+        .await
+    {
+        Ok(import) => HttpResponse::Ok().json(ContactImportProps::from(&import)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to get contact import {} for user {}: {}",
+                import_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
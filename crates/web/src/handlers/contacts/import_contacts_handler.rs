@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use actix_multipart::Multipart;
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::ImportContactsCommand;
+use application::usecases::UsecaseError;
+use application::usecases::import_contacts_usecase::{
+    ImportContactsUsecase, MAX_CONTACT_IMPORT_BYTES,
+};
+use domain::{
+    models::contact_import::{
+        ContactImportColumnMapping, ContactImportFormat, ContactImportStatus,
+    },
+    repositories::{
+        contact_import_repository::ContactImportRepository, contact_repository::ContactRepository,
+        organization_repository::OrganizationRepository,
+    },
+};
+use futures_util::StreamExt;
+use tracing::error;
+
+use crate::{
+    dto::ContactImportProps, handlers::contacts::ErrorResponse, membership::CurrentMember,
+};
+
+const MAX_FIELD_BYTES: usize = 1024;
+
+/// Accepts a `multipart/form-data` body with a `file` part holding a CSV or vCard file.
+/// Optional text fields: `format` (`csv` or `vcard`), `name_column`, `phone_columns`
+/// (comma separated), `email_column`, `notes_column` and `default_country_code`.
+///
+/// Answers `201` with the report when the file was imported right away, or `202` when it
+/// was queued; poll `GET /contacts/imports/{id}` for the report then.
+pub async fn handle_import_contacts(
+    payload: Multipart,
+    member: CurrentMember,
+    contact_import_repository: web::Data<Arc<dyn ContactImportRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let form = match read_import_form(payload).await {
+        Ok(form) => form,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
+    };
+    let Some((file_name, content)) = form.file else {
+        return HttpResponse::UnprocessableEntity().json(ErrorResponse {
+            error: "Choose a CSV or vCard file to import".to_owned(),
+        });
+    };
+
+    let import_contacts_usecase = ImportContactsUsecase::builder()
+        .contact_import_repository(contact_import_repository.get_ref().clone())
+        .contact_repository(contact_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = ImportContactsCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        file_name,
+        content,
+        format: form.format,
+        column_mapping: form.column_mapping,
+        default_country_code: form.default_country_code,
+    };
+
+    match import_contacts_usecase.execute(cmd).await {
+        Ok(import) => {
+            let props = ContactImportProps::from(&import);
+            if import.status == ContactImportStatus::Pending {
+                HttpResponse::Accepted().json(props)
+            } else {
+                HttpResponse::Created().json(props)
+            }
+        }
+        Err(err) => {
+            error!(
+                "failed to import contacts for user {}: {}",
+                member.user_id, err
+            );
+
+            match err {
+                UsecaseError::Validation(_) => {
+                    HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                        error: err.to_http_message(),
+                    })
+                }
+                UsecaseError::Forbidden(_) => HttpResponse::Forbidden().json(ErrorResponse {
+                    error: err.to_http_message(),
+                }),
+                _ => HttpResponse::InternalServerError().json(ErrorResponse {
+                    error: "Unable to import contacts right now.".to_owned(),
+                }),
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ImportForm {
+    file: Option<(String, String)>,
+    format: Option<ContactImportFormat>,
+    column_mapping: ContactImportColumnMapping,
+    default_country_code: Option<String>,
+}
+
+async fn read_import_form(mut payload: Multipart) -> Result<ImportForm, String> {
+    let mut form = ImportForm::default();
+
+    while let Some(field) = payload.next().await {
+        let mut field = field.map_err(|err| format!("Invalid upload: {}", err))?;
+        let field_name = field.name().unwrap_or_default().to_owned();
+
+        let limit = if field_name == "file" {
+            MAX_CONTACT_IMPORT_BYTES
+        } else {
+            MAX_FIELD_BYTES
+        };
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk.map_err(|err| format!("Invalid upload: {}", err))?;
+            if bytes.len() + chunk.len() > limit {
+                return Err(if field_name == "file" {
+                    "Contact files are limited to 5 MB".to_owned()
+                } else {
+                    format!("The {} field is too long", field_name)
+                });
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+
+        let value = String::from_utf8(bytes).map_err(|_| {
+            if field_name == "file" {
+                "Contact files must be UTF-8 encoded".to_owned()
+            } else {
+                format!("The {} field must be valid UTF-8", field_name)
+            }
+        })?;
+        let text = Some(value.trim().to_owned()).filter(|value| !value.is_empty());
+
+        match field_name.as_str() {
+            "file" => {
+                let file_name = field
+                    .content_disposition()
+                    .and_then(|disposition| disposition.get_filename())
+                    .map(|name| name.rsplit(['/', '\\']).next().unwrap_or_default().trim())
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or("contacts")
+                    .chars()
+                    .filter(|c| !c.is_control())
+                    .take(255)
+                    .collect();
+                form.file = Some((file_name, value));
+            }
+            "format" => {
+                form.format = match text.as_deref().map(str::to_ascii_lowercase).as_deref() {
+                    None => None,
+                    Some("csv") => Some(ContactImportFormat::Csv),
+                    Some("vcard" | "vcf") => Some(ContactImportFormat::VCard),
+                    Some(_) => return Err("Format must be csv or vcard".to_owned()),
+                };
+            }
+            "name_column" => form.column_mapping.name = text,
+            "phone_columns" => {
+                form.column_mapping.phone_numbers = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|column| !column.is_empty())
+                    .map(str::to_owned)
+                    .collect();
+            }
+            "email_column" => form.column_mapping.email = text,
+            "notes_column" => form.column_mapping.notes = text,
+            "default_country_code" => form.default_country_code = text,
+            _ => {}
+        }
+    }
+
+    Ok(form)
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::list_contacts_usecase::ListContactsUsecase;
+use domain::repositories::contact_repository::ContactRepository;
+use tracing::error;
+
+use crate::{dto::ContactProps, membership::CurrentMember};
+
+pub async fn handle_list_contacts(
+    member: CurrentMember,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+) -> impl Responder {
+    let list_contacts_usecase = ListContactsUsecase::builder()
+        .contact_repository(contact_repository.get_ref().clone())
+        .build();
+
+    match list_contacts_usecase.execute(member.organization_id).await {
+        Ok(contacts) => {
+            HttpResponse::Ok().json(contacts.iter().map(ContactProps::from).collect::<Vec<_>>())
+        }
+        Err(err) => {
+            error!(
+                "failed to list contacts for organization {}: {}",
+                member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -0,0 +1,55 @@
+pub mod create_contact_handler;
+pub mod delete_contact_handler;
+pub mod get_contact_handler;
+pub mod get_contact_import_handler;
+pub mod import_contacts_handler;
+pub mod list_contacts_handler;
+pub mod update_contact_handler;
+
+use actix_web::{HttpResponse, dev::HttpServiceFactory, web};
+use application::usecases::UsecaseError;
+use serde::Serialize;
+
+use crate::handlers::contacts::{
+    create_contact_handler::handle_create_contact, delete_contact_handler::handle_delete_contact,
+    get_contact_handler::handle_get_contact, get_contact_import_handler::handle_get_contact_import,
+    import_contacts_handler::handle_import_contacts, list_contacts_handler::handle_list_contacts,
+    update_contact_handler::handle_update_contact,
+};
+use crate::middlewares::auth::ProtectedMiddleware;
+
+pub fn build_contacts_service() -> impl HttpServiceFactory {
+    web::scope("/contacts")
+        .wrap(ProtectedMiddleware::new())
+        .route("", web::get().to(handle_list_contacts))
+        .route("", web::post().to(handle_create_contact))
+        .route("/imports", web::post().to(handle_import_contacts))
+        .route("/imports/{id}", web::get().to(handle_get_contact_import))
+        .route("/{id}", web::get().to(handle_get_contact))
+        .route("/{id}", web::put().to(handle_update_contact))
+        .route("/{id}", web::delete().to(handle_delete_contact))
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Create and update fail the same ways, so they share one error mapping.
+fn contact_save_error(err: &UsecaseError) -> HttpResponse {
+    match err {
+        UsecaseError::Validation(_) => HttpResponse::UnprocessableEntity().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        UsecaseError::ContactPhoneNumberTaken => HttpResponse::Conflict().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        UsecaseError::EntityNotFound => HttpResponse::NotFound().finish(),
+        UsecaseError::Forbidden(_) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        _ => HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "Unable to save contact right now.".to_owned(),
+        }),
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::UpdateContactCommand;
+use application::usecases::update_contact_usecase::UpdateContactUsecase;
+use domain::repositories::{
+    contact_repository::ContactRepository, organization_repository::OrganizationRepository,
+};
+use tracing::error;
+
+use crate::{
+    dto::{ContactProps, ContactRequest},
+    handlers::contacts::contact_save_error,
+    membership::CurrentMember,
+};
+
+pub async fn handle_update_contact(
+    path: web::Path<uuid::Uuid>,
+    update_req: web::Json<ContactRequest>,
+    member: CurrentMember,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let contact_id = path.into_inner();
+
+    let update_contact_usecase = UpdateContactUsecase::builder()
+        .contact_repository(contact_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = UpdateContactCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        contact_id,
+        details: update_req.into_inner().into(),
+    };
+
+    match update_contact_usecase.execute(cmd).await {
+        Ok(contact) => HttpResponse::Ok().json(ContactProps::from(&contact)),
+        Err(err) => {
+            error!(
+                "failed to update contact {} for user {}: {}",
+                contact_id, member.user_id, err
+            );
+            contact_save_error(&err)
+        }
+    }
+}
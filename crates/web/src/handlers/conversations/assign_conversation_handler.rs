@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::AssignConversationCommand;
+use application::usecases::UsecaseError;
+use application::usecases::assign_conversation_usecase::AssignConversationUsecase;
+use domain::repositories::{
+    contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+    organization_repository::OrganizationRepository,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    contact_names::conversation_props_with_contact,
+    dto::{AssignConversationRequest, CONVERSATION_ASSIGNED_EVENT, ConversationEventProps},
+    membership::CurrentMember,
+    realtime::{MessageEventBroadcaster, RealtimeChannel},
+};
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Assigns, reassigns or unassigns a conversation - PUT /conversations/{id}/assignee
+pub async fn handle_assign_conversation(
+    path: web::Path<uuid::Uuid>,
+    assign_req: web::Json<AssignConversationRequest>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    let conversation_id = path.into_inner();
+
+    let assign_conversation_usecase = AssignConversationUsecase::builder()
+        .conversation_repository(conversation_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = AssignConversationCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        conversation_id,
+        assigned_user_id: assign_req.into_inner().assigned_user_id,
+    };
+
+    match assign_conversation_usecase.execute(cmd).await {
+        Ok(conversation) => {
+            let conversation =
+                conversation_props_with_contact(contact_repository.get_ref(), &conversation).await;
+            message_event_broadcaster.publish(
+                RealtimeChannel::Organization(member.organization_id),
+                ConversationEventProps {
+                    event_type: CONVERSATION_ASSIGNED_EVENT.to_owned(),
+                    conversation: conversation.clone(),
+                },
+            );
+
+            HttpResponse::Ok().json(conversation)
+        }
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Conversation not found.".to_owned(),
+        }),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to assign conversation {} for user {}: {}",
+                conversation_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to assign the conversation right now.".to_owned(),
+            })
+        }
+    }
+}
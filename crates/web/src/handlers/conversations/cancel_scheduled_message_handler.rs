@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::cancel_scheduled_message_usecase::CancelScheduledMessageUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::{
+    repositories::message_repository::MessageRepository, traits::media_storage::MediaStorage,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::membership::CurrentMember;
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Cancels a message before it is sent - DELETE /conversations/{id}/scheduled-messages/{message_id}
+pub async fn handle_cancel_scheduled_message(
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+    member: CurrentMember,
+    message_repository: web::Data<Arc<dyn MessageRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    media_storage: web::Data<Arc<dyn MediaStorage>>,
+) -> impl Responder {
+    let (conversation_id, message_id) = path.into_inner();
+    let cancel_scheduled_message_usecase = CancelScheduledMessageUsecase::builder()
+        .message_repository(message_repository.get_ref().clone())
+        .media_storage(media_storage.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    match cancel_scheduled_message_usecase
+        .execute(
+            member.user_id,
+            member.organization_id,
+            conversation_id,
+            message_id,
+        )
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Message not found.".to_owned(),
+        }),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err @ UsecaseError::MessageNotScheduled) => {
+            HttpResponse::Conflict().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err) => {
+            error!(
+                "failed to cancel scheduled message {} for user {}: {}",
+                message_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to cancel this message right now.".to_owned(),
+            })
+        }
+    }
+}
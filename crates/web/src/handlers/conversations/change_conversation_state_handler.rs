@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::ChangeConversationStateCommand;
+use application::usecases::UsecaseError;
+use application::usecases::change_conversation_state_usecase::ChangeConversationStateUsecase;
+use domain::{
+    models::conversation::ConversationStateChange,
+    repositories::{
+        contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+        organization_repository::OrganizationRepository,
+    },
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    contact_names::conversation_props_with_contact,
+    dto::{
+        CONVERSATION_UPDATED_EVENT, ConversationEventProps, SnoozeConversationRequest,
+        parse_snoozed_until,
+    },
+    membership::CurrentMember,
+    realtime::{MessageEventBroadcaster, RealtimeChannel},
+};
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// POST /conversations/{id}/archive
+pub async fn handle_archive_conversation(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    change_conversation_state(
+        path.into_inner(),
+        ConversationStateChange::Archive,
+        member,
+        conversation_repository,
+        organization_repository,
+        contact_repository,
+        message_event_broadcaster,
+    )
+    .await
+}
+
+/// POST /conversations/{id}/unarchive
+pub async fn handle_unarchive_conversation(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    change_conversation_state(
+        path.into_inner(),
+        ConversationStateChange::Unarchive,
+        member,
+        conversation_repository,
+        organization_repository,
+        contact_repository,
+        message_event_broadcaster,
+    )
+    .await
+}
+
+/// POST /conversations/{id}/pin
+pub async fn handle_pin_conversation(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    change_conversation_state(
+        path.into_inner(),
+        ConversationStateChange::Pin,
+        member,
+        conversation_repository,
+        organization_repository,
+        contact_repository,
+        message_event_broadcaster,
+    )
+    .await
+}
+
+/// POST /conversations/{id}/unpin
+pub async fn handle_unpin_conversation(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    change_conversation_state(
+        path.into_inner(),
+        ConversationStateChange::Unpin,
+        member,
+        conversation_repository,
+        organization_repository,
+        contact_repository,
+        message_event_broadcaster,
+    )
+    .await
+}
+
+/// POST /conversations/{id}/snooze
+pub async fn handle_snooze_conversation(
+    path: web::Path<uuid::Uuid>,
+    snooze_req: web::Json<SnoozeConversationRequest>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    let snoozed_until = match parse_snoozed_until(&snooze_req.snoozed_until) {
+        Ok(snoozed_until) => snoozed_until,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
+    };
+
+    change_conversation_state(
+        path.into_inner(),
+        ConversationStateChange::Snooze(snoozed_until),
+        member,
+        conversation_repository,
+        organization_repository,
+        contact_repository,
+        message_event_broadcaster,
+    )
+    .await
+}
+
+/// POST /conversations/{id}/unsnooze
+pub async fn handle_unsnooze_conversation(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    change_conversation_state(
+        path.into_inner(),
+        ConversationStateChange::Unsnooze,
+        member,
+        conversation_repository,
+        organization_repository,
+        contact_repository,
+        message_event_broadcaster,
+    )
+    .await
+}
+
+async fn change_conversation_state(
+    conversation_id: uuid::Uuid,
+    change: ConversationStateChange,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> HttpResponse {
+    let change_conversation_state_usecase = ChangeConversationStateUsecase::builder()
+        .conversation_repository(conversation_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = ChangeConversationStateCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        conversation_id,
+        change,
+    };
+
+    match change_conversation_state_usecase.execute(cmd).await {
+        Ok(conversation) => {
+            let conversation =
+                conversation_props_with_contact(contact_repository.get_ref(), &conversation).await;
+            message_event_broadcaster.publish(
+                RealtimeChannel::Organization(member.organization_id),
+                ConversationEventProps {
+                    event_type: CONVERSATION_UPDATED_EVENT.to_owned(),
+                    conversation: conversation.clone(),
+                },
+            );
+
+            HttpResponse::Ok().json(conversation)
+        }
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Conversation not found.".to_owned(),
+        }),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to change state of conversation {} for user {}: {}",
+                conversation_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to update the conversation right now.".to_owned(),
+            })
+        }
+    }
+}
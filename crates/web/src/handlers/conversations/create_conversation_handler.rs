@@ -6,34 +6,33 @@ use application::commands::CreateConversationCommand;
 use application::usecases::UsecaseError;
 use application::usecases::create_conversation_usecase::CreateConversationUsecase;
 use domain::repositories::conversation_repository::ConversationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
 use tracing::error;
 
 use crate::{
     dto::{CreateConversationRequest, CreateConversationResponse, FlashProps},
     flash::set_flash,
-    session::session_user_id,
+    membership::{CurrentMember, forbidden_or_redirect},
 };
 
 pub async fn handle_create_conversation(
     req: HttpRequest,
     create_req: web::Json<CreateConversationRequest>,
     session: Session,
+    member: CurrentMember,
     conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
 ) -> impl Responder {
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::Found()
-            .append_header((LOCATION, "/auth/login"))
-            .finish();
-    };
-
     let cmd = CreateConversationCommand {
-        user_id,
+        user_id: member.user_id,
+        organization_id: member.organization_id,
         phone_number_id: create_req.phone_number_id,
         recipient_phone_number: create_req.recipient_phone_number.clone(),
     };
 
     let create_conversation_usecase = CreateConversationUsecase::builder()
         .conversation_repository(conversation_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
         .build();
 
     match create_conversation_usecase.execute(cmd).await {
@@ -47,10 +46,13 @@ pub async fn handle_create_conversation(
 
             HttpResponse::Created().json(CreateConversationResponse { id: result.id })
         }
+        Err(err @ UsecaseError::Forbidden(_)) => {
+            forbidden_or_redirect(&err, &req, &session, "/conversations")
+        }
         Err(err) => {
             error!(
                 "failed to create conversation for user {} and phone_number {}: {}",
-                user_id, create_req.phone_number_id, err
+                member.user_id, create_req.phone_number_id, err
             );
 
             match err {
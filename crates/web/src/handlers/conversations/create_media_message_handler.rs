@@ -0,0 +1,263 @@
+use std::sync::Arc;
+
+use actix_multipart::Multipart;
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::{CreateMessageCommand, MessageAttachmentUpload};
+use application::usecases::UsecaseError;
+use application::usecases::create_message_usecase::{
+    CreateMessageUsecase, MAX_ATTACHMENT_BYTES_PER_MESSAGE, MAX_ATTACHMENTS_PER_MESSAGE,
+    UnverifiedSenderPolicy,
+};
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::{
+    repositories::{
+        contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+        message_repository::MessageRepository,
+        message_template_repository::MessageTemplateRepository,
+        opt_out_repository::OptOutRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+        phone_number_repository::PhoneNumberRepository, user_repository::UserRepository,
+    },
+    traits::{media_storage::MediaStorage, media_url_signer::MediaUrlSigner},
+};
+use futures_util::StreamExt;
+use serde::Serialize;
+use time::OffsetDateTime;
+use tracing::error;
+
+use crate::{
+    dto::{CreateMessageResponse, MessageProps, parse_scheduled_at},
+    handlers::conversations::UsageRepositories,
+    membership::CurrentMember,
+};
+
+const MAX_CONTENT_BYTES: usize = 16 * 1024;
+const MAX_SCHEDULED_AT_BYTES: usize = 64;
+const MAX_TEMPLATE_ID_BYTES: usize = 64;
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+struct MediaMessageForm {
+    content: String,
+    attachments: Vec<MessageAttachmentUpload>,
+    scheduled_at: Option<OffsetDateTime>,
+    template_id: Option<uuid::Uuid>,
+}
+
+/// Accepts a `multipart/form-data` body with an optional `content` text field (or a
+/// `template_id` to render instead), an optional `scheduled_at` RFC 3339 time and one or more
+/// `files` parts, and sends them as a single MMS.
+pub async fn handle_create_media_message(
+    path: web::Path<uuid::Uuid>,
+    payload: Multipart,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    message_repository: web::Data<Arc<dyn MessageRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    outbound_message_queue_repository: web::Data<Arc<dyn OutboundMessageQueueRepository>>,
+    opt_out_repository: web::Data<Arc<dyn OptOutRepository>>,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    media_storage: web::Data<Arc<dyn MediaStorage>>,
+    media_url_signer: web::Data<Arc<dyn MediaUrlSigner>>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    (usage_repository, usage_limit_repository): UsageRepositories,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    unverified_sender_policy: web::Data<UnverifiedSenderPolicy>,
+) -> impl Responder {
+    let form = match read_media_form(payload).await {
+        Ok(form) => form,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
+    };
+
+    let create_message_usecase = CreateMessageUsecase::builder()
+        .conversation_repository(conversation_repository.get_ref().clone())
+        .message_repository(message_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .outbound_message_queue_repository(outbound_message_queue_repository.get_ref().clone())
+        .opt_out_repository(opt_out_repository.get_ref().clone())
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .contact_repository(contact_repository.get_ref().clone())
+        .media_storage(media_storage.get_ref().clone())
+        .usage_repository(usage_repository.get_ref().clone())
+        .usage_limit_repository(usage_limit_repository.get_ref().clone())
+        .user_repository(user_repository.get_ref().clone())
+        .unverified_sender_policy(*unverified_sender_policy.get_ref())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    let conversation_id = path.into_inner();
+    let cmd = CreateMessageCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        conversation_id,
+        content: form.content,
+        attachments: form.attachments,
+        scheduled_at: form.scheduled_at,
+        template_id: form.template_id,
+    };
+
+    match create_message_usecase.execute(cmd).await {
+        Ok(result) => HttpResponse::Created().json(CreateMessageResponse {
+            message: MessageProps::signed(&result.message, media_url_signer.as_ref().as_ref()),
+        }),
+        Err(err) => {
+            error!(
+                "failed to create media message for user {} and conversation {}: {}",
+                member.user_id, conversation_id, err
+            );
+
+            match err {
+                UsecaseError::Validation(_)
+                | UsecaseError::MessageRejected(_)
+                | UsecaseError::EntityNotFound => {
+                    HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                        error: err.to_http_message(),
+                    })
+                }
+                UsecaseError::EmailNotVerified
+                | UsecaseError::RecipientOptedOut
+                | UsecaseError::UsageLimitReached { .. }
+                | UsecaseError::Forbidden(_) => HttpResponse::Forbidden().json(ErrorResponse {
+                    error: err.to_http_message(),
+                }),
+                UsecaseError::ExternalService(_) => {
+                    HttpResponse::BadGateway().json(ErrorResponse {
+                        error: err.to_http_message(),
+                    })
+                }
+                _ => HttpResponse::InternalServerError().json(ErrorResponse {
+                    error: "Unable to create message right now.".to_owned(),
+                }),
+            }
+        }
+    }
+}
+
+async fn read_media_form(mut payload: Multipart) -> Result<MediaMessageForm, String> {
+    let mut content = String::new();
+    let mut attachments = Vec::new();
+    let mut scheduled_at = None;
+    let mut template_id = None;
+    let mut total_bytes = 0usize;
+
+    while let Some(field) = payload.next().await {
+        let mut field = field.map_err(|err| format!("Invalid upload: {}", err))?;
+        let field_name = field.name().unwrap_or_default().to_owned();
+
+        match field_name.as_str() {
+            "content" => {
+                let mut bytes = Vec::new();
+                while let Some(chunk) = field.next().await {
+                    let chunk = chunk.map_err(|err| format!("Invalid upload: {}", err))?;
+                    if bytes.len() + chunk.len() > MAX_CONTENT_BYTES {
+                        return Err("Message content is too long".to_owned());
+                    }
+                    bytes.extend_from_slice(&chunk);
+                }
+                content = String::from_utf8(bytes)
+                    .map_err(|_| "Message content must be valid UTF-8".to_owned())?;
+            }
+            "scheduled_at" | "scheduledAt" => {
+                let mut bytes = Vec::new();
+                while let Some(chunk) = field.next().await {
+                    let chunk = chunk.map_err(|err| format!("Invalid upload: {}", err))?;
+                    if bytes.len() + chunk.len() > MAX_SCHEDULED_AT_BYTES {
+                        return Err("Scheduled time is too long".to_owned());
+                    }
+                    bytes.extend_from_slice(&chunk);
+                }
+                let value = String::from_utf8_lossy(&bytes);
+                if !value.trim().is_empty() {
+                    scheduled_at = Some(parse_scheduled_at(&value)?);
+                }
+            }
+            "template_id" | "templateId" => {
+                let mut bytes = Vec::new();
+                while let Some(chunk) = field.next().await {
+                    let chunk = chunk.map_err(|err| format!("Invalid upload: {}", err))?;
+                    if bytes.len() + chunk.len() > MAX_TEMPLATE_ID_BYTES {
+                        return Err("Template id is too long".to_owned());
+                    }
+                    bytes.extend_from_slice(&chunk);
+                }
+                let value = String::from_utf8_lossy(&bytes);
+                if !value.trim().is_empty() {
+                    template_id = Some(
+                        uuid::Uuid::parse_str(value.trim())
+                            .map_err(|_| "Template id is invalid".to_owned())?,
+                    );
+                }
+            }
+            "files" | "files[]" => {
+                if attachments.len() >= MAX_ATTACHMENTS_PER_MESSAGE {
+                    return Err(format!(
+                        "A message can include at most {} attachments",
+                        MAX_ATTACHMENTS_PER_MESSAGE
+                    ));
+                }
+
+                let file_name = field
+                    .content_disposition()
+                    .and_then(|disposition| disposition.get_filename())
+                    .map(sanitize_file_name)
+                    .unwrap_or_else(|| "attachment".to_owned());
+                let content_type = field
+                    .content_type()
+                    .map(|mime| mime.essence_str().to_owned())
+                    .unwrap_or_else(|| "application/octet-stream".to_owned());
+
+                let mut bytes = Vec::new();
+                while let Some(chunk) = field.next().await {
+                    let chunk = chunk.map_err(|err| format!("Invalid upload: {}", err))?;
+                    total_bytes += chunk.len();
+                    if total_bytes > MAX_ATTACHMENT_BYTES_PER_MESSAGE {
+                        return Err("Attachments exceed the 5 MB limit per message".to_owned());
+                    }
+                    bytes.extend_from_slice(&chunk);
+                }
+
+                attachments.push(MessageAttachmentUpload {
+                    file_name,
+                    content_type,
+                    bytes,
+                });
+            }
+            _ => {
+                // Drain unknown fields so the stream can advance.
+                while let Some(chunk) = field.next().await {
+                    chunk.map_err(|err| format!("Invalid upload: {}", err))?;
+                }
+            }
+        }
+    }
+
+    Ok(MediaMessageForm {
+        content,
+        attachments,
+        scheduled_at,
+        template_id,
+    })
+}
+
+fn sanitize_file_name(value: &str) -> String {
+    let name = value
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect::<String>();
+    let name = name.trim();
+
+    if name.is_empty() {
+        "attachment".to_owned()
+    } else {
+        name.chars().take(255).collect()
+    }
+}
@@ -1,23 +1,28 @@
 use std::sync::Arc;
 
-use actix_session::Session;
 use actix_web::{HttpResponse, Responder, web};
 use application::commands::CreateMessageCommand;
 use application::usecases::UsecaseError;
-use application::usecases::create_message_usecase::CreateMessageUsecase;
+use application::usecases::create_message_usecase::{CreateMessageUsecase, UnverifiedSenderPolicy};
+use domain::repositories::organization_repository::OrganizationRepository;
 use domain::{
     repositories::{
-        conversation_repository::ConversationRepository, message_repository::MessageRepository,
-        phone_number_repository::PhoneNumberRepository,
+        contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+        message_repository::MessageRepository,
+        message_template_repository::MessageTemplateRepository,
+        opt_out_repository::OptOutRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+        phone_number_repository::PhoneNumberRepository, user_repository::UserRepository,
     },
-    traits::outbound_message_service::OutboundMessageService,
+    traits::{media_storage::MediaStorage, media_url_signer::MediaUrlSigner},
 };
 use serde::Serialize;
 use tracing::error;
 
 use crate::{
-    dto::{CreateMessageRequest, CreateMessageResponse, MessageProps},
-    session::session_user_id,
+    dto::{CreateMessageRequest, CreateMessageResponse, MessageProps, parse_scheduled_at},
+    handlers::conversations::UsageRepositories,
+    membership::CurrentMember,
 };
 
 #[derive(Debug, Serialize)]
@@ -28,38 +33,69 @@ struct ErrorResponse {
 pub async fn handle_create_message(
     path: web::Path<uuid::Uuid>,
     create_req: web::Json<CreateMessageRequest>,
-    session: Session,
+    member: CurrentMember,
     conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
     message_repository: web::Data<Arc<dyn MessageRepository>>,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
-    outbound_message_service: web::Data<Arc<dyn OutboundMessageService>>,
+    outbound_message_queue_repository: web::Data<Arc<dyn OutboundMessageQueueRepository>>,
+    opt_out_repository: web::Data<Arc<dyn OptOutRepository>>,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    media_storage: web::Data<Arc<dyn MediaStorage>>,
+    media_url_signer: web::Data<Arc<dyn MediaUrlSigner>>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    (usage_repository, usage_limit_repository): UsageRepositories,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    unverified_sender_policy: web::Data<UnverifiedSenderPolicy>,
 ) -> impl Responder {
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::Unauthorized().finish();
+    let scheduled_at = match create_req
+        .scheduled_at
+        .as_deref()
+        .filter(|value| !value.trim().is_empty())
+        .map(parse_scheduled_at)
+        .transpose()
+    {
+        Ok(scheduled_at) => scheduled_at,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
     };
 
     let create_message_usecase = CreateMessageUsecase::builder()
         .conversation_repository(conversation_repository.get_ref().clone())
         .message_repository(message_repository.get_ref().clone())
         .phone_number_repository(phone_number_repository.get_ref().clone())
-        .outbound_message_service(outbound_message_service.get_ref().clone())
+        .outbound_message_queue_repository(outbound_message_queue_repository.get_ref().clone())
+        .opt_out_repository(opt_out_repository.get_ref().clone())
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .contact_repository(contact_repository.get_ref().clone())
+        .media_storage(media_storage.get_ref().clone())
+        .usage_repository(usage_repository.get_ref().clone())
+        .usage_limit_repository(usage_limit_repository.get_ref().clone())
+        .user_repository(user_repository.get_ref().clone())
+        .unverified_sender_policy(*unverified_sender_policy.get_ref())
+        .organization_repository(organization_repository.get_ref().clone())
         .build();
 
     let conversation_id = path.into_inner();
     let cmd = CreateMessageCommand {
-        user_id,
+        user_id: member.user_id,
+        organization_id: member.organization_id,
         conversation_id,
         content: create_req.content.clone(),
+        attachments: Vec::new(),
+        scheduled_at,
+        template_id: create_req.template_id,
     };
 
     match create_message_usecase.execute(cmd).await {
         Ok(result) => HttpResponse::Created().json(CreateMessageResponse {
-            message: MessageProps::from(&result.message),
+            message: MessageProps::signed(&result.message, media_url_signer.as_ref().as_ref()),
         }),
         Err(err) => {
             error!(
                 "failed to create message for user {} and conversation {}: {}",
-                user_id, conversation_id, err
+                member.user_id, conversation_id, err
             );
 
             match err {
@@ -70,6 +106,12 @@ pub async fn handle_create_message(
                         error: err.to_http_message(),
                     })
                 }
+                UsecaseError::EmailNotVerified
+                | UsecaseError::RecipientOptedOut
+                | UsecaseError::UsageLimitReached { .. }
+                | UsecaseError::Forbidden(_) => HttpResponse::Forbidden().json(ErrorResponse {
+                    error: err.to_http_message(),
+                }),
                 UsecaseError::ExternalService(_) => {
                     HttpResponse::BadGateway().json(ErrorResponse {
                         error: err.to_http_message(),
@@ -5,30 +5,32 @@ use actix_web::{HttpRequest, HttpResponse, Responder, http::header::LOCATION, we
 use application::usecases::UsecaseError;
 use application::usecases::delete_conversation_usecase::DeleteConversationUsecase;
 use domain::repositories::conversation_repository::ConversationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
 use tracing::error;
 
-use crate::{dto::FlashProps, flash::set_flash, session::session_user_id};
+use crate::{
+    dto::FlashProps,
+    flash::set_flash,
+    membership::{CurrentMember, forbidden_or_redirect},
+};
 
 pub async fn handle_delete_conversation(
     req: HttpRequest,
     path: web::Path<uuid::Uuid>,
     session: Session,
+    member: CurrentMember,
     conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
 ) -> impl Responder {
     let conversation_id = path.into_inner();
 
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::SeeOther()
-            .append_header((LOCATION, "/auth/login"))
-            .finish();
-    };
-
     let delete_conversation_usecase = DeleteConversationUsecase::builder()
         .conversation_repository(conversation_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
         .build();
 
     match delete_conversation_usecase
-        .execute(user_id, conversation_id)
+        .execute(member.user_id, member.organization_id, conversation_id)
         .await
     {
         Ok(_) => {
@@ -51,10 +53,13 @@ pub async fn handle_delete_conversation(
 
             HttpResponse::NotFound().finish()
         }
+        Err(err @ UsecaseError::Forbidden(_)) => {
+            forbidden_or_redirect(&err, &req, &session, "/conversations")
+        }
         Err(err) => {
             error!(
                 "failed to delete conversation {} for user {}: {}",
-                conversation_id, user_id, err
+                conversation_id, member.user_id, err
             );
             HttpResponse::InternalServerError().finish()
         }
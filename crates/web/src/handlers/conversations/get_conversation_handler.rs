@@ -1,47 +1,88 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use actix_session::Session;
 use actix_web::{HttpRequest, Responder, web};
 use application::usecases::UsecaseError;
 use application::usecases::get_conversation_usecase::GetConversationUsecase;
 use application::usecases::list_conversations_usecase::ListConversationsUsecase;
+use application::usecases::list_message_status_history_usecase::ListMessageStatusHistoryUsecase;
 use application::usecases::list_messages_by_conversation_usecase::ListMessagesByConversationUsecase;
 use application::usecases::list_phone_numbers_usecase::ListPhoneNumbersUsecase;
+use domain::repositories::contact_repository::ContactRepository;
 use domain::repositories::conversation_repository::ConversationRepository;
 use domain::repositories::message_repository::MessageRepository;
+use domain::repositories::message_status_event_repository::MessageStatusEventRepository;
+use domain::repositories::organization_invitation_repository::OrganizationInvitationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
 use domain::repositories::phone_number_repository::PhoneNumberRepository;
+use domain::traits::media_url_signer::MediaUrlSigner;
 use serde::Serialize;
 use tracing::error;
 
 use crate::{
-    dto::{ConversationProps, FlashProps, MessageProps, PhoneNumberProps},
+    contact_names::{conversation_props_with_contact, conversation_props_with_contacts},
+    dto::{
+        ConversationProps, ConversationsQuery, FlashProps, MessageProps, MessageStatusEventProps,
+        OrganizationMemberProps, PhoneNumberProps, conversation_filter_name,
+        conversation_view_name, parse_conversation_filter, parse_conversation_view,
+    },
     flash::extract_flash,
     handlers::conversations::MESSAGE_PAGE_SIZE,
     inertia::Page,
-    session::session_user_id,
+    member_names::organization_member_props,
+    membership::CurrentMember,
+    realtime::MessageEventBroadcaster,
+    unread_counts::{conversation_props_with_unread, mark_conversation_read},
 };
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ConversationPageProps {
     pub flash: Option<FlashProps>,
+    /// The sidebar list's `view` and `state`, as on the conversations page.
+    pub view: &'static str,
+    pub state: &'static str,
+    /// The signed-in user, so that conversations assigned to them read as theirs.
+    pub current_user_id: Option<uuid::Uuid>,
+    /// Everyone conversations can be assigned to.
+    pub members: Vec<OrganizationMemberProps>,
     pub conversations: Vec<ConversationProps>,
     pub conversation: Option<ConversationProps>,
     pub messages: Vec<MessageProps>,
     pub messages_next_cursor: Option<uuid::Uuid>,
+    /// Delivery timelines of the messages above, keyed by message id.
+    pub message_status_history: HashMap<uuid::Uuid, Vec<MessageStatusEventProps>>,
     pub phone_numbers: Vec<PhoneNumberProps>,
 }
 
 pub async fn render_get_conversation(
     req: HttpRequest,
     path: web::Path<uuid::Uuid>,
+    query: web::Query<ConversationsQuery>,
     session: Session,
+    member: Option<CurrentMember>,
     conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
     message_repository: web::Data<Arc<dyn MessageRepository>>,
+    message_status_event_repository: web::Data<Arc<dyn MessageStatusEventRepository>>,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    organization_invitation_repository: web::Data<Arc<dyn OrganizationInvitationRepository>>,
+    media_url_signer: web::Data<Arc<dyn MediaUrlSigner>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
 ) -> impl Responder {
     let conversation_id = path.into_inner();
     let flash = extract_flash(&session);
+    let view = query
+        .view
+        .as_deref()
+        .and_then(parse_conversation_view)
+        .unwrap_or_default();
+    let filter = query
+        .state
+        .as_deref()
+        .and_then(parse_conversation_filter)
+        .unwrap_or_default();
 
     let get_conversation_usecase = GetConversationUsecase::builder()
         .conversation_repository(conversation_repository.get_ref().clone())
@@ -52,41 +93,78 @@ pub async fn render_get_conversation(
     let list_messages_by_conversation_usecase = ListMessagesByConversationUsecase::builder()
         .message_repository(message_repository.get_ref().clone())
         .build();
+    let list_message_status_history_usecase = ListMessageStatusHistoryUsecase::builder()
+        .message_status_event_repository(message_status_event_repository.get_ref().clone())
+        .build();
     let list_phone_numbers_usecase = ListPhoneNumbersUsecase::builder()
         .phone_number_repository(phone_number_repository.get_ref().clone())
         .build();
 
-    let (conversation, conversations, messages, messages_next_cursor, phone_numbers) =
-        match session_user_id(&session) {
-        Some(user_id) => {
+    let (
+        conversation,
+        conversations,
+        messages,
+        messages_next_cursor,
+        message_status_history,
+        phone_numbers,
+    ) = match member {
+        Some(member) => {
             let conversation = match get_conversation_usecase
-                .execute(user_id, conversation_id)
+                .execute(member.organization_id, conversation_id)
                 .await
             {
-                Ok(item) => Some(ConversationProps::from(&item)),
+                Ok(item) => {
+                    Some(conversation_props_with_contact(contact_repository.get_ref(), &item).await)
+                }
                 Err(UsecaseError::EntityNotFound) => None,
                 Err(err) => {
                     error!(
                         "failed to get conversation {} for user {}: {}",
-                        conversation_id, user_id, err
+                        conversation_id, member.user_id, err
                     );
                     None
                 }
             };
 
+            // Opening a thread reads it, before the sidebar counts below are taken.
+            if conversation.is_some()
+                && let Err(err) = mark_conversation_read(
+                    conversation_repository.get_ref(),
+                    message_event_broadcaster.get_ref(),
+                    &member,
+                    conversation_id,
+                )
+                .await
+            {
+                error!(
+                    "failed to mark conversation {} read for user {}: {}",
+                    conversation_id, member.user_id, err
+                );
+            }
+
             let messages = if conversation.is_some() {
                 match list_messages_by_conversation_usecase
-                    .execute(user_id, conversation_id, None, MESSAGE_PAGE_SIZE)
+                    .execute(
+                        member.organization_id,
+                        conversation_id,
+                        None,
+                        MESSAGE_PAGE_SIZE,
+                    )
                     .await
                 {
                     Ok(page) => (
-                        page.messages.iter().map(MessageProps::from).collect(),
+                        page.messages
+                            .iter()
+                            .map(|message| {
+                                MessageProps::signed(message, media_url_signer.as_ref().as_ref())
+                            })
+                            .collect(),
                         page.next_cursor,
                     ),
                     Err(err) => {
                         error!(
                             "failed to list messages for conversation {} and user {}: {}",
-                            conversation_id, user_id, err
+                            conversation_id, member.user_id, err
                         );
                         (Vec::new(), None)
                     }
@@ -95,18 +173,67 @@ pub async fn render_get_conversation(
                 (Vec::new(), None)
             };
 
-            let conversations = match list_conversations_usecase.execute(user_id).await {
-                Ok(items) => items.iter().map(ConversationProps::from).collect(),
+            let message_ids = messages
+                .0
+                .iter()
+                .map(|message: &MessageProps| message.id)
+                .collect::<Vec<_>>();
+            let message_status_history = match list_message_status_history_usecase
+                .execute(member.organization_id, &message_ids)
+                .await
+            {
+                Ok(history) => history
+                    .into_iter()
+                    .map(|(message_id, events)| {
+                        (
+                            message_id,
+                            events.iter().map(MessageStatusEventProps::from).collect(),
+                        )
+                    })
+                    .collect(),
                 Err(err) => {
-                    error!("failed to list conversations for user {}: {}", user_id, err);
+                    error!(
+                        "failed to load status history for conversation {} and user {}: {}",
+                        conversation_id, member.user_id, err
+                    );
+                    HashMap::new()
+                }
+            };
+
+            let conversations = match list_conversations_usecase
+                .execute(member.organization_id, member.user_id, view, filter)
+                .await
+            {
+                Ok(items) => {
+                    let conversations =
+                        conversation_props_with_contacts(contact_repository.get_ref(), &items)
+                            .await;
+                    conversation_props_with_unread(
+                        conversation_repository.get_ref(),
+                        &member,
+                        conversations,
+                    )
+                    .await
+                }
+                Err(err) => {
+                    error!(
+                        "failed to list conversations for user {}: {}",
+                        member.user_id, err
+                    );
                     Vec::new()
                 }
             };
 
-            let phone_numbers = match list_phone_numbers_usecase.execute(user_id).await {
+            let phone_numbers = match list_phone_numbers_usecase
+                .execute(member.organization_id)
+                .await
+            {
                 Ok(items) => items.iter().map(PhoneNumberProps::from).collect(),
                 Err(err) => {
-                    error!("failed to list phone numbers for user {}: {}", user_id, err);
+                    error!(
+                        "failed to list phone numbers for user {}: {}",
+                        member.user_id, err
+                    );
                     Vec::new()
                 }
             };
@@ -116,10 +243,30 @@ pub async fn render_get_conversation(
                 conversations,
                 messages.0,
                 messages.1,
+                message_status_history,
                 phone_numbers,
             )
         }
-        None => (None, Vec::new(), Vec::new(), None, Vec::new()),
+        None => (
+            None,
+            Vec::new(),
+            Vec::new(),
+            None,
+            HashMap::new(),
+            Vec::new(),
+        ),
+    };
+
+    let members = match member {
+        Some(member) => {
+            organization_member_props(
+                organization_repository.get_ref(),
+                organization_invitation_repository.get_ref(),
+                &member,
+            )
+            .await
+        }
+        None => Vec::new(),
     };
 
     Page::builder()
@@ -127,10 +274,15 @@ pub async fn render_get_conversation(
         .name("Conversations")
         .props(ConversationPageProps {
             flash,
+            view: conversation_view_name(view),
+            state: conversation_filter_name(filter),
+            current_user_id: member.map(|member| member.user_id),
+            members,
             conversations,
             conversation,
             messages,
             messages_next_cursor,
+            message_status_history,
             phone_numbers,
         })
         .build()
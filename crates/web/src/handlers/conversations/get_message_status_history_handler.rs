@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::get_message_status_history_usecase::GetMessageStatusHistoryUsecase;
+use domain::repositories::message_repository::MessageRepository;
+use domain::repositories::message_status_event_repository::MessageStatusEventRepository;
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::{MessageStatusEventProps, MessageStatusHistoryResponse},
+    membership::CurrentMember,
+};
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Delivery timeline of one message - GET /conversations/{id}/messages/{message_id}/status-history
+pub async fn handle_get_message_status_history(
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+    member: CurrentMember,
+    message_repository: web::Data<Arc<dyn MessageRepository>>,
+    message_status_event_repository: web::Data<Arc<dyn MessageStatusEventRepository>>,
+) -> impl Responder {
+    let (conversation_id, message_id) = path.into_inner();
+
+    let get_message_status_history_usecase = GetMessageStatusHistoryUsecase::builder()
+        .message_repository(message_repository.get_ref().clone())
+        .message_status_event_repository(message_status_event_repository.get_ref().clone())
+        .build();
+
+    match get_message_status_history_usecase
+        .execute(member.organization_id, conversation_id, message_id)
+        .await
+    {
+        Ok(events) => HttpResponse::Ok().json(MessageStatusHistoryResponse {
+            events: events.iter().map(MessageStatusEventProps::from).collect(),
+        }),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Message not found.".to_owned(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to load status history for message {} and user {}: {}",
+                message_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to load delivery history right now.".to_owned(),
+            })
+        }
+    }
+}
@@ -1,19 +1,19 @@
 use std::sync::Arc;
 
-use actix_session::Session;
 use actix_web::{HttpResponse, Responder, web};
 use application::usecases::UsecaseError;
 use application::usecases::get_conversation_usecase::GetConversationUsecase;
 use application::usecases::list_messages_by_conversation_usecase::ListMessagesByConversationUsecase;
 use domain::repositories::conversation_repository::ConversationRepository;
 use domain::repositories::message_repository::MessageRepository;
+use domain::traits::media_url_signer::MediaUrlSigner;
 use serde::{Deserialize, Serialize};
 use tracing::error;
 
 use crate::{
     dto::{MessageProps, MessagesPageResponse},
     handlers::conversations::{MAX_MESSAGE_PAGE_SIZE, MESSAGE_PAGE_SIZE},
-    session::session_user_id,
+    membership::CurrentMember,
 };
 
 #[derive(Debug, Deserialize)]
@@ -31,14 +31,11 @@ struct ErrorResponse {
 pub async fn handle_list_conversation_messages(
     path: web::Path<uuid::Uuid>,
     query: web::Query<MessagesPageQuery>,
-    session: Session,
+    member: CurrentMember,
     conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
     message_repository: web::Data<Arc<dyn MessageRepository>>,
+    media_url_signer: web::Data<Arc<dyn MediaUrlSigner>>,
 ) -> impl Responder {
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::Unauthorized().finish();
-    };
-
     let conversation_id = path.into_inner();
     let query = query.into_inner();
     let limit = query
@@ -53,7 +50,10 @@ pub async fn handle_list_conversation_messages(
         .message_repository(message_repository.get_ref().clone())
         .build();
 
-    if let Err(err) = get_conversation_usecase.execute(user_id, conversation_id).await {
+    if let Err(err) = get_conversation_usecase
+        .execute(member.organization_id, conversation_id)
+        .await
+    {
         return match err {
             UsecaseError::EntityNotFound => HttpResponse::NotFound().json(ErrorResponse {
                 error: "Conversation not found.".to_owned(),
@@ -61,7 +61,7 @@ pub async fn handle_list_conversation_messages(
             _ => {
                 error!(
                     "failed to validate conversation {} for user {}: {}",
-                    conversation_id, user_id, err
+                    conversation_id, member.user_id, err
                 );
                 HttpResponse::InternalServerError().json(ErrorResponse {
                     error: "Unable to load message history right now.".to_owned(),
@@ -71,11 +71,15 @@ pub async fn handle_list_conversation_messages(
     }
 
     match list_messages_by_conversation_usecase
-        .execute(user_id, conversation_id, query.cursor, limit)
+        .execute(member.organization_id, conversation_id, query.cursor, limit)
         .await
     {
         Ok(page) => HttpResponse::Ok().json(MessagesPageResponse {
-            messages: page.messages.iter().map(MessageProps::from).collect(),
+            messages: page
+                .messages
+                .iter()
+                .map(|message| MessageProps::signed(message, media_url_signer.as_ref().as_ref()))
+                .collect(),
             next_cursor: page.next_cursor,
         }),
         Err(UsecaseError::EntityNotFound) if query.cursor.is_some() => {
@@ -86,7 +90,7 @@ pub async fn handle_list_conversation_messages(
         Err(err) => {
             error!(
                 "failed to list paginated messages for user {} and conversation {}: {}",
-                user_id, conversation_id, err
+                member.user_id, conversation_id, err
             );
             HttpResponse::InternalServerError().json(ErrorResponse {
                 error: "Unable to load message history right now.".to_owned(),
@@ -4,33 +4,66 @@ use actix_session::Session;
 use actix_web::{HttpRequest, Responder, web};
 use application::usecases::list_conversations_usecase::ListConversationsUsecase;
 use application::usecases::list_phone_numbers_usecase::ListPhoneNumbersUsecase;
+use domain::repositories::contact_repository::ContactRepository;
 use domain::repositories::conversation_repository::ConversationRepository;
+use domain::repositories::organization_invitation_repository::OrganizationInvitationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
 use domain::repositories::phone_number_repository::PhoneNumberRepository;
 use serde::Serialize;
 use tracing::error;
 
 use crate::{
-    dto::{ConversationProps, FlashProps, PhoneNumberProps},
+    contact_names::conversation_props_with_contacts,
+    dto::{
+        ConversationProps, ConversationsQuery, FlashProps, OrganizationMemberProps,
+        PhoneNumberProps, conversation_filter_name, conversation_view_name,
+        parse_conversation_filter, parse_conversation_view,
+    },
     flash::extract_flash,
     inertia::Page,
-    session::session_user_id,
+    member_names::organization_member_props,
+    membership::CurrentMember,
+    unread_counts::conversation_props_with_unread,
 };
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ConversationsPageProps {
     pub flash: Option<FlashProps>,
+    /// `all`, `mine` or `unassigned`.
+    pub view: &'static str,
+    /// `inbox`, `pinned`, `snoozed` or `archived`.
+    pub state: &'static str,
+    /// The signed-in user, so that conversations assigned to them read as theirs.
+    pub current_user_id: Option<uuid::Uuid>,
+    /// Everyone conversations can be assigned to.
+    pub members: Vec<OrganizationMemberProps>,
     pub conversations: Vec<ConversationProps>,
     pub phone_numbers: Vec<PhoneNumberProps>,
 }
 
 pub async fn render_list_conversations(
     req: HttpRequest,
+    query: web::Query<ConversationsQuery>,
     session: Session,
+    member: Option<CurrentMember>,
     conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    organization_invitation_repository: web::Data<Arc<dyn OrganizationInvitationRepository>>,
 ) -> impl Responder {
     let flash = extract_flash(&session);
+    let view = query
+        .view
+        .as_deref()
+        .and_then(parse_conversation_view)
+        .unwrap_or_default();
+    let filter = query
+        .state
+        .as_deref()
+        .and_then(parse_conversation_filter)
+        .unwrap_or_default();
 
     let list_conversations_usecase = ListConversationsUsecase::builder()
         .conversation_repository(conversation_repository.get_ref().clone())
@@ -39,20 +72,42 @@ pub async fn render_list_conversations(
         .phone_number_repository(phone_number_repository.get_ref().clone())
         .build();
 
-    let (conversations, phone_numbers) = match session_user_id(&session) {
-        Some(user_id) => {
-            let conversations = match list_conversations_usecase.execute(user_id).await {
-                Ok(items) => items.iter().map(ConversationProps::from).collect(),
+    let (conversations, phone_numbers) = match member {
+        Some(member) => {
+            let conversations = match list_conversations_usecase
+                .execute(member.organization_id, member.user_id, view, filter)
+                .await
+            {
+                Ok(items) => {
+                    let conversations =
+                        conversation_props_with_contacts(contact_repository.get_ref(), &items)
+                            .await;
+                    conversation_props_with_unread(
+                        conversation_repository.get_ref(),
+                        &member,
+                        conversations,
+                    )
+                    .await
+                }
                 Err(err) => {
-                    error!("failed to list conversations for user {}: {}", user_id, err);
+                    error!(
+                        "failed to list conversations for user {}: {}",
+                        member.user_id, err
+                    );
                     Vec::new()
                 }
             };
 
-            let phone_numbers = match list_phone_numbers_usecase.execute(user_id).await {
+            let phone_numbers = match list_phone_numbers_usecase
+                .execute(member.organization_id)
+                .await
+            {
                 Ok(items) => items.iter().map(PhoneNumberProps::from).collect(),
                 Err(err) => {
-                    error!("failed to list phone numbers for user {}: {}", user_id, err);
+                    error!(
+                        "failed to list phone numbers for user {}: {}",
+                        member.user_id, err
+                    );
                     Vec::new()
                 }
             };
@@ -62,11 +117,27 @@ pub async fn render_list_conversations(
         None => (Vec::new(), Vec::new()),
     };
 
+    let members = match member {
+        Some(member) => {
+            organization_member_props(
+                organization_repository.get_ref(),
+                organization_invitation_repository.get_ref(),
+                &member,
+            )
+            .await
+        }
+        None => Vec::new(),
+    };
+
     Page::builder()
         .req(req)
         .name("Conversations")
         .props(ConversationsPageProps {
             flash,
+            view: conversation_view_name(view),
+            state: conversation_filter_name(filter),
+            current_user_id: member.map(|member| member.user_id),
+            members,
             conversations,
             phone_numbers,
         })
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::list_scheduled_messages_usecase::ListScheduledMessagesUsecase;
+use domain::{
+    repositories::{
+        conversation_repository::ConversationRepository, message_repository::MessageRepository,
+    },
+    traits::media_url_signer::MediaUrlSigner,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::{MessageProps, ScheduledMessagesResponse},
+    membership::CurrentMember,
+};
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Messages waiting to be sent later - GET /conversations/{id}/scheduled-messages
+pub async fn handle_list_scheduled_messages(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    message_repository: web::Data<Arc<dyn MessageRepository>>,
+    media_url_signer: web::Data<Arc<dyn MediaUrlSigner>>,
+) -> impl Responder {
+    let conversation_id = path.into_inner();
+    let list_scheduled_messages_usecase = ListScheduledMessagesUsecase::builder()
+        .conversation_repository(conversation_repository.get_ref().clone())
+        .message_repository(message_repository.get_ref().clone())
+        .build();
+
+    match list_scheduled_messages_usecase
+        .execute(member.organization_id, conversation_id)
+        .await
+    {
+        Ok(messages) => HttpResponse::Ok().json(ScheduledMessagesResponse {
+            messages: messages
+                .iter()
+                .map(|message| MessageProps::signed(message, media_url_signer.as_ref().as_ref()))
+                .collect(),
+        }),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Conversation not found.".to_owned(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to list scheduled messages for user {} and conversation {}: {}",
+                member.user_id, conversation_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to load scheduled messages right now.".to_owned(),
+            })
+        }
+    }
+}
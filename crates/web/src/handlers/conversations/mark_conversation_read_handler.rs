@@ -0,0 +1,277 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use domain::repositories::conversation_repository::ConversationRepository;
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::MarkConversationReadResponse, membership::CurrentMember,
+    realtime::MessageEventBroadcaster, unread_counts::mark_conversation_read,
+};
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Marks a conversation read for the signed-in member - POST /conversations/{id}/read
+pub async fn handle_mark_conversation_read(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
+) -> impl Responder {
+    let conversation_id = path.into_inner();
+
+    match mark_conversation_read(
+        conversation_repository.get_ref(),
+        message_event_broadcaster.get_ref(),
+        &member,
+        conversation_id,
+    )
+    .await
+    {
+        Ok(total_unread) => HttpResponse::Ok().json(MarkConversationReadResponse { total_unread }),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Conversation not found.".to_owned(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to mark conversation {} read for user {}: {}",
+                conversation_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to mark the conversation as read right now.".to_owned(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use actix_web::{Responder, body::to_bytes, http::StatusCode, test::TestRequest, web};
+    use async_trait::async_trait;
+    use domain::{
+        models::conversation::{
+            AssigneeLoad, Conversation, ConversationFilter, ConversationStateChange,
+            ConversationView, UnreadCount,
+        },
+        repositories::{RepositoryError, conversation_repository::ConversationRepository},
+    };
+    use time::OffsetDateTime;
+
+    use super::handle_mark_conversation_read;
+    use crate::{
+        membership::CurrentMember,
+        realtime::{MessageEventBroadcaster, RealtimeChannel, RealtimeEvent},
+    };
+
+    /// One conversation, with unread messages left in another one after it is read.
+    struct FakeConversationRepository {
+        conversation: Conversation,
+        unread_elsewhere: i64,
+    }
+
+    #[async_trait]
+    impl ConversationRepository for FakeConversationRepository {
+        async fn create_conversation(
+            &self,
+            _conversation: &Conversation,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            organization_id: &uuid::Uuid,
+            id: &uuid::Uuid,
+        ) -> Result<Conversation, RepositoryError> {
+            if self.conversation.id == *id && self.conversation.organization_id == *organization_id
+            {
+                Ok(self.conversation.clone())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        }
+
+        async fn find_by_phone_number_and_recipient(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _phone_number_id: &uuid::Uuid,
+            _recipient_phone_number: &str,
+        ) -> Result<Conversation, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_organization_id(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(vec![self.conversation.clone()])
+        }
+
+        async fn list_filtered(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+            _view: ConversationView,
+            _filter: ConversationFilter,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn change_state(
+            &self,
+            conversation: &Conversation,
+            _change: ConversationStateChange,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn record_activity(
+            &self,
+            conversation: &Conversation,
+            _last_message_at: OffsetDateTime,
+            _updated_at: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn reopen(&self, _conversation: &Conversation) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn assign(
+            &self,
+            conversation: &Conversation,
+            _assigned_user_id: Option<uuid::Uuid>,
+            _now: OffsetDateTime,
+        ) -> Result<Conversation, RepositoryError> {
+            Ok(conversation.clone())
+        }
+
+        async fn claim_assignment(
+            &self,
+            _conversation: &Conversation,
+            _assigned_user_id: &uuid::Uuid,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+
+        async fn list_assignee_loads(
+            &self,
+            _organization_id: &uuid::Uuid,
+        ) -> Result<Vec<AssigneeLoad>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn mark_read(
+            &self,
+            _conversation: &Conversation,
+            _user_id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn list_unread_counts(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _user_id: &uuid::Uuid,
+        ) -> Result<Vec<UnreadCount>, RepositoryError> {
+            Ok(vec![UnreadCount {
+                conversation_id: uuid::Uuid::now_v7(),
+                unread: self.unread_elsewhere,
+            }])
+        }
+
+        async fn wake_snoozed(
+            &self,
+            _now: OffsetDateTime,
+        ) -> Result<Vec<Conversation>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_conversation(
+            &self,
+            _organization_id: &uuid::Uuid,
+            _id: &uuid::Uuid,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn claim_away_reply(
+            &self,
+            _conversation: &Conversation,
+            _closed_since: OffsetDateTime,
+            _now: OffsetDateTime,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
+    }
+
+    #[actix_web::test]
+    async fn publishes_the_read_event_to_the_reader_only() {
+        let now = OffsetDateTime::now_utc();
+        let member = CurrentMember {
+            user_id: uuid::Uuid::now_v7(),
+            organization_id: uuid::Uuid::now_v7(),
+        };
+        let conversation = Conversation::builder()
+            .id(uuid::Uuid::now_v7())
+            .phone_number_id(uuid::Uuid::now_v7())
+            .organization_id(member.organization_id)
+            .user_id(member.user_id)
+            .last_message_at(now)
+            .created_at(now)
+            .updated_at(now)
+            .build();
+        let conversation_repository: Arc<dyn ConversationRepository> =
+            Arc::new(FakeConversationRepository {
+                conversation: conversation.clone(),
+                unread_elsewhere: 3,
+            });
+        let message_event_broadcaster = Arc::new(MessageEventBroadcaster::default());
+        let mut reader_events = message_event_broadcaster.subscribe(RealtimeChannel::Member {
+            organization_id: member.organization_id,
+            user_id: member.user_id,
+        });
+        let mut teammate_events = message_event_broadcaster.subscribe(RealtimeChannel::Member {
+            organization_id: member.organization_id,
+            user_id: uuid::Uuid::now_v7(),
+        });
+        let mut organization_events = message_event_broadcaster
+            .subscribe(RealtimeChannel::Organization(member.organization_id));
+
+        let response = handle_mark_conversation_read(
+            web::Path::from(conversation.id),
+            member,
+            web::Data::new(conversation_repository),
+            web::Data::new(message_event_broadcaster),
+        )
+        .await
+        .respond_to(&TestRequest::default().to_http_request());
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let Ok(body) = to_bytes(response.into_body()).await else {
+            panic!("unreadable body");
+        };
+        assert_eq!(body.as_ref(), br#"{"totalUnread":3}"#);
+
+        match reader_events.try_recv().expect("read event") {
+            RealtimeEvent::ConversationRead(event) => {
+                assert_eq!(event.conversation_id, conversation.id);
+                assert_eq!(event.total_unread, 3);
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+        assert!(teammate_events.try_recv().is_err());
+        assert!(organization_events.try_recv().is_err());
+    }
+}
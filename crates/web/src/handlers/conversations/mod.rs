@@ -1,32 +1,106 @@
+pub mod assign_conversation_handler;
+pub mod cancel_scheduled_message_handler;
+pub mod change_conversation_state_handler;
 pub mod create_conversation_handler;
+pub mod create_media_message_handler;
 pub mod create_message_handler;
 pub mod delete_conversation_handler;
 pub mod get_conversation_handler;
+pub mod get_message_status_history_handler;
 pub mod list_conversation_messages_handler;
 pub mod list_conversations_handler;
+pub mod list_scheduled_messages_handler;
+pub mod mark_conversation_read_handler;
+pub mod preview_message_handler;
+pub mod reschedule_message_handler;
+
+use std::sync::Arc;
 
 use actix_web::{dev::HttpServiceFactory, web};
+use domain::repositories::{
+    usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+};
 
 use crate::handlers::conversations::{
+    assign_conversation_handler::handle_assign_conversation,
+    cancel_scheduled_message_handler::handle_cancel_scheduled_message,
+    change_conversation_state_handler::{
+        handle_archive_conversation, handle_pin_conversation, handle_snooze_conversation,
+        handle_unarchive_conversation, handle_unpin_conversation, handle_unsnooze_conversation,
+    },
     create_conversation_handler::handle_create_conversation,
+    create_media_message_handler::handle_create_media_message,
     create_message_handler::handle_create_message,
     delete_conversation_handler::handle_delete_conversation,
     get_conversation_handler::render_get_conversation,
+    get_message_status_history_handler::handle_get_message_status_history,
     list_conversation_messages_handler::handle_list_conversation_messages,
     list_conversations_handler::render_list_conversations,
+    list_scheduled_messages_handler::handle_list_scheduled_messages,
+    mark_conversation_read_handler::handle_mark_conversation_read,
+    preview_message_handler::handle_preview_message,
+    reschedule_message_handler::handle_reschedule_message,
 };
 use crate::middlewares::auth::ProtectedMiddleware;
 
 pub const MESSAGE_PAGE_SIZE: usize = 10;
 pub const MAX_MESSAGE_PAGE_SIZE: usize = 50;
 
+/// The usage repositories the send handlers take as one argument, because actix-web
+/// handlers take at most 16.
+type UsageRepositories = (
+    web::Data<Arc<dyn UsageRepository>>,
+    web::Data<Arc<dyn UsageLimitRepository>>,
+);
+
 pub fn build_conversations_service() -> impl HttpServiceFactory {
     web::scope("/conversations")
         .wrap(ProtectedMiddleware::new())
         .route("", web::get().to(render_list_conversations))
         .route("", web::post().to(handle_create_conversation))
         .route("/{id}", web::get().to(render_get_conversation))
-        .route("/{id}/messages", web::get().to(handle_list_conversation_messages))
+        .route("/{id}/assignee", web::put().to(handle_assign_conversation))
+        .route("/{id}/read", web::post().to(handle_mark_conversation_read))
+        .route("/{id}/archive", web::post().to(handle_archive_conversation))
+        .route(
+            "/{id}/unarchive",
+            web::post().to(handle_unarchive_conversation),
+        )
+        .route("/{id}/pin", web::post().to(handle_pin_conversation))
+        .route("/{id}/unpin", web::post().to(handle_unpin_conversation))
+        .route("/{id}/snooze", web::post().to(handle_snooze_conversation))
+        .route(
+            "/{id}/unsnooze",
+            web::post().to(handle_unsnooze_conversation),
+        )
+        .route(
+            "/{id}/messages",
+            web::get().to(handle_list_conversation_messages),
+        )
         .route("/{id}/messages", web::post().to(handle_create_message))
+        .route(
+            "/{id}/messages/media",
+            web::post().to(handle_create_media_message),
+        )
+        .route(
+            "/{id}/messages/preview",
+            web::post().to(handle_preview_message),
+        )
+        .route(
+            "/{id}/messages/{message_id}/status-history",
+            web::get().to(handle_get_message_status_history),
+        )
+        .route(
+            "/{id}/scheduled-messages",
+            web::get().to(handle_list_scheduled_messages),
+        )
+        .route(
+            "/{id}/scheduled-messages/{message_id}",
+            web::patch().to(handle_reschedule_message),
+        )
+        .route(
+            "/{id}/scheduled-messages/{message_id}",
+            web::delete().to(handle_cancel_scheduled_message),
+        )
         .route("/{id}", web::delete().to(handle_delete_conversation))
 }
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::PreviewMessageCommand;
+use application::usecases::UsecaseError;
+use application::usecases::preview_message_usecase::PreviewMessageUsecase;
+use domain::repositories::{
+    contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+    message_template_repository::MessageTemplateRepository,
+    phone_number_repository::PhoneNumberRepository,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::{MessagePreviewProps, PreviewMessageRequest},
+    membership::CurrentMember,
+    types::SmsSegmentPrice,
+};
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Segments, encoding and cost of a draft - POST /conversations/{id}/messages/preview
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_preview_message(
+    path: web::Path<uuid::Uuid>,
+    preview_req: web::Json<PreviewMessageRequest>,
+    member: CurrentMember,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+    sms_segment_price: web::Data<SmsSegmentPrice>,
+) -> impl Responder {
+    let conversation_id = path.into_inner();
+    let preview_message_usecase = PreviewMessageUsecase::builder()
+        .conversation_repository(conversation_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .contact_repository(contact_repository.get_ref().clone())
+        .maybe_segment_price_micros(sms_segment_price.0)
+        .build();
+    let preview_req = preview_req.into_inner();
+    let cmd = PreviewMessageCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        conversation_id,
+        content: preview_req.content,
+        template_id: preview_req.template_id,
+    };
+
+    match preview_message_usecase.execute(cmd).await {
+        Ok(preview) => HttpResponse::Ok().json(MessagePreviewProps::from(&preview)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Conversation or template not found.".to_owned(),
+        }),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err) => {
+            error!(
+                "failed to preview message in conversation {} for user {}: {}",
+                conversation_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to preview this message right now.".to_owned(),
+            })
+        }
+    }
+}
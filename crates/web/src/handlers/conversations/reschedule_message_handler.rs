@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::RescheduleMessageCommand;
+use application::usecases::UsecaseError;
+use application::usecases::reschedule_message_usecase::RescheduleMessageUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::{
+    repositories::{
+        message_repository::MessageRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+    },
+    traits::media_url_signer::MediaUrlSigner,
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::{CreateMessageResponse, MessageProps, RescheduleMessageRequest, parse_scheduled_at},
+    membership::CurrentMember,
+};
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Moves a scheduled message - PATCH /conversations/{id}/scheduled-messages/{message_id}
+pub async fn handle_reschedule_message(
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+    reschedule_req: web::Json<RescheduleMessageRequest>,
+    member: CurrentMember,
+    message_repository: web::Data<Arc<dyn MessageRepository>>,
+    outbound_message_queue_repository: web::Data<Arc<dyn OutboundMessageQueueRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    media_url_signer: web::Data<Arc<dyn MediaUrlSigner>>,
+) -> impl Responder {
+    let scheduled_at = match parse_scheduled_at(&reschedule_req.scheduled_at) {
+        Ok(scheduled_at) => scheduled_at,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
+    };
+
+    let (conversation_id, message_id) = path.into_inner();
+    let reschedule_message_usecase = RescheduleMessageUsecase::builder()
+        .message_repository(message_repository.get_ref().clone())
+        .outbound_message_queue_repository(outbound_message_queue_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = RescheduleMessageCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        conversation_id,
+        message_id,
+        scheduled_at,
+    };
+
+    match reschedule_message_usecase.execute(cmd).await {
+        Ok(message) => HttpResponse::Ok().json(CreateMessageResponse {
+            message: MessageProps::signed(&message, media_url_signer.as_ref().as_ref()),
+        }),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(ErrorResponse {
+            error: "Message not found.".to_owned(),
+        }),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err @ UsecaseError::MessageNotScheduled) => {
+            HttpResponse::Conflict().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err) => {
+            error!(
+                "failed to reschedule message {} for user {}: {}",
+                message_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to reschedule this message right now.".to_owned(),
+            })
+        }
+    }
+}
@@ -1,62 +1,69 @@
 use std::{sync::Arc, time::Duration};
 
-use actix_session::Session;
 use actix_web::{HttpResponse, Responder, http::header, web, web::Bytes};
 use futures_util::stream::unfold;
 use tokio::{
-    sync::broadcast::error::RecvError,
+    sync::broadcast::{Receiver, error::RecvError},
     time::{Instant, interval_at},
 };
 use tracing::error;
 
-use crate::{realtime::MessageEventBroadcaster, session::session_user_id};
+use crate::{
+    membership::CurrentMember,
+    realtime::{MessageEventBroadcaster, RealtimeChannel, RealtimeEvent},
+};
 
 pub async fn stream_message_events(
-    session: Session,
+    member: CurrentMember,
     message_event_broadcaster: web::Data<Arc<MessageEventBroadcaster>>,
 ) -> impl Responder {
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::Unauthorized().finish();
+    let receivers = Receivers {
+        organization: message_event_broadcaster
+            .subscribe(RealtimeChannel::Organization(member.organization_id)),
+        user: message_event_broadcaster.subscribe(RealtimeChannel::User(member.user_id)),
+        member: message_event_broadcaster.subscribe(RealtimeChannel::Member {
+            organization_id: member.organization_id,
+            user_id: member.user_id,
+        }),
     };
-
-    let receiver = message_event_broadcaster.subscribe(user_id);
     let keepalive = interval_at(
         Instant::now() + Duration::from_secs(25),
         Duration::from_secs(25),
     );
     let stream = unfold(
-        (receiver, keepalive, true),
-        move |(mut receiver, mut keepalive, first_frame)| async move {
+        (receivers, keepalive, true),
+        move |(mut receivers, mut keepalive, first_frame)| async move {
             if first_frame {
                 return Some((
                     Ok::<Bytes, actix_web::Error>(Bytes::from_static(b"retry: 5000\n\n")),
-                    (receiver, keepalive, false),
+                    (receivers, keepalive, false),
                 ));
             }
 
             loop {
-                tokio::select! {
-                    result = receiver.recv() => {
-                        match result {
-                            Ok(event) => {
-                                match serde_json::to_string(&event) {
-                                    Ok(json) => {
-                                        let frame = format!("event: {}\ndata: {}\n\n", event.event_type, json);
-                                        return Some((Ok(Bytes::from(frame)), (receiver, keepalive, false)));
-                                    }
-                                    Err(err) => {
-                                        error!("failed to serialize SSE payload: {}", err);
-                                        continue;
-                                    }
-                                }
-                            }
-                            Err(RecvError::Lagged(_)) => continue,
-                            Err(RecvError::Closed) => return None,
-                        }
-                    }
+                let result = tokio::select! {
+                    result = receivers.organization.recv() => result,
+                    result = receivers.user.recv() => result,
+                    result = receivers.member.recv() => result,
                     _ = keepalive.tick() => {
-                        return Some((Ok(Bytes::from_static(b": keepalive\n\n")), (receiver, keepalive, false)));
+                        return Some((Ok(Bytes::from_static(b": keepalive\n\n")), (receivers, keepalive, false)));
                     }
+                };
+
+                match result {
+                    Ok(event) => match serde_json::to_string(&event) {
+                        Ok(json) => {
+                            let frame =
+                                format!("event: {}\ndata: {}\n\n", event.event_type(), json);
+                            return Some((Ok(Bytes::from(frame)), (receivers, keepalive, false)));
+                        }
+                        Err(err) => {
+                            error!("failed to serialize SSE payload: {}", err);
+                            continue;
+                        }
+                    },
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
                 }
             }
         },
@@ -70,3 +77,10 @@ pub async fn stream_message_events(
         .insert_header(("X-Accel-Buffering", "no"))
         .streaming(stream)
 }
+
+/// One receiver per `RealtimeChannel` the session listens on.
+struct Receivers {
+    organization: Receiver<RealtimeEvent>,
+    user: Receiver<RealtimeEvent>,
+    member: Receiver<RealtimeEvent>,
+}
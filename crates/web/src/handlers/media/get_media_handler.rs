@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, http::header, web};
+use application::usecases::UsecaseError;
+use application::usecases::get_message_attachment_usecase::GetMessageAttachmentUsecase;
+use domain::{
+    repositories::message_repository::MessageRepository,
+    traits::{media_storage::MediaStorage, media_url_signer::MediaUrlSigner},
+};
+use serde::Deserialize;
+use tracing::error;
+
+#[derive(Debug, Deserialize)]
+pub struct MediaQuery {
+    pub token: Option<String>,
+}
+
+/// Serves a stored attachment to anyone holding a valid signed URL. Telnyx uses
+/// these to fetch outbound MMS media and the UI uses them for previews.
+pub async fn handle_get_media(
+    path: web::Path<uuid::Uuid>,
+    query: web::Query<MediaQuery>,
+    message_repository: web::Data<Arc<dyn MessageRepository>>,
+    media_storage: web::Data<Arc<dyn MediaStorage>>,
+    media_url_signer: web::Data<Arc<dyn MediaUrlSigner>>,
+) -> impl Responder {
+    let attachment_id = path.into_inner();
+
+    let Some(token) = query.token.as_deref() else {
+        return HttpResponse::Unauthorized().finish();
+    };
+    match media_url_signer.verify(token) {
+        Ok(signed_id) if signed_id == attachment_id => {}
+        _ => return HttpResponse::Forbidden().finish(),
+    }
+
+    let get_message_attachment_usecase = GetMessageAttachmentUsecase::builder()
+        .message_repository(message_repository.get_ref().clone())
+        .media_storage(media_storage.get_ref().clone())
+        .build();
+
+    match get_message_attachment_usecase.execute(attachment_id).await {
+        Ok(result) => {
+            // Inbound media comes from arbitrary senders, so only render types that
+            // cannot execute script inline and force everything else to download.
+            let disposition = if is_previewable(&result.attachment.content_type) {
+                "inline"
+            } else {
+                "attachment"
+            };
+
+            HttpResponse::Ok()
+                .insert_header((
+                    header::CONTENT_TYPE,
+                    result.attachment.content_type.as_str(),
+                ))
+                .insert_header((
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "{}; filename=\"{}\"",
+                        disposition,
+                        result
+                            .attachment
+                            .file_name
+                            .replace(['"', '\\', '\r', '\n'], "")
+                    ),
+                ))
+                .insert_header((header::X_CONTENT_TYPE_OPTIONS, "nosniff"))
+                .insert_header((header::CACHE_CONTROL, "private, max-age=3600"))
+                .body(result.bytes)
+        }
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!("failed to load media {}: {}", attachment_id, err);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
+
+fn is_previewable(content_type: &str) -> bool {
+    let content_type = content_type.to_ascii_lowercase();
+    !content_type.starts_with("image/svg")
+        && ["image/", "video/", "audio/"]
+            .iter()
+            .any(|prefix| content_type.starts_with(prefix))
+}
@@ -0,0 +1,9 @@
+pub mod get_media_handler;
+
+use actix_web::{dev::HttpServiceFactory, web};
+
+use crate::handlers::media::get_media_handler::handle_get_media;
+
+pub fn build_media_service() -> impl HttpServiceFactory {
+    web::scope("/media").route("/{id}", web::get().to(handle_get_media))
+}
@@ -1,6 +1,12 @@
 pub(crate) mod auth;
+pub(crate) mod campaigns;
+pub(crate) mod contacts;
 pub(crate) mod conversations;
 pub(crate) mod events;
 pub(crate) mod inertia;
+pub(crate) mod media;
+pub(crate) mod organizations;
 pub(crate) mod phone_numbers;
+pub(crate) mod templates;
+pub(crate) mod usage;
 pub(crate) mod webhooks;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::{
+    UsecaseError, accept_organization_invitation_usecase::AcceptOrganizationInvitationUsecase,
+};
+use domain::repositories::organization_invitation_repository::OrganizationInvitationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::user_repository::UserRepository;
+use domain::traits::token_service::TokenService;
+use tracing::error;
+
+use crate::{
+    dto::{AcceptOrganizationInvitationQuery, FlashProps},
+    flash::set_flash,
+    session::{session_user_id, set_organization_id},
+};
+
+/// Consume an invitation link - GET /organizations/invitations/accept?token=
+/// The joined organization becomes the active one; the outcome is reported through a flash message.
+pub async fn handle_accept_organization_invitation(
+    query: web::Query<AcceptOrganizationInvitationQuery>,
+    session: Session,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    organization_invitation_repository: web::Data<Arc<dyn OrganizationInvitationRepository>>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    token_service: web::Data<Arc<dyn TokenService>>,
+) -> impl Responder {
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let accept_organization_invitation_usecase = AcceptOrganizationInvitationUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .organization_invitation_repository(organization_invitation_repository.get_ref().clone())
+        .user_repository(user_repository.get_ref().clone())
+        .token_service(token_service.get_ref().clone())
+        .build();
+
+    let flash = match query.into_inner().token {
+        Some(token) => match accept_organization_invitation_usecase
+            .execute(user_id, token)
+            .await
+        {
+            Ok(organization) => {
+                set_organization_id(&session, &organization.id);
+                FlashProps::success(format!("You joined {}.", organization.name))
+            }
+            Err(err @ UsecaseError::InvalidInvitation) => FlashProps::error(err.to_http_message()),
+            Err(err) => {
+                error!(
+                    "failed to accept organization invitation for user {}: {}",
+                    user_id, err
+                );
+                FlashProps::error("We could not accept the invitation right now. Please try again.")
+            }
+        },
+        None => FlashProps::error(UsecaseError::InvalidInvitation.to_http_message()),
+    };
+
+    set_flash(&session, flash);
+
+    HttpResponse::Found()
+        .append_header((actix_web::http::header::LOCATION, "/"))
+        .finish()
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::ChangeOrganizationMemberRoleCommand;
+use application::usecases::UsecaseError;
+use application::usecases::change_organization_member_role_usecase::ChangeOrganizationMemberRoleUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use tracing::error;
+
+use crate::{
+    dto::{ChangeOrganizationMemberRoleRequest, OrganizationMemberProps},
+    handlers::organizations::ErrorResponse,
+    membership::CurrentMember,
+};
+
+pub async fn handle_change_organization_member_role(
+    path: web::Path<uuid::Uuid>,
+    role_req: web::Json<ChangeOrganizationMemberRoleRequest>,
+    member: CurrentMember,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let member_user_id = path.into_inner();
+
+    let change_organization_member_role_usecase = ChangeOrganizationMemberRoleUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = ChangeOrganizationMemberRoleCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        member_user_id,
+        role: role_req.into_inner().role,
+    };
+
+    match change_organization_member_role_usecase.execute(cmd).await {
+        Ok(updated) => HttpResponse::Ok().json(OrganizationMemberProps::from(&updated)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to change role of user {} in organization {}: {}",
+                member_user_id, member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
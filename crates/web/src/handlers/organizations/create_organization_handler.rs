@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::CreateOrganizationCommand;
+use application::usecases::UsecaseError;
+use application::usecases::create_organization_usecase::CreateOrganizationUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use tracing::error;
+
+use crate::{
+    dto::{CreateOrganizationRequest, OrganizationProps},
+    handlers::organizations::ErrorResponse,
+    session::{session_user_id, set_organization_id},
+};
+
+/// Creates an organization with the session user as its only member and switches to it.
+pub async fn handle_create_organization(
+    create_req: web::Json<CreateOrganizationRequest>,
+    session: Session,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let create_organization_usecase = CreateOrganizationUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = CreateOrganizationCommand {
+        user_id,
+        name: create_req.into_inner().name,
+    };
+
+    match create_organization_usecase.execute(cmd).await {
+        Ok(organization) => {
+            set_organization_id(&session, &organization.id);
+            HttpResponse::Created().json(OrganizationProps::new(&organization, organization.id))
+        }
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err) => {
+            error!(
+                "failed to create organization for user {}: {}",
+                user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to create the organization right now.".to_owned(),
+            })
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::InviteOrganizationMemberCommand;
+use application::usecases::UsecaseError;
+use application::usecases::invite_organization_member_usecase::InviteOrganizationMemberUsecase;
+use domain::repositories::organization_invitation_repository::OrganizationInvitationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::user_repository::UserRepository;
+use domain::traits::email_sender::EmailSender;
+use domain::traits::token_service::TokenService;
+use tracing::error;
+
+use crate::{
+    dto::{InviteOrganizationMemberRequest, OrganizationInvitationProps},
+    handlers::organizations::ErrorResponse,
+    membership::CurrentMember,
+    types::PublicBaseUrl,
+};
+
+pub async fn handle_invite_organization_member(
+    invite_req: web::Json<InviteOrganizationMemberRequest>,
+    member: CurrentMember,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    organization_invitation_repository: web::Data<Arc<dyn OrganizationInvitationRepository>>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    token_service: web::Data<Arc<dyn TokenService>>,
+    email_sender: web::Data<Arc<dyn EmailSender>>,
+    public_base_url: web::Data<PublicBaseUrl>,
+) -> impl Responder {
+    let invite_organization_member_usecase = InviteOrganizationMemberUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .organization_invitation_repository(organization_invitation_repository.get_ref().clone())
+        .user_repository(user_repository.get_ref().clone())
+        .token_service(token_service.get_ref().clone())
+        .email_sender(email_sender.get_ref().clone())
+        .public_base_url(public_base_url.0.clone())
+        .build();
+    let invite_req = invite_req.into_inner();
+    let cmd = InviteOrganizationMemberCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        email: invite_req.email,
+        role: invite_req.role,
+    };
+
+    match invite_organization_member_usecase.execute(cmd).await {
+        Ok(invitation) => {
+            HttpResponse::Created().json(OrganizationInvitationProps::from(&invitation))
+        }
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to invite member to organization {}: {}",
+                member.organization_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to send the invitation right now.".to_owned(),
+            })
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::list_organization_members_usecase::ListOrganizationMembersUsecase;
+use domain::repositories::organization_invitation_repository::OrganizationInvitationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use tracing::error;
+
+use crate::{dto::OrganizationMembersProps, membership::CurrentMember};
+
+pub async fn handle_list_organization_members(
+    member: CurrentMember,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    organization_invitation_repository: web::Data<Arc<dyn OrganizationInvitationRepository>>,
+) -> impl Responder {
+    let list_organization_members_usecase = ListOrganizationMembersUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .organization_invitation_repository(organization_invitation_repository.get_ref().clone())
+        .build();
+
+    match list_organization_members_usecase
+        .execute(member.organization_id)
+        .await
+    {
+        Ok(members) => HttpResponse::Ok().json(OrganizationMembersProps::from(&members)),
+        Err(err) => {
+            error!(
+                "failed to list members of organization {}: {}",
+                member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
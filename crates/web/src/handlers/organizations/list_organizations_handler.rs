@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::list_organizations_usecase::ListOrganizationsUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use tracing::error;
+
+use crate::{dto::OrganizationProps, membership::CurrentMember};
+
+pub async fn handle_list_organizations(
+    member: CurrentMember,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let list_organizations_usecase = ListOrganizationsUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    match list_organizations_usecase.execute(member.user_id).await {
+        Ok(organizations) => HttpResponse::Ok().json(
+            organizations
+                .iter()
+                .map(|organization| OrganizationProps::new(organization, member.organization_id))
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) => {
+            error!(
+                "failed to list organizations for user {}: {}",
+                member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -0,0 +1,66 @@
+pub mod accept_organization_invitation_handler;
+pub mod change_organization_member_role_handler;
+pub mod create_organization_handler;
+pub mod invite_organization_member_handler;
+pub mod list_organization_members_handler;
+pub mod list_organizations_handler;
+pub mod remove_organization_member_handler;
+pub mod revoke_organization_invitation_handler;
+pub mod switch_organization_handler;
+pub mod update_conversation_routing_handler;
+
+use actix_web::{dev::HttpServiceFactory, web};
+use serde::Serialize;
+
+use crate::handlers::organizations::{
+    accept_organization_invitation_handler::handle_accept_organization_invitation,
+    change_organization_member_role_handler::handle_change_organization_member_role,
+    create_organization_handler::handle_create_organization,
+    invite_organization_member_handler::handle_invite_organization_member,
+    list_organization_members_handler::handle_list_organization_members,
+    list_organizations_handler::handle_list_organizations,
+    remove_organization_member_handler::handle_remove_organization_member,
+    revoke_organization_invitation_handler::handle_revoke_organization_invitation,
+    switch_organization_handler::handle_switch_organization,
+    update_conversation_routing_handler::handle_update_conversation_routing,
+};
+use crate::middlewares::auth::ProtectedMiddleware;
+
+/// Members and invitations routes act on the organization the session is working in.
+pub fn build_organizations_service() -> impl HttpServiceFactory {
+    web::scope("/organizations")
+        .wrap(ProtectedMiddleware::new())
+        .route("", web::get().to(handle_list_organizations))
+        .route("", web::post().to(handle_create_organization))
+        .route("/members", web::get().to(handle_list_organization_members))
+        .route(
+            "/members/{user_id}",
+            web::delete().to(handle_remove_organization_member),
+        )
+        .route(
+            "/members/{user_id}/role",
+            web::put().to(handle_change_organization_member_role),
+        )
+        .route(
+            "/invitations",
+            web::post().to(handle_invite_organization_member),
+        )
+        .route(
+            "/invitations/accept",
+            web::get().to(handle_accept_organization_invitation),
+        )
+        .route(
+            "/invitations/{id}",
+            web::delete().to(handle_revoke_organization_invitation),
+        )
+        .route(
+            "/routing",
+            web::put().to(handle_update_conversation_routing),
+        )
+        .route("/{id}/switch", web::post().to(handle_switch_organization))
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::remove_organization_member_usecase::RemoveOrganizationMemberUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use tracing::error;
+
+use crate::{handlers::organizations::ErrorResponse, membership::CurrentMember};
+
+/// Removes a member from the active organization. Members may also remove themselves to
+/// leave without any permission; their session then falls back to another organization.
+pub async fn handle_remove_organization_member(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let user_id = path.into_inner();
+
+    let remove_organization_member_usecase = RemoveOrganizationMemberUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    match remove_organization_member_usecase
+        .execute(member.organization_id, member.user_id, user_id)
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to remove user {} from organization {}: {}",
+                user_id, member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
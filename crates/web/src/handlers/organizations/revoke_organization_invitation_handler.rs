@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::revoke_organization_invitation_usecase::RevokeOrganizationInvitationUsecase;
+use domain::repositories::organization_invitation_repository::OrganizationInvitationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use tracing::error;
+
+use crate::{handlers::organizations::ErrorResponse, membership::CurrentMember};
+
+pub async fn handle_revoke_organization_invitation(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+    organization_invitation_repository: web::Data<Arc<dyn OrganizationInvitationRepository>>,
+) -> impl Responder {
+    let invitation_id = path.into_inner();
+
+    let revoke_organization_invitation_usecase = RevokeOrganizationInvitationUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .organization_invitation_repository(organization_invitation_repository.get_ref().clone())
+        .build();
+
+    match revoke_organization_invitation_usecase
+        .execute(member.organization_id, member.user_id, invitation_id)
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to revoke invitation {} of organization {}: {}",
+                invitation_id, member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use domain::repositories::{RepositoryError, organization_repository::OrganizationRepository};
+use tracing::error;
+
+use crate::session::{session_user_id, set_organization_id};
+
+/// Makes the organization the one the session works in. Only members may switch to it.
+pub async fn handle_switch_organization(
+    path: web::Path<uuid::Uuid>,
+    session: Session,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let organization_id = path.into_inner();
+
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    match organization_repository
+        .find_member(&organization_id, &user_id)
+        .await
+    {
+        Ok(_) => {
+            set_organization_id(&session, &organization_id);
+            HttpResponse::NoContent().finish()
+        }
+        Err(RepositoryError::NotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to switch user {} to organization {}: {}",
+                user_id, organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
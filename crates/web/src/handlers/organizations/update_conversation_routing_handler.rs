@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::update_conversation_routing_usecase::UpdateConversationRoutingUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use tracing::error;
+
+use crate::{
+    dto::{OrganizationProps, UpdateConversationRoutingRequest},
+    handlers::organizations::ErrorResponse,
+    membership::CurrentMember,
+};
+
+/// Chooses how conversations of the active organization are assigned when a message
+/// arrives while nobody holds them.
+pub async fn handle_update_conversation_routing(
+    routing_req: web::Json<UpdateConversationRoutingRequest>,
+    member: CurrentMember,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let update_conversation_routing_usecase = UpdateConversationRoutingUsecase::builder()
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    match update_conversation_routing_usecase
+        .execute(
+            member.organization_id,
+            member.user_id,
+            routing_req.into_inner().conversation_routing,
+        )
+        .await
+    {
+        Ok(organization) => HttpResponse::Ok().json(OrganizationProps::new(
+            &organization,
+            member.organization_id,
+        )),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to update conversation routing of organization {}: {}",
+                member.organization_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
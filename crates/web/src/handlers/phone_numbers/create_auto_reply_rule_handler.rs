@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::CreateAutoReplyRuleCommand;
+use application::usecases::create_auto_reply_rule_usecase::CreateAutoReplyRuleUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::{
+    auto_reply_rule_repository::AutoReplyRuleRepository,
+    phone_number_repository::PhoneNumberRepository,
+};
+use tracing::error;
+
+use crate::{
+    dto::{AutoReplyRuleProps, AutoReplyRuleRequest},
+    handlers::phone_numbers::auto_reply_rule_save_error,
+    membership::CurrentMember,
+};
+
+pub async fn handle_create_auto_reply_rule(
+    path: web::Path<uuid::Uuid>,
+    create_req: web::Json<AutoReplyRuleRequest>,
+    member: CurrentMember,
+    auto_reply_rule_repository: web::Data<Arc<dyn AutoReplyRuleRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let phone_number_id = path.into_inner();
+
+    let create_auto_reply_rule_usecase = CreateAutoReplyRuleUsecase::builder()
+        .auto_reply_rule_repository(auto_reply_rule_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = CreateAutoReplyRuleCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        phone_number_id,
+        details: create_req.into_inner().into(),
+    };
+
+    match create_auto_reply_rule_usecase.execute(cmd).await {
+        Ok(result) => HttpResponse::Created().json(AutoReplyRuleProps::from(&result.rule)),
+        Err(err) => {
+            error!(
+                "failed to create auto-reply rule for phone number {} and user {}: {}",
+                phone_number_id, member.user_id, err
+            );
+            auto_reply_rule_save_error(&err)
+        }
+    }
+}
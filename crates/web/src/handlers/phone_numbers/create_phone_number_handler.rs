@@ -5,30 +5,31 @@ use actix_web::{HttpRequest, HttpResponse, Responder, http::header::LOCATION, we
 use application::commands::CreatePhoneNumberCommand;
 use application::usecases::UsecaseError;
 use application::usecases::create_phone_number_usecase::CreatePhoneNumberUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
 use domain::repositories::phone_number_repository::PhoneNumberRepository;
 use tracing::error;
 
 use crate::{
     dto::{CreatePhoneNumberRequest, CreatePhoneNumberResponse, FlashProps},
     flash::set_flash,
-    session::session_user_id,
+    membership::{CurrentMember, forbidden_or_redirect},
 };
 
 pub async fn handle_create_phone_number(
     req: HttpRequest,
     create_req: web::Json<CreatePhoneNumberRequest>,
     session: Session,
+    member: CurrentMember,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
 ) -> impl Responder {
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::Unauthorized().finish();
-    };
-
     let create_phone_number_usecase = CreatePhoneNumberUsecase::builder()
         .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
         .build();
     let cmd = CreatePhoneNumberCommand {
-        user_id,
+        user_id: member.user_id,
+        organization_id: member.organization_id,
         name: create_req.name.clone(),
         phone: create_req.phone.clone(),
     };
@@ -44,10 +45,11 @@ pub async fn handle_create_phone_number(
 
             HttpResponse::Created().json(CreatePhoneNumberResponse { id: result.id })
         }
+        Err(err @ UsecaseError::Forbidden(_)) => forbidden_or_redirect(&err, &req, &session, "/"),
         Err(err) => {
             error!(
                 "failed to create phone number for user {} and phone {}: {}",
-                user_id, create_req.phone, err
+                member.user_id, create_req.phone, err
             );
 
             match err {
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::delete_auto_reply_rule_usecase::DeleteAutoReplyRuleUsecase;
+use domain::repositories::auto_reply_rule_repository::AutoReplyRuleRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::phone_number_repository::PhoneNumberRepository;
+use tracing::error;
+
+use crate::{handlers::phone_numbers::ErrorResponse, membership::CurrentMember};
+
+pub async fn handle_delete_auto_reply_rule(
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+    member: CurrentMember,
+    auto_reply_rule_repository: web::Data<Arc<dyn AutoReplyRuleRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let (phone_number_id, rule_id) = path.into_inner();
+
+    let delete_auto_reply_rule_usecase = DeleteAutoReplyRuleUsecase::builder()
+        .auto_reply_rule_repository(auto_reply_rule_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    match delete_auto_reply_rule_usecase
+        .execute(
+            member.user_id,
+            member.organization_id,
+            phone_number_id,
+            rule_id,
+        )
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to delete auto-reply rule {} for user {}: {}",
+                rule_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
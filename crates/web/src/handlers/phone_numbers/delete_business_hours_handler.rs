@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::delete_business_hours_usecase::DeleteBusinessHoursUsecase;
+use domain::repositories::business_hours_repository::BusinessHoursRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::phone_number_repository::PhoneNumberRepository;
+use tracing::error;
+
+use crate::{handlers::phone_numbers::ErrorResponse, membership::CurrentMember};
+
+pub async fn handle_delete_business_hours(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    business_hours_repository: web::Data<Arc<dyn BusinessHoursRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let phone_number_id = path.into_inner();
+
+    let delete_business_hours_usecase = DeleteBusinessHoursUsecase::builder()
+        .business_hours_repository(business_hours_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+
+    match delete_business_hours_usecase
+        .execute(member.user_id, member.organization_id, phone_number_id)
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to delete business hours of phone number {} for user {}: {}",
+                phone_number_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -4,31 +4,33 @@ use actix_session::Session;
 use actix_web::{HttpRequest, HttpResponse, Responder, http::header::LOCATION, web};
 use application::usecases::UsecaseError;
 use application::usecases::delete_phone_number_usecase::DeletePhoneNumberUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
 use domain::repositories::phone_number_repository::PhoneNumberRepository;
 use tracing::error;
 
-use crate::{dto::FlashProps, flash::set_flash, session::session_user_id};
+use crate::{
+    dto::FlashProps,
+    flash::set_flash,
+    membership::{CurrentMember, forbidden_or_redirect},
+};
 
 pub async fn handle_delete_phone_number(
     req: HttpRequest,
     path: web::Path<uuid::Uuid>,
     session: Session,
+    member: CurrentMember,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
 ) -> impl Responder {
     let phone_number_id = path.into_inner();
 
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::SeeOther()
-            .append_header((LOCATION, "/auth/login"))
-            .finish();
-    };
-
     let delete_phone_number_usecase = DeletePhoneNumberUsecase::builder()
         .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
         .build();
 
     match delete_phone_number_usecase
-        .execute(user_id, phone_number_id)
+        .execute(member.user_id, member.organization_id, phone_number_id)
         .await
     {
         Ok(_) => {
@@ -51,10 +53,11 @@ pub async fn handle_delete_phone_number(
 
             HttpResponse::NotFound().finish()
         }
+        Err(err @ UsecaseError::Forbidden(_)) => forbidden_or_redirect(&err, &req, &session, "/"),
         Err(err) => {
             error!(
                 "failed to delete phone number {} for user {}: {}",
-                phone_number_id, user_id, err
+                phone_number_id, member.user_id, err
             );
             if req.headers().contains_key("x-inertia") {
                 set_flash(
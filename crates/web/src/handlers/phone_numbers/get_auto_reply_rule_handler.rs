@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::get_auto_reply_rule_usecase::GetAutoReplyRuleUsecase;
+use domain::repositories::auto_reply_rule_repository::AutoReplyRuleRepository;
+use domain::repositories::phone_number_repository::PhoneNumberRepository;
+use tracing::error;
+
+use crate::dto::AutoReplyRuleProps;
+use crate::membership::CurrentMember;
+
+pub async fn handle_get_auto_reply_rule(
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+    member: CurrentMember,
+    auto_reply_rule_repository: web::Data<Arc<dyn AutoReplyRuleRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+) -> impl Responder {
+    let (phone_number_id, rule_id) = path.into_inner();
+
+    let get_auto_reply_rule_usecase = GetAutoReplyRuleUsecase::builder()
+        .auto_reply_rule_repository(auto_reply_rule_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .build();
+
+    match get_auto_reply_rule_usecase
+        .execute(member.organization_id, phone_number_id, rule_id)
+        .await
+    {
+        Ok(rule) => HttpResponse::Ok().json(AutoReplyRuleProps::from(&rule)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to get auto-reply rule {} for user {}: {}",
+                rule_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
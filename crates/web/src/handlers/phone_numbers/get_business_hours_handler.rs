@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::get_business_hours_usecase::GetBusinessHoursUsecase;
+use domain::repositories::business_hours_repository::BusinessHoursRepository;
+use domain::repositories::phone_number_repository::PhoneNumberRepository;
+use tracing::error;
+
+use crate::dto::BusinessHoursProps;
+use crate::membership::CurrentMember;
+
+pub async fn handle_get_business_hours(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    business_hours_repository: web::Data<Arc<dyn BusinessHoursRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+) -> impl Responder {
+    let phone_number_id = path.into_inner();
+
+    let get_business_hours_usecase = GetBusinessHoursUsecase::builder()
+        .business_hours_repository(business_hours_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .build();
+
+    match get_business_hours_usecase
+        .execute(member.organization_id, phone_number_id)
+        .await
+    {
+        Ok(business_hours) => HttpResponse::Ok().json(BusinessHoursProps::from(&business_hours)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to get business hours of phone number {} for user {}: {}",
+                phone_number_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
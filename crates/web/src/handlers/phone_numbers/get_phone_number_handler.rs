@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use actix_session::Session;
 use actix_web::{HttpResponse, Responder, web};
 use application::usecases::UsecaseError;
 use application::usecases::get_phone_number_usecase::GetPhoneNumberUsecase;
@@ -8,25 +7,21 @@ use domain::repositories::phone_number_repository::PhoneNumberRepository;
 use tracing::error;
 
 use crate::dto::PhoneNumberProps;
-use crate::session::session_user_id;
+use crate::membership::CurrentMember;
 
 pub async fn handle_get_phone_number(
     path: web::Path<uuid::Uuid>,
-    session: Session,
+    member: CurrentMember,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
 ) -> impl Responder {
     let phone_number_id = path.into_inner();
 
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::Unauthorized().finish();
-    };
-
     let get_phone_number_usecase = GetPhoneNumberUsecase::builder()
         .phone_number_repository(phone_number_repository.get_ref().clone())
         .build();
 
     match get_phone_number_usecase
-        .execute(user_id, phone_number_id)
+        .execute(member.organization_id, phone_number_id)
         .await
     {
         Ok(phone_number) => HttpResponse::Ok().json(PhoneNumberProps::from(&phone_number)),
@@ -34,7 +29,7 @@ pub async fn handle_get_phone_number(
         Err(err) => {
             error!(
                 "failed to get phone number {} for user {}: {}",
-                phone_number_id, user_id, err
+                phone_number_id, member.user_id, err
             );
             HttpResponse::InternalServerError().finish()
         }
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::list_auto_reply_rules_usecase::ListAutoReplyRulesUsecase;
+use domain::repositories::{
+    auto_reply_rule_repository::AutoReplyRuleRepository,
+    phone_number_repository::PhoneNumberRepository,
+};
+use tracing::error;
+
+use crate::{dto::AutoReplyRuleProps, membership::CurrentMember};
+
+pub async fn handle_list_auto_reply_rules(
+    path: web::Path<uuid::Uuid>,
+    member: CurrentMember,
+    auto_reply_rule_repository: web::Data<Arc<dyn AutoReplyRuleRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+) -> impl Responder {
+    let phone_number_id = path.into_inner();
+
+    let list_auto_reply_rules_usecase = ListAutoReplyRulesUsecase::builder()
+        .auto_reply_rule_repository(auto_reply_rule_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .build();
+
+    match list_auto_reply_rules_usecase
+        .execute(member.organization_id, phone_number_id)
+        .await
+    {
+        Ok(rules) => HttpResponse::Ok().json(
+            rules
+                .iter()
+                .map(AutoReplyRuleProps::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to list auto-reply rules for phone number {} and user {}: {}",
+                phone_number_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -1,26 +1,24 @@
 use std::sync::Arc;
 
-use actix_session::Session;
 use actix_web::{HttpResponse, Responder, web};
 use application::usecases::list_phone_numbers_usecase::ListPhoneNumbersUsecase;
 use domain::repositories::phone_number_repository::PhoneNumberRepository;
 use tracing::error;
 
-use crate::{dto::PhoneNumberProps, session::session_user_id};
+use crate::{dto::PhoneNumberProps, membership::CurrentMember};
 
 pub async fn handle_list_phone_numbers(
-    session: Session,
+    member: CurrentMember,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
 ) -> impl Responder {
-    let Some(user_id) = session_user_id(&session) else {
-        return HttpResponse::Unauthorized().finish();
-    };
-
     let list_phone_numbers_usecase = ListPhoneNumbersUsecase::builder()
         .phone_number_repository(phone_number_repository.get_ref().clone())
         .build();
 
-    match list_phone_numbers_usecase.execute(user_id).await {
+    match list_phone_numbers_usecase
+        .execute(member.organization_id)
+        .await
+    {
         Ok(phone_numbers) => HttpResponse::Ok().json(
             phone_numbers
                 .iter()
@@ -28,7 +26,10 @@ pub async fn handle_list_phone_numbers(
                 .collect::<Vec<_>>(),
         ),
         Err(err) => {
-            error!("failed to list phone numbers for user {}: {}", user_id, err);
+            error!(
+                "failed to list phone numbers for user {}: {}",
+                member.user_id, err
+            );
             HttpResponse::InternalServerError().finish()
         }
     }
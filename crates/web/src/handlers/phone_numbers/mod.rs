@@ -1,15 +1,33 @@
+pub mod create_auto_reply_rule_handler;
 pub mod create_phone_number_handler;
+pub mod delete_auto_reply_rule_handler;
+pub mod delete_business_hours_handler;
 pub mod delete_phone_number_handler;
+pub mod get_auto_reply_rule_handler;
+pub mod get_business_hours_handler;
 pub mod get_phone_number_handler;
+pub mod list_auto_reply_rules_handler;
 pub mod list_phone_numbers_handler;
+pub mod save_business_hours_handler;
+pub mod update_auto_reply_rule_handler;
 
-use actix_web::{dev::HttpServiceFactory, web};
+use actix_web::{HttpResponse, dev::HttpServiceFactory, web};
+use application::usecases::UsecaseError;
+use serde::Serialize;
 
 use crate::handlers::phone_numbers::{
+    create_auto_reply_rule_handler::handle_create_auto_reply_rule,
     create_phone_number_handler::handle_create_phone_number,
+    delete_auto_reply_rule_handler::handle_delete_auto_reply_rule,
+    delete_business_hours_handler::handle_delete_business_hours,
     delete_phone_number_handler::handle_delete_phone_number,
+    get_auto_reply_rule_handler::handle_get_auto_reply_rule,
+    get_business_hours_handler::handle_get_business_hours,
     get_phone_number_handler::handle_get_phone_number,
+    list_auto_reply_rules_handler::handle_list_auto_reply_rules,
     list_phone_numbers_handler::handle_list_phone_numbers,
+    save_business_hours_handler::handle_save_business_hours,
+    update_auto_reply_rule_handler::handle_update_auto_reply_rule,
 };
 use crate::middlewares::auth::ProtectedMiddleware;
 
@@ -20,4 +38,57 @@ pub fn build_phone_numbers_service() -> impl HttpServiceFactory {
         .route("", web::post().to(handle_create_phone_number))
         .route("/{id}", web::get().to(handle_get_phone_number))
         .route("/{id}", web::delete().to(handle_delete_phone_number))
+        .route(
+            "/{id}/auto-replies",
+            web::get().to(handle_list_auto_reply_rules),
+        )
+        .route(
+            "/{id}/auto-replies",
+            web::post().to(handle_create_auto_reply_rule),
+        )
+        .route(
+            "/{id}/auto-replies/{rule_id}",
+            web::get().to(handle_get_auto_reply_rule),
+        )
+        .route(
+            "/{id}/auto-replies/{rule_id}",
+            web::put().to(handle_update_auto_reply_rule),
+        )
+        .route(
+            "/{id}/auto-replies/{rule_id}",
+            web::delete().to(handle_delete_auto_reply_rule),
+        )
+        .route(
+            "/{id}/business-hours",
+            web::get().to(handle_get_business_hours),
+        )
+        .route(
+            "/{id}/business-hours",
+            web::put().to(handle_save_business_hours),
+        )
+        .route(
+            "/{id}/business-hours",
+            web::delete().to(handle_delete_business_hours),
+        )
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Create and update fail the same ways, so they share one error mapping.
+fn auto_reply_rule_save_error(err: &UsecaseError) -> HttpResponse {
+    match err {
+        UsecaseError::Validation(_) => HttpResponse::UnprocessableEntity().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        UsecaseError::EntityNotFound => HttpResponse::NotFound().finish(),
+        UsecaseError::Forbidden(_) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        _ => HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "Unable to save auto-reply rule right now.".to_owned(),
+        }),
+    }
 }
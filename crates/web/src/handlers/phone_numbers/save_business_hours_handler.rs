@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::{BusinessHoursDetails, SaveBusinessHoursCommand};
+use application::usecases::UsecaseError;
+use application::usecases::save_business_hours_usecase::SaveBusinessHoursUsecase;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::{
+    business_hours_repository::BusinessHoursRepository,
+    phone_number_repository::PhoneNumberRepository,
+};
+use tracing::error;
+
+use crate::{
+    dto::{BusinessHoursProps, BusinessHoursRequest},
+    handlers::phone_numbers::ErrorResponse,
+    membership::CurrentMember,
+};
+
+pub async fn handle_save_business_hours(
+    path: web::Path<uuid::Uuid>,
+    save_req: web::Json<BusinessHoursRequest>,
+    member: CurrentMember,
+    business_hours_repository: web::Data<Arc<dyn BusinessHoursRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let phone_number_id = path.into_inner();
+
+    let details = match BusinessHoursDetails::try_from(save_req.into_inner()) {
+        Ok(details) => details,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
+    };
+
+    let save_business_hours_usecase = SaveBusinessHoursUsecase::builder()
+        .business_hours_repository(business_hours_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = SaveBusinessHoursCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        phone_number_id,
+        details,
+    };
+
+    match save_business_hours_usecase.execute(cmd).await {
+        Ok(business_hours) => HttpResponse::Ok().json(BusinessHoursProps::from(&business_hours)),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err @ UsecaseError::Forbidden(_)) => HttpResponse::Forbidden().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        Err(err) => {
+            error!(
+                "failed to save business hours of phone number {} for user {}: {}",
+                phone_number_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to save business hours right now.".to_owned(),
+            })
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::UpdateAutoReplyRuleCommand;
+use application::usecases::update_auto_reply_rule_usecase::UpdateAutoReplyRuleUsecase;
+use domain::repositories::auto_reply_rule_repository::AutoReplyRuleRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::phone_number_repository::PhoneNumberRepository;
+use tracing::error;
+
+use crate::{
+    dto::{AutoReplyRuleProps, AutoReplyRuleRequest},
+    handlers::phone_numbers::auto_reply_rule_save_error,
+    membership::CurrentMember,
+};
+
+pub async fn handle_update_auto_reply_rule(
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+    update_req: web::Json<AutoReplyRuleRequest>,
+    member: CurrentMember,
+    auto_reply_rule_repository: web::Data<Arc<dyn AutoReplyRuleRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    organization_repository: web::Data<Arc<dyn OrganizationRepository>>,
+) -> impl Responder {
+    let (phone_number_id, rule_id) = path.into_inner();
+
+    let update_auto_reply_rule_usecase = UpdateAutoReplyRuleUsecase::builder()
+        .auto_reply_rule_repository(auto_reply_rule_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .organization_repository(organization_repository.get_ref().clone())
+        .build();
+    let cmd = UpdateAutoReplyRuleCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        phone_number_id,
+        rule_id,
+        details: update_req.into_inner().into(),
+    };
+
+    match update_auto_reply_rule_usecase.execute(cmd).await {
+        Ok(rule) => HttpResponse::Ok().json(AutoReplyRuleProps::from(&rule)),
+        Err(err) => {
+            error!(
+                "failed to update auto-reply rule {} for user {}: {}",
+                rule_id, member.user_id, err
+            );
+            auto_reply_rule_save_error(&err)
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::CreateMessageTemplateCommand;
+use application::usecases::create_message_template_usecase::CreateMessageTemplateUsecase;
+use domain::repositories::message_template_repository::MessageTemplateRepository;
+use tracing::error;
+
+use crate::{
+    dto::{MessageTemplateProps, MessageTemplateRequest},
+    handlers::templates::template_save_error,
+    session::session_user_id,
+};
+
+pub async fn handle_create_message_template(
+    create_req: web::Json<MessageTemplateRequest>,
+    session: Session,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+) -> impl Responder {
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let create_message_template_usecase = CreateMessageTemplateUsecase::builder()
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .build();
+    let cmd = CreateMessageTemplateCommand {
+        user_id,
+        details: create_req.into_inner().into(),
+    };
+
+    match create_message_template_usecase.execute(cmd).await {
+        Ok(result) => HttpResponse::Created().json(MessageTemplateProps::from(&result.template)),
+        Err(err) => {
+            error!("failed to create template for user {}: {}", user_id, err);
+            template_save_error(&err)
+        }
+    }
+}
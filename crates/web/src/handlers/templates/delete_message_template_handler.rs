@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::UsecaseError;
+use application::usecases::delete_message_template_usecase::DeleteMessageTemplateUsecase;
+use domain::repositories::message_template_repository::MessageTemplateRepository;
+use tracing::error;
+
+use crate::session::session_user_id;
+
+pub async fn handle_delete_message_template(
+    path: web::Path<uuid::Uuid>,
+    session: Session,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+) -> impl Responder {
+    let template_id = path.into_inner();
+
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let delete_message_template_usecase = DeleteMessageTemplateUsecase::builder()
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .build();
+
+    match delete_message_template_usecase
+        .execute(user_id, template_id)
+        .await
+    {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to delete template {} for user {}: {}",
+                template_id, user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
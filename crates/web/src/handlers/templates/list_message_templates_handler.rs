@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::list_message_templates_usecase::ListMessageTemplatesUsecase;
+use domain::repositories::message_template_repository::MessageTemplateRepository;
+use tracing::error;
+
+use crate::{dto::MessageTemplateProps, session::session_user_id};
+
+pub async fn handle_list_message_templates(
+    session: Session,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+) -> impl Responder {
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let list_message_templates_usecase = ListMessageTemplatesUsecase::builder()
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .build();
+
+    match list_message_templates_usecase.execute(user_id).await {
+        Ok(templates) => HttpResponse::Ok().json(
+            templates
+                .iter()
+                .map(MessageTemplateProps::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) => {
+            error!("failed to list templates for user {}: {}", user_id, err);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
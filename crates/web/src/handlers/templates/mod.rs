@@ -0,0 +1,52 @@
+pub mod create_message_template_handler;
+pub mod delete_message_template_handler;
+pub mod get_message_template_handler;
+pub mod list_message_templates_handler;
+pub mod preview_message_template_handler;
+pub mod update_message_template_handler;
+
+use actix_web::{HttpResponse, dev::HttpServiceFactory, web};
+use application::usecases::UsecaseError;
+use serde::Serialize;
+
+use crate::handlers::templates::{
+    create_message_template_handler::handle_create_message_template,
+    delete_message_template_handler::handle_delete_message_template,
+    get_message_template_handler::handle_get_message_template,
+    list_message_templates_handler::handle_list_message_templates,
+    preview_message_template_handler::handle_preview_message_template,
+    update_message_template_handler::handle_update_message_template,
+};
+use crate::middlewares::auth::ProtectedMiddleware;
+
+pub fn build_templates_service() -> impl HttpServiceFactory {
+    web::scope("/templates")
+        .wrap(ProtectedMiddleware::new())
+        .route("", web::get().to(handle_list_message_templates))
+        .route("", web::post().to(handle_create_message_template))
+        .route("/{id}", web::get().to(handle_get_message_template))
+        .route("/{id}", web::put().to(handle_update_message_template))
+        .route("/{id}", web::delete().to(handle_delete_message_template))
+        .route(
+            "/{id}/preview",
+            web::get().to(handle_preview_message_template),
+        )
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Create and update fail the same ways, so they share one error mapping.
+fn template_save_error(err: &UsecaseError) -> HttpResponse {
+    match err {
+        UsecaseError::Validation(_) => HttpResponse::UnprocessableEntity().json(ErrorResponse {
+            error: err.to_http_message(),
+        }),
+        UsecaseError::EntityNotFound => HttpResponse::NotFound().finish(),
+        _ => HttpResponse::InternalServerError().json(ErrorResponse {
+            error: "Unable to save template right now.".to_owned(),
+        }),
+    }
+}
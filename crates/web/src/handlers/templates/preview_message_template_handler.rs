@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::PreviewMessageTemplateCommand;
+use application::usecases::UsecaseError;
+use application::usecases::preview_message_template_usecase::PreviewMessageTemplateUsecase;
+use domain::repositories::{
+    contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+    message_template_repository::MessageTemplateRepository,
+    phone_number_repository::PhoneNumberRepository,
+};
+use serde::Deserialize;
+use tracing::error;
+
+use crate::{dto::MessageTemplatePreviewProps, membership::CurrentMember};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewMessageTemplateQuery {
+    conversation_id: Option<uuid::Uuid>,
+}
+
+pub async fn handle_preview_message_template(
+    path: web::Path<uuid::Uuid>,
+    query: web::Query<PreviewMessageTemplateQuery>,
+    member: CurrentMember,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+    conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
+    phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    contact_repository: web::Data<Arc<dyn ContactRepository>>,
+) -> impl Responder {
+    let template_id = path.into_inner();
+
+    let preview_message_template_usecase = PreviewMessageTemplateUsecase::builder()
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .conversation_repository(conversation_repository.get_ref().clone())
+        .phone_number_repository(phone_number_repository.get_ref().clone())
+        .contact_repository(contact_repository.get_ref().clone())
+        .build();
+    let cmd = PreviewMessageTemplateCommand {
+        user_id: member.user_id,
+        organization_id: member.organization_id,
+        template_id,
+        conversation_id: query.into_inner().conversation_id,
+    };
+
+    match preview_message_template_usecase.execute(cmd).await {
+        Ok(preview) => HttpResponse::Ok().json(MessageTemplatePreviewProps::from(&preview)),
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!(
+                "failed to preview template {} for user {}: {}",
+                template_id, member.user_id, err
+            );
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
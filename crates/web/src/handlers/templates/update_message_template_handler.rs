@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::commands::UpdateMessageTemplateCommand;
+use application::usecases::update_message_template_usecase::UpdateMessageTemplateUsecase;
+use domain::repositories::message_template_repository::MessageTemplateRepository;
+use tracing::error;
+
+use crate::{
+    dto::{MessageTemplateProps, MessageTemplateRequest},
+    handlers::templates::template_save_error,
+    session::session_user_id,
+};
+
+pub async fn handle_update_message_template(
+    path: web::Path<uuid::Uuid>,
+    update_req: web::Json<MessageTemplateRequest>,
+    session: Session,
+    message_template_repository: web::Data<Arc<dyn MessageTemplateRepository>>,
+) -> impl Responder {
+    let template_id = path.into_inner();
+
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let update_message_template_usecase = UpdateMessageTemplateUsecase::builder()
+        .message_template_repository(message_template_repository.get_ref().clone())
+        .build();
+    let cmd = UpdateMessageTemplateCommand {
+        user_id,
+        template_id,
+        details: update_req.into_inner().into(),
+    };
+
+    match update_message_template_usecase.execute(cmd).await {
+        Ok(template) => HttpResponse::Ok().json(MessageTemplateProps::from(&template)),
+        Err(err) => {
+            error!(
+                "failed to update template {} for user {}: {}",
+                template_id, user_id, err
+            );
+            template_save_error(&err)
+        }
+    }
+}
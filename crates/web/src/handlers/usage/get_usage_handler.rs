@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::get_usage_usecase::GetUsageUsecase;
+use domain::{
+    models::usage::UsagePeriod,
+    repositories::{
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+    },
+};
+use tracing::error;
+
+use crate::{
+    dto::{UsageQuery, UsageReportProps},
+    handlers::usage::parse_period,
+    session::session_user_id,
+};
+
+/// Usage rollups and limits - GET /usage?period=daily|monthly
+pub async fn handle_get_usage(
+    query: web::Query<UsageQuery>,
+    session: Session,
+    usage_repository: web::Data<Arc<dyn UsageRepository>>,
+    usage_limit_repository: web::Data<Arc<dyn UsageLimitRepository>>,
+) -> impl Responder {
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let period = match query.period.as_deref() {
+        None => UsagePeriod::Monthly,
+        Some(value) => match parse_period(value) {
+            Ok(period) => period,
+            Err(response) => return response,
+        },
+    };
+
+    let get_usage_usecase = GetUsageUsecase::builder()
+        .usage_repository(usage_repository.get_ref().clone())
+        .usage_limit_repository(usage_limit_repository.get_ref().clone())
+        .build();
+
+    match get_usage_usecase.execute(user_id, period).await {
+        Ok(report) => HttpResponse::Ok().json(UsageReportProps::from(&report)),
+        Err(err) => {
+            error!("failed to get usage for user {}: {}", user_id, err);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -0,0 +1,76 @@
+pub mod get_usage_handler;
+pub mod save_usage_limit_handler;
+pub mod save_user_usage_limit_handler;
+
+use actix_web::{HttpResponse, dev::HttpServiceFactory, web};
+use application::usecases::UsecaseError;
+use domain::models::usage::{UsageLimit, UsagePeriod};
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::UsageLimitProps,
+    handlers::usage::{
+        get_usage_handler::handle_get_usage, save_usage_limit_handler::handle_save_usage_limit,
+        save_user_usage_limit_handler::handle_save_user_usage_limit,
+    },
+    middlewares::auth::ProtectedMiddleware,
+};
+
+/// The operator route authenticates with `OPS_API_TOKEN` instead of a session, so the
+/// session check is applied per route.
+pub fn build_usage_service() -> impl HttpServiceFactory {
+    web::scope("/usage")
+        .route(
+            "",
+            web::get()
+                .to(handle_get_usage)
+                .wrap(ProtectedMiddleware::new()),
+        )
+        .route(
+            "/limits/{period}",
+            web::put()
+                .to(handle_save_usage_limit)
+                .wrap(ProtectedMiddleware::new()),
+        )
+        .route(
+            "/users/{user_id}/limits/{period}",
+            web::put().to(handle_save_user_usage_limit),
+        )
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+fn parse_period(value: &str) -> Result<UsagePeriod, HttpResponse> {
+    UsagePeriod::parse(value).ok_or_else(|| {
+        HttpResponse::BadRequest().json(ErrorResponse {
+            error: "period must be daily or monthly".to_owned(),
+        })
+    })
+}
+
+/// User and operator updates answer the same way.
+fn usage_limit_saved_response(
+    result: Result<Option<UsageLimit>, UsecaseError>,
+    user_id: uuid::Uuid,
+) -> HttpResponse {
+    match result {
+        Ok(Some(limit)) => HttpResponse::Ok().json(UsageLimitProps::from(&limit)),
+        Ok(None) => HttpResponse::NoContent().finish(),
+        Err(err @ UsecaseError::Validation(_)) => {
+            HttpResponse::UnprocessableEntity().json(ErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().finish(),
+        Err(err) => {
+            error!("failed to save usage limit for user {}: {}", user_id, err);
+            HttpResponse::InternalServerError().json(ErrorResponse {
+                error: "Unable to save the usage limit right now.".to_owned(),
+            })
+        }
+    }
+}
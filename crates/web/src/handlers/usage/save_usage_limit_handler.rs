@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use actix_session::Session;
+use actix_web::{HttpResponse, Responder, web};
+use application::usecases::save_usage_limit_usecase::SaveUsageLimitUsecase;
+use domain::repositories::{
+    usage_limit_repository::UsageLimitRepository, user_repository::UserRepository,
+};
+
+use crate::{
+    dto::UsageLimitRequest,
+    handlers::usage::{ErrorResponse, parse_period, usage_limit_saved_response},
+    session::session_user_id,
+};
+
+/// Set or clear the caller's spending limits - PUT /usage/limits/{period}
+pub async fn handle_save_usage_limit(
+    path: web::Path<String>,
+    save_req: web::Json<UsageLimitRequest>,
+    session: Session,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    usage_limit_repository: web::Data<Arc<dyn UsageLimitRepository>>,
+) -> impl Responder {
+    let Some(user_id) = session_user_id(&session) else {
+        return HttpResponse::Unauthorized().finish();
+    };
+
+    let period = match parse_period(&path.into_inner()) {
+        Ok(period) => period,
+        Err(response) => return response,
+    };
+    let cmd = match save_req.into_inner().into_command(user_id, period) {
+        Ok(cmd) => cmd,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
+    };
+
+    let save_usage_limit_usecase = SaveUsageLimitUsecase::builder()
+        .user_repository(user_repository.get_ref().clone())
+        .usage_limit_repository(usage_limit_repository.get_ref().clone())
+        .build();
+
+    usage_limit_saved_response(save_usage_limit_usecase.execute(cmd).await, user_id)
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use application::usecases::save_usage_limit_usecase::SaveUsageLimitUsecase;
+use domain::repositories::{
+    usage_limit_repository::UsageLimitRepository, user_repository::UserRepository,
+};
+use tracing::info;
+
+use crate::{
+    dto::UsageLimitRequest,
+    handlers::{
+        usage::{ErrorResponse, parse_period, usage_limit_saved_response},
+        webhooks::authorize_ops_request,
+    },
+    types::OpsApiToken,
+};
+
+/// Set or clear any user's spending limits, e.g. to lift a hard limit before the period
+/// resets - PUT /usage/users/{user_id}/limits/{period}
+pub async fn handle_save_user_usage_limit(
+    req: HttpRequest,
+    path: web::Path<(uuid::Uuid, String)>,
+    save_req: web::Json<UsageLimitRequest>,
+    ops_api_token: web::Data<OpsApiToken>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    usage_limit_repository: web::Data<Arc<dyn UsageLimitRepository>>,
+) -> impl Responder {
+    if let Err(response) = authorize_ops_request(&req, &ops_api_token) {
+        return response;
+    }
+
+    let (user_id, period) = path.into_inner();
+    let period = match parse_period(&period) {
+        Ok(period) => period,
+        Err(response) => return response,
+    };
+    let cmd = match save_req.into_inner().into_command(user_id, period) {
+        Ok(cmd) => cmd,
+        Err(message) => {
+            return HttpResponse::UnprocessableEntity().json(ErrorResponse { error: message });
+        }
+    };
+
+    let save_usage_limit_usecase = SaveUsageLimitUsecase::builder()
+        .user_repository(user_repository.get_ref().clone())
+        .usage_limit_repository(usage_limit_repository.get_ref().clone())
+        .build();
+
+    let result = save_usage_limit_usecase.execute(cmd).await;
+    if result.is_ok() {
+        info!(
+            "operator updated the {} usage limit of user {}",
+            period.name(),
+            user_id
+        );
+    }
+
+    usage_limit_saved_response(result, user_id)
+}
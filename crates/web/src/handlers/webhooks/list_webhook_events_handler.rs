@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use application::usecases::list_webhook_events_usecase::ListWebhookEventsUsecase;
+use domain::{
+    models::processed_webhook_event::WebhookEventStatus,
+    repositories::processed_webhook_event_repository::ProcessedWebhookEventRepository,
+};
+use serde::Deserialize;
+use tracing::error;
+
+use crate::{
+    dto::{WebhookEventProps, webhook_event::parse_webhook_event_status},
+    handlers::webhooks::authorize_ops_request,
+    types::{OpsApiToken, WebErrorResponse},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ListWebhookEventsQuery {
+    status: Option<String>,
+    limit: Option<usize>,
+}
+
+/// List stored webhook events - GET /webhooks/events?status=dead_lettered&limit=50
+pub async fn handle_list_webhook_events(
+    req: HttpRequest,
+    query: web::Query<ListWebhookEventsQuery>,
+    ops_api_token: web::Data<OpsApiToken>,
+    processed_webhook_event_repository: web::Data<Arc<dyn ProcessedWebhookEventRepository>>,
+) -> impl Responder {
+    if let Err(response) = authorize_ops_request(&req, &ops_api_token) {
+        return response;
+    }
+
+    let query = query.into_inner();
+    let status = match query.status.as_deref() {
+        None => WebhookEventStatus::DeadLettered,
+        Some(value) => match parse_webhook_event_status(value) {
+            Some(status) => status,
+            None => {
+                return HttpResponse::BadRequest().json(WebErrorResponse {
+                    error: "status must be one of pending, processed, parked, dead_lettered"
+                        .to_owned(),
+                });
+            }
+        },
+    };
+
+    let list_webhook_events_usecase = ListWebhookEventsUsecase::builder()
+        .processed_webhook_event_repository(processed_webhook_event_repository.get_ref().clone())
+        .build();
+
+    match list_webhook_events_usecase
+        .execute(status, query.limit)
+        .await
+    {
+        Ok(events) => HttpResponse::Ok().json(
+            events
+                .iter()
+                .map(WebhookEventProps::from)
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) => {
+            error!("failed to list webhook events: {}", err);
+            HttpResponse::InternalServerError().json(WebErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+    }
+}
@@ -1,12 +1,53 @@
+pub mod list_webhook_events_handler;
+pub mod replay_webhook_event_handler;
 pub mod telnyx_messaging_webhook_handler;
 
-use actix_web::{dev::HttpServiceFactory, web};
+use actix_web::{
+    HttpRequest, HttpResponse, dev::HttpServiceFactory, http::header::AUTHORIZATION, web,
+};
 
-use crate::handlers::webhooks::telnyx_messaging_webhook_handler::handle_telnyx_messaging_webhook;
+use crate::{
+    handlers::webhooks::{
+        list_webhook_events_handler::handle_list_webhook_events,
+        replay_webhook_event_handler::handle_replay_webhook_event,
+        telnyx_messaging_webhook_handler::handle_telnyx_messaging_webhook,
+    },
+    types::OpsApiToken,
+};
 
 pub fn build_webhooks_service() -> impl HttpServiceFactory {
-    web::scope("/webhooks").route(
-        "/telnyx/messaging",
-        web::post().to(handle_telnyx_messaging_webhook),
-    )
+    web::scope("/webhooks")
+        .route(
+            "/telnyx/messaging",
+            web::post().to(handle_telnyx_messaging_webhook),
+        )
+        .route("/events", web::get().to(handle_list_webhook_events))
+        .route(
+            "/events/{event_id}/replay",
+            web::post().to(handle_replay_webhook_event),
+        )
+}
+
+/// Operator endpoints answer 404 unless `OPS_API_TOKEN` is configured, and 401 unless the
+/// request carries it as a bearer token.
+pub(crate) fn authorize_ops_request(
+    req: &HttpRequest,
+    ops_api_token: &OpsApiToken,
+) -> Result<(), HttpResponse> {
+    if ops_api_token.0.is_none() {
+        return Err(HttpResponse::NotFound().finish());
+    }
+
+    let candidate = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+
+    if ops_api_token.authorizes(candidate) {
+        Ok(())
+    } else {
+        Err(HttpResponse::Unauthorized().finish())
+    }
 }
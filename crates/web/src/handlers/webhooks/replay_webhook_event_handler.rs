@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+use application::usecases::{
+    UsecaseError, replay_webhook_event_usecase::ReplayWebhookEventUsecase,
+};
+use domain::repositories::processed_webhook_event_repository::ProcessedWebhookEventRepository;
+use tracing::{error, info};
+
+use crate::{
+    handlers::webhooks::authorize_ops_request,
+    types::{OpsApiToken, WebErrorResponse},
+};
+
+/// Requeue a dead-lettered webhook event - POST /webhooks/events/{event_id}/replay
+pub async fn handle_replay_webhook_event(
+    req: HttpRequest,
+    path: web::Path<String>,
+    ops_api_token: web::Data<OpsApiToken>,
+    processed_webhook_event_repository: web::Data<Arc<dyn ProcessedWebhookEventRepository>>,
+) -> impl Responder {
+    if let Err(response) = authorize_ops_request(&req, &ops_api_token) {
+        return response;
+    }
+
+    let event_id = path.into_inner();
+    let replay_webhook_event_usecase = ReplayWebhookEventUsecase::builder()
+        .processed_webhook_event_repository(processed_webhook_event_repository.get_ref().clone())
+        .build();
+
+    match replay_webhook_event_usecase.execute(&event_id).await {
+        Ok(()) => {
+            info!("requeued dead-lettered Telnyx webhook {}", event_id);
+            HttpResponse::Accepted().finish()
+        }
+        Err(UsecaseError::EntityNotFound) => HttpResponse::NotFound().json(WebErrorResponse {
+            error: "No dead-lettered webhook event with this id".to_owned(),
+        }),
+        Err(err) => {
+            error!("failed to replay webhook event {}: {}", event_id, err);
+            HttpResponse::InternalServerError().json(WebErrorResponse {
+                error: err.to_http_message(),
+            })
+        }
+    }
+}
@@ -19,6 +19,7 @@ impl<'a, P> Page<'a, P>
 where
     P: Serialize,
 {
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_responder(self) -> impl Responder {
         if self.req.headers().contains_key("x-inertia") {
             InertiaResponder::new(self.name, self.props).respond_to(&self.req)
@@ -1,13 +1,26 @@
+// Actix handlers take one argument per extractor, so long parameter lists are expected.
+#![allow(clippy::too_many_arguments)]
+
+pub mod campaign_worker;
+pub mod contact_import_worker;
+pub(crate) mod contact_names;
 pub mod dto;
 pub(crate) mod flash;
 pub(crate) mod handlers;
 pub(crate) mod inertia;
+pub(crate) mod member_names;
+pub(crate) mod membership;
 pub(crate) mod middlewares;
+pub mod outbound_dispatcher;
 pub mod realtime;
 pub mod server;
 pub(crate) mod session;
+pub mod snooze_worker;
 pub mod types;
+pub(crate) mod unread_counts;
+pub mod usage_alert_worker;
 pub(crate) mod webhook_forwarding;
+pub mod webhook_inbox;
 
 use serde::{Serialize, Serializer, ser::SerializeMap};
 
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use application::usecases::list_organization_members_usecase::ListOrganizationMembersUsecase;
+use domain::repositories::{
+    organization_invitation_repository::OrganizationInvitationRepository,
+    organization_repository::OrganizationRepository,
+};
+use tracing::warn;
+
+use crate::{dto::OrganizationMemberProps, membership::CurrentMember};
+
+/// Members of the current organization, so that pages can name conversation assignees.
+/// A failed lookup only costs the names, so it is logged and no members are listed.
+pub(crate) async fn organization_member_props(
+    organization_repository: &Arc<dyn OrganizationRepository>,
+    organization_invitation_repository: &Arc<dyn OrganizationInvitationRepository>,
+    member: &CurrentMember,
+) -> Vec<OrganizationMemberProps> {
+    match ListOrganizationMembersUsecase::builder()
+        .organization_repository(organization_repository.clone())
+        .organization_invitation_repository(organization_invitation_repository.clone())
+        .build()
+        .execute(member.organization_id)
+        .await
+    {
+        Ok(members) => members
+            .members
+            .iter()
+            .map(OrganizationMemberProps::from)
+            .collect(),
+        Err(err) => {
+            warn!(
+                "failed to list members of organization {}: {}",
+                member.organization_id, err
+            );
+            Vec::new()
+        }
+    }
+}
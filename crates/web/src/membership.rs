@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use actix_session::{Session, SessionExt};
+use actix_web::{
+    FromRequest, HttpRequest, HttpResponse,
+    dev::Payload,
+    error::{ErrorInternalServerError, ErrorUnauthorized},
+    http::header::LOCATION,
+    web,
+};
+use application::usecases::UsecaseError;
+use domain::repositories::{RepositoryError, organization_repository::OrganizationRepository};
+use futures_util::future::LocalBoxFuture;
+use serde::Serialize;
+use tracing::error;
+
+use crate::{
+    dto::FlashProps,
+    flash::set_flash,
+    session::{get_organization_id, session_user_id, set_organization_id},
+};
+
+/// The signed-in user and the organization whose phone numbers and inboxes they work on.
+/// Extracting it answers 401 when there is no session user or the user has no organization.
+/// Permissions are not checked here; the usecases check them against the member's role.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CurrentMember {
+    pub user_id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+}
+
+/// Answers a [`UsecaseError::Forbidden`] with 403 and a JSON error, or for Inertia visits
+/// sends the user back to `location` with a flash message instead.
+pub(crate) fn forbidden_or_redirect(
+    err: &UsecaseError,
+    req: &HttpRequest,
+    session: &Session,
+    location: &str,
+) -> HttpResponse {
+    if !req.headers().contains_key("x-inertia") {
+        return HttpResponse::Forbidden().json(ForbiddenResponse {
+            error: err.to_http_message(),
+        });
+    }
+
+    set_flash(session, FlashProps::error(err.to_http_message()));
+    HttpResponse::SeeOther()
+        .append_header((LOCATION, location))
+        .finish()
+}
+
+#[derive(Debug, Serialize)]
+struct ForbiddenResponse {
+    error: String,
+}
+
+impl FromRequest for CurrentMember {
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let session = req.get_session();
+        let organization_repository = req
+            .app_data::<web::Data<Arc<dyn OrganizationRepository>>>()
+            .map(|repository| repository.get_ref().clone());
+
+        Box::pin(async move {
+            let Some(organization_repository) = organization_repository else {
+                error!("organization repository is not registered");
+                return Err(ErrorInternalServerError("organization lookup unavailable"));
+            };
+
+            current_member(&session, &organization_repository).await
+        })
+    }
+}
+
+/// Resolves the organization the session user is acting in. The last switched-to
+/// organization is re-checked on every request so removed members lose access straight
+/// away; without a valid choice the user's oldest organization is used and remembered.
+async fn current_member(
+    session: &Session,
+    organization_repository: &Arc<dyn OrganizationRepository>,
+) -> Result<CurrentMember, actix_web::Error> {
+    let Some(user_id) = session_user_id(session) else {
+        return Err(ErrorUnauthorized("not signed in"));
+    };
+
+    if let Some(organization_id) = get_organization_id(session) {
+        match organization_repository
+            .find_member(&organization_id, &user_id)
+            .await
+        {
+            Ok(_) => {
+                return Ok(CurrentMember {
+                    user_id,
+                    organization_id,
+                });
+            }
+            Err(RepositoryError::NotFound) => {}
+            Err(err) => {
+                error!(
+                    "failed to check membership of user {} in organization {}: {}",
+                    user_id, organization_id, err
+                );
+                return Err(ErrorInternalServerError("organization lookup failed"));
+            }
+        }
+    }
+
+    let organizations = organization_repository
+        .list_by_user_id(&user_id)
+        .await
+        .map_err(|err| {
+            error!("failed to list organizations for user {}: {}", user_id, err);
+            ErrorInternalServerError("organization lookup failed")
+        })?;
+    let Some(organization) = organizations.first() else {
+        return Err(ErrorUnauthorized("no organization"));
+    };
+    set_organization_id(session, &organization.id);
+
+    Ok(CurrentMember {
+        user_id,
+        organization_id: organization.id,
+    })
+}
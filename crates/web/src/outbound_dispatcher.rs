@@ -0,0 +1,115 @@
+use std::{sync::Arc, time::Duration};
+
+use application::usecases::dispatch_outbound_messages_usecase::DispatchOutboundMessagesUsecase;
+use domain::{
+    repositories::{
+        contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+        message_repository::MessageRepository,
+        message_status_event_repository::MessageStatusEventRepository,
+        opt_out_repository::OptOutRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+    },
+    traits::{media_url_signer::MediaUrlSigner, outbound_message_service::OutboundMessageService},
+};
+use time::OffsetDateTime;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, warn};
+
+use crate::{
+    contact_names::conversation_props_with_contact,
+    dto::{MessageEventProps, MessageProps},
+    realtime::{MessageEventBroadcaster, RealtimeChannel},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Background worker that drains the outbound message queue and pushes the resulting
+/// status changes to connected clients. Safe to run in several processes at once.
+#[derive(bon::Builder)]
+pub struct OutboundMessageDispatcher {
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    message_repository: Arc<dyn MessageRepository>,
+    message_status_event_repository: Arc<dyn MessageStatusEventRepository>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    outbound_message_service: Arc<dyn OutboundMessageService>,
+    media_url_signer: Arc<dyn MediaUrlSigner>,
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    message_event_broadcaster: Arc<MessageEventBroadcaster>,
+    /// Used to estimate the cost of a sent message until Telnyx reports it.
+    segment_price_micros: Option<i64>,
+    #[builder(default = POLL_INTERVAL)]
+    poll_interval: Duration,
+}
+
+impl OutboundMessageDispatcher {
+    pub async fn run(self) {
+        let usecase = DispatchOutboundMessagesUsecase::builder()
+            .outbound_message_queue_repository(self.outbound_message_queue_repository.clone())
+            .message_repository(self.message_repository.clone())
+            .message_status_event_repository(self.message_status_event_repository.clone())
+            .conversation_repository(self.conversation_repository.clone())
+            .opt_out_repository(self.opt_out_repository.clone())
+            .outbound_message_service(self.outbound_message_service.clone())
+            .media_url_signer(self.media_url_signer.clone())
+            .usage_repository(self.usage_repository.clone())
+            .usage_limit_repository(self.usage_limit_repository.clone())
+            .maybe_segment_price_micros(self.segment_price_micros)
+            .build();
+
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            self.dispatch_due(&usecase).await;
+        }
+    }
+
+    async fn dispatch_due(&self, usecase: &DispatchOutboundMessagesUsecase) {
+        let result = match usecase.execute(OffsetDateTime::now_utc()).await {
+            Ok(result) => result,
+            Err(err) => {
+                error!("failed to claim outbound messages: {}", err);
+                return;
+            }
+        };
+
+        for (message_id, err) in result.failures {
+            warn!(
+                "failed to dispatch outbound message {}: {}",
+                message_id, err
+            );
+        }
+
+        for (message_id, err) in result.usage_failures {
+            error!(
+                "failed to record usage of outbound message {}: {}",
+                message_id, err
+            );
+        }
+
+        for notification in result.notifications {
+            let conversation = conversation_props_with_contact(
+                &self.contact_repository,
+                &notification.conversation,
+            )
+            .await;
+
+            self.message_event_broadcaster.publish(
+                RealtimeChannel::Organization(notification.organization_id),
+                MessageEventProps {
+                    event_type: "message.updated".to_owned(),
+                    message: MessageProps::signed(
+                        &notification.message,
+                        self.media_url_signer.as_ref(),
+                    ),
+                    conversation,
+                },
+            );
+        }
+    }
+}
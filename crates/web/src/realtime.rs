@@ -1,28 +1,93 @@
 use std::{collections::HashMap, sync::Mutex};
 
+use serde::Serialize;
 use tokio::sync::broadcast;
 
-use crate::dto::MessageEventProps;
+use crate::dto::{
+    ConversationEventProps, ConversationReadEventProps, MessageEventProps, UsageAlertEventProps,
+};
 
+/// Anything pushed to open sessions over `/events/messages`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum RealtimeEvent {
+    Message(Box<MessageEventProps>),
+    Conversation(Box<ConversationEventProps>),
+    ConversationRead(ConversationReadEventProps),
+    UsageAlert(UsageAlertEventProps),
+}
+
+impl RealtimeEvent {
+    /// SSE event name, also sent as the payload's `type`.
+    pub fn event_type(&self) -> &str {
+        match self {
+            RealtimeEvent::Message(event) => &event.event_type,
+            RealtimeEvent::Conversation(event) => &event.event_type,
+            RealtimeEvent::ConversationRead(event) => &event.event_type,
+            RealtimeEvent::UsageAlert(event) => &event.event_type,
+        }
+    }
+}
+
+impl From<MessageEventProps> for RealtimeEvent {
+    fn from(value: MessageEventProps) -> Self {
+        RealtimeEvent::Message(Box::new(value))
+    }
+}
+
+impl From<ConversationEventProps> for RealtimeEvent {
+    fn from(value: ConversationEventProps) -> Self {
+        RealtimeEvent::Conversation(Box::new(value))
+    }
+}
+
+impl From<ConversationReadEventProps> for RealtimeEvent {
+    fn from(value: ConversationReadEventProps) -> Self {
+        RealtimeEvent::ConversationRead(value)
+    }
+}
+
+impl From<UsageAlertEventProps> for RealtimeEvent {
+    fn from(value: UsageAlertEventProps) -> Self {
+        RealtimeEvent::UsageAlert(value)
+    }
+}
+
+/// Who receives an event. Personal organizations share their owner's id, so channels are
+/// typed rather than keyed by the bare id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RealtimeChannel {
+    /// Message and conversation events everyone working in the organization sees.
+    Organization(uuid::Uuid),
+    /// Usage alerts, which follow the user whichever organization they have open.
+    User(uuid::Uuid),
+    /// Read state, which is per member and per organization.
+    Member {
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    },
+}
+
+/// Fans events out per channel, see `RealtimeChannel`.
 #[derive(Debug, Default)]
 pub struct MessageEventBroadcaster {
-    senders: Mutex<HashMap<uuid::Uuid, broadcast::Sender<MessageEventProps>>>,
+    senders: Mutex<HashMap<RealtimeChannel, broadcast::Sender<RealtimeEvent>>>,
 }
 
 impl MessageEventBroadcaster {
-    pub fn publish(&self, user_id: uuid::Uuid, event: MessageEventProps) {
-        let sender = self.sender_for_user(user_id);
-        let _ = sender.send(event);
+    pub fn publish(&self, channel: RealtimeChannel, event: impl Into<RealtimeEvent>) {
+        let sender = self.sender_for(channel);
+        let _ = sender.send(event.into());
     }
 
-    pub fn subscribe(&self, user_id: uuid::Uuid) -> broadcast::Receiver<MessageEventProps> {
-        self.sender_for_user(user_id).subscribe()
+    pub fn subscribe(&self, channel: RealtimeChannel) -> broadcast::Receiver<RealtimeEvent> {
+        self.sender_for(channel).subscribe()
     }
 
-    fn sender_for_user(&self, user_id: uuid::Uuid) -> broadcast::Sender<MessageEventProps> {
+    fn sender_for(&self, channel: RealtimeChannel) -> broadcast::Sender<RealtimeEvent> {
         let mut senders = self.senders.lock().expect("message broadcaster lock");
         senders
-            .entry(user_id)
+            .entry(channel)
             .or_insert_with(|| broadcast::channel(128).0)
             .clone()
     }
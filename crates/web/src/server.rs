@@ -20,23 +20,46 @@ use crate::{
     dto::{DashboardAnalyticsProps, FlashProps},
     flash::{clear_flash, extract_flash},
     handlers::{
-        auth::build_auth_service, conversations::build_conversations_service,
-        events::build_events_service, inertia::version, phone_numbers::build_phone_numbers_service,
+        auth::build_auth_service, campaigns::build_campaigns_service,
+        contacts::build_contacts_service, conversations::build_conversations_service,
+        events::build_events_service, inertia::version, media::build_media_service,
+        organizations::build_organizations_service, phone_numbers::build_phone_numbers_service,
+        templates::build_templates_service, usage::build_usage_service,
         webhooks::build_webhooks_service,
     },
     inertia::{Page, dist_dir, is_dev, response_with_html},
+    membership::CurrentMember,
     middlewares::auth::ProtectedMiddleware,
     realtime::MessageEventBroadcaster,
     session::session_user_id,
+    types::{OpsApiToken, PublicBaseUrl, SmsSegmentPrice},
     webhook_forwarding::TelnyxWebhookForwarder,
 };
+use application::usecases::create_message_usecase::UnverifiedSenderPolicy;
 use application::usecases::get_dashboard_home_usecase::GetDashboardHomeUsecase;
+use domain::repositories::auto_reply_rule_repository::AutoReplyRuleRepository;
+use domain::repositories::business_hours_repository::BusinessHoursRepository;
+use domain::repositories::campaign_repository::CampaignRepository;
+use domain::repositories::contact_import_repository::ContactImportRepository;
+use domain::repositories::contact_repository::ContactRepository;
 use domain::repositories::conversation_repository::ConversationRepository;
 use domain::repositories::message_repository::MessageRepository;
+use domain::repositories::message_status_event_repository::MessageStatusEventRepository;
+use domain::repositories::message_template_repository::MessageTemplateRepository;
+use domain::repositories::opt_out_repository::OptOutRepository;
+use domain::repositories::organization_invitation_repository::OrganizationInvitationRepository;
+use domain::repositories::organization_repository::OrganizationRepository;
+use domain::repositories::outbound_message_queue_repository::OutboundMessageQueueRepository;
 use domain::repositories::phone_number_repository::PhoneNumberRepository;
 use domain::repositories::processed_webhook_event_repository::ProcessedWebhookEventRepository;
+use domain::repositories::reset_password_repository::ResetPasswordRepository;
+use domain::repositories::usage_limit_repository::UsageLimitRepository;
+use domain::repositories::usage_repository::UsageRepository;
 use domain::repositories::user_repository::UserRepository;
-use domain::traits::outbound_message_service::OutboundMessageService;
+use domain::traits::email_sender::EmailSender;
+use domain::traits::media_downloader::MediaDownloader;
+use domain::traits::media_storage::MediaStorage;
+use domain::traits::media_url_signer::MediaUrlSigner;
 use domain::traits::password_hasher::PasswordHasher;
 use domain::traits::token_service::TokenService;
 
@@ -45,11 +68,32 @@ pub fn create_web_service(
     user_repository: Arc<dyn UserRepository>,
     conversation_repository: Arc<dyn ConversationRepository>,
     message_repository: Arc<dyn MessageRepository>,
+    message_status_event_repository: Arc<dyn MessageStatusEventRepository>,
     phone_number_repository: Arc<dyn PhoneNumberRepository>,
     processed_webhook_event_repository: Arc<dyn ProcessedWebhookEventRepository>,
+    reset_password_repository: Arc<dyn ResetPasswordRepository>,
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    contact_import_repository: Arc<dyn ContactImportRepository>,
+    campaign_repository: Arc<dyn CampaignRepository>,
+    message_template_repository: Arc<dyn MessageTemplateRepository>,
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    business_hours_repository: Arc<dyn BusinessHoursRepository>,
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    organization_invitation_repository: Arc<dyn OrganizationInvitationRepository>,
     password_hasher: Arc<dyn PasswordHasher>,
     token_service: Arc<dyn TokenService>,
-    outbound_message_service: Arc<dyn OutboundMessageService>,
+    media_storage: Arc<dyn MediaStorage>,
+    media_downloader: Arc<dyn MediaDownloader>,
+    media_url_signer: Arc<dyn MediaUrlSigner>,
+    email_sender: Arc<dyn EmailSender>,
+    public_base_url: String,
+    block_unverified_senders: bool,
+    ops_api_token: Option<String>,
+    sms_segment_price_micros: Option<i64>,
     telnyx_public_key: String,
     telnyx_webhook_forward_urls: Vec<String>,
     message_event_broadcaster: Arc<MessageEventBroadcaster>,
@@ -84,19 +128,50 @@ pub fn create_web_service(
         .app_data(web::Data::new(password_hasher))
         .app_data(web::Data::new(conversation_repository))
         .app_data(web::Data::new(message_repository))
+        .app_data(web::Data::new(message_status_event_repository))
         .app_data(web::Data::new(phone_number_repository))
         .app_data(web::Data::new(processed_webhook_event_repository))
+        .app_data(web::Data::new(reset_password_repository))
+        .app_data(web::Data::new(outbound_message_queue_repository))
+        .app_data(web::Data::new(opt_out_repository))
+        .app_data(web::Data::new(contact_repository))
+        .app_data(web::Data::new(contact_import_repository))
+        .app_data(web::Data::new(campaign_repository))
+        .app_data(web::Data::new(message_template_repository))
+        .app_data(web::Data::new(auto_reply_rule_repository))
+        .app_data(web::Data::new(business_hours_repository))
+        .app_data(web::Data::new(usage_repository))
+        .app_data(web::Data::new(usage_limit_repository))
+        .app_data(web::Data::new(organization_repository))
+        .app_data(web::Data::new(organization_invitation_repository))
         .app_data(web::Data::new(token_service.clone()))
-        .app_data(web::Data::new(outbound_message_service))
+        .app_data(web::Data::new(media_storage))
+        .app_data(web::Data::new(media_downloader))
+        .app_data(web::Data::new(media_url_signer))
+        .app_data(web::Data::new(email_sender))
+        .app_data(web::Data::new(PublicBaseUrl(public_base_url)))
+        .app_data(web::Data::new(if block_unverified_senders {
+            UnverifiedSenderPolicy::Block
+        } else {
+            UnverifiedSenderPolicy::Allow
+        }))
+        .app_data(web::Data::new(OpsApiToken(ops_api_token)))
+        .app_data(web::Data::new(SmsSegmentPrice(sms_segment_price_micros)))
         .app_data(web::Data::new(telnyx_public_key))
         .app_data(web::Data::new(TelnyxWebhookForwarder::new(
             telnyx_webhook_forward_urls,
         )))
         .app_data(web::Data::new(message_event_broadcaster))
         .route("/", web::get().to(index).wrap(ProtectedMiddleware::new()))
+        .service(build_campaigns_service())
+        .service(build_contacts_service())
         .service(build_conversations_service())
         .service(build_events_service())
+        .service(build_media_service())
+        .service(build_organizations_service())
         .service(build_phone_numbers_service())
+        .service(build_templates_service())
+        .service(build_usage_service())
         .service(build_auth_service())
         .service(build_webhooks_service())
         .service(
@@ -120,6 +195,7 @@ struct HomePageProps {
     pub flash: Option<FlashProps>,
     pub phone_numbers: Vec<crate::dto::PhoneNumberProps>,
     pub analytics: DashboardAnalyticsProps,
+    pub email_verified: bool,
 }
 
 async fn index(
@@ -128,6 +204,8 @@ async fn index(
     conversation_repository: web::Data<Arc<dyn ConversationRepository>>,
     message_repository: web::Data<Arc<dyn MessageRepository>>,
     phone_number_repository: web::Data<Arc<dyn PhoneNumberRepository>>,
+    user_repository: web::Data<Arc<dyn UserRepository>>,
+    member: Option<CurrentMember>,
 ) -> impl Responder {
     let flash = extract_flash(&session);
 
@@ -141,8 +219,11 @@ async fn index(
         .phone_number_repository(phone_number_repository.get_ref().clone())
         .build();
 
-    let (phone_numbers, analytics) = match session_user_id(&session) {
-        Some(user_id) => match get_dashboard_home_usecase.execute(user_id).await {
+    let (phone_numbers, analytics) = match member {
+        Some(member) => match get_dashboard_home_usecase
+            .execute(member.organization_id, member.user_id)
+            .await
+        {
             Ok(result) => {
                 let phone_numbers = result
                     .phone_numbers
@@ -154,14 +235,16 @@ async fn index(
                     .total_conversations(result.analytics.total_conversations)
                     .total_messages(result.analytics.total_messages)
                     .total_phone_numbers(result.analytics.total_phone_numbers)
+                    .unread_conversations(result.analytics.unread_conversations)
+                    .unread_messages(result.analytics.unread_messages)
                     .build();
 
                 (phone_numbers, analytics)
             }
             Err(err) => {
                 error!(
-                    "failed to load dashboard home data for user {}: {}",
-                    user_id, err
+                    "failed to load dashboard home data for organization {}: {}",
+                    member.organization_id, err
                 );
 
                 (
@@ -170,6 +253,8 @@ async fn index(
                         .total_conversations(0)
                         .total_messages(0)
                         .total_phone_numbers(0)
+                        .unread_conversations(0)
+                        .unread_messages(0)
                         .build(),
                 )
             }
@@ -180,10 +265,22 @@ async fn index(
                 .total_conversations(0)
                 .total_messages(0)
                 .total_phone_numbers(0)
+                .unread_conversations(0)
+                .unread_messages(0)
                 .build(),
         ),
     };
 
+    // Only nag about verification when we positively know the address is unverified.
+    let email_verified = match session_user_id(&session) {
+        Some(user_id) => user_repository
+            .find_by_id(&user_id)
+            .await
+            .map(|user| user.email_verified)
+            .unwrap_or(true),
+        None => true,
+    };
+
     Page::builder()
         .req(req)
         .name("App")
@@ -192,6 +289,7 @@ async fn index(
                 .maybe_flash(flash)
                 .phone_numbers(phone_numbers)
                 .analytics(analytics)
+                .email_verified(email_verified)
                 .build(),
         )
         .build()
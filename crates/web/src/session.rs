@@ -13,6 +13,8 @@ const LEGACY_USER_ID_SESSION_KEY: &str = "id";
 const ACCESS_TOKEN_SESSION_KEY: &str = "access_token";
 /// Session key for consolidated auth payload
 const AUTH_DATA_SESSION_KEY: &str = "auth_data";
+/// Session key for the organization the user is working in
+const ORGANIZATION_ID_SESSION_KEY: &str = "organization_id";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionUserData {
@@ -77,6 +79,19 @@ pub fn get_access_token(session: &Session) -> Option<String> {
         .flatten()
 }
 
+/// Get the organization the user last switched to. Membership is not checked here.
+pub fn get_organization_id(session: &Session) -> Option<uuid::Uuid> {
+    session
+        .get::<uuid::Uuid>(ORGANIZATION_ID_SESSION_KEY)
+        .ok()
+        .flatten()
+}
+
+/// Remember the organization the user is working in.
+pub fn set_organization_id(session: &Session, organization_id: &uuid::Uuid) {
+    let _ = session.insert(ORGANIZATION_ID_SESSION_KEY, organization_id);
+}
+
 /// Set the authenticated state in the session.
 /// Call this after successful login.
 pub fn set_authenticated(session: &Session, user_id: &str, email: &str, access_token: &str) {
@@ -100,4 +115,5 @@ pub fn clear_authenticated(session: &Session) {
     let _ = session.remove(USER_ID_SESSION_KEY);
     let _ = session.remove(LEGACY_USER_ID_SESSION_KEY);
     let _ = session.remove(ACCESS_TOKEN_SESSION_KEY);
+    let _ = session.remove(ORGANIZATION_ID_SESSION_KEY);
 }
@@ -0,0 +1,66 @@
+use std::{sync::Arc, time::Duration};
+
+use application::usecases::wake_snoozed_conversations_usecase::WakeSnoozedConversationsUsecase;
+use domain::repositories::{
+    contact_repository::ContactRepository, conversation_repository::ConversationRepository,
+};
+use time::OffsetDateTime;
+use tokio::time::MissedTickBehavior;
+use tracing::error;
+
+use crate::{
+    contact_names::conversation_props_with_contact,
+    dto::{CONVERSATION_UPDATED_EVENT, ConversationEventProps},
+    realtime::{MessageEventBroadcaster, RealtimeChannel},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Background worker that returns snoozed conversations to the inbox once their time is up
+/// and tells open sessions. Safe to run in several processes at once.
+#[derive(bon::Builder)]
+pub struct SnoozeWorker {
+    conversation_repository: Arc<dyn ConversationRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    message_event_broadcaster: Arc<MessageEventBroadcaster>,
+    #[builder(default = POLL_INTERVAL)]
+    poll_interval: Duration,
+}
+
+impl SnoozeWorker {
+    pub async fn run(self) {
+        let usecase = WakeSnoozedConversationsUsecase::builder()
+            .conversation_repository(self.conversation_repository.clone())
+            .build();
+
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            self.wake(&usecase).await;
+        }
+    }
+
+    async fn wake(&self, usecase: &WakeSnoozedConversationsUsecase) {
+        let conversations = match usecase.execute(OffsetDateTime::now_utc()).await {
+            Ok(conversations) => conversations,
+            Err(err) => {
+                error!("failed to wake snoozed conversations: {}", err);
+                return;
+            }
+        };
+
+        for conversation in conversations {
+            let props =
+                conversation_props_with_contact(&self.contact_repository, &conversation).await;
+            self.message_event_broadcaster.publish(
+                RealtimeChannel::Organization(conversation.organization_id),
+                ConversationEventProps {
+                    event_type: CONVERSATION_UPDATED_EVENT.to_owned(),
+                    conversation: props,
+                },
+            );
+        }
+    }
+}
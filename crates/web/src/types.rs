@@ -54,6 +54,36 @@ impl ResponseError for WebError {
                 application::usecases::UsecaseError::TokenGenerationFailed => {
                     actix_web::http::StatusCode::INTERNAL_SERVER_ERROR
                 }
+                application::usecases::UsecaseError::InvalidResetToken => {
+                    actix_web::http::StatusCode::BAD_REQUEST
+                }
+                application::usecases::UsecaseError::InvalidVerificationToken => {
+                    actix_web::http::StatusCode::BAD_REQUEST
+                }
+                application::usecases::UsecaseError::InvalidInvitation => {
+                    actix_web::http::StatusCode::BAD_REQUEST
+                }
+                application::usecases::UsecaseError::Forbidden(_) => {
+                    actix_web::http::StatusCode::FORBIDDEN
+                }
+                application::usecases::UsecaseError::EmailNotVerified => {
+                    actix_web::http::StatusCode::FORBIDDEN
+                }
+                application::usecases::UsecaseError::RecipientOptedOut => {
+                    actix_web::http::StatusCode::FORBIDDEN
+                }
+                application::usecases::UsecaseError::UsageLimitReached { .. } => {
+                    actix_web::http::StatusCode::FORBIDDEN
+                }
+                application::usecases::UsecaseError::ContactPhoneNumberTaken => {
+                    actix_web::http::StatusCode::CONFLICT
+                }
+                application::usecases::UsecaseError::CampaignStatusConflict { .. } => {
+                    actix_web::http::StatusCode::CONFLICT
+                }
+                application::usecases::UsecaseError::MessageNotScheduled => {
+                    actix_web::http::StatusCode::CONFLICT
+                }
                 application::usecases::UsecaseError::MessageRejected(_) => {
                     actix_web::http::StatusCode::UNPROCESSABLE_ENTITY
                 }
@@ -63,6 +93,12 @@ impl ResponseError for WebError {
                 application::usecases::UsecaseError::Database(_) => {
                     actix_web::http::StatusCode::INTERNAL_SERVER_ERROR
                 }
+                application::usecases::UsecaseError::MediaStorage(_) => {
+                    actix_web::http::StatusCode::INTERNAL_SERVER_ERROR
+                }
+                application::usecases::UsecaseError::InvalidStatusTransition(_) => {
+                    actix_web::http::StatusCode::CONFLICT
+                }
             },
         }
     }
@@ -80,3 +116,32 @@ impl ResponseError for WebError {
 pub struct WebErrorResponse {
     pub error: String,
 }
+
+/// Absolute base URL of the app, used for links that are opened outside the browser
+/// session such as the ones in emails.
+#[derive(Debug, Clone)]
+pub struct PublicBaseUrl(pub String);
+
+/// Price of one SMS segment in millionths of the billing currency, used for cost estimates.
+#[derive(Debug, Clone, Copy)]
+pub struct SmsSegmentPrice(pub Option<i64>);
+
+/// Bearer token for the operator endpoints. Those endpoints are hidden when unset.
+#[derive(Debug, Clone)]
+pub struct OpsApiToken(pub Option<String>);
+
+impl OpsApiToken {
+    /// Compares in constant time so the token cannot be guessed byte by byte.
+    pub fn authorizes(&self, candidate: &str) -> bool {
+        let Some(token) = self.0.as_deref() else {
+            return false;
+        };
+
+        token.len() == candidate.len()
+            && token
+                .bytes()
+                .zip(candidate.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use application::usecases::{
+    UsecaseError, list_unread_counts_usecase::ListUnreadCountsUsecase,
+    mark_conversation_read_usecase::MarkConversationReadUsecase,
+};
+use domain::repositories::conversation_repository::ConversationRepository;
+use tracing::warn;
+
+use crate::{
+    dto::{CONVERSATION_READ_EVENT, ConversationProps, ConversationReadEventProps},
+    membership::CurrentMember,
+    realtime::{MessageEventBroadcaster, RealtimeChannel},
+};
+
+/// Fills in the member's unread count on each conversation. A failed lookup only costs the
+/// badges, so it is logged and the counts are left out.
+pub(crate) async fn conversation_props_with_unread(
+    conversation_repository: &Arc<dyn ConversationRepository>,
+    member: &CurrentMember,
+    conversations: Vec<ConversationProps>,
+) -> Vec<ConversationProps> {
+    let counts = match ListUnreadCountsUsecase::builder()
+        .conversation_repository(conversation_repository.clone())
+        .build()
+        .execute(member.organization_id, member.user_id)
+        .await
+    {
+        Ok(counts) => counts,
+        Err(err) => {
+            warn!(
+                "failed to count unread messages for user {}: {}",
+                member.user_id, err
+            );
+            return conversations;
+        }
+    };
+
+    conversations
+        .into_iter()
+        .map(|conversation| {
+            let unread = counts.get(&conversation.id).copied().unwrap_or(0);
+            conversation.with_unread(unread)
+        })
+        .collect()
+}
+
+/// Marks the conversation read for the member and tells their other open tabs.
+pub(crate) async fn mark_conversation_read(
+    conversation_repository: &Arc<dyn ConversationRepository>,
+    message_event_broadcaster: &MessageEventBroadcaster,
+    member: &CurrentMember,
+    conversation_id: uuid::Uuid,
+) -> Result<i64, UsecaseError> {
+    let total_unread = MarkConversationReadUsecase::builder()
+        .conversation_repository(conversation_repository.clone())
+        .build()
+        .execute(member.organization_id, member.user_id, conversation_id)
+        .await?;
+
+    message_event_broadcaster.publish(
+        RealtimeChannel::Member {
+            organization_id: member.organization_id,
+            user_id: member.user_id,
+        },
+        ConversationReadEventProps {
+            event_type: CONVERSATION_READ_EVENT.to_owned(),
+            conversation_id,
+            total_unread,
+        },
+    );
+
+    Ok(total_unread)
+}
@@ -0,0 +1,81 @@
+use std::{sync::Arc, time::Duration};
+
+use application::usecases::notify_usage_limits_usecase::NotifyUsageLimitsUsecase;
+use domain::{
+    repositories::{
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+        user_repository::UserRepository,
+    },
+    traits::email_sender::EmailSender,
+};
+use time::OffsetDateTime;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+
+use crate::{
+    dto::UsageAlertEventProps,
+    realtime::{MessageEventBroadcaster, RealtimeChannel},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Background worker that emails users and pushes an SSE event when their spend crosses a
+/// soft limit. Safe to run in several processes at once.
+#[derive(bon::Builder)]
+pub struct UsageAlertWorker {
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    email_sender: Arc<dyn EmailSender>,
+    message_event_broadcaster: Arc<MessageEventBroadcaster>,
+    #[builder(default = POLL_INTERVAL)]
+    poll_interval: Duration,
+}
+
+impl UsageAlertWorker {
+    pub async fn run(self) {
+        let usecase = NotifyUsageLimitsUsecase::builder()
+            .usage_repository(self.usage_repository.clone())
+            .usage_limit_repository(self.usage_limit_repository.clone())
+            .user_repository(self.user_repository.clone())
+            .email_sender(self.email_sender.clone())
+            .build();
+
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            self.notify(&usecase).await;
+        }
+    }
+
+    async fn notify(&self, usecase: &NotifyUsageLimitsUsecase) {
+        let result = match usecase.execute(OffsetDateTime::now_utc()).await {
+            Ok(result) => result,
+            Err(err) => {
+                error!("failed to check usage limits: {}", err);
+                return;
+            }
+        };
+
+        for (user_id, err) in result.failures {
+            warn!(
+                "failed to notify user {} about their usage: {}",
+                user_id, err
+            );
+        }
+
+        for alert in result.alerts {
+            info!(
+                "user {} crossed their {} soft usage limit",
+                alert.user_id,
+                alert.period.name()
+            );
+            self.message_event_broadcaster.publish(
+                RealtimeChannel::User(alert.user_id),
+                UsageAlertEventProps::from(&alert),
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::{sync::Arc, time::Duration};
+
+use application::{
+    commands::{
+        ProcessTelnyxWebhookCommand, TelnyxWebhookMessageCost, TelnyxWebhookMessageError,
+        TelnyxWebhookMessageMedia, TelnyxWebhookMessageParticipant, TelnyxWebhookMessagePayload,
+    },
+    usecases::{
+        process_telnyx_messaging_webhook_usecase::{
+            MessagingWebhookNotificationKind, ProcessTelnyxMessagingWebhookUsecase,
+        },
+        process_webhook_inbox_usecase::ProcessWebhookInboxUsecase,
+    },
+};
+use domain::{
+    models::processed_webhook_event::ProcessedWebhookEvent,
+    repositories::{
+        auto_reply_rule_repository::AutoReplyRuleRepository,
+        business_hours_repository::BusinessHoursRepository, contact_repository::ContactRepository,
+        conversation_repository::ConversationRepository, message_repository::MessageRepository,
+        message_status_event_repository::MessageStatusEventRepository,
+        opt_out_repository::OptOutRepository, organization_repository::OrganizationRepository,
+        outbound_message_queue_repository::OutboundMessageQueueRepository,
+        phone_number_repository::PhoneNumberRepository,
+        processed_webhook_event_repository::ProcessedWebhookEventRepository,
+        usage_limit_repository::UsageLimitRepository, usage_repository::UsageRepository,
+    },
+    traits::{
+        media_downloader::MediaDownloader, media_storage::MediaStorage,
+        media_url_signer::MediaUrlSigner,
+    },
+};
+use time::OffsetDateTime;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error, warn};
+
+use crate::{
+    contact_names::conversation_props_with_contact,
+    dto::{CONVERSATION_ASSIGNED_EVENT, ConversationEventProps, MessageEventProps, MessageProps},
+    realtime::{MessageEventBroadcaster, RealtimeChannel},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Background worker that applies webhooks stored by the Telnyx webhook endpoint and pushes
+/// the resulting message changes to connected clients. Safe to run in several processes.
+#[derive(bon::Builder)]
+pub struct WebhookInboxWorker {
+    processed_webhook_event_repository: Arc<dyn ProcessedWebhookEventRepository>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    contact_repository: Arc<dyn ContactRepository>,
+    message_repository: Arc<dyn MessageRepository>,
+    message_status_event_repository: Arc<dyn MessageStatusEventRepository>,
+    phone_number_repository: Arc<dyn PhoneNumberRepository>,
+    opt_out_repository: Arc<dyn OptOutRepository>,
+    outbound_message_queue_repository: Arc<dyn OutboundMessageQueueRepository>,
+    auto_reply_rule_repository: Arc<dyn AutoReplyRuleRepository>,
+    business_hours_repository: Arc<dyn BusinessHoursRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    media_storage: Arc<dyn MediaStorage>,
+    media_downloader: Arc<dyn MediaDownloader>,
+    media_url_signer: Arc<dyn MediaUrlSigner>,
+    usage_repository: Arc<dyn UsageRepository>,
+    usage_limit_repository: Arc<dyn UsageLimitRepository>,
+    message_event_broadcaster: Arc<MessageEventBroadcaster>,
+    help_reply: Option<String>,
+    #[builder(default = POLL_INTERVAL)]
+    poll_interval: Duration,
+}
+
+impl WebhookInboxWorker {
+    pub async fn run(self) {
+        let processor = ProcessTelnyxMessagingWebhookUsecase::builder()
+            .conversation_repository(self.conversation_repository.clone())
+            .message_repository(self.message_repository.clone())
+            .message_status_event_repository(self.message_status_event_repository.clone())
+            .phone_number_repository(self.phone_number_repository.clone())
+            .opt_out_repository(self.opt_out_repository.clone())
+            .outbound_message_queue_repository(self.outbound_message_queue_repository.clone())
+            .contact_repository(self.contact_repository.clone())
+            .auto_reply_rule_repository(self.auto_reply_rule_repository.clone())
+            .business_hours_repository(self.business_hours_repository.clone())
+            .organization_repository(self.organization_repository.clone())
+            .media_storage(self.media_storage.clone())
+            .media_downloader(self.media_downloader.clone())
+            .usage_repository(self.usage_repository.clone())
+            .usage_limit_repository(self.usage_limit_repository.clone())
+            .maybe_help_reply(self.help_reply.clone())
+            .build();
+        let usecase = ProcessWebhookInboxUsecase::builder()
+            .processed_webhook_event_repository(self.processed_webhook_event_repository.clone())
+            .processor(processor)
+            .build();
+
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            self.process_pending(&usecase).await;
+        }
+    }
+
+    async fn process_pending(&self, usecase: &ProcessWebhookInboxUsecase) {
+        let result = match usecase
+            .execute(OffsetDateTime::now_utc(), decode_event)
+            .await
+        {
+            Ok(result) => result,
+            Err(err) => {
+                error!("failed to claim Telnyx webhook events: {}", err);
+                return;
+            }
+        };
+
+        for (event_id, err) in result.failures {
+            warn!(
+                "failed to process Telnyx webhook {}, will retry: {}",
+                event_id, err
+            );
+        }
+
+        for event_id in result.parked {
+            debug!(
+                "parked Telnyx webhook {} until its outbound message is stored",
+                event_id
+            );
+        }
+
+        for (event_id, reason) in result.dead_lettered {
+            error!("dead-lettered Telnyx webhook {}: {}", event_id, reason);
+        }
+
+        for notification in result.notifications {
+            let event_type = match notification.kind {
+                MessagingWebhookNotificationKind::MessageCreated => "message.created",
+                MessagingWebhookNotificationKind::MessageUpdated => "message.updated",
+            };
+            let conversation = conversation_props_with_contact(
+                &self.contact_repository,
+                &notification.conversation,
+            )
+            .await;
+
+            if notification.assigned {
+                self.message_event_broadcaster.publish(
+                    RealtimeChannel::Organization(notification.organization_id),
+                    ConversationEventProps {
+                        event_type: CONVERSATION_ASSIGNED_EVENT.to_owned(),
+                        conversation: conversation.clone(),
+                    },
+                );
+            }
+            self.message_event_broadcaster.publish(
+                RealtimeChannel::Organization(notification.organization_id),
+                MessageEventProps {
+                    event_type: event_type.to_owned(),
+                    message: MessageProps::signed(
+                        &notification.message,
+                        self.media_url_signer.as_ref(),
+                    ),
+                    conversation,
+                },
+            );
+        }
+    }
+}
+
+fn decode_event(event: &ProcessedWebhookEvent) -> Result<ProcessTelnyxWebhookCommand, String> {
+    let webhook =
+        serde_json::from_value::<telnyx::TelnyxMessagingWebhook>(event.payload_json.clone())
+            .map_err(|err| format!("invalid Telnyx webhook payload: {}", err))?;
+
+    Ok(ProcessTelnyxWebhookCommand {
+        event_id: webhook.data.id,
+        event_type: webhook.data.event_type,
+        occurred_at: webhook.data.occurred_at,
+        payload: map_payload(webhook.data.payload),
+    })
+}
+
+fn map_payload(payload: telnyx::TelnyxMessagingWebhookPayload) -> TelnyxWebhookMessagePayload {
+    TelnyxWebhookMessagePayload {
+        provider_message_id: payload.id,
+        from_phone_number: payload
+            .from
+            .as_ref()
+            .and_then(|value| value.phone_number.clone()),
+        from_carrier: payload
+            .from
+            .as_ref()
+            .and_then(|value| value.carrier.clone()),
+        from_line_type: payload.from.and_then(|value| value.line_type),
+        to: payload
+            .to
+            .into_iter()
+            .map(|participant| TelnyxWebhookMessageParticipant {
+                phone_number: participant.phone_number,
+                status: participant.status,
+                carrier: participant.carrier,
+                line_type: participant.line_type,
+            })
+            .collect(),
+        text: payload.text,
+        received_at: payload.received_at,
+        sent_at: payload.sent_at,
+        completed_at: payload.completed_at,
+        errors: payload
+            .errors
+            .into_iter()
+            .map(|error| TelnyxWebhookMessageError {
+                code: error.code,
+                detail: error.detail,
+                title: error.title,
+            })
+            .collect(),
+        media: payload
+            .media
+            .into_iter()
+            .map(|media| TelnyxWebhookMessageMedia {
+                url: media.url,
+                content_type: media.content_type,
+                size: media.size,
+                sha256: media.sha256,
+            })
+            .collect(),
+        parts: payload.parts,
+        encoding: payload.encoding,
+        cost: payload.cost.map(|cost| TelnyxWebhookMessageCost {
+            amount: cost.amount,
+            currency: cost.currency,
+        }),
+        messaging_profile_id: payload.messaging_profile_id,
+    }
+}
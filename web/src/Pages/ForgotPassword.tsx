@@ -1,3 +1,4 @@
+import { useEffect } from "react";
 import { useForm } from "react-hook-form";
 import { zodResolver } from "@hookform/resolvers/zod";
 import * as z from "zod";
@@ -12,8 +13,11 @@ import {
   FormMessage,
 } from "@/components/ui/form";
 import { Input } from "@/components/ui/input";
-import { Mail, ArrowLeft } from "lucide-react";
-import { Link } from "@inertiajs/react";
+import { Alert, AlertDescription, AlertTitle } from "@/components/ui/alert";
+import { useFlash } from "@/hooks/use-flash";
+import type { PropsWithFlash } from "@/lib/types";
+import { AlertCircle, Mail, ArrowLeft } from "lucide-react";
+import { Link, router } from "@inertiajs/react";
 
 const forgotPasswordSchema = z.object({
   email: z.email("Invalid email address"),
@@ -21,7 +25,16 @@ const forgotPasswordSchema = z.object({
 
 type ForgotPasswordFormValues = z.infer<typeof forgotPasswordSchema>;
 
-function ForgotPassword() {
+interface ForgotPasswordPageProps extends PropsWithFlash {
+  errors?: {
+    email?: string;
+    general?: string;
+  };
+}
+
+function ForgotPassword({ errors, flash }: ForgotPasswordPageProps) {
+  useFlash(flash);
+
   const form = useForm<ForgotPasswordFormValues>({
     resolver: zodResolver(forgotPasswordSchema),
     defaultValues: {
@@ -29,8 +42,18 @@ function ForgotPassword() {
     },
   });
 
+  useEffect(() => {
+    if (errors?.email) {
+      form.setError("email", { message: errors.email });
+    }
+  }, [errors?.email, form]);
+
   function onSubmit(values: ForgotPasswordFormValues) {
-    console.log("Password reset request for:", values.email);
+    return new Promise<void>((resolve) => {
+      router.post("/auth/forgot-password", values, {
+        onFinish: () => resolve(),
+      });
+    });
   }
 
   return (
@@ -42,6 +65,16 @@ function ForgotPassword() {
       supportingDescription="Recovery should be short, explicit, and secure so operators can get back into the workspace quickly."
     >
       <div className="space-y-6">
+        {errors?.general && (
+          <Alert
+            variant="destructive"
+            className="rounded-[1.5rem] border-destructive/30 bg-destructive/5"
+          >
+            <AlertCircle className="size-4" />
+            <AlertTitle>Unable to send reset link</AlertTitle>
+            <AlertDescription>{errors.general}</AlertDescription>
+          </Alert>
+        )}
           <Form {...form}>
           <form onSubmit={form.handleSubmit(onSubmit)} className="space-y-5">
               <FormField
@@ -1,4 +1,4 @@
-import { useState } from "react";
+import { useEffect } from "react";
 import { useForm } from "react-hook-form";
 import { zodResolver } from "@hookform/resolvers/zod";
 import * as z from "zod";
@@ -12,8 +12,11 @@ import {
   FormLabel,
   FormMessage,
 } from "@/components/ui/form";
-import { Link } from "@inertiajs/react";
-import { CheckCircle2 } from "lucide-react";
+import { Alert, AlertDescription, AlertTitle } from "@/components/ui/alert";
+import { useFlash } from "@/hooks/use-flash";
+import type { PropsWithFlash } from "@/lib/types";
+import { Link, router } from "@inertiajs/react";
+import { AlertCircle } from "lucide-react";
 
 const resetPasswordSchema = z
   .object({
@@ -33,8 +36,16 @@ const resetPasswordSchema = z
 
 type ResetPasswordFormValues = z.infer<typeof resetPasswordSchema>;
 
-function ResetPassword() {
-  const [isSuccess, setIsSuccess] = useState(false);
+interface ResetPasswordPageProps extends PropsWithFlash {
+  token?: string | null;
+  errors?: {
+    password?: string;
+    general?: string;
+  };
+}
+
+function ResetPassword({ token, errors, flash }: ResetPasswordPageProps) {
+  useFlash(flash);
 
   const form = useForm<ResetPasswordFormValues>({
     resolver: zodResolver(resetPasswordSchema),
@@ -44,38 +55,38 @@ function ResetPassword() {
     },
   });
 
+  useEffect(() => {
+    if (errors?.password) {
+      form.setError("password", { message: errors.password });
+    }
+  }, [errors?.password, form]);
+
   function onSubmit(values: ResetPasswordFormValues) {
-    console.log("Password reset:", values);
-    setIsSuccess(true);
+    return new Promise<void>((resolve) => {
+      router.post(
+        "/auth/reset-password",
+        {
+          token: token ?? "",
+          password: values.password,
+          password_confirmation: values.confirmPassword,
+        },
+        { onFinish: () => resolve() },
+      );
+    });
   }
 
-  if (isSuccess) {
+  if (!token) {
     return (
       <AuthShell
-        eyebrow="Recovery complete"
-        title="Your password has been reset"
-        description="Use the new password to return to the operator workspace."
-        supportingTitle="Recovery finished cleanly."
-        supportingDescription="The next step is straightforward: sign in again and pick up your messaging work where you left it."
+        eyebrow="Link required"
+        title="Open the link from your email"
+        description="Password resets start from the link we email you. Request a new one if it has expired."
+        supportingTitle="Recovery starts from your inbox."
+        supportingDescription="Each reset link works once and expires shortly after it is sent, so the workspace stays protected."
       >
-        <div className="space-y-6">
-          <div className="rounded-[1.75rem] border border-emerald-200/80 bg-emerald-50/80 p-6 text-center">
-            <div className="mx-auto flex size-16 items-center justify-center rounded-full bg-emerald-100 text-emerald-700">
-              <CheckCircle2 className="size-8" />
-            </div>
-            <h3 className="mt-5 font-display text-2xl font-semibold tracking-tight text-foreground">
-              Password reset successful
-            </h3>
-            <p className="mt-3 text-sm leading-6 text-muted-foreground">
-              Your new credentials are active. Return to login and continue in
-              the workspace.
-            </p>
-          </div>
-
-          <Button asChild className="h-12 w-full rounded-2xl text-base">
-            <Link href="/auth/login">Go to login</Link>
-          </Button>
-        </div>
+        <Button asChild className="h-12 w-full rounded-2xl text-base">
+          <Link href="/auth/forgot-password">Request a reset link</Link>
+        </Button>
       </AuthShell>
     );
   }
@@ -89,6 +100,22 @@ function ResetPassword() {
       supportingDescription="A clear reset flow keeps operators moving while preserving the control expected from an admin workspace."
     >
       <div className="space-y-6">
+        {errors?.general && (
+          <Alert
+            variant="destructive"
+            className="rounded-[1.5rem] border-destructive/30 bg-destructive/5"
+          >
+            <AlertCircle className="size-4" />
+            <AlertTitle>Unable to reset password</AlertTitle>
+            <AlertDescription>
+              {errors.general}{" "}
+              <Link href="/auth/forgot-password" className="underline">
+                Request a new link
+              </Link>
+              .
+            </AlertDescription>
+          </Alert>
+        )}
           <Form {...form}>
           <form onSubmit={form.handleSubmit(onSubmit)} className="space-y-5">
               <FormField
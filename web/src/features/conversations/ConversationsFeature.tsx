@@ -40,6 +40,11 @@ function ConversationsFeature() {
       sidebarContent={
         <ConversationsSidebar
           conversations={controller.sortedConversations}
+          view={controller.view}
+          listState={controller.listState}
+          onChangeListFilter={controller.changeListFilter}
+          currentUserId={controller.currentUserId}
+          members={controller.members}
           selectedConversationId={controller.selectedConversationId}
           onSelectConversation={controller.selectConversation}
           deletingConversationId={controller.deletingConversationId}
@@ -55,8 +60,11 @@ function ConversationsFeature() {
           visibleMessages={controller.visibleMessages}
           nextCursor={controller.nextCursor}
           onLoadOlderMessages={controller.loadOlderMessages}
+          messageStatusHistory={controller.messageStatusHistory}
           messageDraft={controller.messageDraft}
           onMessageDraftChange={controller.setMessageDraft}
+          attachmentDrafts={controller.attachmentDrafts}
+          onAttachmentDraftsChange={controller.setAttachmentDrafts}
           onSendMessage={controller.sendMessage}
           onComposerKeyDown={controller.composerKeyDown}
           isSendingMessage={controller.isSendingMessage}
@@ -9,7 +9,14 @@ import {
   EmptyMedia,
   EmptyTitle,
 } from "@/components/ui/empty";
-import type { Conversation, Message, PhoneNumber, SentMediaItem } from "../types";
+import type {
+  Conversation,
+  Message,
+  MessageStatusEventRecord,
+  PhoneNumber,
+  SentMediaItem,
+} from "../types";
+import { conversationTitle } from "../utils/message-utils";
 import { MessageComposer } from "./MessageComposer";
 import { MessageList } from "./MessageList";
 import { SentMediaPanel } from "./SentMediaPanel";
@@ -21,8 +28,11 @@ type ConversationMainPanelProps = {
   visibleMessages: Message[];
   nextCursor: string | null;
   onLoadOlderMessages: () => Promise<boolean>;
+  messageStatusHistory: Record<string, MessageStatusEventRecord[]>;
   messageDraft: string;
   onMessageDraftChange: (draft: string) => void;
+  attachmentDrafts: File[];
+  onAttachmentDraftsChange: (files: File[]) => void;
   onSendMessage: (event: FormEvent<HTMLFormElement>) => void;
   onComposerKeyDown: (event: KeyboardEvent<HTMLTextAreaElement>) => void;
   isSendingMessage: boolean;
@@ -58,8 +68,11 @@ export function ConversationMainPanel({
   visibleMessages,
   nextCursor,
   onLoadOlderMessages,
+  messageStatusHistory,
   messageDraft,
   onMessageDraftChange,
+  attachmentDrafts,
+  onAttachmentDraftsChange,
   onSendMessage,
   onComposerKeyDown,
   isSendingMessage,
@@ -94,9 +107,17 @@ export function ConversationMainPanel({
               </p>
               <div className="flex flex-wrap items-center gap-2">
                 <h2 className="font-display text-2xl font-semibold tracking-tight text-foreground">
-                  {selectedConversation.recipientPhoneNumber ??
-                    `Conversation ${selectedConversation.id.slice(0, 8)}`}
+                  {conversationTitle(selectedConversation)}
                 </h2>
+                {selectedConversation.contactId &&
+                selectedConversation.recipientPhoneNumber ? (
+                  <Badge
+                    variant="outline"
+                    className="rounded-full px-3 py-1 text-muted-foreground"
+                  >
+                    {selectedConversation.recipientPhoneNumber}
+                  </Badge>
+                ) : null}
                 {selectedPhoneNumber ? (
                   <Badge
                     variant="outline"
@@ -147,11 +168,14 @@ export function ConversationMainPanel({
           messages={visibleMessages}
           nextCursor={nextCursor}
           onLoadOlderMessages={onLoadOlderMessages}
+          messageStatusHistory={messageStatusHistory}
         />
 
         <MessageComposer
           messageDraft={messageDraft}
           onMessageDraftChange={onMessageDraftChange}
+          attachmentDrafts={attachmentDrafts}
+          onAttachmentDraftsChange={onAttachmentDraftsChange}
           onSendMessage={onSendMessage}
           onComposerKeyDown={onComposerKeyDown}
           isSendingMessage={isSendingMessage}
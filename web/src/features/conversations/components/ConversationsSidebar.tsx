@@ -13,13 +13,49 @@ import {
 } from "@/components/ui/alert-dialog";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
+import { Tabs, TabsList, TabsTrigger } from "@/components/ui/tabs";
 import { cn } from "@/lib/utils";
 import { Trash2 } from "lucide-react";
-import type { Conversation } from "../types";
-import { formatConversationTime, getLatestMessage } from "../utils/message-utils";
+import type {
+  Conversation,
+  ConversationState,
+  ConversationView,
+  OrganizationMemberRecord,
+} from "../types";
+import {
+  assigneeLabel,
+  conversationTitle,
+  formatConversationTime,
+  getLatestMessage,
+} from "../utils/message-utils";
+
+const VIEW_TABS: { value: ConversationView; label: string }[] = [
+  { value: "all", label: "All" },
+  { value: "mine", label: "Mine" },
+  { value: "unassigned", label: "Unassigned" },
+];
+
+const STATE_TABS: { value: ConversationState; label: string }[] = [
+  { value: "inbox", label: "Inbox" },
+  { value: "pinned", label: "Pinned" },
+  { value: "snoozed", label: "Snoozed" },
+  { value: "archived", label: "Archived" },
+];
+
+const TABS_LIST_CLASS_NAME = "grid h-auto w-full rounded-2xl bg-white/[0.06] p-1";
+const TABS_TRIGGER_CLASS_NAME =
+  "rounded-xl px-2 py-1.5 text-xs text-white/65 data-[state=active]:bg-white data-[state=active]:text-slate-950";
 
 type ConversationsSidebarProps = {
   conversations: Conversation[];
+  view: ConversationView;
+  listState: ConversationState;
+  onChangeListFilter: (filter: {
+    view?: ConversationView;
+    state?: ConversationState;
+  }) => void;
+  currentUserId: string | null;
+  members: OrganizationMemberRecord[];
   selectedConversationId: string | null;
   onSelectConversation: (conversationId: string) => void;
   deletingConversationId: string | null;
@@ -28,6 +64,11 @@ type ConversationsSidebarProps = {
 
 export function ConversationsSidebar({
   conversations,
+  view,
+  listState,
+  onChangeListFilter,
+  currentUserId,
+  members,
   selectedConversationId,
   onSelectConversation,
   deletingConversationId,
@@ -51,12 +92,58 @@ export function ConversationsSidebar({
             {conversations.length}
           </Badge>
         </div>
+
+        <div className="mt-4 space-y-2">
+          <Tabs
+            value={view}
+            onValueChange={(value) =>
+              onChangeListFilter({ view: value as ConversationView })
+            }
+          >
+            <TabsList
+              className={cn(TABS_LIST_CLASS_NAME, "grid-cols-3")}
+              aria-label="Assignee"
+            >
+              {VIEW_TABS.map((tab) => (
+                <TabsTrigger
+                  key={tab.value}
+                  value={tab.value}
+                  className={TABS_TRIGGER_CLASS_NAME}
+                >
+                  {tab.label}
+                </TabsTrigger>
+              ))}
+            </TabsList>
+          </Tabs>
+          <Tabs
+            value={listState}
+            onValueChange={(value) =>
+              onChangeListFilter({ state: value as ConversationState })
+            }
+          >
+            <TabsList
+              className={cn(TABS_LIST_CLASS_NAME, "grid-cols-4")}
+              aria-label="State"
+            >
+              {STATE_TABS.map((tab) => (
+                <TabsTrigger
+                  key={tab.value}
+                  value={tab.value}
+                  className={TABS_TRIGGER_CLASS_NAME}
+                >
+                  {tab.label}
+                </TabsTrigger>
+              ))}
+            </TabsList>
+          </Tabs>
+        </div>
       </div>
 
       {conversations.length === 0 ? (
         <div className="rounded-[1.5rem] border border-dashed border-white/12 bg-black/10 p-4 text-sm text-white/60">
-          No conversations available yet. Open a new thread to populate this
-          queue.
+          {listState === "inbox" && view === "all"
+            ? "No conversations available yet. Open a new thread to populate this queue."
+            : "No conversations match these filters."}
         </div>
       ) : (
         <div className="space-y-2">
@@ -66,6 +153,7 @@ export function ConversationsSidebar({
             const isDeleting = deletingConversationId === conversation.id;
             const isActionVisible =
               actionConversationId === conversation.id || isDeleting;
+            const hasUnread = !isActive && conversation.unreadCount > 0;
 
             return (
               <div
@@ -98,10 +186,24 @@ export function ConversationsSidebar({
                 >
                   <div className="flex items-start justify-between gap-3">
                     <div className="min-w-0">
-                      <p className="truncate text-sm font-medium">
-                        {conversation.recipientPhoneNumber ??
-                          `Conversation ${conversation.id.slice(0, 8)}`}
-                      </p>
+                      <div className="flex items-center gap-2">
+                        <p
+                          className={cn(
+                            "truncate text-sm",
+                            hasUnread ? "font-semibold text-white" : "font-medium",
+                          )}
+                        >
+                          {conversationTitle(conversation)}
+                        </p>
+                        {hasUnread ? (
+                          <Badge
+                            className="shrink-0 rounded-full bg-emerald-400/90 px-2 py-0.5 text-[11px] text-slate-950 shadow-none hover:bg-emerald-400/90"
+                            aria-label={`${conversation.unreadCount} unread`}
+                          >
+                            {conversation.unreadCount}
+                          </Badge>
+                        ) : null}
+                      </div>
                       <p
                         className={cn(
                           "mt-2 truncate text-xs leading-5",
@@ -112,6 +214,14 @@ export function ConversationsSidebar({
                           conversation.recipientPhoneNumber ??
                           "No messages yet"}
                       </p>
+                      <p
+                        className={cn(
+                          "mt-1 truncate text-[11px]",
+                          isActive ? "text-slate-500" : "text-white/45",
+                        )}
+                      >
+                        {assigneeLabel(conversation, currentUserId, members)}
+                      </p>
                     </div>
                   </div>
                 </button>
@@ -147,7 +257,7 @@ export function ConversationsSidebar({
                             : "pointer-events-none opacity-0",
                         )}
                         disabled={deletingConversationId !== null}
-                        aria-label={`Delete ${conversation.recipientPhoneNumber ?? `conversation ${conversation.id.slice(0, 8)}`}`}
+                        aria-label={`Delete ${conversationTitle(conversation)}`}
                       >
                         <Trash2 className="size-4" />
                       </Button>
@@ -158,8 +268,7 @@ export function ConversationsSidebar({
                         <AlertDialogDescription className="leading-6">
                           This permanently removes the thread with{" "}
                           <span className="font-medium text-foreground">
-                            {conversation.recipientPhoneNumber ??
-                              `Conversation ${conversation.id.slice(0, 8)}`}
+                            {conversationTitle(conversation)}
                           </span>
                           . Message history for this thread will no longer be
                           available in the workspace.
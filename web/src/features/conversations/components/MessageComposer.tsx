@@ -1,12 +1,19 @@
-import type { FormEvent, KeyboardEvent } from "react";
-import { SendHorizontal } from "lucide-react";
+import { useRef, type FormEvent, type KeyboardEvent } from "react";
+import { Paperclip, SendHorizontal, X } from "lucide-react";
 
 import { Button } from "@/components/ui/button";
 import { Textarea } from "@/components/ui/textarea";
 
+import { formatFileSize } from "../utils/message-utils";
+
+const ACCEPTED_ATTACHMENT_TYPES = "image/*,video/*,audio/*,application/pdf,text/vcard";
+const MAX_ATTACHMENTS = 10;
+
 type MessageComposerProps = {
   messageDraft: string;
   onMessageDraftChange: (draft: string) => void;
+  attachmentDrafts: File[];
+  onAttachmentDraftsChange: (files: File[]) => void;
   onSendMessage: (event: FormEvent<HTMLFormElement>) => void;
   onComposerKeyDown: (event: KeyboardEvent<HTMLTextAreaElement>) => void;
   isSendingMessage: boolean;
@@ -15,10 +22,32 @@ type MessageComposerProps = {
 export function MessageComposer({
   messageDraft,
   onMessageDraftChange,
+  attachmentDrafts,
+  onAttachmentDraftsChange,
   onSendMessage,
   onComposerKeyDown,
   isSendingMessage,
 }: MessageComposerProps) {
+  const fileInputRef = useRef<HTMLInputElement>(null);
+
+  function addAttachments(files: FileList | null) {
+    if (!files) {
+      return;
+    }
+
+    onAttachmentDraftsChange(
+      [...attachmentDrafts, ...Array.from(files)].slice(0, MAX_ATTACHMENTS),
+    );
+
+    if (fileInputRef.current) {
+      fileInputRef.current.value = "";
+    }
+  }
+
+  function removeAttachment(index: number) {
+    onAttachmentDraftsChange(attachmentDrafts.filter((_, fileIndex) => fileIndex !== index));
+  }
+
   return (
     <form
       onSubmit={onSendMessage}
@@ -34,6 +63,29 @@ export function MessageComposer({
           disabled={isSendingMessage}
         />
 
+        {attachmentDrafts.length > 0 ? (
+          <ul className="mt-3 flex flex-wrap gap-2">
+            {attachmentDrafts.map((file, index) => (
+              <li
+                key={`${file.name}-${index}`}
+                className="flex items-center gap-2 rounded-full border border-border/80 bg-background/80 px-3 py-1 text-xs text-foreground"
+              >
+                <span className="max-w-48 truncate">{file.name}</span>
+                <span className="text-muted-foreground">{formatFileSize(file.size)}</span>
+                <button
+                  type="button"
+                  aria-label={`Remove ${file.name}`}
+                  className="text-muted-foreground hover:text-foreground"
+                  onClick={() => removeAttachment(index)}
+                  disabled={isSendingMessage}
+                >
+                  <X className="size-3" />
+                </button>
+              </li>
+            ))}
+          </ul>
+        ) : null}
+
         <div className="mt-4 flex flex-col gap-3 border-t border-border/70 pt-4 sm:flex-row sm:items-center sm:justify-between">
           <div className="space-y-1">
             <p className="text-xs font-medium text-foreground">
@@ -44,14 +96,34 @@ export function MessageComposer({
             </p>
           </div>
 
-          <Button
-            type="submit"
-            className="rounded-full px-5"
-            disabled={isSendingMessage}
-          >
-            <SendHorizontal className="size-4" />
-            {isSendingMessage ? "Sending..." : "Send message"}
-          </Button>
+          <div className="flex items-center gap-2">
+            <input
+              ref={fileInputRef}
+              type="file"
+              multiple
+              accept={ACCEPTED_ATTACHMENT_TYPES}
+              className="hidden"
+              onChange={(event) => addAttachments(event.target.files)}
+            />
+            <Button
+              type="button"
+              variant="outline"
+              className="rounded-full px-4"
+              onClick={() => fileInputRef.current?.click()}
+              disabled={isSendingMessage || attachmentDrafts.length >= MAX_ATTACHMENTS}
+            >
+              <Paperclip className="size-4" />
+              Attach
+            </Button>
+            <Button
+              type="submit"
+              className="rounded-full px-5"
+              disabled={isSendingMessage}
+            >
+              <SendHorizontal className="size-4" />
+              {isSendingMessage ? "Sending..." : "Send message"}
+            </Button>
+          </div>
         </div>
       </div>
     </form>
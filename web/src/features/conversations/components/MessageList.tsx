@@ -8,18 +8,20 @@ import {
 import { LoaderCircle } from "lucide-react";
 
 import { cn } from "@/lib/utils";
-import type { Message } from "../types";
+import type { Message, MessageStatusEventRecord } from "../types";
 import {
   formatMessageStatus,
   formatMessageTime,
   messageStatusClassName,
 } from "../utils/message-utils";
+import { MessageStatusTimeline } from "./MessageStatusTimeline";
 
 type MessageListProps = {
   conversationId: string;
   messages: Message[];
   nextCursor: string | null;
   onLoadOlderMessages: () => Promise<boolean>;
+  messageStatusHistory: Record<string, MessageStatusEventRecord[]>;
 };
 
 type PendingPrependState = {
@@ -33,6 +35,7 @@ export function MessageList({
   messages,
   nextCursor,
   onLoadOlderMessages,
+  messageStatusHistory,
 }: MessageListProps) {
   const scrollContainerRef = useRef<HTMLDivElement | null>(null);
   const topSentinelRef = useRef<HTMLDivElement | null>(null);
@@ -207,6 +210,13 @@ export function MessageList({
                   </span>{" "}
                   - {formatMessageTime(message.createdAt)}
                 </p>
+                {!inbound ? (
+                  <MessageStatusTimeline
+                    conversationId={conversationId}
+                    message={message}
+                    initialEvents={messageStatusHistory[message.id]}
+                  />
+                ) : null}
               </div>
             </div>
           );
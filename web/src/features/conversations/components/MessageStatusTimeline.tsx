@@ -0,0 +1,123 @@
+import { useState } from "react";
+import { ChevronDown, LoaderCircle } from "lucide-react";
+
+import {
+  Collapsible,
+  CollapsibleContent,
+  CollapsibleTrigger,
+} from "@/components/ui/collapsible";
+import { cn } from "@/lib/utils";
+import type {
+  Message,
+  MessageStatusEventRecord,
+  MessageStatusHistoryResponse,
+} from "../types";
+import { formatMessageStatus, formatMessageTime } from "../utils/message-utils";
+
+type MessageStatusTimelineProps = {
+  conversationId: string;
+  message: Message;
+  initialEvents?: MessageStatusEventRecord[];
+};
+
+export function MessageStatusTimeline({
+  conversationId,
+  message,
+  initialEvents,
+}: MessageStatusTimelineProps) {
+  const [open, setOpen] = useState(false);
+  const [events, setEvents] = useState<MessageStatusEventRecord[]>(
+    initialEvents ?? [],
+  );
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  async function loadHistory() {
+    setIsLoading(true);
+    setError(null);
+
+    try {
+      const response = await fetch(
+        `/conversations/${encodeURIComponent(conversationId)}/messages/${encodeURIComponent(message.id)}/status-history`,
+        {
+          method: "GET",
+          headers: {
+            Accept: "application/json",
+          },
+          credentials: "same-origin",
+        },
+      );
+
+      if (!response.ok) {
+        throw new Error("Unable to load delivery history right now.");
+      }
+
+      const payload = (await response.json()) as MessageStatusHistoryResponse;
+      setEvents(payload.events ?? []);
+    } catch (err) {
+      setError(
+        err instanceof Error
+          ? err.message
+          : "Unable to load delivery history right now.",
+      );
+    } finally {
+      setIsLoading(false);
+    }
+  }
+
+  function handleOpenChange(nextOpen: boolean) {
+    setOpen(nextOpen);
+    // Webhooks keep arriving while the thread is open, so refresh on every expand.
+    if (nextOpen) {
+      void loadHistory();
+    }
+  }
+
+  return (
+    <Collapsible open={open} onOpenChange={handleOpenChange}>
+      <CollapsibleTrigger className="mt-2 flex items-center gap-1 text-[11px] text-primary-foreground/78 hover:text-primary-foreground">
+        <ChevronDown
+          className={cn("size-3 transition-transform", open && "rotate-180")}
+        />
+        Delivery history
+      </CollapsibleTrigger>
+      <CollapsibleContent>
+        <ol className="mt-2 space-y-2 border-l border-primary-foreground/30 pl-3 text-[11px] text-primary-foreground/85">
+          {events.map((event) => (
+            <li key={event.id}>
+              <span className="font-medium">
+                {formatMessageStatus(event.status)}
+              </span>
+              {event.providerStatus && event.providerStatus !== event.status
+                ? ` (${event.providerStatus})`
+                : null}{" "}
+              - {formatMessageTime(event.occurredAt)}
+              {event.carrier ? (
+                <span className="block text-primary-foreground/70">
+                  via {event.carrier}
+                </span>
+              ) : null}
+              {event.errorCode || event.errorDetail ? (
+                <span className="block text-primary-foreground/70">
+                  {[event.errorCode, event.errorDetail]
+                    .filter(Boolean)
+                    .join(": ")}
+                </span>
+              ) : null}
+            </li>
+          ))}
+          {isLoading ? (
+            <li className="flex items-center gap-1">
+              <LoaderCircle className="size-3 animate-spin" />
+              Loading
+            </li>
+          ) : null}
+          {!isLoading && error ? <li>{error}</li> : null}
+          {!isLoading && !error && events.length === 0 ? (
+            <li>No delivery updates yet.</li>
+          ) : null}
+        </ol>
+      </CollapsibleContent>
+    </Collapsible>
+  );
+}
@@ -13,15 +13,19 @@ import { toast } from "sonner";
 import {
   PAGE_SIZE,
   compareMessagesAsc,
+  mapAttachmentRecord,
 } from "../utils/message-utils";
 import type {
   Conversation,
+  ConversationState,
+  ConversationView,
   ConversationsPageProps,
   Message,
   MessageRecord,
   MessagesPageResponse,
   MessageWindow,
   PhoneNumber,
+  RealtimeConversationReadEvent,
   RealtimeMessageEvent,
   RealtimeMessageEventType,
   SentMediaItem,
@@ -37,7 +41,7 @@ type ErrorResponse = { error?: string };
 function mapMessageRecord(record: MessageRecord): Message {
   return {
     ...record,
-    mediaFiles: [],
+    mediaFiles: (record.attachments ?? []).map(mapAttachmentRecord),
   };
 }
 
@@ -50,7 +54,11 @@ function mapConversationRecord(
     phoneNumberId: record.phoneNumberId,
     userId: record.userId,
     recipientPhoneNumber: record.recipientPhoneNumber ?? null,
+    contactId: record.contactId ?? null,
+    displayName: record.displayName ?? null,
+    assignedUserId: record.assignedUserId ?? null,
     lastMessageAt: record.lastMessageAt,
+    unreadCount: record.unreadCount ?? 0,
     createdAt: record.createdAt,
     updatedAt: record.updatedAt,
     messages,
@@ -92,6 +100,11 @@ export function useConversationsController() {
     string | null
   >(null);
 
+  const view = props.view ?? "all";
+  const listState = props.state ?? "inbox";
+  const currentUserId = props.currentUserId ?? null;
+  const members = useMemo(() => props.members ?? [], [props.members]);
+
   const phoneNumbers = useMemo<PhoneNumber[]>(
     () => (props.phoneNumbers ?? []).map((item) => ({ ...item })),
     [props.phoneNumbers],
@@ -117,6 +130,7 @@ export function useConversationsController() {
     Record<string, MessageWindow>
   >({});
   const [messageDraft, setMessageDraft] = useState("");
+  const [attachmentDrafts, setAttachmentDrafts] = useState<File[]>([]);
   const [isCreateConversationDialogOpen, setIsCreateConversationDialogOpen] =
     useState(false);
   const [fromPhoneNumberId, setFromPhoneNumberId] = useState<string>(
@@ -143,8 +157,16 @@ export function useConversationsController() {
   }, [url]);
 
   const applyRealtimeEvent = useEffectEvent(
-    (_eventType: RealtimeMessageEventType, payload: RealtimeMessageEvent) => {
+    (eventType: RealtimeMessageEventType, payload: RealtimeMessageEvent) => {
       const incomingMessage = mapMessageRecord(payload.message);
+      const isNewInbound =
+        eventType === "message.created" && incomingMessage.messageType === "INBOUND";
+      const isOpen = payload.conversation.id === selectedConversationId;
+
+      // Replies landing in the open thread are read as they arrive.
+      if (isNewInbound && isOpen) {
+        void markConversationRead(payload.conversation.id);
+      }
 
       startTransition(() => {
         setConversations((prev) => {
@@ -158,6 +180,10 @@ export function useConversationsController() {
             return {
               ...conversation,
               ...mapConversationRecord(payload.conversation),
+              unreadCount:
+                isNewInbound && !isOpen
+                  ? conversation.unreadCount + 1
+                  : conversation.unreadCount,
               messages: upsertMessages(conversation.messages, incomingMessage),
             };
           });
@@ -167,7 +193,10 @@ export function useConversationsController() {
           }
 
           return [
-            mapConversationRecord(payload.conversation, [incomingMessage]),
+            {
+              ...mapConversationRecord(payload.conversation, [incomingMessage]),
+              unreadCount: isNewInbound && !isOpen ? 1 : 0,
+            },
             ...prev,
           ];
         });
@@ -190,6 +219,20 @@ export function useConversationsController() {
     },
   );
 
+  const applyConversationRead = useEffectEvent(
+    (payload: RealtimeConversationReadEvent) => {
+      startTransition(() => {
+        setConversations((prev) =>
+          prev.map((conversation) =>
+            conversation.id === payload.conversationId
+              ? { ...conversation, unreadCount: 0 }
+              : conversation,
+          ),
+        );
+      });
+    },
+  );
+
   useEffect(() => {
     const eventSource = new EventSource("/events/messages");
     const registerHandler = (eventType: RealtimeMessageEventType) => {
@@ -212,10 +255,25 @@ export function useConversationsController() {
 
     const createdHandler = registerHandler("message.created");
     const updatedHandler = registerHandler("message.updated");
+    const readHandler = (event: Event) => {
+      if (!(event instanceof MessageEvent)) {
+        return;
+      }
+
+      try {
+        applyConversationRead(
+          JSON.parse(event.data) as RealtimeConversationReadEvent,
+        );
+      } catch {
+        // Ignore malformed realtime payloads and keep the stream alive.
+      }
+    };
+    eventSource.addEventListener("conversation.read", readHandler);
 
     return () => {
       eventSource.removeEventListener("message.created", createdHandler);
       eventSource.removeEventListener("message.updated", updatedHandler);
+      eventSource.removeEventListener("conversation.read", readHandler);
       eventSource.close();
     };
   }, []);
@@ -232,12 +290,23 @@ export function useConversationsController() {
       return null;
     }
 
-    return (
-      sortedConversations.find(
-        (conversation) => conversation.id === selectedConversationId,
-      ) ?? null
+    const listed = sortedConversations.find(
+      (conversation) => conversation.id === selectedConversationId,
     );
-  }, [selectedConversationId, sortedConversations]);
+    if (listed) {
+      return listed;
+    }
+
+    // Archived or snoozed threads can be open while the sidebar shows the inbox.
+    return props.conversation?.id === selectedConversationId
+      ? mapConversationRecord(props.conversation, selectedConversationMessages)
+      : null;
+  }, [
+    props.conversation,
+    selectedConversationId,
+    selectedConversationMessages,
+    sortedConversations,
+  ]);
 
   const selectedPhoneNumber = useMemo(() => {
     if (!selectedConversation) {
@@ -297,6 +366,20 @@ export function useConversationsController() {
     ? selectedWindow.nextCursor
     : fallbackMessageWindow?.nextCursor ?? null;
 
+  async function markConversationRead(conversationId: string) {
+    try {
+      await fetch(`/conversations/${encodeURIComponent(conversationId)}/read`, {
+        method: "POST",
+        headers: {
+          Accept: "application/json",
+        },
+        credentials: "same-origin",
+      });
+    } catch {
+      // The badge catches up on the next page load, so a failed call is not worth a toast.
+    }
+  }
+
   async function loadOlderMessages(): Promise<boolean> {
     if (!selectedConversationId || !nextCursor) {
       return false;
@@ -390,7 +473,7 @@ export function useConversationsController() {
     }
 
     const content = messageDraft.trim();
-    if (!content) {
+    if (!content && attachmentDrafts.length === 0) {
       return;
     }
 
@@ -404,9 +487,23 @@ export function useConversationsController() {
     setIsSendingMessage(true);
 
     try {
-      const response = await fetch(
-        `/conversations/${encodeURIComponent(selectedConversationId)}/messages`,
-        {
+      const messagesUrl = `/conversations/${encodeURIComponent(selectedConversationId)}/messages`;
+      let response: Response;
+      if (attachmentDrafts.length > 0) {
+        const formData = new FormData();
+        formData.append("content", content);
+        attachmentDrafts.forEach((file) => formData.append("files", file, file.name));
+
+        response = await fetch(`${messagesUrl}/media`, {
+          method: "POST",
+          headers: {
+            Accept: "application/json",
+          },
+          credentials: "same-origin",
+          body: formData,
+        });
+      } else {
+        response = await fetch(messagesUrl, {
           method: "POST",
           headers: {
             "Content-Type": "application/json",
@@ -414,8 +511,8 @@ export function useConversationsController() {
           },
           credentials: "same-origin",
           body: JSON.stringify({ content }),
-        },
-      );
+        });
+      }
 
       if (!response.ok) {
         let errorMessage = "Unable to send message right now.";
@@ -465,6 +562,7 @@ export function useConversationsController() {
       });
 
       setMessageDraft("");
+      setAttachmentDrafts([]);
     } catch (error) {
       toast.error(
         error instanceof Error ? error.message : "Unable to send message right now.",
@@ -487,9 +585,30 @@ export function useConversationsController() {
   }
 
   function selectConversation(conversationId: string) {
-    router.get(`/conversations/${encodeURIComponent(conversationId)}`, {}, {
-      preserveScroll: true,
-    });
+    // Keeps the sidebar's view and state filters while switching threads.
+    router.get(
+      `/conversations/${encodeURIComponent(conversationId)}${window.location.search}`,
+      {},
+      {
+        preserveScroll: true,
+      },
+    );
+  }
+
+  function changeListFilter(filter: {
+    view?: ConversationView;
+    state?: ConversationState;
+  }) {
+    const path = selectedConversationId
+      ? `/conversations/${encodeURIComponent(selectedConversationId)}`
+      : "/conversations";
+    router.get(
+      path,
+      { view: filter.view ?? view, state: filter.state ?? listState },
+      {
+        preserveScroll: true,
+      },
+    );
   }
 
   function deleteConversation(conversationId: string) {
@@ -565,6 +684,11 @@ export function useConversationsController() {
     logout,
     phoneNumbers,
     sortedConversations,
+    view,
+    listState,
+    changeListFilter,
+    currentUserId,
+    members,
     selectedConversationId,
     selectConversation,
     deletingConversationId,
@@ -583,8 +707,11 @@ export function useConversationsController() {
     visibleMessages,
     nextCursor,
     loadOlderMessages,
+    messageStatusHistory: props.messageStatusHistory ?? {},
     messageDraft,
     setMessageDraft,
+    attachmentDrafts,
+    setAttachmentDrafts,
     isSendingMessage,
     sendMessage,
     composerKeyDown,
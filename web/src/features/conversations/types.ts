@@ -3,20 +3,39 @@ import type { PropsWithFlash } from "@/lib/types";
 export type MessageType = "INBOUND" | "OUTBOUND";
 export type MessageStatus = "pending" | "queued" | "delivered" | "failed" | "sent";
 export type MediaKind = "image" | "video" | "document";
+export type ConversationView = "all" | "mine" | "unassigned";
+export type ConversationState = "inbox" | "pinned" | "snoozed" | "archived";
 
 export type MediaFile = {
   id: string;
   name: string;
   kind: MediaKind;
   sizeLabel: string;
+  url?: string;
 };
 
+export interface MessageAttachmentRecord {
+  id: string;
+  fileName: string;
+  contentType: string;
+  sizeBytes: number;
+  url?: string | null;
+}
+
 export interface ConversationRecord {
   id: string;
   phoneNumberId: string;
   userId: string;
   recipientPhoneNumber?: string | null;
+  contactId?: string | null;
+  displayName?: string | null;
+  assignedUserId?: string | null;
+  assignedAt?: string | null;
+  archivedAt?: string | null;
+  pinnedAt?: string | null;
+  snoozedUntil?: string | null;
   lastMessageAt: string;
+  unreadCount?: number | null;
   createdAt: string;
   updatedAt: string;
 }
@@ -36,6 +55,29 @@ export interface MessageRecord {
   content: string;
   createdAt: string;
   updatedAt: string;
+  attachments?: MessageAttachmentRecord[];
+}
+
+export interface MessageStatusEventRecord {
+  id: string;
+  messageId: string;
+  status: MessageStatus;
+  providerStatus?: string | null;
+  carrier?: string | null;
+  errorCode?: string | null;
+  errorDetail?: string | null;
+  occurredAt: string;
+}
+
+export interface MessageStatusHistoryResponse {
+  events: MessageStatusEventRecord[];
+}
+
+export interface OrganizationMemberRecord {
+  userId: string;
+  email?: string | null;
+  role: string;
+  joinedAt: string;
 }
 
 export interface PhoneNumberRecord {
@@ -56,7 +98,11 @@ export interface Conversation {
   phoneNumberId: string;
   userId: string;
   recipientPhoneNumber?: string | null;
+  contactId?: string | null;
+  displayName?: string | null;
+  assignedUserId: string | null;
   lastMessageAt: string;
+  unreadCount: number;
   createdAt: string;
   updatedAt: string;
   messages: Message[];
@@ -89,6 +135,12 @@ export interface RealtimeMessageEvent {
   conversation: ConversationRecord;
 }
 
+export interface RealtimeConversationReadEvent {
+  type: "conversation.read";
+  conversationId: string;
+  totalUnread: number;
+}
+
 export type SentMediaItem = MediaFile & {
   messageId: string;
   sentAt: string;
@@ -97,9 +149,14 @@ export type SentMediaItem = MediaFile & {
 
 export interface ConversationsPageProps extends PropsWithFlash {
   [key: string]: unknown;
+  view?: ConversationView;
+  state?: ConversationState;
+  currentUserId?: string | null;
+  members?: OrganizationMemberRecord[];
   conversations?: ConversationRecord[];
   conversation?: ConversationRecord | null;
   messages?: MessageRecord[];
   messagesNextCursor?: string | null;
+  messageStatusHistory?: Record<string, MessageStatusEventRecord[]>;
   phoneNumbers?: PhoneNumberRecord[];
 }
@@ -1,8 +1,11 @@
 import type {
   Conversation,
+  MediaFile,
   MediaKind,
   Message,
+  MessageAttachmentRecord,
   MessageStatus,
+  OrganizationMemberRecord,
 } from "../types";
 
 export const PAGE_SIZE = 10;
@@ -57,6 +60,33 @@ export function getLatestMessage(conversation: Conversation): Message | undefine
   return [...conversation.messages].sort(compareMessagesDesc)[0];
 }
 
+/** Contact name when the recipient is in the address book, otherwise the raw number. */
+export function conversationTitle(conversation: Conversation): string {
+  return (
+    conversation.displayName ??
+    conversation.recipientPhoneNumber ??
+    `Conversation ${conversation.id.slice(0, 8)}`
+  );
+}
+
+export function assigneeLabel(
+  conversation: Conversation,
+  currentUserId: string | null,
+  members: OrganizationMemberRecord[],
+): string {
+  if (!conversation.assignedUserId) {
+    return "Unassigned";
+  }
+  if (conversation.assignedUserId === currentUserId) {
+    return "Assigned to you";
+  }
+
+  const assignee = members.find(
+    (member) => member.userId === conversation.assignedUserId,
+  );
+  return `Assigned to ${assignee?.email ?? "another member"}`;
+}
+
 export function formatMessageTime(dateValue: string): string {
   const timestamp = parseDateValue(dateValue);
   if (timestamp === null) {
@@ -131,3 +161,37 @@ export function mediaKindToneClassName(kind: MediaKind): string {
       return "border-emerald-300 bg-emerald-50 text-emerald-700";
   }
 }
+
+export function mediaKindFromContentType(contentType: string): MediaKind {
+  if (contentType.startsWith("image/")) {
+    return "image";
+  }
+
+  if (contentType.startsWith("video/")) {
+    return "video";
+  }
+
+  return "document";
+}
+
+export function formatFileSize(sizeBytes: number): string {
+  if (sizeBytes < 1024) {
+    return `${sizeBytes} B`;
+  }
+
+  if (sizeBytes < 1024 * 1024) {
+    return `${(sizeBytes / 1024).toFixed(1)} KB`;
+  }
+
+  return `${(sizeBytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+export function mapAttachmentRecord(record: MessageAttachmentRecord): MediaFile {
+  return {
+    id: record.id,
+    name: record.fileName,
+    kind: mediaKindFromContentType(record.contentType),
+    sizeLabel: formatFileSize(record.sizeBytes),
+    url: record.url ?? undefined,
+  };
+}
@@ -1,5 +1,6 @@
 import { AnalyticsOverview } from "./components/AnalyticsOverview";
 import { AddPhoneNumberDialog } from "./components/AddPhoneNumberDialog";
+import { EmailVerificationBanner } from "./components/EmailVerificationBanner";
 import { PhoneNumbersCard } from "./components/PhoneNumbersCard";
 import { useDashboardController } from "./hooks/use-dashboard-controller";
 import type { DashboardPageProps } from "./types";
@@ -96,10 +97,19 @@ function DashboardFeature({ pageProps }: DashboardFeatureProps) {
       }
     >
       <div className="space-y-6">
+        {!controller.emailVerified && (
+          <EmailVerificationBanner
+            isResending={controller.isResendingVerification}
+            onResend={controller.resendVerificationEmail}
+          />
+        )}
+
         <AnalyticsOverview
           totalConversations={controller.totalConversations}
           totalMessages={controller.totalMessages}
           totalPhoneNumbers={controller.totalPhoneNumbers}
+          unreadConversations={controller.unreadConversations}
+          unreadMessages={controller.unreadMessages}
         />
 
         <PhoneNumbersCard
@@ -5,12 +5,16 @@ type AnalyticsOverviewProps = {
   totalConversations: number;
   totalMessages: number;
   totalPhoneNumbers: number;
+  unreadConversations: number;
+  unreadMessages: number;
 };
 
 export function AnalyticsOverview({
   totalConversations,
   totalMessages,
   totalPhoneNumbers,
+  unreadConversations,
+  unreadMessages,
 }: AnalyticsOverviewProps) {
   return (
     <section className="relative overflow-hidden rounded-[2rem] border border-border/80 bg-card/90 p-6 shadow-[0_30px_90px_-55px_rgba(15,23,42,0.5)] sm:p-7">
@@ -68,11 +72,20 @@ export function AnalyticsOverview({
               </p>
               <MessageSquareText className="size-4 text-muted-foreground" />
             </div>
-            <p className="mt-6 font-display text-4xl font-semibold tracking-tight text-foreground">
-              {totalConversations}
-            </p>
+            <div className="mt-6 flex items-baseline gap-3">
+              <p className="font-display text-4xl font-semibold tracking-tight text-foreground">
+                {totalConversations}
+              </p>
+              {unreadConversations > 0 ? (
+                <Badge className="rounded-full px-2.5 py-1 shadow-none">
+                  {unreadConversations} unread
+                </Badge>
+              ) : null}
+            </div>
             <p className="mt-3 text-sm leading-6 text-muted-foreground">
-              Current threads available for follow-up and reply workflows.
+              {unreadMessages > 0
+                ? `${unreadMessages} new ${unreadMessages === 1 ? "message" : "messages"} waiting for you to read.`
+                : "Current threads available for follow-up and reply workflows."}
             </p>
           </div>
 
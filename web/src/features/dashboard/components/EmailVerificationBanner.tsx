@@ -0,0 +1,36 @@
+import { Alert, AlertDescription, AlertTitle } from "@/components/ui/alert";
+import { Button } from "@/components/ui/button";
+import { MailWarning } from "lucide-react";
+
+type EmailVerificationBannerProps = {
+  isResending: boolean;
+  onResend: () => void;
+};
+
+export function EmailVerificationBanner({
+  isResending,
+  onResend,
+}: EmailVerificationBannerProps) {
+  return (
+    <Alert className="rounded-[1.5rem] border-amber-500/40 bg-amber-50/80 text-amber-950 dark:bg-amber-500/10 dark:text-amber-100">
+      <MailWarning className="size-4" />
+      <AlertTitle>Verify your email address</AlertTitle>
+      <AlertDescription className="flex flex-col gap-3 sm:flex-row sm:items-center sm:justify-between">
+        <span>
+          We sent a verification link when you signed up. Some workspaces
+          require a verified address before messages can be sent.
+        </span>
+        <Button
+          type="button"
+          variant="outline"
+          size="sm"
+          className="rounded-full"
+          disabled={isResending}
+          onClick={onResend}
+        >
+          {isResending ? "Sending..." : "Resend verification email"}
+        </Button>
+      </AlertDescription>
+    </Alert>
+  );
+}
@@ -28,6 +28,8 @@ export function useDashboardController(props: DashboardPageProps) {
   const [phoneValidation, setPhoneValidation] =
     useState<PhoneValidationResult | null>(null);
   const [isAddPhoneDialogOpen, setIsAddPhoneDialogOpen] = useState(false);
+  const [isResendingVerification, setIsResendingVerification] =
+    useState(false);
 
   function resetAddPhoneForm() {
     setPhoneNameInput("");
@@ -86,6 +88,27 @@ export function useDashboardController(props: DashboardPageProps) {
     postLogout("/auth/logout");
   }
 
+  function resendVerificationEmail() {
+    if (isResendingVerification) {
+      return;
+    }
+
+    setIsResendingVerification(true);
+    router.post(
+      "/auth/verify-email/resend",
+      {},
+      {
+        preserveScroll: true,
+        onError: () => {
+          toast.error("Unable to send the verification email right now.");
+        },
+        onFinish: () => {
+          setIsResendingVerification(false);
+        },
+      },
+    );
+  }
+
   function deletePhoneNumber(phoneNumberId: string) {
     if (deletingPhoneNumberId) {
       return;
@@ -109,6 +132,11 @@ export function useDashboardController(props: DashboardPageProps) {
     totalConversations: props.analytics.totalConversations,
     totalMessages: props.analytics.totalMessages,
     totalPhoneNumbers: props.analytics.totalPhoneNumbers,
+    unreadConversations: props.analytics.unreadConversations,
+    unreadMessages: props.analytics.unreadMessages,
+    emailVerified: props.emailVerified ?? true,
+    isResendingVerification,
+    resendVerificationEmail,
     phoneNumbers,
     isCreatingPhoneNumber,
     isAddPhoneDialogOpen,
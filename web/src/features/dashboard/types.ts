@@ -18,10 +18,13 @@ export interface DashboardAnalyticsRecord {
   totalConversations: number;
   totalMessages: number;
   totalPhoneNumbers: number;
+  unreadConversations: number;
+  unreadMessages: number;
 }
 
 export interface DashboardPageProps extends PropsWithFlash {
   [key: string]: unknown;
   analytics: DashboardAnalyticsRecord;
   phoneNumbers: DashboardPhoneNumberRecord[];
+  emailVerified?: boolean;
 }